@@ -0,0 +1,33 @@
+//! Helpers exposing the semantics of our widgets to assistive technologies
+//! (e.g., screen readers), through egui's AccessKit integration.
+use eframe::egui::{self, accesskit::Role};
+
+/// Name the given widget, for widgets without a visible label (e.g., text
+/// areas or icon buttons)
+pub fn set_name(response: &egui::Response, name: &str) {
+    response
+        .ctx
+        .accesskit_node_builder(response.id, |builder| builder.set_name(name));
+}
+
+/// Override the role egui gives to the given widget by default
+pub fn set_role(response: &egui::Response, role: Role) {
+    response
+        .ctx
+        .accesskit_node_builder(response.id, |builder| builder.set_role(role));
+}
+
+/// Indicate whether the given collapsible widget is expanded
+pub fn set_expanded(response: &egui::Response, expanded: bool) {
+    response
+        .ctx
+        .accesskit_node_builder(response.id, |builder| builder.set_expanded(expanded));
+}
+
+/// Expose the given text area as a read-only document with the given name
+pub fn set_read_only_text(response: &egui::Response, name: &str) {
+    response.ctx.accesskit_node_builder(response.id, |builder| {
+        builder.set_name(name);
+        builder.set_read_only();
+    });
+}
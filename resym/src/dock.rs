@@ -0,0 +1,43 @@
+use egui_dock::{DockState, NodeIndex};
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
+
+/// Panels of the main window, which can be rearranged, hidden or detached
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DockTab {
+    /// Search bars and lists displayed in the left side panel by default
+    Browser,
+    /// Reconstructed content or differences
+    Code,
+    /// Console, cross-references and reverse dependencies
+    Console,
+    TypeNotes,
+}
+
+impl DockTab {
+    /// Tabs which can be hidden by users
+    pub const HIDEABLE: [DockTab; 3] = [DockTab::Browser, DockTab::Console, DockTab::TypeNotes];
+
+    pub fn title(&self) -> &'static str {
+        tr(match self {
+            DockTab::Browser => "Browser",
+            DockTab::Code => "Code",
+            DockTab::Console => "Console",
+            DockTab::TypeNotes => "Type notes",
+        })
+    }
+}
+
+/// Layout of the main window used until users rearrange it: the code view in
+/// the middle, surrounded by the browser (left), the type notes (right) and
+/// the console (bottom). Note: split fractions are the share of the code view.
+pub fn default_dock_state() -> DockState<DockTab> {
+    let mut dock_state = DockState::new(vec![DockTab::Code]);
+    let surface = dock_state.main_surface_mut();
+    let [code_node, _] = surface.split_left(NodeIndex::root(), 0.8, vec![DockTab::Browser]);
+    let [code_node, _] = surface.split_below(code_node, 0.75, vec![DockTab::Console]);
+    surface.split_right(code_node, 0.8, vec![DockTab::TypeNotes]);
+
+    dock_state
+}
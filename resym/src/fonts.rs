@@ -0,0 +1,47 @@
+//! Fonts used to display code (i.e., in the code view and the console), which
+//! can be replaced with any monospace font installed on the system.
+use eframe::egui::{self, FontDefinitions, FontFamily, FontId};
+
+use crate::platform;
+
+/// Name of the font family used to display code
+const CODE_FONT_FAMILY: &str = "code";
+
+/// Font used to display code, with the given size
+pub fn code_font_id(font_size: u16) -> FontId {
+    FontId::new(font_size as f32, FontFamily::Name(CODE_FONT_FAMILY.into()))
+}
+
+/// Build the application's font definitions, with `code_font` as the preferred
+/// font for code. egui's monospace fonts are used as fallbacks, or if
+/// `code_font` is `None`.
+pub fn font_definitions(code_font: Option<&str>) -> FontDefinitions {
+    let mut font_definitions = FontDefinitions::default();
+    let mut code_fonts = font_definitions
+        .families
+        .get(&FontFamily::Monospace)
+        .cloned()
+        .unwrap_or_default();
+
+    if let Some(code_font) = code_font {
+        match platform::load_system_font(code_font) {
+            Some(font_data) => {
+                font_definitions
+                    .font_data
+                    .insert(code_font.to_string(), font_data);
+                code_fonts.insert(0, code_font.to_string());
+            }
+            None => log::warn!("Failed to load font '{code_font}', using the default font instead"),
+        }
+    }
+    font_definitions
+        .families
+        .insert(FontFamily::Name(CODE_FONT_FAMILY.into()), code_fonts);
+
+    font_definitions
+}
+
+/// Apply the given code font to `ctx`. Takes effect on the next frame.
+pub fn apply_code_font(ctx: &egui::Context, code_font: Option<&str>) {
+    ctx.set_fonts(font_definitions(code_font));
+}
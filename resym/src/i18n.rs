@@ -0,0 +1,445 @@
+//! Translations of the labels displayed in the UI. Labels are written in
+//! English in the code, and translated with `tr` when they're displayed.
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        OnceLock,
+    },
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Language of the UI, selected by the user
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Language {
+    English,
+    French,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::French];
+
+    /// Name of the language, in that language
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::French => "Français",
+        }
+    }
+
+    fn translations(&self) -> Option<&'static HashMap<&'static str, &'static str>> {
+        static FRENCH: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+
+        match self {
+            Language::English => None,
+            Language::French => {
+                Some(FRENCH.get_or_init(|| FRENCH_LABELS.iter().copied().collect()))
+            }
+        }
+    }
+}
+
+static CURRENT_LANGUAGE: AtomicU8 = AtomicU8::new(Language::English as u8);
+
+pub fn set_language(language: Language) {
+    CURRENT_LANGUAGE.store(language as u8, Ordering::Relaxed);
+}
+
+pub fn current_language() -> Language {
+    Language::ALL
+        .into_iter()
+        .find(|language| *language as u8 == CURRENT_LANGUAGE.load(Ordering::Relaxed))
+        .unwrap_or(Language::English)
+}
+
+/// Translate the given (English) label into the current language. Labels
+/// without translation are displayed in English.
+pub fn tr(label: &'static str) -> &'static str {
+    current_language()
+        .translations()
+        .and_then(|translations| translations.get(label).copied())
+        .unwrap_or(label)
+}
+
+const FRENCH_LABELS: &[(&str, &str)] = &[
+    // Menu bar
+    ("File", "Fichier"),
+    ("Open PDB file (Ctrl+O)", "Ouvrir un fichier PDB (Ctrl+O)"),
+    ("Open URL (Ctrl+L)", "Ouvrir une URL (Ctrl+L)"),
+    ("Open PE image ...", "Ouvrir une image PE ..."),
+    ("Open", "Ouvrir"),
+    ("Compare", "Comparer"),
+    ("Compare with file ...", "Comparer avec un fichier ..."),
+    ("Compare with URL ...", "Comparer avec une URL ..."),
+    ("Project", "Projet"),
+    ("Open project ...", "Ouvrir un projet ..."),
+    ("Save project ...", "Enregistrer le projet ..."),
+    ("Export", "Exporter"),
+    (
+        "Export selected types ...",
+        "Exporter les types sélectionnés ...",
+    ),
+    (
+        "Export all enumerations ...",
+        "Exporter toutes les énumérations ...",
+    ),
+    (
+        "Export dependency graph (DOT) ...",
+        "Exporter le graphe de dépendances (DOT) ...",
+    ),
+    (
+        "Export layout assertions ...",
+        "Exporter des assertions de disposition ...",
+    ),
+    ("Settings", "Paramètres"),
+    ("Exit", "Quitter"),
+    ("View", "Affichage"),
+    ("Reset layout", "Réinitialiser la disposition"),
+    ("Tools", "Outils"),
+    ("Go to type (Ctrl+P)", "Aller au type (Ctrl+P)"),
+    ("Demangle symbol name", "Décoder un nom de symbole"),
+    ("Look up address", "Rechercher une adresse"),
+    ("About this PDB", "À propos de ce PDB"),
+    ("Performance", "Performances"),
+    (
+        "Rename types and members",
+        "Renommer des types et des membres",
+    ),
+    ("Plugins", "Extensions"),
+    ("Reload plugins", "Recharger les extensions"),
+    // Panels
+    ("Browser", "Navigateur"),
+    ("Code", "Code"),
+    ("Console", "Console"),
+    ("Type notes", "Notes de types"),
+    ("Differences", "Différences"),
+    // Settings
+    ("Theme", "Thème"),
+    ("Language", "Langue"),
+    ("Code font", "Police du code"),
+    ("Default", "Par défaut"),
+    ("Code view font size", "Taille de police du code"),
+    ("Console font size", "Taille de police de la console"),
+    ("UI scale", "Échelle de l'interface"),
+    ("Files", "Fichiers"),
+    (
+        "Offer to reload PDB files changed on disk",
+        "Proposer de recharger les fichiers PDB modifiés sur le disque",
+    ),
+    (
+        "Write logs to a file",
+        "Écrire les journaux dans un fichier",
+    ),
+    (
+        "Reduced memory mode (for huge PDB files)",
+        "Mode mémoire réduite (pour les très gros fichiers PDB)",
+    ),
+    (
+        "Applies to PDB files loaded from now on",
+        "S'applique aux fichiers PDB chargés à partir de maintenant",
+    ),
+    ("Search", "Recherche"),
+    (
+        "Ignore types from the std namespace",
+        "Ignorer les types de l'espace de noms std",
+    ),
+    (
+        "Show undecorated symbol names",
+        "Afficher les noms de symboles non décorés",
+    ),
+    (
+        "Group template instantiations",
+        "Regrouper les instanciations de templates",
+    ),
+    ("Type reconstruction", "Reconstruction des types"),
+    ("Primitive types style", "Style des types primitifs"),
+    (
+        "Custom primitive types (<portable name> => <name>)",
+        "Types primitifs personnalisés (<nom portable> => <nom>)",
+    ),
+    ("Print header", "Afficher l'en-tête"),
+    ("Header template", "Modèle d'en-tête"),
+    ("Reset", "Réinitialiser"),
+    (
+        "Print definitions of referenced types",
+        "Afficher les définitions des types référencés",
+    ),
+    (
+        "Otherwise, print forward declarations of referenced types",
+        "Sinon, afficher les déclarations anticipées des types référencés",
+    ),
+    (
+        "Print access specifiers",
+        "Afficher les spécificateurs d'accès",
+    ),
+    (
+        "Only print public members",
+        "N'afficher que les membres publics",
+    ),
+    (
+        "Omit compiler-generated members",
+        "Omettre les membres générés par le compilateur",
+    ),
+    (
+        "Print RVAs of static data members",
+        "Afficher les RVA des membres de données statiques",
+    ),
+    (
+        "Emit Doxygen comments (sizes, offsets, mangled names)",
+        "Générer des commentaires Doxygen (tailles, offsets, noms décorés)",
+    ),
+    (
+        "Annotate flag enums with their bits",
+        "Annoter les énumérations de drapeaux avec leurs bits",
+    ),
+    ("Enumerators order", "Ordre des énumérateurs"),
+    ("PDB order", "Ordre du PDB"),
+    ("By value", "Par valeur"),
+    ("By name", "Par nom"),
+    (
+        "Print virtual addresses next to RVAs",
+        "Afficher les adresses virtuelles à côté des RVA",
+    ),
+    ("Image base", "Adresse de base de l'image"),
+    ("From PE image ...", "Depuis une image PE ..."),
+    (
+        "Treat well-known types as opaque",
+        "Traiter les types connus comme opaques",
+    ),
+    (
+        "Show the type notes panel",
+        "Afficher le panneau des notes de types",
+    ),
+    (
+        "Print type notes as comments",
+        "Afficher les notes de types en commentaires",
+    ),
+    (
+        "Enable C++ syntax highlighting",
+        "Activer la coloration syntaxique C++",
+    ),
+    ("Print line numbers", "Afficher les numéros de ligne"),
+    (
+        "Wrap long lines in the code view",
+        "Renvoyer les longues lignes à la ligne",
+    ),
+    ("Code style", "Style du code"),
+    ("Indentation width", "Largeur d'indentation"),
+    ("Indent with tabs", "Indenter avec des tabulations"),
+    ("Brace placement", "Placement des accolades"),
+    ("Pointer alignment", "Alignement des pointeurs"),
+    ("Wrap long lines", "Renvoyer les longues lignes"),
+    ("Maximum line width", "Largeur de ligne maximale"),
+    (
+        "Offsets, sizes and enum values",
+        "Offsets, tailles et valeurs d'énumérations",
+    ),
+    ("Hexadecimal", "Hexadécimal"),
+    ("Decimal", "Décimal"),
+    ("Pad with leading zeros", "Compléter avec des zéros"),
+    ("Format with clang-format", "Formater avec clang-format"),
+    ("Path", "Chemin"),
+    ("Style", "Style"),
+    ("Comparison", "Comparaison"),
+    ("Ignore comment lines", "Ignorer les lignes de commentaires"),
+    (
+        "Match types by unique (decorated) name",
+        "Associer les types par nom unique (décoré)",
+    ),
+    (
+        "Ignore offset-only changes",
+        "Ignorer les changements d'offset seuls",
+    ),
+    // Browser
+    ("Search types", "Rechercher des types"),
+    ("Search symbols", "Rechercher des symboles"),
+    ("Search modules", "Rechercher des modules"),
+    ("Search source files", "Rechercher des fichiers sources"),
+    ("Browse modules", "Parcourir les modules"),
+    ("Browse source files", "Parcourir les fichiers sources"),
+    ("Browse streams", "Parcourir les flux"),
+    ("Bookmarks", "Favoris"),
+    ("Use regular expressions", "Utiliser des expressions régulières"),
+    ("Match whole words", "Mots entiers uniquement"),
+    ("Match case", "Respecter la casse"),
+    ("Changed types only", "Types modifiés uniquement"),
+    (
+        "Only list types whose reconstruction differs between the two PDBs",
+        "Ne lister que les types dont la reconstruction diffère entre les deux PDB",
+    ),
+    ("Functions", "Fonctions"),
+    ("Data", "Données"),
+    ("Thunks", "Thunks"),
+    ("Publics", "Publics"),
+    ("Other", "Autres"),
+    ("Publics only", "Publics uniquement"),
+    ("Only list public symbols", "Ne lister que les symboles publics"),
+    ("Name", "Nom"),
+    ("Kind", "Genre"),
+    ("Size", "Taille"),
+    ("Index", "Index"),
+    ("instantiations", "instanciations"),
+    ("No results", "Aucun résultat"),
+    // Code view
+    ("Reconstructed type(s) - C++", "Type(s) reconstruit(s) - C++"),
+    (
+        "Differences between reconstructed type(s) - C++",
+        "Différences entre les types reconstruits - C++",
+    ),
+    ("↩  Wrap lines", "↩  Renvoyer à la ligne"),
+    ("💾  Save (Ctrl+S)", "💾  Enregistrer (Ctrl+S)"),
+    ("📋  Copy (Ctrl+S)", "📋  Copier (Ctrl+S)"),
+    ("🔍  Find XRefs to (Alt+X)", "🔍  Trouver les références (Alt+X)"),
+    ("🔍  Find reverse dependencies", "🔍  Trouver les dépendances inverses"),
+    ("🔗  Dependency graph", "🔗  Graphe de dépendances"),
+    ("ℹ  Type properties", "ℹ  Propriétés du type"),
+    ("ℹ  Symbol properties", "ℹ  Propriétés du symbole"),
+    ("⭐  Bookmark", "⭐  Ajouter aux favoris"),
+    ("⭐  Remove bookmark", "⭐  Retirer des favoris"),
+    ("Compare public symbols", "Comparer les symboles publics"),
+    ("📋  Copy as patch", "📋  Copier comme patch"),
+    ("Unified patch", "Patch unifié"),
+    ("Side by side", "Côte à côte"),
+    ("Dependencies", "Dépendances"),
+    ("Access specifiers", "Spécificateurs d'accès"),
+    ("(overrides the global settings)", "(remplace les paramètres globaux)"),
+    ("Lines", "Lignes"),
+    ("Fields", "Champs"),
+    ("Virtual methods", "Méthodes virtuelles"),
+    ("Reconstruction cancelled", "Reconstruction annulée"),
+    ("Line numbers", "Numéros de ligne"),
+    ("Old line numbers", "Anciens numéros de ligne"),
+    ("New line numbers", "Nouveaux numéros de ligne"),
+    ("Reconstructed type", "Type reconstruit"),
+    ("Type differences", "Différences de types"),
+    // Console and type notes
+    ("XRefs to", "Références vers"),
+    ("XRefs from", "Références depuis"),
+    ("Reverse dependencies", "Dépendances inverses"),
+    ("Notes", "Notes"),
+    (
+        "Type notes are disabled in the settings",
+        "Les notes de types sont désactivées dans les paramètres",
+    ),
+    ("Select a type to attach notes to it", "Sélectionnez un type pour lui associer des notes"),
+    ("Free-form notes about this type", "Notes libres sur ce type"),
+    // Status bar
+    ("No PDB loaded", "Aucun PDB chargé"),
+    ("Cancel", "Annuler"),
+    (
+        "Cancel loading PDBs and dumping all types",
+        "Annuler le chargement des PDB et l'extraction de tous les types",
+    ),
+    ("Working", "En cours"),
+    ("pending", "en attente"),
+    ("Ready", "Prêt"),
+    ("Loaded in", "Chargé en"),
+    ("types", "types"),
+    ("Click to dismiss", "Cliquer pour masquer"),
+    // Tool windows
+    ("Open URL", "Ouvrir une URL"),
+    ("URL to open:", "URL à ouvrir :"),
+    (
+        "Address (RVA, or VA if an image base is given):",
+        "Adresse (RVA, ou VA si une adresse de base est donnée) :",
+    ),
+    ("Image base (optional):", "Adresse de base de l'image (facultative) :"),
+    ("Look up", "Rechercher"),
+    ("Address is out of the image's range", "L'adresse est en dehors de l'image"),
+    ("Invalid address", "Adresse invalide"),
+    ("Decorated name:", "Nom décoré :"),
+    ("Demangled name:", "Nom décodé :"),
+    ("Copy", "Copier"),
+    ("Invalid decorated name", "Nom décoré invalide"),
+    ("Symbol properties", "Propriétés du symbole"),
+    ("Address", "Adresse"),
+    ("Section", "Section"),
+    ("Exports", "Exports"),
+    ("Unknown", "Inconnu"),
+    (
+        "This symbol points outside of the loaded PE image",
+        "Ce symbole pointe en dehors de l'image PE chargée",
+    ),
+    ("Type properties", "Propriétés du type"),
+    ("Unique name", "Nom unique"),
+    ("None", "Aucun"),
+    ("Field count", "Nombre de champs"),
+    ("Forward reference", "Référence anticipée"),
+    ("Yes", "Oui"),
+    ("No", "Non"),
+    ("Source files", "Fichiers sources"),
+    (
+        "No line information found for this type's methods",
+        "Aucune information de ligne trouvée pour les méthodes de ce type",
+    ),
+    ("Dependency graph", "Graphe de dépendances"),
+    ("GUID", "GUID"),
+    ("Age", "Âge"),
+    ("Signature", "Signature"),
+    ("Symbol server key", "Clé du serveur de symboles"),
+    ("Machine type", "Type de machine"),
+    ("Streams", "Flux"),
+    ("Types", "Types"),
+    ("Modules", "Modules"),
+    ("Stripped", "Épuré"),
+    ("No operation has been timed yet", "Aucune opération n'a encore été chronométrée"),
+    ("Operation", "Opération"),
+    ("Count", "Nombre"),
+    ("Last", "Dernière"),
+    ("Average", "Moyenne"),
+    ("Max", "Max"),
+    ("Load", "Chargement"),
+    ("Filter", "Filtrage"),
+    ("Reconstruction", "Reconstruction"),
+    ("Diff", "Comparaison"),
+    ("Recent operations", "Opérations récentes"),
+    ("items", "éléments"),
+    ("📋  Copy report", "📋  Copier le rapport"),
+    ("Clear", "Effacer"),
+    ("PDB file changed", "Fichier PDB modifié"),
+    ("Reload", "Recharger"),
+    ("Ignore", "Ignorer"),
+    ("PDB GUID:", "GUID du PDB :"),
+    (
+        "One rename per line, applied to reconstructed types:",
+        "Un renommage par ligne, appliqué aux types reconstruits :",
+    ),
+    ("Go to type", "Aller au type"),
+    ("No matching types", "Aucun type correspondant"),
+    // Menu tooltips and dialogs
+    (
+        "Show the sections and exports of symbols",
+        "Afficher les sections et les exports des symboles",
+    ),
+    (
+        "Select multiple types with Ctrl+click and Shift+click",
+        "Sélectionner plusieurs types avec Ctrl+clic et Maj+clic",
+    ),
+    (
+        "Export all the enumerations of the PDB, sorted by name",
+        "Exporter toutes les énumérations du PDB, triées par nom",
+    ),
+    (
+        "Generate static_asserts for the sizes and offsets of the selected types (or of all types)",
+        "Générer des static_asserts pour les tailles et offsets des types sélectionnés (ou de tous les types)",
+    ),
+    ("Export as", "Exporter en"),
+    ("Plugins are loaded from", "Les extensions sont chargées depuis"),
+    (
+        "Use the image base of an executable or a DLL",
+        "Utiliser l'adresse de base d'un exécutable ou d'une DLL",
+    ),
+    ("Opaque types (<regex> [=> <header>])", "Types opaques (<regex> [=> <en-tête>])"),
+    ("Select a PDB file", "Sélectionner un fichier PDB"),
+    ("Select a PE image", "Sélectionner une image PE"),
+    ("Select a project file", "Sélectionner un fichier de projet"),
+    ("Save project file", "Enregistrer le fichier de projet"),
+    ("Save content to file", "Enregistrer le contenu dans un fichier"),
+    ("Save diff to file", "Enregistrer les différences dans un fichier"),
+    ("Save selected types to file", "Enregistrer les types sélectionnés dans un fichier"),
+    ("Save dependency graph to file", "Enregistrer le graphe de dépendances dans un fichier"),
+    ("Save enumerations to file", "Enregistrer les énumérations dans un fichier"),
+    ("Save layout assertions to file", "Enregistrer les assertions de disposition dans un fichier"),
+];
@@ -1,12 +1,24 @@
 #[cfg(target_arch = "wasm32")]
+mod accessibility;
+#[cfg(target_arch = "wasm32")]
+mod dock;
+#[cfg(target_arch = "wasm32")]
+mod fonts;
+#[cfg(target_arch = "wasm32")]
 mod frontend;
 #[cfg(target_arch = "wasm32")]
+mod i18n;
+#[cfg(target_arch = "wasm32")]
 mod mode;
 #[cfg(target_arch = "wasm32")]
 mod module_tree;
 #[cfg(target_arch = "wasm32")]
 mod module_tree_view;
 #[cfg(target_arch = "wasm32")]
+mod pdb_project;
+#[cfg(target_arch = "wasm32")]
+mod platform;
+#[cfg(target_arch = "wasm32")]
 mod resym_app;
 #[cfg(target_arch = "wasm32")]
 mod settings;
@@ -0,0 +1,95 @@
+//! Persistent copy of the console's content, used to diagnose crashes and
+//! long sessions after the fact
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use resym_core::header_template::current_date;
+
+const PKG_NAME: &str = env!("CARGO_PKG_NAME");
+
+/// Size above which the log file is rotated
+const MAX_LOG_FILE_SIZE: u64 = 4 * 1024 * 1024;
+/// Number of rotated log files kept next to the current one
+const ROTATED_LOG_FILE_COUNT: usize = 3;
+
+/// Directory containing the log files, in the application's config directory
+pub fn log_directory() -> Option<PathBuf> {
+    eframe::storage_dir(PKG_NAME).map(|storage_dir| storage_dir.join("logs"))
+}
+
+pub struct LogFile {
+    directory: PathBuf,
+    file: File,
+    file_size: u64,
+}
+
+impl LogFile {
+    /// Open the current log file of the given directory, to append to it
+    pub fn open(directory: &Path) -> io::Result<Self> {
+        fs::create_dir_all(directory)?;
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(log_file_path(directory, 0))?;
+        let file_size = file.metadata()?.len();
+        let mut log_file = Self {
+            directory: directory.to_path_buf(),
+            file,
+            file_size,
+        };
+        // Separate sessions from each other
+        log_file.write_lines(&[format!(
+            "==== {PKG_NAME} v{} started on {} ====",
+            env!("CARGO_PKG_VERSION"),
+            current_date()
+        )])?;
+
+        Ok(log_file)
+    }
+
+    pub fn write_lines(&mut self, lines: &[String]) -> io::Result<()> {
+        if lines.is_empty() {
+            return Ok(());
+        }
+        if self.file_size >= MAX_LOG_FILE_SIZE {
+            self.rotate()?;
+        }
+
+        let mut content = lines.join("\n");
+        content.push('\n');
+        self.file.write_all(content.as_bytes())?;
+        // Flush right away, so that nothing is lost if the application crashes
+        self.file.flush()?;
+        self.file_size += content.len() as u64;
+
+        Ok(())
+    }
+
+    /// Shift the existing log files (dropping the oldest one) and start a
+    /// new one
+    fn rotate(&mut self) -> io::Result<()> {
+        for index in (1..=ROTATED_LOG_FILE_COUNT).rev() {
+            let source = log_file_path(&self.directory, index - 1);
+            if source.exists() {
+                fs::rename(source, log_file_path(&self.directory, index))?;
+            }
+        }
+        self.file = File::create(log_file_path(&self.directory, 0))?;
+        self.file_size = 0;
+
+        Ok(())
+    }
+}
+
+/// Path of the current log file (`resym.log`) or of a rotated one (e.g.,
+/// `resym.1.log`)
+fn log_file_path(directory: &Path, index: usize) -> PathBuf {
+    if index == 0 {
+        directory.join(format!("{PKG_NAME}.log"))
+    } else {
+        directory.join(format!("{PKG_NAME}.{index}.log"))
+    }
+}
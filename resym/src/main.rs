@@ -1,9 +1,18 @@
 #![windows_subsystem = "windows"]
 
+mod accessibility;
+mod dock;
+mod fonts;
 mod frontend;
+mod i18n;
+#[cfg(not(target_arch = "wasm32"))]
+mod log_file;
 mod mode;
 mod module_tree;
 mod module_tree_view;
+mod pdb_project;
+mod platform;
+mod project;
 mod resym_app;
 mod settings;
 mod syntax_highlighting;
@@ -28,6 +37,10 @@ fn main() -> Result<()> {
 
     let native_options = eframe::NativeOptions {
         viewport,
+        // Keep track of the OS' theme, for the "System" theme option
+        follow_system_theme: true,
+        // The window's geometry is restored from the application's settings
+        persist_window: false,
         ..Default::default()
     };
 
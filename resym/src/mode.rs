@@ -1,4 +1,4 @@
-use resym_core::diffing::DiffChange;
+use crate::syntax_highlighting::LineDescriptions;
 
 #[derive(PartialEq)]
 pub enum ResymAppMode {
@@ -7,5 +7,5 @@ pub enum ResymAppMode {
     /// This mode means we're browsing a single PDB file
     Browsing(String, usize, String),
     /// This mode means we're comparing two PDB files for differences
-    Comparing(String, String, usize, Vec<DiffChange>, String),
+    Comparing(String, String, usize, LineDescriptions, String),
 }
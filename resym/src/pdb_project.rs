@@ -0,0 +1,89 @@
+use resym_core::{pdb_file::TypeIndex, pdb_types::TypeNotes};
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(target_arch = "wasm32"))]
+use anyhow::Result;
+
+/// User data attached to a specific PDB file, persisted in a separate project
+/// file identified by the PDB's GUID
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+pub struct PdbProject {
+    pub type_notes: TypeNotes,
+    #[serde(default)]
+    pub bookmarks: Vec<Bookmark>,
+}
+
+/// Type bookmarked by the user
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Bookmark {
+    pub type_name: String,
+    pub type_index: TypeIndex,
+}
+
+impl PdbProject {
+    pub fn is_bookmarked(&self, type_index: TypeIndex) -> bool {
+        self.bookmarks
+            .iter()
+            .any(|bookmark| bookmark.type_index == type_index)
+    }
+
+    /// Add the given type to the bookmarks, or remove it if it's already there
+    pub fn toggle_bookmark(&mut self, type_name: &str, type_index: TypeIndex) {
+        if self.is_bookmarked(type_index) {
+            self.bookmarks
+                .retain(|bookmark| bookmark.type_index != type_index);
+        } else {
+            self.bookmarks.push(Bookmark {
+                type_name: type_name.to_string(),
+                type_index,
+            });
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl PdbProject {
+    /// Load the project file associated with the given PDB. Returns an empty
+    /// project if there's none.
+    pub fn load(pdb_guid: &str) -> Result<Self> {
+        match project_file_path(pdb_guid) {
+            Some(project_file_path) if project_file_path.is_file() => {
+                let project_file = std::fs::read_to_string(project_file_path)?;
+                Ok(serde_json::from_str(&project_file)?)
+            }
+            _ => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self, pdb_guid: &str) -> Result<()> {
+        let project_file_path = project_file_path(pdb_guid)
+            .ok_or_else(|| anyhow::anyhow!("Failed to locate the storage directory"))?;
+        if let Some(projects_directory) = project_file_path.parent() {
+            std::fs::create_dir_all(projects_directory)?;
+        }
+        std::fs::write(project_file_path, serde_json::to_string_pretty(self)?)?;
+
+        Ok(())
+    }
+}
+
+/// Project files cannot be stored on wasm32, so projects only live in memory
+#[cfg(target_arch = "wasm32")]
+impl PdbProject {
+    pub fn load(_pdb_guid: &str) -> anyhow::Result<Self> {
+        Ok(Self::default())
+    }
+
+    pub fn save(&self, _pdb_guid: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn project_file_path(pdb_guid: &str) -> Option<std::path::PathBuf> {
+    eframe::storage_dir(env!("CARGO_PKG_NAME")).map(|storage_dir| {
+        storage_dir
+            .join("projects")
+            .join(format!("{pdb_guid}.json"))
+    })
+}
@@ -0,0 +1,222 @@
+//! Platform-specific helpers, which hide the differences between native and
+//! wasm32 targets from the rest of the application.
+use eframe::egui;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+
+/// Label of the button used to export reconstructed content
+#[cfg(not(target_arch = "wasm32"))]
+pub const SAVE_BUTTON_LABEL: &str = "💾  Save (Ctrl+S)";
+#[cfg(target_arch = "wasm32")]
+pub const SAVE_BUTTON_LABEL: &str = "📋  Copy (Ctrl+S)";
+
+/// File types suggested to the user when exporting content
+pub struct FileFilter<'a> {
+    pub patterns: &'a [&'a str],
+    pub description: &'a str,
+}
+
+/// Native file open/save dialogs
+#[cfg(not(target_arch = "wasm32"))]
+pub trait FileDialog {
+    /// Ask the user to select an existing file, returns `None` if cancelled
+    fn open_file(&self, title: &str, filter: &FileFilter) -> Option<PathBuf>;
+    /// Ask the user to select a destination file, returns `None` if cancelled
+    fn save_file(&self, title: &str, filter: &FileFilter) -> Option<PathBuf>;
+}
+
+/// Dialogs implemented with `tinyfiledialogs`, used by default
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    feature = "tinyfiledialogs",
+    not(feature = "rfd")
+))]
+pub struct TinyFileDialogs;
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    feature = "tinyfiledialogs",
+    not(feature = "rfd")
+))]
+impl FileDialog for TinyFileDialogs {
+    fn open_file(&self, title: &str, filter: &FileFilter) -> Option<PathBuf> {
+        tinyfiledialogs::open_file_dialog(title, "", Some((filter.patterns, filter.description)))
+            .map(PathBuf::from)
+    }
+
+    fn save_file(&self, title: &str, filter: &FileFilter) -> Option<PathBuf> {
+        tinyfiledialogs::save_file_dialog_with_filter(
+            title,
+            "",
+            filter.patterns,
+            filter.description,
+        )
+        .map(PathBuf::from)
+    }
+}
+
+/// Dialogs implemented with `rfd`, for platforms where `tinyfiledialogs`
+/// misbehaves (e.g., Linux systems without `zenity` or `kdialog`)
+#[cfg(all(not(target_arch = "wasm32"), feature = "rfd"))]
+pub struct RfdFileDialogs;
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "rfd"))]
+impl RfdFileDialogs {
+    fn dialog(title: &str, filter: &FileFilter) -> rfd::FileDialog {
+        let extensions: Vec<&str> = filter
+            .patterns
+            .iter()
+            .map(|pattern| pattern.trim_start_matches("*."))
+            .collect();
+        rfd::FileDialog::new()
+            .set_title(title)
+            .add_filter(filter.description, &extensions)
+    }
+}
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "rfd"))]
+impl FileDialog for RfdFileDialogs {
+    fn open_file(&self, title: &str, filter: &FileFilter) -> Option<PathBuf> {
+        Self::dialog(title, filter).pick_file()
+    }
+
+    fn save_file(&self, title: &str, filter: &FileFilter) -> Option<PathBuf> {
+        Self::dialog(title, filter).save_file()
+    }
+}
+
+/// Fallback used when no file dialog implementation is enabled, dialogs are
+/// never shown
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "tinyfiledialogs"),
+    not(feature = "rfd")
+))]
+pub struct NoFileDialogs;
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "tinyfiledialogs"),
+    not(feature = "rfd")
+))]
+impl FileDialog for NoFileDialogs {
+    fn open_file(&self, _title: &str, _filter: &FileFilter) -> Option<PathBuf> {
+        log::error!("resym was built without file dialogs support");
+        None
+    }
+
+    fn save_file(&self, _title: &str, _filter: &FileFilter) -> Option<PathBuf> {
+        log::error!("resym was built without file dialogs support");
+        None
+    }
+}
+
+/// Return the file dialog implementation selected at compile time
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    feature = "tinyfiledialogs",
+    not(feature = "rfd")
+))]
+pub fn file_dialog() -> impl FileDialog {
+    TinyFileDialogs
+}
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "rfd"))]
+pub fn file_dialog() -> impl FileDialog {
+    RfdFileDialogs
+}
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "tinyfiledialogs"),
+    not(feature = "rfd")
+))]
+pub fn file_dialog() -> impl FileDialog {
+    NoFileDialogs
+}
+
+/// Export text content out of the application.
+///
+/// On native targets, the user is prompted for a file path and the content is
+/// written to disk (with the filter's first extension if the path has none).
+/// On wasm32 targets, we don't have access to the file system, so the content
+/// is copied to the clipboard instead.
+///
+/// Returns a description of the destination, or `None` if the user cancelled.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn export_text(
+    _ctx: &egui::Context,
+    dialog_title: &str,
+    filter: &FileFilter,
+    content: &str,
+) -> std::io::Result<Option<String>> {
+    let Some(mut file_path) = file_dialog().save_file(dialog_title, filter) else {
+        return Ok(None);
+    };
+    if file_path.extension().is_none() {
+        if let Some(pattern) = filter.patterns.first() {
+            file_path.set_extension(pattern.trim_start_matches("*."));
+        }
+    }
+    std::fs::write(&file_path, content)?;
+
+    Ok(Some(format!("'{}'", file_path.display())))
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn export_text(
+    ctx: &egui::Context,
+    _dialog_title: &str,
+    _filter: &FileFilter,
+    content: &str,
+) -> std::io::Result<Option<String>> {
+    ctx.output_mut(|output| output.copied_text = content.to_string());
+
+    Ok(Some("the clipboard".to_string()))
+}
+
+/// Return the family names of the monospace fonts installed on the system
+#[cfg(not(target_arch = "wasm32"))]
+pub fn system_monospace_fonts() -> Vec<String> {
+    let mut font_database = fontdb::Database::new();
+    font_database.load_system_fonts();
+
+    let mut font_families: Vec<String> = font_database
+        .faces()
+        .filter(|face| face.monospaced)
+        .filter_map(|face| face.families.first().map(|(family, _)| family.clone()))
+        .collect();
+    font_families.sort();
+    font_families.dedup();
+
+    font_families
+}
+
+/// Web pages cannot enumerate the fonts installed on the system
+#[cfg(target_arch = "wasm32")]
+pub fn system_monospace_fonts() -> Vec<String> {
+    vec![]
+}
+
+/// Load the regular face of an installed font family, returns `None` if the
+/// family cannot be found
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_system_font(font_family: &str) -> Option<egui::FontData> {
+    let mut font_database = fontdb::Database::new();
+    font_database.load_system_fonts();
+
+    let face_id = font_database.query(&fontdb::Query {
+        families: &[fontdb::Family::Name(font_family)],
+        ..Default::default()
+    })?;
+    font_database.with_face_data(face_id, |font_data, face_index| {
+        let mut font_data = egui::FontData::from_owned(font_data.to_vec());
+        font_data.index = face_index;
+        font_data
+    })
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn load_system_font(_font_family: &str) -> Option<egui::FontData> {
+    None
+}
@@ -0,0 +1,81 @@
+//! Project files (`.resym`), which bundle a PDB file with the user's work on
+//! it (e.g., notes, bookmarks and renames) to resume sessions later on.
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use resym_core::pdb_file::{PdbMetadata, TypeIndex};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    pdb_project::PdbProject,
+    resym_app::{BottomPanelTab, LeftPanelTab},
+};
+
+/// Extension of project files
+pub const PROJECT_FILE_EXTENSION: &str = "resym";
+/// Symbol server used to fetch PDB files which cannot be found on disk
+#[cfg(feature = "http")]
+const SYMBOL_SERVER_URL: &str = "https://msdl.microsoft.com/download/symbols";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ResymProject {
+    pub pdb: ProjectPdb,
+    #[serde(flatten)]
+    pub pdb_project: PdbProject,
+    /// User-defined type and member names (`RenameMap` format)
+    pub renames: String,
+    pub window_state: WindowState,
+}
+
+/// Location of the PDB file a project is about
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ProjectPdb {
+    /// Path of the PDB file, or its name if it wasn't loaded from disk
+    pub path: PathBuf,
+    pub guid: String,
+    /// Key used to fetch the PDB from a symbol server if it cannot be found
+    pub symbol_server_key: String,
+}
+
+/// State of the main window, restored when opening a project
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WindowState {
+    pub left_panel_tab: LeftPanelTab,
+    pub bottom_panel_tab: BottomPanelTab,
+    pub selected_type_index: Option<TypeIndex>,
+}
+
+impl ResymProject {
+    pub fn load(project_file_path: &Path) -> Result<Self> {
+        let project_file = std::fs::read_to_string(project_file_path)?;
+
+        Ok(serde_json::from_str(&project_file)?)
+    }
+
+    pub fn save(&self, project_file_path: &Path) -> Result<()> {
+        std::fs::write(project_file_path, serde_json::to_string_pretty(self)?)?;
+
+        Ok(())
+    }
+}
+
+impl ProjectPdb {
+    pub fn new(metadata: &PdbMetadata) -> Self {
+        Self {
+            path: metadata.file_path.clone(),
+            guid: metadata.guid.clone(),
+            symbol_server_key: metadata.symbol_server_key(),
+        }
+    }
+
+    /// URL of the PDB file on the symbol server
+    #[cfg(feature = "http")]
+    pub fn symbol_server_url(&self) -> Option<String> {
+        let file_name = self.path.file_name()?.to_string_lossy();
+
+        Some(format!(
+            "{SYMBOL_SERVER_URL}/{file_name}/{}/{file_name}",
+            self.symbol_server_key
+        ))
+    }
+}
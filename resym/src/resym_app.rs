@@ -1,26 +1,61 @@
 use anyhow::Result;
 use eframe::egui;
+use egui_dock::{DockArea, DockState};
 use memory_logger::blocking::MemoryLogger;
+#[cfg(not(target_arch = "wasm32"))]
+use resym_core::clang_format::ClangFormatConfiguration;
+#[cfg(feature = "plugins")]
+use resym_core::plugins::{PluginExportFormat, PluginManager, PluginMenuCommand};
 use resym_core::{
-    backend::{Backend, BackendCommand, PDBSlot},
-    frontend::FrontendCommand,
-    pdb_file::{SymbolIndex, TypeIndex},
+    backend::{Backend, BackendCommand, OperationKind, PDBSlot, SymbolListOptions},
+    diffing::{format_side_by_side_diff, format_unified_patch, DiffChange, DiffOptions},
+    frontend::{FrontendCommand, SearchTarget},
+    header_template::HeaderTemplate,
+    pdb_file::{LoadMode, ModuleIndex, StreamIndex, SymbolIndex, TypeIndex, TypeInfoMap, TypeList},
+    pdb_types::{
+        CodeFormatConfiguration, EnumValueOrder, MemberFilter, NumberFormat, OpaqueTypeRules,
+        PrimitiveTypeMapping, RenameMap, TypeNotes,
+    },
+    ErrorContext, ResymCoreError,
 };
+use serde::{Deserialize, Serialize};
 
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
 #[cfg(target_arch = "wasm32")]
 use std::{cell::RefCell, rc::Rc};
-use std::{fmt::Write, sync::Arc, vec};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+    sync::Arc,
+    vec,
+};
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::log_file::{self, LogFile};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::project::{ProjectPdb, ResymProject, WindowState, PROJECT_FILE_EXTENSION};
 #[cfg(feature = "http")]
 use crate::ui_components::OpenURLComponent;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::ui_components::ReloadPromptComponent;
 use crate::{
+    dock::{default_dock_state, DockTab},
+    fonts,
     frontend::EguiFrontendController,
+    i18n::{self, tr},
     mode::ResymAppMode,
     module_tree::{ModuleInfo, ModulePath},
-    settings::ResymAppSettings,
+    pdb_project::PdbProject,
+    platform,
+    settings::{ResymAppSettings, ThemePreference, ToolWindow},
     ui_components::{
-        CodeViewComponent, ConsoleComponent, IndexListComponent, IndexListOrdering,
-        ModuleTreeComponent, SettingsComponent, TextSearchComponent,
+        AddressLookupComponent, CodeViewComponent, ConsoleComponent, DemanglerComponent,
+        DependencyGraphComponent, DiffStatisticsComponent, IndexListComponent, IndexListOrdering,
+        ModuleTreeComponent, PdbMetadataComponent, PerformanceComponent, RenamesComponent,
+        SearchOptions, SettingsComponent, StatusBarComponent, SymbolPropertiesComponent,
+        TextSearchComponent, TypeNotesComponent, TypePaletteComponent, TypePropertiesComponent,
+        TypeTableComponent, ViewSettingsComponent,
     },
 };
 
@@ -41,20 +76,72 @@ impl From<ResymPDBSlots> for PDBSlot {
     }
 }
 
+/// Text formats diffs can be saved in
+#[derive(Clone, Copy)]
+enum DiffExportFormat {
+    UnifiedPatch,
+    SideBySide,
+}
+
 /// Tabs available for the left-side panel
-#[derive(PartialEq)]
-enum LeftPanelTab {
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeftPanelTab {
     TypeSearch,
     SymbolSearch,
     ModuleBrowsing,
+    SourceFileBrowsing,
+    StreamBrowsing,
+    Bookmarks,
+}
+
+/// Parts of the main window which can be focused in turn with F6
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FocusablePanel {
+    TypeSearch,
+    TypeList,
+    CodeView,
+}
+
+impl FocusablePanel {
+    const ALL: [FocusablePanel; 3] = [
+        FocusablePanel::TypeSearch,
+        FocusablePanel::TypeList,
+        FocusablePanel::CodeView,
+    ];
+}
+
+/// Directory from which plugins are loaded on startup
+#[cfg(all(feature = "plugins", not(target_arch = "wasm32")))]
+fn plugins_directory() -> Option<std::path::PathBuf> {
+    eframe::storage_dir(PKG_NAME).map(|storage_dir| storage_dir.join("plugins"))
+}
+
+/// Directory where PDB files opened from URLs are cached
+#[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+fn url_cache_directory() -> Option<std::path::PathBuf> {
+    eframe::storage_dir(PKG_NAME).map(|storage_dir| storage_dir.join("pdb_cache"))
+}
+
+/// Plugins can only be loaded from the file system, which isn't available on
+/// wasm32
+#[cfg(all(feature = "plugins", target_arch = "wasm32"))]
+fn plugins_directory() -> Option<std::path::PathBuf> {
+    None
 }
 
 /// Tabs available for the bottom panel
-#[derive(PartialEq)]
-enum BottomPanelTab {
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BottomPanelTab {
     Console,
     XRefsTo,
     XRefsFrom,
+    ReverseDependencies,
+}
+
+/// State kept while the main PDB is being reloaded, restored once it's loaded
+#[cfg(not(target_arch = "wasm32"))]
+struct PendingReload {
+    selected_type_name: Option<String>,
 }
 
 /// Struct that represents our GUI application.
@@ -64,23 +151,108 @@ pub struct ResymApp {
     // Components used in the left-side panel
     left_panel_selected_tab: LeftPanelTab,
     type_search: TextSearchComponent,
-    type_list: IndexListComponent<TypeIndex>,
+    // Only list types that differ between the two PDBs, in comparing mode
+    changed_types_only: bool,
+    type_list: TypeTableComponent,
     selected_type_index: Option<TypeIndex>,
     symbol_search: TextSearchComponent,
+    symbol_kind_filter: SymbolKindFilterComponent,
     symbol_list: IndexListComponent<SymbolIndex>,
     selected_symbol_index: Option<SymbolIndex>,
     module_search: TextSearchComponent,
     module_tree: ModuleTreeComponent,
+    source_file_search: TextSearchComponent,
+    source_file_list: IndexListComponent<ModuleIndex>,
+    stream_list: IndexListComponent<StreamIndex>,
+    bookmark_list: IndexListComponent<TypeIndex>,
     code_view: CodeViewComponent,
+    view_settings: ViewSettingsComponent,
+    diff_statistics: DiffStatisticsComponent,
     // Components used in the bottom panel
     bottom_panel_selected_tab: BottomPanelTab,
     console: ConsoleComponent,
     xref_to_list: IndexListComponent<TypeIndex>,
     xref_from_list: IndexListComponent<TypeIndex>,
+    reverse_dependency_list: IndexListComponent<TypeIndex>,
+    // Components used in the right-side panel
+    type_notes: TypeNotesComponent,
     // Other components
     settings: SettingsComponent,
+    demangler: DemanglerComponent,
+    address_lookup: AddressLookupComponent,
+    symbol_properties: SymbolPropertiesComponent,
+    type_properties: TypePropertiesComponent,
+    type_palette: TypePaletteComponent,
+    dependency_graph: DependencyGraphComponent,
+    pdb_metadata: PdbMetadataComponent,
+    performance: PerformanceComponent,
+    status_bar: StatusBarComponent,
+    #[cfg(not(target_arch = "wasm32"))]
+    reload_prompt: ReloadPromptComponent,
+    renames: RenamesComponent,
     #[cfg(feature = "http")]
     open_url: OpenURLComponent,
+    /// Opaque type rules last sent to the backend
+    applied_opaque_types: Option<String>,
+    /// Primitive type mapping last sent to the backend
+    applied_primitive_type_mapping: Option<String>,
+    /// Code style last sent to the backend
+    applied_code_format: Option<CodeFormatConfiguration>,
+    applied_header_template: Option<String>,
+    applied_member_filter: Option<MemberFilter>,
+    applied_print_static_member_rvas: Option<bool>,
+    applied_doxygen_comments: Option<bool>,
+    applied_flag_enum_comments: Option<bool>,
+    applied_enum_value_order: Option<EnumValueOrder>,
+    applied_forward_declare_dependencies: Option<bool>,
+    applied_image_base: Option<Option<u64>>,
+    applied_load_mode: Option<LoadMode>,
+    applied_number_format: Option<NumberFormat>,
+    /// Code font last applied to the egui context (`None` for the default font)
+    applied_code_font: Option<String>,
+    applied_ui_scale: Option<f32>,
+    /// Renames last sent to the backend
+    applied_renames: Option<BTreeMap<String, String>>,
+    /// GUID of the PDB loaded in the main slot
+    main_pdb_guid: Option<String>,
+    /// Renames applied to the PDB loaded in the main slot
+    main_pdb_rename_map: RenameMap,
+    /// Project data (e.g., type notes) of the PDB loaded in the main slot
+    main_pdb_project: PdbProject,
+    /// Type notes last sent to the backend
+    applied_type_notes: Option<TypeNotes>,
+    /// Location of the PDB loaded in the main slot, saved in project files
+    #[cfg(not(target_arch = "wasm32"))]
+    main_pdb_location: Option<ProjectPdb>,
+    /// Project being opened, applied once its PDB has been loaded
+    #[cfg(not(target_arch = "wasm32"))]
+    pending_project: Option<ResymProject>,
+    /// Reload of the main PDB in progress, if any
+    #[cfg(not(target_arch = "wasm32"))]
+    pending_reload: Option<PendingReload>,
+    /// Whether loaded PDB files are watched, last sent to the backend
+    #[cfg(not(target_arch = "wasm32"))]
+    applied_watch_pdb_files: Option<bool>,
+    /// `clang-format` configuration, last sent to the backend
+    #[cfg(not(target_arch = "wasm32"))]
+    applied_clang_format: Option<Option<ClangFormatConfiguration>>,
+    /// File the console's content is copied to, if enabled
+    #[cfg(not(target_arch = "wasm32"))]
+    log_file: Option<LogFile>,
+    #[cfg(not(target_arch = "wasm32"))]
+    applied_log_to_file: Option<bool>,
+    /// Last type list received from the backend, with the original type names
+    filtered_types: TypeList,
+    /// Kind and size of the main PDB's types
+    type_infos: TypeInfoMap,
+    #[cfg(feature = "plugins")]
+    plugin_manager: PluginManager,
+    /// Export formats and menu commands registered by plugins, queried once
+    /// when loading plugins
+    #[cfg(feature = "plugins")]
+    plugin_export_formats: Vec<PluginExportFormat>,
+    #[cfg(feature = "plugins")]
+    plugin_menu_commands: Vec<PluginMenuCommand>,
     frontend_controller: Arc<EguiFrontendController>,
     backend: Backend,
     /// Field used by wasm32 targets to store PDB file information
@@ -93,26 +265,181 @@ pub struct ResymApp {
 impl eframe::App for ResymApp {
     fn save(&mut self, storage: &mut dyn eframe::Storage) {
         // Save settings on shutdown
+        self.settings.app_settings.open_tool_windows = [
+            (ToolWindow::Demangler, self.demangler.is_open()),
+            (ToolWindow::AddressLookup, self.address_lookup.is_open()),
+            (ToolWindow::Performance, self.performance.is_open()),
+        ]
+        .into_iter()
+        .filter_map(|(tool_window, is_open)| is_open.then_some(tool_window))
+        .collect();
         eframe::set_value(storage, eframe::APP_KEY, &self.settings.app_settings);
+
+        // Save the notes which are still being edited
+        if self.type_notes.take_unsaved_changes() {
+            if let Some(pdb_guid) = &self.main_pdb_guid {
+                if let Err(err) = self.main_pdb_project.save(pdb_guid) {
+                    log::error!("Failed to save type notes: {err}");
+                }
+            }
+        }
     }
 
     /// Called each time the UI needs repainting, which may be many times per second.
     /// Put your widgets into a `SidePanel`, `TopPanel`, `CentralPanel`, `Window` or `Area`.
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
         // For wasm32 targets, we cannot block in the UI thread so we have to
         // check for PDB file opening results manually in an non-blocking way.
         #[cfg(target_arch = "wasm32")]
         self.process_open_pdb_file_result();
 
         // Process incoming commands, if any
-        self.process_ui_commands();
+        self.process_ui_commands(ctx);
+
+        // Move new log lines to the console (and to the log file if enabled)
+        #[cfg(target_arch = "wasm32")]
+        self.console.collect_logs();
+        #[cfg(not(target_arch = "wasm32"))]
+        self.process_log_file_update();
+
+        // Keep track of the window's geometry, restored at startup
+        self.record_window_layout(ctx);
 
         // Update theme if needed
-        self.process_theme_update(ctx);
+        self.process_theme_update(ctx, frame.info().system_theme);
+
+        // Update code font if needed
+        self.process_code_font_update(ctx);
+
+        // Update UI scale if needed
+        self.process_ui_scale_update(ctx);
+
+        // Update the UI's language if needed
+        i18n::set_language(self.settings.app_settings.language);
+
+        // Update opaque type rules if needed
+        self.process_opaque_type_rules_update();
+
+        // Update primitive type mapping if needed
+        self.process_primitive_type_mapping_update();
+
+        // Update code style if needed
+        self.process_code_format_update();
+
+        // Update number format if needed
+        self.process_number_format_update();
+
+        // Update header template if needed
+        self.process_header_template_update();
+
+        // Update member filter if needed
+        self.process_member_filter_update();
+
+        // Update static member annotations if needed
+        self.process_static_member_rvas_update();
+
+        // Update Doxygen comments if needed
+        self.process_doxygen_comments_update();
+
+        // Update flag enum comments if needed
+        self.process_flag_enum_comments_update();
+
+        // Update enumerators order if needed
+        self.process_enum_value_order_update();
+
+        // Update dependency forward declarations if needed
+        self.process_forward_declare_dependencies_update();
+
+        // Update image base if needed
+        self.process_image_base_update();
+
+        // Update load mode if needed
+        self.process_load_mode_update();
+
+        // Update renames if needed
+        self.process_renames_update();
+
+        // Update PDB file watching if needed
+        #[cfg(not(target_arch = "wasm32"))]
+        self.process_watch_pdb_files_update();
+
+        // Update type notes if needed
+        self.process_type_notes_update();
+
+        // Update clang-format configuration if needed
+        #[cfg(not(target_arch = "wasm32"))]
+        self.process_clang_format_update();
 
         // Update the "Settings" window if open
         self.settings.update(ctx);
 
+        // Make the current view follow the global settings, unless overridden
+        self.view_settings.sync(&self.settings.app_settings);
+
+        // Collapse template instantiations in the type list if needed
+        self.type_list
+            .set_group_templates(self.settings.app_settings.search_group_templates);
+
+        // Update the "Demangle symbol name" window if open
+        self.demangler.update(ctx);
+
+        // Update the "Look up address" window if open
+        self.address_lookup.update(ctx, &self.backend);
+
+        // Update the "Symbol properties" window if open
+        self.symbol_properties
+            .update(ctx, self.settings.app_settings.image_base());
+
+        // Update the "Type properties" window if open
+        self.type_properties.update(ctx);
+
+        // Update the "Go to type" palette if open
+        if let Some(type_index) = self.type_palette.update(ctx, &self.backend) {
+            self.selected_type_index = Some(type_index);
+            self.refresh_selected_type();
+        }
+
+        // Update the "Dependency graph" window if open
+        let mut on_type_selected = |_: &str, type_index: TypeIndex| {
+            // Update currently selected type index
+            self.selected_type_index = Some(type_index);
+
+            // Note: only support "Browsing" mode
+            if let ResymAppMode::Browsing(..) = self.current_mode {
+                if let Err(err) = self
+                    .backend
+                    .send_command(BackendCommand::ReconstructTypeByIndex(
+                        ResymPDBSlots::Main as usize,
+                        type_index,
+                        self.view_settings.primitive_types_flavor,
+                        self.settings.app_settings.print_header,
+                        self.view_settings.reconstruct_dependencies,
+                        self.view_settings.print_access_specifiers,
+                        self.settings.app_settings.ignore_std_types,
+                    ))
+                {
+                    log::error!("Failed to reconstruct type: {}", err);
+                }
+            }
+        };
+        self.dependency_graph.update(ctx, &mut on_type_selected);
+
+        // Update the "About this PDB" window if open
+        self.pdb_metadata.update(ctx);
+
+        // Update the "Performance" window if open
+        self.performance.update(ctx);
+
+        // Offer to reload PDB files which changed on disk, if any
+        #[cfg(not(target_arch = "wasm32"))]
+        for (pdb_slot, pdb_file_path) in self.reload_prompt.update(ctx) {
+            self.reload_pdb_file(pdb_slot, pdb_file_path);
+        }
+
+        // Update the "Rename types and members" window if open
+        self.renames
+            .update(ctx, &mut self.settings.app_settings.renames);
+
         // Update "Open URL" window if open
         #[cfg(feature = "http")]
         self.open_url.update(ctx, &self.backend);
@@ -120,14 +447,12 @@ impl eframe::App for ResymApp {
         // Update the top panel (i.e, menu bar)
         self.update_top_panel(ctx);
 
-        // Update the left side panel (i.e., the type search bar and the type list)
-        self.update_left_side_panel(ctx);
-
-        // Update the bottom panel (i.e., the console)
-        self.update_bottom_panel(ctx);
+        // Update the status bar (i.e., loaded PDBs and backend progress)
+        self.update_status_bar(ctx);
 
-        // Update the central panel (i.e., the code view)
-        self.update_central_panel(ctx);
+        // Update the dockable panels (i.e., the browser, the console, the type
+        // notes and the code view)
+        self.update_dock_area(ctx);
 
         // Process drag and drop messages, if any
         self.handle_drag_and_drop(ctx);
@@ -151,6 +476,11 @@ impl ResymApp {
             cc.egui_ctx.clone(),
         ));
         let backend = Backend::new(frontend_controller.clone())?;
+        // Collect the duration of operations, displayed in the "Performance" window
+        backend.send_command(BackendCommand::SetReportOperationTimings(true))?;
+        // Only download PDB files opened from URLs once
+        #[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+        backend.send_command(BackendCommand::SetURLCacheDirectory(url_cache_directory()))?;
 
         // Load settings on launch
         let app_settings = if let Some(storage) = cc.storage {
@@ -159,35 +489,180 @@ impl ResymApp {
             ResymAppSettings::default()
         };
 
+        // Note: fonts must be set before the first frame, as the code font
+        // family has to be defined before its first use
+        let code_font = app_settings.code_font.clone();
+        fonts::apply_code_font(&cc.egui_ctx, code_font.as_deref());
+
         log::info!("{} {}", PKG_NAME, PKG_VERSION);
-        Ok(Self {
+        let mut app = Self {
             current_mode: ResymAppMode::Idle,
             left_panel_selected_tab: LeftPanelTab::TypeSearch,
-            type_search: TextSearchComponent::new(),
-            type_list: IndexListComponent::new(IndexListOrdering::Alphabetical),
+            type_search: TextSearchComponent::new("Search types"),
+            changed_types_only: false,
+            type_list: TypeTableComponent::new(),
             selected_type_index: None,
-            symbol_search: TextSearchComponent::new(),
+            symbol_search: TextSearchComponent::new("Search symbols"),
+            symbol_kind_filter: SymbolKindFilterComponent::new(),
             symbol_list: IndexListComponent::new(IndexListOrdering::Alphabetical),
             selected_symbol_index: None,
-            module_search: TextSearchComponent::new(),
+            module_search: TextSearchComponent::new("Search modules"),
             module_tree: ModuleTreeComponent::new(),
+            source_file_search: TextSearchComponent::new("Search source files"),
+            source_file_list: IndexListComponent::new(IndexListOrdering::Alphabetical),
+            stream_list: IndexListComponent::new(IndexListOrdering::None),
+            bookmark_list: IndexListComponent::new(IndexListOrdering::Alphabetical),
             code_view: CodeViewComponent::new(),
+            view_settings: ViewSettingsComponent::new(&app_settings),
+            diff_statistics: DiffStatisticsComponent::new(),
             bottom_panel_selected_tab: BottomPanelTab::Console,
             console: ConsoleComponent::new(logger),
             xref_to_list: IndexListComponent::new(IndexListOrdering::Alphabetical),
             xref_from_list: IndexListComponent::new(IndexListOrdering::Alphabetical),
+            reverse_dependency_list: IndexListComponent::new(IndexListOrdering::Alphabetical),
+            type_notes: TypeNotesComponent::new(),
             settings: SettingsComponent::new(app_settings),
+            demangler: DemanglerComponent::new(),
+            address_lookup: AddressLookupComponent::new(),
+            symbol_properties: SymbolPropertiesComponent::new(),
+            type_properties: TypePropertiesComponent::new(),
+            type_palette: TypePaletteComponent::new(),
+            dependency_graph: DependencyGraphComponent::new(),
+            pdb_metadata: PdbMetadataComponent::new(),
+            performance: PerformanceComponent::new(),
+            status_bar: StatusBarComponent::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            reload_prompt: ReloadPromptComponent::new(),
+            renames: RenamesComponent::new(),
             #[cfg(feature = "http")]
             open_url: OpenURLComponent::new(),
+            applied_opaque_types: None,
+            applied_primitive_type_mapping: None,
+            applied_code_format: None,
+            applied_header_template: None,
+            applied_member_filter: None,
+            applied_print_static_member_rvas: None,
+            applied_doxygen_comments: None,
+            applied_flag_enum_comments: None,
+            applied_enum_value_order: None,
+            applied_forward_declare_dependencies: None,
+            applied_image_base: None,
+            applied_load_mode: None,
+            applied_number_format: None,
+            applied_code_font: code_font,
+            applied_ui_scale: None,
+            applied_renames: None,
+            main_pdb_guid: None,
+            main_pdb_rename_map: RenameMap::default(),
+            main_pdb_project: PdbProject::default(),
+            applied_type_notes: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            main_pdb_location: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            pending_project: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            pending_reload: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            applied_watch_pdb_files: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            applied_clang_format: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            log_file: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            applied_log_to_file: None,
+            filtered_types: vec![],
+            type_infos: TypeInfoMap::default(),
+            #[cfg(feature = "plugins")]
+            plugin_manager: PluginManager::new(),
+            #[cfg(feature = "plugins")]
+            plugin_export_formats: vec![],
+            #[cfg(feature = "plugins")]
+            plugin_menu_commands: vec![],
             frontend_controller,
             backend,
             #[cfg(target_arch = "wasm32")]
             open_pdb_data: Rc::new(RefCell::new(None)),
-        })
+        };
+        #[cfg(feature = "plugins")]
+        app.reload_plugins();
+        app.restore_layout(&cc.egui_ctx);
+
+        Ok(app)
+    }
+
+    /// Restore the window's geometry and the tool windows that were open when
+    /// the application was last closed. The layout of panels is part of the
+    /// settings (see `DockState`).
+    fn restore_layout(&mut self, ctx: &egui::Context) {
+        let app_settings = &self.settings.app_settings;
+        if let Some((width, height)) = app_settings.window_size {
+            ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(egui::vec2(width, height)));
+        }
+        if let Some((x, y)) = app_settings.window_position {
+            ctx.send_viewport_cmd(egui::ViewportCommand::OuterPosition(egui::pos2(x, y)));
+        }
+        for tool_window in app_settings.open_tool_windows.clone() {
+            match tool_window {
+                ToolWindow::Demangler => self.demangler.open(),
+                ToolWindow::AddressLookup => self.address_lookup.open(),
+                ToolWindow::Performance => self.performance.open(),
+            }
+        }
+    }
+
+    fn record_window_layout(&mut self, ctx: &egui::Context) {
+        let (inner_rect, outer_rect) =
+            ctx.input(|input| (input.viewport().inner_rect, input.viewport().outer_rect));
+        if let Some(inner_rect) = inner_rect {
+            self.settings.app_settings.window_size =
+                Some((inner_rect.width(), inner_rect.height()));
+        }
+        if let Some(outer_rect) = outer_rect {
+            self.settings.app_settings.window_position = Some((outer_rect.min.x, outer_rect.min.y));
+        }
+    }
+
+    /// (Re)load plugins from the user's plugins directory, if it exists
+    #[cfg(feature = "plugins")]
+    fn reload_plugins(&mut self) {
+        self.plugin_manager = PluginManager::new();
+        self.plugin_export_formats.clear();
+        self.plugin_menu_commands.clear();
+
+        let Some(plugins_directory) = plugins_directory().filter(|dir| dir.is_dir()) else {
+            return;
+        };
+        match self.plugin_manager.load_directory(&plugins_directory) {
+            Err(err) => log::error!("Failed to load plugins: {err}"),
+            Ok(errors) => {
+                for err in errors {
+                    log::error!("Failed to load plugin: {err}");
+                }
+            }
+        }
+        for plugin_name in self.plugin_manager.plugin_names() {
+            log::info!("Plugin '{plugin_name}' loaded");
+        }
+
+        match self.plugin_manager.export_formats() {
+            Ok(export_formats) => self.plugin_export_formats = export_formats,
+            Err(err) => log::error!("Failed to list plugin export formats: {err}"),
+        }
+        match self.plugin_manager.menu_commands() {
+            Ok(menu_commands) => self.plugin_menu_commands = menu_commands,
+            Err(err) => log::error!("Failed to list plugin commands: {err}"),
+        }
     }
 
-    fn process_theme_update(&mut self, ctx: &egui::Context) {
-        let theme = if self.settings.app_settings.use_light_theme {
+    fn process_theme_update(&mut self, ctx: &egui::Context, system_theme: Option<eframe::Theme>) {
+        let use_light_theme = match self.settings.app_settings.theme {
+            // Note: the system theme is updated at runtime by `eframe`.
+            // Default to the dark theme if it cannot be detected.
+            ThemePreference::System => system_theme == Some(eframe::Theme::Light),
+            ThemePreference::Light => true,
+            ThemePreference::Dark => false,
+        };
+        let theme = if use_light_theme {
             egui::Visuals::light()
         } else {
             egui::Visuals::dark()
@@ -195,867 +670,2922 @@ impl ResymApp {
         ctx.set_visuals(theme);
     }
 
-    fn update_top_panel(&mut self, ctx: &egui::Context) {
-        egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
-            // Process keyboard shortcuts, if any
-            self.consume_keyboard_shortcuts(ui);
+    fn process_code_font_update(&mut self, ctx: &egui::Context) {
+        let code_font = &self.settings.app_settings.code_font;
+        if &self.applied_code_font == code_font {
+            // Font hasn't changed
+            return;
+        }
 
-            // The top panel is often a good place for a menu bar
-            self.update_menu_bar(ui);
-        });
+        fonts::apply_code_font(ctx, code_font.as_deref());
+        self.applied_code_font = code_font.clone();
     }
 
-    fn update_left_side_panel(&mut self, ctx: &egui::Context) {
-        egui::SidePanel::left("side_panel")
-            .default_width(250.0)
-            .width_range(100.0..=f32::INFINITY)
-            .show(ctx, |ui| {
-                ui.add_space(2.0);
-                ui.horizontal(|ui| {
-                    ui.selectable_value(
-                        &mut self.left_panel_selected_tab,
-                        LeftPanelTab::TypeSearch,
-                        "Search types",
-                    );
-                    ui.selectable_value(
-                        &mut self.left_panel_selected_tab,
-                        LeftPanelTab::SymbolSearch,
-                        "Search symbols",
-                    );
-                    ui.selectable_value(
-                        &mut self.left_panel_selected_tab,
-                        LeftPanelTab::ModuleBrowsing,
-                        "Browse modules",
-                    );
-                });
-                ui.separator();
-
-                match self.left_panel_selected_tab {
-                    LeftPanelTab::TypeSearch => {
-                        // Callback run when the search query changes
-                        let on_query_update = |search_query: &str| {
-                            // Update filtered list if filter has changed
-                            let result = if let ResymAppMode::Comparing(..) = self.current_mode {
-                                self.backend.send_command(BackendCommand::ListTypesMerged(
-                                    vec![
-                                        ResymPDBSlots::Main as usize,
-                                        ResymPDBSlots::Diff as usize,
-                                    ],
-                                    search_query.to_string(),
-                                    self.settings.app_settings.search_case_insensitive,
-                                    self.settings.app_settings.search_use_regex,
-                                    self.settings.app_settings.ignore_std_types,
-                                ))
-                            } else {
-                                self.backend.send_command(BackendCommand::ListTypes(
-                                    ResymPDBSlots::Main as usize,
-                                    search_query.to_string(),
-                                    self.settings.app_settings.search_case_insensitive,
-                                    self.settings.app_settings.search_use_regex,
-                                    self.settings.app_settings.ignore_std_types,
-                                ))
-                            };
-                            if let Err(err) = result {
-                                log::error!("Failed to update type filter value: {}", err);
-                            }
-                        };
-
-                        // Update the type search bar
-                        ui.label("Search");
-                        self.type_search.update(ui, &on_query_update);
-                        ui.separator();
-                        ui.add_space(4.0);
-
-                        // Callback run when a type is selected in the list
-                        let mut on_type_selected = |type_name: &str, type_index: TypeIndex| {
-                            // Update currently selected type index
-                            self.selected_type_index = Some(type_index);
-
-                            match self.current_mode {
-                                ResymAppMode::Browsing(..) => {
-                                    if let Err(err) = self.backend.send_command(
-                                        BackendCommand::ReconstructTypeByIndex(
-                                            ResymPDBSlots::Main as usize,
-                                            type_index,
-                                            self.settings.app_settings.primitive_types_flavor,
-                                            self.settings.app_settings.print_header,
-                                            self.settings.app_settings.reconstruct_dependencies,
-                                            self.settings.app_settings.print_access_specifiers,
-                                            self.settings.app_settings.ignore_std_types,
-                                        ),
-                                    ) {
-                                        log::error!("Failed to reconstruct type: {}", err);
-                                    }
-                                }
-                                ResymAppMode::Comparing(..) => {
-                                    if let Err(err) =
-                                        self.backend.send_command(BackendCommand::DiffTypeByName(
-                                            ResymPDBSlots::Main as usize,
-                                            ResymPDBSlots::Diff as usize,
-                                            type_name.to_string(),
-                                            self.settings.app_settings.primitive_types_flavor,
-                                            self.settings.app_settings.print_header,
-                                            self.settings.app_settings.reconstruct_dependencies,
-                                            self.settings.app_settings.print_access_specifiers,
-                                            self.settings.app_settings.ignore_std_types,
-                                        ))
-                                    {
-                                        log::error!("Failed to reconstruct type diff: {}", err);
-                                    }
-                                }
-                                _ => log::error!("Invalid application state"),
-                            }
-                        };
-                        // Update the type list
-                        self.type_list.update(ui, &mut on_type_selected);
-                    }
+    fn process_ui_scale_update(&mut self, ctx: &egui::Context) {
+        // Keep track of zooming with keyboard shortcuts (e.g., Ctrl+Plus)
+        if self.applied_ui_scale.is_some_and(|applied_ui_scale| {
+            applied_ui_scale == self.settings.app_settings.ui_scale
+                && applied_ui_scale != ctx.zoom_factor()
+        }) {
+            self.settings.app_settings.ui_scale = ctx.zoom_factor();
+            self.applied_ui_scale = Some(ctx.zoom_factor());
+            return;
+        }
 
-                    LeftPanelTab::SymbolSearch => {
-                        // Callback run when the search query changes
-                        let on_query_update = |search_query: &str| {
-                            // Update filtered list if filter has changed
-                            let result = if let ResymAppMode::Comparing(..) = self.current_mode {
-                                self.backend.send_command(BackendCommand::ListSymbolsMerged(
-                                    vec![
-                                        ResymPDBSlots::Main as usize,
-                                        ResymPDBSlots::Diff as usize,
-                                    ],
-                                    search_query.to_string(),
-                                    self.settings.app_settings.search_case_insensitive,
-                                    self.settings.app_settings.search_use_regex,
-                                    self.settings.app_settings.ignore_std_types,
-                                ))
-                            } else {
-                                self.backend.send_command(BackendCommand::ListSymbols(
-                                    ResymPDBSlots::Main as usize,
-                                    search_query.to_string(),
-                                    self.settings.app_settings.search_case_insensitive,
-                                    self.settings.app_settings.search_use_regex,
-                                    self.settings.app_settings.ignore_std_types,
-                                ))
-                            };
-                            if let Err(err) = result {
-                                log::error!("Failed to update type filter value: {}", err);
-                            }
-                        };
-
-                        // Update the symbol search bar
-                        ui.label("Search");
-                        self.symbol_search.update(ui, &on_query_update);
-                        ui.separator();
-                        ui.add_space(4.0);
-
-                        // Callback run when a type is selected in the list
-                        let mut on_symbol_selected =
-                            |symbol_name: &str, symbol_index: SymbolIndex| {
-                                // Update currently selected type index
-                                self.selected_symbol_index = Some(symbol_index);
-
-                                match self.current_mode {
-                                    ResymAppMode::Browsing(..) => {
-                                        if let Err(err) = self.backend.send_command(
-                                            BackendCommand::ReconstructSymbolByIndex(
-                                                ResymPDBSlots::Main as usize,
-                                                symbol_index,
-                                                self.settings.app_settings.primitive_types_flavor,
-                                                self.settings.app_settings.print_header,
-                                                self.settings.app_settings.print_access_specifiers,
-                                            ),
-                                        ) {
-                                            log::error!("Failed to reconstruct type: {}", err);
-                                        }
-                                    }
-                                    ResymAppMode::Comparing(..) => {
-                                        if let Err(err) = self.backend.send_command(
-                                            BackendCommand::DiffSymbolByName(
-                                                ResymPDBSlots::Main as usize,
-                                                ResymPDBSlots::Diff as usize,
-                                                symbol_name.to_string(),
-                                                self.settings.app_settings.primitive_types_flavor,
-                                                self.settings.app_settings.print_header,
-                                                self.settings.app_settings.print_access_specifiers,
-                                            ),
-                                        ) {
-                                            log::error!("Failed to reconstruct type diff: {}", err);
-                                        }
-                                    }
-                                    _ => log::error!("Invalid application state"),
-                                }
-                            };
+        let ui_scale = self.settings.app_settings.ui_scale;
+        if self.applied_ui_scale == Some(ui_scale) {
+            // Scale hasn't changed
+            return;
+        }
 
-                        // Update the symbol list
-                        self.symbol_list.update(ui, &mut on_symbol_selected);
-                    }
+        // Note: the zoom factor is applied on top of the OS' scale factor
+        ctx.set_zoom_factor(ui_scale);
+        self.applied_ui_scale = Some(ui_scale);
+    }
 
-                    LeftPanelTab::ModuleBrowsing => {
-                        // Callback run when the search query changes
-                        let on_query_update = |search_query: &str| match self.current_mode {
-                            ResymAppMode::Browsing(..) | ResymAppMode::Comparing(..) => {
-                                // Request a module list update
-                                if let Err(err) =
-                                    self.backend.send_command(BackendCommand::ListModules(
-                                        ResymPDBSlots::Main as usize,
-                                        search_query.to_string(),
-                                        self.settings.app_settings.search_case_insensitive,
-                                        self.settings.app_settings.search_use_regex,
-                                    ))
-                                {
-                                    log::error!("Failed to update module list: {}", err);
-                                }
-                            }
-                            _ => {}
-                        };
-                        // Update the type search bar
-                        ui.label("Search");
-                        self.module_search.update(ui, &on_query_update);
-                        ui.separator();
-                        ui.add_space(4.0);
-
-                        // Callback run when a module is selected in the tree
-                        let on_module_selected =
-                            |module_path: &ModulePath, module_info: &ModuleInfo| match self
-                                .current_mode
-                            {
-                                ResymAppMode::Browsing(..) => {
-                                    if let Err(err) = self.backend.send_command(
-                                        BackendCommand::ReconstructModuleByIndex(
-                                            ResymPDBSlots::Main as usize,
-                                            module_info.pdb_index,
-                                            self.settings.app_settings.primitive_types_flavor,
-                                            self.settings.app_settings.print_header,
-                                            self.settings.app_settings.print_access_specifiers,
-                                        ),
-                                    ) {
-                                        log::error!("Failed to reconstruct module: {}", err);
-                                    }
-                                }
+    fn process_opaque_type_rules_update(&mut self) {
+        let opaque_types = if self.settings.app_settings.use_opaque_types {
+            self.settings.app_settings.opaque_types.clone()
+        } else {
+            String::default()
+        };
+        if self.applied_opaque_types.as_ref() == Some(&opaque_types) {
+            // Rules haven't changed
+            return;
+        }
 
-                                ResymAppMode::Comparing(..) => {
-                                    if let Err(err) =
-                                        self.backend.send_command(BackendCommand::DiffModuleByPath(
-                                            ResymPDBSlots::Main as usize,
-                                            ResymPDBSlots::Diff as usize,
-                                            module_path.to_string(),
-                                            self.settings.app_settings.primitive_types_flavor,
-                                            self.settings.app_settings.print_header,
-                                            self.settings.app_settings.print_access_specifiers,
-                                        ))
-                                    {
-                                        log::error!("Failed to reconstruct type diff: {}", err);
-                                    }
-                                }
+        match OpaqueTypeRules::parse(&opaque_types) {
+            Err(err) => log::error!("Failed to parse opaque type rules: {err}"),
+            Ok(rules) => {
+                if let Err(err) = self
+                    .backend
+                    .send_command(BackendCommand::SetOpaqueTypeRules(rules))
+                {
+                    log::error!("Failed to update opaque type rules: {err}");
+                }
+            }
+        }
+        self.applied_opaque_types = Some(opaque_types);
+    }
 
-                                _ => log::error!("Invalid application state"),
-                            };
+    fn process_primitive_type_mapping_update(&mut self) {
+        let primitive_type_mapping = &self.settings.app_settings.primitive_type_mapping;
+        if self.applied_primitive_type_mapping.as_ref() == Some(primitive_type_mapping) {
+            // Mapping hasn't changed
+            return;
+        }
 
-                        // Update the module list
-                        self.module_tree.update(ctx, ui, &on_module_selected);
-                    }
+        match PrimitiveTypeMapping::parse(primitive_type_mapping) {
+            Err(err) => log::error!("Failed to parse primitive type mapping: {err}"),
+            Ok(mapping) => {
+                if let Err(err) = self
+                    .backend
+                    .send_command(BackendCommand::SetPrimitiveTypeMapping(mapping))
+                {
+                    log::error!("Failed to update primitive type mapping: {err}");
                 }
-            });
+            }
+        }
+        self.applied_primitive_type_mapping = Some(primitive_type_mapping.clone());
     }
 
-    /// Update/render the bottom panel component and its sub-components
-    fn update_bottom_panel(&mut self, ctx: &egui::Context) {
-        egui::TopBottomPanel::bottom("bottom_panel")
-            .min_height(100.0)
-            .resizable(true)
-            .show(ctx, |ui| {
-                ui.add_space(4.0);
-                ui.vertical(|ui| {
-                    // Tab headers
-                    ui.horizontal(|ui| {
-                        ui.selectable_value(
-                            &mut self.bottom_panel_selected_tab,
-                            BottomPanelTab::Console,
-                            "Console",
-                        );
+    fn process_code_format_update(&mut self) {
+        let code_format = self.settings.app_settings.code_format();
+        if self.applied_code_format.as_ref() == Some(&code_format) {
+            // Style hasn't changed
+            return;
+        }
 
-                        if let ResymAppMode::Browsing(..) = self.current_mode {
-                            // Only display those tabs in browsing mode
-                            ui.selectable_value(
-                                &mut self.bottom_panel_selected_tab,
-                                BottomPanelTab::XRefsTo,
-                                "XRefs to",
-                            );
-                            ui.selectable_value(
-                                &mut self.bottom_panel_selected_tab,
-                                BottomPanelTab::XRefsFrom,
-                                "XRefs from",
-                            );
-                        }
-                    });
-                    ui.separator();
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::SetCodeFormat(code_format.clone()))
+        {
+            log::error!("Failed to update code style: {err}");
+        }
+        self.applied_code_format = Some(code_format);
+    }
 
-                    let mut on_type_selected = |_: &str, type_index: TypeIndex| {
-                        // Update currently selected type index
-                        self.selected_type_index = Some(type_index);
+    fn process_number_format_update(&mut self) {
+        let number_format = self.settings.app_settings.number_format();
+        if self.applied_number_format == Some(number_format) {
+            // Format hasn't changed
+            return;
+        }
 
-                        // Note: only support "Browsing" mode
-                        if let ResymAppMode::Browsing(..) = self.current_mode {
-                            if let Err(err) =
-                                self.backend
-                                    .send_command(BackendCommand::ReconstructTypeByIndex(
-                                        ResymPDBSlots::Main as usize,
-                                        type_index,
-                                        self.settings.app_settings.primitive_types_flavor,
-                                        self.settings.app_settings.print_header,
-                                        self.settings.app_settings.reconstruct_dependencies,
-                                        self.settings.app_settings.print_access_specifiers,
-                                        self.settings.app_settings.ignore_std_types,
-                                    ))
-                            {
-                                log::error!("Failed to reconstruct type: {}", err);
-                            }
-                        }
-                    };
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::SetNumberFormat(number_format))
+        {
+            log::error!("Failed to update number format: {err}");
+        }
+        self.applied_number_format = Some(number_format);
+    }
 
-                    // Tab body
-                    match self.bottom_panel_selected_tab {
-                        BottomPanelTab::Console => {
-                            // Console panel
-                            self.console.update(ui);
-                        }
-                        BottomPanelTab::XRefsTo => {
-                            // Update xref list
-                            self.xref_to_list.update(ui, &mut on_type_selected);
-                        }
-                        BottomPanelTab::XRefsFrom => {
-                            // Update xref list
-                            self.xref_from_list.update(ui, &mut on_type_selected);
-                        }
-                    }
-                });
-            });
+    fn process_header_template_update(&mut self) {
+        let header_template = &self.settings.app_settings.header_template;
+        if self.applied_header_template.as_ref() == Some(header_template) {
+            // Template hasn't changed
+            return;
+        }
+
+        if let Err(err) =
+            self.backend
+                .send_command(BackendCommand::SetHeaderTemplate(HeaderTemplate::new(
+                    header_template,
+                )))
+        {
+            log::error!("Failed to update header template: {err}");
+        }
+        self.applied_header_template = Some(header_template.clone());
     }
 
-    fn update_central_panel(&mut self, ctx: &egui::Context) {
-        egui::CentralPanel::default().show(ctx, |ui| {
-            ui.horizontal(|ui| {
-                // The central panel the region left after adding TopPanel's and SidePanel's
-                // Put the label on the left
-                ui.label(if let ResymAppMode::Comparing(..) = self.current_mode {
-                    "Differences between reconstructed type(s) - C++"
-                } else {
-                    "Reconstructed type(s) - C++"
-                });
+    fn process_member_filter_update(&mut self) {
+        let member_filter = self.settings.app_settings.member_filter();
+        if self.applied_member_filter.as_ref() == Some(&member_filter) {
+            // Filter hasn't changed
+            return;
+        }
 
-                // Start displaying buttons from the right
-                #[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
-                ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
-                    // Fetures only available in "Browsing" mode
-                    if let ResymAppMode::Browsing(..) = self.current_mode {
-                        // Save button
-                        // Note: not available on wasm32
-                        #[cfg(not(target_arch = "wasm32"))]
-                        if ui.button("💾  Save (Ctrl+S)").clicked() {
-                            self.start_save_reconstruted_content();
-                        }
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::SetMemberFilter(member_filter.clone()))
+        {
+            log::error!("Failed to update member filter: {err}");
+        }
+        self.applied_member_filter = Some(member_filter);
+    }
 
-                        // Cross-references button
-                        if let Some(selected_type_index) = self.selected_type_index {
-                            if ui.button("🔍  Find XRefs to (Alt+X)").clicked() {
-                                self.list_xrefs_for_type(selected_type_index);
-                            }
-                        }
-                    }
-                });
-            });
-            ui.separator();
+    fn process_static_member_rvas_update(&mut self) {
+        let print_static_member_rvas = self.settings.app_settings.print_static_member_rvas;
+        if self.applied_print_static_member_rvas == Some(print_static_member_rvas) {
+            // Setting hasn't changed
+            return;
+        }
 
-            // Update the code view component
-            self.code_view
-                .update(&self.settings.app_settings, &self.current_mode, ui);
-        });
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::SetPrintStaticMemberRvas(
+                print_static_member_rvas,
+            ))
+        {
+            log::error!("Failed to update static member annotations: {err}");
+        }
+        self.applied_print_static_member_rvas = Some(print_static_member_rvas);
     }
 
-    fn consume_keyboard_shortcuts(&mut self, ui: &mut egui::Ui) {
-        /// Keyboard shortcut for opening files
-        const CTRL_O_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
-            modifiers: egui::Modifiers::CTRL,
-            logical_key: egui::Key::O,
-        };
-        ui.input_mut(|input_state| {
-            if input_state.consume_shortcut(&CTRL_O_SHORTCUT) {
-                self.start_open_pdb_file(ResymPDBSlots::Main as usize);
-            }
-        });
+    fn process_doxygen_comments_update(&mut self) {
+        let doxygen_comments = self.settings.app_settings.doxygen_comments;
+        if self.applied_doxygen_comments == Some(doxygen_comments) {
+            // Setting hasn't changed
+            return;
+        }
 
-        // Keyboard shortcut for opening URLs
-        #[cfg(feature = "http")]
-        const CTRL_L_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
-            modifiers: egui::Modifiers::CTRL,
-            logical_key: egui::Key::L,
-        };
-        #[cfg(feature = "http")]
-        ui.input_mut(|input_state| {
-            if input_state.consume_shortcut(&CTRL_L_SHORTCUT) {
-                self.open_url.open(ResymPDBSlots::Main);
-            }
-        });
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::SetDoxygenComments(doxygen_comments))
+        {
+            log::error!("Failed to update Doxygen comments: {err}");
+        }
+        self.applied_doxygen_comments = Some(doxygen_comments);
+    }
 
-        // Keyboard shortcut for finding cross-references
-        const ALT_X_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
-            modifiers: egui::Modifiers::ALT,
-            logical_key: egui::Key::X,
+    fn process_flag_enum_comments_update(&mut self) {
+        let flag_enum_comments = self.settings.app_settings.flag_enum_comments;
+        if self.applied_flag_enum_comments == Some(flag_enum_comments) {
+            // Setting hasn't changed
+            return;
+        }
+
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::SetFlagEnumComments(flag_enum_comments))
+        {
+            log::error!("Failed to update flag enum comments: {err}");
+        }
+        self.applied_flag_enum_comments = Some(flag_enum_comments);
+    }
+
+    fn process_enum_value_order_update(&mut self) {
+        let enum_value_order = self.settings.app_settings.enum_value_order;
+        if self.applied_enum_value_order == Some(enum_value_order) {
+            // Setting hasn't changed
+            return;
+        }
+
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::SetEnumValueOrder(enum_value_order))
+        {
+            log::error!("Failed to update enumerators order: {err}");
+        }
+        self.applied_enum_value_order = Some(enum_value_order);
+    }
+
+    fn process_forward_declare_dependencies_update(&mut self) {
+        let forward_declare_dependencies = self.settings.app_settings.forward_declare_dependencies;
+        if self.applied_forward_declare_dependencies == Some(forward_declare_dependencies) {
+            // Setting hasn't changed
+            return;
+        }
+
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::SetForwardDeclareDependencies(
+                forward_declare_dependencies,
+            ))
+        {
+            log::error!("Failed to update dependency forward declarations: {err}");
+        }
+        self.applied_forward_declare_dependencies = Some(forward_declare_dependencies);
+    }
+
+    fn process_image_base_update(&mut self) {
+        let image_base = self.settings.app_settings.image_base();
+        if self.applied_image_base == Some(image_base) {
+            // Setting hasn't changed
+            return;
+        }
+
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::SetImageBase(image_base))
+        {
+            log::error!("Failed to update image base: {err}");
+        }
+        self.applied_image_base = Some(image_base);
+    }
+
+    fn process_load_mode_update(&mut self) {
+        let load_mode = self.settings.app_settings.load_mode();
+        if self.applied_load_mode == Some(load_mode) {
+            // Setting hasn't changed
+            return;
+        }
+
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::SetLoadMode(load_mode))
+        {
+            log::error!("Failed to update load mode: {err}");
+        }
+        self.applied_load_mode = Some(load_mode);
+    }
+
+    fn process_renames_update(&mut self) {
+        let renames = &self.settings.app_settings.renames;
+        if self.applied_renames.as_ref() == Some(renames) {
+            // Renames haven't changed
+            return;
+        }
+
+        // Note: invalid renames are reported in the "Rename" window
+        let rename_maps: HashMap<String, RenameMap> = renames
+            .iter()
+            .filter_map(|(guid, renames)| Some((guid.clone(), RenameMap::parse(renames).ok()?)))
+            .collect();
+        self.main_pdb_rename_map = self
+            .main_pdb_guid
+            .as_ref()
+            .and_then(|guid| rename_maps.get(guid).cloned())
+            .unwrap_or_default();
+        self.update_type_list();
+        self.update_bookmark_list();
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::SetRenameMaps(rename_maps))
+        {
+            log::error!("Failed to update renames: {err}");
+        }
+        self.applied_renames = Some(renames.clone());
+    }
+
+    fn process_type_notes_update(&mut self) {
+        let type_notes = if self.settings.app_settings.emit_type_notes {
+            self.main_pdb_project.type_notes.clone()
+        } else {
+            TypeNotes::default()
         };
-        ui.input_mut(|input_state| {
-            if input_state.consume_shortcut(&ALT_X_SHORTCUT) {
-                if let Some(selected_type_index) = self.selected_type_index {
-                    self.list_xrefs_for_type(selected_type_index);
-                }
-            }
-        });
+        if self.applied_type_notes.as_ref() == Some(&type_notes) {
+            // Notes haven't changed
+            return;
+        }
 
-        // Keyboard shortcut for saving reconstructed content
-        #[cfg(not(target_arch = "wasm32"))]
-        const CTRL_S_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
-            modifiers: egui::Modifiers::CTRL,
-            logical_key: egui::Key::S,
+        if let Err(err) = self.backend.send_command(BackendCommand::SetTypeNotes(
+            ResymPDBSlots::Main as usize,
+            type_notes.clone(),
+        )) {
+            log::error!("Failed to update type notes: {err}");
+        }
+        self.applied_type_notes = Some(type_notes);
+    }
+
+    /// Options used to list symbols without any search filter, from the
+    /// current settings and the symbol kind filter
+    fn symbol_list_options(&self) -> SymbolListOptions {
+        SymbolListOptions {
+            case_insensitive_search: false,
+            use_regex: false,
+            ignore_std_types: self.settings.app_settings.ignore_std_types,
+            undecorate_names: self.settings.app_settings.search_undecorate_symbols,
+            kind_filter: self.symbol_kind_filter.kind_filter,
+        }
+    }
+
+    /// Options used to diff types, from the current settings
+    fn diff_options(&self) -> DiffOptions {
+        let app_settings = &self.settings.app_settings;
+        DiffOptions {
+            print_header: app_settings.print_header,
+            reconstruct_dependencies: self.view_settings.reconstruct_dependencies,
+            print_access_specifiers: self.view_settings.print_access_specifiers,
+            ignore_std_types: app_settings.ignore_std_types,
+            ignore_comments: app_settings.diff_ignore_comments,
+            match_unique_names: app_settings.diff_match_unique_names,
+            ignore_offset_only_changes: app_settings.diff_ignore_offset_only_changes,
+        }
+    }
+
+    /// Original name of the selected type, if it's part of the type list
+    fn selected_type_name(&self) -> Option<&str> {
+        let selected_type_index = self.selected_type_index?;
+        self.filtered_types
+            .iter()
+            .find(|(_, type_index)| *type_index == selected_type_index)
+            .map(|(type_name, _)| type_name.as_str())
+    }
+
+    /// Number of types matching the current search query (e.g.,
+    /// "1,234 / 567,890 types")
+    fn filtered_type_count(&self) -> String {
+        let filtered_type_count = format_count(self.filtered_types.len());
+        match self.current_mode {
+            // Note: merged type lists have no total
+            ResymAppMode::Browsing(..) if !self.type_infos.is_empty() => format!(
+                "{filtered_type_count} / {} types",
+                format_count(self.type_infos.len())
+            ),
+            _ => format!("{filtered_type_count} types"),
+        }
+    }
+
+    /// Update the type list component, displaying user-defined type names
+    fn update_type_list(&mut self) {
+        let type_list = self
+            .filtered_types
+            .iter()
+            .map(|(type_name, type_index)| {
+                (
+                    self.main_pdb_rename_map.type_name(type_name).to_string(),
+                    *type_index,
+                )
+            })
+            .collect();
+        self.type_list.update_type_list(type_list, &self.type_infos);
+    }
+
+    /// Update the bookmark list component, displaying user-defined type names
+    fn update_bookmark_list(&mut self) {
+        let bookmark_list = self
+            .main_pdb_project
+            .bookmarks
+            .iter()
+            .map(|bookmark| {
+                (
+                    self.main_pdb_rename_map
+                        .type_name(&bookmark.type_name)
+                        .to_string(),
+                    bookmark.type_index,
+                )
+            })
+            .collect();
+        self.bookmark_list.update_index_list(bookmark_list);
+    }
+
+    /// Bookmark the selected type, or remove its bookmark if it's already
+    /// bookmarked
+    fn toggle_selected_type_bookmark(&mut self) {
+        let (Some(pdb_guid), Some(type_index)) =
+            (self.main_pdb_guid.as_ref(), self.selected_type_index)
+        else {
+            return;
         };
-        // Ctrl+S shortcut handling
-        // Note: not available on wasm32
-        #[cfg(not(target_arch = "wasm32"))]
-        ui.input_mut(|input_state| {
-            if input_state.consume_shortcut(&CTRL_S_SHORTCUT) {
-                self.start_save_reconstruted_content();
-            }
+        let Some(type_name) = self.selected_type_name().map(str::to_string) else {
+            return;
+        };
+
+        self.main_pdb_project
+            .toggle_bookmark(&type_name, type_index);
+        if let Err(err) = self.main_pdb_project.save(pdb_guid) {
+            log::error!("Failed to save bookmarks: {err}");
+        }
+        self.update_bookmark_list();
+    }
+
+    fn update_top_panel(&mut self, ctx: &egui::Context) {
+        egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
+            // Process keyboard shortcuts, if any
+            self.consume_keyboard_shortcuts(ui);
+
+            // The top panel is often a good place for a menu bar
+            self.update_menu_bar(ui);
         });
     }
 
-    fn process_ui_commands(&mut self) {
-        while let Ok(cmd) = self.frontend_controller.rx_ui.try_recv() {
-            match cmd {
-                FrontendCommand::LoadPDBResult(result) => match result {
-                    Err(err) => {
-                        log::error!("Failed to load PDB file: {}", err);
+    fn update_dock_area(&mut self, ctx: &egui::Context) {
+        // Note: the dock state is moved out of the settings while panels are
+        // displayed, as they need to borrow the whole application
+        let mut dock_state = std::mem::replace(
+            &mut self.settings.app_settings.dock_state,
+            DockState::new(vec![]),
+        );
+        DockArea::new(&mut dock_state)
+            .style(egui_dock::Style::from_egui(ctx.style().as_ref()))
+            .show(ctx, &mut ResymTabViewer { app: self });
+        self.settings.app_settings.dock_state = dock_state;
+    }
+
+    /// Show the given panel if it's hidden, or hide it otherwise
+    fn toggle_dock_tab(&mut self, tab: DockTab) {
+        let dock_state = &mut self.settings.app_settings.dock_state;
+        if let Some(tab_location) = dock_state.find_tab(&tab) {
+            dock_state.remove_tab(tab_location);
+        } else {
+            dock_state.push_to_focused_leaf(tab);
+        }
+    }
+
+    fn browser_panel_ui(&mut self, ui: &mut egui::Ui) {
+        let ctx = ui.ctx().clone();
+        ui.add_space(2.0);
+        ui.horizontal(|ui| {
+            ui.selectable_value(
+                &mut self.left_panel_selected_tab,
+                LeftPanelTab::TypeSearch,
+                tr("Search types"),
+            );
+            ui.selectable_value(
+                &mut self.left_panel_selected_tab,
+                LeftPanelTab::SymbolSearch,
+                tr("Search symbols"),
+            );
+            ui.selectable_value(
+                &mut self.left_panel_selected_tab,
+                LeftPanelTab::ModuleBrowsing,
+                tr("Browse modules"),
+            );
+            ui.selectable_value(
+                &mut self.left_panel_selected_tab,
+                LeftPanelTab::SourceFileBrowsing,
+                tr("Browse source files"),
+            );
+            ui.selectable_value(
+                &mut self.left_panel_selected_tab,
+                LeftPanelTab::StreamBrowsing,
+                tr("Browse streams"),
+            );
+            ui.selectable_value(
+                &mut self.left_panel_selected_tab,
+                LeftPanelTab::Bookmarks,
+                tr("Bookmarks"),
+            );
+        });
+        ui.separator();
+
+        match self.left_panel_selected_tab {
+            LeftPanelTab::TypeSearch => {
+                // Callback run when the search query changes
+                let on_query_update = |search_query: &str, search_options: &SearchOptions| {
+                    let (search_filter, use_regex) = search_options.backend_filter(search_query);
+                    // Update filtered list if filter has changed
+                    let result = if let ResymAppMode::Comparing(..) = self.current_mode {
+                        self.backend.send_command(merged_type_list_command(
+                            self.changed_types_only,
+                            search_filter,
+                            search_options.case_insensitive,
+                            use_regex,
+                            self.diff_options(),
+                        ))
+                    } else {
+                        self.backend.send_command(BackendCommand::ListTypes(
+                            ResymPDBSlots::Main as usize,
+                            search_filter,
+                            search_options.case_insensitive,
+                            use_regex,
+                            self.settings.app_settings.ignore_std_types,
+                        ))
+                    };
+                    if let Err(err) = result {
+                        log::error!("Failed to update type filter value: {}", err);
                     }
-                    Ok(pdb_slot) => {
-                        if pdb_slot == ResymPDBSlots::Main as usize {
-                            // Unload the PDB used for diffing if one is loaded
-                            if let ResymAppMode::Comparing(..) = self.current_mode {
-                                if let Err(err) = self.backend.send_command(
-                                    BackendCommand::UnloadPDB(ResymPDBSlots::Diff as usize),
-                                ) {
-                                    log::error!(
-                                        "Failed to unload the PDB used for comparison: {}",
-                                        err
-                                    );
-                                }
+                };
+
+                // Update the type search bar, along with the number
+                // of matching types
+                let type_count = self.filtered_type_count();
+                ui.horizontal(|ui| {
+                    ui.label(tr("Search"));
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        ui.label(
+                            egui::RichText::new(type_count)
+                                .color(ui.style().visuals.widgets.inactive.text_color()),
+                        );
+                    });
+                });
+                let mut search_options = self.settings.app_settings.search_options();
+                self.type_search
+                    .update(ui, &mut search_options, &on_query_update);
+                self.settings
+                    .app_settings
+                    .set_search_options(search_options);
+                if let ResymAppMode::Comparing(..) = self.current_mode {
+                    if ui
+                        .checkbox(&mut self.changed_types_only, tr("Changed types only"))
+                        .on_hover_text(tr(
+                            "Only list types whose reconstruction differs between the two PDBs",
+                        ))
+                        .changed()
+                    {
+                        let (search_filter, use_regex) =
+                            search_options.backend_filter(self.type_search.search_filter());
+                        if let Err(err) = self.backend.send_command(merged_type_list_command(
+                            self.changed_types_only,
+                            search_filter,
+                            search_options.case_insensitive,
+                            use_regex,
+                            self.diff_options(),
+                        )) {
+                            log::error!("Failed to update type filter value: {}", err);
+                        }
+                    }
+                }
+                ui.separator();
+                ui.add_space(4.0);
+
+                // Callback run when a type is selected in the list
+                let diff_options = self.diff_options();
+                let mut on_type_selected = |type_name: &str, type_index: TypeIndex| {
+                    // Update currently selected type index
+                    self.selected_type_index = Some(type_index);
+
+                    match self.current_mode {
+                        ResymAppMode::Browsing(..) => {
+                            if let Err(err) =
+                                self.backend
+                                    .send_command(BackendCommand::ReconstructTypeByIndex(
+                                        ResymPDBSlots::Main as usize,
+                                        type_index,
+                                        self.view_settings.primitive_types_flavor,
+                                        self.settings.app_settings.print_header,
+                                        self.view_settings.reconstruct_dependencies,
+                                        self.view_settings.print_access_specifiers,
+                                        self.settings.app_settings.ignore_std_types,
+                                    ))
+                            {
+                                log::error!("Failed to reconstruct type: {}", err);
+                            }
+                        }
+                        ResymAppMode::Comparing(..) => {
+                            if let Err(err) =
+                                self.backend.send_command(BackendCommand::DiffTypeByName(
+                                    ResymPDBSlots::Main as usize,
+                                    ResymPDBSlots::Diff as usize,
+                                    type_name.to_string(),
+                                    self.view_settings.primitive_types_flavor,
+                                    diff_options,
+                                ))
+                            {
+                                log::error!("Failed to reconstruct type diff: {}", err);
                             }
+                        }
+                        _ => log::error!("Invalid application state"),
+                    }
+                };
+                // Update the type list
+                self.type_list.update(ui, &mut on_type_selected);
+            }
 
-                            // Reset current mode
-                            self.current_mode =
-                                ResymAppMode::Browsing(String::default(), 0, String::default());
-                            // Reset selected type
-                            self.selected_type_index = None;
-                            // Reset xref lists
-                            self.xref_to_list.update_index_list(vec![]);
-                            self.xref_from_list.update_index_list(vec![]);
+            LeftPanelTab::SymbolSearch => {
+                ui.label(tr("Search"));
+                // Changing the kinds of listed symbols also updates the results
+                let kind_filter_changed = self.symbol_kind_filter.update(ui);
+
+                // Callback run when the search query changes
+                let symbol_list_options = self.symbol_list_options();
+                let on_query_update = |search_query: &str, search_options: &SearchOptions| {
+                    let (search_filter, use_regex) = search_options.backend_filter(search_query);
+                    let list_options = SymbolListOptions {
+                        case_insensitive_search: search_options.case_insensitive,
+                        use_regex,
+                        ..symbol_list_options
+                    };
+                    // Update filtered list if filter has changed
+                    let result = if let ResymAppMode::Comparing(..) = self.current_mode {
+                        self.backend.send_command(BackendCommand::ListSymbolsMerged(
+                            vec![ResymPDBSlots::Main as usize, ResymPDBSlots::Diff as usize],
+                            search_filter,
+                            list_options,
+                        ))
+                    } else {
+                        self.backend.send_command(BackendCommand::ListSymbols(
+                            ResymPDBSlots::Main as usize,
+                            search_filter,
+                            list_options,
+                        ))
+                    };
+                    if let Err(err) = result {
+                        log::error!("Failed to update type filter value: {}", err);
+                    }
+                };
+
+                // Update the symbol search bar
+                let mut search_options = self.settings.app_settings.search_options();
+                self.symbol_search
+                    .update(ui, &mut search_options, &on_query_update);
+                if kind_filter_changed {
+                    on_query_update(self.symbol_search.search_filter(), &search_options);
+                }
+                self.settings
+                    .app_settings
+                    .set_search_options(search_options);
+                ui.separator();
+                ui.add_space(4.0);
 
-                            // Request a type list update
-                            if let Err(err) = self.backend.send_command(BackendCommand::ListTypes(
-                                ResymPDBSlots::Main as usize,
-                                String::default(),
-                                false,
-                                false,
-                                self.settings.app_settings.ignore_std_types,
-                            )) {
-                                log::error!("Failed to update type filter value: {}", err);
+                // Callback run when a type is selected in the list
+                let mut on_symbol_selected = |symbol_name: &str, symbol_index: SymbolIndex| {
+                    // Update currently selected type index
+                    self.selected_symbol_index = Some(symbol_index);
+
+                    match self.current_mode {
+                        ResymAppMode::Browsing(..) => {
+                            if let Err(err) =
+                                self.backend
+                                    .send_command(BackendCommand::ReconstructSymbolByIndex(
+                                        ResymPDBSlots::Main as usize,
+                                        symbol_index,
+                                        self.view_settings.primitive_types_flavor,
+                                        self.settings.app_settings.print_header,
+                                        self.view_settings.print_access_specifiers,
+                                    ))
+                            {
+                                log::error!("Failed to reconstruct type: {}", err);
                             }
-                            // Request a symbol list update
+                        }
+                        ResymAppMode::Comparing(..) => {
                             if let Err(err) =
-                                self.backend.send_command(BackendCommand::ListSymbols(
+                                self.backend.send_command(BackendCommand::DiffSymbolByName(
                                     ResymPDBSlots::Main as usize,
-                                    String::default(),
-                                    false,
-                                    false,
-                                    self.settings.app_settings.ignore_std_types,
+                                    ResymPDBSlots::Diff as usize,
+                                    symbol_name.to_string(),
+                                    self.view_settings.primitive_types_flavor,
+                                    self.settings.app_settings.print_header,
+                                    self.view_settings.print_access_specifiers,
+                                    self.settings.app_settings.diff_ignore_comments,
                                 ))
                             {
-                                log::error!("Failed to update type filter value: {}", err);
+                                log::error!("Failed to reconstruct type diff: {}", err);
                             }
+                        }
+                        _ => log::error!("Invalid application state"),
+                    }
+                };
+
+                // Update the symbol list
+                self.symbol_list.update(ui, &mut on_symbol_selected);
+            }
+
+            LeftPanelTab::ModuleBrowsing => {
+                // Callback run when the search query changes
+                let on_query_update = |search_query: &str, search_options: &SearchOptions| {
+                    let (search_filter, use_regex) = search_options.backend_filter(search_query);
+                    match self.current_mode {
+                        ResymAppMode::Browsing(..) | ResymAppMode::Comparing(..) => {
                             // Request a module list update
                             if let Err(err) =
                                 self.backend.send_command(BackendCommand::ListModules(
                                     ResymPDBSlots::Main as usize,
-                                    String::default(),
-                                    false,
-                                    false,
+                                    search_filter,
+                                    search_options.case_insensitive,
+                                    use_regex,
                                 ))
                             {
                                 log::error!("Failed to update module list: {}", err);
                             }
-                        } else if pdb_slot == ResymPDBSlots::Diff as usize {
-                            // Reset current mode
-                            self.current_mode = ResymAppMode::Comparing(
-                                String::default(),
-                                String::default(),
-                                0,
-                                vec![],
-                                String::default(),
-                            );
-                            // Reset selected type
-                            self.selected_type_index = None;
-                            // Reset xref lists
-                            self.xref_to_list.update_index_list(vec![]);
-                            self.xref_from_list.update_index_list(vec![]);
+                        }
+                        _ => {}
+                    }
+                };
+                // Update the type search bar
+                ui.label(tr("Search"));
+                let mut search_options = self.settings.app_settings.search_options();
+                self.module_search
+                    .update(ui, &mut search_options, &on_query_update);
+                self.settings
+                    .app_settings
+                    .set_search_options(search_options);
+                ui.separator();
+                ui.add_space(4.0);
 
-                            // Request a type list update
+                // Callback run when a module is selected in the tree
+                let on_module_selected =
+                    |module_path: &ModulePath, module_info: &ModuleInfo| match self.current_mode {
+                        ResymAppMode::Browsing(..) => {
                             if let Err(err) =
-                                self.backend.send_command(BackendCommand::ListTypesMerged(
-                                    vec![
+                                self.backend
+                                    .send_command(BackendCommand::ReconstructModuleByIndex(
                                         ResymPDBSlots::Main as usize,
-                                        ResymPDBSlots::Diff as usize,
-                                    ],
-                                    String::default(),
-                                    false,
-                                    false,
-                                    self.settings.app_settings.ignore_std_types,
+                                        module_info.pdb_index,
+                                        self.view_settings.primitive_types_flavor,
+                                        self.settings.app_settings.print_header,
+                                        self.view_settings.print_access_specifiers,
+                                    ))
+                            {
+                                log::error!("Failed to reconstruct module: {}", err);
+                            }
+                        }
+
+                        ResymAppMode::Comparing(..) => {
+                            if let Err(err) =
+                                self.backend.send_command(BackendCommand::DiffModuleByPath(
+                                    ResymPDBSlots::Main as usize,
+                                    ResymPDBSlots::Diff as usize,
+                                    module_path.to_string(),
+                                    self.view_settings.primitive_types_flavor,
+                                    self.settings.app_settings.print_header,
+                                    self.view_settings.print_access_specifiers,
+                                    self.settings.app_settings.diff_ignore_comments,
                                 ))
                             {
-                                log::error!("Failed to update type filter value: {}", err);
+                                log::error!("Failed to reconstruct type diff: {}", err);
                             }
                         }
-                    }
-                },
 
-                FrontendCommand::LoadURLResult(result) => match result {
-                    Err(err) => {
-                        log::error!("Failed to load URL: {}", err);
-                    }
-                    Ok((pdb_slot, file_name, data)) => {
-                        if let Err(err) = self
-                            .backend
-                            .send_command(BackendCommand::LoadPDBFromVec(pdb_slot, file_name, data))
+                        _ => log::error!("Invalid application state"),
+                    };
+
+                // Update the module list
+                self.module_tree.update(&ctx, ui, &on_module_selected);
+            }
+
+            LeftPanelTab::SourceFileBrowsing => {
+                // Callback run when the search query changes
+                let on_query_update = |search_query: &str, search_options: &SearchOptions| {
+                    let (search_filter, use_regex) = search_options.backend_filter(search_query);
+                    match self.current_mode {
+                        ResymAppMode::Browsing(..) | ResymAppMode::Comparing(..) => {
+                            // Request a source file list update
+                            if let Err(err) =
+                                self.backend.send_command(BackendCommand::ListSourceFiles(
+                                    ResymPDBSlots::Main as usize,
+                                    search_filter,
+                                    search_options.case_insensitive,
+                                    use_regex,
+                                ))
+                            {
+                                log::error!("Failed to update source file list: {}", err);
+                            }
+                        }
+                        _ => {}
+                    }
+                };
+                // Update the source file search bar
+                ui.label(tr("Search"));
+                let mut search_options = self.settings.app_settings.search_options();
+                self.source_file_search
+                    .update(ui, &mut search_options, &on_query_update);
+                self.settings
+                    .app_settings
+                    .set_search_options(search_options);
+                ui.separator();
+                ui.add_space(4.0);
+
+                // Callback run when a source file is selected in the list
+                let mut on_source_file_selected = |file_path: &str, _: ModuleIndex| {
+                    // Note: only support "Browsing" mode
+                    if let ResymAppMode::Browsing(..) = self.current_mode {
+                        if let Err(err) =
+                            self.backend
+                                .send_command(BackendCommand::ReconstructSourceFileByPath(
+                                    ResymPDBSlots::Main as usize,
+                                    file_path.to_string(),
+                                    self.view_settings.primitive_types_flavor,
+                                    self.settings.app_settings.print_header,
+                                    self.view_settings.print_access_specifiers,
+                                ))
                         {
-                            log::error!("Failed to load the PDB file: {err}");
+                            log::error!("Failed to reconstruct source file: {}", err);
                         }
                     }
-                },
+                };
 
-                FrontendCommand::ReconstructTypeResult(type_reconstruction_result) => {
-                    match type_reconstruction_result {
-                        Err(err) => {
-                            let error_msg = format!("Failed to reconstruct type: {}", err);
-                            log::error!("{}", &error_msg);
+                // Update the source file list
+                self.source_file_list
+                    .update(ui, &mut on_source_file_selected);
+            }
 
-                            // Show an empty "reconstruted" view
-                            self.current_mode =
-                                ResymAppMode::Browsing(Default::default(), 0, error_msg);
+            LeftPanelTab::StreamBrowsing => {
+                // Callback run when a stream is selected in the list
+                let mut on_stream_selected = |_: &str, stream_index: StreamIndex| {
+                    // Note: only support "Browsing" mode
+                    if let ResymAppMode::Browsing(..) = self.current_mode {
+                        if let Err(err) = self.backend.send_command(BackendCommand::DumpStream(
+                            ResymPDBSlots::Main as usize,
+                            stream_index,
+                        )) {
+                            log::error!("Failed to dump stream: {}", err);
                         }
-                        Ok((reconstructed_type, xrefs_from)) => {
-                            let last_line_number = 1 + reconstructed_type.lines().count();
-                            let line_numbers =
-                                (1..last_line_number).fold(String::default(), |mut acc, e| {
-                                    let _r = writeln!(&mut acc, "{e}");
-                                    acc
-                                });
-                            self.current_mode = ResymAppMode::Browsing(
-                                line_numbers,
-                                last_line_number,
-                                reconstructed_type,
-                            );
+                    }
+                };
 
-                            // Update xref lists
-                            self.xref_to_list.update_index_list(vec![]);
-                            self.xref_from_list.update_index_list(xrefs_from);
-                            // Switch to the "xref from" tab
-                            self.bottom_panel_selected_tab = BottomPanelTab::XRefsFrom;
+                // Update the stream list
+                self.stream_list.update(ui, &mut on_stream_selected);
+            }
+
+            LeftPanelTab::Bookmarks => {
+                // Callback run when a bookmarked type is selected in the list
+                let mut on_type_selected = |_: &str, type_index: TypeIndex| {
+                    // Update currently selected type index
+                    self.selected_type_index = Some(type_index);
+
+                    // Note: only support "Browsing" mode
+                    if let ResymAppMode::Browsing(..) = self.current_mode {
+                        if let Err(err) =
+                            self.backend
+                                .send_command(BackendCommand::ReconstructTypeByIndex(
+                                    ResymPDBSlots::Main as usize,
+                                    type_index,
+                                    self.view_settings.primitive_types_flavor,
+                                    self.settings.app_settings.print_header,
+                                    self.view_settings.reconstruct_dependencies,
+                                    self.view_settings.print_access_specifiers,
+                                    self.settings.app_settings.ignore_std_types,
+                                ))
+                        {
+                            log::error!("Failed to reconstruct type: {}", err);
                         }
                     }
+                };
+
+                // Update the bookmark list
+                self.bookmark_list.update(ui, &mut on_type_selected);
+            }
+        }
+    }
+
+    /// Update/render the bottom panel component and its sub-components
+    fn update_status_bar(&mut self, ctx: &egui::Context) {
+        egui::TopBottomPanel::bottom("status_bar").show(ctx, |ui| {
+            let cancel_requested = self.status_bar.update(
+                ui,
+                &self.filtered_type_count(),
+                self.backend.pending_command_count(),
+            );
+            if cancel_requested {
+                self.backend.cancel_operations();
+            }
+        });
+    }
+
+    fn console_panel_ui(&mut self, ui: &mut egui::Ui) {
+        ui.add_space(4.0);
+        ui.vertical(|ui| {
+            // Tab headers
+            ui.horizontal(|ui| {
+                ui.selectable_value(
+                    &mut self.bottom_panel_selected_tab,
+                    BottomPanelTab::Console,
+                    tr("Console"),
+                );
+
+                if let ResymAppMode::Browsing(..) = self.current_mode {
+                    // Only display those tabs in browsing mode
+                    ui.selectable_value(
+                        &mut self.bottom_panel_selected_tab,
+                        BottomPanelTab::XRefsTo,
+                        tr("XRefs to"),
+                    );
+                    ui.selectable_value(
+                        &mut self.bottom_panel_selected_tab,
+                        BottomPanelTab::XRefsFrom,
+                        tr("XRefs from"),
+                    );
+                    ui.selectable_value(
+                        &mut self.bottom_panel_selected_tab,
+                        BottomPanelTab::ReverseDependencies,
+                        tr("Reverse dependencies"),
+                    );
                 }
+            });
+            ui.separator();
 
-                FrontendCommand::ListModulesResult(module_list_result) => {
-                    match module_list_result {
-                        Err(err) => {
-                            log::error!("Failed to retrieve module list: {}", err);
-                        }
-                        Ok(module_list) => {
-                            self.module_tree.set_module_list(module_list);
-                        }
+            let mut on_type_selected = |_: &str, type_index: TypeIndex| {
+                // Update currently selected type index
+                self.selected_type_index = Some(type_index);
+
+                // Note: only support "Browsing" mode
+                if let ResymAppMode::Browsing(..) = self.current_mode {
+                    if let Err(err) =
+                        self.backend
+                            .send_command(BackendCommand::ReconstructTypeByIndex(
+                                ResymPDBSlots::Main as usize,
+                                type_index,
+                                self.view_settings.primitive_types_flavor,
+                                self.settings.app_settings.print_header,
+                                self.view_settings.reconstruct_dependencies,
+                                self.view_settings.print_access_specifiers,
+                                self.settings.app_settings.ignore_std_types,
+                            ))
+                    {
+                        log::error!("Failed to reconstruct type: {}", err);
                     }
                 }
+            };
+
+            // Tab body
+            match self.bottom_panel_selected_tab {
+                BottomPanelTab::Console => {
+                    // Console panel
+                    self.console
+                        .update(ui, self.settings.app_settings.console_font_size);
+                }
+                BottomPanelTab::XRefsTo => {
+                    // Update xref list
+                    self.xref_to_list.update(ui, &mut on_type_selected);
+                }
+                BottomPanelTab::XRefsFrom => {
+                    // Update xref list
+                    self.xref_from_list.update(ui, &mut on_type_selected);
+                }
+                BottomPanelTab::ReverseDependencies => {
+                    // Update reverse dependency list
+                    self.reverse_dependency_list
+                        .update(ui, &mut on_type_selected);
+                }
+            }
+        });
+    }
 
-                FrontendCommand::ReconstructSymbolResult(result) => {
-                    match result {
-                        Err(err) => {
-                            let error_msg = format!("Failed to reconstruct symbol: {}", err);
-                            log::error!("{}", &error_msg);
+    /// Update/render the type notes of the selected type, in "Browsing" mode only
+    fn type_notes_panel_ui(&mut self, ui: &mut egui::Ui) {
+        if !self.settings.app_settings.show_type_notes {
+            ui.label(tr("Type notes are disabled in the settings"));
+            return;
+        }
+        if !matches!(self.current_mode, ResymAppMode::Browsing(..)) {
+            return;
+        }
 
-                            // Show an empty "reconstruted" view
-                            self.current_mode =
-                                ResymAppMode::Browsing(Default::default(), 0, error_msg);
-                        }
-                        Ok(reconstructed_symbol) => {
-                            let last_line_number = 1 + reconstructed_symbol.lines().count();
-                            let line_numbers =
-                                (1..last_line_number).fold(String::default(), |mut acc, e| {
-                                    let _r = writeln!(&mut acc, "{e}");
-                                    acc
-                                });
-                            self.current_mode = ResymAppMode::Browsing(
-                                line_numbers,
-                                last_line_number,
-                                reconstructed_symbol,
-                            );
-                        }
-                    }
+        ui.add_space(2.0);
+        // Notes are stored by PDB GUID, which has to be known first
+        let type_name = self
+            .main_pdb_guid
+            .as_ref()
+            .and(self.selected_type_name())
+            .map(str::to_string);
+        if self.type_notes.update(
+            ui,
+            type_name.as_deref(),
+            &mut self.main_pdb_project.type_notes,
+        ) {
+            if let Some(pdb_guid) = &self.main_pdb_guid {
+                if let Err(err) = self.main_pdb_project.save(pdb_guid) {
+                    log::error!("Failed to save type notes: {err}");
                 }
+            }
+        }
+    }
 
-                FrontendCommand::ReconstructModuleResult(module_reconstruction_result) => {
-                    match module_reconstruction_result {
-                        Err(err) => {
-                            let error_msg = format!("Failed to reconstruct module: {}", err);
-                            log::error!("{}", &error_msg);
+    fn code_panel_ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            // Put the label on the left
+            ui.label(if let ResymAppMode::Comparing(..) = self.current_mode {
+                tr("Differences between reconstructed type(s) - C++")
+            } else {
+                tr("Reconstructed type(s) - C++")
+            });
 
-                            // Show an empty "reconstruted" view
-                            self.current_mode =
-                                ResymAppMode::Browsing(Default::default(), 0, error_msg);
+            // Start displaying buttons from the right
+            #[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                // Soft-wrap toggle
+                if !matches!(self.current_mode, ResymAppMode::Idle) {
+                    ui.toggle_value(
+                        &mut self.settings.app_settings.wrap_code_view_lines,
+                        tr("↩  Wrap lines"),
+                    );
+                }
+
+                // Features only available in "Browsing" mode
+                if let ResymAppMode::Browsing(..) = self.current_mode {
+                    // Save button
+                    // Note: copies content to the clipboard on wasm32
+                    if ui.button(tr(platform::SAVE_BUTTON_LABEL)).clicked() {
+                        self.start_save_reconstruted_content(ui.ctx());
+                    }
+
+                    // Cross-references button
+                    if let Some(selected_type_index) = self.selected_type_index {
+                        if ui.button(tr("🔍  Find XRefs to (Alt+X)")).clicked() {
+                            self.list_xrefs_for_type(selected_type_index);
                         }
-                        Ok(reconstructed_module) => {
-                            let last_line_number = 1 + reconstructed_module.lines().count();
-                            let line_numbers =
-                                (1..last_line_number).fold(String::default(), |mut acc, e| {
-                                    let _r = writeln!(&mut acc, "{e}");
-                                    acc
-                                });
-                            self.current_mode = ResymAppMode::Browsing(
-                                line_numbers,
-                                last_line_number,
-                                reconstructed_module,
-                            );
+                        if ui.button(tr("🔍  Find reverse dependencies")).clicked() {
+                            self.list_reverse_dependencies_for_type(selected_type_index);
+                        }
+                        if ui.button(tr("🔗  Dependency graph")).clicked() {
+                            self.show_dependency_graph(selected_type_index);
                         }
+                        if let Some(type_name) = self.selected_type_name().map(str::to_string) {
+                            if ui.button(tr("ℹ  Type properties")).clicked() {
+                                self.show_type_properties(selected_type_index, &type_name);
+                            }
+                        }
+                        if self.main_pdb_guid.is_some() && self.selected_type_name().is_some() {
+                            let bookmark_label =
+                                if self.main_pdb_project.is_bookmarked(selected_type_index) {
+                                    tr("⭐  Remove bookmark")
+                                } else {
+                                    tr("⭐  Bookmark")
+                                };
+                            if ui.button(bookmark_label).clicked() {
+                                self.toggle_selected_type_bookmark();
+                            }
+                        }
+                    }
+
+                    // Symbol properties button
+                    if let Some(selected_symbol_index) = self.selected_symbol_index {
+                        if ui.button(tr("ℹ  Symbol properties")).clicked() {
+                            self.show_symbol_properties(selected_symbol_index);
+                        }
+                    }
+                }
+                // Features only available in "Comparing" mode
+                if let ResymAppMode::Comparing(..) = self.current_mode {
+                    if ui.button(tr("Compare public symbols")).clicked() {
+                        self.diff_public_symbols();
+                    }
+                    if ui.button(tr("📋  Copy as patch")).clicked() {
+                        self.copy_diff_as_patch(ui.ctx());
                     }
+                    ui.menu_button(tr(platform::SAVE_BUTTON_LABEL), |ui| {
+                        if ui.button(tr("Unified patch")).clicked() {
+                            self.start_save_diff(ui.ctx(), DiffExportFormat::UnifiedPatch);
+                            ui.close_menu();
+                        }
+                        if ui.button(tr("Side by side")).clicked() {
+                            self.start_save_diff(ui.ctx(), DiffExportFormat::SideBySide);
+                            ui.close_menu();
+                        }
+                    });
+                }
+            });
+        });
+        // Display a summary of the changes in "Comparing" mode
+        if let ResymAppMode::Comparing(..) = self.current_mode {
+            self.diff_statistics.update(ui);
+        }
+        // Reconstruction settings for the current view
+        if !matches!(self.current_mode, ResymAppMode::Idle)
+            && self.view_settings.update(ui, &self.settings.app_settings)
+        {
+            self.refresh_selected_type();
+        }
+        ui.separator();
+
+        // Update the code view component
+        self.code_view
+            .update(&mut self.settings.app_settings, &self.current_mode, ui);
+    }
+
+    /// Reconstruct the selected type again (e.g., after the view's settings
+    /// have changed)
+    fn refresh_selected_type(&self) {
+        let Some(type_index) = self.selected_type_index else {
+            return;
+        };
+
+        // Note: only support "Browsing" mode
+        if let ResymAppMode::Browsing(..) = self.current_mode {
+            if let Err(err) = self
+                .backend
+                .send_command(BackendCommand::ReconstructTypeByIndex(
+                    ResymPDBSlots::Main as usize,
+                    type_index,
+                    self.view_settings.primitive_types_flavor,
+                    self.settings.app_settings.print_header,
+                    self.view_settings.reconstruct_dependencies,
+                    self.view_settings.print_access_specifiers,
+                    self.settings.app_settings.ignore_std_types,
+                ))
+            {
+                log::error!("Failed to reconstruct type: {}", err);
+            }
+        }
+    }
+
+    fn consume_keyboard_shortcuts(&mut self, ui: &mut egui::Ui) {
+        /// Keyboard shortcut for opening files
+        const CTRL_O_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
+            modifiers: egui::Modifiers::CTRL,
+            logical_key: egui::Key::O,
+        };
+        ui.input_mut(|input_state| {
+            if input_state.consume_shortcut(&CTRL_O_SHORTCUT) {
+                self.start_open_pdb_file(ResymPDBSlots::Main as usize);
+            }
+        });
+
+        // Keyboard shortcut for opening URLs
+        #[cfg(feature = "http")]
+        const CTRL_L_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
+            modifiers: egui::Modifiers::CTRL,
+            logical_key: egui::Key::L,
+        };
+        #[cfg(feature = "http")]
+        ui.input_mut(|input_state| {
+            if input_state.consume_shortcut(&CTRL_L_SHORTCUT) {
+                self.open_url.open(ResymPDBSlots::Main);
+            }
+        });
+
+        // Keyboard shortcut for finding cross-references
+        const ALT_X_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
+            modifiers: egui::Modifiers::ALT,
+            logical_key: egui::Key::X,
+        };
+        ui.input_mut(|input_state| {
+            if input_state.consume_shortcut(&ALT_X_SHORTCUT) {
+                if let Some(selected_type_index) = self.selected_type_index {
+                    self.list_xrefs_for_type(selected_type_index);
+                }
+            }
+        });
+
+        // Keyboard shortcut for jumping to types
+        const CTRL_P_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
+            modifiers: egui::Modifiers::CTRL,
+            logical_key: egui::Key::P,
+        };
+        ui.input_mut(|input_state| {
+            if input_state.consume_shortcut(&CTRL_P_SHORTCUT)
+                && matches!(self.current_mode, ResymAppMode::Browsing(..))
+            {
+                self.type_palette.open();
+            }
+        });
+
+        // Keyboard shortcut for focusing the type search bar
+        // Note: Ctrl+L is already used for opening URLs
+        const CTRL_F_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
+            modifiers: egui::Modifiers::CTRL,
+            logical_key: egui::Key::F,
+        };
+        ui.input_mut(|input_state| {
+            if input_state.consume_shortcut(&CTRL_F_SHORTCUT) {
+                self.focus_panel(FocusablePanel::TypeSearch);
+            }
+        });
+
+        // Keyboard shortcuts for moving the focus between the type search
+        // bar, the type list and the code view.
+        // Note: Tab is left to egui, which uses it to move between widgets
+        const SHIFT_F6_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
+            modifiers: egui::Modifiers::SHIFT,
+            logical_key: egui::Key::F6,
+        };
+        const F6_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
+            modifiers: egui::Modifiers::NONE,
+            logical_key: egui::Key::F6,
+        };
+        let focus_cycle_step = ui.input_mut(|input_state| {
+            // Note: check Shift+F6 first, as F6 matches it too
+            if input_state.consume_shortcut(&SHIFT_F6_SHORTCUT) {
+                Some(FocusablePanel::ALL.len() - 1)
+            } else if input_state.consume_shortcut(&F6_SHORTCUT) {
+                Some(1)
+            } else {
+                None
+            }
+        });
+        if let Some(step) = focus_cycle_step {
+            let next_panel = match self.focused_panel() {
+                Some(panel) => {
+                    let position = FocusablePanel::ALL
+                        .iter()
+                        .position(|element| *element == panel)
+                        .unwrap_or_default();
+                    FocusablePanel::ALL[(position + step) % FocusablePanel::ALL.len()]
                 }
+                None => FocusablePanel::TypeSearch,
+            };
+            self.focus_panel(next_panel);
+        }
+
+        // Keyboard shortcut for saving reconstructed content
+        #[cfg(not(target_arch = "wasm32"))]
+        const CTRL_S_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
+            modifiers: egui::Modifiers::CTRL,
+            logical_key: egui::Key::S,
+        };
+        // Ctrl+S shortcut handling
+        if ui.input_mut(|input_state| input_state.consume_shortcut(&CTRL_S_SHORTCUT)) {
+            if let ResymAppMode::Comparing(..) = self.current_mode {
+                self.start_save_diff(ui.ctx(), DiffExportFormat::UnifiedPatch);
+            } else {
+                self.start_save_reconstruted_content(ui.ctx());
+            }
+        }
+    }
+
+    /// Panel which had the keyboard focus during the last frame, if any
+    fn focused_panel(&self) -> Option<FocusablePanel> {
+        if self.type_search.has_focus() {
+            Some(FocusablePanel::TypeSearch)
+        } else if self.type_list.has_focus() {
+            Some(FocusablePanel::TypeList)
+        } else if self.code_view.has_focus() {
+            Some(FocusablePanel::CodeView)
+        } else {
+            None
+        }
+    }
+
+    fn focus_panel(&mut self, panel: FocusablePanel) {
+        match panel {
+            FocusablePanel::TypeSearch => {
+                self.left_panel_selected_tab = LeftPanelTab::TypeSearch;
+                self.type_search.request_focus();
+            }
+            FocusablePanel::TypeList => {
+                self.left_panel_selected_tab = LeftPanelTab::TypeSearch;
+                self.type_list.request_focus();
+            }
+            FocusablePanel::CodeView => self.code_view.request_focus(),
+        }
+    }
+
+    /// Show the given error next to the PDB the failed command operated on
+    fn show_pdb_error(&mut self, err: &ResymCoreError) {
+        let Some(ErrorContext {
+            command,
+            pdb_slot: Some(pdb_slot),
+        }) = err.context()
+        else {
+            return;
+        };
+
+        self.set_pdb_error(*pdb_slot, Some(format!("{command} failed: {err}")));
+    }
+
+    fn set_pdb_error(&mut self, pdb_slot: PDBSlot, error: Option<String>) {
+        if pdb_slot == ResymPDBSlots::Main as usize {
+            self.status_bar.set_main_pdb_error(error);
+        } else if pdb_slot == ResymPDBSlots::Diff as usize {
+            self.status_bar.set_diff_pdb_error(error);
+        }
+    }
+
+    fn process_ui_commands(&mut self, ctx: &egui::Context) {
+        while let Ok(cmd) = self.frontend_controller.rx_ui.try_recv() {
+            // Errors are still logged to the console when handled below
+            if let Some(err) = cmd.error() {
+                self.show_pdb_error(err);
+            }
+            match cmd {
+                FrontendCommand::LoadPDBResult(result) => match result {
+                    Err(err) => {
+                        if let ResymCoreError::OperationCancelledError = err {
+                            log::info!("PDB loading has been cancelled");
+                        } else {
+                            log::error!("Failed to load PDB file: {}", err);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            self.pending_project = None;
+                            self.pending_reload = None;
+                            self.reload_prompt.abort_reloads();
+                        }
+                    }
+                    Ok(pdb_slot) => {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            // The file changed again while being reloaded, load its
+                            // final version instead of displaying this one
+                            if let Some(pdb_file_path) = self.reload_prompt.finish_reload(pdb_slot)
+                            {
+                                self.reload_pdb_file(pdb_slot, pdb_file_path);
+                                continue;
+                            }
+                            // Changes to the previously loaded PDB don't matter anymore
+                            self.reload_prompt.dismiss(pdb_slot);
+                        }
+                        self.set_pdb_error(pdb_slot, None);
+                        if pdb_slot == ResymPDBSlots::Main as usize {
+                            // Unload the PDB used for diffing if one is loaded
+                            if let ResymAppMode::Comparing(..) = self.current_mode {
+                                if let Err(err) = self.backend.send_command(
+                                    BackendCommand::UnloadPDB(ResymPDBSlots::Diff as usize),
+                                ) {
+                                    log::error!(
+                                        "Failed to unload the PDB used for comparison: {}",
+                                        err
+                                    );
+                                }
+                            }
+
+                            // Retrieve the PDB's GUID, used to look up renames
+                            // and the PDB's project file
+                            self.main_pdb_guid = None;
+                            self.main_pdb_project = PdbProject::default();
+                            self.update_bookmark_list();
+                            #[cfg(not(target_arch = "wasm32"))]
+                            {
+                                self.main_pdb_location = None;
+                            }
+                            self.status_bar.set_diff_pdb(None);
+                            if let Err(err) = self.backend.send_command(
+                                BackendCommand::GetPDBMetadata(ResymPDBSlots::Main as usize),
+                            ) {
+                                log::error!("Failed to retrieve PDB information: {err}");
+                            }
+
+                            // Reset current mode
+                            self.current_mode =
+                                ResymAppMode::Browsing(String::default(), 0, String::default());
+                            // Reset selected type
+                            self.selected_type_index = None;
+                            // Reset xref lists
+                            self.xref_to_list.update_index_list(vec![]);
+                            self.xref_from_list.update_index_list(vec![]);
+                            self.reverse_dependency_list.update_index_list(vec![]);
+
+                            // Request a type list update. Reloaded PDBs keep the
+                            // current search query.
+                            #[cfg(not(target_arch = "wasm32"))]
+                            let (search_filter, case_insensitive, use_regex) =
+                                if self.pending_reload.is_some() {
+                                    let search_options =
+                                        self.settings.app_settings.search_options();
+                                    let (search_filter, use_regex) = search_options
+                                        .backend_filter(self.type_search.search_filter());
+                                    (search_filter, search_options.case_insensitive, use_regex)
+                                } else {
+                                    (String::default(), false, false)
+                                };
+                            #[cfg(target_arch = "wasm32")]
+                            let (search_filter, case_insensitive, use_regex) =
+                                (String::default(), false, false);
+                            if let Err(err) = self.backend.send_command(BackendCommand::ListTypes(
+                                ResymPDBSlots::Main as usize,
+                                search_filter,
+                                case_insensitive,
+                                use_regex,
+                                self.settings.app_settings.ignore_std_types,
+                            )) {
+                                log::error!("Failed to update type filter value: {}", err);
+                            }
+                            // Request the types' kind and size, displayed in the type list
+                            if let Err(err) = self.backend.send_command(
+                                BackendCommand::ListTypeInfos(ResymPDBSlots::Main as usize),
+                            ) {
+                                log::error!("Failed to retrieve type information: {err}");
+                            }
+                            // Request a symbol list update
+                            if let Err(err) =
+                                self.backend.send_command(BackendCommand::ListSymbols(
+                                    ResymPDBSlots::Main as usize,
+                                    String::default(),
+                                    self.symbol_list_options(),
+                                ))
+                            {
+                                log::error!("Failed to update type filter value: {}", err);
+                            }
+                            // Request a module list update
+                            if let Err(err) =
+                                self.backend.send_command(BackendCommand::ListModules(
+                                    ResymPDBSlots::Main as usize,
+                                    String::default(),
+                                    false,
+                                    false,
+                                ))
+                            {
+                                log::error!("Failed to update module list: {}", err);
+                            }
+                            // Request a source file list update
+                            if let Err(err) =
+                                self.backend.send_command(BackendCommand::ListSourceFiles(
+                                    ResymPDBSlots::Main as usize,
+                                    String::default(),
+                                    false,
+                                    false,
+                                ))
+                            {
+                                log::error!("Failed to update source file list: {}", err);
+                            }
+                            // Request a stream list update
+                            if let Err(err) = self.backend.send_command(
+                                BackendCommand::ListStreams(ResymPDBSlots::Main as usize),
+                            ) {
+                                log::error!("Failed to update stream list: {}", err);
+                            }
+                        } else if pdb_slot == ResymPDBSlots::Diff as usize {
+                            // Reset current mode
+                            self.current_mode = ResymAppMode::Comparing(
+                                String::default(),
+                                String::default(),
+                                0,
+                                vec![],
+                                String::default(),
+                            );
+                            // Reset diff statistics
+                            self.diff_statistics.set_statistics(None);
+                            // Retrieve the PDB's information, displayed in the status bar
+                            if let Err(err) = self.backend.send_command(
+                                BackendCommand::GetPDBMetadata(ResymPDBSlots::Diff as usize),
+                            ) {
+                                log::error!("Failed to retrieve PDB information: {err}");
+                            }
+                            // Reset selected type
+                            self.selected_type_index = None;
+                            // Reset xref lists
+                            self.xref_to_list.update_index_list(vec![]);
+                            self.xref_from_list.update_index_list(vec![]);
+                            self.reverse_dependency_list.update_index_list(vec![]);
+
+                            // Request a type list update
+                            if let Err(err) = self.backend.send_command(merged_type_list_command(
+                                self.changed_types_only,
+                                String::default(),
+                                false,
+                                false,
+                                self.diff_options(),
+                            )) {
+                                log::error!("Failed to update type filter value: {}", err);
+                            }
+                        }
+                    }
+                },
+
+                FrontendCommand::LoadURLResult(result) => match result {
+                    Err(err) => {
+                        log::error!("Failed to load URL: {}", err);
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            self.pending_project = None;
+                        }
+                    }
+                    Ok((pdb_slot, file_name, data)) => {
+                        if let Err(err) = self
+                            .backend
+                            .send_command(BackendCommand::LoadPDBFromVec(pdb_slot, file_name, data))
+                        {
+                            log::error!("Failed to load the PDB file: {err}");
+                        }
+                    }
+                },
+
+                FrontendCommand::ReconstructTypeResult(type_reconstruction_result) => {
+                    match type_reconstruction_result {
+                        Err(ResymCoreError::OperationCancelledError) => {
+                            log::info!("Reconstruction has been cancelled");
+                            self.current_mode = ResymAppMode::Browsing(
+                                Default::default(),
+                                0,
+                                tr("Reconstruction cancelled").to_string(),
+                            );
+                        }
+                        Err(err) => {
+                            let error_msg = format!("Failed to reconstruct type: {}", err);
+                            log::error!("{}", &error_msg);
+
+                            // Show an empty "reconstruted" view
+                            self.current_mode =
+                                ResymAppMode::Browsing(Default::default(), 0, error_msg);
+                        }
+                        Ok((reconstructed_type, xrefs_from)) => {
+                            let reconstructed_type =
+                                self.post_process_reconstructed_code(reconstructed_type);
+                            let last_line_number = 1 + reconstructed_type.lines().count();
+                            let line_numbers =
+                                (1..last_line_number).fold(String::default(), |mut acc, e| {
+                                    let _r = writeln!(&mut acc, "{e}");
+                                    acc
+                                });
+                            self.current_mode = ResymAppMode::Browsing(
+                                line_numbers,
+                                last_line_number,
+                                reconstructed_type,
+                            );
+
+                            // Update xref lists
+                            self.xref_to_list.update_index_list(vec![]);
+                            self.xref_from_list.update_index_list(xrefs_from);
+                            self.reverse_dependency_list.update_index_list(vec![]);
+                            // Switch to the "xref from" tab
+                            self.bottom_panel_selected_tab = BottomPanelTab::XRefsFrom;
+                        }
+                    }
+                }
+
+                FrontendCommand::ReconstructTypesResult(reconstructed_types_result) => {
+                    match reconstructed_types_result {
+                        Err(err) => {
+                            log::error!("Failed to reconstruct the selected types: {err}");
+                        }
+                        Ok(reconstructed_types) => {
+                            let reconstructed_types =
+                                self.post_process_reconstructed_code(reconstructed_types);
+                            self.save_reconstructed_types(ctx, &reconstructed_types);
+                        }
+                    }
+                }
+
+                FrontendCommand::ReconstructAllEnumsResult(reconstructed_enums_result) => {
+                    match reconstructed_enums_result {
+                        Err(err) => {
+                            log::error!("Failed to reconstruct enumerations: {err}");
+                        }
+                        Ok(reconstructed_enums) => {
+                            let reconstructed_enums =
+                                self.post_process_reconstructed_code(reconstructed_enums);
+                            self.save_reconstructed_enums(ctx, &reconstructed_enums);
+                        }
+                    }
+                }
+
+                FrontendCommand::ListModulesResult(module_list_result) => {
+                    self.module_search.set_error(None);
+                    match module_list_result {
+                        Err(err) => {
+                            log::error!("Failed to retrieve module list: {}", err);
+                        }
+                        Ok(module_list) => {
+                            self.module_tree.set_module_list(module_list);
+                        }
+                    }
+                }
+
+                FrontendCommand::ReconstructSymbolResult(result) => {
+                    match result {
+                        Err(err) => {
+                            let error_msg = format!("Failed to reconstruct symbol: {}", err);
+                            log::error!("{}", &error_msg);
+
+                            // Show an empty "reconstruted" view
+                            self.current_mode =
+                                ResymAppMode::Browsing(Default::default(), 0, error_msg);
+                        }
+                        Ok(reconstructed_symbol) => {
+                            let reconstructed_symbol =
+                                self.post_process_reconstructed_code(reconstructed_symbol);
+                            let last_line_number = 1 + reconstructed_symbol.lines().count();
+                            let line_numbers =
+                                (1..last_line_number).fold(String::default(), |mut acc, e| {
+                                    let _r = writeln!(&mut acc, "{e}");
+                                    acc
+                                });
+                            self.current_mode = ResymAppMode::Browsing(
+                                line_numbers,
+                                last_line_number,
+                                reconstructed_symbol,
+                            );
+                        }
+                    }
+                }
+
+                FrontendCommand::ReconstructModuleResult(module_reconstruction_result) => {
+                    match module_reconstruction_result {
+                        Err(err) => {
+                            let error_msg = format!("Failed to reconstruct module: {}", err);
+                            log::error!("{}", &error_msg);
+
+                            // Show an empty "reconstruted" view
+                            self.current_mode =
+                                ResymAppMode::Browsing(Default::default(), 0, error_msg);
+                        }
+                        Ok(reconstructed_module) => {
+                            let reconstructed_module =
+                                self.post_process_reconstructed_code(reconstructed_module);
+                            let last_line_number = 1 + reconstructed_module.lines().count();
+                            let line_numbers =
+                                (1..last_line_number).fold(String::default(), |mut acc, e| {
+                                    let _r = writeln!(&mut acc, "{e}");
+                                    acc
+                                });
+                            self.current_mode = ResymAppMode::Browsing(
+                                line_numbers,
+                                last_line_number,
+                                reconstructed_module,
+                            );
+                        }
+                    }
+                }
+
+                FrontendCommand::ListSourceFilesResult(source_file_list_result) => {
+                    self.source_file_search.set_error(None);
+                    match source_file_list_result {
+                        Err(err) => {
+                            log::error!("Failed to retrieve source file list: {}", err);
+                        }
+                        Ok(source_file_list) => {
+                            self.source_file_list.update_index_list(source_file_list);
+                        }
+                    }
+                }
+
+                FrontendCommand::ReconstructSourceFileResult(source_file_reconstruction_result) => {
+                    match source_file_reconstruction_result {
+                        Err(err) => {
+                            let error_msg = format!("Failed to reconstruct source file: {}", err);
+                            log::error!("{}", &error_msg);
+
+                            // Show an empty "reconstruted" view
+                            self.current_mode =
+                                ResymAppMode::Browsing(Default::default(), 0, error_msg);
+                        }
+                        Ok(reconstructed_source_file) => {
+                            let reconstructed_source_file =
+                                self.post_process_reconstructed_code(reconstructed_source_file);
+                            let last_line_number = 1 + reconstructed_source_file.lines().count();
+                            let line_numbers =
+                                (1..last_line_number).fold(String::default(), |mut acc, e| {
+                                    let _r = writeln!(&mut acc, "{e}");
+                                    acc
+                                });
+                            self.current_mode = ResymAppMode::Browsing(
+                                line_numbers,
+                                last_line_number,
+                                reconstructed_source_file,
+                            );
+                        }
+                    }
+                }
+
+                FrontendCommand::ListStreamsResult(stream_list_result) => {
+                    match stream_list_result {
+                        Err(err) => {
+                            log::error!("Failed to retrieve stream list: {}", err);
+                        }
+                        Ok(stream_list) => {
+                            self.stream_list.update_index_list(stream_list);
+                        }
+                    }
+                }
+
+                FrontendCommand::DumpStreamResult(stream_dump_result) => match stream_dump_result {
+                    Err(err) => {
+                        let error_msg = format!("Failed to dump stream: {}", err);
+                        log::error!("{}", &error_msg);
+
+                        // Show an empty "reconstruted" view
+                        self.current_mode =
+                            ResymAppMode::Browsing(Default::default(), 0, error_msg);
+                    }
+                    Ok(stream_dump) => {
+                        let last_line_number = 1 + stream_dump.lines().count();
+                        let line_numbers =
+                            (1..last_line_number).fold(String::default(), |mut acc, e| {
+                                let _r = writeln!(&mut acc, "{e}");
+                                acc
+                            });
+                        self.current_mode =
+                            ResymAppMode::Browsing(line_numbers, last_line_number, stream_dump);
+                    }
+                },
+
+                FrontendCommand::DiffResult(type_diff_result) => match type_diff_result {
+                    Err(err) => {
+                        let error_msg = format!("Failed to generate diff: {}", err);
+                        log::error!("{}", &error_msg);
+
+                        // Show an empty "reconstruted" view
+                        self.current_mode = ResymAppMode::Comparing(
+                            Default::default(),
+                            Default::default(),
+                            0,
+                            vec![],
+                            error_msg,
+                        );
+                        self.diff_statistics.set_statistics(None);
+                    }
+                    Ok(type_diff) => {
+                        self.diff_statistics
+                            .set_statistics(Some(type_diff.statistics.clone()));
+                        let mut last_line_number = 1;
+                        let diff_lines = type_diff.metadata.iter().zip(type_diff.inline_changes);
+                        let (line_numbers_old, line_numbers_new, line_changes) = diff_lines.fold(
+                            (String::default(), String::default(), vec![]),
+                            |(mut acc_old, mut acc_new, mut acc_changes),
+                             (metadata, inline_changes)| {
+                                let indices = metadata.0;
+
+                                if let Some(indice) = indices.0 {
+                                    last_line_number = std::cmp::max(last_line_number, 1 + indice);
+                                    let _r = writeln!(&mut acc_old, "{}", 1 + indice);
+                                } else {
+                                    let _r = writeln!(&mut acc_old);
+                                }
+
+                                if let Some(indice) = indices.1 {
+                                    last_line_number = std::cmp::max(last_line_number, 1 + indice);
+                                    let _r = writeln!(&mut acc_new, "{}", 1 + indice);
+                                } else {
+                                    let _r = writeln!(&mut acc_new);
+                                }
+
+                                acc_changes.push((metadata.1, inline_changes));
+
+                                (acc_old, acc_new, acc_changes)
+                            },
+                        );
+
+                        self.current_mode = ResymAppMode::Comparing(
+                            line_numbers_old,
+                            line_numbers_new,
+                            last_line_number,
+                            line_changes,
+                            type_diff.data,
+                        );
+                    }
+                },
+
+                FrontendCommand::SearchFilterError(search_target, err) => {
+                    // Keep the previous results, but let the user know why
+                    // they're not updated
+                    let search_component = match search_target {
+                        SearchTarget::Types => &mut self.type_search,
+                        SearchTarget::Symbols => &mut self.symbol_search,
+                        SearchTarget::Modules => &mut self.module_search,
+                        SearchTarget::SourceFiles => &mut self.source_file_search,
+                    };
+                    search_component.set_error(Some(err));
+                }
+
+                FrontendCommand::ListTypesResult(filtered_types) => {
+                    self.type_search.set_error(None);
+                    // Update type list component
+                    self.filtered_types = filtered_types;
+                    self.update_type_list();
+                    // Select the type that was selected before reloading the PDB
+                    #[cfg(not(target_arch = "wasm32"))]
+                    if let Some(pending_reload) = self.pending_reload.take() {
+                        self.reselect_type(pending_reload.selected_type_name);
+                    }
+                }
+
+                FrontendCommand::ListTypeInfosResult(type_infos) => {
+                    self.type_infos = type_infos;
+                    self.update_type_list();
+                }
+
+                FrontendCommand::FuzzyFindTypesResult(matching_types) => {
+                    self.type_palette.set_matches(matching_types);
+                }
+
+                FrontendCommand::ListSymbolsResult(filtered_symbols) => {
+                    self.symbol_search.set_error(None);
+                    // Update symbol list component
+                    self.symbol_list.update_index_list(filtered_symbols);
+                }
+
+                FrontendCommand::ListTypeCrossReferencesResult(xref_list_result) => {
+                    match xref_list_result {
+                        Err(err) => {
+                            log::error!("Failed to list cross-references: {err}");
+                        }
+                        Ok(xref_list) => {
+                            let xref_count = xref_list.len();
+                            log::info!("{xref_count} cross-references found!");
+
+                            // Update xref list component
+                            self.xref_to_list.update_index_list(xref_list);
+                            // Switch to xref tab
+                            self.bottom_panel_selected_tab = BottomPanelTab::XRefsTo;
+                        }
+                    }
+                }
+
+                FrontendCommand::ListTypeReverseDependenciesResult(
+                    reverse_dependency_list_result,
+                ) => match reverse_dependency_list_result {
+                    Err(err) => {
+                        log::error!("Failed to list reverse dependencies: {err}");
+                    }
+                    Ok(reverse_dependency_list) => {
+                        let dependency_count = reverse_dependency_list.len();
+                        log::info!("{dependency_count} reverse dependencies found!");
+
+                        // Update reverse dependency list component
+                        self.reverse_dependency_list
+                            .update_index_list(reverse_dependency_list);
+                        // Switch to reverse dependency tab
+                        self.bottom_panel_selected_tab = BottomPanelTab::ReverseDependencies;
+                    }
+                },
+
+                FrontendCommand::ExportTypeDependencyGraphResult(export_result) => {
+                    match export_result {
+                        Err(err) => {
+                            log::error!("Failed to export dependency graph: {err}");
+                        }
+                        Ok(dependency_graph) => {
+                            self.save_dependency_graph(ctx, &dependency_graph);
+                        }
+                    }
+                }
+
+                FrontendCommand::ExportLayoutAssertionsResult(export_result) => match export_result
+                {
+                    Err(err) => {
+                        log::error!("Failed to export layout assertions: {err}");
+                    }
+                    Ok(layout_assertions) => {
+                        self.save_layout_assertions(ctx, &layout_assertions);
+                    }
+                },
+
+                FrontendCommand::LookupSymbolByRVAResult(symbol_result) => {
+                    self.address_lookup.set_result(symbol_result);
+                }
+
+                FrontendCommand::PDBMetadataResult(pdb_slot, metadata_result) => {
+                    if pdb_slot == ResymPDBSlots::Diff as usize {
+                        self.status_bar.set_diff_pdb(metadata_result.ok());
+                        continue;
+                    }
+
+                    self.status_bar
+                        .set_main_pdb(metadata_result.as_ref().ok().cloned());
+                    if let Ok(metadata) = &metadata_result {
+                        if self.main_pdb_guid.as_ref() != Some(&metadata.guid) {
+                            // Load the PDB's project file, if any
+                            self.main_pdb_project = PdbProject::load(&metadata.guid)
+                                .unwrap_or_else(|err| {
+                                    log::error!("Failed to load project file: {err}");
+                                    PdbProject::default()
+                                });
+                            self.applied_type_notes = None;
+                        }
+                        self.main_pdb_guid = Some(metadata.guid.clone());
+                        // Make sure the main PDB's renames are up to date
+                        self.applied_renames = None;
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            self.main_pdb_location = Some(ProjectPdb::new(metadata));
+                            // Apply the project being opened, if any
+                            if let Some(project) = self.pending_project.take() {
+                                self.apply_project(project, &metadata.guid);
+                            }
+                        }
+                    }
+                    self.pdb_metadata.set_metadata(metadata_result);
+                }
+
+                FrontendCommand::TypeDependencyGraphResult(graph_result) => {
+                    self.dependency_graph.set_graph(graph_result);
+                }
+
+                FrontendCommand::LookupSymbolLocationResult(symbol_location_result) => {
+                    self.symbol_properties
+                        .set_symbol_location(symbol_location_result);
+                }
+
+                FrontendCommand::LookupTypePropertiesResult(type_properties_result) => {
+                    self.type_properties
+                        .set_type_properties(type_properties_result);
+                }
+
+                FrontendCommand::LookupTypeSourceLocationsResult(source_locations_result) => {
+                    self.type_properties
+                        .set_source_locations(source_locations_result);
+                }
+
+                #[cfg(not(target_arch = "wasm32"))]
+                FrontendCommand::LoadPEResult(result) => {
+                    if let Err(err) = result {
+                        log::error!("Failed to load PE file: {}", err);
+                    }
+                }
+
+                #[cfg(not(target_arch = "wasm32"))]
+                FrontendCommand::PDBFileChanged(pdb_slot, pdb_file_path) => {
+                    self.reload_prompt.notify_change(pdb_slot, pdb_file_path);
+                }
+
+                FrontendCommand::OperationTimingReport(timing) => {
+                    // Only successful PDB loadings report the number of types
+                    if timing.kind == OperationKind::Load && timing.item_count.is_some() {
+                        self.status_bar.set_last_load(timing.clone());
+                    }
+                    self.performance.record(timing);
+                }
+
+                // Results of commands the GUI never sends
+                FrontendCommand::ListTypesTaggedResult(_)
+                | FrontendCommand::CompareTypeResult(_)
+                | FrontendCommand::DiffAllTypesResult(_)
+                | FrontendCommand::CheckHeaderLayoutsResult(_)
+                | FrontendCommand::ListConstantsResult(_) => {
+                    log::warn!("Unexpected command result received");
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
+    fn update_menu_bar(&mut self, ui: &mut egui::Ui) {
+        egui::menu::bar(ui, |ui| {
+            ui.menu_button(tr("File"), |ui| {
+                if ui.button(tr("Open PDB file (Ctrl+O)")).clicked() {
+                    ui.close_menu();
+                    self.start_open_pdb_file(ResymPDBSlots::Main as usize);
+                }
+
+                #[cfg(feature = "http")]
+                if ui.button(tr("Open URL (Ctrl+L)")).clicked() {
+                    ui.close_menu();
+                    self.open_url.open(ResymPDBSlots::Main);
+                }
+
+                #[cfg(not(target_arch = "wasm32"))]
+                if ui
+                    .add_enabled(
+                        matches!(self.current_mode, ResymAppMode::Browsing(..)),
+                        egui::Button::new(tr("Open PE image ...")),
+                    )
+                    .on_hover_text(tr("Show the sections and exports of symbols"))
+                    .clicked()
+                {
+                    ui.close_menu();
+                    self.start_open_pe_file(ResymPDBSlots::Main as usize);
+                }
+
+                // Separate "Open" from "Project"
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    ui.separator();
+
+                    if ui.button(tr("Open project ...")).clicked() {
+                        ui.close_menu();
+                        self.start_open_project();
+                    }
+                    if ui
+                        .add_enabled(
+                            self.main_pdb_location.is_some()
+                                && matches!(self.current_mode, ResymAppMode::Browsing(..)),
+                            egui::Button::new(tr("Save project ...")),
+                        )
+                        .clicked()
+                    {
+                        ui.close_menu();
+                        self.start_save_project();
+                    }
+                }
+
+                // Separate "Open" from "Compare"
+                ui.separator();
+
+                if ui
+                    .add_enabled(
+                        matches!(self.current_mode, ResymAppMode::Browsing(..)),
+                        egui::Button::new(tr("Compare with file ...")),
+                    )
+                    .clicked()
+                {
+                    ui.close_menu();
+                    self.start_open_pdb_file(ResymPDBSlots::Diff as usize);
+                }
+
+                #[cfg(feature = "http")]
+                if ui
+                    .add_enabled(
+                        matches!(self.current_mode, ResymAppMode::Browsing(..)),
+                        egui::Button::new(tr("Compare with URL ...")),
+                    )
+                    .clicked()
+                {
+                    ui.close_menu();
+                    self.open_url.open(ResymPDBSlots::Diff);
+                }
+
+                // Separate "Compare" from "Export"
+                ui.separator();
+
+                if ui
+                    .add_enabled(
+                        matches!(self.current_mode, ResymAppMode::Browsing(..)),
+                        egui::Button::new(tr("Export dependency graph (DOT) ...")),
+                    )
+                    .clicked()
+                {
+                    ui.close_menu();
+                    self.export_dependency_graph();
+                }
+
+                if ui
+                    .add_enabled(
+                        matches!(self.current_mode, ResymAppMode::Browsing(..))
+                            && self.type_list.selected_elements().next().is_some(),
+                        egui::Button::new(tr("Export selected types ...")),
+                    )
+                    .on_hover_text(tr("Select multiple types with Ctrl+click and Shift+click"))
+                    .clicked()
+                {
+                    ui.close_menu();
+                    self.export_selected_types();
+                }
+
+                if ui
+                    .add_enabled(
+                        matches!(self.current_mode, ResymAppMode::Browsing(..)),
+                        egui::Button::new(tr("Export all enumerations ...")),
+                    )
+                    .on_hover_text(tr("Export all the enumerations of the PDB, sorted by name"))
+                    .clicked()
+                {
+                    ui.close_menu();
+                    self.export_all_enums();
+                }
+
+                if ui
+                    .add_enabled(
+                        matches!(self.current_mode, ResymAppMode::Browsing(..)),
+                        egui::Button::new(tr("Export layout assertions ...")),
+                    )
+                    .on_hover_text(
+                        tr("Generate static_asserts for the sizes and offsets of the selected types (or of all types)"),
+                    )
+                    .clicked()
+                {
+                    ui.close_menu();
+                    self.export_layout_assertions();
+                }
+
+                #[cfg(feature = "plugins")]
+                for export_format_id in 0..self.plugin_export_formats.len() {
+                    if ui
+                        .add_enabled(
+                            matches!(self.current_mode, ResymAppMode::Browsing(..)),
+                            egui::Button::new(format!(
+                                "{} {} ...",
+                                tr("Export as"),
+                                self.plugin_export_formats[export_format_id].name
+                            )),
+                        )
+                        .clicked()
+                    {
+                        ui.close_menu();
+                        self.export_with_plugin(ui.ctx(), export_format_id);
+                    }
+                }
+
+                // Separate "Export" from "Settings"
+                ui.separator();
+
+                if ui.button(tr("Settings")).clicked() {
+                    ui.close_menu();
+                    self.settings.open();
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                if ui.button(tr("Exit")).clicked() {
+                    ui.close_menu();
+                    ui.ctx().send_viewport_cmd(egui::ViewportCommand::Close);
+                }
+            });
+
+            ui.menu_button(tr("View"), |ui| {
+                for tab in DockTab::HIDEABLE {
+                    let mut shown = self
+                        .settings
+                        .app_settings
+                        .dock_state
+                        .find_tab(&tab)
+                        .is_some();
+                    if ui.checkbox(&mut shown, tab.title()).clicked() {
+                        ui.close_menu();
+                        self.toggle_dock_tab(tab);
+                    }
+                }
+                ui.separator();
+                if ui.button(tr("Reset layout")).clicked() {
+                    ui.close_menu();
+                    self.settings.app_settings.dock_state = default_dock_state();
+                }
+            });
+
+            ui.menu_button(tr("Tools"), |ui| {
+                if ui
+                    .add_enabled(
+                        matches!(self.current_mode, ResymAppMode::Browsing(..)),
+                        egui::Button::new(tr("Go to type (Ctrl+P)")),
+                    )
+                    .clicked()
+                {
+                    ui.close_menu();
+                    self.type_palette.open();
+                }
+                if ui.button(tr("Demangle symbol name")).clicked() {
+                    ui.close_menu();
+                    self.demangler.open();
+                }
+                if ui
+                    .add_enabled(
+                        !matches!(self.current_mode, ResymAppMode::Idle),
+                        egui::Button::new(tr("Look up address")),
+                    )
+                    .clicked()
+                {
+                    ui.close_menu();
+                    self.address_lookup.open();
+                }
+                if ui
+                    .add_enabled(
+                        !matches!(self.current_mode, ResymAppMode::Idle),
+                        egui::Button::new(tr("About this PDB")),
+                    )
+                    .clicked()
+                {
+                    ui.close_menu();
+                    self.show_pdb_metadata();
+                }
+                if ui.button(tr("Performance")).clicked() {
+                    ui.close_menu();
+                    self.performance.open();
+                }
+                if ui
+                    .add_enabled(
+                        self.main_pdb_guid.is_some()
+                            && matches!(self.current_mode, ResymAppMode::Browsing(..)),
+                        egui::Button::new(tr("Rename types and members")),
+                    )
+                    .clicked()
+                {
+                    ui.close_menu();
+                    if let Some(pdb_guid) = self.main_pdb_guid.clone() {
+                        self.renames.open(pdb_guid);
+                    }
+                }
+            });
+
+            #[cfg(feature = "plugins")]
+            ui.menu_button(tr("Plugins"), |ui| {
+                for menu_command_id in 0..self.plugin_menu_commands.len() {
+                    if ui
+                        .button(&self.plugin_menu_commands[menu_command_id].label)
+                        .clicked()
+                    {
+                        ui.close_menu();
+                        self.run_plugin_menu_command(menu_command_id);
+                    }
+                }
+                if !self.plugin_menu_commands.is_empty() {
+                    ui.separator();
+                }
+                let mut reload_button = ui.button(tr("Reload plugins"));
+                if let Some(plugins_directory) = plugins_directory() {
+                    reload_button = reload_button.on_hover_text(format!(
+                        "{} '{}'",
+                        tr("Plugins are loaded from"),
+                        plugins_directory.display()
+                    ));
+                }
+                if reload_button.clicked() {
+                    ui.close_menu();
+                    self.reload_plugins();
+                }
+            });
+        });
+    }
+
+    /// Function invoked on `Open PDB File` or when the Ctrl+O shortcut is used
+    #[cfg(not(target_arch = "wasm32"))]
+    fn start_open_pdb_file(&mut self, pdb_slot: PDBSlot) {
+        use platform::FileDialog;
+
+        let file_path_opt = platform::file_dialog().open_file(
+            tr("Select a PDB file"),
+            &platform::FileFilter {
+                patterns: &["*.pdb"],
+                description: "PDB files (*.pdb)",
+            },
+        );
+        if let Some(file_path) = file_path_opt {
+            if let Err(err) = self
+                .backend
+                .send_command(BackendCommand::LoadPDBFromPath(pdb_slot, file_path))
+            {
+                log::error!("Failed to load the PDB file: {err}");
+            }
+        }
+    }
+
+    /// Function invoked on `Open PE image`
+    #[cfg(not(target_arch = "wasm32"))]
+    fn start_open_pe_file(&mut self, pdb_slot: PDBSlot) {
+        use platform::FileDialog;
+
+        let file_path_opt = platform::file_dialog().open_file(
+            tr("Select a PE image"),
+            &platform::FileFilter {
+                patterns: &["*.exe", "*.dll", "*.sys"],
+                description: "PE images (*.exe;*.dll;*.sys)",
+            },
+        );
+        if let Some(file_path) = file_path_opt {
+            if let Err(err) = self
+                .backend
+                .send_command(BackendCommand::LoadPEFromPath(pdb_slot, file_path))
+            {
+                log::error!("Failed to load the PE file: {err}");
+            }
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn start_open_pdb_file(&mut self, pdb_slot: PDBSlot) {
+        let open_pdb_data = self.open_pdb_data.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            let file_opt = rfd::AsyncFileDialog::new()
+                .add_filter("PDB files (*.pdb)", &["pdb"])
+                .pick_file()
+                .await;
+            if let Some(file) = file_opt {
+                // We unwrap() the return value to assert that we are not expecting
+                // threads to ever fail while holding the lock.
+                *open_pdb_data.borrow_mut() = Some((pdb_slot, file.file_name(), file.read().await));
+            }
+        });
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn process_open_pdb_file_result(&self) {
+        // We unwrap() the return value to assert that we are not expecting
+        // threads to ever fail while holding the lock.
+        if let Some((pdb_slot, pdb_name, pdb_bytes)) = self.open_pdb_data.borrow_mut().take() {
+            if let Err(err) = self.backend.send_command(BackendCommand::LoadPDBFromVec(
+                pdb_slot, pdb_name, pdb_bytes,
+            )) {
+                log::error!("Failed to load the PDB file: {err}");
+            }
+        }
+    }
+
+    /// Function invoked on 'Find XRefs to'
+    /// Function invoked on `Open project`
+    #[cfg(not(target_arch = "wasm32"))]
+    fn start_open_project(&mut self) {
+        let file_path_opt = platform::file_dialog().open_file(
+            tr("Select a project file"),
+            &platform::FileFilter {
+                patterns: &[&format!("*.{PROJECT_FILE_EXTENSION}")],
+                description: "resym project (*.resym)",
+            },
+        );
+        let Some(file_path) = file_path_opt else {
+            return;
+        };
+
+        match ResymProject::load(&file_path) {
+            Err(err) => log::error!("Failed to open project file: {err}"),
+            Ok(project) => {
+                if let Err(err) = self.load_project_pdb(&project.pdb) {
+                    log::error!("Failed to load the project's PDB file: {err}");
+                    return;
+                }
+                // The rest of the project is applied once the PDB is loaded
+                self.pending_project = Some(project);
+            }
+        }
+    }
+
+    /// Load a project's PDB file from disk if it can be found there, or from
+    /// the symbol server otherwise
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_project_pdb(&self, project_pdb: &ProjectPdb) -> Result<()> {
+        if project_pdb.path.is_file() {
+            self.backend.send_command(BackendCommand::LoadPDBFromPath(
+                ResymPDBSlots::Main as usize,
+                project_pdb.path.clone(),
+            ))?;
+            return Ok(());
+        }
+
+        #[cfg(feature = "http")]
+        if let Some(url) = project_pdb.symbol_server_url() {
+            log::info!(
+                "'{}' cannot be found, fetching it from the symbol server ...",
+                project_pdb.path.display()
+            );
+            self.backend.send_command(BackendCommand::LoadPDBFromURL(
+                ResymPDBSlots::Main as usize,
+                url,
+            ))?;
+            return Ok(());
+        }
+
+        Err(anyhow::anyhow!(
+            "'{}' cannot be found",
+            project_pdb.path.display()
+        ))
+    }
+
+    /// Restore the data and window state saved in a project, once its PDB
+    /// file has been loaded
+    #[cfg(not(target_arch = "wasm32"))]
+    fn apply_project(&mut self, project: ResymProject, pdb_guid: &str) {
+        if project.pdb.guid != pdb_guid {
+            log::warn!(
+                "The loaded PDB doesn't match the project's PDB (GUID {})",
+                project.pdb.guid
+            );
+        }
+
+        // The project's data takes precedence over the data stored for the PDB
+        self.main_pdb_project = project.pdb_project;
+        if let Err(err) = self.main_pdb_project.save(pdb_guid) {
+            log::error!("Failed to save type notes and bookmarks: {err}");
+        }
+        self.applied_type_notes = None;
+        if project.renames.trim().is_empty() {
+            self.settings.app_settings.renames.remove(pdb_guid);
+        } else {
+            self.settings
+                .app_settings
+                .renames
+                .insert(pdb_guid.to_string(), project.renames);
+        }
+        self.update_bookmark_list();
+
+        // Restore the window's state
+        let window_state = project.window_state;
+        self.left_panel_selected_tab = window_state.left_panel_tab;
+        self.bottom_panel_selected_tab = window_state.bottom_panel_tab;
+        if let Some(type_index) = window_state.selected_type_index {
+            self.selected_type_index = Some(type_index);
+            if let Err(err) = self
+                .backend
+                .send_command(BackendCommand::ReconstructTypeByIndex(
+                    ResymPDBSlots::Main as usize,
+                    type_index,
+                    self.view_settings.primitive_types_flavor,
+                    self.settings.app_settings.print_header,
+                    self.view_settings.reconstruct_dependencies,
+                    self.view_settings.print_access_specifiers,
+                    self.settings.app_settings.ignore_std_types,
+                ))
+            {
+                log::error!("Failed to reconstruct type: {}", err);
+            }
+        }
+        log::info!("Project has been opened successfully!");
+    }
+
+    /// Function invoked on `Save project`
+    #[cfg(not(target_arch = "wasm32"))]
+    fn start_save_project(&self) {
+        let (Some(project_pdb), Some(pdb_guid)) = (&self.main_pdb_location, &self.main_pdb_guid)
+        else {
+            return;
+        };
+        let file_path_opt = platform::file_dialog().save_file(
+            tr("Save project file"),
+            &platform::FileFilter {
+                patterns: &[&format!("*.{PROJECT_FILE_EXTENSION}")],
+                description: "resym project (*.resym)",
+            },
+        );
+        let Some(file_path) = file_path_opt else {
+            return;
+        };
+
+        let project = ResymProject {
+            pdb: project_pdb.clone(),
+            pdb_project: self.main_pdb_project.clone(),
+            renames: self
+                .settings
+                .app_settings
+                .renames
+                .get(pdb_guid)
+                .cloned()
+                .unwrap_or_default(),
+            window_state: WindowState {
+                left_panel_tab: self.left_panel_selected_tab,
+                bottom_panel_tab: self.bottom_panel_selected_tab,
+                selected_type_index: self.selected_type_index,
+            },
+        };
+        match project.save(&file_path) {
+            Err(err) => log::error!("Failed to save project file: {err}"),
+            Ok(()) => log::info!("Project has been saved to '{}'.", file_path.display()),
+        }
+    }
+
+    fn list_xrefs_for_type(&self, type_index: TypeIndex) {
+        log::info!(
+            "Looking for cross-references for type #0x{:x}...",
+            type_index
+        );
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::ListTypeCrossReferences(
+                ResymPDBSlots::Main as usize,
+                type_index,
+            ))
+        {
+            log::error!(
+                "Failed to list cross-references to type #0x{:x}: {err}",
+                type_index
+            );
+        }
+    }
+
+    /// Function invoked on 'Find reverse dependencies'
+    fn list_reverse_dependencies_for_type(&self, type_index: TypeIndex) {
+        log::info!(
+            "Looking for reverse dependencies for type #0x{:x}...",
+            type_index
+        );
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::ListTypeReverseDependencies(
+                ResymPDBSlots::Main as usize,
+                type_index,
+            ))
+        {
+            log::error!(
+                "Failed to list reverse dependencies of type #0x{:x}: {err}",
+                type_index
+            );
+        }
+    }
+
+    /// Load the given PDB file again, keeping the current search query and
+    /// selection for the main PDB
+    #[cfg(not(target_arch = "wasm32"))]
+    fn reload_pdb_file(&mut self, pdb_slot: PDBSlot, pdb_file_path: PathBuf) {
+        if pdb_slot == ResymPDBSlots::Main as usize {
+            self.pending_reload = Some(PendingReload {
+                selected_type_name: self.selected_type_name().map(str::to_string),
+            });
+        }
+        log::info!("Reloading '{}' ...", pdb_file_path.display());
+        self.reload_prompt.start_reload(pdb_slot);
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::LoadPDBFromPath(pdb_slot, pdb_file_path))
+        {
+            self.pending_reload = None;
+            self.reload_prompt.finish_reload(pdb_slot);
+            log::error!("Failed to reload PDB file: {err}");
+        }
+    }
+
+    /// Select the given type again, if it still exists in the type list
+    #[cfg(not(target_arch = "wasm32"))]
+    fn reselect_type(&mut self, type_name: Option<String>) {
+        let Some(type_name) = type_name else {
+            return;
+        };
+        self.selected_type_index = self
+            .filtered_types
+            .iter()
+            .find(|(name, _)| *name == type_name)
+            .map(|(_, type_index)| *type_index);
+        self.refresh_selected_type();
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn process_watch_pdb_files_update(&mut self) {
+        let watch_pdb_files = self.settings.app_settings.watch_pdb_files;
+        if self.applied_watch_pdb_files == Some(watch_pdb_files) {
+            // Setting hasn't changed
+            return;
+        }
+
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::SetWatchPDBFiles(watch_pdb_files))
+        {
+            log::error!("Failed to update PDB file watching: {err}");
+        }
+        self.applied_watch_pdb_files = Some(watch_pdb_files);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn process_clang_format_update(&mut self) {
+        let clang_format = self.settings.app_settings.clang_format_configuration();
+        if self.applied_clang_format.as_ref() == Some(&clang_format) {
+            // Configuration hasn't changed
+            return;
+        }
+
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::SetClangFormat(clang_format.clone()))
+        {
+            log::error!("Failed to update clang-format configuration: {err}");
+        }
+        self.applied_clang_format = Some(clang_format);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn process_log_file_update(&mut self) {
+        let new_lines = self.console.collect_logs();
+
+        let log_to_file = self.settings.app_settings.log_to_file;
+        if self.applied_log_to_file != Some(log_to_file) {
+            self.log_file = None;
+            if log_to_file {
+                match log_file::log_directory().map(|log_directory| LogFile::open(&log_directory)) {
+                    Some(Ok(log_file)) => self.log_file = Some(log_file),
+                    Some(Err(err)) => log::error!("Failed to open the log file: {err}"),
+                    None => log::error!("Failed to open the log file: no config directory"),
+                }
+            }
+            self.applied_log_to_file = Some(log_to_file);
+        }
+
+        if let Some(log_file) = self.log_file.as_mut() {
+            if let Err(err) = log_file.write_lines(new_lines) {
+                log::error!("Failed to write to the log file: {err}");
+                self.log_file = None;
+            }
+        }
+    }
+
+    fn show_pdb_metadata(&mut self) {
+        self.pdb_metadata.open();
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::GetPDBMetadata(ResymPDBSlots::Main as usize))
+        {
+            log::error!("Failed to retrieve PDB information: {err}");
+        }
+    }
 
-                FrontendCommand::DiffResult(type_diff_result) => match type_diff_result {
-                    Err(err) => {
-                        let error_msg = format!("Failed to generate diff: {}", err);
-                        log::error!("{}", &error_msg);
+    fn show_type_properties(&mut self, type_index: TypeIndex, type_name: &str) {
+        self.type_properties.open(type_name);
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::LookupTypeProperties(
+                ResymPDBSlots::Main as usize,
+                type_index,
+            ))
+        {
+            log::error!("Failed to look up type properties: {err}");
+        }
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::LookupTypeSourceLocations(
+                ResymPDBSlots::Main as usize,
+                type_name.to_string(),
+            ))
+        {
+            log::error!("Failed to look up type source locations: {err}");
+        }
+    }
 
-                        // Show an empty "reconstruted" view
-                        self.current_mode = ResymAppMode::Comparing(
-                            Default::default(),
-                            Default::default(),
-                            0,
-                            vec![],
-                            error_msg,
-                        );
-                    }
-                    Ok(type_diff) => {
-                        let mut last_line_number = 1;
-                        let (line_numbers_old, line_numbers_new, line_changes) =
-                            type_diff.metadata.iter().fold(
-                                (String::default(), String::default(), vec![]),
-                                |(mut acc_old, mut acc_new, mut acc_changes), metadata| {
-                                    let indices = metadata.0;
-
-                                    if let Some(indice) = indices.0 {
-                                        last_line_number =
-                                            std::cmp::max(last_line_number, 1 + indice);
-                                        let _r = writeln!(&mut acc_old, "{}", 1 + indice);
-                                    } else {
-                                        let _r = writeln!(&mut acc_old);
-                                    }
-
-                                    if let Some(indice) = indices.1 {
-                                        last_line_number =
-                                            std::cmp::max(last_line_number, 1 + indice);
-                                        let _r = writeln!(&mut acc_new, "{}", 1 + indice);
-                                    } else {
-                                        let _r = writeln!(&mut acc_new);
-                                    }
-
-                                    acc_changes.push(metadata.1);
-
-                                    (acc_old, acc_new, acc_changes)
-                                },
-                            );
+    fn show_symbol_properties(&mut self, symbol_index: SymbolIndex) {
+        self.symbol_properties.open();
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::LookupSymbolLocationByIndex(
+                ResymPDBSlots::Main as usize,
+                symbol_index,
+            ))
+        {
+            log::error!("Failed to look up symbol location: {err}");
+        }
+    }
 
-                        self.current_mode = ResymAppMode::Comparing(
-                            line_numbers_old,
-                            line_numbers_new,
-                            last_line_number,
-                            line_changes,
-                            type_diff.data,
-                        );
-                    }
-                },
+    /// Apply optional post-processing steps (e.g., plugins) to reconstructed
+    /// code before displaying it. Note: `clang-format` is run by the backend.
+    #[cfg_attr(not(feature = "plugins"), allow(unused_mut))]
+    fn post_process_reconstructed_code(&self, mut code: String) -> String {
+        #[cfg(feature = "plugins")]
+        match self.plugin_manager.post_process(code.clone()) {
+            Ok(processed_code) => code = processed_code,
+            Err(err) => log::warn!("Failed to post-process code with plugins: {err}"),
+        }
 
-                FrontendCommand::ListTypesResult(filtered_types) => {
-                    // Update type list component
-                    self.type_list.update_index_list(filtered_types);
-                }
+        code
+    }
 
-                FrontendCommand::ListSymbolsResult(filtered_symbols) => {
-                    // Update symbol list component
-                    self.symbol_list.update_index_list(filtered_symbols);
-                }
+    fn show_dependency_graph(&mut self, type_index: TypeIndex) {
+        self.dependency_graph.open(type_index);
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::GetTypeDependencyGraph(
+                ResymPDBSlots::Main as usize,
+                type_index,
+                self.settings.app_settings.ignore_std_types,
+            ))
+        {
+            log::error!("Failed to retrieve dependency graph: {err}");
+        }
+    }
 
-                FrontendCommand::ListTypeCrossReferencesResult(xref_list_result) => {
-                    match xref_list_result {
-                        Err(err) => {
-                            log::error!("Failed to list cross-references: {err}");
-                        }
-                        Ok(xref_list) => {
-                            let xref_count = xref_list.len();
-                            log::info!("{xref_count} cross-references found!");
+    fn diff_public_symbols(&mut self) {
+        // Reset selected type
+        self.selected_type_index = None;
+        if let Err(err) = self.backend.send_command(BackendCommand::DiffPublicSymbols(
+            ResymPDBSlots::Main as usize,
+            ResymPDBSlots::Diff as usize,
+            self.view_settings.primitive_types_flavor,
+            self.settings.app_settings.print_header,
+            self.view_settings.print_access_specifiers,
+            self.settings.app_settings.diff_ignore_comments,
+        )) {
+            log::error!("Failed to compare public symbols: {err}");
+        }
+    }
 
-                            // Update xref list component
-                            self.xref_to_list.update_index_list(xref_list);
-                            // Switch to xref tab
-                            self.bottom_panel_selected_tab = BottomPanelTab::XRefsTo;
-                        }
-                    }
+    /// Function invoked on 'Save' or when the Ctrl+S shortcut is used
+    fn start_save_reconstruted_content(&self, ctx: &egui::Context) {
+        if let ResymAppMode::Browsing(_, _, ref reconstructed_type) = self.current_mode {
+            let export_result = platform::export_text(
+                ctx,
+                tr("Save content to file"),
+                &platform::FileFilter {
+                    patterns: &["*.c", "*.cc", "*.cpp", "*.cxx", "*.h", "*.hpp", "*.hxx"],
+                    description: "C/C++ Source File (*.c;*.cc;*.cpp;*.cxx;*.h;*.hpp;*.hxx)",
+                },
+                reconstructed_type,
+            );
+            match export_result {
+                Ok(Some(destination)) => {
+                    log::info!("Reconstructed content has been exported to {destination}.");
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    log::error!("Failed to write reconstructed content to file: {err}");
                 }
             }
         }
     }
 
-    #[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
-    fn update_menu_bar(&mut self, ui: &mut egui::Ui) {
-        egui::menu::bar(ui, |ui| {
-            ui.menu_button("File", |ui| {
-                if ui.button("Open PDB file (Ctrl+O)").clicked() {
-                    ui.close_menu();
-                    self.start_open_pdb_file(ResymPDBSlots::Main as usize);
-                }
+    /// Function invoked on 'Copy as patch', in "Comparing" mode
+    fn copy_diff_as_patch(&self, ctx: &egui::Context) {
+        if let Some(patch) = self.format_diff(DiffExportFormat::UnifiedPatch) {
+            ctx.output_mut(|output| output.copied_text = patch);
+        }
+    }
 
-                #[cfg(feature = "http")]
-                if ui.button("Open URL (Ctrl+L)").clicked() {
-                    ui.close_menu();
-                    self.open_url.open(ResymPDBSlots::Main);
-                }
+    /// Function invoked on 'Save', in "Comparing" mode
+    fn start_save_diff(&self, ctx: &egui::Context, format: DiffExportFormat) {
+        let Some(content) = self.format_diff(format) else {
+            return;
+        };
+        let filter = match format {
+            DiffExportFormat::UnifiedPatch => platform::FileFilter {
+                patterns: &["*.patch", "*.diff"],
+                description: "Patch File (*.patch;*.diff)",
+            },
+            DiffExportFormat::SideBySide => platform::FileFilter {
+                patterns: &["*.txt"],
+                description: "Text File (*.txt)",
+            },
+        };
+        match platform::export_text(ctx, tr("Save diff to file"), &filter, &content) {
+            Ok(Some(destination)) => {
+                log::info!("Diff has been exported to {destination}.");
+            }
+            Ok(None) => {}
+            Err(err) => {
+                log::error!("Failed to write diff to file: {err}");
+            }
+        }
+    }
 
-                // Separate "Open" from "Compare"
-                ui.separator();
+    /// Format the diff displayed in "Comparing" mode, if any
+    fn format_diff(&self, format: DiffExportFormat) -> Option<String> {
+        let ResymAppMode::Comparing(_, _, _, ref line_changes, ref diff_data) = self.current_mode
+        else {
+            return None;
+        };
 
-                if ui
-                    .add_enabled(
-                        matches!(self.current_mode, ResymAppMode::Browsing(..)),
-                        egui::Button::new("Compare with file ..."),
-                    )
-                    .clicked()
-                {
-                    ui.close_menu();
-                    self.start_open_pdb_file(ResymPDBSlots::Diff as usize);
-                }
+        let changes: Vec<DiffChange> = line_changes.iter().map(|(change, _)| *change).collect();
+        Some(match format {
+            DiffExportFormat::UnifiedPatch => {
+                let (main_pdb_name, diff_pdb_name) = self.status_bar.pdb_names();
+                format_unified_patch(
+                    &changes,
+                    diff_data,
+                    &format!("a/{}", main_pdb_name.unwrap_or_default()),
+                    &format!("b/{}", diff_pdb_name.unwrap_or_default()),
+                )
+            }
+            DiffExportFormat::SideBySide => format_side_by_side_diff(&changes, diff_data),
+        })
+    }
 
-                #[cfg(feature = "http")]
-                if ui
-                    .add_enabled(
-                        matches!(self.current_mode, ResymAppMode::Browsing(..)),
-                        egui::Button::new("Compare with URL ..."),
-                    )
-                    .clicked()
-                {
-                    ui.close_menu();
-                    self.open_url.open(ResymPDBSlots::Diff);
-                }
+    /// Function invoked on 'Export selected types'
+    fn export_selected_types(&self) {
+        let type_indices: Vec<TypeIndex> = self
+            .type_list
+            .selected_elements()
+            .map(|(_, type_index)| type_index)
+            .collect();
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::ReconstructTypesByIndex(
+                ResymPDBSlots::Main as usize,
+                type_indices,
+                self.view_settings.primitive_types_flavor,
+                self.settings.app_settings.print_header,
+                self.view_settings.reconstruct_dependencies,
+                self.view_settings.print_access_specifiers,
+                self.settings.app_settings.ignore_std_types,
+            ))
+        {
+            log::error!("Failed to reconstruct the selected types: {err}");
+        }
+    }
 
-                // Separate "Compare" from "Settings"
-                ui.separator();
+    fn save_reconstructed_types(&self, ctx: &egui::Context, reconstructed_types: &str) {
+        let export_result = platform::export_text(
+            ctx,
+            tr("Save selected types to file"),
+            &platform::FileFilter {
+                patterns: &["*.c", "*.cc", "*.cpp", "*.cxx", "*.h", "*.hpp", "*.hxx"],
+                description: "C/C++ Source File (*.c;*.cc;*.cpp;*.cxx;*.h;*.hpp;*.hxx)",
+            },
+            reconstructed_types,
+        );
+        match export_result {
+            Ok(Some(destination)) => {
+                log::info!("Selected types have been exported to {destination}.");
+            }
+            Ok(None) => {}
+            Err(err) => {
+                log::error!("Failed to write selected types to file: {err}");
+            }
+        }
+    }
 
-                if ui.button("Settings").clicked() {
-                    ui.close_menu();
-                    self.settings.open();
-                }
-                #[cfg(not(target_arch = "wasm32"))]
-                if ui.button("Exit").clicked() {
-                    ui.close_menu();
-                    ui.ctx().send_viewport_cmd(egui::ViewportCommand::Close);
-                }
-            });
-        });
+    /// Function invoked on 'Export dependency graph'. Exports the graph of the
+    /// selected type if any, or the graph of the whole PDB otherwise.
+    fn export_dependency_graph(&self) {
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::ExportTypeDependencyGraph(
+                ResymPDBSlots::Main as usize,
+                self.selected_type_index,
+                self.settings.app_settings.ignore_std_types,
+            ))
+        {
+            log::error!("Failed to export dependency graph: {err}");
+        }
     }
 
-    /// Function invoked on `Open PDB File` or when the Ctrl+O shortcut is used
-    #[cfg(not(target_arch = "wasm32"))]
-    fn start_open_pdb_file(&mut self, pdb_slot: PDBSlot) {
-        let file_path_opt = tinyfiledialogs::open_file_dialog(
-            "Select a PDB file",
-            "",
-            Some((&["*.pdb"], "PDB files (*.pdb)")),
+    fn save_dependency_graph(&self, ctx: &egui::Context, dependency_graph: &str) {
+        let export_result = platform::export_text(
+            ctx,
+            tr("Save dependency graph to file"),
+            &platform::FileFilter {
+                patterns: &["*.dot", "*.gv"],
+                description: "Graphviz DOT File (*.dot;*.gv)",
+            },
+            dependency_graph,
         );
-        if let Some(file_path) = file_path_opt {
-            if let Err(err) = self
-                .backend
-                .send_command(BackendCommand::LoadPDBFromPath(pdb_slot, file_path.into()))
-            {
-                log::error!("Failed to load the PDB file: {err}");
+        match export_result {
+            Ok(Some(destination)) => {
+                log::info!("Dependency graph has been exported to {destination}.");
+            }
+            Ok(None) => {}
+            Err(err) => {
+                log::error!("Failed to write dependency graph to file: {err}");
             }
         }
     }
 
-    #[cfg(target_arch = "wasm32")]
-    fn start_open_pdb_file(&mut self, pdb_slot: PDBSlot) {
-        let open_pdb_data = self.open_pdb_data.clone();
-        wasm_bindgen_futures::spawn_local(async move {
-            let file_opt = rfd::AsyncFileDialog::new()
-                .add_filter("PDB files (*.pdb)", &["pdb"])
-                .pick_file()
-                .await;
-            if let Some(file) = file_opt {
-                // We unwrap() the return value to assert that we are not expecting
-                // threads to ever fail while holding the lock.
-                *open_pdb_data.borrow_mut() = Some((pdb_slot, file.file_name(), file.read().await));
-            }
-        });
+    /// Function invoked on 'Export all enumerations'
+    fn export_all_enums(&self) {
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::ReconstructAllEnums(
+                ResymPDBSlots::Main as usize,
+                self.view_settings.primitive_types_flavor,
+                self.settings.app_settings.print_header,
+                self.settings.app_settings.ignore_std_types,
+            ))
+        {
+            log::error!("Failed to reconstruct enumerations: {err}");
+        }
     }
 
-    #[cfg(target_arch = "wasm32")]
-    fn process_open_pdb_file_result(&self) {
-        // We unwrap() the return value to assert that we are not expecting
-        // threads to ever fail while holding the lock.
-        if let Some((pdb_slot, pdb_name, pdb_bytes)) = self.open_pdb_data.borrow_mut().take() {
-            if let Err(err) = self.backend.send_command(BackendCommand::LoadPDBFromVec(
-                pdb_slot, pdb_name, pdb_bytes,
-            )) {
-                log::error!("Failed to load the PDB file: {err}");
+    fn save_reconstructed_enums(&self, ctx: &egui::Context, reconstructed_enums: &str) {
+        let export_result = platform::export_text(
+            ctx,
+            tr("Save enumerations to file"),
+            &platform::FileFilter {
+                patterns: &["*.h", "*.hpp", "*.hxx"],
+                description: "C/C++ Header File (*.h;*.hpp;*.hxx)",
+            },
+            reconstructed_enums,
+        );
+        match export_result {
+            Ok(Some(destination)) => {
+                log::info!("Enumerations have been exported to {destination}.");
+            }
+            Ok(None) => {}
+            Err(err) => {
+                log::error!("Failed to write enumerations to file: {err}");
             }
         }
     }
 
-    /// Function invoked on 'Find XRefs to'
-    fn list_xrefs_for_type(&self, type_index: TypeIndex) {
-        log::info!(
-            "Looking for cross-references for type #0x{:x}...",
-            type_index
-        );
+    /// Function invoked on 'Export layout assertions'. Exports assertions for
+    /// the selected types if any, or for all types otherwise.
+    fn export_layout_assertions(&self) {
+        let type_names: Vec<String> = self
+            .type_list
+            .selected_elements()
+            .map(|(type_name, _)| type_name.to_string())
+            .collect();
         if let Err(err) = self
             .backend
-            .send_command(BackendCommand::ListTypeCrossReferences(
+            .send_command(BackendCommand::ExportLayoutAssertions(
                 ResymPDBSlots::Main as usize,
-                type_index,
+                (!type_names.is_empty()).then_some(type_names),
+                self.settings.app_settings.ignore_std_types,
             ))
         {
-            log::error!(
-                "Failed to list cross-references to type #0x{:x}: {err}",
-                type_index
-            );
+            log::error!("Failed to export layout assertions: {err}");
         }
     }
 
-    /// Function invoked on 'Save' or when the Ctrl+S shortcut is used
-    #[cfg(not(target_arch = "wasm32"))]
-    fn start_save_reconstruted_content(&self) {
-        if let ResymAppMode::Browsing(_, _, ref reconstructed_type) = self.current_mode {
-            let file_path_opt = tinyfiledialogs::save_file_dialog_with_filter(
-                "Save content to file",
-                "",
-                &["*.c", "*.cc", "*.cpp", "*.cxx", "*.h", "*.hpp", "*.hxx"],
-                "C/C++ Source File (*.c;*.cc;*.cpp;*.cxx;*.h;*.hpp;*.hxx)",
-            );
-            if let Some(file_path) = file_path_opt {
-                let write_result = std::fs::write(&file_path, reconstructed_type);
-                match write_result {
-                    Ok(()) => log::info!("Reconstructed content has been saved to '{file_path}'."),
-                    Err(err) => {
-                        log::error!("Failed to write reconstructed content to file: {err}");
-                    }
-                }
+    fn save_layout_assertions(&self, ctx: &egui::Context, layout_assertions: &str) {
+        let export_result = platform::export_text(
+            ctx,
+            tr("Save layout assertions to file"),
+            &platform::FileFilter {
+                patterns: &["*.cc", "*.cpp", "*.cxx"],
+                description: "C++ Source File (*.cc;*.cpp;*.cxx)",
+            },
+            layout_assertions,
+        );
+        match export_result {
+            Ok(Some(destination)) => {
+                log::info!("Layout assertions have been exported to {destination}.");
+            }
+            Ok(None) => {}
+            Err(err) => {
+                log::error!("Failed to write layout assertions to file: {err}");
+            }
+        }
+    }
+
+    #[cfg(feature = "plugins")]
+    fn export_with_plugin(&self, ctx: &egui::Context, export_format_id: usize) {
+        let ResymAppMode::Browsing(_, _, ref reconstructed_type) = self.current_mode else {
+            return;
+        };
+        let export_format = &self.plugin_export_formats[export_format_id];
+        let exported_content = match self
+            .plugin_manager
+            .export(export_format, reconstructed_type)
+        {
+            Ok(exported_content) => exported_content,
+            Err(err) => {
+                log::error!("Failed to export content: {err}");
+                return;
+            }
+        };
+
+        let pattern = format!("*.{}", export_format.extension);
+        let description = format!("{} (*.{})", export_format.name, export_format.extension);
+        let export_result = platform::export_text(
+            ctx,
+            tr("Save content to file"),
+            &platform::FileFilter {
+                patterns: &[&pattern],
+                description: &description,
+            },
+            &exported_content,
+        );
+        match export_result {
+            Ok(Some(destination)) => {
+                log::info!("Content has been exported to {destination}.");
             }
+            Ok(None) => {}
+            Err(err) => {
+                log::error!("Failed to write exported content to file: {err}");
+            }
+        }
+    }
+
+    #[cfg(feature = "plugins")]
+    fn run_plugin_menu_command(&self, menu_command_id: usize) {
+        let code = match self.current_mode {
+            ResymAppMode::Browsing(_, _, ref reconstructed_type) => reconstructed_type.as_str(),
+            _ => "",
+        };
+        let menu_command = &self.plugin_menu_commands[menu_command_id];
+        match self.plugin_manager.run_menu_command(menu_command, code) {
+            Ok(Some(message)) => log::info!("{}: {message}", menu_command.label),
+            Ok(None) => {}
+            Err(err) => log::error!("Failed to run plugin command: {err}"),
         }
     }
 
@@ -1100,3 +3630,92 @@ impl ResymApp {
         });
     }
 }
+
+/// Displays the application's panels in the dock area
+struct ResymTabViewer<'a> {
+    app: &'a mut ResymApp,
+}
+
+impl egui_dock::TabViewer for ResymTabViewer<'_> {
+    type Tab = DockTab;
+
+    fn title(&mut self, tab: &mut Self::Tab) -> egui::WidgetText {
+        match tab {
+            DockTab::Code if matches!(self.app.current_mode, ResymAppMode::Comparing(..)) => {
+                tr("Differences").into()
+            }
+            _ => tab.title().into(),
+        }
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, tab: &mut Self::Tab) {
+        match tab {
+            DockTab::Browser => self.app.browser_panel_ui(ui),
+            DockTab::Code => self.app.code_panel_ui(ui),
+            DockTab::Console => self.app.console_panel_ui(ui),
+            DockTab::TypeNotes => self.app.type_notes_panel_ui(ui),
+        }
+    }
+
+    fn closeable(&mut self, tab: &mut Self::Tab) -> bool {
+        // The code view cannot be hidden
+        *tab != DockTab::Code
+    }
+}
+
+/// Command listing the types of both PDBs in comparing mode (or only the ones
+/// that changed, if `changed_types_only` is set)
+fn merged_type_list_command(
+    changed_types_only: bool,
+    search_filter: String,
+    case_insensitive: bool,
+    use_regex: bool,
+    diff_options: DiffOptions,
+) -> BackendCommand {
+    if changed_types_only {
+        BackendCommand::ListChangedTypesMerged(
+            ResymPDBSlots::Main as usize,
+            ResymPDBSlots::Diff as usize,
+            search_filter,
+            case_insensitive,
+            use_regex,
+            diff_options,
+        )
+    } else {
+        BackendCommand::ListTypesMerged(
+            vec![ResymPDBSlots::Main as usize, ResymPDBSlots::Diff as usize],
+            search_filter,
+            case_insensitive,
+            use_regex,
+            diff_options.ignore_std_types,
+        )
+    }
+}
+
+/// Format a count with thousands separators (e.g., "1,234").
+/// Note: English separators are used, regardless of the UI's language.
+fn format_count(count: usize) -> String {
+    let digits = count.to_string();
+    let mut output = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            output.push(',');
+        }
+        output.push(digit);
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_count_thousands_separators() {
+        assert_eq!(format_count(0), "0");
+        assert_eq!(format_count(999), "999");
+        assert_eq!(format_count(1000), "1,000");
+        assert_eq!(format_count(1234567), "1,234,567");
+    }
+}
@@ -1,38 +1,290 @@
-use resym_core::pdb_types::PrimitiveReconstructionFlavor;
+use std::{collections::BTreeMap, ops::RangeInclusive};
+
+use egui_dock::DockState;
+#[cfg(not(target_arch = "wasm32"))]
+use resym_core::clang_format::ClangFormatConfiguration;
+use resym_core::{
+    header_template::DEFAULT_HEADER_TEMPLATE,
+    pdb_file::LoadMode,
+    pdb_types::{
+        BraceStyle, CodeFormatConfiguration, EnumValueOrder, MemberFilter, NumberBase,
+        NumberFormat, PointerAlignment, PrimitiveReconstructionFlavor,
+    },
+};
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    dock::{default_dock_state, DockTab},
+    i18n::Language,
+    ui_components::{parse_address, SearchOptions},
+};
+
+/// Well-known SDK/CRT types users might not want to reconstruct
+const DEFAULT_OPAQUE_TYPES: &str = "^std::\n\
+                                    ^_LIST_ENTRY$ => <windows.h>\n\
+                                    ^_UNICODE_STRING$ => <winternl.h>\n\
+                                    ^HWND__$ => <windows.h>\n";
+
+const DEFAULT_PRIMITIVE_TYPE_MAPPING: &str = "int8_t => i8\n\
+                                              uint8_t => u8\n\
+                                              int16_t => i16\n\
+                                              uint16_t => u16\n\
+                                              int32_t => i32\n\
+                                              uint32_t => u32\n\
+                                              int64_t => i64\n\
+                                              uint64_t => u64\n";
+
+/// Font sizes available to users
+pub const FONT_SIZE_RANGE: RangeInclusive<u16> = 8..=20;
+/// Scale factors of the whole UI available to users
+pub const UI_SCALE_RANGE: RangeInclusive<f32> = 0.5..=3.0;
+
+/// Theme selected by the user
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThemePreference {
+    /// Follow the OS' dark/light preference
+    System,
+    Light,
+    Dark,
+}
+
+/// Tool windows whose visibility is restored at startup
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToolWindow {
+    Demangler,
+    AddressLookup,
+    Performance,
+}
+
 /// This struct represents the persistent settings of the application.
+/// Note: missing fields take their default value, so that settings persisted
+/// by older versions can still be loaded.
 #[derive(Serialize, Deserialize)]
+#[serde(default)]
 pub struct ResymAppSettings {
-    pub use_light_theme: bool,
+    pub theme: ThemePreference,
+    pub language: Language,
+    // Monospace font used in the code view and the console (`None` for
+    // egui's default font)
+    pub code_font: Option<String>,
+    // Font size of the code view
     pub font_size: u16,
+    pub console_font_size: u16,
+    // Scale factor of the whole UI (i.e., zoom factor), on top of the OS' one
+    pub ui_scale: f32,
+    // Offer to reload PDB files when they change on disk
+    pub watch_pdb_files: bool,
+    // Copy the console's content to a rotating log file
+    pub log_to_file: bool,
+    // Resolve type names from PDB files when needed instead of keeping them
+    // in memory (see `LoadMode::ReducedMemory`)
+    pub reduced_memory_mode: bool,
     pub search_case_insensitive: bool,
     pub search_use_regex: bool,
+    pub search_whole_word: bool,
+    // Show undecorated names in the symbol list
+    pub search_undecorate_symbols: bool,
+    // Collapse instantiations of the same template in the type list
+    pub search_group_templates: bool,
     pub enable_syntax_hightlighting: bool,
     #[serde(with = "PrimitiveReconstructionFlavorDef")]
     pub primitive_types_flavor: PrimitiveReconstructionFlavor,
+    // Names of primitive types with the custom flavor, one per line
+    // (`<portable name> => <name>`)
+    pub primitive_type_mapping: String,
     pub print_header: bool,
+    // Template of the header printed before reconstructed content
+    pub header_template: String,
     pub reconstruct_dependencies: bool,
+    // Forward declare referenced types when their definitions aren't printed
+    pub forward_declare_dependencies: bool,
     pub print_access_specifiers: bool,
+    // Omit private and protected members from reconstructed types
+    pub public_members_only: bool,
+    // Omit compiler-generated members and symbols (e.g., `__vecDelDtor`)
+    pub hide_compiler_generated: bool,
+    // Annotate static data members with the RVA of their definition
+    pub print_static_member_rvas: bool,
+    // Emit Doxygen comments with the metadata of types and fields
+    pub doxygen_comments: bool,
+    // Annotate the enumerators of flag enums with their bits
+    pub flag_enum_comments: bool,
+    // Order in which enumerators are printed
+    #[serde(with = "EnumValueOrderDef")]
+    pub enum_value_order: EnumValueOrder,
+    // Print virtual addresses next to RVAs, using `image_base`
+    pub print_virtual_addresses: bool,
+    pub image_base: String,
     // Ignore types in the `std` namespace (e.g., STL-generated types)
     pub ignore_std_types: bool,
+    // Replace matching dependencies with includes or forward declarations
+    pub use_opaque_types: bool,
+    // Rules matching opaque types, one per line (`<regex> [=> <header>]`)
+    pub opaque_types: String,
     pub print_line_numbers: bool,
+    // Soft-wrap long lines in the code view instead of scrolling horizontally
+    pub wrap_code_view_lines: bool,
+    pub format_indent_width: usize,
+    pub format_use_tabs: bool,
+    #[serde(with = "BraceStyleDef")]
+    pub format_brace_style: BraceStyle,
+    #[serde(with = "PointerAlignmentDef")]
+    pub format_pointer_alignment: PointerAlignment,
+    // Wrap lines longer than `format_max_line_width`
+    pub format_wrap_lines: bool,
+    pub format_max_line_width: usize,
+    // Print offsets, sizes and enum values in hexadecimal or decimal
+    #[serde(with = "NumberBaseDef")]
+    pub format_number_base: NumberBase,
+    pub format_zero_padding: bool,
+    // Pipe reconstructed code through `clang-format` before displaying it
+    pub use_clang_format: bool,
+    pub clang_format_path: String,
+    pub clang_format_style: String,
+    // Ignore comment lines (e.g., header) when comparing PDB files
+    pub diff_ignore_comments: bool,
+    // Pair types by their unique (decorated) name when comparing PDB files
+    pub diff_match_unique_names: bool,
+    // Don't report fields that only moved because a previous field changed
+    pub diff_ignore_offset_only_changes: bool,
+    // User-defined type and member names, by PDB GUID (`RenameMap` format)
+    pub renames: BTreeMap<String, String>,
+    pub show_type_notes: bool,
+    // Emit user notes as comment blocks above type definitions
+    pub emit_type_notes: bool,
+    // Layout of the main window, restored at startup
+    pub dock_state: DockState<DockTab>,
+    pub window_size: Option<(f32, f32)>,
+    pub window_position: Option<(f32, f32)>,
+    pub open_tool_windows: Vec<ToolWindow>,
 }
 
 impl Default for ResymAppSettings {
     fn default() -> Self {
         Self {
-            use_light_theme: false,
+            theme: ThemePreference::System,
+            language: Language::English,
+            code_font: None,
             font_size: 14,
+            console_font_size: 12,
+            ui_scale: 1.0,
+            watch_pdb_files: true,
+            log_to_file: false,
+            reduced_memory_mode: false,
             search_case_insensitive: true,
             search_use_regex: false,
+            search_whole_word: false,
+            search_undecorate_symbols: true,
+            search_group_templates: true,
             enable_syntax_hightlighting: true,
             primitive_types_flavor: PrimitiveReconstructionFlavor::Portable,
+            primitive_type_mapping: DEFAULT_PRIMITIVE_TYPE_MAPPING.to_string(),
             print_header: true,
+            header_template: DEFAULT_HEADER_TEMPLATE.to_string(),
             reconstruct_dependencies: true,
+            forward_declare_dependencies: false,
             print_access_specifiers: true,
+            public_members_only: false,
+            hide_compiler_generated: false,
+            print_static_member_rvas: false,
+            doxygen_comments: false,
+            flag_enum_comments: false,
+            enum_value_order: EnumValueOrder::Record,
+            print_virtual_addresses: false,
+            image_base: "0x140000000".to_string(),
             ignore_std_types: true,
+            use_opaque_types: false,
+            opaque_types: DEFAULT_OPAQUE_TYPES.to_string(),
             print_line_numbers: false,
+            wrap_code_view_lines: false,
+            format_indent_width: 2,
+            format_use_tabs: false,
+            format_brace_style: BraceStyle::Attach,
+            format_pointer_alignment: PointerAlignment::Left,
+            format_wrap_lines: false,
+            format_max_line_width: 100,
+            format_number_base: NumberBase::Hexadecimal,
+            format_zero_padding: true,
+            use_clang_format: false,
+            clang_format_path: "clang-format".to_string(),
+            clang_format_style: "LLVM".to_string(),
+            diff_ignore_comments: false,
+            diff_match_unique_names: false,
+            diff_ignore_offset_only_changes: false,
+            renames: BTreeMap::new(),
+            show_type_notes: true,
+            emit_type_notes: false,
+            dock_state: default_dock_state(),
+            window_size: None,
+            window_position: None,
+            open_tool_windows: vec![],
+        }
+    }
+}
+
+impl ResymAppSettings {
+    pub fn search_options(&self) -> SearchOptions {
+        SearchOptions {
+            case_insensitive: self.search_case_insensitive,
+            use_regex: self.search_use_regex,
+            whole_word: self.search_whole_word,
+        }
+    }
+
+    pub fn set_search_options(&mut self, search_options: SearchOptions) {
+        self.search_case_insensitive = search_options.case_insensitive;
+        self.search_use_regex = search_options.use_regex;
+        self.search_whole_word = search_options.whole_word;
+    }
+
+    pub fn code_format(&self) -> CodeFormatConfiguration {
+        CodeFormatConfiguration {
+            indent_width: self.format_indent_width,
+            use_tabs: self.format_use_tabs,
+            brace_style: self.format_brace_style,
+            pointer_alignment: self.format_pointer_alignment,
+            max_line_width: self.format_wrap_lines.then_some(self.format_max_line_width),
+        }
+    }
+
+    pub fn number_format(&self) -> NumberFormat {
+        NumberFormat {
+            base: self.format_number_base,
+            zero_padded: self.format_zero_padding,
+        }
+    }
+
+    /// `clang-format` configuration used to format reconstructed code, if
+    /// enabled
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn clang_format_configuration(&self) -> Option<ClangFormatConfiguration> {
+        self.use_clang_format.then(|| ClangFormatConfiguration {
+            path: self.clang_format_path.clone().into(),
+            style: self.clang_format_style.clone(),
+        })
+    }
+
+    pub fn load_mode(&self) -> LoadMode {
+        if self.reduced_memory_mode {
+            LoadMode::ReducedMemory
+        } else {
+            LoadMode::Default
+        }
+    }
+
+    /// Image base used to print virtual addresses, if enabled and valid
+    pub fn image_base(&self) -> Option<u64> {
+        if self.print_virtual_addresses {
+            parse_address(&self.image_base).ok()
+        } else {
+            None
+        }
+    }
+
+    pub fn member_filter(&self) -> MemberFilter {
+        MemberFilter {
+            public_only: self.public_members_only,
+            hide_compiler_generated: self.hide_compiler_generated,
         }
     }
 }
@@ -42,6 +294,95 @@ impl Default for ResymAppSettings {
 #[serde(remote = "PrimitiveReconstructionFlavor")]
 enum PrimitiveReconstructionFlavorDef {
     Portable,
+    Stdint,
     Microsoft,
     Raw,
+    Custom,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "BraceStyle")]
+enum BraceStyleDef {
+    Attach,
+    Allman,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "PointerAlignment")]
+enum PointerAlignmentDef {
+    Left,
+    Right,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "NumberBase")]
+enum NumberBaseDef {
+    Hexadecimal,
+    Decimal,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "EnumValueOrder")]
+enum EnumValueOrderDef {
+    Record,
+    Value,
+    Name,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settings_from_older_versions_are_loaded() {
+        // Settings persisted before most fields were added
+        let settings: ResymAppSettings = serde_json::from_str(
+            r#"{
+                "use_light_theme": true,
+                "font_size": 16,
+                "search_case_insensitive": false,
+                "search_use_regex": true,
+                "enable_syntax_hightlighting": false,
+                "primitive_types_flavor": "Microsoft",
+                "print_header": false,
+                "reconstruct_dependencies": false,
+                "print_access_specifiers": false,
+                "ignore_std_types": false,
+                "print_line_numbers": true
+            }"#,
+        )
+        .expect("deserialize settings");
+
+        // Persisted values are kept
+        assert_eq!(settings.font_size, 16);
+        assert!(!settings.search_case_insensitive);
+        assert!(settings.search_use_regex);
+        assert!(!settings.enable_syntax_hightlighting);
+        assert_eq!(
+            settings.primitive_types_flavor,
+            PrimitiveReconstructionFlavor::Microsoft
+        );
+        assert!(!settings.print_header);
+        assert!(!settings.reconstruct_dependencies);
+        assert!(!settings.print_access_specifiers);
+        assert!(!settings.ignore_std_types);
+        assert!(settings.print_line_numbers);
+
+        // Missing values take their default
+        let default_settings = ResymAppSettings::default();
+        assert_eq!(settings.theme, default_settings.theme);
+        assert_eq!(
+            settings.console_font_size,
+            default_settings.console_font_size
+        );
+        assert_eq!(settings.header_template, default_settings.header_template);
+        assert_eq!(settings.opaque_types, default_settings.opaque_types);
+        assert_eq!(
+            settings.format_brace_style,
+            default_settings.format_brace_style
+        );
+        assert_eq!(settings.enum_value_order, default_settings.enum_value_order);
+        assert!(settings.renames.is_empty());
+        assert!(settings.open_tool_windows.is_empty());
+    }
 }
@@ -4,9 +4,14 @@ use eframe::{
 };
 use syntect::{easy::HighlightLines, highlighting::FontStyle, util::LinesWithEndings};
 
-use resym_core::{diffing::DiffChange, syntax_highlighting::CodeTheme};
+use resym_core::{
+    diffing::{split_range_by_inline_changes, DiffChange, DiffInlineChanges},
+    syntax_highlighting::CodeTheme,
+};
+
+use crate::fonts;
 
-pub type LineDescriptions = Vec<DiffChange>;
+pub type LineDescriptions = Vec<(DiffChange, DiffInlineChanges)>;
 
 /// Memoized code highlighting
 pub fn highlight_code(
@@ -70,7 +75,7 @@ impl CodeHighlighter {
                 // Fallback:
                 LayoutJob::simple(
                     code.into(),
-                    egui::FontId::monospace(theme.font_size as f32),
+                    fonts::code_font_id(theme.font_size),
                     if theme.dark_mode {
                         egui::Color32::LIGHT_GRAY
                     } else {
@@ -94,6 +99,8 @@ impl CodeHighlighter {
 
         const COLOR_RED: egui::Color32 = egui::Color32::from_rgb(0x50, 0x10, 0x10);
         const COLOR_GREEN: egui::Color32 = egui::Color32::from_rgb(0x10, 0x50, 0x10);
+        const COLOR_RED_EMPHASIZED: egui::Color32 = egui::Color32::from_rgb(0x90, 0x20, 0x20);
+        const COLOR_GREEN_EMPHASIZED: egui::Color32 = egui::Color32::from_rgb(0x20, 0x90, 0x20);
 
         let syntax = self
             .ps
@@ -117,17 +124,25 @@ impl CodeHighlighter {
 
         for (line_id, line) in LinesWithEndings::from(text).enumerate() {
             // Change the background of regions that have been affected in the diff.
-            let bg_color = match line_descriptions {
-                None => egui::Color32::TRANSPARENT,
-                Some(line_desc) => match line_desc.get(line_id) {
-                    None => egui::Color32::TRANSPARENT,
-                    Some(line_desc) => match line_desc {
-                        DiffChange::Insert => COLOR_GREEN,
-                        DiffChange::Delete => COLOR_RED,
-                        DiffChange::Equal => egui::Color32::TRANSPARENT,
-                    },
+            let line_desc = line_descriptions.and_then(|line_desc| line_desc.get(line_id));
+            let (bg_color, bg_color_emphasized) = match line_desc {
+                None => (egui::Color32::TRANSPARENT, egui::Color32::TRANSPARENT),
+                Some((line_change, _)) => match line_change {
+                    DiffChange::Insert => (COLOR_GREEN, COLOR_GREEN_EMPHASIZED),
+                    DiffChange::Delete => (COLOR_RED, COLOR_RED_EMPHASIZED),
+                    DiffChange::Equal => (egui::Color32::TRANSPARENT, egui::Color32::TRANSPARENT),
                 },
             };
+            // Words that changed within the line (as absolute byte ranges)
+            let line_start = as_byte_range(text, line).start;
+            let inline_changes = line_desc
+                .map(|(_, inline_changes)| {
+                    inline_changes
+                        .iter()
+                        .map(|range| (line_start + range.start)..(line_start + range.end))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
 
             for (style, range) in h.highlight_line(line, &self.ps).ok()? {
                 let fg = style.foreground;
@@ -139,18 +154,26 @@ impl CodeHighlighter {
                 } else {
                     egui::Stroke::NONE
                 };
-                job.sections.push(LayoutSection {
-                    leading_space: 0.0,
-                    byte_range: as_byte_range(text, range),
-                    format: TextFormat {
-                        background: bg_color,
-                        font_id: egui::FontId::monospace(theme.font_size as f32),
-                        color: text_color,
-                        italics,
-                        underline,
-                        ..Default::default()
-                    },
-                });
+                for (byte_range, emphasized) in
+                    split_range_by_inline_changes(as_byte_range(text, range), &inline_changes)
+                {
+                    job.sections.push(LayoutSection {
+                        leading_space: 0.0,
+                        byte_range,
+                        format: TextFormat {
+                            background: if emphasized {
+                                bg_color_emphasized
+                            } else {
+                                bg_color
+                            },
+                            font_id: fonts::code_font_id(theme.font_size),
+                            color: text_color,
+                            italics,
+                            underline,
+                            ..Default::default()
+                        },
+                    });
+                }
             }
         }
 
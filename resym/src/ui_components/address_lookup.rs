@@ -0,0 +1,119 @@
+use eframe::egui;
+use resym_core::{
+    backend::{Backend, BackendCommand},
+    pdb_file::SymbolAtAddress,
+};
+
+use crate::{i18n::tr, resym_app::ResymPDBSlots};
+
+pub struct AddressLookupComponent {
+    window_open: bool,
+    address_text: String,
+    image_base_text: String,
+    result_text: Option<Result<String, String>>,
+}
+
+impl AddressLookupComponent {
+    pub fn new() -> Self {
+        Self {
+            window_open: false,
+            address_text: String::default(),
+            image_base_text: String::default(),
+            result_text: None,
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.window_open = true;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.window_open
+    }
+
+    pub fn set_result(&mut self, result: resym_core::Result<SymbolAtAddress>) {
+        self.result_text = Some(
+            result
+                .map(|symbol| format_symbol_at_address(&symbol))
+                .map_err(|err| err.to_string()),
+        );
+    }
+
+    pub fn update(&mut self, ctx: &egui::Context, backend: &Backend) {
+        let mut window_open = self.window_open;
+        egui::Window::new(tr("Look up address"))
+            .anchor(egui::Align2::CENTER_CENTER, [0.0; 2])
+            .open(&mut window_open)
+            .auto_sized()
+            .collapsible(false)
+            .show(ctx, |ui| {
+                ui.label(tr("Address (RVA, or VA if an image base is given):"));
+                ui.text_edit_singleline(&mut self.address_text);
+                ui.label(tr("Image base (optional):"));
+                ui.text_edit_singleline(&mut self.image_base_text);
+                if ui.button(tr("Look up")).clicked() {
+                    match self.parse_rva() {
+                        Ok(rva) => {
+                            self.result_text = None;
+                            if let Err(err) =
+                                backend.send_command(BackendCommand::LookupSymbolByRVA(
+                                    ResymPDBSlots::Main as usize,
+                                    rva,
+                                ))
+                            {
+                                log::error!("Failed to look up address: {err}");
+                            }
+                        }
+                        Err(err) => self.result_text = Some(Err(err)),
+                    }
+                }
+
+                ui.add_space(4.0);
+                match &self.result_text {
+                    Some(Ok(symbol_text)) => {
+                        ui.monospace(symbol_text);
+                    }
+                    Some(Err(err)) => {
+                        ui.label(egui::RichText::new(err).color(ui.style().visuals.error_fg_color));
+                    }
+                    None => {}
+                }
+            });
+        self.window_open = window_open;
+    }
+
+    fn parse_rva(&self) -> Result<u32, String> {
+        let address = parse_address(&self.address_text)?;
+        let image_base = if self.image_base_text.trim().is_empty() {
+            0
+        } else {
+            parse_address(&self.image_base_text)?
+        };
+
+        address
+            .checked_sub(image_base)
+            .and_then(|rva| u32::try_from(rva).ok())
+            .ok_or_else(|| tr("Address is out of the image's range").to_string())
+    }
+}
+
+/// Parse an hexadecimal (if prefixed with `0x`) or decimal address
+pub fn parse_address(text: &str) -> Result<u64, String> {
+    let text = text.trim();
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex_text) => u64::from_str_radix(hex_text, 16),
+        None => text.parse(),
+    }
+    .map_err(|_| format!("{}: '{text}'", tr("Invalid address")))
+}
+
+fn format_symbol_at_address(symbol: &SymbolAtAddress) -> String {
+    if symbol.offset == 0 {
+        format!("{} (RVA=0x{:x})", symbol.name, symbol.rva)
+    } else {
+        format!(
+            "{}+0x{:x} (RVA=0x{:x})",
+            symbol.name, symbol.offset, symbol.rva
+        )
+    }
+}
@@ -1,23 +1,60 @@
 use eframe::egui;
 use resym_core::syntax_highlighting::CodeTheme;
 
-use crate::{mode::ResymAppMode, settings::ResymAppSettings, syntax_highlighting::highlight_code};
+use crate::{
+    accessibility, fonts,
+    i18n::tr,
+    mode::ResymAppMode,
+    settings::{ResymAppSettings, FONT_SIZE_RANGE},
+    syntax_highlighting::highlight_code,
+};
 
-pub struct CodeViewComponent {}
+/// Zoom factor to reach before changing the font size by one step
+const ZOOM_STEP: f32 = 1.1;
+
+pub struct CodeViewComponent {
+    /// Zoom accumulated (e.g., with Ctrl+mouse wheel) since the font size
+    /// last changed
+    pending_zoom: f32,
+    /// Whether the code had the keyboard focus during the last frame
+    has_focus: bool,
+    focus_requested: bool,
+}
 
 impl CodeViewComponent {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            pending_zoom: 1.0,
+            has_focus: false,
+            focus_requested: false,
+        }
+    }
+
+    pub fn has_focus(&self) -> bool {
+        self.has_focus
+    }
+
+    /// Give the keyboard focus to the code during the next update, to
+    /// navigate and select it with the keyboard
+    pub fn request_focus(&mut self) {
+        self.focus_requested = true;
     }
 
     pub fn update(
         &mut self,
-        app_settings: &ResymAppSettings,
+        app_settings: &mut ResymAppSettings,
         current_mode: &ResymAppMode,
         ui: &mut egui::Ui,
     ) {
+        // Ctrl+mouse wheel (or pinch) over the code view adjusts the font size
+        if ui.rect_contains_pointer(ui.max_rect()) {
+            self.update_zoom(app_settings, ui.input(|input| input.zoom_delta()));
+        }
+        let app_settings = &*app_settings;
+
         const LANGUAGE_SYNTAX: &str = "cpp";
-        let theme = if app_settings.use_light_theme {
+        // Note: follow the actual visuals, which might come from the OS' theme
+        let theme = if !ui.visuals().dark_mode {
             CodeTheme::light(app_settings.font_size, LANGUAGE_SYNTAX.to_string())
         } else {
             CodeTheme::dark(app_settings.font_size, LANGUAGE_SYNTAX.to_string())
@@ -29,116 +66,159 @@ impl CodeViewComponent {
             None
         };
 
-        // Layouter that'll disable wrapping and apply syntax highlighting if needed
-        let mut layouter = |ui: &egui::Ui, string: &str, _wrap_width: f32| {
-            let layout_job = highlight_code(
+        // Layouter that'll wrap lines (or not) and apply syntax highlighting if needed
+        let wrap_lines = app_settings.wrap_code_view_lines;
+        let mut layouter = |ui: &egui::Ui, string: &str, wrap_width: f32| {
+            let mut layout_job = highlight_code(
                 ui.ctx(),
                 &theme,
                 string,
                 app_settings.enable_syntax_hightlighting,
                 line_desc,
             );
+            if wrap_lines {
+                layout_job.wrap.max_width = wrap_width;
+            }
             ui.fonts(|fonts| fonts.layout_job(layout_job))
         };
+        // Long lines only have to be scrolled horizontally when they're not wrapped
+        let scroll_area = if wrap_lines {
+            egui::ScrollArea::vertical()
+        } else {
+            egui::ScrollArea::both()
+        };
+        // Wrapped lines fill the available width
+        let code_width = if wrap_lines {
+            f32::INFINITY
+        } else {
+            ui.spacing().text_edit_width
+        };
 
         // Type dump area
-        egui::ScrollArea::both()
-            .auto_shrink([false, false])
-            .show(ui, |ui| {
-                // TODO(ergrelet): see if there's a better way to compute this width.
-                let line_number_digit_width = 2 + app_settings.font_size as u32;
-                let (num_colums, min_column_width) = if app_settings.print_line_numbers {
-                    match current_mode {
-                        ResymAppMode::Comparing(_, _, last_line_number, ..) => {
-                            // Compute the columns' sizes from the number of digits
-                            let char_count = last_line_number.checked_ilog10().unwrap_or(1) + 1;
-                            let line_number_width = (char_count * line_number_digit_width) as f32;
+        let mut code_response: Option<egui::Response> = None;
+        scroll_area.auto_shrink([false, false]).show(ui, |ui| {
+            // TODO(ergrelet): see if there's a better way to compute this width.
+            let line_number_digit_width = 2 + app_settings.font_size as u32;
+            let (num_colums, min_column_width) = if app_settings.print_line_numbers {
+                match current_mode {
+                    ResymAppMode::Comparing(_, _, last_line_number, ..) => {
+                        // Compute the columns' sizes from the number of digits
+                        let char_count = last_line_number.checked_ilog10().unwrap_or(1) + 1;
+                        let line_number_width = (char_count * line_number_digit_width) as f32;
 
-                            // Old index + new index + code editor
-                            (3, line_number_width)
-                        }
-                        ResymAppMode::Browsing(_, last_line_number, _) => {
-                            // Compute the columns' sizes from the number of digits
-                            let char_count = last_line_number.checked_ilog10().unwrap_or(1) + 1;
-                            let line_number_width = (char_count * line_number_digit_width) as f32;
+                        // Old index + new index + code editor
+                        (3, line_number_width)
+                    }
+                    ResymAppMode::Browsing(_, last_line_number, _) => {
+                        // Compute the columns' sizes from the number of digits
+                        let char_count = last_line_number.checked_ilog10().unwrap_or(1) + 1;
+                        let line_number_width = (char_count * line_number_digit_width) as f32;
 
-                            // Line numbers + code editor
-                            (2, line_number_width)
-                        }
-                        _ => {
-                            // Code editor only
-                            (1, 0.0)
-                        }
+                        // Line numbers + code editor
+                        (2, line_number_width)
                     }
-                } else {
-                    // Code editor only
-                    (1, 0.0)
-                };
-
-                egui::Grid::new("code_editor_grid")
-                    .num_columns(num_colums)
-                    .min_col_width(min_column_width)
-                    .show(ui, |ui| {
-                        match current_mode {
-                            ResymAppMode::Comparing(
-                                line_numbers_old,
-                                line_numbers_new,
-                                _,
-                                _,
-                                reconstructed_type_diff,
-                            ) => {
-                                // Line numbers
-                                if app_settings.print_line_numbers {
-                                    ui.add(
-                                        egui::TextEdit::multiline(&mut line_numbers_old.as_str())
-                                            .font(egui::FontId::monospace(
-                                                app_settings.font_size as f32,
-                                            ))
-                                            .interactive(false)
-                                            .desired_width(min_column_width),
-                                    );
-                                    ui.add(
-                                        egui::TextEdit::multiline(&mut line_numbers_new.as_str())
-                                            .font(egui::FontId::monospace(
-                                                app_settings.font_size as f32,
-                                            ))
-                                            .interactive(false)
-                                            .desired_width(min_column_width),
-                                    );
-                                }
-                                // Text content
-                                ui.add(
-                                    egui::TextEdit::multiline(
-                                        &mut reconstructed_type_diff.as_str(),
-                                    )
-                                    .code_editor()
-                                    .layouter(&mut layouter),
+                    _ => {
+                        // Code editor only
+                        (1, 0.0)
+                    }
+                }
+            } else {
+                // Code editor only
+                (1, 0.0)
+            };
+
+            egui::Grid::new("code_editor_grid")
+                .num_columns(num_colums)
+                .min_col_width(min_column_width)
+                .show(ui, |ui| {
+                    match current_mode {
+                        ResymAppMode::Comparing(
+                            line_numbers_old,
+                            line_numbers_new,
+                            _,
+                            _,
+                            reconstructed_type_diff,
+                        ) => {
+                            // Line numbers
+                            if app_settings.print_line_numbers {
+                                let response = ui.add(
+                                    egui::TextEdit::multiline(&mut line_numbers_old.as_str())
+                                        .font(fonts::code_font_id(app_settings.font_size))
+                                        .interactive(false)
+                                        .desired_width(min_column_width),
+                                );
+                                accessibility::set_read_only_text(
+                                    &response,
+                                    tr("Old line numbers"),
+                                );
+                                let response = ui.add(
+                                    egui::TextEdit::multiline(&mut line_numbers_new.as_str())
+                                        .font(fonts::code_font_id(app_settings.font_size))
+                                        .interactive(false)
+                                        .desired_width(min_column_width),
+                                );
+                                accessibility::set_read_only_text(
+                                    &response,
+                                    tr("New line numbers"),
                                 );
                             }
-                            ResymAppMode::Browsing(line_numbers, _, reconstructed_type_content) => {
-                                // Line numbers
-                                if app_settings.print_line_numbers {
-                                    ui.add(
-                                        egui::TextEdit::multiline(&mut line_numbers.as_str())
-                                            .font(egui::FontId::monospace(
-                                                app_settings.font_size as f32,
-                                            ))
-                                            .interactive(false)
-                                            .desired_width(min_column_width),
-                                    );
-                                }
-                                // Text content
-                                ui.add(
-                                    egui::TextEdit::multiline(
-                                        &mut reconstructed_type_content.as_str(),
-                                    )
+                            // Text content
+                            let response = ui.add(
+                                egui::TextEdit::multiline(&mut reconstructed_type_diff.as_str())
                                     .code_editor()
+                                    .desired_width(code_width)
                                     .layouter(&mut layouter),
+                            );
+                            accessibility::set_read_only_text(&response, tr("Type differences"));
+                            code_response = Some(response);
+                        }
+                        ResymAppMode::Browsing(line_numbers, _, reconstructed_type_content) => {
+                            // Line numbers
+                            if app_settings.print_line_numbers {
+                                let response = ui.add(
+                                    egui::TextEdit::multiline(&mut line_numbers.as_str())
+                                        .font(fonts::code_font_id(app_settings.font_size))
+                                        .interactive(false)
+                                        .desired_width(min_column_width),
                                 );
+                                accessibility::set_read_only_text(&response, tr("Line numbers"));
                             }
-                            ResymAppMode::Idle => {}
+                            // Text content
+                            let response = ui.add(
+                                egui::TextEdit::multiline(&mut reconstructed_type_content.as_str())
+                                    .code_editor()
+                                    .desired_width(code_width)
+                                    .layouter(&mut layouter),
+                            );
+                            accessibility::set_read_only_text(&response, tr("Reconstructed type"));
+                            code_response = Some(response);
                         }
-                    });
-            });
+                        ResymAppMode::Idle => {}
+                    }
+                });
+        });
+
+        self.has_focus = false;
+        if let Some(code_response) = code_response {
+            if std::mem::take(&mut self.focus_requested) {
+                code_response.request_focus();
+            }
+            self.has_focus = code_response.has_focus();
+        }
+    }
+
+    /// Accumulate the given zoom factor and update the font size with it
+    fn update_zoom(&mut self, app_settings: &mut ResymAppSettings, zoom_delta: f32) {
+        self.pending_zoom *= zoom_delta;
+        let font_size = if self.pending_zoom >= ZOOM_STEP {
+            app_settings.font_size.saturating_add(1)
+        } else if self.pending_zoom <= 1.0 / ZOOM_STEP {
+            app_settings.font_size.saturating_sub(1)
+        } else {
+            return;
+        };
+
+        self.pending_zoom = 1.0;
+        app_settings.font_size = font_size.clamp(*FONT_SIZE_RANGE.start(), *FONT_SIZE_RANGE.end());
     }
 }
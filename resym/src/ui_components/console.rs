@@ -1,6 +1,8 @@
-use eframe::egui::{self, ScrollArea, TextStyle};
+use eframe::egui::{self, ScrollArea};
 use memory_logger::blocking::MemoryLogger;
 
+use crate::fonts;
+
 pub struct ConsoleComponent {
     logger: &'static MemoryLogger,
     content: Vec<String>,
@@ -14,14 +16,20 @@ impl ConsoleComponent {
         }
     }
 
-    pub fn update(&mut self, ui: &mut egui::Ui) {
-        // Update console content
+    /// Move the lines logged since the last call into the console, and
+    /// return them. Called on every frame, even when the console is hidden.
+    pub fn collect_logs(&mut self) -> &[String] {
+        let first_new_line = self.content.len();
         self.content
             .extend(self.logger.read().lines().map(|s| s.to_string()));
         self.logger.clear();
 
-        const TEXT_STYLE: TextStyle = TextStyle::Monospace;
-        let row_height = ui.text_style_height(&TEXT_STYLE);
+        &self.content[first_new_line..]
+    }
+
+    pub fn update(&mut self, ui: &mut egui::Ui, font_size: u16) {
+        let font_id = fonts::code_font_id(font_size);
+        let row_height = ui.fonts(|fonts| fonts.row_height(&font_id));
         let num_rows = self.content.len();
         ScrollArea::both().stick_to_bottom(true).show_rows(
             ui,
@@ -31,7 +39,7 @@ impl ConsoleComponent {
                 for row_index in row_range {
                     ui.add(
                         egui::TextEdit::singleline(&mut self.content[row_index].as_str())
-                            .font(TEXT_STYLE)
+                            .font(font_id.clone())
                             .clip_text(false)
                             .desired_width(f32::INFINITY),
                     );
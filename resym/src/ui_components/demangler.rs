@@ -0,0 +1,64 @@
+use eframe::egui;
+use resym_core::pdb_file::demangle_symbol_name;
+
+use crate::i18n::tr;
+
+pub struct DemanglerComponent {
+    window_open: bool,
+    decorated_name: String,
+    demangled_name: Option<String>,
+}
+
+impl DemanglerComponent {
+    pub fn new() -> Self {
+        Self {
+            window_open: false,
+            decorated_name: String::default(),
+            demangled_name: None,
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.window_open = true;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.window_open
+    }
+
+    pub fn update(&mut self, ctx: &egui::Context) {
+        egui::Window::new(tr("Demangle symbol name"))
+            .anchor(egui::Align2::CENTER_CENTER, [0.0; 2])
+            .open(&mut self.window_open)
+            .auto_sized()
+            .collapsible(false)
+            .show(ctx, |ui| {
+                ui.label(tr("Decorated name:"));
+                if ui.text_edit_singleline(&mut self.decorated_name).changed() {
+                    self.demangled_name = demangle_symbol_name(self.decorated_name.trim(), true);
+                }
+
+                ui.add_space(4.0);
+                ui.label(tr("Demangled name:"));
+                match &self.demangled_name {
+                    Some(demangled_name) => {
+                        ui.horizontal(|ui| {
+                            ui.monospace(demangled_name);
+                            if ui.button(tr("Copy")).clicked() {
+                                ui.output_mut(|output| {
+                                    output.copied_text = demangled_name.clone();
+                                });
+                            }
+                        });
+                    }
+                    None if self.decorated_name.trim().is_empty() => {}
+                    None => {
+                        ui.label(
+                            egui::RichText::new(tr("Invalid decorated name"))
+                                .color(ui.style().visuals.error_fg_color),
+                        );
+                    }
+                }
+            });
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::{HashMap, VecDeque};
+
+use eframe::egui::{self, ScrollArea};
+use resym_core::pdb_file::{TypeDependencyGraph, TypeIndex};
+
+use crate::i18n::tr;
+
+const NODE_SIZE: egui::Vec2 = egui::vec2(180.0, 24.0);
+const NODE_SPACING: egui::Vec2 = egui::vec2(20.0, 48.0);
+/// Maximum number of characters displayed in a node
+const NODE_LABEL_MAX_LENGTH: usize = 24;
+
+pub struct DependencyGraphComponent {
+    window_open: bool,
+    root_type_index: Option<TypeIndex>,
+    graph_layout: Option<Result<GraphLayout, String>>,
+}
+
+struct GraphLayout {
+    nodes: Vec<NodeLayout>,
+    /// Edges between nodes (indices into `nodes`)
+    edges: Vec<(usize, usize, bool)>,
+    size: egui::Vec2,
+}
+
+struct NodeLayout {
+    type_name: String,
+    type_index: TypeIndex,
+    label: String,
+    /// Position of the node, relative to the graph's origin
+    rect: egui::Rect,
+}
+
+impl DependencyGraphComponent {
+    pub fn new() -> Self {
+        Self {
+            window_open: false,
+            root_type_index: None,
+            graph_layout: None,
+        }
+    }
+
+    pub fn open(&mut self, root_type_index: TypeIndex) {
+        self.window_open = true;
+        self.root_type_index = Some(root_type_index);
+        self.graph_layout = None;
+    }
+
+    pub fn set_graph(&mut self, result: resym_core::Result<TypeDependencyGraph>) {
+        self.graph_layout = Some(
+            result
+                .map(|graph| GraphLayout::new(&graph, self.root_type_index))
+                .map_err(|err| err.to_string()),
+        );
+    }
+
+    pub fn update<CB: FnMut(&str, TypeIndex)>(
+        &mut self,
+        ctx: &egui::Context,
+        on_type_selected: &mut CB,
+    ) {
+        egui::Window::new(tr("Dependency graph"))
+            .open(&mut self.window_open)
+            .default_size([640.0, 480.0])
+            .collapsible(false)
+            .show(ctx, |ui| match &self.graph_layout {
+                None => {
+                    ui.spinner();
+                }
+                Some(Err(err)) => {
+                    ui.label(egui::RichText::new(err).color(ui.style().visuals.error_fg_color));
+                }
+                Some(Ok(graph_layout)) => {
+                    ScrollArea::both()
+                        .auto_shrink([false, false])
+                        .show(ui, |ui| {
+                            let (response, painter) =
+                                ui.allocate_painter(graph_layout.size, egui::Sense::hover());
+                            let offset = response.rect.min.to_vec2();
+
+                            // Draw edges first, so that nodes are drawn on top of them
+                            let stroke = ui.visuals().widgets.noninteractive.fg_stroke;
+                            for (from, to, is_pointer) in &graph_layout.edges {
+                                let from_pos =
+                                    graph_layout.nodes[*from].rect.center_bottom() + offset;
+                                let to_pos = graph_layout.nodes[*to].rect.center_top() + offset;
+                                if *is_pointer {
+                                    // Pointers only need forward declarations
+                                    painter.extend(egui::Shape::dashed_line(
+                                        &[from_pos, to_pos],
+                                        stroke,
+                                        4.0,
+                                        4.0,
+                                    ));
+                                } else {
+                                    painter.arrow(from_pos, to_pos - from_pos, stroke);
+                                }
+                            }
+
+                            for node in &graph_layout.nodes {
+                                let is_root = Some(node.type_index) == self.root_type_index;
+                                if ui
+                                    .put(
+                                        node.rect.translate(offset),
+                                        egui::Button::new(&node.label).selected(is_root),
+                                    )
+                                    .on_hover_text(&node.type_name)
+                                    .clicked()
+                                {
+                                    on_type_selected(&node.type_name, node.type_index);
+                                }
+                            }
+                        });
+                }
+            });
+    }
+}
+
+impl GraphLayout {
+    /// Place nodes in layers, according to their distance to the root type
+    fn new(graph: &TypeDependencyGraph, root_type_index: Option<TypeIndex>) -> Self {
+        let node_indices: HashMap<TypeIndex, usize> = graph
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, (_, type_index))| (*type_index, i))
+            .collect();
+        let edges: Vec<(usize, usize, bool)> = graph
+            .edges
+            .iter()
+            .filter_map(|(from, to, is_pointer)| {
+                Some((
+                    *node_indices.get(from)?,
+                    *node_indices.get(to)?,
+                    *is_pointer,
+                ))
+            })
+            .collect();
+
+        // Compute each node's depth with a breadth-first traversal
+        let mut node_depths: Vec<Option<usize>> = vec![None; graph.nodes.len()];
+        let mut nodes_to_visit = VecDeque::new();
+        if let Some(root_node) = root_type_index.and_then(|i| node_indices.get(&i)) {
+            node_depths[*root_node] = Some(0);
+            nodes_to_visit.push_back(*root_node);
+        }
+        while let Some(node) = nodes_to_visit.pop_front() {
+            let depth = node_depths[node].unwrap_or_default();
+            for (_, to, _) in edges.iter().filter(|(from, _, _)| *from == node) {
+                if node_depths[*to].is_none() {
+                    node_depths[*to] = Some(depth + 1);
+                    nodes_to_visit.push_back(*to);
+                }
+            }
+        }
+        // Unreachable nodes go into a separate, last layer
+        let last_layer = node_depths.iter().flatten().max().map_or(0, |d| d + 1);
+
+        let mut layer_lengths: HashMap<usize, usize> = HashMap::new();
+        let nodes = graph
+            .nodes
+            .iter()
+            .zip(node_depths)
+            .map(|((type_name, type_index), depth)| {
+                let depth = depth.unwrap_or(last_layer);
+                let layer_length = layer_lengths.entry(depth).or_default();
+                let position = egui::pos2(
+                    *layer_length as f32 * (NODE_SIZE.x + NODE_SPACING.x),
+                    depth as f32 * (NODE_SIZE.y + NODE_SPACING.y),
+                );
+                *layer_length += 1;
+
+                NodeLayout {
+                    type_name: type_name.clone(),
+                    type_index: *type_index,
+                    label: node_label(type_name),
+                    rect: egui::Rect::from_min_size(position, NODE_SIZE),
+                }
+            })
+            .collect();
+
+        let layer_count = layer_lengths.keys().max().map_or(0, |d| d + 1);
+        let max_layer_length = layer_lengths.values().max().copied().unwrap_or_default();
+        let size = egui::vec2(
+            max_layer_length as f32 * (NODE_SIZE.x + NODE_SPACING.x),
+            layer_count as f32 * (NODE_SIZE.y + NODE_SPACING.y),
+        );
+
+        Self { nodes, edges, size }
+    }
+}
+
+/// Shorten type names that wouldn't fit in a node, keeping the end of the name
+fn node_label(type_name: &str) -> String {
+    let char_count = type_name.chars().count();
+    if char_count <= NODE_LABEL_MAX_LENGTH {
+        type_name.to_string()
+    } else {
+        let suffix: String = type_name
+            .chars()
+            .skip(char_count - (NODE_LABEL_MAX_LENGTH - 1))
+            .collect();
+        format!("…{suffix}")
+    }
+}
@@ -0,0 +1,61 @@
+use eframe::egui;
+use resym_core::diffing::DiffStatistics;
+
+use crate::i18n::tr;
+
+/// UI component in charge of displaying a summary of the current diff
+pub struct DiffStatisticsComponent {
+    statistics: Option<DiffStatistics>,
+}
+
+impl DiffStatisticsComponent {
+    pub fn new() -> Self {
+        Self { statistics: None }
+    }
+
+    pub fn set_statistics(&mut self, statistics: Option<DiffStatistics>) {
+        self.statistics = statistics;
+    }
+
+    /// Update/render the UI component
+    pub fn update(&self, ui: &mut egui::Ui) {
+        if let Some(statistics) = &self.statistics {
+            ui.horizontal_wrapped(|ui| {
+                ui.label(format!(
+                    "{}: +{} -{}",
+                    tr("Lines"),
+                    statistics.lines_added,
+                    statistics.lines_removed
+                ));
+                ui.separator();
+                ui.label(format!(
+                    "{}: +{} -{}",
+                    tr("Fields"),
+                    statistics.fields_added,
+                    statistics.fields_removed
+                ));
+                ui.separator();
+                ui.label(format!(
+                    "{}: +{} -{}",
+                    tr("Virtual methods"),
+                    statistics.virtual_methods_added,
+                    statistics.virtual_methods_removed
+                ));
+                if statistics.size_before.is_some() || statistics.size_after.is_some() {
+                    ui.separator();
+                    ui.label(format!(
+                        "{}: {} -> {}",
+                        tr("Size"),
+                        format_size(statistics.size_before),
+                        format_size(statistics.size_after)
+                    ));
+                }
+            });
+        }
+    }
+}
+
+fn format_size(size: Option<u64>) -> String {
+    size.map(|size| format!("{size:#x}"))
+        .unwrap_or_else(|| "N/A".to_string())
+}
@@ -1,9 +1,22 @@
-use eframe::egui::{self, ScrollArea, TextStyle};
+use std::{collections::BTreeSet, ops::Range};
+
+use eframe::egui::{self, accesskit::Role, ScrollArea, TextStyle};
+
+use crate::{accessibility, i18n::tr};
 
 pub struct IndexListComponent<I: Copy> {
     index_list: Vec<(String, I)>,
+    /// Last clicked row, used as the start of Shift+click ranges
     selected_row: usize,
+    /// Selected rows, extended with Ctrl+click and Shift+click
+    selected_rows: BTreeSet<usize>,
     list_ordering: IndexListOrdering,
+    /// Whether one of the rows had the keyboard focus during the last frame
+    has_focus: bool,
+    /// Rows rendered during the last frame
+    rendered_rows: Range<usize>,
+    /// Row to focus once it's rendered, after keyboard navigation
+    pending_focus_row: Option<usize>,
 }
 
 pub enum IndexListOrdering {
@@ -18,13 +31,19 @@ impl<I: Copy> IndexListComponent<I> {
         Self {
             index_list: vec![],
             selected_row: usize::MAX,
+            selected_rows: BTreeSet::new(),
             list_ordering: ordering,
+            has_focus: false,
+            rendered_rows: 0..0,
+            pending_focus_row: None,
         }
     }
 
     pub fn update_index_list(&mut self, index_list: Vec<(String, I)>) {
         self.index_list = index_list;
         self.selected_row = usize::MAX;
+        self.selected_rows.clear();
+        self.pending_focus_row = None;
 
         // Reorder list if needed
         if let IndexListOrdering::Alphabetical = self.list_ordering {
@@ -33,39 +52,136 @@ impl<I: Copy> IndexListComponent<I> {
         }
     }
 
+    /// Elements currently selected, in display order
+    pub fn selected_elements(&self) -> impl Iterator<Item = (&str, I)> {
+        self.selected_rows.iter().filter_map(|row_index| {
+            self.index_list
+                .get(*row_index)
+                .map(|(name, index)| (name.as_str(), *index))
+        })
+    }
+
+    /// Update/render the UI component. `on_element_selected` is only invoked
+    /// for single selections (i.e., when no modifier key is pressed).
     pub fn update<CB: FnMut(&str, I)>(&mut self, ui: &mut egui::Ui, on_element_selected: &mut CB) {
         let num_rows = self.index_list.len();
         const TEXT_STYLE: TextStyle = TextStyle::Body;
         let row_height = ui.text_style_height(&TEXT_STYLE);
+        let row_height_with_spacing = row_height + ui.spacing().item_spacing.y;
+
+        // Arrow/page keys move the selection while the list has the focus
+        let viewport_height = ui.available_height();
+        let rows_per_page = (viewport_height / row_height_with_spacing).max(1.0) as usize;
+        if self.has_focus {
+            if let Some(row_index) = navigate_rows(ui, self.selected_row, num_rows, rows_per_page) {
+                self.selected_rows = BTreeSet::from([row_index]);
+                self.selected_row = row_index;
+                self.pending_focus_row = Some(row_index);
+                let (type_name, type_index) = &self.index_list[row_index];
+                on_element_selected(type_name, *type_index);
+            }
+        }
+        // Scroll to the row to focus, so that it gets rendered
+        let mut scroll_area = ScrollArea::vertical().auto_shrink([false, false]);
+        if let Some(row_index) = self.pending_focus_row {
+            if !self.rendered_rows.contains(&row_index) {
+                let offset = if row_index < self.rendered_rows.start {
+                    row_index as f32 * row_height_with_spacing
+                } else {
+                    ((row_index + 1) as f32 * row_height_with_spacing - viewport_height).max(0.0)
+                };
+                scroll_area = scroll_area.vertical_scroll_offset(offset);
+            }
+        }
+
+        self.has_focus = false;
         ui.with_layout(
             egui::Layout::top_down(egui::Align::Min).with_cross_justify(true),
             |ui| {
                 if num_rows == 0 {
                     // Display a default message to make it obvious the list is empty
-                    ui.label("No results");
+                    ui.label(tr("No results"));
                     return;
                 }
 
-                ScrollArea::vertical()
-                    .auto_shrink([false, false])
-                    .show_rows(ui, row_height, num_rows, |ui, row_range| {
-                        for row_index in row_range {
-                            let (type_name, type_index) = &self.index_list[row_index];
+                scroll_area.show_rows(ui, row_height, num_rows, |ui, row_range| {
+                    self.rendered_rows = row_range.clone();
+                    for row_index in row_range {
+                        let (type_name, type_index) = &self.index_list[row_index];
 
-                            if ui
-                                .selectable_label(self.selected_row == row_index, type_name)
-                                .clicked()
-                            {
+                        let response =
+                            ui.selectable_label(self.selected_rows.contains(&row_index), type_name);
+                        accessibility::set_role(&response, Role::ListBoxOption);
+                        if self.pending_focus_row == Some(row_index) {
+                            self.pending_focus_row = None;
+                            response.request_focus();
+                            response.scroll_to_me(None);
+                        }
+                        self.has_focus |= response.has_focus();
+                        if response.clicked() {
+                            let modifiers = ui.input(|input_state| input_state.modifiers);
+                            if modifiers.shift && self.selected_row < num_rows {
+                                // Select the range of rows between the last clicked row and this one
+                                if !modifiers.command {
+                                    self.selected_rows.clear();
+                                }
+                                self.selected_rows.extend(
+                                    self.selected_row.min(row_index)
+                                        ..=self.selected_row.max(row_index),
+                                );
+                            } else if modifiers.command {
+                                // Toggle the row's selection
+                                if !self.selected_rows.remove(&row_index) {
+                                    self.selected_rows.insert(row_index);
+                                }
+                                self.selected_row = row_index;
+                            } else {
+                                self.selected_rows = BTreeSet::from([row_index]);
                                 self.selected_row = row_index;
                                 on_element_selected(type_name, *type_index);
                             }
                         }
-                    });
+                    }
+                });
             },
         );
     }
 }
 
+/// Row to move the selection to, according to the navigation keys pressed by
+/// the user (arrows, Page Up/Down, Home/End), if any
+pub fn navigate_rows(
+    ui: &egui::Ui,
+    selected_row: usize,
+    num_rows: usize,
+    rows_per_page: usize,
+) -> Option<usize> {
+    use egui::{Key, Modifiers};
+
+    let last_row = num_rows.checked_sub(1)?;
+    // Start from the top of the list when no row is selected
+    let selected_row = (selected_row < num_rows).then_some(selected_row);
+    let target_row = ui.input_mut(|input| {
+        if input.consume_key(Modifiers::NONE, Key::ArrowDown) {
+            Some(selected_row.map_or(0, |row| row + 1))
+        } else if input.consume_key(Modifiers::NONE, Key::ArrowUp) {
+            Some(selected_row.map_or(0, |row| row.saturating_sub(1)))
+        } else if input.consume_key(Modifiers::NONE, Key::PageDown) {
+            Some(selected_row.map_or(0, |row| row + rows_per_page))
+        } else if input.consume_key(Modifiers::NONE, Key::PageUp) {
+            Some(selected_row.map_or(0, |row| row.saturating_sub(rows_per_page)))
+        } else if input.consume_key(Modifiers::NONE, Key::Home) {
+            Some(0)
+        } else if input.consume_key(Modifiers::NONE, Key::End) {
+            Some(last_row)
+        } else {
+            None
+        }
+    })?;
+
+    Some(target_row.min(last_row))
+}
+
 impl<I: Copy> Default for IndexListComponent<I> {
     fn default() -> Self {
         Self::new(IndexListOrdering::None)
@@ -1,17 +1,51 @@
+mod address_lookup;
 mod code_view;
 mod console;
+mod demangler;
+mod dependency_graph;
+mod diff_statistics;
 mod index_list;
 mod module_tree;
 #[cfg(feature = "http")]
 mod open_url;
+mod pdb_metadata;
+mod performance;
+#[cfg(not(target_arch = "wasm32"))]
+mod reload_prompt;
+mod renames;
 mod settings;
+mod status_bar;
+mod symbol_kind_filter;
+mod symbol_properties;
 mod text_search;
+mod type_notes;
+mod type_palette;
+mod type_properties;
+mod type_table;
+mod view_settings;
 
+pub use address_lookup::*;
 pub use code_view::*;
 pub use console::*;
+pub use demangler::*;
+pub use dependency_graph::*;
+pub use diff_statistics::*;
 pub use index_list::*;
 pub use module_tree::*;
 #[cfg(feature = "http")]
 pub use open_url::*;
+pub use pdb_metadata::*;
+pub use performance::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use reload_prompt::*;
+pub use renames::*;
 pub use settings::*;
+pub use status_bar::*;
+pub use symbol_kind_filter::*;
+pub use symbol_properties::*;
 pub use text_search::*;
+pub use type_notes::*;
+pub use type_palette::*;
+pub use type_properties::*;
+pub use type_table::*;
+pub use view_settings::*;
@@ -1,7 +1,7 @@
 use eframe::egui;
 use resym_core::backend::{Backend, BackendCommand};
 
-use crate::resym_app::ResymPDBSlots;
+use crate::{i18n::tr, resym_app::ResymPDBSlots};
 
 pub struct OpenURLComponent {
     url_text: String,
@@ -27,17 +27,17 @@ impl OpenURLComponent {
 
     pub fn update(&mut self, ctx: &egui::Context, backend: &Backend) {
         if let Some(pdb_slot) = self.pdb_slot {
-            egui::Window::new("Open URL")
+            egui::Window::new(tr("Open URL"))
                 .anchor(egui::Align2::CENTER_CENTER, [0.0; 2])
                 .auto_sized()
                 .collapsible(false)
                 .show(ctx, |ui| {
-                    ui.label("URL to open:");
+                    ui.label(tr("URL to open:"));
                     ui.text_edit_singleline(&mut self.url_text);
                     ui.horizontal(|ui| {
-                        if ui.button("Cancel").clicked() {
+                        if ui.button(tr("Cancel")).clicked() {
                             self.close();
-                        } else if ui.button("Open").clicked() {
+                        } else if ui.button(tr("Open")).clicked() {
                             self.start_open_pdb_from_url(backend, pdb_slot, self.url_text.clone());
                             self.close();
                         }
@@ -0,0 +1,68 @@
+use eframe::egui;
+use resym_core::pdb_file::PdbMetadata;
+
+use crate::i18n::tr;
+
+pub struct PdbMetadataComponent {
+    window_open: bool,
+    metadata: Option<Result<PdbMetadata, String>>,
+}
+
+impl PdbMetadataComponent {
+    pub fn new() -> Self {
+        Self {
+            window_open: false,
+            metadata: None,
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.window_open = true;
+        self.metadata = None;
+    }
+
+    pub fn set_metadata(&mut self, result: resym_core::Result<PdbMetadata>) {
+        self.metadata = Some(result.map_err(|err| err.to_string()));
+    }
+
+    pub fn update(&mut self, ctx: &egui::Context) {
+        egui::Window::new(tr("About this PDB"))
+            .anchor(egui::Align2::CENTER_CENTER, [0.0; 2])
+            .open(&mut self.window_open)
+            .auto_sized()
+            .collapsible(false)
+            .show(ctx, |ui| match &self.metadata {
+                None => {
+                    ui.spinner();
+                }
+                Some(Err(err)) => {
+                    ui.label(egui::RichText::new(err).color(ui.style().visuals.error_fg_color));
+                }
+                Some(Ok(metadata)) => {
+                    egui::Grid::new("pdb_metadata_grid")
+                        .num_columns(2)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            let mut add_row = |label: &'static str, value: String| {
+                                ui.label(tr(label));
+                                ui.monospace(value);
+                                ui.end_row();
+                            };
+                            add_row("File", metadata.file_path.display().to_string());
+                            add_row("GUID", metadata.guid.clone());
+                            add_row("Age", metadata.age.to_string());
+                            add_row("Signature", format!("0x{:08x}", metadata.signature));
+                            add_row("Symbol server key", metadata.symbol_server_key());
+                            add_row("Machine type", metadata.machine_type.clone());
+                            add_row("Streams", metadata.stream_count.to_string());
+                            add_row("Types", metadata.type_count.to_string());
+                            add_row("Modules", metadata.module_count.to_string());
+                            add_row(
+                                "Stripped",
+                                tr(if metadata.is_stripped { "Yes" } else { "No" }).to_string(),
+                            );
+                        });
+                }
+            });
+    }
+}
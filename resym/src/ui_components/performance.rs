@@ -0,0 +1,177 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    time::Duration,
+};
+
+use eframe::egui;
+use resym_core::backend::{OperationKind, OperationTiming};
+
+use crate::i18n::tr;
+
+/// Number of operations listed in the "Recent operations" section
+const MAX_RECENT_OPERATIONS: usize = 50;
+
+/// Aggregated durations of operations of the same kind
+#[derive(Default)]
+struct OperationStatistics {
+    count: u32,
+    total: Duration,
+    last: Duration,
+    max: Duration,
+}
+
+impl OperationStatistics {
+    fn record(&mut self, duration: Duration) {
+        self.count += 1;
+        self.total += duration;
+        self.last = duration;
+        self.max = self.max.max(duration);
+    }
+
+    fn average(&self) -> Duration {
+        self.total / self.count.max(1)
+    }
+}
+
+/// UI component in charge of displaying the duration of operations performed
+/// by the backend (e.g., loading, filtering, reconstruction and diffing)
+pub struct PerformanceComponent {
+    window_open: bool,
+    statistics: BTreeMap<OperationKind, OperationStatistics>,
+    recent_operations: VecDeque<OperationTiming>,
+}
+
+impl PerformanceComponent {
+    pub fn new() -> Self {
+        Self {
+            window_open: false,
+            statistics: BTreeMap::new(),
+            recent_operations: VecDeque::new(),
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.window_open = true;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.window_open
+    }
+
+    pub fn record(&mut self, timing: OperationTiming) {
+        self.statistics
+            .entry(timing.kind)
+            .or_default()
+            .record(timing.duration);
+        if self.recent_operations.len() == MAX_RECENT_OPERATIONS {
+            self.recent_operations.pop_front();
+        }
+        self.recent_operations.push_back(timing);
+    }
+
+    pub fn update(&mut self, ctx: &egui::Context) {
+        let mut clear = false;
+        egui::Window::new(tr("Performance"))
+            .anchor(egui::Align2::CENTER_CENTER, [0.0; 2])
+            .open(&mut self.window_open)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                if self.statistics.is_empty() {
+                    ui.label(
+                        egui::RichText::new(tr("No operation has been timed yet"))
+                            .color(ui.style().visuals.widgets.inactive.text_color()),
+                    );
+                    return;
+                }
+
+                egui::Grid::new("performance_statistics_grid")
+                    .num_columns(5)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for header in ["Operation", "Count", "Last", "Average", "Max"] {
+                            ui.strong(tr(header));
+                        }
+                        ui.end_row();
+                        for (kind, statistics) in &self.statistics {
+                            ui.label(tr(kind.label()));
+                            ui.monospace(statistics.count.to_string());
+                            ui.monospace(format_duration(statistics.last));
+                            ui.monospace(format_duration(statistics.average()));
+                            ui.monospace(format_duration(statistics.max));
+                            ui.end_row();
+                        }
+                    });
+
+                ui.separator();
+                ui.label(tr("Recent operations"));
+                egui::ScrollArea::vertical()
+                    .max_height(200.0)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        egui::Grid::new("performance_recent_operations_grid")
+                            .num_columns(3)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for timing in self.recent_operations.iter().rev() {
+                                    ui.monospace(timing.name);
+                                    ui.monospace(format_duration(timing.duration));
+                                    if let Some(item_count) = timing.item_count {
+                                        ui.monospace(format!("{item_count} {}", tr("items")));
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+                    });
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button(tr("📋  Copy report")).clicked() {
+                        let report = self.report();
+                        ui.output_mut(|output| output.copied_text = report);
+                    }
+                    if ui.button(tr("Clear")).clicked() {
+                        clear = true;
+                    }
+                });
+            });
+
+        if clear {
+            self.statistics.clear();
+            self.recent_operations.clear();
+        }
+    }
+
+    /// Plain text version of the collected timings, to be attached to bug
+    /// reports
+    fn report(&self) -> String {
+        let mut report = format!("resym v{}\n", env!("CARGO_PKG_VERSION"));
+        for (kind, statistics) in &self.statistics {
+            report.push_str(&format!(
+                "{}: count={} last={} average={} max={}\n",
+                kind.label(),
+                statistics.count,
+                format_duration(statistics.last),
+                format_duration(statistics.average()),
+                format_duration(statistics.max),
+            ));
+        }
+        report.push_str("Recent operations:\n");
+        for timing in &self.recent_operations {
+            report.push_str(&format!(
+                "  {} {}",
+                timing.name,
+                format_duration(timing.duration)
+            ));
+            if let Some(item_count) = timing.item_count {
+                report.push_str(&format!(" items={item_count}"));
+            }
+            report.push('\n');
+        }
+
+        report
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.1} ms", duration.as_secs_f64() * 1000.0)
+}
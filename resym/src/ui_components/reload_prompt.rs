@@ -0,0 +1,122 @@
+use std::path::PathBuf;
+
+use eframe::egui;
+use resym_core::backend::PDBSlot;
+
+use crate::i18n::tr;
+
+/// UI component offering to reload PDB files which changed on disk
+pub struct ReloadPromptComponent {
+    /// Changed PDB files, with the slot they're loaded in
+    changed_pdb_files: Vec<(PDBSlot, PathBuf)>,
+    /// Slots being reloaded, with the file to load again if it changed while
+    /// being reloaded
+    reloads_in_progress: Vec<(PDBSlot, Option<PathBuf>)>,
+}
+
+impl ReloadPromptComponent {
+    pub fn new() -> Self {
+        Self {
+            changed_pdb_files: vec![],
+            reloads_in_progress: vec![],
+        }
+    }
+
+    /// Offer to reload the PDB loaded in the given slot. Files are usually
+    /// written several times in a row, so duplicate notifications are ignored.
+    pub fn notify_change(&mut self, pdb_slot: PDBSlot, pdb_file_path: PathBuf) {
+        // Changes made while the file is being reloaded are applied once the
+        // reload finishes
+        if let Some((_, changed_pdb_file_path)) = self
+            .reloads_in_progress
+            .iter_mut()
+            .find(|(slot, _)| *slot == pdb_slot)
+        {
+            *changed_pdb_file_path = Some(pdb_file_path);
+            return;
+        }
+
+        if !self
+            .changed_pdb_files
+            .iter()
+            .any(|(slot, _)| *slot == pdb_slot)
+        {
+            self.changed_pdb_files.push((pdb_slot, pdb_file_path));
+        }
+    }
+
+    /// Forget about changes to the given slot (e.g., when another PDB is loaded
+    /// in it)
+    pub fn dismiss(&mut self, pdb_slot: PDBSlot) {
+        self.changed_pdb_files.retain(|(slot, _)| *slot != pdb_slot);
+    }
+
+    /// Record that the PDB loaded in the given slot is being reloaded
+    pub fn start_reload(&mut self, pdb_slot: PDBSlot) {
+        self.dismiss(pdb_slot);
+        if !self
+            .reloads_in_progress
+            .iter()
+            .any(|(slot, _)| *slot == pdb_slot)
+        {
+            self.reloads_in_progress.push((pdb_slot, None));
+        }
+    }
+
+    /// Record that the given slot has been reloaded. Returns the file to load
+    /// again if it changed in the meantime.
+    pub fn finish_reload(&mut self, pdb_slot: PDBSlot) -> Option<PathBuf> {
+        let position = self
+            .reloads_in_progress
+            .iter()
+            .position(|(slot, _)| *slot == pdb_slot)?;
+
+        self.reloads_in_progress.remove(position).1
+    }
+
+    /// Record that reloads in progress failed. Files which changed in the
+    /// meantime are offered to be reloaded again.
+    pub fn abort_reloads(&mut self) {
+        for (pdb_slot, changed_pdb_file_path) in std::mem::take(&mut self.reloads_in_progress) {
+            if let Some(pdb_file_path) = changed_pdb_file_path {
+                self.notify_change(pdb_slot, pdb_file_path);
+            }
+        }
+    }
+
+    /// Update/render the UI component. Returns the PDB files the user chose to
+    /// reload.
+    pub fn update(&mut self, ctx: &egui::Context) -> Vec<(PDBSlot, PathBuf)> {
+        let mut files_to_reload = vec![];
+        if self.changed_pdb_files.is_empty() {
+            return files_to_reload;
+        }
+
+        let mut dismissed_slots = vec![];
+        egui::Window::new(tr("PDB file changed"))
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 40.0])
+            .auto_sized()
+            .collapsible(false)
+            .show(ctx, |ui| {
+                for (pdb_slot, pdb_file_path) in &self.changed_pdb_files {
+                    ui.label(format!(
+                        "'{}' has changed on disk.",
+                        pdb_file_path.display()
+                    ));
+                    ui.horizontal(|ui| {
+                        if ui.button(tr("Reload")).clicked() {
+                            files_to_reload.push((*pdb_slot, pdb_file_path.clone()));
+                            dismissed_slots.push(*pdb_slot);
+                        }
+                        if ui.button(tr("Ignore")).clicked() {
+                            dismissed_slots.push(*pdb_slot);
+                        }
+                    });
+                }
+            });
+        self.changed_pdb_files
+            .retain(|(slot, _)| !dismissed_slots.contains(slot));
+
+        files_to_reload
+    }
+}
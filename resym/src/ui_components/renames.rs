@@ -0,0 +1,66 @@
+use std::collections::BTreeMap;
+
+use eframe::egui;
+use resym_core::pdb_types::RenameMap;
+
+use crate::i18n::tr;
+
+pub struct RenamesComponent {
+    window_open: bool,
+    /// GUID of the PDB whose renames are being edited
+    pdb_guid: Option<String>,
+}
+
+impl RenamesComponent {
+    pub fn new() -> Self {
+        Self {
+            window_open: false,
+            pdb_guid: None,
+        }
+    }
+
+    pub fn open(&mut self, pdb_guid: String) {
+        self.window_open = true;
+        self.pdb_guid = Some(pdb_guid);
+    }
+
+    /// Edit the renames of the current PDB, stored by PDB GUID in `renames`
+    pub fn update(&mut self, ctx: &egui::Context, renames: &mut BTreeMap<String, String>) {
+        let Some(pdb_guid) = self.pdb_guid.as_ref() else {
+            return;
+        };
+
+        egui::Window::new(tr("Rename types and members"))
+            .open(&mut self.window_open)
+            .default_size([480.0, 320.0])
+            .collapsible(false)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(tr("PDB GUID:"));
+                    ui.monospace(pdb_guid);
+                });
+                ui.label(tr("One rename per line, applied to reconstructed types:"));
+                ui.monospace("<type> => <new name>");
+                ui.monospace("<type>.<member> => <new name>");
+
+                let pdb_renames = renames.entry(pdb_guid.clone()).or_default();
+                ui.add(
+                    egui::TextEdit::multiline(pdb_renames)
+                        .code_editor()
+                        .desired_rows(8)
+                        .desired_width(f32::INFINITY)
+                        .hint_text("_unnamed_1234 => Header"),
+                );
+                if let Err(err) = RenameMap::parse(pdb_renames) {
+                    ui.label(
+                        egui::RichText::new(err.to_string())
+                            .color(ui.style().visuals.error_fg_color),
+                    );
+                }
+                // Don't keep track of PDBs without renames
+                if pdb_renames.trim().is_empty() {
+                    renames.remove(pdb_guid);
+                }
+            });
+    }
+}
@@ -1,11 +1,30 @@
 use eframe::egui;
-use resym_core::pdb_types::PrimitiveReconstructionFlavor;
+#[cfg(not(target_arch = "wasm32"))]
+use resym_core::pe_file::PeFile;
+use resym_core::{
+    header_template::{DEFAULT_HEADER_TEMPLATE, HEADER_TEMPLATE_PLACEHOLDERS},
+    pdb_types::{
+        BraceStyle, EnumValueOrder, NumberBase, PointerAlignment, PrimitiveReconstructionFlavor,
+    },
+};
 
-use crate::settings::ResymAppSettings;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::log_file;
+use crate::{
+    i18n::{tr, Language},
+    platform,
+    settings::{ResymAppSettings, ThemePreference, FONT_SIZE_RANGE, UI_SCALE_RANGE},
+    ui_components::parse_address,
+};
 
 pub struct SettingsComponent {
     window_open: bool,
     pub app_settings: ResymAppSettings,
+    /// Monospace fonts installed on the system, listed the first time the
+    /// window is opened
+    system_fonts: Option<Vec<String>>,
+    /// UI scale selected with the slider, while it's being dragged
+    dragged_ui_scale: Option<f32>,
 }
 
 impl SettingsComponent {
@@ -13,6 +32,8 @@ impl SettingsComponent {
         Self {
             window_open: false,
             app_settings,
+            system_fonts: None,
+            dragged_ui_scale: None,
         }
     }
 
@@ -21,55 +42,170 @@ impl SettingsComponent {
     }
 
     pub fn update(&mut self, ctx: &egui::Context) {
-        egui::Window::new("Settings")
+        if self.window_open && self.system_fonts.is_none() {
+            self.system_fonts = Some(platform::system_monospace_fonts());
+        }
+        let system_fonts = self.system_fonts.as_deref().unwrap_or_default();
+
+        egui::Window::new(tr("Settings"))
+            // Note: the title changes with the language
+            .id(egui::Id::new("settings_window"))
             .anchor(egui::Align2::CENTER_CENTER, [0.0; 2])
             .open(&mut self.window_open)
             .auto_sized()
             .collapsible(false)
             .show(ctx, |ui| {
                 const INTER_SECTION_SPACING: f32 = 10.0;
-                ui.label("Theme");
+                ui.label(tr("Theme"));
                 // Show radio-buttons to switch between light and dark mode.
                 ui.horizontal(|ui| {
-                    ui.selectable_value(&mut self.app_settings.use_light_theme, true, "☀ Light");
-                    ui.selectable_value(&mut self.app_settings.use_light_theme, false, "🌙 Dark");
+                    ui.selectable_value(
+                        &mut self.app_settings.theme,
+                        ThemePreference::System,
+                        "💻 System",
+                    );
+                    ui.selectable_value(
+                        &mut self.app_settings.theme,
+                        ThemePreference::Light,
+                        "☀ Light",
+                    );
+                    ui.selectable_value(
+                        &mut self.app_settings.theme,
+                        ThemePreference::Dark,
+                        "🌙 Dark",
+                    );
                 });
                 ui.label(
-                    egui::RichText::new("Font size")
+                    egui::RichText::new(tr("Language"))
+                        .color(ui.style().visuals.widgets.inactive.text_color()),
+                );
+                egui::ComboBox::from_id_source("language")
+                    .selected_text(self.app_settings.language.native_name())
+                    .show_ui(ui, |ui| {
+                        for language in Language::ALL {
+                            ui.selectable_value(
+                                &mut self.app_settings.language,
+                                language,
+                                language.native_name(),
+                            );
+                        }
+                    });
+                ui.label(
+                    egui::RichText::new(tr("Code font"))
                         .color(ui.style().visuals.widgets.inactive.text_color()),
                 );
-                egui::ComboBox::from_id_source("font_size")
-                    .selected_text(format!("{}", self.app_settings.font_size))
+                egui::ComboBox::from_id_source("code_font")
+                    .selected_text(
+                        self.app_settings
+                            .code_font
+                            .as_deref()
+                            .unwrap_or(tr("Default")),
+                    )
                     .show_ui(ui, |ui| {
-                        for font_size in 8..=20 {
+                        ui.selectable_value(&mut self.app_settings.code_font, None, tr("Default"));
+                        for font_family in system_fonts {
                             ui.selectable_value(
-                                &mut self.app_settings.font_size,
-                                font_size,
-                                font_size.to_string(),
+                                &mut self.app_settings.code_font,
+                                Some(font_family.clone()),
+                                font_family,
                             );
                         }
                     });
+                egui::Grid::new("font_sizes_grid")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label(
+                            egui::RichText::new(tr("Code view font size"))
+                                .color(ui.style().visuals.widgets.inactive.text_color()),
+                        );
+                        font_size_combo_box(ui, "font_size", &mut self.app_settings.font_size);
+                        ui.end_row();
+
+                        ui.label(
+                            egui::RichText::new(tr("Console font size"))
+                                .color(ui.style().visuals.widgets.inactive.text_color()),
+                        );
+                        font_size_combo_box(
+                            ui,
+                            "console_font_size",
+                            &mut self.app_settings.console_font_size,
+                        );
+                        ui.end_row();
+
+                        ui.label(
+                            egui::RichText::new(tr("UI scale"))
+                                .color(ui.style().visuals.widgets.inactive.text_color()),
+                        );
+                        // Note: only apply the scale once the slider is released, as
+                        // the slider would move under the cursor otherwise
+                        let mut ui_scale =
+                            self.dragged_ui_scale.unwrap_or(self.app_settings.ui_scale);
+                        let slider_response = ui.add(
+                            egui::Slider::new(&mut ui_scale, UI_SCALE_RANGE)
+                                .step_by(0.05)
+                                .suffix("x"),
+                        );
+                        if slider_response.dragged() {
+                            self.dragged_ui_scale = Some(ui_scale);
+                        } else {
+                            self.app_settings.ui_scale = ui_scale;
+                            self.dragged_ui_scale = None;
+                        }
+                        ui.end_row();
+                    });
+                ui.add_space(INTER_SECTION_SPACING);
+
+                // Note: case sensitivity, regular expressions and whole-word
+                // matching are toggled next to search bars
+                ui.label(tr("Files"));
+                #[cfg(not(target_arch = "wasm32"))]
+                ui.checkbox(
+                    &mut self.app_settings.watch_pdb_files,
+                    tr("Offer to reload PDB files changed on disk"),
+                )
+                .on_hover_text(tr("Applies to PDB files loaded from now on"));
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    let log_directory = log_file::log_directory()
+                        .map(|log_directory| log_directory.display().to_string())
+                        .unwrap_or_default();
+                    ui.checkbox(
+                        &mut self.app_settings.log_to_file,
+                        tr("Write logs to a file"),
+                    )
+                    .on_hover_text(format!(
+                        "Copy the console's content to rotating log files in '{log_directory}'"
+                    ));
+                }
+                ui.checkbox(
+                    &mut self.app_settings.reduced_memory_mode,
+                    tr("Reduced memory mode (for huge PDB files)"),
+                )
+                .on_hover_text(
+                    "Type names are read from PDB files when needed instead of being kept in \
+                     memory, which makes searches slower. Applies to PDB files loaded from now on",
+                );
                 ui.add_space(INTER_SECTION_SPACING);
 
-                ui.label("Search");
+                ui.label(tr("Search"));
                 ui.checkbox(
-                    &mut self.app_settings.search_case_insensitive,
-                    "Case insensitive",
+                    &mut self.app_settings.search_undecorate_symbols,
+                    tr("Show undecorated symbol names"),
                 );
                 ui.checkbox(
-                    &mut self.app_settings.search_use_regex,
-                    "Enable regular expressions",
+                    &mut self.app_settings.search_group_templates,
+                    tr("Group template instantiations"),
                 );
                 ui.add_space(INTER_SECTION_SPACING);
 
-                ui.label("Type reconstruction");
+                ui.label(tr("Type reconstruction"));
                 ui.checkbox(
                     &mut self.app_settings.enable_syntax_hightlighting,
-                    "Enable C++ syntax highlighting",
+                    tr("Enable C++ syntax highlighting"),
                 );
 
                 ui.label(
-                    egui::RichText::new("Primitive types style")
+                    egui::RichText::new(tr("Primitive types style"))
                         .color(ui.style().visuals.widgets.inactive.text_color()),
                 );
                 egui::ComboBox::from_id_source("primitive_types_flavor")
@@ -80,6 +216,11 @@ impl SettingsComponent {
                             PrimitiveReconstructionFlavor::Portable,
                             "Portable",
                         );
+                        ui.selectable_value(
+                            &mut self.app_settings.primitive_types_flavor,
+                            PrimitiveReconstructionFlavor::Stdint,
+                            "Stdint",
+                        );
                         ui.selectable_value(
                             &mut self.app_settings.primitive_types_flavor,
                             PrimitiveReconstructionFlavor::Microsoft,
@@ -90,25 +231,348 @@ impl SettingsComponent {
                             PrimitiveReconstructionFlavor::Raw,
                             "Raw",
                         );
+                        ui.selectable_value(
+                            &mut self.app_settings.primitive_types_flavor,
+                            PrimitiveReconstructionFlavor::Custom,
+                            "Custom",
+                        );
                     });
+                ui.add_enabled_ui(
+                    self.app_settings.primitive_types_flavor
+                        == PrimitiveReconstructionFlavor::Custom,
+                    |ui| {
+                        ui.label(
+                            egui::RichText::new(tr(
+                                "Custom primitive types (<portable name> => <name>)",
+                            ))
+                            .color(ui.style().visuals.widgets.inactive.text_color()),
+                        );
+                        ui.add(
+                            egui::TextEdit::multiline(
+                                &mut self.app_settings.primitive_type_mapping,
+                            )
+                            .code_editor()
+                            .desired_rows(4),
+                        );
+                    },
+                );
 
-                ui.checkbox(&mut self.app_settings.print_header, "Print header");
+                ui.checkbox(&mut self.app_settings.print_header, tr("Print header"));
+                ui.add_enabled_ui(self.app_settings.print_header, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            egui::RichText::new(tr("Header template"))
+                                .color(ui.style().visuals.widgets.inactive.text_color()),
+                        )
+                        .on_hover_text(
+                            HEADER_TEMPLATE_PLACEHOLDERS
+                                .iter()
+                                .map(|(name, description)| format!("{{{name}}}: {description}"))
+                                .collect::<Vec<_>>()
+                                .join("\n"),
+                        );
+                        if ui.button(tr("Reset")).clicked() {
+                            self.app_settings.header_template = DEFAULT_HEADER_TEMPLATE.to_string();
+                        }
+                    });
+                    ui.add(
+                        egui::TextEdit::multiline(&mut self.app_settings.header_template)
+                            .code_editor()
+                            .desired_rows(4),
+                    );
+                });
                 ui.checkbox(
                     &mut self.app_settings.reconstruct_dependencies,
-                    "Print definitions of referenced types",
+                    tr("Print definitions of referenced types"),
                 );
+                ui.add_enabled_ui(!self.app_settings.reconstruct_dependencies, |ui| {
+                    ui.checkbox(
+                        &mut self.app_settings.forward_declare_dependencies,
+                        tr("Otherwise, print forward declarations of referenced types"),
+                    );
+                });
                 ui.checkbox(
                     &mut self.app_settings.print_access_specifiers,
-                    "Print access specifiers",
+                    tr("Print access specifiers"),
+                );
+                ui.checkbox(
+                    &mut self.app_settings.public_members_only,
+                    tr("Only print public members"),
+                );
+                ui.checkbox(
+                    &mut self.app_settings.hide_compiler_generated,
+                    tr("Omit compiler-generated members"),
+                );
+                ui.checkbox(
+                    &mut self.app_settings.print_static_member_rvas,
+                    tr("Print RVAs of static data members"),
+                );
+                ui.checkbox(
+                    &mut self.app_settings.doxygen_comments,
+                    tr("Emit Doxygen comments (sizes, offsets, mangled names)"),
+                );
+                ui.checkbox(
+                    &mut self.app_settings.flag_enum_comments,
+                    tr("Annotate flag enums with their bits"),
                 );
+                ui.label(
+                    egui::RichText::new(tr("Enumerators order"))
+                        .color(ui.style().visuals.widgets.inactive.text_color()),
+                );
+                egui::ComboBox::from_id_source("enum_value_order")
+                    .selected_text(format!("{:?}", self.app_settings.enum_value_order))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(
+                            &mut self.app_settings.enum_value_order,
+                            EnumValueOrder::Record,
+                            tr("PDB order"),
+                        );
+                        ui.selectable_value(
+                            &mut self.app_settings.enum_value_order,
+                            EnumValueOrder::Value,
+                            tr("By value"),
+                        );
+                        ui.selectable_value(
+                            &mut self.app_settings.enum_value_order,
+                            EnumValueOrder::Name,
+                            tr("By name"),
+                        );
+                    });
+                ui.checkbox(
+                    &mut self.app_settings.print_virtual_addresses,
+                    tr("Print virtual addresses next to RVAs"),
+                );
+                ui.add_enabled_ui(self.app_settings.print_virtual_addresses, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(tr("Image base"));
+                        let image_base_valid = parse_address(&self.app_settings.image_base).is_ok();
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.app_settings.image_base)
+                                .desired_width(140.0)
+                                .text_color_opt(
+                                    (!image_base_valid)
+                                        .then_some(ui.style().visuals.error_fg_color),
+                                ),
+                        );
+                        #[cfg(not(target_arch = "wasm32"))]
+                        if ui
+                            .button(tr("From PE image ..."))
+                            .on_hover_text(tr("Use the image base of an executable or a DLL"))
+                            .clicked()
+                        {
+                            if let Some(image_base) = image_base_from_pe() {
+                                self.app_settings.image_base = format!("{image_base:#x}");
+                            }
+                        }
+                    });
+                });
                 ui.checkbox(
                     &mut self.app_settings.ignore_std_types,
-                    "Ignore types from the std namespace",
+                    tr("Ignore types from the std namespace"),
+                );
+                ui.checkbox(
+                    &mut self.app_settings.use_opaque_types,
+                    tr("Treat well-known types as opaque"),
                 );
+                ui.add_enabled_ui(self.app_settings.use_opaque_types, |ui| {
+                    ui.label(
+                        egui::RichText::new(tr("Opaque types (<regex> [=> <header>])"))
+                            .color(ui.style().visuals.widgets.inactive.text_color()),
+                    );
+                    ui.add(
+                        egui::TextEdit::multiline(&mut self.app_settings.opaque_types)
+                            .code_editor()
+                            .desired_rows(4),
+                    );
+                });
                 ui.checkbox(
                     &mut self.app_settings.print_line_numbers,
-                    "Print line numbers",
+                    tr("Print line numbers"),
+                );
+                ui.checkbox(
+                    &mut self.app_settings.wrap_code_view_lines,
+                    tr("Wrap long lines in the code view"),
+                );
+                ui.checkbox(
+                    &mut self.app_settings.show_type_notes,
+                    tr("Show the type notes panel"),
+                );
+                ui.checkbox(
+                    &mut self.app_settings.emit_type_notes,
+                    tr("Print type notes as comments"),
+                );
+                ui.add_space(INTER_SECTION_SPACING);
+
+                ui.label(tr("Code style"));
+                ui.checkbox(
+                    &mut self.app_settings.format_use_tabs,
+                    tr("Indent with tabs"),
+                );
+                ui.add_enabled_ui(!self.app_settings.format_use_tabs, |ui| {
+                    ui.label(
+                        egui::RichText::new(tr("Indentation width"))
+                            .color(ui.style().visuals.widgets.inactive.text_color()),
+                    );
+                    egui::ComboBox::from_id_source("format_indent_width")
+                        .selected_text(format!("{}", self.app_settings.format_indent_width))
+                        .show_ui(ui, |ui| {
+                            for indent_width in [2, 3, 4, 8] {
+                                ui.selectable_value(
+                                    &mut self.app_settings.format_indent_width,
+                                    indent_width,
+                                    indent_width.to_string(),
+                                );
+                            }
+                        });
+                });
+                ui.label(
+                    egui::RichText::new(tr("Brace placement"))
+                        .color(ui.style().visuals.widgets.inactive.text_color()),
+                );
+                egui::ComboBox::from_id_source("format_brace_style")
+                    .selected_text(format!("{:?}", self.app_settings.format_brace_style))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(
+                            &mut self.app_settings.format_brace_style,
+                            BraceStyle::Attach,
+                            "Attach",
+                        );
+                        ui.selectable_value(
+                            &mut self.app_settings.format_brace_style,
+                            BraceStyle::Allman,
+                            "Allman",
+                        );
+                    });
+                ui.label(
+                    egui::RichText::new(tr("Pointer alignment"))
+                        .color(ui.style().visuals.widgets.inactive.text_color()),
+                );
+                egui::ComboBox::from_id_source("format_pointer_alignment")
+                    .selected_text(match self.app_settings.format_pointer_alignment {
+                        PointerAlignment::Left => "int* p",
+                        PointerAlignment::Right => "int *p",
+                    })
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(
+                            &mut self.app_settings.format_pointer_alignment,
+                            PointerAlignment::Left,
+                            "int* p",
+                        );
+                        ui.selectable_value(
+                            &mut self.app_settings.format_pointer_alignment,
+                            PointerAlignment::Right,
+                            "int *p",
+                        );
+                    });
+                ui.label(
+                    egui::RichText::new(tr("Offsets, sizes and enum values"))
+                        .color(ui.style().visuals.widgets.inactive.text_color()),
+                );
+                egui::ComboBox::from_id_source("format_number_base")
+                    .selected_text(format!("{:?}", self.app_settings.format_number_base))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(
+                            &mut self.app_settings.format_number_base,
+                            NumberBase::Hexadecimal,
+                            tr("Hexadecimal"),
+                        );
+                        ui.selectable_value(
+                            &mut self.app_settings.format_number_base,
+                            NumberBase::Decimal,
+                            tr("Decimal"),
+                        );
+                    });
+                ui.add_enabled(
+                    self.app_settings.format_number_base == NumberBase::Hexadecimal,
+                    egui::Checkbox::new(
+                        &mut self.app_settings.format_zero_padding,
+                        tr("Pad with leading zeros"),
+                    ),
+                );
+                ui.checkbox(
+                    &mut self.app_settings.format_wrap_lines,
+                    tr("Wrap long lines"),
+                );
+                ui.add_enabled_ui(self.app_settings.format_wrap_lines, |ui| {
+                    ui.label(
+                        egui::RichText::new(tr("Maximum line width"))
+                            .color(ui.style().visuals.widgets.inactive.text_color()),
+                    );
+                    ui.add(
+                        egui::DragValue::new(&mut self.app_settings.format_max_line_width)
+                            .clamp_range(40..=400),
+                    );
+                });
+                // Note: not available on wasm32
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    ui.checkbox(
+                        &mut self.app_settings.use_clang_format,
+                        tr("Format with clang-format"),
+                    );
+                    ui.add_enabled_ui(self.app_settings.use_clang_format, |ui| {
+                        egui::Grid::new("clang_format_grid")
+                            .num_columns(2)
+                            .show(ui, |ui| {
+                                ui.label(tr("Path"));
+                                ui.text_edit_singleline(&mut self.app_settings.clang_format_path);
+                                ui.end_row();
+
+                                ui.label(tr("Style"));
+                                ui.text_edit_singleline(&mut self.app_settings.clang_format_style);
+                                ui.end_row();
+                            });
+                    });
+                }
+                ui.add_space(INTER_SECTION_SPACING);
+
+                ui.label(tr("Comparison"));
+                ui.checkbox(
+                    &mut self.app_settings.diff_ignore_comments,
+                    tr("Ignore comment lines"),
+                );
+                ui.checkbox(
+                    &mut self.app_settings.diff_match_unique_names,
+                    tr("Match types by unique (decorated) name"),
+                );
+                ui.checkbox(
+                    &mut self.app_settings.diff_ignore_offset_only_changes,
+                    tr("Ignore offset-only changes"),
                 );
             });
     }
 }
+
+/// Ask the user for a PE image and return its image base
+#[cfg(not(target_arch = "wasm32"))]
+fn image_base_from_pe() -> Option<u64> {
+    use platform::FileDialog;
+
+    let pe_path = platform::file_dialog().open_file(
+        tr("Select a PE image"),
+        &platform::FileFilter {
+            patterns: &["*.exe", "*.dll", "*.sys"],
+            description: "PE images (*.exe;*.dll;*.sys)",
+        },
+    )?;
+    match PeFile::load_from_file(&pe_path) {
+        Ok(pe_file) => Some(pe_file.image_base),
+        Err(err) => {
+            log::error!(
+                "Failed to read the image base of '{}': {err}",
+                pe_path.display()
+            );
+            None
+        }
+    }
+}
+
+fn font_size_combo_box(ui: &mut egui::Ui, id_source: &str, font_size: &mut u16) {
+    egui::ComboBox::from_id_source(id_source)
+        .selected_text(font_size.to_string())
+        .show_ui(ui, |ui| {
+            for size in FONT_SIZE_RANGE {
+                ui.selectable_value(font_size, size, size.to_string());
+            }
+        });
+}
@@ -0,0 +1,158 @@
+use eframe::egui;
+use resym_core::{backend::OperationTiming, pdb_file::PdbMetadata};
+
+use crate::{accessibility, i18n::tr, ui_components::format_duration};
+
+/// UI component in charge of displaying a summary of the loaded PDB files and
+/// the progress of backend operations, at the bottom of the main window
+pub struct StatusBarComponent {
+    main_pdb: Option<PdbMetadata>,
+    diff_pdb: Option<PdbMetadata>,
+    /// Last error reported by the backend for each PDB, until dismissed
+    main_pdb_error: Option<String>,
+    diff_pdb_error: Option<String>,
+    /// Last PDB successfully loaded by the backend
+    last_load: Option<OperationTiming>,
+}
+
+impl StatusBarComponent {
+    pub fn new() -> Self {
+        Self {
+            main_pdb: None,
+            diff_pdb: None,
+            main_pdb_error: None,
+            diff_pdb_error: None,
+            last_load: None,
+        }
+    }
+
+    pub fn set_main_pdb(&mut self, metadata: Option<PdbMetadata>) {
+        self.main_pdb = metadata;
+    }
+
+    /// Set the metadata of the PDB the main PDB is compared with, if any
+    pub fn set_diff_pdb(&mut self, metadata: Option<PdbMetadata>) {
+        self.diff_pdb = metadata;
+    }
+
+    pub fn set_main_pdb_error(&mut self, error: Option<String>) {
+        self.main_pdb_error = error;
+    }
+
+    pub fn set_diff_pdb_error(&mut self, error: Option<String>) {
+        self.diff_pdb_error = error;
+    }
+
+    pub fn set_last_load(&mut self, timing: OperationTiming) {
+        self.last_load = Some(timing);
+    }
+
+    /// File names of the main PDB and of the PDB it's compared with, if any
+    pub fn pdb_names(&self) -> (Option<String>, Option<String>) {
+        (
+            self.main_pdb.as_ref().map(pdb_name),
+            self.diff_pdb.as_ref().map(pdb_name),
+        )
+    }
+
+    /// Update/render the UI component. Returns `true` if the user asked to
+    /// cancel the operations in progress.
+    pub fn update(
+        &mut self,
+        ui: &mut egui::Ui,
+        type_count: &str,
+        pending_operations: usize,
+    ) -> bool {
+        let mut cancel_requested = false;
+        ui.horizontal(|ui| {
+            match &self.main_pdb {
+                None => {
+                    ui.label(
+                        egui::RichText::new(tr("No PDB loaded"))
+                            .color(ui.style().visuals.widgets.inactive.text_color()),
+                    );
+                    // Loading the PDB might have failed
+                    pdb_error_label(ui, &mut self.main_pdb_error);
+                }
+                Some(main_pdb) => {
+                    ui.label(format!("📄 {}", pdb_name(main_pdb)))
+                        .on_hover_text(main_pdb.file_path.display().to_string());
+                    pdb_error_label(ui, &mut self.main_pdb_error);
+                    if let Some(diff_pdb) = &self.diff_pdb {
+                        ui.label(format!("⇄ {}", pdb_name(diff_pdb)))
+                            .on_hover_text(diff_pdb.file_path.display().to_string());
+                    }
+                    pdb_error_label(ui, &mut self.diff_pdb_error);
+                    ui.separator();
+                    ui.label(&main_pdb.machine_type);
+                    ui.separator();
+                    ui.label(type_count);
+                }
+            }
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if pending_operations > 0 {
+                    cancel_requested = ui
+                        .small_button(tr("Cancel"))
+                        .on_hover_text(tr("Cancel loading PDBs and dumping all types"))
+                        .clicked();
+                    ui.label(format!(
+                        "{} ({pending_operations} {})",
+                        tr("Working"),
+                        tr("pending")
+                    ));
+                    ui.spinner();
+                } else {
+                    ui.label(
+                        egui::RichText::new(tr("Ready"))
+                            .color(ui.style().visuals.widgets.inactive.text_color()),
+                    );
+                }
+                if let Some(last_load) = &self.last_load {
+                    ui.separator();
+                    let mut text = format!(
+                        "{} {}",
+                        tr("Loaded in"),
+                        format_duration(last_load.duration)
+                    );
+                    if let Some(type_count) = last_load.item_count {
+                        text.push_str(&format!(" ({type_count} {})", tr("types")));
+                    }
+                    ui.label(
+                        egui::RichText::new(text)
+                            .color(ui.style().visuals.widgets.inactive.text_color()),
+                    );
+                }
+            });
+        });
+
+        cancel_requested
+    }
+}
+
+/// Warning displayed next to the name of a PDB, when a command operating on
+/// it failed. Clicking on the warning dismisses it.
+fn pdb_error_label(ui: &mut egui::Ui, pdb_error: &mut Option<String>) {
+    let Some(error) = pdb_error.as_deref() else {
+        return;
+    };
+
+    let response = ui
+        .add(
+            egui::Label::new(egui::RichText::new("⚠").color(ui.style().visuals.error_fg_color))
+                .sense(egui::Sense::click()),
+        )
+        .on_hover_text(format!("{error}\n\n{}", tr("Click to dismiss")));
+    accessibility::set_name(&response, error);
+    if response.clicked() {
+        *pdb_error = None;
+    }
+}
+
+fn pdb_name(metadata: &PdbMetadata) -> String {
+    metadata
+        .file_path
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy().to_string())
+        .unwrap_or_else(|| metadata.file_path.display().to_string())
+}
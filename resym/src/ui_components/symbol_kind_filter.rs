@@ -0,0 +1,44 @@
+use eframe::egui;
+use resym_core::pdb_file::{SymbolKind, SymbolKindFilter};
+
+use crate::i18n::tr;
+
+/// Toggles used to filter the symbol list by kind
+pub struct SymbolKindFilterComponent {
+    pub kind_filter: SymbolKindFilter,
+}
+
+impl SymbolKindFilterComponent {
+    pub fn new() -> Self {
+        Self {
+            kind_filter: SymbolKindFilter::default(),
+        }
+    }
+
+    /// Update/render the UI component. Returns `true` if the filter has been
+    /// modified.
+    pub fn update(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = false;
+        ui.horizontal_wrapped(|ui| {
+            for (enabled, label) in [
+                (&mut self.kind_filter.functions, "Functions"),
+                (&mut self.kind_filter.data, "Data"),
+                (&mut self.kind_filter.thunks, "Thunks"),
+                (&mut self.kind_filter.publics, "Publics"),
+                (&mut self.kind_filter.others, "Other"),
+            ] {
+                changed |= ui.toggle_value(enabled, tr(label)).changed();
+            }
+            if ui
+                .button(tr("Publics only"))
+                .on_hover_text(tr("Only list public symbols"))
+                .clicked()
+            {
+                self.kind_filter = SymbolKindFilter::from_kinds(&[SymbolKind::Public]);
+                changed = true;
+            }
+        });
+
+        changed
+    }
+}
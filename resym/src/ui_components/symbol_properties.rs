@@ -0,0 +1,86 @@
+use eframe::egui;
+use resym_core::{pdb_file::SymbolLocation, pdb_types::format_rva};
+
+use crate::i18n::tr;
+
+pub struct SymbolPropertiesComponent {
+    window_open: bool,
+    symbol_location: Option<Result<SymbolLocation, String>>,
+}
+
+impl SymbolPropertiesComponent {
+    pub fn new() -> Self {
+        Self {
+            window_open: false,
+            symbol_location: None,
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.window_open = true;
+        self.symbol_location = None;
+    }
+
+    pub fn set_symbol_location(&mut self, result: resym_core::Result<SymbolLocation>) {
+        self.symbol_location = Some(result.map_err(|err| err.to_string()));
+    }
+
+    /// Update/render the window. Virtual addresses are displayed if an image
+    /// base is given.
+    pub fn update(&mut self, ctx: &egui::Context, image_base: Option<u64>) {
+        egui::Window::new(tr("Symbol properties"))
+            .anchor(egui::Align2::CENTER_CENTER, [0.0; 2])
+            .open(&mut self.window_open)
+            .auto_sized()
+            .collapsible(false)
+            .show(ctx, |ui| match &self.symbol_location {
+                None => {
+                    ui.spinner();
+                }
+                Some(Err(err)) => {
+                    ui.label(egui::RichText::new(err).color(ui.style().visuals.error_fg_color));
+                }
+                Some(Ok(symbol_location)) => {
+                    egui::Grid::new("symbol_properties_grid")
+                        .num_columns(2)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.label(tr("Name"));
+                            ui.monospace(&symbol_location.name);
+                            ui.end_row();
+
+                            ui.label(tr("Address"));
+                            ui.monospace(format_rva(symbol_location.rva, image_base));
+                            ui.end_row();
+
+                            ui.label(tr("Section"));
+                            ui.monospace(&symbol_location.section_name);
+                            ui.end_row();
+
+                            if !symbol_location.export_names.is_empty() {
+                                ui.label(tr("Exports"));
+                                ui.monospace(symbol_location.export_names.join(", "));
+                                ui.end_row();
+                            }
+
+                            ui.label(tr("Size"));
+                            ui.monospace(
+                                symbol_location
+                                    .size
+                                    .map(|size| format!("0x{size:x}"))
+                                    .unwrap_or_else(|| tr("Unknown").to_string()),
+                            );
+                            ui.end_row();
+                        });
+                    if symbol_location.outside_image {
+                        ui.label(
+                            egui::RichText::new(tr(
+                                "This symbol points outside of the loaded PE image",
+                            ))
+                            .color(ui.style().visuals.warn_fg_color),
+                        );
+                    }
+                }
+            });
+    }
+}
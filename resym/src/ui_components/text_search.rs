@@ -1,20 +1,154 @@
 use eframe::egui;
 
+use crate::{accessibility, i18n::tr};
+
+/// Options applied to search queries, toggled next to search bars
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SearchOptions {
+    pub case_insensitive: bool,
+    pub use_regex: bool,
+    pub whole_word: bool,
+}
+
+impl SearchOptions {
+    /// Filter to send to the backend for the given query, along with whether
+    /// it's a regular expression. Whole-word matching is implemented with
+    /// regular expressions.
+    pub fn backend_filter(&self, search_query: &str) -> (String, bool) {
+        if !self.whole_word || search_query.is_empty() {
+            (search_query.to_string(), self.use_regex)
+        } else if self.use_regex {
+            (format!(r"\b(?:{search_query})\b"), true)
+        } else {
+            (format!(r"\b{}\b", regex::escape(search_query)), true)
+        }
+    }
+}
+
 pub struct TextSearchComponent {
+    /// Name of the search bar for assistive technologies (e.g., "Search types"),
+    /// translated when it's displayed
+    accessible_name: &'static str,
     search_filter: String,
+    /// Reason why the current search filter cannot be used, if any
+    error: Option<String>,
+    /// Whether the search bar had the keyboard focus during the last frame
+    has_focus: bool,
+    focus_requested: bool,
 }
 
 impl TextSearchComponent {
-    pub fn new() -> Self {
+    pub fn new(accessible_name: &'static str) -> Self {
         Self {
+            accessible_name,
             search_filter: String::default(),
+            error: None,
+            has_focus: false,
+            focus_requested: false,
         }
     }
 
+    pub fn search_filter(&self) -> &str {
+        &self.search_filter
+    }
+
+    pub fn set_error(&mut self, error: Option<String>) {
+        self.error = error;
+    }
+
+    pub fn has_focus(&self) -> bool {
+        self.has_focus
+    }
+
+    /// Give the keyboard focus to the search bar during the next update
+    pub fn request_focus(&mut self) {
+        self.focus_requested = true;
+    }
+
     /// Update/render the UI component
-    pub fn update<CB: Fn(&str)>(&mut self, ui: &mut egui::Ui, on_query_update: &CB) {
-        if ui.text_edit_singleline(&mut self.search_filter).changed() {
-            on_query_update(self.search_filter.as_str());
-        }
+    pub fn update<CB: Fn(&str, &SearchOptions)>(
+        &mut self,
+        ui: &mut egui::Ui,
+        search_options: &mut SearchOptions,
+        on_query_update: &CB,
+    ) {
+        ui.horizontal(|ui| {
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                // Changing options also updates the results
+                let mut options_changed = false;
+                options_changed |= toggle(
+                    ui,
+                    &mut search_options.use_regex,
+                    ".*",
+                    tr("Use regular expressions"),
+                );
+                options_changed |= toggle(
+                    ui,
+                    &mut search_options.whole_word,
+                    "ab",
+                    tr("Match whole words"),
+                );
+                let mut case_sensitive = !search_options.case_insensitive;
+                if toggle(ui, &mut case_sensitive, "Aa", tr("Match case")) {
+                    search_options.case_insensitive = !case_sensitive;
+                    options_changed = true;
+                }
+
+                ui.scope(|ui| {
+                    if self.error.is_some() {
+                        // Outline the search bar in red if the filter is invalid
+                        let error_stroke =
+                            egui::Stroke::new(1.0, ui.style().visuals.error_fg_color);
+                        let visuals = ui.visuals_mut();
+                        visuals.widgets.inactive.bg_stroke = error_stroke;
+                        visuals.widgets.hovered.bg_stroke = error_stroke;
+                        visuals.selection.stroke = error_stroke;
+                    }
+
+                    let mut response = ui.add(
+                        egui::TextEdit::singleline(&mut self.search_filter)
+                            .desired_width(ui.available_width()),
+                    );
+                    accessibility::set_name(&response, tr(self.accessible_name));
+                    if std::mem::take(&mut self.focus_requested) {
+                        response.request_focus();
+                    }
+                    // Escape clears the filter (text fields lose the focus
+                    // when it's pressed, give it back)
+                    let mut filter_cleared = false;
+                    if response.lost_focus()
+                        && ui.input(|input| input.key_pressed(egui::Key::Escape))
+                        && !self.search_filter.is_empty()
+                    {
+                        self.search_filter.clear();
+                        response.request_focus();
+                        filter_cleared = true;
+                    }
+                    self.has_focus = response.has_focus() || filter_cleared;
+                    if let Some(error) = &self.error {
+                        response = response.on_hover_text(
+                            egui::RichText::new(error).color(ui.style().visuals.error_fg_color),
+                        );
+                    }
+                    if response.changed() || options_changed || filter_cleared {
+                        on_query_update(self.search_filter.as_str(), search_options);
+                    }
+                });
+            });
+        });
     }
 }
+
+/// Small toggle button displayed next to search bars. Returns `true` if the
+/// value has been toggled.
+fn toggle(ui: &mut egui::Ui, value: &mut bool, text: &str, hover_text: &str) -> bool {
+    let response = ui.selectable_label(*value, egui::RichText::new(text).monospace());
+    // The button's text is an abbreviation, use its description instead
+    accessibility::set_name(&response, hover_text);
+    let clicked = response.on_hover_text(hover_text).clicked();
+    if clicked {
+        *value = !*value;
+    }
+
+    clicked
+}
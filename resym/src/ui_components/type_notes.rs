@@ -0,0 +1,65 @@
+use eframe::egui;
+use resym_core::pdb_types::TypeNotes;
+
+use crate::i18n::tr;
+
+pub struct TypeNotesComponent {
+    /// Notes have been edited since they were last saved
+    has_unsaved_changes: bool,
+}
+
+impl TypeNotesComponent {
+    pub fn new() -> Self {
+        Self {
+            has_unsaved_changes: false,
+        }
+    }
+
+    /// Edit the note attached to the given type. Returns `true` if
+    /// `type_notes` has been modified and should be saved, which happens once
+    /// the user is done editing the note (i.e., when it loses focus) rather
+    /// than on every keystroke.
+    pub fn update(
+        &mut self,
+        ui: &mut egui::Ui,
+        type_name: Option<&str>,
+        type_notes: &mut TypeNotes,
+    ) -> bool {
+        ui.label(tr("Notes"));
+        ui.separator();
+
+        let Some(type_name) = type_name else {
+            ui.label(
+                egui::RichText::new(tr("Select a type to attach notes to it"))
+                    .color(ui.style().visuals.widgets.inactive.text_color()),
+            );
+            return false;
+        };
+
+        ui.monospace(type_name);
+        let mut type_note = type_notes.get(type_name).cloned().unwrap_or_default();
+        let response = ui.add(
+            egui::TextEdit::multiline(&mut type_note)
+                .desired_rows(12)
+                .desired_width(f32::INFINITY)
+                .hint_text(tr("Free-form notes about this type")),
+        );
+        if response.changed() {
+            // Don't keep track of types without notes
+            if type_note.trim().is_empty() {
+                type_notes.remove(type_name);
+            } else {
+                type_notes.insert(type_name.to_string(), type_note);
+            }
+            self.has_unsaved_changes = true;
+        }
+
+        response.lost_focus() && self.take_unsaved_changes()
+    }
+
+    /// Returns `true` if notes have been edited since they were last saved,
+    /// and consider them saved
+    pub fn take_unsaved_changes(&mut self) -> bool {
+        std::mem::take(&mut self.has_unsaved_changes)
+    }
+}
@@ -0,0 +1,142 @@
+use eframe::egui;
+use resym_core::{
+    backend::{Backend, BackendCommand},
+    pdb_file::{TypeIndex, TypeList},
+};
+
+use crate::{accessibility, i18n::tr, resym_app::ResymPDBSlots};
+
+/// Maximum number of matches listed in the palette
+const MAX_MATCHES: usize = 100;
+
+/// Popup used to jump to types by name, independently of the type list's
+/// search filter
+pub struct TypePaletteComponent {
+    window_open: bool,
+    query: String,
+    /// Matching types, best matches first
+    matches: TypeList,
+    selected_match: usize,
+    matches_need_update: bool,
+}
+
+impl TypePaletteComponent {
+    pub fn new() -> Self {
+        Self {
+            window_open: false,
+            query: String::default(),
+            matches: vec![],
+            selected_match: 0,
+            matches_need_update: false,
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.window_open = true;
+        self.query.clear();
+        self.selected_match = 0;
+        self.matches_need_update = true;
+    }
+
+    /// Update the list of types matching the current query, as ranked by the
+    /// backend
+    pub fn set_matches(&mut self, matches: TypeList) {
+        self.matches = matches;
+        self.selected_match = self
+            .selected_match
+            .min(self.matches.len().saturating_sub(1));
+    }
+
+    /// Update/render the palette. Returns the index of the type picked by
+    /// the user, if any.
+    pub fn update(&mut self, ctx: &egui::Context, backend: &Backend) -> Option<TypeIndex> {
+        if !self.window_open {
+            return None;
+        }
+        if self.matches_need_update {
+            self.request_matches(backend);
+        }
+
+        // Keyboard navigation, handled before the text field consumes events
+        let mut picked_match = None;
+        let mut selection_moved = false;
+        ctx.input_mut(|input| {
+            if input.consume_key(egui::Modifiers::NONE, egui::Key::Escape) {
+                self.window_open = false;
+            }
+            if input.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown) {
+                self.selected_match =
+                    (self.selected_match + 1).min(self.matches.len().saturating_sub(1));
+                selection_moved = true;
+            }
+            if input.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp) {
+                self.selected_match = self.selected_match.saturating_sub(1);
+                selection_moved = true;
+            }
+            if input.consume_key(egui::Modifiers::NONE, egui::Key::Enter) {
+                picked_match = Some(self.selected_match);
+            }
+        });
+
+        egui::Window::new(tr("Go to type"))
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 64.0])
+            .title_bar(false)
+            .resizable(false)
+            .collapsible(false)
+            .open(&mut self.window_open)
+            .show(ctx, |ui| {
+                ui.set_width(480.0);
+                let query_response = ui.add(
+                    egui::TextEdit::singleline(&mut self.query)
+                        .hint_text(tr("Go to type"))
+                        .desired_width(f32::INFINITY),
+                );
+                accessibility::set_name(&query_response, tr("Go to type"));
+                query_response.request_focus();
+                if query_response.changed() {
+                    self.selected_match = 0;
+                    self.matches_need_update = true;
+                }
+                ui.separator();
+
+                if self.matches.is_empty() {
+                    ui.label(
+                        egui::RichText::new(tr("No matching types"))
+                            .color(ui.style().visuals.widgets.inactive.text_color()),
+                    );
+                    return;
+                }
+                egui::ScrollArea::vertical()
+                    .max_height(320.0)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        for (match_index, (type_name, _)) in self.matches.iter().enumerate() {
+                            let is_selected = match_index == self.selected_match;
+                            let label = ui.selectable_label(is_selected, type_name);
+                            if is_selected && selection_moved {
+                                label.scroll_to_me(None);
+                            }
+                            if label.clicked() {
+                                picked_match = Some(match_index);
+                            }
+                        }
+                    });
+            });
+
+        let (_, type_index) = picked_match.and_then(|match_index| self.matches.get(match_index))?;
+        self.window_open = false;
+
+        Some(*type_index)
+    }
+
+    fn request_matches(&mut self, backend: &Backend) {
+        if let Err(err) = backend.send_command(BackendCommand::FuzzyFindTypes(
+            ResymPDBSlots::Main as usize,
+            self.query.clone(),
+            MAX_MATCHES,
+        )) {
+            log::error!("Failed to find types: {err}");
+        }
+        self.matches_need_update = false;
+    }
+}
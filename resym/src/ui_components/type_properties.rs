@@ -0,0 +1,147 @@
+use eframe::egui;
+use resym_core::pdb_file::{TypeProperties, TypeSourceLocation};
+
+use crate::{accessibility, i18n::tr};
+
+pub struct TypePropertiesComponent {
+    window_open: bool,
+    type_name: String,
+    type_properties: Option<Result<TypeProperties, String>>,
+    source_locations: Option<Result<Vec<TypeSourceLocation>, String>>,
+}
+
+impl TypePropertiesComponent {
+    pub fn new() -> Self {
+        Self {
+            window_open: false,
+            type_name: String::default(),
+            type_properties: None,
+            source_locations: None,
+        }
+    }
+
+    pub fn open(&mut self, type_name: &str) {
+        self.window_open = true;
+        self.type_name = type_name.to_string();
+        self.type_properties = None;
+        self.source_locations = None;
+    }
+
+    pub fn set_type_properties(&mut self, result: resym_core::Result<TypeProperties>) {
+        self.type_properties = Some(result.map_err(|err| err.to_string()));
+    }
+
+    pub fn set_source_locations(&mut self, result: resym_core::Result<Vec<TypeSourceLocation>>) {
+        self.source_locations = Some(result.map_err(|err| err.to_string()));
+    }
+
+    pub fn update(&mut self, ctx: &egui::Context) {
+        egui::Window::new(tr("Type properties"))
+            .anchor(egui::Align2::CENTER_CENTER, [0.0; 2])
+            .open(&mut self.window_open)
+            .auto_sized()
+            .collapsible(false)
+            .show(ctx, |ui| {
+                match &self.type_properties {
+                    None => {
+                        ui.spinner();
+                    }
+                    Some(Err(err)) => {
+                        ui.label(egui::RichText::new(err).color(ui.style().visuals.error_fg_color));
+                    }
+                    Some(Ok(type_properties)) => {
+                        egui::Grid::new("type_properties_grid")
+                            .num_columns(3)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                property_row(ui, tr("Name"), &self.type_name);
+                                property_row(
+                                    ui,
+                                    tr("Index"),
+                                    &format!("{:#x}", type_properties.index),
+                                );
+                                property_row(
+                                    ui,
+                                    tr("Unique name"),
+                                    type_properties.unique_name.as_deref().unwrap_or(tr("None")),
+                                );
+                                property_row(
+                                    ui,
+                                    tr("Size"),
+                                    &type_properties
+                                        .size
+                                        .map(|size| format!("{size:#x}"))
+                                        .unwrap_or_else(|| tr("Unknown").to_string()),
+                                );
+                                property_row(
+                                    ui,
+                                    tr("Field count"),
+                                    &type_properties.field_count.to_string(),
+                                );
+                                property_row(
+                                    ui,
+                                    tr("Forward reference"),
+                                    if type_properties.is_forward_reference {
+                                        tr("Yes")
+                                    } else {
+                                        tr("No")
+                                    },
+                                );
+                            });
+                    }
+                }
+
+                ui.separator();
+                ui.label(tr("Source files"));
+                match &self.source_locations {
+                    None => {
+                        ui.spinner();
+                    }
+                    Some(Err(err)) => {
+                        ui.label(egui::RichText::new(err).color(ui.style().visuals.error_fg_color));
+                    }
+                    Some(Ok(source_locations)) if source_locations.is_empty() => {
+                        ui.label(
+                            egui::RichText::new(tr(
+                                "No line information found for this type's methods",
+                            ))
+                            .color(ui.style().visuals.widgets.inactive.text_color()),
+                        );
+                    }
+                    Some(Ok(source_locations)) => {
+                        egui::Grid::new("type_source_locations_grid")
+                            .num_columns(2)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for source_location in source_locations {
+                                    ui.monospace(format!(
+                                        "{}:{}",
+                                        source_location.file_path, source_location.line
+                                    ));
+                                    ui.label(match source_location.method_count {
+                                        1 => "1 method".to_string(),
+                                        method_count => format!("{method_count} methods"),
+                                    });
+                                    ui.end_row();
+                                }
+                            });
+                    }
+                }
+            });
+    }
+}
+
+/// Add a row displaying a property, with a button copying its value to the
+/// clipboard
+fn property_row(ui: &mut egui::Ui, label: &str, value: &str) {
+    ui.label(label);
+    ui.monospace(value);
+    let copy_button = ui.small_button("📋");
+    accessibility::set_name(&copy_button, &format!("{} {label}", tr("Copy")));
+    if copy_button.on_hover_text(tr("Copy")).clicked() {
+        ui.output_mut(|output| {
+            output.copied_text = value.to_string();
+        });
+    }
+    ui.end_row();
+}
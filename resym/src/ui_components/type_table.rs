@@ -0,0 +1,453 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+
+use eframe::egui::{self, accesskit::Role, TextStyle};
+use egui_extras::{Column, TableBuilder};
+use resym_core::pdb_file::{TypeIndex, TypeInfo, TypeInfoMap};
+
+use crate::{accessibility, i18n::tr, ui_components::navigate_rows};
+
+/// Columns of the type table, which can be used to sort it
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TypeTableColumn {
+    Name,
+    Kind,
+    Size,
+    TypeIndex,
+}
+
+impl TypeTableColumn {
+    const ALL: [TypeTableColumn; 4] = [
+        TypeTableColumn::Name,
+        TypeTableColumn::Kind,
+        TypeTableColumn::Size,
+        TypeTableColumn::TypeIndex,
+    ];
+
+    fn title(&self) -> &'static str {
+        tr(match self {
+            TypeTableColumn::Name => "Name",
+            TypeTableColumn::Kind => "Kind",
+            TypeTableColumn::Size => "Size",
+            TypeTableColumn::TypeIndex => "Index",
+        })
+    }
+}
+
+struct TypeRow {
+    name: String,
+    type_index: TypeIndex,
+    /// Not available when browsing merged type lists
+    info: Option<TypeInfo>,
+    selected: bool,
+}
+
+/// Row as displayed in the table
+enum VisibleRow {
+    /// Instantiations of a template, collapsed into a single row
+    Group {
+        name: String,
+        count: usize,
+        expanded: bool,
+    },
+    /// Index into `TypeTableComponent::rows`
+    Type { row: usize, nested: bool },
+}
+
+pub struct TypeTableComponent {
+    rows: Vec<TypeRow>,
+    visible_rows: Vec<VisibleRow>,
+    /// Last clicked visible row, used as the start of Shift+click ranges
+    selected_row: usize,
+    sort_column: TypeTableColumn,
+    sort_ascending: bool,
+    /// Group instantiations of the same template under a common row
+    group_templates: bool,
+    expanded_groups: HashSet<String>,
+    /// Whether one of the rows had the keyboard focus during the last frame
+    has_focus: bool,
+    /// Visible row to focus once it's rendered, after keyboard navigation
+    pending_focus_row: Option<usize>,
+}
+
+impl TypeTableComponent {
+    pub fn new() -> Self {
+        Self {
+            rows: vec![],
+            visible_rows: vec![],
+            selected_row: usize::MAX,
+            sort_column: TypeTableColumn::Name,
+            sort_ascending: true,
+            group_templates: false,
+            expanded_groups: HashSet::new(),
+            has_focus: false,
+            pending_focus_row: None,
+        }
+    }
+
+    pub fn has_focus(&self) -> bool {
+        self.has_focus
+    }
+
+    /// Give the keyboard focus to the last clicked row (or the first one)
+    pub fn request_focus(&mut self) {
+        if !self.visible_rows.is_empty() {
+            self.pending_focus_row = Some(if self.selected_row < self.visible_rows.len() {
+                self.selected_row
+            } else {
+                0
+            });
+        }
+    }
+
+    pub fn set_group_templates(&mut self, group_templates: bool) {
+        if self.group_templates != group_templates {
+            self.group_templates = group_templates;
+            self.update_visible_rows();
+        }
+    }
+
+    pub fn update_type_list(
+        &mut self,
+        type_list: Vec<(String, TypeIndex)>,
+        type_infos: &TypeInfoMap,
+    ) {
+        self.rows = type_list
+            .into_iter()
+            .map(|(name, type_index)| TypeRow {
+                info: type_infos.get(&type_index).copied(),
+                name,
+                type_index,
+                selected: false,
+            })
+            .collect();
+        self.selected_row = usize::MAX;
+        self.sort_rows();
+    }
+
+    /// Elements currently selected, in display order
+    pub fn selected_elements(&self) -> impl Iterator<Item = (&str, TypeIndex)> {
+        self.rows
+            .iter()
+            .filter(|row| row.selected)
+            .map(|row| (row.name.as_str(), row.type_index))
+    }
+
+    fn sort_rows(&mut self) {
+        let (sort_column, sort_ascending) = (self.sort_column, self.sort_ascending);
+        self.rows.sort_by(|lhs, rhs| {
+            let ordering = match sort_column {
+                TypeTableColumn::Name => Ordering::Equal,
+                TypeTableColumn::Kind => lhs
+                    .info
+                    .map(|info| info.kind)
+                    .cmp(&rhs.info.map(|info| info.kind)),
+                TypeTableColumn::Size => lhs
+                    .info
+                    .map(|info| info.size)
+                    .cmp(&rhs.info.map(|info| info.size)),
+                TypeTableColumn::TypeIndex => lhs.type_index.cmp(&rhs.type_index),
+            }
+            // Order types alphabetically when they're equal otherwise
+            .then_with(|| lhs.name.cmp(&rhs.name));
+            if sort_ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+        self.update_visible_rows();
+    }
+
+    fn update_visible_rows(&mut self) {
+        // Shift+click ranges don't make sense after reordering rows
+        self.selected_row = usize::MAX;
+        self.pending_focus_row = None;
+        if !self.group_templates {
+            self.visible_rows = (0..self.rows.len())
+                .map(|row| VisibleRow::Type { row, nested: false })
+                .collect();
+            return;
+        }
+
+        // Groups are displayed where their first instantiation would be
+        let mut groups: HashMap<String, Vec<usize>> = HashMap::new();
+        let mut ordered_rows: Vec<Result<usize, String>> = vec![];
+        for (row_index, row) in self.rows.iter().enumerate() {
+            match template_name(&row.name) {
+                None => ordered_rows.push(Ok(row_index)),
+                Some(template_name) => {
+                    let group = groups.entry(template_name.clone()).or_default();
+                    if group.is_empty() {
+                        ordered_rows.push(Err(template_name));
+                    }
+                    group.push(row_index);
+                }
+            }
+        }
+
+        self.visible_rows = Vec::with_capacity(ordered_rows.len());
+        for ordered_row in ordered_rows {
+            match ordered_row {
+                Ok(row) => self
+                    .visible_rows
+                    .push(VisibleRow::Type { row, nested: false }),
+                Err(template_name) => {
+                    let members = &groups[&template_name];
+                    // Don't bother collapsing templates instantiated once
+                    if let [row] = members[..] {
+                        self.visible_rows
+                            .push(VisibleRow::Type { row, nested: false });
+                        continue;
+                    }
+
+                    let expanded = self.expanded_groups.contains(&template_name);
+                    self.visible_rows.push(VisibleRow::Group {
+                        name: template_name,
+                        count: members.len(),
+                        expanded,
+                    });
+                    if expanded {
+                        self.visible_rows
+                            .extend(members.iter().map(|row| VisibleRow::Type {
+                                row: *row,
+                                nested: true,
+                            }));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Update/render the UI component. `on_element_selected` is only invoked
+    /// for single selections (i.e., when no modifier key is pressed).
+    pub fn update<CB: FnMut(&str, TypeIndex)>(
+        &mut self,
+        ui: &mut egui::Ui,
+        on_element_selected: &mut CB,
+    ) {
+        let num_rows = self.visible_rows.len();
+        if num_rows == 0 {
+            // Display a default message to make it obvious the list is empty
+            ui.label(tr("No results"));
+            return;
+        }
+
+        let row_height = ui.text_style_height(&TextStyle::Body);
+        let nested_row_indent = ui.spacing().indent;
+
+        // Arrow/page keys move the selection while the table has the focus
+        if self.has_focus {
+            let rows_per_page = (ui.available_height() / (row_height + ui.spacing().item_spacing.y))
+                .max(1.0) as usize;
+            if let Some(row_index) = navigate_rows(ui, self.selected_row, num_rows, rows_per_page) {
+                self.on_row_navigated(row_index, on_element_selected);
+            }
+        }
+
+        let mut clicked_column = None;
+        let mut clicked_row = None;
+        let mut table_builder = TableBuilder::new(ui);
+        if let Some(row_index) = self.pending_focus_row {
+            table_builder = table_builder.scroll_to_row(row_index, None);
+        }
+        self.has_focus = false;
+        table_builder
+            .striped(true)
+            .resizable(true)
+            .auto_shrink([false, false])
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::remainder().at_least(100.0).clip(true))
+            .columns(Column::auto(), 3)
+            .header(row_height, |mut header| {
+                for column in TypeTableColumn::ALL {
+                    header.col(|ui| {
+                        let mut title = column.title().to_string();
+                        if column == self.sort_column {
+                            title.push_str(if self.sort_ascending { " ⏶" } else { " ⏷" });
+                        }
+                        let response = ui.add(
+                            egui::Label::new(egui::RichText::new(title).strong())
+                                .sense(egui::Sense::click()),
+                        );
+                        accessibility::set_role(&response, Role::ColumnHeader);
+                        if response.clicked() {
+                            clicked_column = Some(column);
+                        }
+                    });
+                }
+            })
+            .body(|body| {
+                body.rows(row_height, num_rows, |mut row| {
+                    let row_index = row.index();
+                    let (type_row, nested) = match &self.visible_rows[row_index] {
+                        VisibleRow::Group {
+                            name,
+                            count,
+                            expanded,
+                        } => {
+                            row.col(|ui| {
+                                let arrow = if *expanded { "⏷" } else { "⏵" };
+                                let response =
+                                    ui.selectable_label(false, format!("{arrow} {name} ({count})"));
+                                // Arrows aren't meaningful to screen readers
+                                accessibility::set_name(
+                                    &response,
+                                    &format!("{name}, {count} {}", tr("instantiations")),
+                                );
+                                accessibility::set_expanded(&response, *expanded);
+                                if self.pending_focus_row == Some(row_index) {
+                                    self.pending_focus_row = None;
+                                    response.request_focus();
+                                }
+                                self.has_focus |= response.has_focus();
+                                if response.clicked() {
+                                    clicked_row = Some(row_index);
+                                }
+                            });
+                            return;
+                        }
+                        VisibleRow::Type { row, nested } => (&self.rows[*row], *nested),
+                    };
+                    row.col(|ui| {
+                        if nested {
+                            ui.add_space(nested_row_indent);
+                        }
+                        let response = ui.selectable_label(type_row.selected, &type_row.name);
+                        accessibility::set_role(&response, Role::ListBoxOption);
+                        if self.pending_focus_row == Some(row_index) {
+                            self.pending_focus_row = None;
+                            response.request_focus();
+                        }
+                        self.has_focus |= response.has_focus();
+                        if response.clicked() {
+                            clicked_row = Some(row_index);
+                        }
+                    });
+                    row.col(|ui| {
+                        if let Some(info) = type_row.info {
+                            ui.label(info.kind.to_string());
+                        }
+                    });
+                    row.col(|ui| {
+                        if let Some(info) = type_row.info {
+                            ui.monospace(format!("{:#x}", info.size));
+                        }
+                    });
+                    row.col(|ui| {
+                        ui.monospace(format!("{:#x}", type_row.type_index));
+                    });
+                });
+            });
+
+        // Clicking on the sorted column's header reverses the order
+        if let Some(column) = clicked_column {
+            if column == self.sort_column {
+                self.sort_ascending = !self.sort_ascending;
+            } else {
+                self.sort_column = column;
+                self.sort_ascending = true;
+            }
+            self.sort_rows();
+        }
+
+        if let Some(row_index) = clicked_row {
+            self.on_row_clicked(ui, row_index, on_element_selected);
+        }
+    }
+
+    /// Select the given visible row after keyboard navigation. Template groups
+    /// are only focused, and can be expanded with Enter or Space.
+    fn on_row_navigated<CB: FnMut(&str, TypeIndex)>(
+        &mut self,
+        row_index: usize,
+        on_element_selected: &mut CB,
+    ) {
+        self.selected_row = row_index;
+        self.pending_focus_row = Some(row_index);
+        if let VisibleRow::Type { row, .. } = self.visible_rows[row_index] {
+            self.rows.iter_mut().for_each(|row| row.selected = false);
+            let type_row = &mut self.rows[row];
+            type_row.selected = true;
+            on_element_selected(&type_row.name, type_row.type_index);
+        }
+    }
+
+    fn on_row_clicked<CB: FnMut(&str, TypeIndex)>(
+        &mut self,
+        ui: &egui::Ui,
+        row_index: usize,
+        on_element_selected: &mut CB,
+    ) {
+        let clicked_type_row = match &self.visible_rows[row_index] {
+            VisibleRow::Group { name, .. } => {
+                // Expand or collapse the group
+                if !self.expanded_groups.remove(name) {
+                    self.expanded_groups.insert(name.clone());
+                }
+                self.update_visible_rows();
+                return;
+            }
+            VisibleRow::Type { row, .. } => *row,
+        };
+
+        let modifiers = ui.input(|input_state| input_state.modifiers);
+        if modifiers.shift && self.selected_row < self.visible_rows.len() {
+            // Select the range of rows between the last clicked row and this one
+            if !modifiers.command {
+                self.rows.iter_mut().for_each(|row| row.selected = false);
+            }
+            let range = self.selected_row.min(row_index)..=self.selected_row.max(row_index);
+            for visible_row in &self.visible_rows[range] {
+                if let VisibleRow::Type { row, .. } = visible_row {
+                    self.rows[*row].selected = true;
+                }
+            }
+        } else if modifiers.command {
+            // Toggle the row's selection
+            let type_row = &mut self.rows[clicked_type_row];
+            type_row.selected = !type_row.selected;
+            self.selected_row = row_index;
+        } else {
+            self.rows.iter_mut().for_each(|row| row.selected = false);
+            let type_row = &mut self.rows[clicked_type_row];
+            type_row.selected = true;
+            self.selected_row = row_index;
+            on_element_selected(&type_row.name, type_row.type_index);
+        }
+    }
+}
+
+impl Default for TypeTableComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Name of the template the given type is an instantiation of, with template
+/// arguments stripped (e.g., `std::vector<>` for `std::vector<int>`)
+fn template_name(type_name: &str) -> Option<String> {
+    if !type_name.contains('<') {
+        return None;
+    }
+
+    let mut template_name = String::with_capacity(type_name.len());
+    let mut depth = 0_usize;
+    for c in type_name.chars() {
+        match c {
+            '<' => {
+                if depth == 0 {
+                    template_name.push_str("<>");
+                }
+                depth += 1;
+            }
+            '>' => depth = depth.saturating_sub(1),
+            _ if depth == 0 => template_name.push(c),
+            _ => {}
+        }
+    }
+
+    Some(template_name)
+}
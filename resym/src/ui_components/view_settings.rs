@@ -0,0 +1,77 @@
+use eframe::egui;
+use resym_core::pdb_types::PrimitiveReconstructionFlavor;
+
+use crate::{i18n::tr, settings::ResymAppSettings};
+
+/// Reconstruction settings used for the current view. They follow the global
+/// settings until they're overridden from the toolbar.
+pub struct ViewSettingsComponent {
+    pub primitive_types_flavor: PrimitiveReconstructionFlavor,
+    pub reconstruct_dependencies: bool,
+    pub print_access_specifiers: bool,
+    overridden: bool,
+}
+
+impl ViewSettingsComponent {
+    pub fn new(app_settings: &ResymAppSettings) -> Self {
+        Self {
+            primitive_types_flavor: app_settings.primitive_types_flavor,
+            reconstruct_dependencies: app_settings.reconstruct_dependencies,
+            print_access_specifiers: app_settings.print_access_specifiers,
+            overridden: false,
+        }
+    }
+
+    /// Follow the global settings, unless they've been overridden
+    pub fn sync(&mut self, app_settings: &ResymAppSettings) {
+        if !self.overridden {
+            *self = Self::new(app_settings);
+        }
+    }
+
+    /// Update/render the toolbar. Returns `true` if the settings have been
+    /// modified.
+    pub fn update(&mut self, ui: &mut egui::Ui, app_settings: &ResymAppSettings) -> bool {
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            egui::ComboBox::from_id_source("view_primitive_types_flavor")
+                .selected_text(format!("{:?}", self.primitive_types_flavor))
+                .show_ui(ui, |ui| {
+                    for (flavor, label) in [
+                        (PrimitiveReconstructionFlavor::Portable, "Portable"),
+                        (PrimitiveReconstructionFlavor::Stdint, "Stdint"),
+                        (PrimitiveReconstructionFlavor::Microsoft, "Microsoft"),
+                        (PrimitiveReconstructionFlavor::Raw, "Raw"),
+                        (PrimitiveReconstructionFlavor::Custom, "Custom"),
+                    ] {
+                        changed |= ui
+                            .selectable_value(&mut self.primitive_types_flavor, flavor, label)
+                            .changed();
+                    }
+                })
+                .response
+                .on_hover_text(tr("Primitive types style"));
+            changed |= ui
+                .checkbox(&mut self.reconstruct_dependencies, tr("Dependencies"))
+                .changed();
+            changed |= ui
+                .checkbox(&mut self.print_access_specifiers, tr("Access specifiers"))
+                .changed();
+
+            if self.overridden {
+                ui.label(
+                    egui::RichText::new(tr("(overrides the global settings)"))
+                        .color(ui.style().visuals.widgets.inactive.text_color()),
+                );
+                if ui.button(tr("Reset")).clicked() {
+                    *self = Self::new(app_settings);
+                    changed = true;
+                }
+            } else if changed {
+                self.overridden = true;
+            }
+        });
+
+        changed
+    }
+}
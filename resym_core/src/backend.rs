@@ -8,30 +8,110 @@ use rayon::{
     ThreadPool,
 };
 
-use core::fmt;
+use core::fmt::{self, Write};
 #[cfg(all(not(feature = "rayon"), not(target_arch = "wasm32")))]
 use std::thread::{self, JoinHandle};
 use std::{
-    collections::{BTreeSet, HashMap},
+    collections::{hash_map::Entry, BTreeSet, HashMap, VecDeque},
     io,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
 };
 #[cfg(not(target_arch = "wasm32"))]
 use std::{path::PathBuf, time::Instant};
 #[cfg(all(not(feature = "rayon"), target_arch = "wasm32"))]
 use wasm_thread::{self as thread, JoinHandle};
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::clang_format::{clang_format, ClangFormatConfiguration};
 use crate::{
-    diffing::{diff_module_by_path, diff_symbol_by_name, diff_type_by_name},
-    error::{Result, ResymCoreError},
-    frontend::{FrontendCommand, FrontendController, ReconstructedType},
+    cancellation::CancellationToken,
+    diffing::{
+        changed_type_names, compare_type_across_pdbs, diff_all_types, diff_module_by_path,
+        diff_public_symbols, diff_symbol_by_name, diff_type_by_name, diff_type_by_name_three_way,
+        DiffOptions,
+    },
+    error::{ErrorContext, Result, ResymCoreError},
+    frontend::{FrontendCommand, FrontendController, ReconstructedType, SearchTarget},
+    header_template::{current_date, HeaderTemplate},
+    layout_check::{check_header_layouts, generate_layout_assertions},
     par_iter_if_available, par_sort_by_if_available,
-    pdb_file::{self, ModuleList, PDBDataSource, PdbFile, SymbolList, TypeList},
-    pdb_types::{include_headers_for_flavor, PrimitiveReconstructionFlavor},
+    pdb_file::{
+        self, demangle_symbol_name, ModuleList, PDBDataSource, PDBReader, PdbFile,
+        ReconstructionSettings, SourceFileList, SymbolList, TypeList,
+    },
+    pdb_types::{
+        include_headers_for_flavor, CodeFormatConfiguration, EnumValueOrder, MemberFilter,
+        NumberFormat, OpaqueTypeRules, PrimitiveReconstructionFlavor, PrimitiveTypeMapping,
+        RenameMap, TypeNotes,
+    },
+    pe_file::PeFile,
     PKG_VERSION,
 };
 
 pub type PDBSlot = usize;
+/// List of types found in several PDBs, each tagged with the slot of the PDB
+/// it comes from
+pub type TaggedTypeList = Vec<(String, PDBSlot, pdb_file::TypeIndex)>;
+
+/// Priority with which the backend processes commands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandPriority {
+    /// Commands the user waits for (e.g., reconstructing the selected type or
+    /// updating a filter), processed before any pending batch command
+    Interactive,
+    /// Long-running commands working on whole PDBs (e.g., dumping all types
+    /// or diffing all types)
+    Batch,
+}
+
+/// Kinds of operations whose duration can be reported to the frontend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OperationKind {
+    Load,
+    Filter,
+    Reconstruction,
+    Diff,
+}
+
+impl OperationKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            OperationKind::Load => "Load",
+            OperationKind::Filter => "Filter",
+            OperationKind::Reconstruction => "Reconstruction",
+            OperationKind::Diff => "Diff",
+        }
+    }
+}
+
+/// Duration of an operation performed by the backend
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperationTiming {
+    pub kind: OperationKind,
+    /// Name of the command which triggered the operation (e.g., `ListTypes`)
+    pub name: &'static str,
+    pub duration: Duration,
+    /// Number of items the operation produced (e.g., types indexed when
+    /// loading a PDB or types matching a filter), when relevant
+    pub item_count: Option<usize>,
+}
+
+/// Options of the symbol listing commands
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SymbolListOptions {
+    pub case_insensitive_search: bool,
+    pub use_regex: bool,
+    pub ignore_std_types: bool,
+    /// Replace decorated names with their undecorated version before
+    /// filtering. Ignored when merging symbol lists, as symbols are paired by
+    /// name.
+    pub undecorate_names: bool,
+    pub kind_filter: pdb_file::SymbolKindFilter,
+}
 
 pub enum BackendCommand {
     /// Load a PDB file given its path as a `PathBuf`.
@@ -41,11 +121,80 @@ pub enum BackendCommand {
     LoadPDBFromVec(PDBSlot, String, Vec<u8>),
     /// Load a PDB file given its name and content as an `Arc<[u8]>`.
     LoadPDBFromArray(PDBSlot, String, Arc<[u8]>),
+    /// Load a PDB file given its name and a reader of its content (e.g.,
+    /// `Cursor::new(bytes)` for buffers owned by embedders).
+    LoadPDBFromData(PDBSlot, String, Box<dyn PDBReader>),
     /// Fetch data via HTTP given its URL as a `String`.
     #[cfg(feature = "http")]
     LoadPDBFromURL(PDBSlot, String),
+    /// Set the directory where PDB files downloaded with `LoadPDBFromURL` are
+    /// cached, so that they're only downloaded once. `None` disables caching.
+    /// Note: cached copies never expire, as symbol server URLs identify a
+    /// specific build of a PDB (see `RefreshURLCache` otherwise).
+    #[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+    SetURLCacheDirectory(Option<PathBuf>),
+    /// Enable or disable downloading PDB files with `LoadPDBFromURL` even if
+    /// they're cached, replacing the cached copies (e.g., when the file at a
+    /// URL changed).
+    #[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+    RefreshURLCache(bool),
     /// Unload a PDB file given its slot.
     UnloadPDB(PDBSlot),
+    /// Load a PE image given its path as a `PathBuf`, to relate the symbols
+    /// of the PDB file loaded in the same slot to the image's sections and
+    /// exports. The image is kept when the PDB file is (re)loaded.
+    #[cfg(not(target_arch = "wasm32"))]
+    LoadPEFromPath(PDBSlot, PathBuf),
+    /// Enable or disable watching PDB files loaded from disk, to notify the
+    /// frontend when they change.
+    #[cfg(all(not(target_arch = "wasm32"), feature = "watch"))]
+    SetWatchPDBFiles(bool),
+    /// Set the `clang-format` configuration used to format reconstructed code
+    /// (or stop formatting it if `None`).
+    #[cfg(not(target_arch = "wasm32"))]
+    SetClangFormat(Option<ClangFormatConfiguration>),
+    /// Set the rules used to treat types as opaque when reconstructing
+    /// dependencies, for all PDBs.
+    SetOpaqueTypeRules(OpaqueTypeRules),
+    /// Set the names of primitive types used with the `Custom` primitives
+    /// flavor, for all PDBs.
+    SetPrimitiveTypeMapping(PrimitiveTypeMapping),
+    /// Set the style applied to reconstructed types, for all PDBs.
+    SetCodeFormat(CodeFormatConfiguration),
+    /// Set the template of the header printed before reconstructed content,
+    /// for all PDBs.
+    SetHeaderTemplate(HeaderTemplate),
+    /// Set which members are omitted from reconstructed types, for all PDBs.
+    SetMemberFilter(MemberFilter),
+    /// Enable or disable RVA annotations on static data members, for all PDBs.
+    SetPrintStaticMemberRvas(bool),
+    /// Enable or disable Doxygen comments containing the metadata of types and
+    /// fields, for all PDBs.
+    SetDoxygenComments(bool),
+    /// Enable or disable comments with the bits of the enumerators of flag
+    /// enums, for all PDBs.
+    SetFlagEnumComments(bool),
+    /// Set the order in which enumerators are printed, for all PDBs.
+    SetEnumValueOrder(EnumValueOrder),
+    /// Enable or disable forward declarations of the user-defined types
+    /// referenced by reconstructed types, when their definitions aren't
+    /// reconstructed, for all PDBs.
+    SetForwardDeclareDependencies(bool),
+    /// Set the image base used to print virtual addresses next to RVAs (or
+    /// stop printing them if `None`), for all PDBs.
+    SetImageBase(Option<u64>),
+    /// Set the mode used to load PDBs. Only applies to PDBs loaded later on.
+    SetLoadMode(pdb_file::LoadMode),
+    /// Set the style of offsets, sizes and enum values, for all PDBs.
+    SetNumberFormat(NumberFormat),
+    /// Enable or disable reporting the duration of operations (e.g., loading,
+    /// filtering, reconstruction and diffing) to the frontend.
+    SetReportOperationTimings(bool),
+    /// Set user-defined names for types and members, by PDB GUID.
+    SetRenameMaps(HashMap<String, RenameMap>),
+    /// Set user notes emitted as comments above type definitions, for a
+    /// given PDB.
+    SetTypeNotes(PDBSlot, TypeNotes),
     /// Reconstruct a type given its type index for a given PDB.
     ReconstructTypeByIndex(
         PDBSlot,
@@ -66,19 +215,48 @@ pub enum BackendCommand {
         bool,
         bool,
     ),
+    /// Reconstruct several types given their type indices for a given PDB,
+    /// emitting shared dependencies once.
+    ReconstructTypesByIndex(
+        PDBSlot,
+        Vec<pdb_file::TypeIndex>,
+        PrimitiveReconstructionFlavor,
+        bool,
+        bool,
+        bool,
+        bool,
+    ),
     /// Reconstruct all types found in a given PDB.
     ReconstructAllTypes(PDBSlot, PrimitiveReconstructionFlavor, bool, bool, bool),
+    /// Reconstruct all enumerations found in a given PDB, sorted by name.
+    ReconstructAllEnums(PDBSlot, PrimitiveReconstructionFlavor, bool, bool),
     /// Retrieve a list of types that match the given filter for a given PDB.
     ListTypes(PDBSlot, String, bool, bool, bool),
     /// Retrieve a list of types that match the given filter for multiple PDBs
     /// and merge the result.
     ListTypesMerged(Vec<PDBSlot>, String, bool, bool, bool),
-    /// Retrieve a list of symbols that match the given filter for multiple PDBs
-    /// and merge the result.
-    ListSymbols(PDBSlot, String, bool, bool, bool),
-    /// Retrieve a list of symbols that match the given filter for multiple PDBs
-    /// and merge the result.
-    ListSymbolsMerged(Vec<PDBSlot>, String, bool, bool, bool),
+    /// Retrieve a list of types that match the given filter for multiple PDBs,
+    /// without merging types found in several of them. Types are sorted by
+    /// name and tagged with the slot of their PDB.
+    ListTypesTagged(Vec<PDBSlot>, String, bool, bool, bool),
+    /// Retrieve a list of types that match the given filter for two PDBs,
+    /// merge the result and only keep the types whose reconstruction differs
+    /// between the two. Changed types are computed on first use, and cached
+    /// until PDBs are loaded or settings change.
+    /// Note: this is a batch command, as computing changed types diffs every
+    /// type of both PDBs.
+    ListChangedTypesMerged(PDBSlot, PDBSlot, String, bool, bool, DiffOptions),
+    /// Retrieve the kind and size of all types of a given PDB.
+    ListTypeInfos(PDBSlot),
+    /// Retrieve the types of a given PDB whose name fuzzy-matches the given
+    /// query, best matches first, up to the given count.
+    FuzzyFindTypes(PDBSlot, String, usize),
+    /// Retrieve a list of symbols that match the given filter and kinds for a
+    /// given PDB.
+    ListSymbols(PDBSlot, String, SymbolListOptions),
+    /// Retrieve a list of symbols that match the given filter and kinds for
+    /// multiple PDBs and merge the result.
+    ListSymbolsMerged(Vec<PDBSlot>, String, SymbolListOptions),
     /// Reconstruct a symbol given its index for a given PDB.
     ReconstructSymbolByIndex(
         PDBSlot,
@@ -91,19 +269,40 @@ pub enum BackendCommand {
     ReconstructSymbolByName(PDBSlot, String, PrimitiveReconstructionFlavor, bool, bool),
     /// Reconstruct all symbols found in a given PDB.
     ReconstructAllSymbols(PDBSlot, PrimitiveReconstructionFlavor, bool, bool),
+    /// Retrieve the constants (`S_CONSTANT` symbols) of a given PDB.
+    ListConstants(PDBSlot, PrimitiveReconstructionFlavor),
     /// Retrieve a list of modules that match the given filter for multiple PDBs
     /// and merge the result.
     ListModules(PDBSlot, String, bool, bool),
     /// Reconstruct a module given its index for a given PDB.
     ReconstructModuleByIndex(PDBSlot, usize, PrimitiveReconstructionFlavor, bool, bool),
+    /// Retrieve a list of source files that match the given filter for a given PDB.
+    ListSourceFiles(PDBSlot, String, bool, bool),
+    /// Reconstruct the functions defined in a source file given its path, for a given PDB.
+    ReconstructSourceFileByPath(PDBSlot, String, PrimitiveReconstructionFlavor, bool, bool),
     /// Reconstruct the diff of a type given its name.
     DiffTypeByName(
         PDBSlot,
         PDBSlot,
         String,
         PrimitiveReconstructionFlavor,
-        bool,
-        bool,
+        DiffOptions,
+    ),
+    /// Reconstruct the three-way diff of a type given its name (base PDB,
+    /// then the two variants).
+    DiffTypeByNameThreeWay(
+        PDBSlot,
+        PDBSlot,
+        PDBSlot,
+        String,
+        PrimitiveReconstructionFlavor,
+        DiffOptions,
+    ),
+    /// Compare a type across the given PDBs (in order).
+    CompareTypeAcrossPDBs(
+        Vec<PDBSlot>,
+        String,
+        PrimitiveReconstructionFlavor,
         bool,
         bool,
     ),
@@ -115,6 +314,7 @@ pub enum BackendCommand {
         PrimitiveReconstructionFlavor,
         bool,
         bool,
+        bool,
     ),
     /// Reconstruct the diff of a module given its path.
     DiffModuleByPath(
@@ -124,15 +324,442 @@ pub enum BackendCommand {
         PrimitiveReconstructionFlavor,
         bool,
         bool,
+        bool,
+    ),
+    /// Reconstruct the diff of all public and function symbols.
+    DiffPublicSymbols(
+        PDBSlot,
+        PDBSlot,
+        PrimitiveReconstructionFlavor,
+        bool,
+        bool,
+        bool,
+    ),
+    /// Diff all the types defined in two PDBs (whose name matches the given
+    /// filter, if any), and retrieve the ones that changed.
+    DiffAllTypes(
+        PDBSlot,
+        PDBSlot,
+        Option<regex::Regex>,
+        PrimitiveReconstructionFlavor,
+        DiffOptions,
     ),
+    /// Compare the layouts of the types defined in the given C/C++ header
+    /// against the ones found in a PDB.
+    CheckHeaderLayouts(PDBSlot, String),
     /// Retrieve a list of all types that reference the given type
     ListTypeCrossReferences(PDBSlot, pdb_file::TypeIndex),
+    /// Retrieve a list of all types that depend on the given type, directly
+    /// or indirectly
+    ListTypeReverseDependencies(PDBSlot, pdb_file::TypeIndex),
+    /// Retrieve the dependency graph of a given type.
+    GetTypeDependencyGraph(PDBSlot, pdb_file::TypeIndex, bool),
+    /// Export the dependency graph of a given type (or of all types if `None`)
+    /// in the Graphviz DOT format.
+    ExportTypeDependencyGraph(PDBSlot, Option<pdb_file::TypeIndex>, bool),
+    /// Export the dependency graph of a given type in the Graphviz DOT format.
+    ExportTypeDependencyGraphByName(PDBSlot, String, bool),
+    /// Export `static_assert`s checking the layouts of the given types (or of
+    /// all types if `None`), as a C++ translation unit.
+    ExportLayoutAssertions(PDBSlot, Option<Vec<String>>, bool),
+    /// Find the symbol located at (or preceding) the given RVA.
+    LookupSymbolByRVA(PDBSlot, u32),
+    /// Retrieve information about a given PDB.
+    GetPDBMetadata(PDBSlot),
+    /// Retrieve the list of MSF streams of a given PDB.
+    ListStreams(PDBSlot),
+    /// Generate an hexadecimal dump of a given MSF stream.
+    DumpStream(PDBSlot, pdb_file::StreamIndex),
+    /// Retrieve the location of a symbol given its index.
+    LookupSymbolLocationByIndex(PDBSlot, pdb_file::SymbolIndex),
+    /// Retrieve the location of a symbol given its name.
+    LookupSymbolLocationByName(PDBSlot, String),
+    /// Retrieve the properties of a type given its index.
+    LookupTypeProperties(PDBSlot, pdb_file::TypeIndex),
+    /// Retrieve the source files defining the methods of a type, given its
+    /// name.
+    LookupTypeSourceLocations(PDBSlot, String),
+}
+
+impl BackendCommand {
+    /// Name of the command, used to tell which command failed or how long
+    /// it took
+    pub fn name(&self) -> &'static str {
+        match self {
+            #[cfg(not(target_arch = "wasm32"))]
+            BackendCommand::LoadPDBFromPath(..) => "LoadPDBFromPath",
+            BackendCommand::LoadPDBFromVec(..) => "LoadPDBFromVec",
+            BackendCommand::LoadPDBFromArray(..) => "LoadPDBFromArray",
+            BackendCommand::LoadPDBFromData(..) => "LoadPDBFromData",
+            #[cfg(feature = "http")]
+            BackendCommand::LoadPDBFromURL(..) => "LoadPDBFromURL",
+            #[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+            BackendCommand::SetURLCacheDirectory(..) => "SetURLCacheDirectory",
+            #[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+            BackendCommand::RefreshURLCache(..) => "RefreshURLCache",
+            BackendCommand::UnloadPDB(..) => "UnloadPDB",
+            #[cfg(not(target_arch = "wasm32"))]
+            BackendCommand::LoadPEFromPath(..) => "LoadPEFromPath",
+            #[cfg(all(not(target_arch = "wasm32"), feature = "watch"))]
+            BackendCommand::SetWatchPDBFiles(..) => "SetWatchPDBFiles",
+            #[cfg(not(target_arch = "wasm32"))]
+            BackendCommand::SetClangFormat(..) => "SetClangFormat",
+            BackendCommand::SetOpaqueTypeRules(..) => "SetOpaqueTypeRules",
+            BackendCommand::SetPrimitiveTypeMapping(..) => "SetPrimitiveTypeMapping",
+            BackendCommand::SetCodeFormat(..) => "SetCodeFormat",
+            BackendCommand::SetHeaderTemplate(..) => "SetHeaderTemplate",
+            BackendCommand::SetMemberFilter(..) => "SetMemberFilter",
+            BackendCommand::SetPrintStaticMemberRvas(..) => "SetPrintStaticMemberRvas",
+            BackendCommand::SetDoxygenComments(..) => "SetDoxygenComments",
+            BackendCommand::SetFlagEnumComments(..) => "SetFlagEnumComments",
+            BackendCommand::SetEnumValueOrder(..) => "SetEnumValueOrder",
+            BackendCommand::SetForwardDeclareDependencies(..) => "SetForwardDeclareDependencies",
+            BackendCommand::SetImageBase(..) => "SetImageBase",
+            BackendCommand::SetLoadMode(..) => "SetLoadMode",
+            BackendCommand::SetNumberFormat(..) => "SetNumberFormat",
+            BackendCommand::SetReportOperationTimings(..) => "SetReportOperationTimings",
+            BackendCommand::SetRenameMaps(..) => "SetRenameMaps",
+            BackendCommand::SetTypeNotes(..) => "SetTypeNotes",
+            BackendCommand::ReconstructTypeByIndex(..) => "ReconstructTypeByIndex",
+            BackendCommand::ReconstructTypeByName(..) => "ReconstructTypeByName",
+            BackendCommand::ReconstructTypesByIndex(..) => "ReconstructTypesByIndex",
+            BackendCommand::ReconstructAllTypes(..) => "ReconstructAllTypes",
+            BackendCommand::ReconstructAllEnums(..) => "ReconstructAllEnums",
+            BackendCommand::ListTypes(..) => "ListTypes",
+            BackendCommand::ListTypesMerged(..) => "ListTypesMerged",
+            BackendCommand::ListTypesTagged(..) => "ListTypesTagged",
+            BackendCommand::ListChangedTypesMerged(..) => "ListChangedTypesMerged",
+            BackendCommand::ListTypeInfos(..) => "ListTypeInfos",
+            BackendCommand::FuzzyFindTypes(..) => "FuzzyFindTypes",
+            BackendCommand::ListSymbols(..) => "ListSymbols",
+            BackendCommand::ListSymbolsMerged(..) => "ListSymbolsMerged",
+            BackendCommand::ReconstructSymbolByIndex(..) => "ReconstructSymbolByIndex",
+            BackendCommand::ReconstructSymbolByName(..) => "ReconstructSymbolByName",
+            BackendCommand::ReconstructAllSymbols(..) => "ReconstructAllSymbols",
+            BackendCommand::ListConstants(..) => "ListConstants",
+            BackendCommand::ListModules(..) => "ListModules",
+            BackendCommand::ReconstructModuleByIndex(..) => "ReconstructModuleByIndex",
+            BackendCommand::ListSourceFiles(..) => "ListSourceFiles",
+            BackendCommand::ReconstructSourceFileByPath(..) => "ReconstructSourceFileByPath",
+            BackendCommand::DiffTypeByName(..) => "DiffTypeByName",
+            BackendCommand::DiffTypeByNameThreeWay(..) => "DiffTypeByNameThreeWay",
+            BackendCommand::CompareTypeAcrossPDBs(..) => "CompareTypeAcrossPDBs",
+            BackendCommand::DiffSymbolByName(..) => "DiffSymbolByName",
+            BackendCommand::DiffModuleByPath(..) => "DiffModuleByPath",
+            BackendCommand::DiffPublicSymbols(..) => "DiffPublicSymbols",
+            BackendCommand::DiffAllTypes(..) => "DiffAllTypes",
+            BackendCommand::CheckHeaderLayouts(..) => "CheckHeaderLayouts",
+            BackendCommand::ListTypeCrossReferences(..) => "ListTypeCrossReferences",
+            BackendCommand::ListTypeReverseDependencies(..) => "ListTypeReverseDependencies",
+            BackendCommand::GetTypeDependencyGraph(..) => "GetTypeDependencyGraph",
+            BackendCommand::ExportTypeDependencyGraph(..) => "ExportTypeDependencyGraph",
+            BackendCommand::ExportTypeDependencyGraphByName(..) => {
+                "ExportTypeDependencyGraphByName"
+            }
+            BackendCommand::ExportLayoutAssertions(..) => "ExportLayoutAssertions",
+            BackendCommand::LookupSymbolByRVA(..) => "LookupSymbolByRVA",
+            BackendCommand::GetPDBMetadata(..) => "GetPDBMetadata",
+            BackendCommand::ListStreams(..) => "ListStreams",
+            BackendCommand::DumpStream(..) => "DumpStream",
+            BackendCommand::LookupSymbolLocationByIndex(..) => "LookupSymbolLocationByIndex",
+            BackendCommand::LookupSymbolLocationByName(..) => "LookupSymbolLocationByName",
+            BackendCommand::LookupTypeProperties(..) => "LookupTypeProperties",
+            BackendCommand::LookupTypeSourceLocations(..) => "LookupTypeSourceLocations",
+        }
+    }
+
+    /// PDB slot the command operates on (the first one, for commands
+    /// operating on several PDBs), if any
+    pub fn pdb_slot(&self) -> Option<PDBSlot> {
+        match self {
+            #[cfg(not(target_arch = "wasm32"))]
+            BackendCommand::LoadPDBFromPath(pdb_slot, ..) => Some(*pdb_slot),
+            #[cfg(feature = "http")]
+            BackendCommand::LoadPDBFromURL(pdb_slot, ..) => Some(*pdb_slot),
+            #[cfg(not(target_arch = "wasm32"))]
+            BackendCommand::LoadPEFromPath(pdb_slot, ..) => Some(*pdb_slot),
+            BackendCommand::LoadPDBFromVec(pdb_slot, ..)
+            | BackendCommand::LoadPDBFromArray(pdb_slot, ..)
+            | BackendCommand::LoadPDBFromData(pdb_slot, ..)
+            | BackendCommand::SetTypeNotes(pdb_slot, ..)
+            | BackendCommand::ReconstructTypeByIndex(pdb_slot, ..)
+            | BackendCommand::ReconstructTypeByName(pdb_slot, ..)
+            | BackendCommand::ReconstructTypesByIndex(pdb_slot, ..)
+            | BackendCommand::ReconstructAllTypes(pdb_slot, ..)
+            | BackendCommand::ReconstructAllEnums(pdb_slot, ..)
+            | BackendCommand::ListTypes(pdb_slot, ..)
+            | BackendCommand::ListChangedTypesMerged(pdb_slot, ..)
+            | BackendCommand::ListSymbols(pdb_slot, ..)
+            | BackendCommand::ReconstructSymbolByIndex(pdb_slot, ..)
+            | BackendCommand::ReconstructSymbolByName(pdb_slot, ..)
+            | BackendCommand::ReconstructAllSymbols(pdb_slot, ..)
+            | BackendCommand::ListConstants(pdb_slot, ..)
+            | BackendCommand::ListModules(pdb_slot, ..)
+            | BackendCommand::ReconstructModuleByIndex(pdb_slot, ..)
+            | BackendCommand::ListSourceFiles(pdb_slot, ..)
+            | BackendCommand::ReconstructSourceFileByPath(pdb_slot, ..)
+            | BackendCommand::DiffTypeByName(pdb_slot, ..)
+            | BackendCommand::DiffTypeByNameThreeWay(pdb_slot, ..)
+            | BackendCommand::DiffSymbolByName(pdb_slot, ..)
+            | BackendCommand::DiffModuleByPath(pdb_slot, ..)
+            | BackendCommand::DiffPublicSymbols(pdb_slot, ..)
+            | BackendCommand::DiffAllTypes(pdb_slot, ..)
+            | BackendCommand::CheckHeaderLayouts(pdb_slot, ..)
+            | BackendCommand::ListTypeCrossReferences(pdb_slot, ..)
+            | BackendCommand::ListTypeReverseDependencies(pdb_slot, ..)
+            | BackendCommand::GetTypeDependencyGraph(pdb_slot, ..)
+            | BackendCommand::ExportTypeDependencyGraph(pdb_slot, ..)
+            | BackendCommand::ExportTypeDependencyGraphByName(pdb_slot, ..)
+            | BackendCommand::ExportLayoutAssertions(pdb_slot, ..)
+            | BackendCommand::LookupSymbolByRVA(pdb_slot, ..)
+            | BackendCommand::DumpStream(pdb_slot, ..)
+            | BackendCommand::LookupSymbolLocationByIndex(pdb_slot, ..)
+            | BackendCommand::LookupSymbolLocationByName(pdb_slot, ..)
+            | BackendCommand::LookupTypeProperties(pdb_slot, ..)
+            | BackendCommand::LookupTypeSourceLocations(pdb_slot, ..)
+            | BackendCommand::FuzzyFindTypes(pdb_slot, ..)
+            | BackendCommand::UnloadPDB(pdb_slot)
+            | BackendCommand::ListTypeInfos(pdb_slot)
+            | BackendCommand::GetPDBMetadata(pdb_slot)
+            | BackendCommand::ListStreams(pdb_slot) => Some(*pdb_slot),
+            BackendCommand::ListTypesMerged(pdb_slots, ..)
+            | BackendCommand::ListTypesTagged(pdb_slots, ..)
+            | BackendCommand::ListSymbolsMerged(pdb_slots, ..)
+            | BackendCommand::CompareTypeAcrossPDBs(pdb_slots, ..) => pdb_slots.first().copied(),
+            _ => None,
+        }
+    }
+
+    /// Context attached to errors occurring while processing the command
+    fn error_context(&self) -> ErrorContext {
+        ErrorContext {
+            command: self.name(),
+            pdb_slot: self.pdb_slot(),
+        }
+    }
+
+    /// Kind and name of the operation performed by the command, if its
+    /// duration is worth reporting
+    fn timed_operation(&self) -> Option<(OperationKind, &'static str)> {
+        let operation_kind = match self {
+            #[cfg(not(target_arch = "wasm32"))]
+            BackendCommand::LoadPDBFromPath(..) | BackendCommand::LoadPEFromPath(..) => {
+                OperationKind::Load
+            }
+            #[cfg(feature = "http")]
+            BackendCommand::LoadPDBFromURL(..) => OperationKind::Load,
+            BackendCommand::LoadPDBFromVec(..)
+            | BackendCommand::LoadPDBFromArray(..)
+            | BackendCommand::LoadPDBFromData(..) => OperationKind::Load,
+            BackendCommand::ListTypes(..)
+            | BackendCommand::ListTypesMerged(..)
+            | BackendCommand::ListTypesTagged(..)
+            | BackendCommand::ListChangedTypesMerged(..)
+            | BackendCommand::ListTypeInfos(..)
+            | BackendCommand::FuzzyFindTypes(..)
+            | BackendCommand::ListSymbols(..)
+            | BackendCommand::ListSymbolsMerged(..)
+            | BackendCommand::ListConstants(..)
+            | BackendCommand::ListModules(..)
+            | BackendCommand::ListSourceFiles(..) => OperationKind::Filter,
+            BackendCommand::ReconstructTypeByIndex(..)
+            | BackendCommand::ReconstructTypeByName(..)
+            | BackendCommand::ReconstructTypesByIndex(..)
+            | BackendCommand::ReconstructAllTypes(..)
+            | BackendCommand::ReconstructAllEnums(..)
+            | BackendCommand::ReconstructSymbolByIndex(..)
+            | BackendCommand::ReconstructSymbolByName(..)
+            | BackendCommand::ReconstructAllSymbols(..)
+            | BackendCommand::ReconstructModuleByIndex(..)
+            | BackendCommand::ReconstructSourceFileByPath(..)
+            | BackendCommand::ExportLayoutAssertions(..) => OperationKind::Reconstruction,
+            BackendCommand::DiffTypeByName(..)
+            | BackendCommand::DiffTypeByNameThreeWay(..)
+            | BackendCommand::CompareTypeAcrossPDBs(..)
+            | BackendCommand::DiffSymbolByName(..)
+            | BackendCommand::DiffModuleByPath(..)
+            | BackendCommand::DiffPublicSymbols(..)
+            | BackendCommand::DiffAllTypes(..) => OperationKind::Diff,
+            _ => return None,
+        };
+
+        Some((operation_kind, self.name()))
+    }
+
+    /// Batch commands are processed once no interactive command is pending.
+    /// Note: a batch command which is already being processed isn't
+    /// interrupted, and commands changing the state batch commands depend on
+    /// (e.g., loading PDBs or changing settings) wait for the ones received
+    /// before them.
+    pub fn priority(&self) -> CommandPriority {
+        match self {
+            BackendCommand::ReconstructAllTypes(..)
+            | BackendCommand::ReconstructAllEnums(..)
+            | BackendCommand::ReconstructAllSymbols(..)
+            | BackendCommand::ListChangedTypesMerged(..)
+            | BackendCommand::DiffPublicSymbols(..)
+            | BackendCommand::DiffAllTypes(..) => CommandPriority::Batch,
+            _ => CommandPriority::Interactive,
+        }
+    }
+
+    /// Indicate if the command may change how types are reconstructed (e.g.,
+    /// by loading a PDB or changing settings), which invalidates cached
+    /// results
+    fn invalidates_reconstructions(&self) -> bool {
+        match self {
+            #[cfg(not(target_arch = "wasm32"))]
+            BackendCommand::LoadPDBFromPath(..) | BackendCommand::LoadPEFromPath(..) => true,
+            #[cfg(feature = "http")]
+            BackendCommand::LoadPDBFromURL(..) => true,
+            BackendCommand::LoadPDBFromVec(..)
+            | BackendCommand::LoadPDBFromArray(..)
+            | BackendCommand::LoadPDBFromData(..)
+            | BackendCommand::UnloadPDB(..)
+            | BackendCommand::SetOpaqueTypeRules(..)
+            | BackendCommand::SetPrimitiveTypeMapping(..)
+            | BackendCommand::SetCodeFormat(..)
+            | BackendCommand::SetMemberFilter(..)
+            | BackendCommand::SetPrintStaticMemberRvas(..)
+            | BackendCommand::SetDoxygenComments(..)
+            | BackendCommand::SetFlagEnumComments(..)
+            | BackendCommand::SetEnumValueOrder(..)
+            | BackendCommand::SetForwardDeclareDependencies(..)
+            | BackendCommand::SetImageBase(..)
+            | BackendCommand::SetNumberFormat(..)
+            | BackendCommand::SetRenameMaps(..)
+            | BackendCommand::SetTypeNotes(..) => true,
+            _ => false,
+        }
+    }
+
+    /// Indicate if the command changes the state other commands depend on
+    /// (i.e., loaded PDBs or settings)
+    fn changes_state(&self) -> bool {
+        match self {
+            #[cfg(not(target_arch = "wasm32"))]
+            BackendCommand::LoadPDBFromPath(..) | BackendCommand::LoadPEFromPath(..) => true,
+            #[cfg(feature = "http")]
+            BackendCommand::LoadPDBFromURL(..) => true,
+            #[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+            BackendCommand::SetURLCacheDirectory(..) | BackendCommand::RefreshURLCache(..) => true,
+            #[cfg(all(not(target_arch = "wasm32"), feature = "watch"))]
+            BackendCommand::SetWatchPDBFiles(..) => true,
+            #[cfg(not(target_arch = "wasm32"))]
+            BackendCommand::SetClangFormat(..) => true,
+            BackendCommand::LoadPDBFromVec(..)
+            | BackendCommand::LoadPDBFromArray(..)
+            | BackendCommand::LoadPDBFromData(..)
+            | BackendCommand::UnloadPDB(..)
+            | BackendCommand::SetOpaqueTypeRules(..)
+            | BackendCommand::SetPrimitiveTypeMapping(..)
+            | BackendCommand::SetCodeFormat(..)
+            | BackendCommand::SetHeaderTemplate(..)
+            | BackendCommand::SetMemberFilter(..)
+            | BackendCommand::SetPrintStaticMemberRvas(..)
+            | BackendCommand::SetDoxygenComments(..)
+            | BackendCommand::SetFlagEnumComments(..)
+            | BackendCommand::SetEnumValueOrder(..)
+            | BackendCommand::SetForwardDeclareDependencies(..)
+            | BackendCommand::SetImageBase(..)
+            | BackendCommand::SetLoadMode(..)
+            | BackendCommand::SetNumberFormat(..)
+            | BackendCommand::SetReportOperationTimings(..)
+            | BackendCommand::SetRenameMaps(..)
+            | BackendCommand::SetTypeNotes(..) => true,
+            _ => false,
+        }
+    }
+
+    /// Indicate if the command operates on the PDB loaded in the given slot
+    fn uses_pdb_slot(&self, pdb_slot: PDBSlot) -> bool {
+        match self {
+            BackendCommand::ListChangedTypesMerged(pdb_slot_from, pdb_slot_to, ..)
+            | BackendCommand::DiffPublicSymbols(pdb_slot_from, pdb_slot_to, ..)
+            | BackendCommand::DiffAllTypes(pdb_slot_from, pdb_slot_to, ..) => {
+                *pdb_slot_from == pdb_slot || *pdb_slot_to == pdb_slot
+            }
+            BackendCommand::ListTypesMerged(pdb_slots, ..)
+            | BackendCommand::ListTypesTagged(pdb_slots, ..)
+            | BackendCommand::ListSymbolsMerged(pdb_slots, ..)
+            | BackendCommand::CompareTypeAcrossPDBs(pdb_slots, ..) => pdb_slots.contains(&pdb_slot),
+            _ => self.pdb_slot() == Some(pdb_slot),
+        }
+    }
+
+    /// Indicate if the command must wait for `older_command`, received before
+    /// it, to be processed. Commands changing the state of a PDB (or the
+    /// settings of all PDBs) wait for the batch commands using it.
+    fn must_wait_for(&self, older_command: &BackendCommand) -> bool {
+        if !self.changes_state() || older_command.priority() != CommandPriority::Batch {
+            return false;
+        }
+
+        match self.pdb_slot() {
+            Some(pdb_slot) => older_command.uses_pdb_slot(pdb_slot),
+            None => true,
+        }
+    }
+}
+
+/// Command sent to the worker, with the token used to cancel it
+type QueuedCommand = (BackendCommand, CancellationToken);
+
+/// Commands received by the worker which haven't been processed yet, in the
+/// order they were received
+#[derive(Default)]
+struct CommandQueue {
+    commands: VecDeque<QueuedCommand>,
+}
+
+impl CommandQueue {
+    /// Wait for the next command to process, interactive commands first.
+    /// Returns `None` once the backend has been dropped and all the commands
+    /// have been processed.
+    fn next_command(&mut self, rx_worker: &Receiver<QueuedCommand>) -> Option<QueuedCommand> {
+        if self.commands.is_empty() {
+            let command = rx_worker.recv().ok()?;
+            self.commands.push_back(command);
+        }
+        self.commands.extend(rx_worker.try_iter());
+
+        let command_index = self.next_command_index();
+        self.commands.remove(command_index)
+    }
+
+    /// Index of the first interactive command, unless it (or an interactive
+    /// command received before it) must wait for batch commands received
+    /// before it. Batch commands are processed in order otherwise.
+    fn next_command_index(&self) -> usize {
+        for (command_index, (command, _)) in self.commands.iter().enumerate() {
+            if command.priority() == CommandPriority::Batch {
+                continue;
+            }
+            if self
+                .commands
+                .range(..command_index)
+                .any(|(older_command, _)| command.must_wait_for(older_command))
+            {
+                break;
+            }
+            return command_index;
+        }
+
+        self.commands
+            .iter()
+            .position(|(command, _)| command.priority() == CommandPriority::Batch)
+            .unwrap_or_default()
+    }
 }
 
 /// Struct that represents the backend. The backend is responsible
 /// for the actual PDB processing (e.g., type listing and reconstruction).
 pub struct Backend {
-    tx_worker: Sender<BackendCommand>,
+    tx_worker: Sender<QueuedCommand>,
+    /// Number of commands sent to the worker which haven't been processed yet
+    pending_commands: Arc<AtomicUsize>,
+    /// Token given to the commands sent since the last cancellation
+    cancellation_token: Mutex<CancellationToken>,
     #[cfg(feature = "rayon")]
     _worker_thread_pool: ThreadPool,
     #[cfg(not(feature = "rayon"))]
@@ -145,7 +772,9 @@ impl Backend {
     pub fn new(
         frontend_controller: Arc<impl FrontendController + Send + Sync + 'static>,
     ) -> Result<Self> {
-        let (tx_worker, rx_worker) = crossbeam_channel::unbounded::<BackendCommand>();
+        let (tx_worker, rx_worker) = crossbeam_channel::unbounded::<QueuedCommand>();
+        let pending_commands = Arc::new(AtomicUsize::new(0));
+        let worker_pending_commands = pending_commands.clone();
 
         // Start a thread pool with as many threads as there are CPUs on the machine,
         // minus one (because we account for the GUI thread).
@@ -155,7 +784,11 @@ impl Backend {
             .num_threads(cpu_count - 1)
             .build()?;
         thread_pool.spawn(move || {
-            let exit_result = worker_thread_routine(rx_worker, frontend_controller.clone());
+            let exit_result = worker_thread_routine(
+                rx_worker,
+                worker_pending_commands,
+                frontend_controller.clone(),
+            );
             if let Err(err) = exit_result {
                 log::error!("Background thread aborted: {}", err);
             }
@@ -164,6 +797,8 @@ impl Backend {
 
         Ok(Self {
             tx_worker,
+            pending_commands,
+            cancellation_token: Mutex::default(),
             _worker_thread_pool: thread_pool,
         })
     }
@@ -173,11 +808,17 @@ impl Backend {
     pub fn new(
         frontend_controller: Arc<impl FrontendController + Send + Sync + 'static>,
     ) -> Result<Self> {
-        let (tx_worker, rx_worker) = crossbeam_channel::unbounded::<BackendCommand>();
+        let (tx_worker, rx_worker) = crossbeam_channel::unbounded::<QueuedCommand>();
+        let pending_commands = Arc::new(AtomicUsize::new(0));
+        let worker_pending_commands = pending_commands.clone();
 
         // Start a new thread
         let worker_thread = thread::spawn(move || {
-            let exit_result = worker_thread_routine(rx_worker, frontend_controller.clone());
+            let exit_result = worker_thread_routine(
+                rx_worker,
+                worker_pending_commands,
+                frontend_controller.clone(),
+            );
             if let Err(err) = exit_result {
                 log::error!("Background thread aborted: {}", err);
             }
@@ -186,35 +827,144 @@ impl Backend {
 
         Ok(Self {
             tx_worker,
+            pending_commands,
+            cancellation_token: Mutex::default(),
             _worker_thread: worker_thread,
         })
     }
 
     pub fn send_command(&self, command: BackendCommand) -> Result<()> {
+        let cancellation_token = self
+            .cancellation_token
+            .lock()
+            .expect("lock shouldn't be poisoned")
+            .clone();
+        self.pending_commands.fetch_add(1, Ordering::SeqCst);
         self.tx_worker
-            .send(command)
-            .map_err(|err| ResymCoreError::CrossbeamError(err.to_string()))
+            .send((command, cancellation_token))
+            .map_err(|err| {
+                self.pending_commands.fetch_sub(1, Ordering::SeqCst);
+                ResymCoreError::CrossbeamError(err.to_string())
+            })
+    }
+
+    /// Number of commands that have been sent but haven't been processed yet
+    /// (i.e., operations in progress)
+    pub fn pending_command_count(&self) -> usize {
+        self.pending_commands.load(Ordering::SeqCst)
+    }
+
+    /// Cancel the operations in progress which support it (i.e., loading PDBs
+    /// and reconstructing all types). Cancelled loads leave the slot as it was
+    /// before the load was requested.
+    /// Note: commands sent afterwards aren't affected, and nothing happens if
+    /// no command is pending.
+    pub fn cancel_operations(&self) {
+        if self.pending_command_count() == 0 {
+            return;
+        }
+        let mut cancellation_token = self
+            .cancellation_token
+            .lock()
+            .expect("lock shouldn't be poisoned");
+        cancellation_token.cancel();
+        *cancellation_token = CancellationToken::default();
+    }
+}
+
+/// Frontend controller attaching the context of the command being processed
+/// to the errors it sends
+struct CommandFrontendController<F> {
+    inner: Arc<F>,
+    error_context: ErrorContext,
+}
+
+impl<F: FrontendController> FrontendController for CommandFrontendController<F> {
+    fn send_command(&self, command: FrontendCommand) -> Result<()> {
+        self.inner
+            .send_command(command.with_error_context(self.error_context))
     }
 }
 
 /// Main backend routine. This processes commands sent by the frontend and sends
 /// results back.
 fn worker_thread_routine(
-    rx_worker: Receiver<BackendCommand>,
-    frontend_controller: Arc<impl FrontendController + Send + Sync + 'static>,
+    rx_worker: Receiver<QueuedCommand>,
+    pending_commands: Arc<AtomicUsize>,
+    base_frontend_controller: Arc<impl FrontendController + Send + Sync + 'static>,
 ) -> Result<()> {
     let mut pdb_files: HashMap<PDBSlot, PdbFile<PDBDataSource>> = HashMap::new();
-    while let Ok(command) = rx_worker.recv() {
+    // Note: settings are applied to loaded PDBs, as well as PDBs loaded later on
+    let mut reconstruction_settings = ReconstructionSettings::default();
+    let mut load_mode = pdb_file::LoadMode::default();
+    let mut report_operation_timings = false;
+    #[cfg(all(not(target_arch = "wasm32"), feature = "watch"))]
+    let mut watch_pdb_files = false;
+    #[cfg(all(not(target_arch = "wasm32"), feature = "watch"))]
+    let mut pdb_file_watchers: HashMap<PDBSlot, notify::RecommendedWatcher> = HashMap::new();
+    #[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+    let mut url_cache_directory: Option<PathBuf> = None;
+    #[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+    let mut refresh_url_cache = false;
+    let mut rename_maps: HashMap<String, RenameMap> = HashMap::new();
+    let mut pe_files: HashMap<PDBSlot, PeFile> = HashMap::new();
+    // Names of the types that changed between two PDBs, by pair of slots and
+    // diff options
+    let mut changed_type_names_cache: HashMap<(PDBSlot, PDBSlot, DiffOptions), BTreeSet<String>> =
+        HashMap::new();
+    let mut command_queue = CommandQueue::default();
+    while let Some((command, cancellation_token)) = command_queue.next_command(&rx_worker) {
+        if command.invalidates_reconstructions() {
+            changed_type_names_cache.clear();
+        }
+        let timed_operation = if report_operation_timings {
+            command.timed_operation()
+        } else {
+            None
+        };
+        let operation_start = Instant::now();
+        let mut item_count = None;
+        // Errors sent to the frontend tell which command failed
+        let frontend_controller = Arc::new(CommandFrontendController {
+            inner: base_frontend_controller.clone(),
+            error_context: command.error_context(),
+        });
         match command {
             #[cfg(not(target_arch = "wasm32"))]
             BackendCommand::LoadPDBFromPath(pdb_slot, pdb_file_path) => {
                 log::info!("Loading a new PDB file ...");
-                match PdbFile::load_from_file(&pdb_file_path) {
+                match PdbFile::load_from_file_with_mode(
+                    &pdb_file_path,
+                    load_mode,
+                    &cancellation_token,
+                ) {
                     Err(err) => frontend_controller
                         .send_command(FrontendCommand::LoadPDBResult(Err(err)))?,
-                    Ok(loaded_pdb_file) => {
+                    Ok(mut loaded_pdb_file) => {
+                        loaded_pdb_file.settings = reconstruction_settings.clone();
+                        loaded_pdb_file.pe_file = pe_files.get(&pdb_slot).cloned();
+                        loaded_pdb_file.rename_map =
+                            rename_map_for_pdb(&rename_maps, &loaded_pdb_file);
+                        item_count = Some(loaded_pdb_file.type_infos.len());
                         frontend_controller
                             .send_command(FrontendCommand::LoadPDBResult(Ok(pdb_slot)))?;
+                        #[cfg(all(not(target_arch = "wasm32"), feature = "watch"))]
+                        if watch_pdb_files {
+                            // Notify the frontend when the file changes on disk
+                            match watch_pdb_file(
+                                pdb_slot,
+                                &pdb_file_path,
+                                base_frontend_controller.clone(),
+                            ) {
+                                Ok(watcher) => {
+                                    pdb_file_watchers.insert(pdb_slot, watcher);
+                                }
+                                Err(err) => {
+                                    pdb_file_watchers.remove(&pdb_slot);
+                                    log::error!("Failed to watch PDB file: {err}");
+                                }
+                            }
+                        }
                         if let Some(pdb_file) = pdb_files.insert(pdb_slot, loaded_pdb_file) {
                             log::info!("'{}' has been unloaded.", pdb_file.file_path.display());
                         }
@@ -228,12 +978,25 @@ fn worker_thread_routine(
 
             BackendCommand::LoadPDBFromVec(pdb_slot, pdb_name, pdb_data) => {
                 log::info!("Loading a new PDB file ...");
-                match PdbFile::load_from_bytes_as_vec(pdb_name.clone(), pdb_data) {
+                match PdbFile::load_from_bytes_as_vec_with_mode(
+                    pdb_name.clone(),
+                    pdb_data,
+                    load_mode,
+                    &cancellation_token,
+                ) {
                     Err(err) => frontend_controller
                         .send_command(FrontendCommand::LoadPDBResult(Err(err)))?,
-                    Ok(loaded_pdb_file) => {
+                    Ok(mut loaded_pdb_file) => {
+                        loaded_pdb_file.settings = reconstruction_settings.clone();
+                        loaded_pdb_file.pe_file = pe_files.get(&pdb_slot).cloned();
+                        loaded_pdb_file.rename_map =
+                            rename_map_for_pdb(&rename_maps, &loaded_pdb_file);
+                        item_count = Some(loaded_pdb_file.type_infos.len());
                         frontend_controller
                             .send_command(FrontendCommand::LoadPDBResult(Ok(pdb_slot)))?;
+                        // PDBs loaded from memory aren't watched
+                        #[cfg(all(not(target_arch = "wasm32"), feature = "watch"))]
+                        pdb_file_watchers.remove(&pdb_slot);
                         if let Some(pdb_file) = pdb_files.insert(pdb_slot, loaded_pdb_file) {
                             log::info!("'{}' has been unloaded.", pdb_file.file_path.display());
                         }
@@ -244,12 +1007,54 @@ fn worker_thread_routine(
 
             BackendCommand::LoadPDBFromArray(pdb_slot, pdb_name, pdb_data) => {
                 log::info!("Loading a new PDB file ...");
-                match PdbFile::load_from_bytes_as_array(pdb_name.clone(), pdb_data) {
+                match PdbFile::load_from_bytes_as_array_with_mode(
+                    pdb_name.clone(),
+                    pdb_data,
+                    load_mode,
+                    &cancellation_token,
+                ) {
+                    Err(err) => frontend_controller
+                        .send_command(FrontendCommand::LoadPDBResult(Err(err)))?,
+                    Ok(mut loaded_pdb_file) => {
+                        loaded_pdb_file.settings = reconstruction_settings.clone();
+                        loaded_pdb_file.pe_file = pe_files.get(&pdb_slot).cloned();
+                        loaded_pdb_file.rename_map =
+                            rename_map_for_pdb(&rename_maps, &loaded_pdb_file);
+                        item_count = Some(loaded_pdb_file.type_infos.len());
+                        frontend_controller
+                            .send_command(FrontendCommand::LoadPDBResult(Ok(pdb_slot)))?;
+                        // PDBs loaded from memory aren't watched
+                        #[cfg(all(not(target_arch = "wasm32"), feature = "watch"))]
+                        pdb_file_watchers.remove(&pdb_slot);
+                        if let Some(pdb_file) = pdb_files.insert(pdb_slot, loaded_pdb_file) {
+                            log::info!("'{}' has been unloaded.", pdb_file.file_path.display());
+                        }
+                        log::info!("'{}' has been loaded successfully!", pdb_name);
+                    }
+                }
+            }
+
+            BackendCommand::LoadPDBFromData(pdb_slot, pdb_name, pdb_reader) => {
+                log::info!("Loading a new PDB file ...");
+                match PdbFile::load_from_reader_with_mode(
+                    pdb_name.clone(),
+                    PDBDataSource::Reader(pdb_reader),
+                    load_mode,
+                    &cancellation_token,
+                ) {
                     Err(err) => frontend_controller
                         .send_command(FrontendCommand::LoadPDBResult(Err(err)))?,
-                    Ok(loaded_pdb_file) => {
+                    Ok(mut loaded_pdb_file) => {
+                        loaded_pdb_file.settings = reconstruction_settings.clone();
+                        loaded_pdb_file.pe_file = pe_files.get(&pdb_slot).cloned();
+                        loaded_pdb_file.rename_map =
+                            rename_map_for_pdb(&rename_maps, &loaded_pdb_file);
+                        item_count = Some(loaded_pdb_file.type_infos.len());
                         frontend_controller
                             .send_command(FrontendCommand::LoadPDBResult(Ok(pdb_slot)))?;
+                        // PDBs loaded from memory aren't watched
+                        #[cfg(all(not(target_arch = "wasm32"), feature = "watch"))]
+                        pdb_file_watchers.remove(&pdb_slot);
                         if let Some(pdb_file) = pdb_files.insert(pdb_slot, loaded_pdb_file) {
                             log::info!("'{}' has been unloaded.", pdb_file.file_path.display());
                         }
@@ -262,22 +1067,70 @@ fn worker_thread_routine(
             BackendCommand::LoadPDBFromURL(pdb_slot, url) => {
                 log::info!("Fetching data from URL ...");
                 // Parse URL and extract file name, if any
-                match url::Url::parse(&url) {
-                    Err(err) => log::error!("Failed to parse URL: {err}"),
-                    Ok(url) => {
-                        let url_path = url.path();
-                        if let Some(pdb_name) = url_path.split('/').last() {
+                let parsed_url = url::Url::parse(&url)
+                    .map_err(|err| {
+                        ResymCoreError::InvalidParameterError(format!("invalid URL: {err}"))
+                    })
+                    .and_then(|url| {
+                        let pdb_name = url.path().split('/').last().unwrap_or_default();
+                        if pdb_name.is_empty() {
+                            Err(ResymCoreError::InvalidParameterError(
+                                "URL doesn't point to a file".to_string(),
+                            ))
+                        } else {
+                            Ok((pdb_name.to_string(), url))
+                        }
+                    });
+                match parsed_url {
+                    Err(err) => frontend_controller
+                        .send_command(FrontendCommand::LoadURLResult(Err(err)))?,
+                    Ok((pdb_name, url)) => {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        let cached_pdb_path =
+                            url_cache_directory.as_deref().map(|cache_directory| {
+                                cached_download_path(cache_directory, url.as_str(), &pdb_name)
+                            });
+                        #[cfg(not(target_arch = "wasm32"))]
+                        let cached_pdb_data = cached_pdb_path
+                            .as_deref()
+                            .filter(|_| !refresh_url_cache)
+                            .and_then(|cached_pdb_path| std::fs::read(cached_pdb_path).ok());
+                        #[cfg(target_arch = "wasm32")]
+                        let cached_pdb_data: Option<Vec<u8>> = None;
+
+                        if let Some(pdb_data) = cached_pdb_data {
+                            log::info!("Using the cached copy of '{url}'");
+                            frontend_controller.send_command(FrontendCommand::LoadURLResult(
+                                Ok((pdb_slot, pdb_name, pdb_data)),
+                            ))?;
+                        } else {
                             let frontend_controller = frontend_controller.clone();
-                            let pdb_name = pdb_name.to_string();
                             let request = ehttp::Request::get(url);
                             ehttp::fetch(request, move |result: ehttp::Result<ehttp::Response>| {
                                 match result {
                                     Err(err) => frontend_controller
-                                        .send_command(FrontendCommand::LoadPDBResult(Err(
+                                        .send_command(FrontendCommand::LoadURLResult(Err(
                                             ResymCoreError::EHttpError(err),
                                         )))
                                         .expect("frontend unavailable"),
+                                    Ok(response) if !response.ok => frontend_controller
+                                        .send_command(FrontendCommand::LoadURLResult(Err(
+                                            ResymCoreError::EHttpError(format!(
+                                                "{} {}",
+                                                response.status, response.status_text
+                                            )),
+                                        )))
+                                        .expect("frontend unavailable"),
                                     Ok(response) => {
+                                        #[cfg(not(target_arch = "wasm32"))]
+                                        if let Some(cached_pdb_path) = cached_pdb_path {
+                                            if let Err(err) = write_cached_download(
+                                                &cached_pdb_path,
+                                                &response.bytes,
+                                            ) {
+                                                log::warn!("Failed to cache downloaded PDB: {err}");
+                                            }
+                                        }
                                         frontend_controller
                                             .send_command(FrontendCommand::LoadURLResult(Ok((
                                                 pdb_slot,
@@ -288,21 +1141,146 @@ fn worker_thread_routine(
                                     }
                                 }
                             });
-                        } else {
-                            log::error!("URL doesn't point to a file");
                         }
                     }
                 }
             }
 
-            BackendCommand::UnloadPDB(pdb_slot) => match pdb_files.remove(&pdb_slot) {
-                None => {
-                    log::error!("Trying to unload an inexistent PDB");
+            #[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+            BackendCommand::SetURLCacheDirectory(cache_directory) => {
+                url_cache_directory = cache_directory;
+            }
+
+            #[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+            BackendCommand::RefreshURLCache(enabled) => {
+                refresh_url_cache = enabled;
+            }
+
+            BackendCommand::UnloadPDB(pdb_slot) => {
+                #[cfg(all(not(target_arch = "wasm32"), feature = "watch"))]
+                pdb_file_watchers.remove(&pdb_slot);
+                match pdb_files.remove(&pdb_slot) {
+                    None => {
+                        log::error!("Trying to unload an inexistent PDB");
+                    }
+                    Some(pdb_file) => {
+                        log::info!("'{}' has been unloaded.", pdb_file.file_path.display());
+                    }
+                }
+                pe_files.remove(&pdb_slot);
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            BackendCommand::LoadPEFromPath(pdb_slot, pe_file_path) => {
+                log::info!("Loading a new PE file ...");
+                let result = PeFile::load_from_file(&pe_file_path).map(|pe_file| {
+                    if let Some(pdb_file) = pdb_files.get_mut(&pdb_slot) {
+                        pdb_file.pe_file = Some(pe_file.clone());
+                    }
+                    pe_files.insert(pdb_slot, pe_file);
+                    log::info!("'{}' has been loaded successfully!", pe_file_path.display());
+
+                    pdb_slot
+                });
+                frontend_controller.send_command(FrontendCommand::LoadPEResult(result))?;
+            }
+
+            #[cfg(all(not(target_arch = "wasm32"), feature = "watch"))]
+            BackendCommand::SetWatchPDBFiles(enabled) => {
+                // Note: only PDBs loaded later on are watched
+                watch_pdb_files = enabled;
+                if !watch_pdb_files {
+                    pdb_file_watchers.clear();
+                }
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            BackendCommand::SetClangFormat(configuration) => {
+                reconstruction_settings.clang_format = configuration;
+                apply_reconstruction_settings(&mut pdb_files, &reconstruction_settings);
+            }
+
+            BackendCommand::SetOpaqueTypeRules(rules) => {
+                reconstruction_settings.opaque_type_rules = rules;
+                apply_reconstruction_settings(&mut pdb_files, &reconstruction_settings);
+            }
+
+            BackendCommand::SetPrimitiveTypeMapping(mapping) => {
+                reconstruction_settings.primitive_type_mapping = mapping;
+                apply_reconstruction_settings(&mut pdb_files, &reconstruction_settings);
+            }
+
+            BackendCommand::SetCodeFormat(configuration) => {
+                reconstruction_settings.code_format = configuration;
+                apply_reconstruction_settings(&mut pdb_files, &reconstruction_settings);
+            }
+
+            BackendCommand::SetHeaderTemplate(template) => {
+                reconstruction_settings.header_template = template;
+                apply_reconstruction_settings(&mut pdb_files, &reconstruction_settings);
+            }
+
+            BackendCommand::SetMemberFilter(filter) => {
+                reconstruction_settings.member_filter = filter;
+                apply_reconstruction_settings(&mut pdb_files, &reconstruction_settings);
+            }
+
+            BackendCommand::SetPrintStaticMemberRvas(enabled) => {
+                reconstruction_settings.print_static_member_rvas = enabled;
+                apply_reconstruction_settings(&mut pdb_files, &reconstruction_settings);
+            }
+
+            BackendCommand::SetDoxygenComments(enabled) => {
+                reconstruction_settings.doxygen_comments = enabled;
+                apply_reconstruction_settings(&mut pdb_files, &reconstruction_settings);
+            }
+
+            BackendCommand::SetFlagEnumComments(enabled) => {
+                reconstruction_settings.flag_enum_comments = enabled;
+                apply_reconstruction_settings(&mut pdb_files, &reconstruction_settings);
+            }
+
+            BackendCommand::SetEnumValueOrder(order) => {
+                reconstruction_settings.enum_value_order = order;
+                apply_reconstruction_settings(&mut pdb_files, &reconstruction_settings);
+            }
+
+            BackendCommand::SetForwardDeclareDependencies(enabled) => {
+                reconstruction_settings.forward_declare_dependencies = enabled;
+                apply_reconstruction_settings(&mut pdb_files, &reconstruction_settings);
+            }
+
+            BackendCommand::SetLoadMode(new_load_mode) => {
+                load_mode = new_load_mode;
+            }
+
+            BackendCommand::SetImageBase(new_image_base) => {
+                reconstruction_settings.image_base = new_image_base;
+                apply_reconstruction_settings(&mut pdb_files, &reconstruction_settings);
+            }
+
+            BackendCommand::SetNumberFormat(format) => {
+                reconstruction_settings.number_format = format;
+                apply_reconstruction_settings(&mut pdb_files, &reconstruction_settings);
+            }
+
+            BackendCommand::SetReportOperationTimings(enabled) => {
+                report_operation_timings = enabled;
+            }
+
+            BackendCommand::SetRenameMaps(maps) => {
+                // Apply renames to loaded PDBs, as well as PDBs loaded later on
+                rename_maps = maps;
+                for pdb_file in pdb_files.values_mut() {
+                    pdb_file.rename_map = rename_map_for_pdb(&rename_maps, pdb_file);
                 }
-                Some(pdb_file) => {
-                    log::info!("'{}' has been unloaded.", pdb_file.file_path.display());
+            }
+
+            BackendCommand::SetTypeNotes(pdb_slot, type_notes) => {
+                if let Some(pdb_file) = pdb_files.get_mut(&pdb_slot) {
+                    pdb_file.type_notes = type_notes;
                 }
-            },
+            }
 
             BackendCommand::ReconstructTypeByIndex(
                 pdb_slot,
@@ -324,7 +1302,12 @@ fn worker_thread_routine(
                         ignore_std_types,
                     );
                     frontend_controller.send_command(FrontendCommand::ReconstructTypeResult(
-                        reconstructed_type_result,
+                        reconstructed_type_result.map(|(data, xrefs_from)| {
+                            (
+                                format_reconstructed_code(&reconstruction_settings, data),
+                                xrefs_from,
+                            )
+                        }),
                     ))?;
                 }
             }
@@ -349,7 +1332,38 @@ fn worker_thread_routine(
                         ignore_std_types,
                     );
                     frontend_controller.send_command(FrontendCommand::ReconstructTypeResult(
-                        reconstructed_type_result,
+                        reconstructed_type_result.map(|(data, xrefs_from)| {
+                            (
+                                format_reconstructed_code(&reconstruction_settings, data),
+                                xrefs_from,
+                            )
+                        }),
+                    ))?;
+                }
+            }
+
+            BackendCommand::ReconstructTypesByIndex(
+                pdb_slot,
+                type_indices,
+                primitives_flavor,
+                print_header,
+                reconstruct_dependencies,
+                print_access_specifiers,
+                ignore_std_types,
+            ) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    let reconstructed_types_result = reconstruct_types_by_index_command(
+                        pdb_file,
+                        &type_indices,
+                        primitives_flavor,
+                        print_header,
+                        reconstruct_dependencies,
+                        print_access_specifiers,
+                        ignore_std_types,
+                    );
+                    frontend_controller.send_command(FrontendCommand::ReconstructTypesResult(
+                        reconstructed_types_result
+                            .map(|data| format_reconstructed_code(&reconstruction_settings, data)),
                     ))?;
                 }
             }
@@ -368,14 +1382,43 @@ fn worker_thread_routine(
                         print_header,
                         print_access_specifiers,
                         ignore_std_types,
+                        &cancellation_token,
                     );
                     frontend_controller.send_command(FrontendCommand::ReconstructTypeResult(
                         // Note: do not return any "xrefs from" when reconstructing all types
-                        reconstructed_type_result.map(|data| (data, vec![])),
+                        reconstructed_type_result.map(|data| {
+                            (
+                                format_reconstructed_code(&reconstruction_settings, data),
+                                vec![],
+                            )
+                        }),
                     ))?;
                 }
             }
 
+            BackendCommand::ReconstructAllEnums(
+                pdb_slot,
+                primitives_flavor,
+                print_header,
+                ignore_std_types,
+            ) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    let reconstructed_enums_result = reconstruct_all_enums_command(
+                        pdb_file,
+                        primitives_flavor,
+                        print_header,
+                        ignore_std_types,
+                    );
+                    frontend_controller.send_command(
+                        FrontendCommand::ReconstructAllEnumsResult(
+                            reconstructed_enums_result.map(|data| {
+                                format_reconstructed_code(&reconstruction_settings, data)
+                            }),
+                        ),
+                    )?;
+                }
+            }
+
             BackendCommand::ListTypes(
                 pdb_slot,
                 search_filter,
@@ -383,7 +1426,12 @@ fn worker_thread_routine(
                 use_regex,
                 ignore_std_types,
             ) => {
-                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                if let Err(err) = check_search_filter(&search_filter, use_regex) {
+                    frontend_controller.send_command(FrontendCommand::SearchFilterError(
+                        SearchTarget::Types,
+                        err,
+                    ))?;
+                } else if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
                     let filtered_type_list = update_type_filter_command(
                         pdb_file,
                         &search_filter,
@@ -392,6 +1440,7 @@ fn worker_thread_routine(
                         ignore_std_types,
                         true,
                     );
+                    item_count = Some(filtered_type_list.len());
                     frontend_controller
                         .send_command(FrontendCommand::ListTypesResult(filtered_type_list))?;
                 }
@@ -404,80 +1453,181 @@ fn worker_thread_routine(
                 use_regex,
                 ignore_std_types,
             ) => {
-                let mut filtered_type_set = BTreeSet::default();
-                for pdb_slot in pdb_slots {
-                    if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
-                        let filtered_type_list = update_type_filter_command(
-                            pdb_file,
-                            &search_filter,
-                            case_insensitive_search,
-                            use_regex,
-                            ignore_std_types,
-                            false,
-                        );
-                        filtered_type_set.extend(filtered_type_list.into_iter().map(|(s, _)| {
-                            // Collapse all type indices to `default`. When merging
-                            // type lists, we can only count on type names to
-                            // represent the types.
-                            (s, Default::default())
-                        }));
-                    }
+                if let Err(err) = check_search_filter(&search_filter, use_regex) {
+                    frontend_controller.send_command(FrontendCommand::SearchFilterError(
+                        SearchTarget::Types,
+                        err,
+                    ))?;
+                } else {
+                    let filtered_type_set = merged_type_filter_command(
+                        &pdb_files,
+                        &pdb_slots,
+                        &search_filter,
+                        case_insensitive_search,
+                        use_regex,
+                        ignore_std_types,
+                    );
+                    item_count = Some(filtered_type_set.len());
+                    frontend_controller.send_command(FrontendCommand::ListTypesResult(
+                        filtered_type_set.into_iter().collect(),
+                    ))?;
                 }
-                frontend_controller.send_command(FrontendCommand::ListTypesResult(
-                    filtered_type_set.into_iter().collect(),
-                ))?;
             }
 
-            BackendCommand::ListSymbols(
-                pdb_slot,
+            BackendCommand::ListTypesTagged(
+                pdb_slots,
                 search_filter,
                 case_insensitive_search,
                 use_regex,
                 ignore_std_types,
             ) => {
-                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
-                    let filtered_symbol_list = update_symbol_filter_command(
-                        pdb_file,
+                if let Err(err) = check_search_filter(&search_filter, use_regex) {
+                    frontend_controller.send_command(FrontendCommand::SearchFilterError(
+                        SearchTarget::Types,
+                        err,
+                    ))?;
+                } else {
+                    let tagged_type_list = tagged_type_filter_command(
+                        &pdb_files,
+                        &pdb_slots,
                         &search_filter,
                         case_insensitive_search,
                         use_regex,
                         ignore_std_types,
                     );
+                    item_count = Some(tagged_type_list.len());
                     frontend_controller
-                        .send_command(FrontendCommand::ListSymbolsResult(filtered_symbol_list))?;
+                        .send_command(FrontendCommand::ListTypesTaggedResult(tagged_type_list))?;
                 }
             }
 
-            BackendCommand::ListSymbolsMerged(
-                pdb_slots,
+            BackendCommand::ListChangedTypesMerged(
+                pdb_from_slot,
+                pdb_to_slot,
                 search_filter,
                 case_insensitive_search,
                 use_regex,
-                ignore_std_types,
+                diff_options,
             ) => {
-                let mut filtered_symbol_set = BTreeSet::default();
-                for pdb_slot in pdb_slots {
-                    if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
-                        let filtered_symbol_list = update_symbol_filter_command(
-                            pdb_file,
-                            &search_filter,
-                            case_insensitive_search,
-                            use_regex,
-                            ignore_std_types,
-                        );
-                        filtered_symbol_set.extend(filtered_symbol_list.into_iter().map(
-                            |(s, _)| {
-                                // Collapse all type indices to `default`. When merging
-                                // type lists, we can only count on type names to
-                                // represent the types.
-                                (s, Default::default())
-                            },
-                        ));
+                if let Err(err) = check_search_filter(&search_filter, use_regex) {
+                    frontend_controller.send_command(FrontendCommand::SearchFilterError(
+                        SearchTarget::Types,
+                        err,
+                    ))?;
+                } else if let (Some(pdb_file_from), Some(pdb_file_to)) =
+                    (pdb_files.get(&pdb_from_slot), pdb_files.get(&pdb_to_slot))
+                {
+                    let changed_types = match changed_type_names_cache.entry((
+                        pdb_from_slot,
+                        pdb_to_slot,
+                        diff_options,
+                    )) {
+                        Entry::Occupied(entry) => Ok(entry.into_mut()),
+                        Entry::Vacant(entry) => changed_type_names(
+                            pdb_file_from,
+                            pdb_file_to,
+                            &diff_options,
+                            &cancellation_token,
+                        )
+                        .map(|changed_types| entry.insert(changed_types)),
+                    };
+                    match changed_types {
+                        Err(ResymCoreError::OperationCancelledError) => {
+                            log::info!("Computing changed types was cancelled");
+                        }
+                        Err(err) => {
+                            log::error!("Failed to compute changed types: {err}");
+                        }
+                        Ok(changed_types) => {
+                            let mut filtered_type_set = merged_type_filter_command(
+                                &pdb_files,
+                                &[pdb_from_slot, pdb_to_slot],
+                                &search_filter,
+                                case_insensitive_search,
+                                use_regex,
+                                diff_options.ignore_std_types,
+                            );
+                            filtered_type_set
+                                .retain(|(type_name, _)| changed_types.contains(type_name));
+                            item_count = Some(filtered_type_set.len());
+                            frontend_controller.send_command(FrontendCommand::ListTypesResult(
+                                filtered_type_set.into_iter().collect(),
+                            ))?;
+                        }
+                    }
+                }
+            }
+
+            BackendCommand::ListTypeInfos(pdb_slot) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    frontend_controller.send_command(FrontendCommand::ListTypeInfosResult(
+                        pdb_file.type_infos.clone(),
+                    ))?;
+                }
+            }
+
+            BackendCommand::FuzzyFindTypes(pdb_slot, query, max_count) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    match pdb_file.fuzzy_find_types(&query, max_count) {
+                        Err(err) => log::error!("Failed to find types: {err}"),
+                        Ok(type_list) => {
+                            item_count = Some(type_list.len());
+                            frontend_controller
+                                .send_command(FrontendCommand::FuzzyFindTypesResult(type_list))?
+                        }
+                    }
+                }
+            }
+
+            BackendCommand::ListSymbols(pdb_slot, search_filter, list_options) => {
+                if let Err(err) = check_search_filter(&search_filter, list_options.use_regex) {
+                    frontend_controller.send_command(FrontendCommand::SearchFilterError(
+                        SearchTarget::Symbols,
+                        err,
+                    ))?;
+                } else if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    let filtered_symbol_list =
+                        update_symbol_filter_command(pdb_file, &search_filter, &list_options);
+                    item_count = Some(filtered_symbol_list.len());
+                    frontend_controller
+                        .send_command(FrontendCommand::ListSymbolsResult(filtered_symbol_list))?;
+                }
+            }
+
+            BackendCommand::ListSymbolsMerged(pdb_slots, search_filter, list_options) => {
+                // Symbols are paired by name when diffing
+                let list_options = SymbolListOptions {
+                    undecorate_names: false,
+                    ..list_options
+                };
+                if let Err(err) = check_search_filter(&search_filter, list_options.use_regex) {
+                    frontend_controller.send_command(FrontendCommand::SearchFilterError(
+                        SearchTarget::Symbols,
+                        err,
+                    ))?;
+                } else {
+                    let mut filtered_symbol_set = BTreeSet::default();
+                    for pdb_slot in pdb_slots {
+                        if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                            let filtered_symbol_list = update_symbol_filter_command(
+                                pdb_file,
+                                &search_filter,
+                                &list_options,
+                            );
+                            filtered_symbol_set.extend(filtered_symbol_list.into_iter().map(
+                                |(s, _)| {
+                                    // Collapse all type indices to `default`. When merging
+                                    // type lists, we can only count on type names to
+                                    // represent the types.
+                                    (s, Default::default())
+                                },
+                            ));
+                        }
                     }
+                    frontend_controller.send_command(FrontendCommand::ListSymbolsResult(
+                        filtered_symbol_set.into_iter().collect(),
+                    ))?;
                 }
-                frontend_controller.send_command(FrontendCommand::ListSymbolsResult(
-                    filtered_symbol_set.into_iter().collect(),
-                ))?;
             }
 
             BackendCommand::ReconstructSymbolByIndex(
@@ -496,7 +1646,9 @@ fn worker_thread_routine(
                         print_access_specifiers,
                     );
                     frontend_controller
-                        .send_command(FrontendCommand::ReconstructSymbolResult(result))?;
+                        .send_command(FrontendCommand::ReconstructSymbolResult(result.map(
+                            |data| format_reconstructed_code(&reconstruction_settings, data),
+                        )))?;
                 }
             }
 
@@ -516,7 +1668,9 @@ fn worker_thread_routine(
                         print_access_specifiers,
                     );
                     frontend_controller
-                        .send_command(FrontendCommand::ReconstructSymbolResult(result))?;
+                        .send_command(FrontendCommand::ReconstructSymbolResult(result.map(
+                            |data| format_reconstructed_code(&reconstruction_settings, data),
+                        )))?;
                 }
             }
 
@@ -534,7 +1688,17 @@ fn worker_thread_routine(
                         print_access_specifiers,
                     );
                     frontend_controller
-                        .send_command(FrontendCommand::ReconstructSymbolResult(result))?;
+                        .send_command(FrontendCommand::ReconstructSymbolResult(result.map(
+                            |data| format_reconstructed_code(&reconstruction_settings, data),
+                        )))?;
+                }
+            }
+
+            BackendCommand::ListConstants(pdb_slot, primitives_flavor) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    let constant_list = pdb_file.constant_list(primitives_flavor);
+                    frontend_controller
+                        .send_command(FrontendCommand::ListConstantsResult(constant_list))?;
                 }
             }
 
@@ -545,6 +1709,7 @@ fn worker_thread_routine(
                 primitives_flavor,
                 print_header,
                 print_access_specifiers,
+                ignore_comments,
             ) => {
                 if let Some(pdb_file_from) = pdb_files.get(&pdb_from_slot) {
                     if let Some(pdb_file_to) = pdb_files.get(&pdb_to_slot) {
@@ -555,6 +1720,7 @@ fn worker_thread_routine(
                             primitives_flavor,
                             print_header,
                             print_access_specifiers,
+                            ignore_comments,
                         );
                         frontend_controller
                             .send_command(FrontendCommand::DiffResult(symbol_diff_result))?;
@@ -579,90 +1745,475 @@ fn worker_thread_routine(
                         print_access_specifiers,
                     );
                     frontend_controller.send_command(FrontendCommand::ReconstructModuleResult(
-                        reconstructed_module_result,
+                        reconstructed_module_result
+                            .map(|data| format_reconstructed_code(&reconstruction_settings, data)),
+                    ))?;
+                }
+            }
+
+            BackendCommand::ListModules(
+                pdb_slot,
+                search_filter,
+                case_insensitive_search,
+                use_regex,
+            ) => {
+                if let Err(err) = check_search_filter(&search_filter, use_regex) {
+                    frontend_controller.send_command(FrontendCommand::SearchFilterError(
+                        SearchTarget::Modules,
+                        err,
+                    ))?;
+                } else if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    let module_list = list_modules_command(
+                        pdb_file,
+                        &search_filter,
+                        case_insensitive_search,
+                        use_regex,
+                    );
+                    frontend_controller
+                        .send_command(FrontendCommand::ListModulesResult(module_list))?;
+                }
+            }
+
+            BackendCommand::ListSourceFiles(
+                pdb_slot,
+                search_filter,
+                case_insensitive_search,
+                use_regex,
+            ) => {
+                if let Err(err) = check_search_filter(&search_filter, use_regex) {
+                    frontend_controller.send_command(FrontendCommand::SearchFilterError(
+                        SearchTarget::SourceFiles,
+                        err,
+                    ))?;
+                } else if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    let source_file_list = list_source_files_command(
+                        pdb_file,
+                        &search_filter,
+                        case_insensitive_search,
+                        use_regex,
+                    );
+                    frontend_controller
+                        .send_command(FrontendCommand::ListSourceFilesResult(source_file_list))?;
+                }
+            }
+
+            BackendCommand::ReconstructSourceFileByPath(
+                pdb_slot,
+                file_path,
+                primitives_flavor,
+                print_header,
+                print_access_specifiers,
+            ) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    let reconstructed_source_file_result = reconstruct_source_file_by_path_command(
+                        pdb_file,
+                        &file_path,
+                        primitives_flavor,
+                        print_header,
+                        print_access_specifiers,
+                    );
+                    frontend_controller.send_command(
+                        FrontendCommand::ReconstructSourceFileResult(
+                            reconstructed_source_file_result.map(|data| {
+                                format_reconstructed_code(&reconstruction_settings, data)
+                            }),
+                        ),
+                    )?;
+                }
+            }
+
+            BackendCommand::DiffTypeByName(
+                pdb_from_slot,
+                pdb_to_slot,
+                type_name,
+                primitives_flavor,
+                diff_options,
+            ) => {
+                if let Some(pdb_file_from) = pdb_files.get(&pdb_from_slot) {
+                    if let Some(pdb_file_to) = pdb_files.get(&pdb_to_slot) {
+                        let type_diff_result = diff_type_by_name(
+                            pdb_file_from,
+                            pdb_file_to,
+                            &type_name,
+                            primitives_flavor,
+                            &diff_options,
+                        );
+                        frontend_controller
+                            .send_command(FrontendCommand::DiffResult(type_diff_result))?;
+                    }
+                }
+            }
+
+            BackendCommand::DiffTypeByNameThreeWay(
+                pdb_base_slot,
+                pdb_a_slot,
+                pdb_b_slot,
+                type_name,
+                primitives_flavor,
+                diff_options,
+            ) => {
+                if let (Some(pdb_file_base), Some(pdb_file_a), Some(pdb_file_b)) = (
+                    pdb_files.get(&pdb_base_slot),
+                    pdb_files.get(&pdb_a_slot),
+                    pdb_files.get(&pdb_b_slot),
+                ) {
+                    let type_diff_result = diff_type_by_name_three_way(
+                        pdb_file_base,
+                        pdb_file_a,
+                        pdb_file_b,
+                        &type_name,
+                        primitives_flavor,
+                        &diff_options,
+                    );
+                    frontend_controller
+                        .send_command(FrontendCommand::DiffResult(type_diff_result))?;
+                }
+            }
+
+            BackendCommand::CompareTypeAcrossPDBs(
+                pdb_slots,
+                type_name,
+                primitives_flavor,
+                print_access_specifiers,
+                ignore_std_types,
+            ) => {
+                let comparison_result = pdb_slots
+                    .iter()
+                    .map(|pdb_slot| {
+                        pdb_files.get(pdb_slot).ok_or_else(|| {
+                            ResymCoreError::InvalidParameterError(format!(
+                                "no PDB loaded in slot {pdb_slot}"
+                            ))
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()
+                    .and_then(|pdb_files| {
+                        compare_type_across_pdbs(
+                            &pdb_files,
+                            &type_name,
+                            primitives_flavor,
+                            print_access_specifiers,
+                            ignore_std_types,
+                        )
+                    });
+                frontend_controller
+                    .send_command(FrontendCommand::CompareTypeResult(comparison_result))?;
+            }
+
+            BackendCommand::DiffModuleByPath(
+                pdb_from_slot,
+                pdb_to_slot,
+                module_path,
+                primitives_flavor,
+                print_header,
+                print_access_specifiers,
+                ignore_comments,
+            ) => {
+                if let Some(pdb_file_from) = pdb_files.get(&pdb_from_slot) {
+                    if let Some(pdb_file_to) = pdb_files.get(&pdb_to_slot) {
+                        let module_diff_result = diff_module_by_path(
+                            pdb_file_from,
+                            pdb_file_to,
+                            &module_path,
+                            primitives_flavor,
+                            print_header,
+                            print_access_specifiers,
+                            ignore_comments,
+                        );
+                        frontend_controller
+                            .send_command(FrontendCommand::DiffResult(module_diff_result))?;
+                    }
+                }
+            }
+
+            BackendCommand::DiffPublicSymbols(
+                pdb_from_slot,
+                pdb_to_slot,
+                primitives_flavor,
+                print_header,
+                print_access_specifiers,
+                ignore_comments,
+            ) => {
+                if let Some(pdb_file_from) = pdb_files.get(&pdb_from_slot) {
+                    if let Some(pdb_file_to) = pdb_files.get(&pdb_to_slot) {
+                        let symbols_diff_result = diff_public_symbols(
+                            pdb_file_from,
+                            pdb_file_to,
+                            primitives_flavor,
+                            print_header,
+                            print_access_specifiers,
+                            ignore_comments,
+                        );
+                        frontend_controller
+                            .send_command(FrontendCommand::DiffResult(symbols_diff_result))?;
+                    }
+                }
+            }
+
+            BackendCommand::DiffAllTypes(
+                pdb_from_slot,
+                pdb_to_slot,
+                type_name_filter,
+                primitives_flavor,
+                diff_options,
+            ) => {
+                if let Some(pdb_file_from) = pdb_files.get(&pdb_from_slot) {
+                    if let Some(pdb_file_to) = pdb_files.get(&pdb_to_slot) {
+                        let changed_types_result = diff_all_types(
+                            pdb_file_from,
+                            pdb_file_to,
+                            type_name_filter.as_ref(),
+                            primitives_flavor,
+                            &diff_options,
+                            &cancellation_token,
+                        );
+                        frontend_controller.send_command(FrontendCommand::DiffAllTypesResult(
+                            changed_types_result,
+                        ))?;
+                    }
+                }
+            }
+
+            BackendCommand::CheckHeaderLayouts(pdb_slot, header) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    let layout_checks = check_header_layouts(pdb_file, &header);
+                    frontend_controller
+                        .send_command(FrontendCommand::CheckHeaderLayoutsResult(layout_checks))?;
+                }
+            }
+
+            BackendCommand::ListTypeCrossReferences(pdb_slot, type_index) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    let xref_list = list_type_xrefs_command(pdb_file, type_index);
+                    frontend_controller
+                        .send_command(FrontendCommand::ListTypeCrossReferencesResult(xref_list))?;
+                }
+            }
+
+            BackendCommand::ListTypeReverseDependencies(pdb_slot, type_index) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    let reverse_dependency_list =
+                        list_type_reverse_dependencies_command(pdb_file, type_index);
+                    frontend_controller.send_command(
+                        FrontendCommand::ListTypeReverseDependenciesResult(reverse_dependency_list),
+                    )?;
+                }
+            }
+
+            BackendCommand::GetTypeDependencyGraph(pdb_slot, type_index, ignore_std_types) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    let graph_result =
+                        pdb_file.type_dependency_graph(Some(type_index), ignore_std_types);
+                    frontend_controller
+                        .send_command(FrontendCommand::TypeDependencyGraphResult(graph_result))?;
+                }
+            }
+
+            BackendCommand::ExportTypeDependencyGraph(pdb_slot, type_index, ignore_std_types) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    let export_result = pdb_file
+                        .type_dependency_graph(type_index, ignore_std_types)
+                        .map(|graph| graph.to_dot());
+                    frontend_controller.send_command(
+                        FrontendCommand::ExportTypeDependencyGraphResult(export_result),
+                    )?;
+                }
+            }
+
+            BackendCommand::ExportTypeDependencyGraphByName(
+                pdb_slot,
+                type_name,
+                ignore_std_types,
+            ) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    let export_result = pdb_file
+                        .type_dependency_graph_by_name(&type_name, ignore_std_types)
+                        .map(|graph| graph.to_dot());
+                    frontend_controller.send_command(
+                        FrontendCommand::ExportTypeDependencyGraphResult(export_result),
+                    )?;
+                }
+            }
+
+            BackendCommand::ExportLayoutAssertions(pdb_slot, type_names, ignore_std_types) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    let export_result = generate_layout_assertions(
+                        pdb_file,
+                        type_names.as_deref(),
+                        ignore_std_types,
+                    );
+                    frontend_controller.send_command(
+                        FrontendCommand::ExportLayoutAssertionsResult(export_result),
+                    )?;
+                }
+            }
+
+            BackendCommand::LookupSymbolByRVA(pdb_slot, rva) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    let lookup_start = Instant::now();
+                    let symbol_result = pdb_file.symbol_at_rva(rva);
+                    log::debug!(
+                        "Address lookup took {} ms",
+                        lookup_start.elapsed().as_millis()
+                    );
+                    frontend_controller
+                        .send_command(FrontendCommand::LookupSymbolByRVAResult(symbol_result))?;
+                }
+            }
+
+            BackendCommand::GetPDBMetadata(pdb_slot) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    frontend_controller.send_command(FrontendCommand::PDBMetadataResult(
+                        pdb_slot,
+                        pdb_file.metadata(),
                     ))?;
                 }
             }
 
-            BackendCommand::ListModules(
-                pdb_slot,
-                search_filter,
-                case_insensitive_search,
-                use_regex,
-            ) => {
+            BackendCommand::ListStreams(pdb_slot) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    frontend_controller
+                        .send_command(FrontendCommand::ListStreamsResult(pdb_file.stream_list()))?;
+                }
+            }
+
+            BackendCommand::DumpStream(pdb_slot, stream_index) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    let stream_dump = pdb_file
+                        .stream_data(stream_index)
+                        .map(|stream_data| hex_dump(&stream_data));
+                    frontend_controller
+                        .send_command(FrontendCommand::DumpStreamResult(stream_dump))?;
+                }
+            }
+
+            BackendCommand::LookupSymbolLocationByIndex(pdb_slot, symbol_index) => {
                 if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
-                    let module_list = list_modules_command(
-                        pdb_file,
-                        &search_filter,
-                        case_insensitive_search,
-                        use_regex,
-                    );
-                    frontend_controller
-                        .send_command(FrontendCommand::ListModulesResult(module_list))?;
+                    let symbol_location_result = pdb_file.symbol_location_by_index(symbol_index);
+                    frontend_controller.send_command(
+                        FrontendCommand::LookupSymbolLocationResult(symbol_location_result),
+                    )?;
                 }
             }
 
-            BackendCommand::DiffTypeByName(
-                pdb_from_slot,
-                pdb_to_slot,
-                type_name,
-                primitives_flavor,
-                print_header,
-                reconstruct_dependencies,
-                print_access_specifiers,
-                ignore_std_types,
-            ) => {
-                if let Some(pdb_file_from) = pdb_files.get(&pdb_from_slot) {
-                    if let Some(pdb_file_to) = pdb_files.get(&pdb_to_slot) {
-                        let type_diff_result = diff_type_by_name(
-                            pdb_file_from,
-                            pdb_file_to,
-                            &type_name,
-                            primitives_flavor,
-                            print_header,
-                            reconstruct_dependencies,
-                            print_access_specifiers,
-                            ignore_std_types,
-                        );
-                        frontend_controller
-                            .send_command(FrontendCommand::DiffResult(type_diff_result))?;
-                    }
+            BackendCommand::LookupSymbolLocationByName(pdb_slot, symbol_name) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    let symbol_location_result = pdb_file.symbol_location_by_name(&symbol_name);
+                    frontend_controller.send_command(
+                        FrontendCommand::LookupSymbolLocationResult(symbol_location_result),
+                    )?;
                 }
             }
 
-            BackendCommand::DiffModuleByPath(
-                pdb_from_slot,
-                pdb_to_slot,
-                module_path,
-                primitives_flavor,
-                print_header,
-                print_access_specifiers,
-            ) => {
-                if let Some(pdb_file_from) = pdb_files.get(&pdb_from_slot) {
-                    if let Some(pdb_file_to) = pdb_files.get(&pdb_to_slot) {
-                        let module_diff_result = diff_module_by_path(
-                            pdb_file_from,
-                            pdb_file_to,
-                            &module_path,
-                            primitives_flavor,
-                            print_header,
-                            print_access_specifiers,
-                        );
-                        frontend_controller
-                            .send_command(FrontendCommand::DiffResult(module_diff_result))?;
-                    }
+            BackendCommand::LookupTypeProperties(pdb_slot, type_index) => {
+                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
+                    let type_properties_result = pdb_file.type_properties(type_index);
+                    frontend_controller.send_command(
+                        FrontendCommand::LookupTypePropertiesResult(type_properties_result),
+                    )?;
                 }
             }
 
-            BackendCommand::ListTypeCrossReferences(pdb_slot, type_index) => {
+            BackendCommand::LookupTypeSourceLocations(pdb_slot, type_name) => {
                 if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
-                    let xref_list = list_type_xrefs_command(pdb_file, type_index);
-                    frontend_controller
-                        .send_command(FrontendCommand::ListTypeCrossReferencesResult(xref_list))?;
+                    let source_locations_result = pdb_file.type_source_locations(&type_name);
+                    frontend_controller.send_command(
+                        FrontendCommand::LookupTypeSourceLocationsResult(source_locations_result),
+                    )?;
                 }
             }
         }
+        pending_commands.fetch_sub(1, Ordering::SeqCst);
+
+        if let Some((kind, name)) = timed_operation {
+            frontend_controller.send_command(FrontendCommand::OperationTimingReport(
+                OperationTiming {
+                    kind,
+                    name,
+                    duration: operation_start.elapsed(),
+                    item_count,
+                },
+            ))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Watch the given PDB file and send `PDBFileChanged` to the frontend when it is
+/// modified or replaced. Watching stops when the returned watcher is dropped.
+#[cfg(all(not(target_arch = "wasm32"), feature = "watch"))]
+fn watch_pdb_file(
+    pdb_slot: PDBSlot,
+    pdb_file_path: &std::path::Path,
+    frontend_controller: Arc<impl FrontendController + Send + Sync + 'static>,
+) -> notify::Result<notify::RecommendedWatcher> {
+    use notify::Watcher;
+
+    let pdb_file_path = pdb_file_path.canonicalize()?;
+    // Note: watch the parent directory, as toolchains tend to replace files
+    // rather than writing to them, which isn't reported by all platforms
+    let watched_directory = pdb_file_path
+        .parent()
+        .map(|directory| directory.to_path_buf())
+        .unwrap_or_default();
+    let changed_file_path = pdb_file_path.clone();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let Ok(event) = event else {
+            return;
+        };
+        let is_relevant = (event.kind.is_create() || event.kind.is_modify())
+            && event
+                .paths
+                .iter()
+                .any(|path| path.file_name() == changed_file_path.file_name());
+        if is_relevant {
+            if let Err(err) = frontend_controller.send_command(FrontendCommand::PDBFileChanged(
+                pdb_slot,
+                changed_file_path.clone(),
+            )) {
+                log::error!("Failed to notify the frontend of a PDB change: {err}");
+            }
+        }
+    })?;
+    watcher.watch(&watched_directory, notify::RecursiveMode::NonRecursive)?;
+
+    Ok(watcher)
+}
+
+/// Path of the cached copy of a PDB file downloaded from `url`.
+/// Note: URLs are hashed to get valid directory names, the file name is kept
+/// as is so that PDB files can be identified in headers. The hash is stable
+/// across Rust versions, so that cached copies remain valid.
+#[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+fn cached_download_path(cache_directory: &std::path::Path, url: &str, pdb_name: &str) -> PathBuf {
+    cache_directory
+        .join(format!(
+            "{:016x}",
+            crate::pdb_types::stable_hash(url.as_bytes())
+        ))
+        .join(pdb_name)
+}
+
+/// Write a downloaded PDB file to the cache. The file is written under a
+/// temporary name first so that interrupted downloads never get reused.
+/// Note: directories are only accessible to the current user on Unix, so
+/// other users cannot plant PDB files in the cache.
+#[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+fn write_cached_download(cached_pdb_path: &std::path::Path, pdb_data: &[u8]) -> Result<()> {
+    if let Some(parent_directory) = cached_pdb_path.parent() {
+        let mut directory_builder = std::fs::DirBuilder::new();
+        directory_builder.recursive(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::DirBuilderExt;
+            directory_builder.mode(0o700);
+        }
+        directory_builder.create(parent_directory)?;
     }
+    let temporary_path = cached_pdb_path.with_extension("part");
+    std::fs::write(&temporary_path, pdb_data)?;
+    std::fs::rename(temporary_path, cached_pdb_path)?;
 
     Ok(())
 }
@@ -687,14 +2238,30 @@ where
         ignore_std_types,
     )?;
     if print_header {
-        let file_header = generate_file_header(pdb_file, primitives_flavor, true, ignore_std_types);
+        let type_list = pdb_file.type_list()?;
+        let type_name = type_list
+            .iter()
+            .find(|(_, index)| *index == type_index)
+            .map(|(name, _)| name.as_str())
+            .or_else(|| {
+                pdb_file
+                    .typedef(type_index)
+                    .map(|typedef| typedef.name.as_str())
+            });
+        let file_header = generate_file_header(
+            pdb_file,
+            primitives_flavor,
+            true,
+            ignore_std_types,
+            type_name,
+        );
         Ok((format!("{file_header}{data}"), xrefs_from))
     } else {
         Ok((data, xrefs_from))
     }
 }
 
-fn reconstruct_type_by_name_command<'p, T>(
+pub(crate) fn reconstruct_type_by_name_command<'p, T>(
     pdb_file: &PdbFile<'p, T>,
     type_name: &str,
     primitives_flavor: PrimitiveReconstructionFlavor,
@@ -714,19 +2281,54 @@ where
         ignore_std_types,
     )?;
     if print_header {
-        let file_header = generate_file_header(pdb_file, primitives_flavor, true, ignore_std_types);
+        let file_header = generate_file_header(
+            pdb_file,
+            primitives_flavor,
+            true,
+            ignore_std_types,
+            Some(type_name),
+        );
         Ok((format!("{file_header}{data}"), xrefs_from))
     } else {
         Ok((data, xrefs_from))
     }
 }
 
+fn reconstruct_types_by_index_command<'p, T>(
+    pdb_file: &PdbFile<'p, T>,
+    type_indices: &[pdb_file::TypeIndex],
+    primitives_flavor: PrimitiveReconstructionFlavor,
+    print_header: bool,
+    reconstruct_dependencies: bool,
+    print_access_specifiers: bool,
+    ignore_std_types: bool,
+) -> Result<String>
+where
+    T: io::Seek + io::Read + std::fmt::Debug + 'p,
+{
+    let data = pdb_file.reconstruct_types_by_index(
+        type_indices,
+        primitives_flavor,
+        reconstruct_dependencies,
+        print_access_specifiers,
+        ignore_std_types,
+    )?;
+    if print_header {
+        let file_header =
+            generate_file_header(pdb_file, primitives_flavor, true, ignore_std_types, None);
+        Ok(format!("{file_header}{data}"))
+    } else {
+        Ok(data)
+    }
+}
+
 fn reconstruct_all_types_command<'p, T>(
     pdb_file: &PdbFile<'p, T>,
     primitives_flavor: PrimitiveReconstructionFlavor,
     print_header: bool,
     print_access_specifiers: bool,
     ignore_std_types: bool,
+    cancellation_token: &CancellationToken,
 ) -> Result<String>
 where
     T: io::Seek + io::Read + std::fmt::Debug + 'p,
@@ -735,9 +2337,30 @@ where
         primitives_flavor,
         print_access_specifiers,
         ignore_std_types,
+        cancellation_token,
     )?;
     if print_header {
-        let file_header = generate_file_header(pdb_file, primitives_flavor, true, ignore_std_types);
+        let file_header =
+            generate_file_header(pdb_file, primitives_flavor, true, ignore_std_types, None);
+        Ok(format!("{file_header}{data}"))
+    } else {
+        Ok(data)
+    }
+}
+
+fn reconstruct_all_enums_command<'p, T>(
+    pdb_file: &PdbFile<'p, T>,
+    primitives_flavor: PrimitiveReconstructionFlavor,
+    print_header: bool,
+    ignore_std_types: bool,
+) -> Result<String>
+where
+    T: io::Seek + io::Read + std::fmt::Debug + 'p,
+{
+    let data = pdb_file.reconstruct_all_enums(primitives_flavor, ignore_std_types)?;
+    if print_header {
+        let file_header =
+            generate_file_header(pdb_file, primitives_flavor, true, ignore_std_types, None);
         Ok(format!("{file_header}{data}"))
     } else {
         Ok(data)
@@ -760,7 +2383,7 @@ where
         print_access_specifiers,
     )?;
     if print_header {
-        let file_header = generate_file_header(pdb_file, primitives_flavor, true, false);
+        let file_header = generate_file_header(pdb_file, primitives_flavor, true, false, None);
         Ok(format!("{file_header}\n{data}"))
     } else {
         Ok(data)
@@ -783,7 +2406,7 @@ where
         print_access_specifiers,
     )?;
     if print_header {
-        let file_header = generate_file_header(pdb_file, primitives_flavor, true, false);
+        let file_header = generate_file_header(pdb_file, primitives_flavor, true, false, None);
         Ok(format!("{file_header}\n{data}"))
     } else {
         Ok(data)
@@ -801,7 +2424,7 @@ where
 {
     let data = pdb_file.reconstruct_all_symbols(primitives_flavor, print_access_specifiers)?;
     if print_header {
-        let file_header = generate_file_header(pdb_file, primitives_flavor, true, false);
+        let file_header = generate_file_header(pdb_file, primitives_flavor, true, false, None);
         Ok(format!("{file_header}{data}"))
     } else {
         Ok(data)
@@ -825,47 +2448,181 @@ where
         print_access_specifiers,
     )?;
     if print_header {
-        let file_header = generate_file_header(pdb_file, primitives_flavor, true, ignore_std_types);
+        let file_header =
+            generate_file_header(pdb_file, primitives_flavor, true, ignore_std_types, None);
+        Ok(format!("{file_header}\n{data}"))
+    } else {
+        Ok(data)
+    }
+}
+
+fn reconstruct_source_file_by_path_command<'p, T>(
+    pdb_file: &PdbFile<'p, T>,
+    file_path: &str,
+    primitives_flavor: PrimitiveReconstructionFlavor,
+    print_header: bool,
+    print_access_specifiers: bool,
+) -> Result<String>
+where
+    T: io::Seek + io::Read + std::fmt::Debug + 'p,
+{
+    let data = pdb_file.reconstruct_source_file_by_path(
+        file_path,
+        primitives_flavor,
+        print_access_specifiers,
+    )?;
+    if print_header {
+        let file_header = generate_file_header(pdb_file, primitives_flavor, true, false, None);
         Ok(format!("{file_header}\n{data}"))
     } else {
         Ok(data)
     }
 }
 
+/// Generate a classic hexadecimal dump (offset, bytes and ASCII characters) of
+/// the given data. Data is truncated to keep the output reasonably sized.
+fn hex_dump(data: &[u8]) -> String {
+    const BYTES_PER_LINE: usize = 16;
+    const MAX_DUMP_SIZE: usize = 0x100000;
+
+    let mut output = String::with_capacity(data.len().min(MAX_DUMP_SIZE) * 5);
+    for (line_index, line) in data[..data.len().min(MAX_DUMP_SIZE)]
+        .chunks(BYTES_PER_LINE)
+        .enumerate()
+    {
+        let _r = write!(&mut output, "{:08x}  ", line_index * BYTES_PER_LINE);
+        for byte_index in 0..BYTES_PER_LINE {
+            match line.get(byte_index) {
+                Some(byte) => {
+                    let _r = write!(&mut output, "{byte:02x} ");
+                }
+                None => output.push_str("   "),
+            }
+        }
+        output.push(' ');
+        output.extend(line.iter().map(|byte| {
+            if byte.is_ascii_graphic() || *byte == b' ' {
+                *byte as char
+            } else {
+                '.'
+            }
+        }));
+        output.push('\n');
+    }
+    if data.len() > MAX_DUMP_SIZE {
+        let _r = writeln!(
+            &mut output,
+            "... (0x{:x} bytes omitted)",
+            data.len() - MAX_DUMP_SIZE
+        );
+    }
+
+    output
+}
+
 fn generate_file_header<T>(
     pdb_file: &PdbFile<T>,
     primitives_flavor: PrimitiveReconstructionFlavor,
     include_header_files: bool,
     ignore_std_types: bool,
+    type_name: Option<&str>,
 ) -> String
 where
-    T: io::Seek + io::Read,
+    T: io::Seek + io::Read + std::fmt::Debug,
 {
-    format!(
-        concat!(
-            "//\n",
-            "// Information extracted with resym v{}\n",
-            "//\n",
-            "// PDB file: {}\n",
-            "// Image architecture: {}\n",
-            "//\n",
-            "{}"
-        ),
-        PKG_VERSION,
-        pdb_file.file_path.display(),
-        pdb_file.machine_type,
-        if include_header_files {
-            format!(
-                "\n{}",
-                include_headers_for_flavor(primitives_flavor, ignore_std_types)
-            )
-        } else {
-            "".to_string()
+    let pdb_path = pdb_file.file_path.display().to_string();
+    let pdb_name = pdb_file.file_name();
+    let guid = pdb_file.guid().unwrap_or_default();
+    let architecture = pdb_file.machine_type.to_string();
+    let date = current_date();
+    let header = pdb_file.settings.header_template.render(&[
+        ("pdb_path", &pdb_path),
+        ("pdb_name", &pdb_name),
+        ("guid", &guid),
+        ("architecture", &architecture),
+        ("date", &date),
+        ("resym_version", PKG_VERSION),
+        ("type_name", type_name.unwrap_or_default()),
+    ]);
+
+    if include_header_files {
+        format!(
+            "{header}\n{}",
+            include_headers_for_flavor(primitives_flavor, ignore_std_types)
+        )
+    } else {
+        header
+    }
+}
+
+/// Filter the types of several PDBs and merge the results
+fn merged_type_filter_command(
+    pdb_files: &HashMap<PDBSlot, PdbFile<PDBDataSource>>,
+    pdb_slots: &[PDBSlot],
+    search_filter: &str,
+    case_insensitive_search: bool,
+    use_regex: bool,
+    ignore_std_types: bool,
+) -> BTreeSet<(String, pdb_file::TypeIndex)> {
+    let mut filtered_type_set = BTreeSet::default();
+    for pdb_slot in pdb_slots {
+        if let Some(pdb_file) = pdb_files.get(pdb_slot) {
+            let filtered_type_list = update_type_filter_command(
+                pdb_file,
+                search_filter,
+                case_insensitive_search,
+                use_regex,
+                ignore_std_types,
+                false,
+            );
+            filtered_type_set.extend(filtered_type_list.into_iter().map(|(s, _)| {
+                // Collapse all type indices to `default`. When merging
+                // type lists, we can only count on type names to
+                // represent the types.
+                (s, Default::default())
+            }));
+        }
+    }
+
+    filtered_type_set
+}
+
+/// Filter the types of several PDBs, keeping track of the PDB each type comes
+/// from
+fn tagged_type_filter_command(
+    pdb_files: &HashMap<PDBSlot, PdbFile<PDBDataSource>>,
+    pdb_slots: &[PDBSlot],
+    search_filter: &str,
+    case_insensitive_search: bool,
+    use_regex: bool,
+    ignore_std_types: bool,
+) -> TaggedTypeList {
+    let mut tagged_type_list = TaggedTypeList::default();
+    for pdb_slot in pdb_slots {
+        if let Some(pdb_file) = pdb_files.get(pdb_slot) {
+            let filtered_type_list = update_type_filter_command(
+                pdb_file,
+                search_filter,
+                case_insensitive_search,
+                use_regex,
+                ignore_std_types,
+                false,
+            );
+            tagged_type_list.extend(
+                filtered_type_list
+                    .into_iter()
+                    .map(|(type_name, type_index)| (type_name, *pdb_slot, type_index)),
+            );
         }
-    )
+    }
+    // Note: the sort is stable, types found in several PDBs stay in the
+    // order of `pdb_slots`
+    tagged_type_list.sort_by(|(lhs, ..), (rhs, ..)| lhs.cmp(rhs));
+
+    tagged_type_list
 }
 
-fn update_type_filter_command<T>(
+pub(crate) fn update_type_filter_command<T>(
     pdb_file: &PdbFile<T>,
     search_filter: &str,
     case_insensitive_search: bool,
@@ -878,11 +2635,23 @@ where
 {
     let filter_start = Instant::now();
 
+    // Typedefs are listed alongside user-defined types
+    let type_list: TypeList = match pdb_file.type_list() {
+        Ok(type_list) => type_list
+            .iter()
+            .cloned()
+            .chain(pdb_file.typedef_list())
+            .collect(),
+        Err(err) => {
+            log::error!("Failed to list types: {err}");
+            TypeList::default()
+        }
+    };
     // Filter out std types if needed
     let filtered_type_list = if ignore_std_types {
-        filter_std_types(&pdb_file.complete_type_list)
+        filter_std_types(&type_list)
     } else {
-        pdb_file.complete_type_list.clone()
+        type_list
     };
 
     // Filter types following the search filter
@@ -908,6 +2677,18 @@ where
     filtered_type_list
 }
 
+/// Check that the given search filter can be used, so that users get
+/// feedback instead of empty results (e.g., for invalid regular expressions)
+fn check_search_filter(search_filter: &str, use_regex: bool) -> std::result::Result<(), String> {
+    if use_regex {
+        regex::Regex::new(search_filter)
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    } else {
+        Ok(())
+    }
+}
+
 /// Filter type list with a regular expression
 fn filter_types_regex(
     type_list: &[(String, u32)],
@@ -958,39 +2739,44 @@ fn filter_std_types(type_list: &[(String, pdb_file::TypeIndex)]) -> TypeList {
 fn update_symbol_filter_command<T>(
     pdb_file: &PdbFile<T>,
     search_filter: &str,
-    case_insensitive_search: bool,
-    use_regex: bool,
-    ignore_std_symbols: bool,
+    list_options: &SymbolListOptions,
 ) -> SymbolList
 where
     T: io::Seek + io::Read + fmt::Debug,
 {
     let filter_start = Instant::now();
 
-    match pdb_file.symbol_list() {
+    match pdb_file.symbol_list_by_kind(&list_options.kind_filter) {
         Err(_) => SymbolList::default(),
         Ok(symbol_list) => {
             // Filter out std types if needed
-            let filtered_symbol_list = if ignore_std_symbols {
+            let filtered_symbol_list = if list_options.ignore_std_types {
                 filter_std_symbols(&symbol_list)
             } else {
                 symbol_list.clone()
             };
+            // Replace decorated names with their undecorated version if needed,
+            // so that filters apply to the latter
+            let filtered_symbol_list = if list_options.undecorate_names {
+                undecorate_symbols(&filtered_symbol_list)
+            } else {
+                filtered_symbol_list
+            };
 
             let filtered_symbol_list = if search_filter.is_empty() {
                 // No need to filter
                 filtered_symbol_list
-            } else if use_regex {
+            } else if list_options.use_regex {
                 filter_symbols_regex(
                     &filtered_symbol_list,
                     search_filter,
-                    case_insensitive_search,
+                    list_options.case_insensitive_search,
                 )
             } else {
                 filter_symbols_regular(
                     &filtered_symbol_list,
                     search_filter,
-                    case_insensitive_search,
+                    list_options.case_insensitive_search,
                 )
             };
 
@@ -1004,6 +2790,18 @@ where
     }
 }
 
+/// Replace decorated symbol names with their undecorated form, names which
+/// cannot be demangled are kept as is
+fn undecorate_symbols(symbol_list: &[(String, pdb_file::SymbolIndex)]) -> SymbolList {
+    par_iter_if_available!(symbol_list)
+        .map(|(symbol_name, symbol_index)| {
+            let symbol_name =
+                demangle_symbol_name(symbol_name, false).unwrap_or_else(|| symbol_name.clone());
+            (symbol_name, *symbol_index)
+        })
+        .collect()
+}
+
 /// Filter symbol list to remove types in the `std` namespace
 fn filter_std_symbols(symbol_list: &[(String, pdb_file::SymbolIndex)]) -> SymbolList {
     par_iter_if_available!(symbol_list)
@@ -1087,6 +2885,43 @@ where
     Ok(filtered_module_list)
 }
 
+fn list_source_files_command<'p, T>(
+    pdb_file: &PdbFile<'p, T>,
+    search_filter: &str,
+    case_insensitive_search: bool,
+    use_regex: bool,
+) -> Result<SourceFileList>
+where
+    T: io::Seek + io::Read + std::fmt::Debug + 'p,
+{
+    let filter_start = Instant::now();
+
+    // Note: source file lists have the same shape as module lists
+    let filtered_source_file_list = if search_filter.is_empty() {
+        // No need to filter
+        pdb_file.source_file_list()?
+    } else if use_regex {
+        filter_modules_regex(
+            &pdb_file.source_file_list()?,
+            search_filter,
+            case_insensitive_search,
+        )
+    } else {
+        filter_modules_regular(
+            &pdb_file.source_file_list()?,
+            search_filter,
+            case_insensitive_search,
+        )
+    };
+
+    log::debug!(
+        "Source file filtering took {} ms",
+        filter_start.elapsed().as_millis()
+    );
+
+    Ok(filtered_source_file_list)
+}
+
 /// Filter module list with a regular expression
 fn filter_modules_regex(
     module_list: &[(String, usize)],
@@ -1142,3 +2977,62 @@ where
 
     Ok(xref_list)
 }
+
+fn list_type_reverse_dependencies_command<'p, T>(
+    pdb_file: &PdbFile<'p, T>,
+    type_index: pdb_file::TypeIndex,
+) -> Result<TypeList>
+where
+    T: io::Seek + io::Read + std::fmt::Debug + 'p,
+{
+    let resolution_start = Instant::now();
+    let reverse_dependency_list = pdb_file.get_reverse_dependencies_for_type(type_index)?;
+    log::debug!(
+        "Reverse dependency resolution took {} ms",
+        resolution_start.elapsed().as_millis()
+    );
+
+    Ok(reverse_dependency_list)
+}
+
+/// Pipe reconstructed code through `clang-format`, if enabled. The code is
+/// kept as is if `clang-format` fails.
+#[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
+fn format_reconstructed_code(settings: &ReconstructionSettings, code: String) -> String {
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(configuration) = &settings.clang_format {
+        match clang_format(&configuration.path, &configuration.style, &code) {
+            Ok(formatted_code) => return formatted_code,
+            Err(err) => {
+                log::warn!("Failed to run clang-format, returning unformatted code: {err}");
+            }
+        }
+    }
+
+    code
+}
+
+/// Apply updated reconstruction settings to the loaded PDBs
+fn apply_reconstruction_settings(
+    pdb_files: &mut HashMap<PDBSlot, PdbFile<PDBDataSource>>,
+    reconstruction_settings: &ReconstructionSettings,
+) {
+    for pdb_file in pdb_files.values_mut() {
+        pdb_file.settings = reconstruction_settings.clone();
+    }
+}
+
+/// Return the renames registered for the given PDB's GUID, if any
+fn rename_map_for_pdb<'p, T>(
+    rename_maps: &HashMap<String, RenameMap>,
+    pdb_file: &PdbFile<'p, T>,
+) -> RenameMap
+where
+    T: io::Seek + io::Read + std::fmt::Debug + 'p,
+{
+    pdb_file
+        .guid()
+        .ok()
+        .and_then(|guid| rename_maps.get(&guid).cloned())
+        .unwrap_or_default()
+}
@@ -0,0 +1,38 @@
+//! Cooperative cancellation of long-running operations (e.g., loading PDBs or
+//! reconstructing all of their types).
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use crate::error::{Result, ResymCoreError};
+
+/// Flag shared between the frontend, which requests cancellation, and the
+/// operations which periodically check it
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn reset(&self) {
+        self.cancelled.store(false, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Return `OperationCancelledError` if cancellation has been requested
+    pub fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            Err(ResymCoreError::OperationCancelledError)
+        } else {
+            Ok(())
+        }
+    }
+}
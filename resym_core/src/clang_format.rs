@@ -0,0 +1,47 @@
+use std::{
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use crate::error::{Result, ResymCoreError};
+
+/// Location of the `clang-format` binary and style used to format
+/// reconstructed code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClangFormatConfiguration {
+    pub path: PathBuf,
+    pub style: String,
+}
+
+/// Format the given code by piping it through a `clang-format` binary, using
+/// the given style (e.g., `LLVM`, `Microsoft` or `file`).
+pub fn clang_format(clang_format_path: &Path, style: &str, code: &str) -> Result<String> {
+    let mut child = Command::new(clang_format_path)
+        .arg(format!("--style={style}"))
+        // Let `clang-format` know it's processing C++ code
+        .arg("--assume-filename=reconstructed.hpp")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // Feed the input from a separate thread, to avoid deadlocking if
+    // `clang-format` fills its output pipe before consuming all of the input
+    let mut stdin = child.stdin.take().expect("stdin should be piped");
+    let code = code.to_owned();
+    let writer_thread = std::thread::spawn(move || stdin.write_all(code.as_bytes()));
+    let output = child.wait_with_output()?;
+    writer_thread
+        .join()
+        .unwrap_or_else(|_| Err(io::Error::other("writer thread panicked")))?;
+
+    if !output.status.success() {
+        return Err(ResymCoreError::ClangFormatError(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    String::from_utf8(output.stdout)
+        .map_err(|err| ResymCoreError::ClangFormatError(err.to_string()))
+}
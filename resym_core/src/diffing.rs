@@ -1,12 +1,14 @@
 #[cfg(target_arch = "wasm32")]
 use instant::Instant;
-use similar::{ChangeTag, TextDiff};
+use regex::Regex;
+use similar::{ChangeTag, DiffOp, DiffTag, TextDiff};
 
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::Instant;
-use std::{fmt::Write, io};
+use std::{collections::BTreeSet, fmt::Write, io, ops::Range, path::PathBuf};
 
 use crate::{
+    cancellation::CancellationToken,
     error::{Result, ResymCoreError},
     pdb_file::PdbFile,
     pdb_types::PrimitiveReconstructionFlavor,
@@ -15,33 +17,135 @@ use crate::{
 
 pub type DiffChange = ChangeTag;
 pub type DiffIndices = (Option<usize>, Option<usize>);
+/// Byte ranges of the words that changed within a line (relative to the
+/// beginning of the line)
+pub type DiffInlineChanges = Vec<Range<usize>>;
+/// Number of unchanged lines printed around changes in unified patches
+const PATCH_CONTEXT_LINE_COUNT: usize = 3;
 
 #[derive(Default)]
 pub struct Diff {
     pub metadata: Vec<(DiffIndices, DiffChange)>,
+    /// Word-level changes, for each line of `data`
+    pub inline_changes: Vec<DiffInlineChanges>,
+    pub statistics: DiffStatistics,
     pub data: String,
 }
+
+impl Diff {
+    /// Format the diff as a unified patch, see `format_unified_patch`
+    pub fn to_unified_patch(&self, from_label: &str, to_label: &str) -> String {
+        let changes: Vec<DiffChange> = self.metadata.iter().map(|(_, change)| *change).collect();
+        format_unified_patch(&changes, &self.data, from_label, to_label)
+    }
+
+    /// Format the diff as two columns, see `format_side_by_side_diff`
+    pub fn to_side_by_side(&self) -> String {
+        let changes: Vec<DiffChange> = self.metadata.iter().map(|(_, change)| *change).collect();
+        format_side_by_side_diff(&changes, &self.data)
+    }
+}
+
+/// Summary of the changes contained in a `Diff`
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DiffStatistics {
+    pub lines_added: usize,
+    pub lines_removed: usize,
+    pub fields_added: usize,
+    pub fields_removed: usize,
+    pub virtual_methods_added: usize,
+    pub virtual_methods_removed: usize,
+    /// Size of the diffed type in the reference PDB (type diffs only)
+    pub size_before: Option<u64>,
+    /// Size of the diffed type in the new PDB (type diffs only)
+    pub size_after: Option<u64>,
+}
+
+impl DiffStatistics {
+    fn update(&mut self, change: DiffChange, line: &str) {
+        let (lines, fields, virtual_methods) = match change {
+            DiffChange::Insert => (
+                &mut self.lines_added,
+                &mut self.fields_added,
+                &mut self.virtual_methods_added,
+            ),
+            DiffChange::Delete => (
+                &mut self.lines_removed,
+                &mut self.fields_removed,
+                &mut self.virtual_methods_removed,
+            ),
+            DiffChange::Equal => return,
+        };
+
+        *lines += 1;
+        let line = line.trim_start();
+        if split_field_offset(line).is_some() {
+            // Fields are prefixed with their offset
+            *fields += 1;
+        } else if is_virtual_method_line(line) {
+            *virtual_methods += 1;
+        }
+    }
+}
+
+/// State of a type in one of the PDBs of an N-way comparison
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeRevision {
+    pub pdb_file_path: PathBuf,
+    /// `false` if the type isn't present in this PDB
+    pub present: bool,
+    pub size: Option<u64>,
+    /// `true` if the type's size differs from the one in the previous PDB
+    pub size_changed: bool,
+    /// `true` if the type's reconstructed layout differs from the one in the
+    /// previous PDB
+    pub layout_changed: bool,
+    /// Changes relative to the previous PDB
+    pub statistics: DiffStatistics,
+}
+
 pub struct DiffLine {
     pub indices: DiffIndices,
     pub change: DiffChange,
     pub line: String,
 }
 
-#[allow(clippy::too_many_arguments)]
+/// Options of type diffs (e.g., `diff_type_by_name`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DiffOptions {
+    pub print_header: bool,
+    pub reconstruct_dependencies: bool,
+    pub print_access_specifiers: bool,
+    pub ignore_std_types: bool,
+    /// Ignore `//` comment lines (e.g., headers)
+    pub ignore_comments: bool,
+    /// Pair types by their unique (decorated) name, and fall back to their
+    /// display name
+    pub match_unique_names: bool,
+    /// Don't report fields whose offset is the only thing that changed
+    pub ignore_offset_only_changes: bool,
+}
+
 pub fn diff_type_by_name<'p, T>(
     pdb_file_from: &PdbFile<'p, T>,
     pdb_file_to: &PdbFile<'p, T>,
     type_name: &str,
     primitives_flavor: PrimitiveReconstructionFlavor,
-    print_header: bool,
-    reconstruct_dependencies: bool,
-    print_access_specifiers: bool,
-    ignore_std_types: bool,
+    diff_options: &DiffOptions,
 ) -> Result<Diff>
 where
     T: io::Seek + io::Read + std::fmt::Debug + 'p,
 {
     let diff_start = Instant::now();
+    let DiffOptions {
+        print_header,
+        reconstruct_dependencies,
+        print_access_specifiers,
+        ignore_std_types,
+        ignore_comments,
+        match_unique_names,
+        ignore_offset_only_changes,
+    } = *diff_options;
 
     // Prepend header if needed
     let (mut reconstructed_type_from, mut reconstructed_type_to) = if print_header {
@@ -51,8 +155,9 @@ where
         (String::default(), String::default())
     };
 
-    // Reconstruct types from both PDBs
-    {
+    // Reconstruct types from both PDBs, keeping track of the name the type
+    // was found by in the "to" PDB
+    let type_name_to = {
         let (reconstructed_type_from_tmp, _) = pdb_file_from
             .reconstruct_type_by_name(
                 type_name,
@@ -62,26 +167,299 @@ where
                 ignore_std_types,
             )
             .unwrap_or_default();
-        let (reconstructed_type_to_tmp, _) = pdb_file_to
+        // Pair types by their unique (decorated) name if requested and fall
+        // back to the display name otherwise
+        let unique_type_name = if match_unique_names {
+            pdb_file_from.type_unique_name(type_name)?
+        } else {
+            None
+        };
+        let reconstruct_type_to = |type_name: &str| {
+            pdb_file_to
+                .reconstruct_type_by_name(
+                    type_name,
+                    primitives_flavor,
+                    reconstruct_dependencies,
+                    print_access_specifiers,
+                    ignore_std_types,
+                )
+                .ok()
+        };
+        let (matched_type_name, (reconstructed_type_to_tmp, _)) = unique_type_name
+            .and_then(|unique_type_name| {
+                reconstruct_type_to(&unique_type_name)
+                    .map(|reconstructed_type| (unique_type_name, reconstructed_type))
+            })
+            .or_else(|| {
+                reconstruct_type_to(type_name)
+                    .map(|reconstructed_type| (type_name.to_owned(), reconstructed_type))
+            })
+            .unwrap_or_else(|| (type_name.to_owned(), Default::default()));
+        if reconstructed_type_from_tmp.is_empty() && reconstructed_type_to_tmp.is_empty() {
+            // Make it obvious an error occured
+            return Err(ResymCoreError::TypeNameNotFoundError(type_name.to_owned()));
+        }
+        reconstructed_type_from.push_str(&reconstructed_type_from_tmp);
+        reconstructed_type_to.push_str(&reconstructed_type_to_tmp);
+
+        matched_type_name
+    };
+
+    // Diff reconstructed representations
+    let mut diff = generate_diff(
+        &reconstructed_type_from,
+        &reconstructed_type_to,
+        ignore_comments,
+        ignore_offset_only_changes,
+    )?;
+    // Note: sizes are read from the type information, as the reconstructed
+    // code depends on the number format
+    diff.statistics.size_before = pdb_file_from.type_size(type_name)?;
+    diff.statistics.size_after = pdb_file_to.type_size(&type_name_to)?;
+    log::debug!("Type diffing took {} ms", diff_start.elapsed().as_millis());
+
+    Ok(diff)
+}
+
+/// Type whose reconstruction differs between two PDB files
+pub struct ChangedType {
+    pub type_name: String,
+    pub diff: Diff,
+}
+
+/// Diff all the types defined in either of the given PDB files (whose name
+/// matches `type_name_filter`, if any), and return the ones that changed
+/// (including added and removed types), ordered by name.
+/// Headers and dependencies are never reconstructed, so that only changes to
+/// the types themselves get reported (`print_header` and
+/// `reconstruct_dependencies` are ignored).
+/// Diffing stops with `OperationCancelledError` if `cancellation_token` gets
+/// cancelled.
+pub fn diff_all_types<'p, T>(
+    pdb_file_from: &PdbFile<'p, T>,
+    pdb_file_to: &PdbFile<'p, T>,
+    type_name_filter: Option<&Regex>,
+    primitives_flavor: PrimitiveReconstructionFlavor,
+    diff_options: &DiffOptions,
+    cancellation_token: &CancellationToken,
+) -> Result<Vec<ChangedType>>
+where
+    T: io::Seek + io::Read + std::fmt::Debug + 'p,
+{
+    let diff_start = Instant::now();
+    let diff_options = DiffOptions {
+        print_header: false,
+        reconstruct_dependencies: false,
+        ..*diff_options
+    };
+
+    let type_list_from = pdb_file_from.type_list()?;
+    let type_list_to = pdb_file_to.type_list()?;
+    let type_names: BTreeSet<&str> = type_list_from
+        .iter()
+        .chain(type_list_to.iter())
+        .map(|(type_name, _)| type_name.as_str())
+        .filter(|type_name| !diff_options.ignore_std_types || !type_name.starts_with("std::"))
+        .filter(|type_name| type_name_filter.map_or(true, |filter| filter.is_match(type_name)))
+        .collect();
+
+    let mut changed_types = vec![];
+    for type_name in type_names {
+        cancellation_token.check()?;
+        let diff_result = diff_type_by_name(
+            pdb_file_from,
+            pdb_file_to,
+            type_name,
+            primitives_flavor,
+            &diff_options,
+        );
+        let diff = match diff_result {
+            Ok(diff) => diff,
+            // Types which cannot be reconstructed from either PDB are skipped
+            Err(ResymCoreError::TypeNameNotFoundError(_)) => continue,
+            Err(err) => return Err(err),
+        };
+        if diff.statistics.lines_added > 0 || diff.statistics.lines_removed > 0 {
+            changed_types.push(ChangedType {
+                type_name: type_name.to_string(),
+                diff,
+            });
+        }
+    }
+    log::debug!(
+        "Diffing all types took {} ms",
+        diff_start.elapsed().as_millis()
+    );
+
+    Ok(changed_types)
+}
+
+/// Names of the types whose reconstruction differs between the given PDB
+/// files (including added and removed types), when diffed as specified by
+/// `diff_options` (see `diff_all_types`).
+pub fn changed_type_names<'p, T>(
+    pdb_file_from: &PdbFile<'p, T>,
+    pdb_file_to: &PdbFile<'p, T>,
+    diff_options: &DiffOptions,
+    cancellation_token: &CancellationToken,
+) -> Result<BTreeSet<String>>
+where
+    T: io::Seek + io::Read + std::fmt::Debug + 'p,
+{
+    let changed_types = diff_all_types(
+        pdb_file_from,
+        pdb_file_to,
+        None,
+        PrimitiveReconstructionFlavor::Portable,
+        diff_options,
+        cancellation_token,
+    )?;
+
+    Ok(changed_types
+        .into_iter()
+        .map(|changed_type| changed_type.type_name)
+        .collect())
+}
+
+/// Compare a type across an ordered list of PDB files (e.g., successive builds
+/// of the same binary) and report, for each of them, how the type changed
+/// relatively to the previous one.
+pub fn compare_type_across_pdbs<'p, T>(
+    pdb_files: &[&PdbFile<'p, T>],
+    type_name: &str,
+    primitives_flavor: PrimitiveReconstructionFlavor,
+    print_access_specifiers: bool,
+    ignore_std_types: bool,
+) -> Result<Vec<TypeRevision>>
+where
+    T: io::Seek + io::Read + std::fmt::Debug + 'p,
+{
+    let comparison_start = Instant::now();
+
+    let mut type_revisions = Vec::with_capacity(pdb_files.len());
+    let mut previous_reconstructed_type = String::default();
+    for (i, pdb_file) in pdb_files.iter().enumerate() {
+        let (reconstructed_type, _) = pdb_file
             .reconstruct_type_by_name(
                 type_name,
                 primitives_flavor,
-                reconstruct_dependencies,
+                false,
                 print_access_specifiers,
                 ignore_std_types,
             )
             .unwrap_or_default();
-        if reconstructed_type_from_tmp.is_empty() && reconstructed_type_to_tmp.is_empty() {
-            // Make it obvious an error occured
-            return Err(ResymCoreError::TypeNameNotFoundError(type_name.to_owned()));
-        }
-        reconstructed_type_from.push_str(&reconstructed_type_from_tmp);
-        reconstructed_type_to.push_str(&reconstructed_type_to_tmp);
+        let size = pdb_file.type_size(type_name)?;
+
+        let (size_changed, layout_changed, statistics) = if i == 0 {
+            (false, false, DiffStatistics::default())
+        } else {
+            let mut statistics = generate_diff(
+                &previous_reconstructed_type,
+                &reconstructed_type,
+                true,
+                false,
+            )?
+            .statistics;
+            statistics.size_before = type_revisions
+                .last()
+                .and_then(|revision: &TypeRevision| revision.size);
+            statistics.size_after = size;
+            (
+                statistics.size_before != statistics.size_after,
+                statistics.lines_added + statistics.lines_removed > 0,
+                statistics,
+            )
+        };
+        type_revisions.push(TypeRevision {
+            pdb_file_path: pdb_file.file_path.clone(),
+            present: !reconstructed_type.is_empty(),
+            size,
+            size_changed,
+            layout_changed,
+            statistics,
+        });
+        previous_reconstructed_type = reconstructed_type;
+    }
+    if !type_revisions.iter().any(|revision| revision.present) {
+        // Make it obvious an error occured
+        return Err(ResymCoreError::TypeNameNotFoundError(type_name.to_owned()));
     }
+    log::debug!(
+        "Type comparison took {} ms",
+        comparison_start.elapsed().as_millis()
+    );
+
+    Ok(type_revisions)
+}
+
+/// Compute a three-way diff of a type between a base PDB and two variants of
+/// it. Conflicting changes are rendered with conflict markers.
+/// Note: types are always paired by their display name, and offset-only
+/// changes are reported (`match_unique_names` and `ignore_offset_only_changes`
+/// are ignored).
+pub fn diff_type_by_name_three_way<'p, T>(
+    pdb_file_base: &PdbFile<'p, T>,
+    pdb_file_a: &PdbFile<'p, T>,
+    pdb_file_b: &PdbFile<'p, T>,
+    type_name: &str,
+    primitives_flavor: PrimitiveReconstructionFlavor,
+    diff_options: &DiffOptions,
+) -> Result<Diff>
+where
+    T: io::Seek + io::Read + std::fmt::Debug + 'p,
+{
+    let diff_start = Instant::now();
+    let DiffOptions {
+        print_header,
+        reconstruct_dependencies,
+        print_access_specifiers,
+        ignore_std_types,
+        ignore_comments,
+        ..
+    } = *diff_options;
+
+    // Reconstruct types from all PDBs
+    let reconstruct_type = |pdb_file: &PdbFile<'p, T>| {
+        pdb_file
+            .reconstruct_type_by_name(
+                type_name,
+                primitives_flavor,
+                reconstruct_dependencies,
+                print_access_specifiers,
+                ignore_std_types,
+            )
+            .map(|(reconstructed_type, _)| reconstructed_type)
+            .unwrap_or_default()
+    };
+    let reconstructed_type_base = reconstruct_type(pdb_file_base);
+    let reconstructed_type_a = reconstruct_type(pdb_file_a);
+    let reconstructed_type_b = reconstruct_type(pdb_file_b);
+    if reconstructed_type_base.is_empty()
+        && reconstructed_type_a.is_empty()
+        && reconstructed_type_b.is_empty()
+    {
+        // Make it obvious an error occured
+        return Err(ResymCoreError::TypeNameNotFoundError(type_name.to_owned()));
+    }
+
+    // Prepend header if needed
+    let diff_header = if print_header {
+        generate_three_way_diff_header(pdb_file_base, pdb_file_a, pdb_file_b)
+    } else {
+        String::default()
+    };
 
     // Diff reconstructed representations
-    let diff = generate_diff(&reconstructed_type_from, &reconstructed_type_to)?;
-    log::debug!("Type diffing took {} ms", diff_start.elapsed().as_millis());
+    let diff = generate_three_way_diff(
+        &(diff_header.clone() + &reconstructed_type_base),
+        &(diff_header.clone() + &reconstructed_type_a),
+        &(diff_header + &reconstructed_type_b),
+        ignore_comments,
+    )?;
+    log::debug!(
+        "Three-way type diffing took {} ms",
+        diff_start.elapsed().as_millis()
+    );
 
     Ok(diff)
 }
@@ -93,6 +471,7 @@ pub fn diff_module_by_path<'p, T>(
     primitives_flavor: PrimitiveReconstructionFlavor,
     print_header: bool,
     print_access_specifiers: bool,
+    ignore_comments: bool,
 ) -> Result<Diff>
 where
     T: io::Seek + io::Read + std::fmt::Debug + 'p,
@@ -126,7 +505,12 @@ where
     }
 
     // Diff reconstructed representations
-    let diff = generate_diff(&reconstructed_module_from, &reconstructed_module_to)?;
+    let diff = generate_diff(
+        &reconstructed_module_from,
+        &reconstructed_module_to,
+        ignore_comments,
+        false,
+    )?;
     log::debug!(
         "Module diffing took {} ms",
         diff_start.elapsed().as_millis()
@@ -142,6 +526,7 @@ pub fn diff_symbol_by_name<'p, T>(
     primitives_flavor: PrimitiveReconstructionFlavor,
     print_header: bool,
     print_access_specifiers: bool,
+    ignore_comments: bool,
 ) -> Result<Diff>
 where
     T: io::Seek + io::Read + std::fmt::Debug + 'p,
@@ -173,7 +558,12 @@ where
     }
 
     // Diff reconstructed representations
-    let diff = generate_diff(&reconstructed_symbol_from, &reconstructed_symbol_to)?;
+    let diff = generate_diff(
+        &reconstructed_symbol_from,
+        &reconstructed_symbol_to,
+        ignore_comments,
+        false,
+    )?;
     log::debug!(
         "Symbol diffing took {} ms",
         diff_start.elapsed().as_millis()
@@ -182,6 +572,51 @@ where
     Ok(diff)
 }
 
+/// Compute the diff between the public and function symbols of two PDB files
+pub fn diff_public_symbols<'p, T>(
+    pdb_file_from: &PdbFile<'p, T>,
+    pdb_file_to: &PdbFile<'p, T>,
+    primitives_flavor: PrimitiveReconstructionFlavor,
+    print_header: bool,
+    print_access_specifiers: bool,
+    ignore_comments: bool,
+) -> Result<Diff>
+where
+    T: io::Seek + io::Read + std::fmt::Debug + 'p,
+{
+    let diff_start = Instant::now();
+
+    // Prepend header if needed
+    let (mut reconstructed_symbols_from, mut reconstructed_symbols_to) = if print_header {
+        let diff_header = generate_diff_header(pdb_file_from, pdb_file_to);
+        (diff_header.clone(), diff_header)
+    } else {
+        (String::default(), String::default())
+    };
+
+    // Reconstruct symbols from both PDBs
+    reconstructed_symbols_from.push_str(
+        &pdb_file_from.reconstruct_public_symbols(primitives_flavor, print_access_specifiers)?,
+    );
+    reconstructed_symbols_to.push_str(
+        &pdb_file_to.reconstruct_public_symbols(primitives_flavor, print_access_specifiers)?,
+    );
+
+    // Diff reconstructed representations
+    let diff = generate_diff(
+        &reconstructed_symbols_from,
+        &reconstructed_symbols_to,
+        ignore_comments,
+        false,
+    )?;
+    log::debug!(
+        "Public symbols diffing took {} ms",
+        diff_start.elapsed().as_millis()
+    );
+
+    Ok(diff)
+}
+
 fn generate_diff_header<'p, T>(
     pdb_file_from: &PdbFile<'p, T>,
     pdb_file_to: &PdbFile<'p, T>,
@@ -211,24 +646,518 @@ where
     )
 }
 
-fn generate_diff(str_from: &str, str_to: &str) -> Result<Diff> {
-    let mut diff_metadata = vec![];
-    let mut diff_data = String::default();
-    {
-        let reconstructed_type_diff = TextDiff::from_lines(str_from, str_to);
-        for change in reconstructed_type_diff.iter_all_changes() {
-            diff_metadata.push(((change.old_index(), change.new_index()), change.tag()));
+/// Compute the line-based diff between two texts (e.g., two successive
+/// reconstructions of the same type)
+pub fn diff_text(str_from: &str, str_to: &str, ignore_comments: bool) -> Result<Diff> {
+    generate_diff(str_from, str_to, ignore_comments, false)
+}
+
+fn generate_diff(
+    str_from: &str,
+    str_to: &str,
+    ignore_comments: bool,
+    ignore_offset_only_changes: bool,
+) -> Result<Diff> {
+    if ignore_offset_only_changes {
+        return generate_offset_insensitive_diff(str_from, str_to, ignore_comments);
+    }
+
+    // Remove comment lines (e.g., headers) if needed, to avoid spurious changes
+    let (str_from, str_to) = if ignore_comments {
+        (strip_comment_lines(str_from), strip_comment_lines(str_to))
+    } else {
+        (str_from.to_owned(), str_to.to_owned())
+    };
+
+    let mut diff = Diff::default();
+    push_line_diff(&mut diff, &str_from, &str_to, (0, 0));
+
+    Ok(diff)
+}
+
+/// Append the line-based diff between two texts to `diff`, offsetting line
+/// indices by `index_offsets`.
+/// Note: inline changes are computed with a word-level diff on replaced lines
+fn push_line_diff(diff: &mut Diff, str_from: &str, str_to: &str, index_offsets: (usize, usize)) {
+    let text_diff = TextDiff::from_lines(str_from, str_to);
+    for op in text_diff.ops() {
+        for change in text_diff.iter_inline_changes(op) {
+            let indices = (
+                change.old_index().map(|index| index + index_offsets.0),
+                change.new_index().map(|index| index + index_offsets.1),
+            );
+            diff.metadata.push((indices, change.tag()));
             let prefix = match change.tag() {
                 ChangeTag::Insert => "+",
                 ChangeTag::Delete => "-",
                 ChangeTag::Equal => " ",
             };
-            write!(&mut diff_data, "{prefix}{change}")?;
+            diff.data.push_str(prefix);
+
+            let line_start = diff.data.len();
+            let mut line_offset = prefix.len();
+            let mut inline_changes = DiffInlineChanges::new();
+            for (emphasized, value) in change.iter_strings_lossy() {
+                if emphasized {
+                    inline_changes.push(line_offset..line_offset + value.len());
+                }
+                line_offset += value.len();
+                diff.data.push_str(&value);
+            }
+            diff.statistics
+                .update(change.tag(), &diff.data[line_start + prefix.len()..]);
+            if change.missing_newline() {
+                diff.data.push('\n');
+            }
+            diff.inline_changes.push(inline_changes);
+        }
+    }
+}
+
+/// Diff the given texts without taking field offsets into account, so that
+/// fields that only moved because a previous field changed aren't reported.
+/// Unchanged lines are displayed as they appear in `str_to`.
+fn generate_offset_insensitive_diff(
+    str_from: &str,
+    str_to: &str,
+    ignore_comments: bool,
+) -> Result<Diff> {
+    let (str_from, str_to) = if ignore_comments {
+        (strip_comment_lines(str_from), strip_comment_lines(str_to))
+    } else {
+        (str_from.to_owned(), str_to.to_owned())
+    };
+    let lines_from: Vec<&str> = str_from.split_inclusive('\n').collect();
+    let lines_to: Vec<&str> = str_to.split_inclusive('\n').collect();
+    let masked_from: Vec<String> = lines_from
+        .iter()
+        .map(|line| mask_field_offset(line))
+        .collect();
+    let masked_to: Vec<String> = lines_to
+        .iter()
+        .map(|line| mask_field_offset(line))
+        .collect();
+    let masked_from: Vec<&str> = masked_from.iter().map(String::as_str).collect();
+    let masked_to: Vec<&str> = masked_to.iter().map(String::as_str).collect();
+
+    let mut diff = Diff::default();
+    let masked_diff = TextDiff::from_slices(&masked_from, &masked_to);
+    for op in masked_diff.ops() {
+        // Replaced lines are diffed again without masking offsets, to compute
+        // inline changes the same way as `generate_diff`
+        if op.tag() == DiffTag::Replace {
+            push_line_diff(
+                &mut diff,
+                &lines_from[op.old_range()].concat(),
+                &lines_to[op.new_range()].concat(),
+                (op.old_range().start, op.new_range().start),
+            );
+            continue;
+        }
+        for change in masked_diff.iter_changes(op) {
+            let indices = (change.old_index(), change.new_index());
+            let line = match change.tag() {
+                ChangeTag::Delete => change.old_index().map(|index| lines_from[index]),
+                ChangeTag::Insert | ChangeTag::Equal => {
+                    change.new_index().map(|index| lines_to[index])
+                }
+            };
+            push_diff_line(&mut diff, indices, change.tag(), line.unwrap_or_default());
+        }
+    }
+
+    Ok(diff)
+}
+
+/// Split a field declaration prefixed with its offset (e.g.,
+/// `/* 0x0010 */ int32_t field;` or `/* 16 */ int32_t field;`, depending on
+/// the number format) into its offset and its declaration
+fn split_field_offset(declaration: &str) -> Option<(&str, &str)> {
+    declaration
+        .strip_prefix("/* ")
+        .and_then(|declaration| declaration.split_once(" */ "))
+        .filter(|(offset, _)| {
+            offset.starts_with(|c: char| c.is_ascii_digit())
+                && offset.chars().all(|c| c.is_ascii_alphanumeric())
+        })
+}
+
+/// Replace the offset comment that prefixes field declarations
+/// (e.g., `/* 0x0010 */`) with a placeholder
+fn mask_field_offset(line: &str) -> String {
+    let declaration = line.trim_start();
+    let indentation = &line[..line.len() - declaration.len()];
+    split_field_offset(declaration).map_or_else(
+        || line.to_string(),
+        |(_, field)| format!("{indentation}/* */ {field}"),
+    )
+}
+
+fn is_virtual_method_line(line: &str) -> bool {
+    const ACCESS_SPECIFIERS: [&str; 3] = ["public: ", "protected: ", "private: "];
+
+    let line = ACCESS_SPECIFIERS
+        .iter()
+        .find_map(|specifier| line.strip_prefix(specifier))
+        .unwrap_or(line);
+    line.starts_with("virtual ")
+}
+
+/// Split the given byte range into contiguous sub-ranges, and flag the ones
+/// that are part of the given inline changes (which must be sorted).
+pub fn split_range_by_inline_changes(
+    byte_range: Range<usize>,
+    inline_changes: &[Range<usize>],
+) -> Vec<(Range<usize>, bool)> {
+    let mut sub_ranges = vec![];
+    let mut current_start = byte_range.start;
+    for inline_change in inline_changes {
+        let change_start = inline_change.start.max(current_start);
+        let change_end = inline_change.end.min(byte_range.end);
+        if change_start >= change_end {
+            continue;
+        }
+
+        if current_start < change_start {
+            sub_ranges.push((current_start..change_start, false));
+        }
+        sub_ranges.push((change_start..change_end, true));
+        current_start = change_end;
+    }
+    if current_start < byte_range.end {
+        sub_ranges.push((current_start..byte_range.end, false));
+    }
+
+    sub_ranges
+}
+
+/// Format diff lines (prefixed like in `Diff::data`, with the kind of change of
+/// each line in `changes`) as a unified patch, as output by `diff -u`. Returns
+/// an empty string if nothing changed.
+pub fn format_unified_patch(
+    changes: &[DiffChange],
+    diff_data: &str,
+    from_label: &str,
+    to_label: &str,
+) -> String {
+    let lines: Vec<&str> = diff_data.lines().collect();
+    let line_count = lines.len().min(changes.len());
+    let changes = &changes[..line_count];
+
+    // Group changed lines and their context into hunks
+    let mut hunks: Vec<Range<usize>> = vec![];
+    for (line_index, change) in changes.iter().enumerate() {
+        if *change == DiffChange::Equal {
+            continue;
+        }
+        let start = line_index.saturating_sub(PATCH_CONTEXT_LINE_COUNT);
+        let end = (line_index + PATCH_CONTEXT_LINE_COUNT + 1).min(line_count);
+        match hunks.last_mut() {
+            Some(hunk) if start <= hunk.end => hunk.end = end,
+            _ => hunks.push(start..end),
+        }
+    }
+    if hunks.is_empty() {
+        return String::default();
+    }
+
+    let mut patch = format!("--- {from_label}\n+++ {to_label}\n");
+    // Number of lines of the old and new texts before the current hunk
+    let (mut old_line_count, mut new_line_count) = (0, 0);
+    let mut processed_line_count = 0;
+    for hunk in hunks {
+        for change in &changes[processed_line_count..hunk.start] {
+            old_line_count += usize::from(*change != DiffChange::Insert);
+            new_line_count += usize::from(*change != DiffChange::Delete);
+        }
+        let hunk_changes = &changes[hunk.clone()];
+        let old_length = hunk_changes
+            .iter()
+            .filter(|change| **change != DiffChange::Insert)
+            .count();
+        let new_length = hunk_changes
+            .iter()
+            .filter(|change| **change != DiffChange::Delete)
+            .count();
+        let _r = writeln!(
+            &mut patch,
+            "@@ -{} +{} @@",
+            hunk_range(old_line_count, old_length),
+            hunk_range(new_line_count, new_length)
+        );
+        for line in &lines[hunk.clone()] {
+            let _r = writeln!(&mut patch, "{line}");
+        }
+
+        old_line_count += old_length;
+        new_line_count += new_length;
+        processed_line_count = hunk.end;
+    }
+
+    patch
+}
+
+/// Format diff lines (prefixed like in `Diff::data`, with the kind of change of
+/// each line in `changes`) as two columns, as output by `diff -y`. Lines are
+/// separated by `|` when changed, `<` when removed and `>` when added.
+pub fn format_side_by_side_diff(changes: &[DiffChange], diff_data: &str) -> String {
+    let mut rows: Vec<(&str, char, &str)> = vec![];
+    // Removed lines are paired with the lines added right after them
+    let mut removed_lines: Vec<&str> = vec![];
+    let mut added_lines: Vec<&str> = vec![];
+    for (change, line) in changes.iter().zip(diff_data.lines()) {
+        // Strip prefixes
+        let line = line.get(1..).unwrap_or_default();
+        match change {
+            DiffChange::Delete => {
+                // Start a new group of changes
+                if !added_lines.is_empty() {
+                    push_changed_rows(&mut rows, &mut removed_lines, &mut added_lines);
+                }
+                removed_lines.push(line);
+            }
+            DiffChange::Insert => added_lines.push(line),
+            DiffChange::Equal => {
+                push_changed_rows(&mut rows, &mut removed_lines, &mut added_lines);
+                rows.push((line, ' ', line));
+            }
         }
     }
+    push_changed_rows(&mut rows, &mut removed_lines, &mut added_lines);
+
+    let column_width = rows
+        .iter()
+        .map(|(left, _, _)| left.chars().count())
+        .max()
+        .unwrap_or_default();
+    rows.into_iter()
+        .fold(String::default(), |mut output, (left, separator, right)| {
+            let row = format!("{left:<column_width$} {separator} {right}");
+            let _r = writeln!(&mut output, "{}", row.trim_end());
+            output
+        })
+}
+
+fn push_changed_rows<'a>(
+    rows: &mut Vec<(&'a str, char, &'a str)>,
+    removed_lines: &mut Vec<&'a str>,
+    added_lines: &mut Vec<&'a str>,
+) {
+    for row_index in 0..removed_lines.len().max(added_lines.len()) {
+        let removed_line = removed_lines.get(row_index).copied();
+        let added_line = added_lines.get(row_index).copied();
+        let separator = match (removed_line, added_line) {
+            (Some(_), Some(_)) => '|',
+            (Some(_), None) => '<',
+            _ => '>',
+        };
+        rows.push((
+            removed_line.unwrap_or_default(),
+            separator,
+            added_line.unwrap_or_default(),
+        ));
+    }
+    removed_lines.clear();
+    added_lines.clear();
+}
+
+/// Format the range of a hunk's header, given the number of lines before it
+fn hunk_range(preceding_line_count: usize, length: usize) -> String {
+    match length {
+        // Empty ranges refer to the line preceding them
+        0 => format!("{preceding_line_count},0"),
+        1 => format!("{}", preceding_line_count + 1),
+        _ => format!("{},{length}", preceding_line_count + 1),
+    }
+}
+
+fn generate_three_way_diff_header<'p, T>(
+    pdb_file_base: &PdbFile<'p, T>,
+    pdb_file_a: &PdbFile<'p, T>,
+    pdb_file_b: &PdbFile<'p, T>,
+) -> String
+where
+    T: io::Seek + io::Read + 'p,
+{
+    format!(
+        concat!(
+            "//\n",
+            "// Showing a three-way diff between PDB files:\n",
+            "//\n",
+            "// Base PDB file: {}\n",
+            "// Image architecture: {}\n",
+            "//\n",
+            "// Variant A PDB file: {}\n",
+            "// Image architecture: {}\n",
+            "//\n",
+            "// Variant B PDB file: {}\n",
+            "// Image architecture: {}\n",
+            "//\n",
+            "// Information extracted with resym v{}\n",
+            "//\n"
+        ),
+        pdb_file_base.file_path.display(),
+        pdb_file_base.machine_type,
+        pdb_file_a.file_path.display(),
+        pdb_file_a.machine_type,
+        pdb_file_b.file_path.display(),
+        pdb_file_b.machine_type,
+        PKG_VERSION,
+    )
+}
+
+/// Merge the changes made in `str_a` and `str_b` relatively to `str_base`.
+/// Lines removed from the base are marked as deleted, lines added by the
+/// variants as inserted. Chunks changed differently in both variants are
+/// rendered in a "conflict" style.
+fn generate_three_way_diff(
+    str_base: &str,
+    str_a: &str,
+    str_b: &str,
+    ignore_comments: bool,
+) -> Result<Diff> {
+    // Remove comment lines (e.g., headers) if needed, to avoid spurious changes
+    let (str_base, str_a, str_b) = if ignore_comments {
+        (
+            strip_comment_lines(str_base),
+            strip_comment_lines(str_a),
+            strip_comment_lines(str_b),
+        )
+    } else {
+        (str_base.to_owned(), str_a.to_owned(), str_b.to_owned())
+    };
+
+    let lines_base: Vec<&str> = str_base.split_inclusive('\n').collect();
+    let lines_a: Vec<&str> = str_a.split_inclusive('\n').collect();
+    let lines_b: Vec<&str> = str_b.split_inclusive('\n').collect();
+    let matches_a = matching_lines(&str_base, &str_a, lines_base.len());
+    let matches_b = matching_lines(&str_base, &str_b, lines_base.len());
+
+    let mut diff = Diff::default();
+    let (mut index_base, mut index_a, mut index_b) = (0, 0, 0);
+    while index_base < lines_base.len() || index_a < lines_a.len() || index_b < lines_b.len() {
+        // Lines left untouched in both variants
+        if index_base < lines_base.len()
+            && matches_a[index_base] == Some(index_a)
+            && matches_b[index_base] == Some(index_b)
+        {
+            push_diff_line(
+                &mut diff,
+                (Some(index_base), Some(index_a)),
+                DiffChange::Equal,
+                lines_base[index_base],
+            );
+            index_base += 1;
+            index_a += 1;
+            index_b += 1;
+            continue;
+        }
+
+        // Find the end of the current chunk (i.e., the next line from the
+        // base that's present in both variants)
+        let (end_base, end_a, end_b) = (index_base..lines_base.len())
+            .find_map(|i| Some((i, matches_a[i]?, matches_b[i]?)))
+            .unwrap_or((lines_base.len(), lines_a.len(), lines_b.len()));
+        let chunk_base = (index_base..end_base).zip(&lines_base[index_base..end_base]);
+        let chunk_a = (index_a..end_a).zip(&lines_a[index_a..end_a]);
+        let chunk_b = (index_b..end_b).zip(&lines_b[index_b..end_b]);
+
+        let changed_in_a = lines_a[index_a..end_a] != lines_base[index_base..end_base];
+        let changed_in_b = lines_b[index_b..end_b] != lines_base[index_base..end_base];
+        if !changed_in_a || !changed_in_b || lines_a[index_a..end_a] == lines_b[index_b..end_b] {
+            // Changes can be merged without conflicts
+            for (i, line) in chunk_base {
+                push_diff_line(&mut diff, (Some(i), None), DiffChange::Delete, line);
+            }
+            if changed_in_a {
+                for (i, line) in chunk_a {
+                    push_diff_line(&mut diff, (None, Some(i)), DiffChange::Insert, line);
+                }
+            } else {
+                for (i, line) in chunk_b {
+                    push_diff_line(&mut diff, (None, Some(i)), DiffChange::Insert, line);
+                }
+            }
+        } else {
+            // Conflict
+            push_diff_line(
+                &mut diff,
+                (None, None),
+                DiffChange::Equal,
+                "<<<<<<< Variant A",
+            );
+            for (i, line) in chunk_a {
+                push_diff_line(&mut diff, (None, Some(i)), DiffChange::Insert, line);
+            }
+            push_diff_line(&mut diff, (None, None), DiffChange::Equal, "||||||| Base");
+            for (i, line) in chunk_base {
+                push_diff_line(&mut diff, (Some(i), None), DiffChange::Delete, line);
+            }
+            push_diff_line(&mut diff, (None, None), DiffChange::Equal, "=======");
+            for (i, line) in chunk_b {
+                push_diff_line(&mut diff, (None, Some(i)), DiffChange::Insert, line);
+            }
+            push_diff_line(
+                &mut diff,
+                (None, None),
+                DiffChange::Equal,
+                ">>>>>>> Variant B",
+            );
+        }
+
+        index_base = end_base;
+        index_a = end_a;
+        index_b = end_b;
+    }
+
+    Ok(diff)
+}
+
+/// Compute, for each line of `str_from`, the index of the matching line in
+/// `str_to` (if any)
+fn matching_lines(str_from: &str, str_to: &str, line_count: usize) -> Vec<Option<usize>> {
+    let mut matches = vec![None; line_count];
+    for op in TextDiff::from_lines(str_from, str_to).ops() {
+        if let DiffOp::Equal {
+            old_index,
+            new_index,
+            len,
+        } = *op
+        {
+            for i in 0..len {
+                matches[old_index + i] = Some(new_index + i);
+            }
+        }
+    }
+
+    matches
+}
+
+fn push_diff_line(diff: &mut Diff, indices: DiffIndices, change: DiffChange, line: &str) {
+    let prefix = match change {
+        ChangeTag::Insert => "+",
+        ChangeTag::Delete => "-",
+        ChangeTag::Equal => " ",
+    };
+    diff.data.push_str(prefix);
+    diff.data.push_str(line);
+    if !line.ends_with('\n') {
+        diff.data.push('\n');
+    }
+    diff.metadata.push((indices, change));
+    diff.inline_changes.push(DiffInlineChanges::new());
+    diff.statistics.update(change, line);
+}
+
+/// Remove `//` comment lines (e.g., headers) from the given text.
+/// Note: `/* */` comments are kept, as reconstructed types use them to
+/// describe their layout (e.g., `/* 0x0000: fields for Base */`).
+fn strip_comment_lines(text: &str) -> String {
+    text.split_inclusive('\n')
+        .filter(|line| !is_comment_line(line))
+        .collect()
+}
 
-    Ok(Diff {
-        metadata: diff_metadata,
-        data: diff_data,
-    })
+fn is_comment_line(line: &str) -> bool {
+    line.trim_start().starts_with("//")
 }
@@ -1,7 +1,29 @@
+use std::fmt;
+
 use thiserror::Error;
 
+use crate::backend::PDBSlot;
+
 pub type Result<T> = std::result::Result<T, ResymCoreError>;
 
+/// Backend command during which an error occurred, and the PDB slot it
+/// operated on (if any)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// Name of the `BackendCommand` (e.g., `ReconstructTypeByIndex`)
+    pub command: &'static str,
+    pub pdb_slot: Option<PDBSlot>,
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.pdb_slot {
+            Some(pdb_slot) => write!(f, "{} (PDB slot {pdb_slot})", self.command),
+            None => write!(f, "{}", self.command),
+        }
+    }
+}
+
 /// Error type used across `resym_core`
 #[derive(Error, Debug)]
 pub enum ResymCoreError {
@@ -60,12 +82,56 @@ pub enum ResymCoreError {
     #[error("module info not found: {0}")]
     ModuleInfoNotFoundError(String),
 
+    /// Error reported by `clang-format`.
+    #[error("clang-format error: {0}")]
+    ClangFormatError(String),
+
+    /// Error reported by a plugin.
+    #[cfg(feature = "plugins")]
+    #[error("plugin error: {0}")]
+    PluginError(String),
+
+    /// Error returned when the headers of a PE image cannot be parsed.
+    #[error("invalid PE file: {0}")]
+    InvalidPeFileError(String),
+
     /// Error returned when parsing a `PrimitiveReconstructionFlavor` from a string fails.
     #[error("invalid primitive type flavor: {0}")]
     ParsePrimitiveFlavorError(String),
 
-    /// Error returned when `resym_core` cannot process the request because of
-    /// unimplemented features.
-    #[error("feature not implemented: {0}")]
-    NotImplementedError(String),
+    /// Error returned when parsing a `SymbolKind` from a string fails.
+    #[error("invalid symbol kind: {0}")]
+    ParseSymbolKindError(String),
+
+    /// Error returned when an operation has been cancelled by the user.
+    #[error("operation cancelled")]
+    OperationCancelledError,
+
+    /// Error returned when a type record cannot be reconstructed because its
+    /// kind isn't supported yet.
+    #[error("unsupported type record: {0}")]
+    UnsupportedTypeRecordError(String),
+
+    /// Error sent by the backend to frontends, along with the command that
+    /// failed. Displayed as the underlying error.
+    #[error("{1}")]
+    BackendCommandError(ErrorContext, Box<ResymCoreError>),
+}
+
+impl ResymCoreError {
+    /// Command which failed, for errors sent by the backend
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            ResymCoreError::BackendCommandError(context, _) => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Underlying error, without the context attached by the backend
+    pub fn without_context(&self) -> &ResymCoreError {
+        match self {
+            ResymCoreError::BackendCommandError(_, err) => err.without_context(),
+            err => err,
+        }
+    }
 }
@@ -0,0 +1,139 @@
+//! Blocking API for tools which only need to open PDB files and query them,
+//! without going through the `Backend`'s worker thread and command channels.
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+use crate::{
+    backend::{reconstruct_type_by_name_command, update_type_filter_command},
+    diffing::{diff_type_by_name, Diff, DiffOptions},
+    error::{Result, ResymCoreError},
+    pdb_file::{PDBDataSource, PdbFile, TypeList},
+    pdb_types::PrimitiveReconstructionFlavor,
+};
+
+/// Options applied when reconstructing or diffing types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconstructionOptions {
+    pub primitives_flavor: PrimitiveReconstructionFlavor,
+    pub print_header: bool,
+    /// Also reconstruct the types referenced by the requested type
+    pub reconstruct_dependencies: bool,
+    pub print_access_specifiers: bool,
+    pub ignore_std_types: bool,
+}
+
+impl Default for ReconstructionOptions {
+    fn default() -> Self {
+        Self {
+            primitives_flavor: PrimitiveReconstructionFlavor::Portable,
+            print_header: false,
+            reconstruct_dependencies: true,
+            print_access_specifiers: true,
+            ignore_std_types: false,
+        }
+    }
+}
+
+/// A loaded PDB file. Every method blocks until the result is available.
+///
+/// ```no_run
+/// use resym_core::{ReconstructionOptions, Resym};
+///
+/// let resym = Resym::open("test.pdb")?;
+/// for (type_name, _) in resym.list_types("Struct", false, false, false)? {
+///     println!("{}", resym.reconstruct(&type_name, &ReconstructionOptions::default())?);
+/// }
+/// # Ok::<(), resym_core::ResymCoreError>(())
+/// ```
+pub struct Resym {
+    pdb_file: PdbFile<'static, PDBDataSource>,
+}
+
+impl Resym {
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn open(pdb_file_path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self {
+            pdb_file: PdbFile::load_from_file(pdb_file_path.as_ref())?,
+        })
+    }
+
+    /// Load a PDB file from memory, `pdb_file_name` is only used in headers
+    pub fn from_bytes(pdb_file_name: String, pdb_file_data: Vec<u8>) -> Result<Self> {
+        Ok(Self {
+            pdb_file: PdbFile::load_from_bytes_as_vec(pdb_file_name, pdb_file_data)?,
+        })
+    }
+
+    /// Underlying `PdbFile`, for queries not covered by this API
+    pub fn pdb_file(&self) -> &PdbFile<'static, PDBDataSource> {
+        &self.pdb_file
+    }
+
+    /// Mutable access to the underlying `PdbFile`, to change how types are
+    /// reconstructed (e.g., its `settings`)
+    pub fn pdb_file_mut(&mut self) -> &mut PdbFile<'static, PDBDataSource> {
+        &mut self.pdb_file
+    }
+
+    /// List the types (and typedefs) whose name matches `search_filter`,
+    /// ordered by type index. An empty filter matches every type.
+    pub fn list_types(
+        &self,
+        search_filter: &str,
+        case_insensitive_search: bool,
+        use_regex: bool,
+        ignore_std_types: bool,
+    ) -> Result<TypeList> {
+        if use_regex {
+            regex::Regex::new(search_filter)
+                .map_err(|err| ResymCoreError::InvalidParameterError(err.to_string()))?;
+        }
+
+        Ok(update_type_filter_command(
+            &self.pdb_file,
+            search_filter,
+            case_insensitive_search,
+            use_regex,
+            ignore_std_types,
+            true,
+        ))
+    }
+
+    /// Reconstruct the type named `type_name`, as C++ code
+    pub fn reconstruct(&self, type_name: &str, options: &ReconstructionOptions) -> Result<String> {
+        let (reconstructed_type, _) = reconstruct_type_by_name_command(
+            &self.pdb_file,
+            type_name,
+            options.primitives_flavor,
+            options.print_header,
+            options.reconstruct_dependencies,
+            options.print_access_specifiers,
+            options.ignore_std_types,
+        )?;
+
+        Ok(reconstructed_type)
+    }
+
+    /// Diff the type named `type_name` between this PDB file (the old
+    /// version) and `other` (the new version)
+    pub fn diff(
+        &self,
+        other: &Resym,
+        type_name: &str,
+        options: &ReconstructionOptions,
+    ) -> Result<Diff> {
+        diff_type_by_name(
+            &self.pdb_file,
+            &other.pdb_file,
+            type_name,
+            options.primitives_flavor,
+            &DiffOptions {
+                print_header: options.print_header,
+                reconstruct_dependencies: options.reconstruct_dependencies,
+                print_access_specifiers: options.print_access_specifiers,
+                ignore_std_types: options.ignore_std_types,
+                ..Default::default()
+            },
+        )
+    }
+}
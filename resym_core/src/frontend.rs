@@ -1,36 +1,160 @@
 use crate::{
-    backend::PDBSlot,
-    diffing::Diff,
-    error::Result,
-    pdb_file::{ModuleList, SymbolList, TypeList},
+    backend::{OperationTiming, PDBSlot, TaggedTypeList},
+    diffing::{ChangedType, Diff, TypeRevision},
+    error::{ErrorContext, Result, ResymCoreError},
+    layout_check::TypeLayoutCheck,
+    pdb_file::{
+        ModuleList, PdbMetadata, SourceFileList, StreamList, SymbolAtAddress, SymbolList,
+        SymbolLocation, SymbolicConstant, TypeDependencyGraph, TypeInfoMap, TypeList,
+        TypeProperties, TypeSourceLocation,
+    },
 };
 
 /// Tuple containing the reconstructed type as a `String`
 /// and the list of directly referenced types as a `TypeList`
 pub type ReconstructedType = (String, TypeList);
 
+/// Lists that can be filtered with search queries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchTarget {
+    Types,
+    Symbols,
+    Modules,
+    SourceFiles,
+}
+
 pub enum FrontendCommand {
     LoadPDBResult(Result<PDBSlot>),
+    /// Sent in response to `LoadPEFromPath`
+    #[cfg(not(target_arch = "wasm32"))]
+    LoadPEResult(Result<PDBSlot>),
+    PDBMetadataResult(PDBSlot, Result<PdbMetadata>),
+    /// Sent when a watched PDB file changes on disk, if enabled with
+    /// `SetWatchPDBFiles`
+    #[cfg(all(not(target_arch = "wasm32"), feature = "watch"))]
+    PDBFileChanged(PDBSlot, std::path::PathBuf),
+
+    // Streams
+    ListStreamsResult(Result<StreamList>),
+    DumpStreamResult(Result<String>),
     /// Send result from `LoadURL` backend command.
     /// Contains last path segment (i.e., file name) as a `String` and data as `Vec<u8>`.
     LoadURLResult(Result<(PDBSlot, String, Vec<u8>)>),
+    /// Sent instead of the list of results when a search filter is invalid
+    /// (e.g., regular expression that doesn't compile)
+    SearchFilterError(SearchTarget, String),
 
     // Types
     ListTypesResult(TypeList),
+    /// Sent in response to `ListTypesTagged`
+    ListTypesTaggedResult(TaggedTypeList),
+    ListTypeInfosResult(TypeInfoMap),
+    FuzzyFindTypesResult(TypeList),
     ReconstructTypeResult(Result<ReconstructedType>),
+    /// Several types reconstructed at once, with shared dependencies
+    ReconstructTypesResult(Result<String>),
+    /// All enumerations of a PDB, sorted by name
+    ReconstructAllEnumsResult(Result<String>),
 
     // Symbols
     ListSymbolsResult(SymbolList),
     ReconstructSymbolResult(Result<String>),
+    ListConstantsResult(Result<Vec<SymbolicConstant>>),
 
     // Modules
     ListModulesResult(Result<ModuleList>),
     ReconstructModuleResult(Result<String>),
 
+    // Source files
+    ListSourceFilesResult(Result<SourceFileList>),
+    ReconstructSourceFileResult(Result<String>),
+
     // Diff
     DiffResult(Result<Diff>),
+    DiffAllTypesResult(Result<Vec<ChangedType>>),
+    CompareTypeResult(Result<Vec<TypeRevision>>),
+    // Layout checks
+    CheckHeaderLayoutsResult(Result<Vec<TypeLayoutCheck>>),
     // Xrefs
     ListTypeCrossReferencesResult(Result<TypeList>),
+    ListTypeReverseDependenciesResult(Result<TypeList>),
+    TypeDependencyGraphResult(Result<TypeDependencyGraph>),
+    /// Dependency graph, in the Graphviz DOT format
+    ExportTypeDependencyGraphResult(Result<String>),
+    /// Layout assertions, as a C++ translation unit
+    ExportLayoutAssertionsResult(Result<String>),
+    // Addresses
+    LookupSymbolByRVAResult(Result<SymbolAtAddress>),
+    LookupSymbolLocationResult(Result<SymbolLocation>),
+    LookupTypePropertiesResult(Result<TypeProperties>),
+    LookupTypeSourceLocationsResult(Result<Vec<TypeSourceLocation>>),
+    /// Sent after each timed operation, if enabled with
+    /// `SetReportOperationTimings`
+    OperationTimingReport(OperationTiming),
+}
+
+/// Error carried by the given `FrontendCommand`, if any. Works with both
+/// shared and mutable references.
+macro_rules! command_error {
+    ($command:expr) => {
+        match $command {
+            FrontendCommand::LoadPDBResult(Err(err))
+            | FrontendCommand::ListStreamsResult(Err(err))
+            | FrontendCommand::DumpStreamResult(Err(err))
+            | FrontendCommand::LoadURLResult(Err(err))
+            | FrontendCommand::ReconstructTypeResult(Err(err))
+            | FrontendCommand::ReconstructTypesResult(Err(err))
+            | FrontendCommand::ReconstructAllEnumsResult(Err(err))
+            | FrontendCommand::ReconstructSymbolResult(Err(err))
+            | FrontendCommand::ListConstantsResult(Err(err))
+            | FrontendCommand::ListModulesResult(Err(err))
+            | FrontendCommand::ReconstructModuleResult(Err(err))
+            | FrontendCommand::ListSourceFilesResult(Err(err))
+            | FrontendCommand::ReconstructSourceFileResult(Err(err))
+            | FrontendCommand::DiffResult(Err(err))
+            | FrontendCommand::DiffAllTypesResult(Err(err))
+            | FrontendCommand::CompareTypeResult(Err(err))
+            | FrontendCommand::CheckHeaderLayoutsResult(Err(err))
+            | FrontendCommand::ListTypeCrossReferencesResult(Err(err))
+            | FrontendCommand::ListTypeReverseDependenciesResult(Err(err))
+            | FrontendCommand::TypeDependencyGraphResult(Err(err))
+            | FrontendCommand::ExportTypeDependencyGraphResult(Err(err))
+            | FrontendCommand::ExportLayoutAssertionsResult(Err(err))
+            | FrontendCommand::LookupSymbolByRVAResult(Err(err))
+            | FrontendCommand::LookupSymbolLocationResult(Err(err))
+            | FrontendCommand::LookupTypePropertiesResult(Err(err))
+            | FrontendCommand::LookupTypeSourceLocationsResult(Err(err)) => Some(err),
+            #[cfg(not(target_arch = "wasm32"))]
+            FrontendCommand::LoadPEResult(Err(err)) => Some(err),
+            FrontendCommand::PDBMetadataResult(_, Err(err)) => Some(err),
+            _ => None,
+        }
+    };
+}
+
+impl FrontendCommand {
+    /// Error carried by the command, if it's the result of an operation that
+    /// failed
+    pub fn error(&self) -> Option<&ResymCoreError> {
+        command_error!(self)
+    }
+
+    /// Attach the given context to the error carried by the command, if any.
+    /// Cancellations aren't considered as errors and are left untouched.
+    pub fn with_error_context(mut self, context: ErrorContext) -> Self {
+        if let Some(err) = command_error!(&mut self) {
+            if !matches!(
+                err,
+                ResymCoreError::OperationCancelledError | ResymCoreError::BackendCommandError(..)
+            ) {
+                let err_without_context =
+                    std::mem::replace(err, ResymCoreError::OperationCancelledError);
+                *err = ResymCoreError::BackendCommandError(context, Box::new(err_without_context));
+            }
+        }
+
+        self
+    }
 }
 
 pub trait FrontendController {
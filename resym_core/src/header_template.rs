@@ -0,0 +1,99 @@
+//! User-editable template for the header comment printed at the top of
+//! reconstructed content.
+#[cfg(target_arch = "wasm32")]
+use instant::{SystemTime, UNIX_EPOCH};
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_HEADER_TEMPLATE: &str = "//
+// Information extracted with resym v{resym_version}
+//
+// PDB file: {pdb_path}
+// Image architecture: {architecture}
+//
+";
+
+/// Placeholders available in header templates, with their description
+pub const HEADER_TEMPLATE_PLACEHOLDERS: &[(&str, &str)] = &[
+    ("pdb_path", "Path of the PDB file"),
+    ("pdb_name", "File name of the PDB file"),
+    ("guid", "GUID of the PDB file"),
+    ("architecture", "Architecture of the image"),
+    ("date", "Current date (YYYY-MM-DD)"),
+    ("resym_version", "Version of resym"),
+    ("type_name", "Name of the reconstructed type, if any"),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderTemplate {
+    template: String,
+}
+
+impl Default for HeaderTemplate {
+    fn default() -> Self {
+        Self::new(DEFAULT_HEADER_TEMPLATE)
+    }
+}
+
+impl HeaderTemplate {
+    pub fn new(template: &str) -> Self {
+        Self {
+            template: template.to_string(),
+        }
+    }
+
+    /// Replace `{placeholder}` occurrences with the given values. Unknown
+    /// placeholders are left untouched.
+    pub fn render(&self, values: &[(&str, &str)]) -> String {
+        let mut output = String::with_capacity(self.template.len());
+        let mut remaining = self.template.as_str();
+        while let Some(start) = remaining.find('{') {
+            output.push_str(&remaining[..start]);
+            remaining = &remaining[start..];
+            let value = remaining.find('}').and_then(|end| {
+                let placeholder = &remaining[1..end];
+                values
+                    .iter()
+                    .find(|(name, _)| *name == placeholder)
+                    .map(|(_, value)| (end, *value))
+            });
+            if let Some((end, value)) = value {
+                output.push_str(value);
+                remaining = &remaining[end + 1..];
+            } else {
+                output.push('{');
+                remaining = &remaining[1..];
+            }
+        }
+        output.push_str(remaining);
+
+        // Make sure the header doesn't end in the middle of a line
+        if !output.is_empty() && !output.ends_with('\n') {
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+/// Return the current (UTC) date, in the YYYY-MM-DD format
+pub fn current_date() -> String {
+    let days_since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() / 86_400)
+        .unwrap_or_default() as i64;
+
+    // Convert days to a civil date (see http://howardhinnant.github.io/date_algorithms.html)
+    let z = days_since_epoch + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
@@ -0,0 +1,499 @@
+//! Validation of hand-written C/C++ headers against the layouts of the types
+//! found in PDB files.
+//!
+//! Headers are parsed with a simple line-based grammar: `struct`, `class` and
+//! `union` definitions (optionally enclosed in `namespace` blocks) whose
+//! opening line may be annotated with a `Size=0x..` comment, and fields
+//! annotated with their offset, either as a leading `/* 0x.. */` comment (like
+//! in reconstructed types) or as a trailing `// 0x..` or `// +0x..` comment.
+//! Decimal sizes and offsets (e.g., `Size=16` or `/* 8 */`) are accepted too.
+//! Fields without an offset annotation are ignored.
+//!
+//! The same layouts can also be exported as `static_assert`s, to catch layout
+//! changes at compile time.
+use std::{
+    fmt::{self, Write},
+    io,
+};
+
+use crate::{error::Result, pdb_file::PdbFile, pdb_types::is_unnamed_type, PKG_VERSION};
+
+/// Layout of a type, as declared in a header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeLayout {
+    pub name: String,
+    pub size: Option<u64>,
+    pub fields: Vec<FieldLayout>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldLayout {
+    pub name: String,
+    pub offset: u64,
+    pub is_bitfield: bool,
+}
+
+/// Difference between a type's layout in a header and in the PDB
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutMismatch {
+    /// The type isn't defined in the PDB
+    MissingType,
+    Size {
+        expected: u64,
+        actual: u64,
+    },
+    /// The field isn't part of the type in the PDB
+    MissingField(String),
+    FieldOffset {
+        field_name: String,
+        expected: u64,
+        actual: u64,
+    },
+}
+
+impl fmt::Display for LayoutMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutMismatch::MissingType => write!(f, "type not found in the PDB"),
+            LayoutMismatch::Size { expected, actual } => {
+                write!(f, "size is 0x{actual:x}, expected 0x{expected:x}")
+            }
+            LayoutMismatch::MissingField(field_name) => {
+                write!(f, "field `{field_name}` not found in the PDB")
+            }
+            LayoutMismatch::FieldOffset {
+                field_name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "field `{field_name}` is at offset 0x{actual:x}, expected 0x{expected:x}"
+            ),
+        }
+    }
+}
+
+/// Result of the validation of a type declared in a header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeLayoutCheck {
+    pub type_name: String,
+    /// Number of fields whose offset has been compared
+    pub checked_field_count: usize,
+    pub mismatches: Vec<LayoutMismatch>,
+}
+
+/// Compare the layouts of the types declared in `header` against the ones
+/// found in the given PDB file (taken from type records, not from
+/// reconstructed code). Types are returned in declaration order.
+pub fn check_header_layouts<'p, T>(
+    pdb_file: &PdbFile<'p, T>,
+    header: &str,
+) -> Result<Vec<TypeLayoutCheck>>
+where
+    T: io::Seek + io::Read + std::fmt::Debug + 'p,
+{
+    let expected_layouts = parse_type_layouts(header);
+    let type_names: Vec<&str> = expected_layouts
+        .iter()
+        .map(|layout| layout.name.as_str())
+        .collect();
+    let actual_layouts = pdb_file.type_layouts(&type_names)?;
+
+    Ok(expected_layouts
+        .iter()
+        .zip(&actual_layouts)
+        .map(|(expected_layout, actual_layout)| {
+            compare_layouts(expected_layout, actual_layout.as_ref())
+        })
+        .collect())
+}
+
+/// Generate a C++ translation unit made of `static_assert`s which check the
+/// sizes and field offsets of the given types (or of all the types defined in
+/// the PDB, if `None`). Layouts are taken from type records, so assertions
+/// don't depend on how reconstructed types are formatted.
+pub fn generate_layout_assertions<'p, T>(
+    pdb_file: &PdbFile<'p, T>,
+    type_names: Option<&[String]>,
+    ignore_std_types: bool,
+) -> Result<String>
+where
+    T: io::Seek + io::Read + std::fmt::Debug + 'p,
+{
+    let type_list;
+    let type_names: Vec<&str> = if let Some(type_names) = type_names {
+        type_names.iter().map(String::as_str).collect()
+    } else {
+        type_list = pdb_file.type_list()?;
+        type_list
+            .iter()
+            .map(|(type_name, _)| type_name.as_str())
+            .filter(|type_name| !ignore_std_types || !type_name.starts_with("std::"))
+            .collect()
+    };
+
+    let mut output = format!(
+        "//\n// Layout assertions generated with resym v{PKG_VERSION}\n//\n// The types below must be defined before this point.\n//\n\n#include <cstddef>\n"
+    );
+    // Anonymous types cannot be referred to
+    let type_names: Vec<&str> = type_names
+        .into_iter()
+        .filter(|type_name| !is_unnamed_type(type_name) && !type_name.starts_with("_unnamed_"))
+        .collect();
+    let layouts = pdb_file.type_layouts(&type_names)?;
+    for (type_name, layout) in type_names.into_iter().zip(layouts) {
+        let Some(layout) = layout else {
+            // Enums don't have a layout to check
+            continue;
+        };
+
+        writeln!(output)?;
+        writeln!(output, "// {type_name}")?;
+        if let Some(size) = layout.size {
+            writeln!(
+                output,
+                "static_assert(sizeof({type_name}) == 0x{size:x}, \"Unexpected size for {type_name}\");"
+            )?;
+        }
+        // `offsetof` is a macro, which cannot take type names containing commas
+        if type_name.contains(',') {
+            continue;
+        }
+        // `offsetof` cannot be applied to bitfields either
+        for field in layout.fields.iter().filter(|field| !field.is_bitfield) {
+            writeln!(
+                output,
+                "static_assert(offsetof({type_name}, {0}) == 0x{1:x}, \"Unexpected offset for {type_name}::{0}\");",
+                field.name, field.offset
+            )?;
+        }
+    }
+
+    Ok(output)
+}
+
+fn compare_layouts(expected: &TypeLayout, actual: Option<&TypeLayout>) -> TypeLayoutCheck {
+    let Some(actual) = actual else {
+        return TypeLayoutCheck {
+            type_name: expected.name.clone(),
+            checked_field_count: 0,
+            mismatches: vec![LayoutMismatch::MissingType],
+        };
+    };
+
+    let mut mismatches = vec![];
+    if let (Some(expected_size), Some(actual_size)) = (expected.size, actual.size) {
+        if expected_size != actual_size {
+            mismatches.push(LayoutMismatch::Size {
+                expected: expected_size,
+                actual: actual_size,
+            });
+        }
+    }
+    for expected_field in &expected.fields {
+        match actual
+            .fields
+            .iter()
+            .find(|field| field.name == expected_field.name)
+        {
+            None => mismatches.push(LayoutMismatch::MissingField(expected_field.name.clone())),
+            Some(actual_field) if actual_field.offset != expected_field.offset => {
+                mismatches.push(LayoutMismatch::FieldOffset {
+                    field_name: expected_field.name.clone(),
+                    expected: expected_field.offset,
+                    actual: actual_field.offset,
+                });
+            }
+            Some(_) => {}
+        }
+    }
+
+    TypeLayoutCheck {
+        type_name: expected.name.clone(),
+        checked_field_count: expected.fields.len(),
+        mismatches,
+    }
+}
+
+/// Blocks the parser can be in
+enum Scope {
+    Namespace(String),
+    /// Top-level type definition, with its index in the parsed layouts
+    Type(usize),
+    /// Anonymous struct/union, whose fields belong to the enclosing type
+    AnonymousAggregate(usize),
+    /// Blocks which don't affect the parsing (e.g., `extern "C"`)
+    Transparent,
+    /// Blocks whose content is ignored (e.g., nested types or enums)
+    Ignored,
+}
+
+impl Scope {
+    fn layout_index(&self) -> Option<usize> {
+        match self {
+            Scope::Type(index) | Scope::AnonymousAggregate(index) => Some(*index),
+            _ => None,
+        }
+    }
+}
+
+/// Extract the layouts of the types defined in the given header
+pub fn parse_type_layouts(header: &str) -> Vec<TypeLayout> {
+    const TYPE_KEYWORDS: [&str; 3] = ["struct", "class", "union"];
+
+    let mut layouts: Vec<TypeLayout> = vec![];
+    let mut scopes: Vec<Scope> = vec![];
+    let mut in_block_comment = false;
+    // Declaration preceding a lone `{`, if any
+    let mut previous_line: Option<(String, Vec<String>)> = None;
+    for line in header.lines() {
+        let (code, comments) = split_comments(line, &mut in_block_comment);
+        let code = code.trim();
+        if code.is_empty() {
+            continue;
+        }
+
+        if let Some(declaration) = code.strip_suffix('{') {
+            let (declaration, comments) = match (declaration.trim(), previous_line.take()) {
+                ("", Some((previous_code, previous_comments))) => {
+                    (previous_code, previous_comments)
+                }
+                (declaration, _) => (declaration.to_string(), comments),
+            };
+            let declaration = declaration.strip_prefix("typedef ").unwrap_or(&declaration);
+            let current_layout_index = scopes.last().and_then(Scope::layout_index);
+
+            let scope = if let Some(namespace) = declaration.strip_prefix("namespace") {
+                Scope::Namespace(namespace.trim().to_string())
+            } else if let Some(type_name) = TYPE_KEYWORDS.iter().find_map(|keyword| {
+                declaration
+                    .strip_prefix(keyword)
+                    .filter(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+            }) {
+                // Ignore base classes, if any
+                let type_name = type_name
+                    .split_once(" : ")
+                    .map_or(type_name, |(type_name, _)| type_name)
+                    .trim();
+                match current_layout_index {
+                    Some(layout_index) if type_name.is_empty() => {
+                        Scope::AnonymousAggregate(layout_index)
+                    }
+                    Some(_) => Scope::Ignored,
+                    None if matches!(scopes.last(), Some(Scope::Ignored)) => Scope::Ignored,
+                    None => {
+                        let type_name = if type_name.is_empty() {
+                            // Named when the definition ends (e.g., `typedef struct { ... } Name;`)
+                            String::new()
+                        } else {
+                            qualified_name(&scopes, type_name)
+                        };
+                        layouts.push(TypeLayout {
+                            name: type_name,
+                            size: comments.iter().find_map(|comment| parse_size(comment)),
+                            fields: vec![],
+                        });
+                        Scope::Type(layouts.len() - 1)
+                    }
+                }
+            } else if current_layout_index.is_some()
+                || matches!(scopes.last(), Some(Scope::Ignored))
+            {
+                Scope::Ignored
+            } else {
+                Scope::Transparent
+            };
+            scopes.push(scope);
+        } else if let Some(rest) = code.strip_prefix('}') {
+            if let Some(Scope::Type(layout_index)) = scopes.pop() {
+                let layout = &mut layouts[layout_index];
+                if layout.name.is_empty() {
+                    let type_name = rest.trim().trim_end_matches(';').trim();
+                    layout.name = qualified_name(&scopes, type_name);
+                }
+            }
+        } else if let Some(declaration) = code.strip_suffix(';') {
+            if let (Some(layout_index), Some(offset)) = (
+                scopes.last().and_then(Scope::layout_index),
+                comments.iter().find_map(|comment| parse_offset(comment)),
+            ) {
+                if let Some((field_name, is_bitfield)) = parse_field_declaration(declaration) {
+                    layouts[layout_index].fields.push(FieldLayout {
+                        name: field_name.to_string(),
+                        offset,
+                        is_bitfield,
+                    });
+                }
+            }
+        } else {
+            previous_line = Some((code.to_string(), comments));
+            continue;
+        }
+        previous_line = None;
+    }
+
+    // Anonymous types which haven't been named cannot be looked up
+    layouts.retain(|layout| !layout.name.is_empty() && !layout.name.ends_with("::"));
+
+    layouts
+}
+
+fn qualified_name(scopes: &[Scope], type_name: &str) -> String {
+    let mut name = String::new();
+    for scope in scopes {
+        if let Scope::Namespace(namespace) = scope {
+            if namespace.is_empty() {
+                // Anonymous namespace
+                continue;
+            }
+            name.push_str(namespace);
+            name.push_str("::");
+        }
+    }
+    name.push_str(type_name);
+
+    name
+}
+
+/// Split a line into its code and the content of its comments, keeping track
+/// of multi-line comments
+fn split_comments(line: &str, in_block_comment: &mut bool) -> (String, Vec<String>) {
+    let mut code = String::new();
+    let mut comments = vec![];
+    let mut remaining = line;
+    loop {
+        if *in_block_comment {
+            if let Some((comment, rest)) = remaining.split_once("*/") {
+                comments.push(comment.to_string());
+                remaining = rest;
+                *in_block_comment = false;
+            } else {
+                comments.push(remaining.to_string());
+                break;
+            }
+        } else {
+            match (remaining.find("//"), remaining.find("/*")) {
+                (Some(line_comment), block_comment)
+                    if block_comment.map_or(true, |block_comment| line_comment < block_comment) =>
+                {
+                    code.push_str(&remaining[..line_comment]);
+                    comments.push(remaining[line_comment + 2..].to_string());
+                    break;
+                }
+                (_, Some(block_comment)) => {
+                    code.push_str(&remaining[..block_comment]);
+                    remaining = &remaining[block_comment + 2..];
+                    *in_block_comment = true;
+                }
+                (None, None) => {
+                    code.push_str(remaining);
+                    break;
+                }
+            }
+        }
+    }
+
+    (code, comments)
+}
+
+/// Parse offset annotations (e.g., `0x10`, `+0x10` or `16`)
+fn parse_offset(annotation: &str) -> Option<u64> {
+    let annotation = annotation.trim();
+    let annotation = annotation.strip_prefix('+').unwrap_or(annotation);
+    let Some(hex_digits) = annotation
+        .strip_prefix("0x")
+        .or_else(|| annotation.strip_prefix("0X"))
+    else {
+        // Decimal offsets must be standalone numbers, to avoid mistaking
+        // comments such as `// 3D position` for offsets
+        let decimal_digits = annotation
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .next()
+            .unwrap_or_default();
+        return decimal_digits.parse().ok();
+    };
+    let hex_digits = hex_digits
+        .split(|c: char| !c.is_ascii_hexdigit())
+        .next()
+        .unwrap_or_default();
+
+    u64::from_str_radix(hex_digits, 16).ok()
+}
+
+/// Parse size annotations (e.g., `Size=0x10`)
+fn parse_size(annotation: &str) -> Option<u64> {
+    let (_, size) = annotation.split_once("Size=")?;
+
+    parse_offset(size)
+}
+
+/// Extract the name of the field declared in `declaration`, and whether it's
+/// a bitfield
+fn parse_field_declaration(declaration: &str) -> Option<(&str, bool)> {
+    const ACCESS_SPECIFIERS: [&str; 3] = ["public: ", "protected: ", "private: "];
+
+    let declaration = declaration.trim();
+    let declaration = ACCESS_SPECIFIERS
+        .iter()
+        .find_map(|specifier| declaration.strip_prefix(specifier))
+        .unwrap_or(declaration);
+    if ["static ", "using ", "typedef ", "friend "]
+        .iter()
+        .any(|keyword| declaration.starts_with(keyword))
+    {
+        return None;
+    }
+
+    // Function pointers (e.g., `void (*callback)(int)`)
+    if let Some((_, rest)) = declaration.split_once("(*") {
+        let name = rest.split(')').next()?.trim();
+        return is_identifier(name).then_some((name, false));
+    }
+    // Methods
+    if declaration.contains('(') {
+        return None;
+    }
+
+    // Ignore default member initializers, bitfield widths and array sizes
+    let declaration = declaration.split('=').next().unwrap_or_default();
+    let (declaration, is_bitfield) = match strip_bitfield_width(declaration) {
+        stripped_declaration if stripped_declaration.len() != declaration.len() => {
+            (stripped_declaration, true)
+        }
+        _ => (declaration, false),
+    };
+    let declaration = declaration.split('[').next().unwrap_or_default().trim_end();
+
+    let name_start = declaration
+        .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .map_or(0, |index| index + 1);
+    let name = &declaration[name_start..];
+    // Unnamed fields (e.g., `uint8_t : 0`) don't have anything before their type
+    if name_start == 0 || !is_identifier(name) {
+        None
+    } else {
+        Some((name, is_bitfield))
+    }
+}
+
+fn strip_bitfield_width(declaration: &str) -> &str {
+    let bytes = declaration.as_bytes();
+    for (index, &byte) in bytes.iter().enumerate() {
+        let is_scope_operator = (index > 0 && bytes[index - 1] == b':')
+            || bytes
+                .get(index + 1)
+                .is_some_and(|&next_byte| next_byte == b':');
+        if byte == b':' && !is_scope_operator {
+            return &declaration[..index];
+        }
+    }
+
+    declaration
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
@@ -1,12 +1,23 @@
 pub mod backend;
+pub mod cancellation;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod clang_format;
 pub mod diffing;
 mod error;
+mod facade;
 pub mod frontend;
+pub mod header_template;
+pub mod layout_check;
 pub mod pdb_file;
 pub mod pdb_types;
+pub mod pe_file;
+#[cfg(feature = "plugins")]
+pub mod plugins;
 pub mod rayon_utils;
+#[cfg(feature = "highlighting")]
 pub mod syntax_highlighting;
 
 pub use error::*;
+pub use facade::*;
 
 const PKG_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -6,33 +6,313 @@ use pdb::FallibleIterator;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 use std::{
-    collections::{BTreeMap, BinaryHeap, HashMap, HashSet, VecDeque},
+    borrow::Cow,
+    collections::{
+        hash_map::DefaultHasher, BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, VecDeque,
+    },
     fmt::Write,
+    hash::{Hash, Hasher},
     io::{self, Read, Seek},
-    path::PathBuf,
-    sync::{Arc, RwLock},
+    path::{Path, PathBuf},
+    sync::{Arc, OnceLock, RwLock},
 };
 #[cfg(not(target_arch = "wasm32"))]
-use std::{fs::File, path::Path, time::Instant};
+use std::{fs::File, time::Instant};
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::clang_format::ClangFormatConfiguration;
 use crate::{
+    cancellation::CancellationToken,
     error::{Result, ResymCoreError},
     frontend::ReconstructedType,
+    header_template::HeaderTemplate,
+    layout_check::TypeLayout,
     par_iter_if_available,
     pdb_types::{
-        self, is_unnamed_type, type_name, DataFormatConfiguration, PrimitiveReconstructionFlavor,
+        self, format_rva, is_compiler_generated_name, is_unnamed_type, type_name,
+        CodeFormatConfiguration, DataFormatConfiguration, EnumValueOrder, MemberFilter,
+        NumberFormat, OpaqueTypeRules, PrimitiveReconstructionFlavor, PrimitiveTypeMapping,
+        RenameMap, TypeNotes,
     },
+    pe_file::PeFile,
 };
 
 pub type TypeIndex = u32;
 pub type TypeList = Vec<(String, TypeIndex)>;
+/// Kind and size of types, by type index
+pub type TypeInfoMap = HashMap<TypeIndex, TypeInfo>;
 /// `SymbolIndex` have two parts: a module index and a symbol index
 pub type SymbolIndex = (ModuleIndex, u32);
 pub type SymbolList = Vec<(String, SymbolIndex)>;
 pub type ModuleIndex = usize;
 pub type ModuleList = Vec<(String, ModuleIndex)>;
+/// Source file paths, paired with the index of the first module they
+/// contributed to
+pub type SourceFileList = Vec<(String, ModuleIndex)>;
+pub type StreamIndex = u16;
+pub type StreamList = Vec<(String, StreamIndex)>;
 
 const GLOBAL_MODULE_INDEX: usize = usize::MAX;
+/// Type indices starting from this value identify typedefs, which are declared
+/// by `S_UDT` symbols and don't have type records of their own
+pub const TYPEDEF_INDEX_BASE: TypeIndex = 0x8000_0000;
+
+/// Alias declared by an `S_UDT` symbol (e.g., `typedef struct _FOO FOO;`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Typedef {
+    pub name: String,
+    /// Index of the aliased type
+    pub type_index: TypeIndex,
+}
+
+/// Named constant declared by an `S_CONSTANT` symbol
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SymbolicConstant {
+    pub name: String,
+    pub type_name: String,
+    /// Value, formatted like enum values
+    pub value: String,
+}
+
+impl std::fmt::Display for SymbolicConstant {
+    /// Format the constant as a C++ declaration, enclosed in its namespace if
+    /// any
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // `constexpr` implies `const`
+        let type_name = self
+            .type_name
+            .strip_prefix("const ")
+            .unwrap_or(&self.type_name);
+        match split_qualified_name(&self.name) {
+            (Some(namespace), name) => write!(
+                f,
+                "namespace {namespace} {{ constexpr {type_name} {name} = {}; }}",
+                self.value
+            ),
+            (None, name) => write!(f, "constexpr {type_name} {name} = {};", self.value),
+        }
+    }
+}
+
+/// Symbol found when looking up an address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolAtAddress {
+    pub name: String,
+    /// RVA of the beginning of the symbol
+    pub rva: u32,
+    /// Offset of the looked up address, relatively to the beginning of the
+    /// symbol
+    pub offset: u32,
+}
+
+/// Kind of user-defined type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TypeKind {
+    Class,
+    Struct,
+    Interface,
+    Union,
+    Enum,
+    Typedef,
+}
+
+impl std::fmt::Display for TypeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            TypeKind::Class => "class",
+            TypeKind::Struct => "struct",
+            TypeKind::Interface => "interface",
+            TypeKind::Union => "union",
+            TypeKind::Enum => "enum",
+            TypeKind::Typedef => "typedef",
+        };
+        write!(f, "{kind}")
+    }
+}
+
+/// Kind of symbol, used to filter symbol lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SymbolKind {
+    Function,
+    Data,
+    Thunk,
+    Public,
+    /// Other named symbols (e.g., typedefs and exports)
+    Other,
+}
+
+impl std::str::FromStr for SymbolKind {
+    type Err = ResymCoreError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "function" | "functions" => Ok(SymbolKind::Function),
+            "data" => Ok(SymbolKind::Data),
+            "thunk" | "thunks" => Ok(SymbolKind::Thunk),
+            "public" | "publics" => Ok(SymbolKind::Public),
+            "other" | "others" => Ok(SymbolKind::Other),
+            _ => Err(ResymCoreError::ParseSymbolKindError(s.to_owned())),
+        }
+    }
+}
+
+/// Kinds of symbols kept in symbol lists. Thunks are only listed on request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SymbolKindFilter {
+    pub functions: bool,
+    pub data: bool,
+    pub thunks: bool,
+    pub publics: bool,
+    pub others: bool,
+}
+
+impl Default for SymbolKindFilter {
+    /// Keep all symbols but thunks
+    fn default() -> Self {
+        Self {
+            functions: true,
+            data: true,
+            thunks: false,
+            publics: true,
+            others: true,
+        }
+    }
+}
+
+impl SymbolKindFilter {
+    /// Only keep symbols of the given kinds, or the default kinds if no kind is
+    /// given
+    pub fn from_kinds(kinds: &[SymbolKind]) -> Self {
+        if kinds.is_empty() {
+            return Self::default();
+        }
+
+        Self {
+            functions: kinds.contains(&SymbolKind::Function),
+            data: kinds.contains(&SymbolKind::Data),
+            thunks: kinds.contains(&SymbolKind::Thunk),
+            publics: kinds.contains(&SymbolKind::Public),
+            others: kinds.contains(&SymbolKind::Other),
+        }
+    }
+
+    pub fn matches(&self, kind: SymbolKind) -> bool {
+        match kind {
+            SymbolKind::Function => self.functions,
+            SymbolKind::Data => self.data,
+            SymbolKind::Thunk => self.thunks,
+            SymbolKind::Public => self.publics,
+            SymbolKind::Other => self.others,
+        }
+    }
+}
+
+/// Information displayed alongside type names in type lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeInfo {
+    pub kind: TypeKind,
+    /// Size of the type in bytes
+    pub size: u64,
+}
+
+/// Information extracted from a PDB's headers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdbMetadata {
+    pub file_path: PathBuf,
+    pub guid: String,
+    pub age: u32,
+    pub signature: u32,
+    pub machine_type: String,
+    pub stream_count: usize,
+    pub type_count: usize,
+    pub module_count: usize,
+    /// `true` if the PDB doesn't contain any type information (e.g., public
+    /// PDBs distributed on symbol servers)
+    pub is_stripped: bool,
+}
+
+impl PdbMetadata {
+    /// Key used to index the PDB on symbol servers
+    pub fn symbol_server_key(&self) -> String {
+        format!("{}{:X}", self.guid.replace('-', ""), self.age)
+    }
+}
+
+/// Location of a symbol in the image
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolLocation {
+    pub name: String,
+    pub rva: u32,
+    /// Name of the section containing the symbol
+    pub section_name: String,
+    /// Size of the symbol, if known (e.g., code size for procedures)
+    pub size: Option<u32>,
+    /// Names under which the symbol is exported, if a PE image is loaded
+    pub export_names: Vec<String>,
+    /// Set if a PE image is loaded and the symbol points outside of it
+    pub outside_image: bool,
+}
+
+/// Details about a type, shown in its properties
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeProperties {
+    pub name: String,
+    pub index: TypeIndex,
+    /// Unique (decorated) name, if any
+    pub unique_name: Option<String>,
+    /// Size of the type (or of its complete type, for forward references), if
+    /// known
+    pub size: Option<u64>,
+    /// Number of members (e.g., fields, methods or enumerators)
+    pub field_count: usize,
+    pub is_forward_reference: bool,
+}
+
+/// Source file defining methods of a given type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeSourceLocation {
+    pub file_path: String,
+    /// First line of the type's methods in the file
+    pub line: u32,
+    /// Number of the type's methods defined in the file
+    pub method_count: usize,
+}
+
+/// Graph of type-to-type references
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TypeDependencyGraph {
+    pub nodes: TypeList,
+    /// Edges going from a type to the types it references. The boolean is
+    /// `true` when the reference is a pointer (i.e., a forward declaration
+    /// is enough).
+    pub edges: Vec<(TypeIndex, TypeIndex, bool)>,
+}
+
+impl TypeDependencyGraph {
+    /// Serialize the graph in the Graphviz DOT format
+    pub fn to_dot(&self) -> String {
+        let mut output = String::from("digraph types {\n    node [shape=box];\n");
+        for (type_name, type_index) in &self.nodes {
+            let label = type_name.replace('\\', "\\\\").replace('"', "\\\"");
+            let _r = writeln!(&mut output, "    t{type_index} [label=\"{label}\"];");
+        }
+        for (from_type_index, to_type_index, is_pointer) in &self.edges {
+            let attributes = if *is_pointer { " [style=dashed]" } else { "" };
+            let _r = writeln!(
+                &mut output,
+                "    t{from_type_index} -> t{to_type_index}{attributes};"
+            );
+        }
+        output.push_str("}\n");
+
+        output
+    }
+}
+
+/// Seekable reader PDB files can be loaded from by the backend
+pub trait PDBReader: Read + Seek + Send + Sync + std::fmt::Debug {}
+
+impl<T> PDBReader for T where T: Read + Seek + Send + Sync + std::fmt::Debug {}
 
 /// Wrapper for different buffer types processed by `resym`
 #[derive(Debug)]
@@ -40,6 +320,8 @@ pub enum PDBDataSource {
     File(std::fs::File),
     Vec(io::Cursor<Vec<u8>>),
     SharedArray(io::Cursor<Arc<[u8]>>),
+    /// Any other reader (e.g., a buffer owned by an embedder)
+    Reader(Box<dyn PDBReader>),
 }
 
 impl Seek for PDBDataSource {
@@ -48,6 +330,7 @@ impl Seek for PDBDataSource {
             PDBDataSource::File(file) => file.seek(pos),
             PDBDataSource::Vec(vec) => vec.seek(pos),
             PDBDataSource::SharedArray(array) => array.seek(pos),
+            PDBDataSource::Reader(reader) => reader.seek(pos),
         }
     }
 }
@@ -58,6 +341,7 @@ impl Read for PDBDataSource {
             PDBDataSource::File(file) => file.read(buf),
             PDBDataSource::Vec(vec) => vec.read(buf),
             PDBDataSource::SharedArray(array) => array.read(buf),
+            PDBDataSource::Reader(reader) => reader.read(buf),
         }
     }
 }
@@ -82,12 +366,181 @@ impl Ord for PrioritizedSymbol {
     }
 }
 
+/// Trade-offs made when loading PDB files
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LoadMode {
+    #[default]
+    Default,
+    /// Don't keep the names of types in memory while loading and resolve them
+    /// from the type stream when first needed instead. Slower, but meant for
+    /// huge PDBs (e.g., kernels and browsers) which would exhaust memory
+    /// otherwise.
+    ReducedMemory,
+}
+
+/// Key used to pair forward references with complete types while loading
+/// PDBs. Names are hashed in reduced-memory mode, to avoid keeping copies of
+/// them around.
+#[derive(Debug, PartialEq, Eq, Hash)]
+enum TypeNameKey {
+    Name(String),
+    Hash(u64),
+}
+
+impl TypeNameKey {
+    fn new(type_name: String, load_mode: LoadMode) -> Self {
+        match load_mode {
+            LoadMode::Default => Self::Name(type_name),
+            LoadMode::ReducedMemory => {
+                let mut hasher = DefaultHasher::new();
+                type_name.hash(&mut hasher);
+                Self::Hash(hasher.finish())
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for TypeNameKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Name(type_name) => write!(f, "{type_name}"),
+            Self::Hash(hash) => write!(f, "#{hash:016x}"),
+        }
+    }
+}
+
+/// Complete types of a PDB by name, used to pair forward references with
+/// them. Hashed names can collide, so the candidates of a hashed key are told
+/// apart by the names of their type records.
+#[derive(Default)]
+struct CompleteTypeMap(DashMap<TypeNameKey, Vec<pdb::TypeIndex>>);
+
+impl CompleteTypeMap {
+    fn insert(
+        &self,
+        type_finder: &pdb::TypeFinder,
+        type_name: String,
+        type_index: pdb::TypeIndex,
+        load_mode: LoadMode,
+    ) {
+        let key = TypeNameKey::new(type_name.clone(), load_mode);
+        let hashed = matches!(key, TypeNameKey::Hash(_));
+        let mut candidates = self.0.entry(key).or_default();
+        // Later definitions replace earlier ones with the same name
+        if hashed {
+            candidates.retain(|candidate| {
+                type_record_name(type_finder, *candidate).as_deref() != Some(type_name.as_str())
+            });
+        } else {
+            candidates.clear();
+        }
+        candidates.push(type_index);
+    }
+
+    fn contains(
+        &self,
+        type_finder: &pdb::TypeFinder,
+        type_name: &str,
+        load_mode: LoadMode,
+    ) -> bool {
+        let key = TypeNameKey::new(type_name.to_owned(), load_mode);
+        self.find(type_finder, &key, type_name).is_some()
+    }
+
+    /// Return the complete type corresponding to the given forward reference
+    fn complete_type_index(
+        &self,
+        type_finder: &pdb::TypeFinder,
+        fwd_key: &TypeNameKey,
+        fwd_type_index: pdb::TypeIndex,
+    ) -> Option<pdb::TypeIndex> {
+        match fwd_key {
+            TypeNameKey::Name(type_name) => self.find(type_finder, fwd_key, type_name),
+            TypeNameKey::Hash(_) => {
+                let type_name = type_record_name(type_finder, fwd_type_index)?;
+                self.find(type_finder, fwd_key, &type_name)
+            }
+        }
+    }
+
+    fn find(
+        &self,
+        type_finder: &pdb::TypeFinder,
+        key: &TypeNameKey,
+        type_name: &str,
+    ) -> Option<pdb::TypeIndex> {
+        let candidates = self.0.get(key)?;
+        match key {
+            TypeNameKey::Name(_) => candidates.last().copied(),
+            TypeNameKey::Hash(_) => candidates.iter().copied().find(|candidate| {
+                type_record_name(type_finder, *candidate).as_deref() == Some(type_name)
+            }),
+        }
+    }
+}
+
+/// Name of the type record at the given index, if any
+fn type_record_name(type_finder: &pdb::TypeFinder, type_index: pdb::TypeIndex) -> Option<String> {
+    let type_data = type_finder.find(type_index).ok()?.parse().ok()?;
+    type_data
+        .name()
+        .map(|type_name| type_name.to_string().into_owned())
+}
+
+/// Options applied when reconstructing types, shared by all the PDBs loaded
+/// in a backend
+#[derive(Debug, Clone, Default)]
+pub struct ReconstructionSettings {
+    /// Types to treat as opaque when reconstructing dependencies
+    pub opaque_type_rules: OpaqueTypeRules,
+    /// Style applied to reconstructed types
+    pub code_format: CodeFormatConfiguration,
+    /// Names of primitive types, used with the `Custom` primitives flavor
+    pub primitive_type_mapping: PrimitiveTypeMapping,
+    /// Template of the header printed before reconstructed content
+    pub header_template: HeaderTemplate,
+    /// Members omitted from reconstructed types
+    pub member_filter: MemberFilter,
+    /// Annotate static data members with the RVA of their definition
+    pub print_static_member_rvas: bool,
+    /// Emit Doxygen comment blocks with the metadata of types and fields
+    pub doxygen_comments: bool,
+    /// Annotate the enumerators of enums whose values are bit flags with
+    /// their bits
+    pub flag_enum_comments: bool,
+    /// Order in which enumerators are printed
+    pub enum_value_order: EnumValueOrder,
+    /// Forward declare the user-defined types referenced by reconstructed
+    /// types, when their definitions aren't reconstructed
+    pub forward_declare_dependencies: bool,
+    /// Image base used to print virtual addresses next to RVAs, if any
+    pub image_base: Option<u64>,
+    /// Style of the offsets, sizes and enum values printed in reconstructed
+    /// types
+    pub number_format: NumberFormat,
+    /// `clang-format` configuration applied to reconstructed code, if enabled
+    #[cfg(not(target_arch = "wasm32"))]
+    pub clang_format: Option<ClangFormatConfiguration>,
+}
+
 pub struct PdbFile<'p, T>
 where
     T: io::Seek + io::Read + 'p,
 {
+    /// Complete types of the PDB, left empty in reduced-memory mode (see
+    /// `PdbFile::type_list`)
     pub complete_type_list: Vec<(String, TypeIndex)>,
+    /// Complete types of the PDB in reduced-memory mode, listed from the type
+    /// stream on first use
+    reduced_type_list: OnceLock<Vec<(String, TypeIndex)>>,
+    /// Typedefs declared in the PDB, identified by their position (offset by
+    /// `TYPEDEF_INDEX_BASE`)
+    pub typedefs: Vec<Typedef>,
+    /// Kind and size of the complete types
+    pub type_infos: TypeInfoMap,
     pub forwarder_to_complete_type: Arc<DashMap<pdb::TypeIndex, pdb::TypeIndex>>,
+    /// Complete anonymous types, by content hash
+    pub unnamed_types: pdb_types::UnnamedTypeMap,
     pub machine_type: pdb::MachineType,
     pub type_information: pdb::TypeInformation<'p>,
     pub debug_information: pdb::DebugInformation<'p>,
@@ -95,6 +548,21 @@ where
     pub sections: Vec<pdb::ImageSectionHeader>,
     pub file_path: PathBuf,
     pub xref_to_map: RwLock<DashMap<TypeIndex, Vec<TypeIndex>>>,
+    /// Options applied when reconstructing types
+    pub settings: ReconstructionSettings,
+    /// User-defined names applied to reconstructed types
+    pub rename_map: RenameMap,
+    /// User notes emitted as comments above type definitions
+    pub type_notes: TypeNotes,
+    /// PE image described by the PDB, used to relate symbols to the
+    /// image's sections and exports
+    pub pe_file: Option<PeFile>,
+    load_mode: LoadMode,
+    /// Number of streams listed in the MSF stream directory, if it could be
+    /// read
+    stream_count: Option<u32>,
+    /// RVAs of global data symbols, by qualified name, built on first use
+    global_data_rvas: OnceLock<HashMap<String, u32>>,
     pdb: RwLock<pdb::PDB<'p, T>>,
 }
 
@@ -102,61 +570,74 @@ where
 impl<'p> PdbFile<'p, File> {
     /// Create `PdbFile` from an `std::path::Path`
     pub fn load_from_file(pdb_file_path: &Path) -> Result<PdbFile<'p, PDBDataSource>> {
-        let file = PDBDataSource::File(File::open(pdb_file_path)?);
-        let mut pdb = pdb::PDB::open(file)?;
-        let type_information = pdb.type_information()?;
-        let debug_information = pdb.debug_information()?;
-        let global_symbols = pdb.global_symbols()?;
-        let sections = pdb.sections().unwrap_or_default().unwrap_or_default();
-        let machine_type = pdb.debug_information()?.machine_type()?;
-
-        let mut pdb_file = PdbFile {
-            complete_type_list: vec![],
-            forwarder_to_complete_type: Arc::new(DashMap::default()),
-            machine_type,
-            type_information,
-            debug_information,
-            global_symbols,
-            sections,
-            file_path: pdb_file_path.to_owned(),
-            xref_to_map: DashMap::default().into(),
-            pdb: pdb.into(),
-        };
-        pdb_file.load_symbols()?;
+        Self::load_from_file_with_mode(
+            pdb_file_path,
+            LoadMode::default(),
+            &CancellationToken::default(),
+        )
+    }
 
-        Ok(pdb_file)
+    /// Create `PdbFile` from an `std::path::Path`, using the given `LoadMode`.
+    /// Loading stops with `OperationCancelledError` if `cancellation_token`
+    /// gets cancelled.
+    pub fn load_from_file_with_mode(
+        pdb_file_path: &Path,
+        load_mode: LoadMode,
+        cancellation_token: &CancellationToken,
+    ) -> Result<PdbFile<'p, PDBDataSource>> {
+        PdbFile::load_from_reader_with_mode(
+            pdb_file_path,
+            PDBDataSource::File(File::open(pdb_file_path)?),
+            load_mode,
+            cancellation_token,
+        )
     }
 }
 
 impl<'p> PdbFile<'p, PDBDataSource> {
+    /// Create `PdbFile` from a `String` and any byte buffer (e.g., a PDB
+    /// downloaded from a symbol server)
+    pub fn load_from_bytes<B>(
+        pdb_file_name: String,
+        pdb_file_data: B,
+    ) -> Result<PdbFile<'p, PDBDataSource>>
+    where
+        B: AsRef<[u8]> + Send + Sync + std::fmt::Debug + 'static,
+    {
+        Self::load_from_reader(
+            pdb_file_name,
+            PDBDataSource::Reader(Box::new(io::Cursor::new(pdb_file_data))),
+        )
+    }
+
     /// Create `PdbFile` from a `String` and a `Vec<u8>`
     pub fn load_from_bytes_as_vec(
         pdb_file_name: String,
         pdb_file_data: Vec<u8>,
     ) -> Result<PdbFile<'p, PDBDataSource>> {
-        let reader = PDBDataSource::Vec(io::Cursor::new(pdb_file_data));
-        let mut pdb = pdb::PDB::open(reader)?;
-        let type_information = pdb.type_information()?;
-        let debug_information = pdb.debug_information()?;
-        let global_symbols = pdb.global_symbols()?;
-        let sections = pdb.sections().unwrap_or_default().unwrap_or_default();
-        let machine_type = pdb.debug_information()?.machine_type()?;
-
-        let mut pdb_file = PdbFile {
-            complete_type_list: vec![],
-            forwarder_to_complete_type: Arc::new(DashMap::default()),
-            machine_type,
-            type_information,
-            debug_information,
-            global_symbols,
-            sections,
-            file_path: pdb_file_name.into(),
-            xref_to_map: DashMap::default().into(),
-            pdb: pdb.into(),
-        };
-        pdb_file.load_symbols()?;
+        Self::load_from_bytes_as_vec_with_mode(
+            pdb_file_name,
+            pdb_file_data,
+            LoadMode::default(),
+            &CancellationToken::default(),
+        )
+    }
 
-        Ok(pdb_file)
+    /// Create `PdbFile` from a `String` and a `Vec<u8>`, using the given
+    /// `LoadMode`. Loading stops with `OperationCancelledError` if
+    /// `cancellation_token` gets cancelled.
+    pub fn load_from_bytes_as_vec_with_mode(
+        pdb_file_name: String,
+        pdb_file_data: Vec<u8>,
+        load_mode: LoadMode,
+        cancellation_token: &CancellationToken,
+    ) -> Result<PdbFile<'p, PDBDataSource>> {
+        PdbFile::load_from_reader_with_mode(
+            pdb_file_name,
+            PDBDataSource::Vec(io::Cursor::new(pdb_file_data)),
+            load_mode,
+            cancellation_token,
+        )
     }
 
     /// Create `PdbFile` from a `String` and a `Arc<[u8]>`
@@ -164,7 +645,59 @@ impl<'p> PdbFile<'p, PDBDataSource> {
         pdb_file_name: String,
         pdb_file_data: Arc<[u8]>,
     ) -> Result<PdbFile<'p, PDBDataSource>> {
-        let reader = PDBDataSource::SharedArray(io::Cursor::new(pdb_file_data));
+        Self::load_from_bytes_as_array_with_mode(
+            pdb_file_name,
+            pdb_file_data,
+            LoadMode::default(),
+            &CancellationToken::default(),
+        )
+    }
+
+    /// Create `PdbFile` from a `String` and a `Arc<[u8]>`, using the given
+    /// `LoadMode`. Loading stops with `OperationCancelledError` if
+    /// `cancellation_token` gets cancelled.
+    pub fn load_from_bytes_as_array_with_mode(
+        pdb_file_name: String,
+        pdb_file_data: Arc<[u8]>,
+        load_mode: LoadMode,
+        cancellation_token: &CancellationToken,
+    ) -> Result<PdbFile<'p, PDBDataSource>> {
+        PdbFile::load_from_reader_with_mode(
+            pdb_file_name,
+            PDBDataSource::SharedArray(io::Cursor::new(pdb_file_data)),
+            load_mode,
+            cancellation_token,
+        )
+    }
+}
+
+impl<'p, T> PdbFile<'p, T>
+where
+    T: io::Seek + io::Read + std::fmt::Debug + 'p,
+{
+    /// Create `PdbFile` from any seekable reader (e.g., a buffer downloaded
+    /// from a symbol server or a memory-mapped file). `pdb_file_path` is only
+    /// used to identify the PDB (e.g., in headers).
+    pub fn load_from_reader(pdb_file_path: impl AsRef<Path>, reader: T) -> Result<Self> {
+        Self::load_from_reader_with_mode(
+            pdb_file_path,
+            reader,
+            LoadMode::default(),
+            &CancellationToken::default(),
+        )
+    }
+
+    /// Create `PdbFile` from any seekable reader, using the given `LoadMode`.
+    /// Loading stops with `OperationCancelledError` if `cancellation_token`
+    /// gets cancelled.
+    pub fn load_from_reader_with_mode(
+        pdb_file_path: impl AsRef<Path>,
+        reader: T,
+        load_mode: LoadMode,
+        cancellation_token: &CancellationToken,
+    ) -> Result<Self> {
+        let mut reader = reader;
+        let stream_count = msf_stream_count(&mut reader);
         let mut pdb = pdb::PDB::open(reader)?;
         let type_information = pdb.type_information()?;
         let debug_information = pdb.debug_information()?;
@@ -174,35 +707,43 @@ impl<'p> PdbFile<'p, PDBDataSource> {
 
         let mut pdb_file = PdbFile {
             complete_type_list: vec![],
+            reduced_type_list: OnceLock::new(),
+            typedefs: vec![],
+            type_infos: TypeInfoMap::default(),
             forwarder_to_complete_type: Arc::new(DashMap::default()),
+            unnamed_types: pdb_types::UnnamedTypeMap::default(),
             machine_type,
             type_information,
             debug_information,
             global_symbols,
             sections,
-            file_path: pdb_file_name.into(),
+            file_path: pdb_file_path.as_ref().to_owned(),
             xref_to_map: DashMap::default().into(),
+            settings: ReconstructionSettings::default(),
+            rename_map: RenameMap::default(),
+            type_notes: TypeNotes::default(),
+            pe_file: None,
+            load_mode,
+            stream_count,
+            global_data_rvas: OnceLock::new(),
             pdb: pdb.into(),
         };
-        pdb_file.load_symbols()?;
+        pdb_file.load_symbols(cancellation_token)?;
 
         Ok(pdb_file)
     }
-}
 
-impl<'p, T> PdbFile<'p, T>
-where
-    T: io::Seek + io::Read + std::fmt::Debug + 'p,
-{
-    fn load_symbols(&mut self) -> Result<()> {
+    fn load_symbols(&mut self, cancellation_token: &CancellationToken) -> Result<()> {
         // Build the list of complete types
-        let complete_symbol_map: DashMap<String, pdb::TypeIndex> = DashMap::default();
+        let complete_symbol_map = CompleteTypeMap::default();
+        let keep_type_names = self.load_mode != LoadMode::ReducedMemory;
         let mut forwarders = vec![];
         let pdb_start = Instant::now();
 
         let mut type_finder = self.type_information.finder();
         let mut type_info_iter = self.type_information.iter();
         while let Some(type_info) = type_info_iter.next()? {
+            cancellation_token.check()?;
             // keep building the index
             type_finder.update(&type_info_iter);
 
@@ -214,61 +755,173 @@ where
 
                         // Ignore forward references
                         if data.properties.forward_reference() {
-                            forwarders.push((class_name, type_index));
+                            forwarders
+                                .push((TypeNameKey::new(class_name, self.load_mode), type_index));
                             continue;
                         }
-                        complete_symbol_map.insert(class_name.clone(), type_index);
+                        complete_symbol_map.insert(
+                            &type_finder,
+                            class_name.clone(),
+                            type_index,
+                            self.load_mode,
+                        );
 
                         // Rename anonymous tags to something unique
                         if is_unnamed_type(&class_name) {
-                            class_name = format!("_unnamed_{type_index}");
+                            class_name = pdb_types::add_unnamed_type(
+                                &type_finder,
+                                &mut self.unnamed_types,
+                                type_index,
+                            );
                         }
-                        self.complete_type_list.push((class_name, type_index.0));
+                        if keep_type_names {
+                            self.complete_type_list.push((class_name, type_index.0));
+                        }
+                        let kind = match data.kind {
+                            pdb::ClassKind::Class => TypeKind::Class,
+                            pdb::ClassKind::Struct => TypeKind::Struct,
+                            pdb::ClassKind::Interface => TypeKind::Interface,
+                        };
+                        self.type_infos.insert(
+                            type_index.0,
+                            TypeInfo {
+                                kind,
+                                size: data.size,
+                            },
+                        );
                     }
                     pdb::TypeData::Union(data) => {
                         let mut class_name = data.name.to_string().into_owned();
 
                         // Ignore forward references
                         if data.properties.forward_reference() {
-                            forwarders.push((class_name, type_index));
+                            forwarders
+                                .push((TypeNameKey::new(class_name, self.load_mode), type_index));
                             continue;
                         }
-                        complete_symbol_map.insert(class_name.clone(), type_index);
+                        complete_symbol_map.insert(
+                            &type_finder,
+                            class_name.clone(),
+                            type_index,
+                            self.load_mode,
+                        );
 
                         // Rename anonymous tags to something unique
                         if is_unnamed_type(&class_name) {
-                            class_name = format!("_unnamed_{type_index}");
+                            class_name = pdb_types::add_unnamed_type(
+                                &type_finder,
+                                &mut self.unnamed_types,
+                                type_index,
+                            );
+                        }
+                        if keep_type_names {
+                            self.complete_type_list.push((class_name, type_index.0));
                         }
-                        self.complete_type_list.push((class_name, type_index.0));
+                        self.type_infos.insert(
+                            type_index.0,
+                            TypeInfo {
+                                kind: TypeKind::Union,
+                                size: data.size,
+                            },
+                        );
                     }
                     pdb::TypeData::Enumeration(data) => {
                         let mut class_name = data.name.to_string().into_owned();
 
                         // Ignore forward references
                         if data.properties.forward_reference() {
-                            forwarders.push((class_name, type_index));
+                            forwarders
+                                .push((TypeNameKey::new(class_name, self.load_mode), type_index));
                             continue;
                         }
-                        complete_symbol_map.insert(class_name.clone(), type_index);
+                        complete_symbol_map.insert(
+                            &type_finder,
+                            class_name.clone(),
+                            type_index,
+                            self.load_mode,
+                        );
 
                         // Rename anonymous tags to something unique
                         if is_unnamed_type(&class_name) {
-                            class_name = format!("_unnamed_{type_index}");
+                            class_name = pdb_types::add_unnamed_type(
+                                &type_finder,
+                                &mut self.unnamed_types,
+                                type_index,
+                            );
                         }
-                        self.complete_type_list.push((class_name, type_index.0));
+                        if keep_type_names {
+                            self.complete_type_list.push((class_name, type_index.0));
+                        }
+                        // Note: enums' size is the size of their underlying type
+                        let size = pdb_types::type_size(&type_finder, data.underlying_type)
+                            .unwrap_or_default() as u64;
+                        self.type_infos.insert(
+                            type_index.0,
+                            TypeInfo {
+                                kind: TypeKind::Enum,
+                                size,
+                            },
+                        );
                     }
                     _ => {}
                 }
             }
         }
+
+        // Typedefs are declared by `S_UDT` symbols. Ignore the ones which are
+        // named after the type they alias (e.g., C++ tags).
+        let mut typedef_names = HashSet::new();
+        let mut symbols = self.global_symbols.iter();
+        while let Some(symbol) = symbols.next()? {
+            cancellation_token.check()?;
+            let Ok(pdb::SymbolData::UserDefinedType(data)) = symbol.parse() else {
+                continue;
+            };
+            let typedef_name = data.name.to_string().into_owned();
+            if complete_symbol_map.contains(&type_finder, &typedef_name, self.load_mode)
+                || typedef_names.contains(&typedef_name)
+            {
+                continue;
+            }
+            let aliased_type_name = type_name(
+                &type_finder,
+                &self.forwarder_to_complete_type,
+                &self.unnamed_types,
+                data.type_index,
+                &PrimitiveReconstructionFlavor::Raw,
+                &self.settings.primitive_type_mapping,
+                &mut pdb_types::NeededTypeSet::new(),
+            )
+            .map(|(type_left, type_right)| type_left + &type_right)
+            .unwrap_or_default();
+            if aliased_type_name == typedef_name {
+                continue;
+            }
+
+            let size = pdb_types::type_size(&type_finder, data.type_index).unwrap_or_default();
+            self.type_infos.insert(
+                TYPEDEF_INDEX_BASE + self.typedefs.len() as TypeIndex,
+                TypeInfo {
+                    kind: TypeKind::Typedef,
+                    size: size as u64,
+                },
+            );
+            typedef_names.insert(typedef_name.clone());
+            self.typedefs.push(Typedef {
+                name: typedef_name,
+                type_index: data.type_index.0,
+            });
+        }
         log::debug!("PDB loading took {} ms", pdb_start.elapsed().as_millis());
 
         // Resolve forwarder references to their corresponding complete type, in parallel
         let fwd_start = Instant::now();
         par_iter_if_available!(forwarders).for_each(|(fwd_name, fwd_type_id)| {
-            if let Some(complete_type_index) = complete_symbol_map.get(fwd_name) {
+            if let Some(complete_type_index) =
+                complete_symbol_map.complete_type_index(&type_finder, fwd_name, *fwd_type_id)
+            {
                 self.forwarder_to_complete_type
-                    .insert(*fwd_type_id, *complete_type_index);
+                    .insert(*fwd_type_id, complete_type_index);
             } else {
                 log::debug!("'{}''s type definition wasn't found", fwd_name);
             }
@@ -289,6 +942,21 @@ where
         print_access_specifiers: bool,
         ignore_std_types: bool,
     ) -> Result<ReconstructedType> {
+        // Typedefs don't have type records
+        if let Some(typedef_index) = self
+            .typedefs
+            .iter()
+            .position(|typedef| typedef.name == type_name)
+        {
+            return self.reconstruct_type_by_index(
+                TYPEDEF_INDEX_BASE + typedef_index as TypeIndex,
+                primitives_flavor,
+                reconstruct_dependencies,
+                print_access_specifiers,
+                ignore_std_types,
+            );
+        }
+
         // Populate our `TypeFinder` and find the right type index
         let mut type_index = TypeIndex::default();
         let mut type_finder = self.type_information.finder();
@@ -309,7 +977,13 @@ where
                             // Rename anonymous tags to something unique
                             let class_name = data.name.to_string();
                             if is_unnamed_type(&class_name) {
-                                if type_name == format!("_unnamed_{item_type_index}") {
+                                if type_name
+                                    == pdb_types::unnamed_type_name(
+                                        &type_finder,
+                                        &self.unnamed_types,
+                                        item_type_index,
+                                    )
+                                {
                                     type_index = item_type_index.0;
                                 }
                             } else if class_name == type_name {
@@ -329,7 +1003,13 @@ where
                             // Rename anonymous tags to something unique
                             let union_name = data.name.to_string();
                             if is_unnamed_type(&union_name) {
-                                if type_name == format!("_unnamed_{item_type_index}") {
+                                if type_name
+                                    == pdb_types::unnamed_type_name(
+                                        &type_finder,
+                                        &self.unnamed_types,
+                                        item_type_index,
+                                    )
+                                {
                                     type_index = item_type_index.0;
                                 }
                             } else if data.name.to_string() == type_name {
@@ -349,7 +1029,13 @@ where
                             // Rename anonymous tags to something unique
                             let enum_name = data.name.to_string();
                             if is_unnamed_type(&enum_name) {
-                                if type_name == format!("_unnamed_{item_type_index}") {
+                                if type_name
+                                    == pdb_types::unnamed_type_name(
+                                        &type_finder,
+                                        &self.unnamed_types,
+                                        item_type_index,
+                                    )
+                                {
                                     type_index = item_type_index.0;
                                 }
                             } else if data.name.to_string() == type_name {
@@ -370,9 +1056,9 @@ where
         if type_index == TypeIndex::default() {
             Err(ResymCoreError::TypeNameNotFoundError(type_name.to_owned()))
         } else {
-            self.reconstruct_type_by_type_index_internal(
+            self.reconstruct_types_by_type_index_internal(
                 &type_finder,
-                type_index,
+                &[type_index],
                 primitives_flavor,
                 reconstruct_dependencies,
                 print_access_specifiers,
@@ -381,14 +1067,84 @@ where
         }
     }
 
-    pub fn reconstruct_type_by_index(
+    /// Return the typedef identified by the given type index, if any
+    pub fn typedef(&self, type_index: TypeIndex) -> Option<&Typedef> {
+        let typedef_index = type_index.checked_sub(TYPEDEF_INDEX_BASE)?;
+
+        self.typedefs.get(typedef_index as usize)
+    }
+
+    pub fn load_mode(&self) -> LoadMode {
+        self.load_mode
+    }
+
+    /// Return the names of the complete types of the PDB, with their type
+    /// index. In reduced-memory mode, names are resolved from the type stream
+    /// the first time the list is needed instead of while loading.
+    pub fn type_list(&self) -> Result<Cow<'_, [(String, TypeIndex)]>> {
+        if self.load_mode != LoadMode::ReducedMemory {
+            return Ok(Cow::Borrowed(&self.complete_type_list));
+        }
+        if let Some(type_list) = self.reduced_type_list.get() {
+            return Ok(Cow::Borrowed(type_list));
+        }
+
+        // Complete types are the ones with type information
+        let mut type_list = Vec::with_capacity(self.type_infos.len());
+        let mut type_finder = self.type_information.finder();
+        let mut type_iter = self.type_information.iter();
+        while let Some(type_item) = type_iter.next()? {
+            type_finder.update(&type_iter);
+            let type_index = type_item.index();
+            if !self.type_infos.contains_key(&type_index.0) {
+                continue;
+            }
+            let Some(type_name) = type_item.parse().ok().and_then(|type_data| {
+                type_data
+                    .name()
+                    .map(|type_name| type_name.to_string().into_owned())
+            }) else {
+                continue;
+            };
+
+            // Rename anonymous tags the same way as when loading
+            if is_unnamed_type(&type_name) {
+                type_list.push((
+                    pdb_types::unnamed_type_name(&type_finder, &self.unnamed_types, type_index),
+                    type_index.0,
+                ));
+            } else {
+                type_list.push((type_name, type_index.0));
+            }
+        }
+
+        Ok(Cow::Borrowed(
+            self.reduced_type_list.get_or_init(|| type_list),
+        ))
+    }
+
+    /// Return the names of the typedefs declared in the PDB, with their type
+    /// index
+    pub fn typedef_list(&self) -> TypeList {
+        self.typedefs
+            .iter()
+            .enumerate()
+            .map(|(typedef_index, typedef)| {
+                (
+                    typedef.name.clone(),
+                    TYPEDEF_INDEX_BASE + typedef_index as TypeIndex,
+                )
+            })
+            .collect()
+    }
+
+    /// Return the constants declared in the global symbols stream, sorted by
+    /// name. Enumerators and static data members are omitted, as they're
+    /// declared with their enum or class.
+    pub fn constant_list(
         &self,
-        type_index: TypeIndex,
         primitives_flavor: PrimitiveReconstructionFlavor,
-        reconstruct_dependencies: bool,
-        print_access_specifiers: bool,
-        ignore_std_types: bool,
-    ) -> Result<ReconstructedType> {
+    ) -> Result<Vec<SymbolicConstant>> {
         // Populate our `TypeFinder`
         let mut type_finder = self.type_information.finder();
         {
@@ -398,17 +1154,353 @@ where
             }
         }
 
-        self.reconstruct_type_by_type_index_internal(
-            &type_finder,
-            type_index,
-            primitives_flavor,
-            reconstruct_dependencies,
-            print_access_specifiers,
-            ignore_std_types,
-        )
-    }
+        let type_list = self.type_list()?;
+        let type_names: HashSet<&str> = type_list
+            .iter()
+            .map(|(type_name, _)| type_name.as_str())
+            .collect();
+        let mut constants = vec![];
+        let mut symbols = self.global_symbols.iter();
+        while let Some(symbol) = symbols.next()? {
+            let Ok(pdb::SymbolData::Constant(constant)) = symbol.parse() else {
+                continue;
+            };
+            let name = constant.name.to_string();
+            let (scope, unqualified_name) = split_qualified_name(&name);
+            if is_enumerator_of(
+                &type_finder,
+                &self.forwarder_to_complete_type,
+                constant.type_index,
+                unqualified_name,
+            ) {
+                continue;
+            }
+            // Namespaces cannot be templates, so these are classes too
+            if scope.is_some_and(|scope| scope.contains('<') || type_names.contains(scope)) {
+                continue;
+            }
 
-    pub fn symbol_list(&self) -> Result<SymbolList> {
+            let type_name = match type_name(
+                &type_finder,
+                &self.forwarder_to_complete_type,
+                &self.unnamed_types,
+                constant.type_index,
+                &primitives_flavor,
+                &self.settings.primitive_type_mapping,
+                &mut pdb_types::NeededTypeSet::new(),
+            ) {
+                Ok((type_left, type_right)) if type_left != "..." => type_left + &type_right,
+                // Missing type information
+                _ => "auto".to_string(),
+            };
+            let value = if is_bool_type(&type_finder, constant.type_index) {
+                let is_false = matches!(
+                    constant.value,
+                    pdb::Variant::U8(0)
+                        | pdb::Variant::U16(0)
+                        | pdb::Variant::U32(0)
+                        | pdb::Variant::U64(0)
+                        | pdb::Variant::I8(0)
+                        | pdb::Variant::I16(0)
+                        | pdb::Variant::I32(0)
+                        | pdb::Variant::I64(0)
+                );
+                (!is_false).to_string()
+            } else {
+                self.settings.number_format.variant(&constant.value)
+            };
+            constants.push(SymbolicConstant {
+                name: name.into_owned(),
+                type_name,
+                value,
+            });
+        }
+        constants.sort();
+        constants.dedup();
+
+        Ok(constants)
+    }
+
+    /// Retrieve the unique (decorated) name of a type, given its name.
+    /// Returns `None` if the type cannot be found or doesn't have a unique name.
+    pub fn type_unique_name(&self, type_name: &str) -> Result<Option<String>> {
+        let mut type_iter = self.type_information.iter();
+        while let Some(item) = type_iter.next()? {
+            let (name, unique_name) = match item.parse() {
+                Ok(pdb::TypeData::Class(data)) if !data.properties.forward_reference() => {
+                    (data.name, data.unique_name)
+                }
+                Ok(pdb::TypeData::Union(data)) if !data.properties.forward_reference() => {
+                    (data.name, data.unique_name)
+                }
+                Ok(pdb::TypeData::Enumeration(data)) if !data.properties.forward_reference() => {
+                    (data.name, data.unique_name)
+                }
+                _ => continue,
+            };
+
+            if name.to_string() == type_name {
+                return Ok(unique_name.map(|unique_name| unique_name.to_string().into_owned()));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Retrieve the types whose name fuzzy-matches `query`, best matches
+    /// first, up to `max_count` of them.
+    /// Characters of `query` must appear in order in matching names (case
+    /// insensitive). Consecutive characters and characters at the start of
+    /// words are favored, then shorter names.
+    pub fn fuzzy_find_types(&self, query: &str, max_count: usize) -> Result<TypeList> {
+        let query: Vec<char> = query
+            .trim()
+            .chars()
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        let type_list = self.type_list()?;
+        let mut scored_matches: Vec<(i64, &(String, TypeIndex))> = type_list
+            .iter()
+            .filter_map(|type_entry| {
+                fuzzy_match_score(&query, &type_entry.0).map(|score| (score, type_entry))
+            })
+            .collect();
+        // Best scores first, then shortest names
+        scored_matches.sort_by(|(score_a, (name_a, _)), (score_b, (name_b, _))| {
+            score_b
+                .cmp(score_a)
+                .then_with(|| name_a.len().cmp(&name_b.len()))
+        });
+
+        Ok(scored_matches
+            .into_iter()
+            .take(max_count)
+            .map(|(_, type_entry)| type_entry.clone())
+            .collect())
+    }
+
+    /// Retrieve the size of a complete type, given its name or unique
+    /// (decorated) name. Returns `None` if the type cannot be found.
+    pub fn type_size(&self, type_name: &str) -> Result<Option<u64>> {
+        let type_index = match self.type_list()?.iter().find(|(name, _)| name == type_name) {
+            Some((_, type_index)) => Some(*type_index),
+            None => self.complete_type_index_by_unique_name(type_name)?,
+        };
+
+        Ok(type_index
+            .and_then(|type_index| self.type_infos.get(&type_index))
+            .map(|type_info| type_info.size))
+    }
+
+    /// Retrieve the index of a complete type, given its unique (decorated) name
+    fn complete_type_index_by_unique_name(
+        &self,
+        unique_type_name: &str,
+    ) -> Result<Option<TypeIndex>> {
+        let mut type_iter = self.type_information.iter();
+        while let Some(item) = type_iter.next()? {
+            let unique_name = match item.parse() {
+                Ok(pdb::TypeData::Class(data)) if !data.properties.forward_reference() => {
+                    data.unique_name
+                }
+                Ok(pdb::TypeData::Union(data)) if !data.properties.forward_reference() => {
+                    data.unique_name
+                }
+                Ok(pdb::TypeData::Enumeration(data)) if !data.properties.forward_reference() => {
+                    data.unique_name
+                }
+                _ => continue,
+            };
+
+            if unique_name.is_some_and(|unique_name| unique_name.to_string() == unique_type_name) {
+                return Ok(Some(item.index().0));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Retrieve the size and field offsets of classes, structs and unions,
+    /// given their names. Layouts are `None` for types which cannot be found
+    /// or don't have one (e.g., enums).
+    pub fn type_layouts(&self, type_names: &[&str]) -> Result<Vec<Option<TypeLayout>>> {
+        // Populate our `TypeFinder`
+        let mut type_finder = self.type_information.finder();
+        {
+            let mut type_iter = self.type_information.iter();
+            while (type_iter.next()?).is_some() {
+                type_finder.update(&type_iter);
+            }
+        }
+
+        let type_list = self.type_list()?;
+        let type_indices: HashMap<&str, TypeIndex> = type_list
+            .iter()
+            .map(|(type_name, type_index)| (type_name.as_str(), *type_index))
+            // Typedefs (e.g., of anonymous types) are laid out like the aliased type
+            .chain(
+                self.typedefs
+                    .iter()
+                    .map(|typedef| (typedef.name.as_str(), typedef.type_index)),
+            )
+            .collect();
+        let mut type_layouts = Vec::with_capacity(type_names.len());
+        for type_name in type_names {
+            let Some(type_index) = type_indices.get(type_name) else {
+                type_layouts.push(None);
+                continue;
+            };
+            let type_index = pdb_types::resolve_complete_type_index(
+                &self.forwarder_to_complete_type,
+                pdb::TypeIndex(*type_index),
+            );
+            let has_layout = self.type_infos.get(&type_index.0).is_some_and(|type_info| {
+                matches!(
+                    type_info.kind,
+                    TypeKind::Class | TypeKind::Struct | TypeKind::Interface | TypeKind::Union
+                )
+            });
+            if !has_layout {
+                type_layouts.push(None);
+                continue;
+            }
+
+            let mut type_data = pdb_types::Data::new(false);
+            type_data.add(
+                &type_finder,
+                &self.forwarder_to_complete_type,
+                &self.unnamed_types,
+                type_index,
+                &PrimitiveReconstructionFlavor::Portable,
+                &mut pdb_types::NeededTypeSet::new(),
+            )?;
+            type_layouts.push(
+                type_data
+                    .type_layout(type_index)
+                    .map(|type_layout| TypeLayout {
+                        name: type_name.to_string(),
+                        ..type_layout
+                    }),
+            );
+        }
+
+        Ok(type_layouts)
+    }
+
+    /// Retrieve the properties of a type, given its index
+    pub fn type_properties(&self, type_index: TypeIndex) -> Result<TypeProperties> {
+        // Populate our `TypeFinder`
+        let mut type_finder = self.type_information.finder();
+        {
+            let mut type_iter = self.type_information.iter();
+            while (type_iter.next()?).is_some() {
+                type_finder.update(&type_iter);
+            }
+        }
+
+        let type_not_found_error =
+            || ResymCoreError::InvalidParameterError(format!("Type #0x{type_index:x} not found"));
+        let item = type_finder
+            .find(pdb::TypeIndex(type_index))
+            .map_err(|_| type_not_found_error())?;
+        let (name, unique_name, field_count, properties) = match item.parse()? {
+            pdb::TypeData::Class(data) => {
+                (data.name, data.unique_name, data.count, data.properties)
+            }
+            pdb::TypeData::Union(data) => {
+                (data.name, data.unique_name, data.count, data.properties)
+            }
+            pdb::TypeData::Enumeration(data) => {
+                (data.name, data.unique_name, data.count, data.properties)
+            }
+            _ => return Err(type_not_found_error()),
+        };
+
+        // Forward references have the size of their complete type
+        let complete_type_index = self
+            .forwarder_to_complete_type
+            .get(&pdb::TypeIndex(type_index))
+            .map_or(type_index, |complete_type_index| complete_type_index.0);
+        Ok(TypeProperties {
+            name: name.to_string().into_owned(),
+            index: type_index,
+            unique_name: unique_name.map(|unique_name| unique_name.to_string().into_owned()),
+            size: self
+                .type_infos
+                .get(&complete_type_index)
+                .map(|type_info| type_info.size),
+            field_count: field_count as usize,
+            is_forward_reference: properties.forward_reference(),
+        })
+    }
+
+    pub fn reconstruct_type_by_index(
+        &self,
+        type_index: TypeIndex,
+        primitives_flavor: PrimitiveReconstructionFlavor,
+        reconstruct_dependencies: bool,
+        print_access_specifiers: bool,
+        ignore_std_types: bool,
+    ) -> Result<ReconstructedType> {
+        // Populate our `TypeFinder`
+        let mut type_finder = self.type_information.finder();
+        {
+            let mut type_iter = self.type_information.iter();
+            while (type_iter.next()?).is_some() {
+                type_finder.update(&type_iter);
+            }
+        }
+
+        self.reconstruct_types_by_type_index_internal(
+            &type_finder,
+            &[type_index],
+            primitives_flavor,
+            reconstruct_dependencies,
+            print_access_specifiers,
+            ignore_std_types,
+        )
+    }
+
+    /// Reconstruct several types at once. Dependencies shared by these types
+    /// are only emitted once.
+    pub fn reconstruct_types_by_index(
+        &self,
+        type_indices: &[TypeIndex],
+        primitives_flavor: PrimitiveReconstructionFlavor,
+        reconstruct_dependencies: bool,
+        print_access_specifiers: bool,
+        ignore_std_types: bool,
+    ) -> Result<String> {
+        // Populate our `TypeFinder`
+        let mut type_finder = self.type_information.finder();
+        {
+            let mut type_iter = self.type_information.iter();
+            while (type_iter.next()?).is_some() {
+                type_finder.update(&type_iter);
+            }
+        }
+
+        let (reconstruction_output, _) = self.reconstruct_types_by_type_index_internal(
+            &type_finder,
+            type_indices,
+            primitives_flavor,
+            reconstruct_dependencies,
+            print_access_specifiers,
+            ignore_std_types,
+        )?;
+
+        Ok(reconstruction_output)
+    }
+
+    /// List symbols of the default kinds (i.e., all symbols but thunks)
+    pub fn symbol_list(&self) -> Result<SymbolList> {
+        self.symbol_list_by_kind(&SymbolKindFilter::default())
+    }
+
+    /// List symbols of the given kinds. Kinds are filtered before symbols
+    /// sharing the same name are deduplicated, so that symbols hidden behind
+    /// others (e.g., thunks behind public symbols) can be listed.
+    pub fn symbol_list_by_kind(&self, kind_filter: &SymbolKindFilter) -> Result<SymbolList> {
         let mut symbol_heap: BinaryHeap<PrioritizedSymbol> = BinaryHeap::new();
 
         // Modules' private symbols
@@ -425,6 +1517,9 @@ where
 
                 let mut module_symbols = module_info.symbols()?;
                 while let Some(symbol) = module_symbols.next()? {
+                    if !symbol_kind(&symbol).is_some_and(|kind| kind_filter.matches(kind)) {
+                        continue;
+                    }
                     if let Some(symbol_name) = get_symbol_name(&symbol) {
                         symbol_heap.push(PrioritizedSymbol {
                             priority: symbol_priority(&symbol),
@@ -439,6 +1534,9 @@ where
         // Global symbols
         let mut symbol_table = self.global_symbols.iter();
         while let Some(symbol) = symbol_table.next()? {
+            if !symbol_kind(&symbol).is_some_and(|kind| kind_filter.matches(kind)) {
+                continue;
+            }
             if let Some(symbol_name) = get_symbol_name(&symbol) {
                 symbol_heap.push(PrioritizedSymbol {
                     priority: symbol_priority(&symbol),
@@ -473,6 +1571,270 @@ where
         Ok(module_list.collect()?)
     }
 
+    pub fn metadata(&self) -> Result<PdbMetadata> {
+        let pdb_information = self
+            .pdb
+            .write()
+            .expect("lock shouldn't be poisoned")
+            .pdb_information()?;
+        let stream_count = self.stream_list()?.len();
+
+        Ok(PdbMetadata {
+            file_path: self.file_path.clone(),
+            guid: format_guid(&pdb_information.guid),
+            age: pdb_information.age,
+            signature: pdb_information.signature,
+            machine_type: self.machine_type.to_string(),
+            stream_count,
+            type_count: self.type_information.len(),
+            module_count: self.debug_information.modules()?.count()?,
+            is_stripped: self.type_information.is_empty(),
+        })
+    }
+
+    /// Return the name of the PDB file, without its directory
+    pub fn file_name(&self) -> String {
+        self.file_path
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+
+    /// Return the PDB's GUID, which identifies a build
+    pub fn guid(&self) -> Result<String> {
+        let pdb_information = self
+            .pdb
+            .write()
+            .expect("lock shouldn't be poisoned")
+            .pdb_information()?;
+
+        Ok(format_guid(&pdb_information.guid))
+    }
+
+    /// Return the list of MSF streams contained in the PDB, with descriptive
+    /// names when known
+    pub fn stream_list(&self) -> Result<StreamList> {
+        const FIXED_STREAM_NAMES: [&str; 5] = ["Old directory", "PDB", "TPI", "DBI", "IPI"];
+
+        let mut pdb = self.pdb.write().expect("lock shouldn't be poisoned");
+        let mut stream_names: HashMap<StreamIndex, String> = FIXED_STREAM_NAMES
+            .iter()
+            .enumerate()
+            .map(|(stream_index, name)| (stream_index as StreamIndex, name.to_string()))
+            .collect();
+        let pdb_information = pdb.pdb_information()?;
+        for stream_name in pdb_information.stream_names()?.iter() {
+            stream_names.insert(
+                stream_name.stream_id.0,
+                stream_name.name.to_string().into_owned(),
+            );
+        }
+
+        // Note: fall back to probing streams until we reach the end of the
+        // directory if it couldn't be read
+        let stream_count = self
+            .stream_count
+            .map_or(usize::MAX, |stream_count| stream_count as usize);
+        let mut stream_list = StreamList::new();
+        for stream_index in (0..=StreamIndex::MAX).take(stream_count) {
+            let stream_size = match pdb.raw_stream(pdb::StreamIndex(stream_index)) {
+                Ok(stream) => stream.map_or(0, |stream| stream.as_slice().len()),
+                Err(pdb::Error::StreamNotFound(_)) => break,
+                Err(err) => return Err(err.into()),
+            };
+            let stream_name = match stream_names.get(&stream_index) {
+                Some(name) => format!("#{stream_index} {name} (0x{stream_size:x} bytes)"),
+                None => format!("#{stream_index} (0x{stream_size:x} bytes)"),
+            };
+            stream_list.push((stream_name, stream_index));
+        }
+
+        Ok(stream_list)
+    }
+
+    /// Return the raw content of the given MSF stream
+    pub fn stream_data(&self, stream_index: StreamIndex) -> Result<Vec<u8>> {
+        let mut pdb = self.pdb.write().expect("lock shouldn't be poisoned");
+        let stream = pdb.raw_stream(pdb::StreamIndex(stream_index))?;
+
+        Ok(stream.map_or_else(Vec::new, |stream| stream.as_slice().to_vec()))
+    }
+
+    pub fn source_file_list(&self) -> Result<SourceFileList> {
+        let mut pdb = self.pdb.write().expect("lock shouldn't be poisoned");
+        let string_table = match pdb.string_table() {
+            Ok(string_table) => string_table,
+            // No line information available
+            Err(pdb::Error::StreamNameNotFound) => return Ok(vec![]),
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut source_files: BTreeMap<String, ModuleIndex> = BTreeMap::new();
+        let mut modules = self.debug_information.modules()?.enumerate();
+        while let Some((module_index, module)) = modules.next()? {
+            let module_info = match pdb.module_info(&module)? {
+                Some(info) => info,
+                None => {
+                    continue;
+                }
+            };
+
+            let line_program = module_info.line_program()?;
+            let mut files = line_program.files();
+            while let Some(file_info) = files.next()? {
+                let file_path = file_info.name.to_string_lossy(&string_table)?;
+                source_files
+                    .entry(file_path.into_owned())
+                    .or_insert(module_index);
+            }
+        }
+
+        Ok(source_files.into_iter().collect())
+    }
+
+    /// Look up the source files in which the methods of the given type are
+    /// defined, sorted by decreasing number of methods
+    pub fn type_source_locations(&self, type_name: &str) -> Result<Vec<TypeSourceLocation>> {
+        let mut pdb = self.pdb.write().expect("lock shouldn't be poisoned");
+        let string_table = match pdb.string_table() {
+            Ok(string_table) => string_table,
+            // No line information available
+            Err(pdb::Error::StreamNameNotFound) => return Ok(vec![]),
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut source_locations: BTreeMap<String, TypeSourceLocation> = BTreeMap::new();
+        let mut modules = self.debug_information.modules()?;
+        while let Some(module) = modules.next()? {
+            let module_info = match pdb.module_info(&module)? {
+                Some(info) => info,
+                None => {
+                    continue;
+                }
+            };
+
+            let line_program = module_info.line_program()?;
+            let mut module_symbols = module_info.symbols()?;
+            while let Some(symbol) = module_symbols.next()? {
+                let procedure = match symbol.parse() {
+                    Ok(pdb::SymbolData::Procedure(procedure))
+                        if is_method_of(&procedure.name.to_string(), type_name) =>
+                    {
+                        procedure
+                    }
+                    _ => {
+                        continue;
+                    }
+                };
+
+                // Only consider the first line of the method, which is where
+                // it's defined
+                let mut lines = line_program.lines_for_symbol(procedure.offset);
+                if let Some(line_info) = lines.next()? {
+                    let file_info = line_program.get_file_info(line_info.file_index)?;
+                    let file_path = file_info.name.to_string_lossy(&string_table)?;
+                    let source_location = source_locations
+                        .entry(file_path.into_owned())
+                        .or_insert_with_key(|file_path| TypeSourceLocation {
+                            file_path: file_path.clone(),
+                            line: line_info.line_start,
+                            method_count: 0,
+                        });
+                    source_location.line = source_location.line.min(line_info.line_start);
+                    source_location.method_count += 1;
+                }
+            }
+        }
+
+        let mut source_locations: Vec<TypeSourceLocation> =
+            source_locations.into_values().collect();
+        source_locations.sort_by(|a, b| b.method_count.cmp(&a.method_count));
+
+        Ok(source_locations)
+    }
+
+    /// Reconstruct the functions defined in the given source file, along with
+    /// the range of lines they span
+    pub fn reconstruct_source_file_by_path(
+        &self,
+        file_path: &str,
+        primitives_flavor: PrimitiveReconstructionFlavor,
+        print_access_specifiers: bool,
+    ) -> Result<String> {
+        // Populate our `TypeFinder`
+        let mut type_finder = self.type_information.finder();
+        {
+            let mut type_iter = self.type_information.iter();
+            while (type_iter.next()?).is_some() {
+                type_finder.update(&type_iter);
+            }
+        }
+
+        let mut pdb = self.pdb.write().expect("lock shouldn't be poisoned");
+        let string_table = pdb.string_table()?;
+        let mut result = String::default();
+        let mut modules = self.debug_information.modules()?;
+        while let Some(module) = modules.next()? {
+            let module_info = match pdb.module_info(&module)? {
+                Some(info) => info,
+                None => {
+                    continue;
+                }
+            };
+
+            let line_program = module_info.line_program()?;
+            // Cache which file indices refer to the requested file
+            let mut matching_files: HashMap<u32, bool> = HashMap::new();
+            let mut module_symbols = module_info.symbols()?;
+            while let Some(symbol) = module_symbols.next()? {
+                let procedure = match symbol.parse() {
+                    Ok(pdb::SymbolData::Procedure(procedure)) => procedure,
+                    _ => {
+                        continue;
+                    }
+                };
+
+                let mut line_range: Option<(u32, u32)> = None;
+                let mut lines = line_program.lines_for_symbol(procedure.offset);
+                while let Some(line_info) = lines.next()? {
+                    let is_matching_file = match matching_files.get(&line_info.file_index.0) {
+                        Some(is_matching_file) => *is_matching_file,
+                        None => {
+                            let file_info = line_program.get_file_info(line_info.file_index)?;
+                            let is_matching_file =
+                                file_info.name.to_string_lossy(&string_table)? == file_path;
+                            matching_files.insert(line_info.file_index.0, is_matching_file);
+                            is_matching_file
+                        }
+                    };
+                    if is_matching_file {
+                        line_range = Some(match line_range {
+                            None => (line_info.line_start, line_info.line_end),
+                            Some((line_start, line_end)) => (
+                                line_start.min(line_info.line_start),
+                                line_end.max(line_info.line_end),
+                            ),
+                        });
+                    }
+                }
+
+                if let Some((line_start, line_end)) = line_range {
+                    if let Some(reconstructed_symbol) = self.reconstruct_symbol(
+                        &type_finder,
+                        &symbol,
+                        primitives_flavor,
+                        print_access_specifiers,
+                    ) {
+                        writeln!(result, "// {file_path}:{line_start}-{line_end}")?;
+                        writeln!(result, "{reconstructed_symbol}")?;
+                    }
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
     pub fn reconstruct_symbol_by_index(
         &self,
         symbol_index: SymbolIndex,
@@ -650,6 +2012,200 @@ where
         Ok(reconstruction_output)
     }
 
+    /// Reconstruct public and function symbols, one per line and sorted by
+    /// name. Addresses are left out so that the output can be compared
+    /// between builds.
+    pub fn reconstruct_public_symbols(
+        &self,
+        primitives_flavor: PrimitiveReconstructionFlavor,
+        print_access_specifiers: bool,
+    ) -> Result<String> {
+        // Populate our `TypeFinder`
+        let mut type_finder = self.type_information.finder();
+        {
+            let mut type_iter = self.type_information.iter();
+            while (type_iter.next()?).is_some() {
+                type_finder.update(&type_iter);
+            }
+        }
+
+        let mut public_symbols = BTreeMap::new();
+
+        // Global symbols
+        let mut symbol_table = self.global_symbols.iter();
+        while let Some(symbol) = symbol_table.next()? {
+            if let Some((symbol_name, reconstructed_symbol)) = self.reconstruct_public_symbol(
+                &type_finder,
+                &symbol,
+                primitives_flavor,
+                print_access_specifiers,
+            ) {
+                public_symbols.insert(symbol_name, reconstructed_symbol);
+            }
+        }
+
+        // Modules' private symbols
+        {
+            let mut pdb = self.pdb.write().expect("lock shouldn't be poisoned");
+            let mut modules = self.debug_information.modules()?;
+            while let Some(module) = modules.next()? {
+                if let Some(module_info) = pdb.module_info(&module)? {
+                    let mut module_symbols = module_info.symbols()?;
+                    while let Some(symbol) = module_symbols.next()? {
+                        if let Some((symbol_name, reconstructed_symbol)) = self
+                            .reconstruct_public_symbol(
+                                &type_finder,
+                                &symbol,
+                                primitives_flavor,
+                                print_access_specifiers,
+                            )
+                        {
+                            public_symbols.insert(symbol_name, reconstructed_symbol);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut reconstruction_output = String::new();
+        for reconstructed_symbol in public_symbols.values() {
+            writeln!(&mut reconstruction_output, "{}", reconstructed_symbol)?;
+        }
+
+        Ok(reconstruction_output)
+    }
+
+    /// Find the symbol that contains (or precedes) the given RVA
+    pub fn symbol_at_rva(&self, rva: u32) -> Result<SymbolAtAddress> {
+        let mut symbol_addresses: BTreeMap<u32, String> = BTreeMap::new();
+
+        // Modules' procedures, inserted first as their names are undecorated
+        {
+            let mut pdb = self.pdb.write().expect("lock shouldn't be poisoned");
+            let mut modules = self.debug_information.modules()?;
+            while let Some(module) = modules.next()? {
+                if let Some(module_info) = pdb.module_info(&module)? {
+                    let mut module_symbols = module_info.symbols()?;
+                    while let Some(symbol) = module_symbols.next()? {
+                        if let Ok(pdb::SymbolData::Procedure(procedure)) = symbol.parse() {
+                            if let Some(procedure_rva) =
+                                symbol_rva(&procedure.offset, &self.sections)
+                            {
+                                symbol_addresses
+                                    .entry(procedure_rva)
+                                    .or_insert_with(|| procedure.name.to_string().into_owned());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Global symbols
+        let mut symbol_table = self.global_symbols.iter();
+        while let Some(symbol) = symbol_table.next()? {
+            let (symbol_offset, symbol_name) = match symbol.parse() {
+                Ok(pdb::SymbolData::Procedure(procedure)) => (procedure.offset, procedure.name),
+                Ok(pdb::SymbolData::Data(data)) => (data.offset, data.name),
+                Ok(pdb::SymbolData::Public(data)) => (data.offset, data.name),
+                _ => {
+                    continue;
+                }
+            };
+            if let Some(global_symbol_rva) = symbol_rva(&symbol_offset, &self.sections) {
+                symbol_addresses
+                    .entry(global_symbol_rva)
+                    .or_insert_with(|| symbol_name.to_string().into_owned());
+            }
+        }
+
+        symbol_addresses
+            .range(..=rva)
+            .next_back()
+            .map(|(closest_symbol_rva, symbol_name)| SymbolAtAddress {
+                name: symbol_name.clone(),
+                rva: *closest_symbol_rva,
+                offset: rva - closest_symbol_rva,
+            })
+            .ok_or_else(|| {
+                ResymCoreError::SymbolNotFoundError(format!("No symbol found at RVA 0x{rva:x}"))
+            })
+    }
+
+    pub fn symbol_location_by_index(&self, symbol_index: SymbolIndex) -> Result<SymbolLocation> {
+        // Check which module the symbol is from
+        if symbol_index.0 == GLOBAL_MODULE_INDEX {
+            // Global symbols
+            let mut symbol_table = self.global_symbols.iter();
+            while let Some(symbol) = symbol_table.next()? {
+                if symbol.index().0 == symbol_index.1 {
+                    return self.symbol_location(&symbol).ok_or_else(|| {
+                        ResymCoreError::SymbolNotFoundError(format!(
+                            "Symbol #{:?} has no address",
+                            symbol_index
+                        ))
+                    });
+                }
+            }
+        } else if let Some(module) = self.debug_information.modules()?.nth(symbol_index.0)? {
+            // Modules' private symbols
+            let mut pdb = self.pdb.write().expect("lock shouldn't be poisoned");
+            if let Some(module_info) = pdb.module_info(&module)? {
+                let mut module_symbols = module_info.symbols_at(symbol_index.1.into())?;
+                while let Some(symbol) = module_symbols.next()? {
+                    if symbol.index().0 == symbol_index.1 {
+                        return self.symbol_location(&symbol).ok_or_else(|| {
+                            ResymCoreError::SymbolNotFoundError(format!(
+                                "Symbol #{:?} has no address",
+                                symbol_index
+                            ))
+                        });
+                    }
+                }
+            }
+        }
+
+        Err(ResymCoreError::SymbolNotFoundError(format!(
+            "Symbol #{:?} not found",
+            symbol_index
+        )))
+    }
+
+    pub fn symbol_location_by_name(&self, symbol_name: &str) -> Result<SymbolLocation> {
+        // Modules' private symbols, looked up first as they carry sizes
+        {
+            let mut pdb = self.pdb.write().expect("lock shouldn't be poisoned");
+            let mut modules = self.debug_information.modules()?;
+            while let Some(module) = modules.next()? {
+                if let Some(module_info) = pdb.module_info(&module)? {
+                    let mut module_symbols = module_info.symbols()?;
+                    while let Some(symbol) = module_symbols.next()? {
+                        if get_symbol_name(&symbol).as_deref() == Some(symbol_name) {
+                            if let Some(symbol_location) = self.symbol_location(&symbol) {
+                                return Ok(symbol_location);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Global symbols
+        let mut symbol_table = self.global_symbols.iter();
+        while let Some(symbol) = symbol_table.next()? {
+            if get_symbol_name(&symbol).as_deref() == Some(symbol_name) {
+                if let Some(symbol_location) = self.symbol_location(&symbol) {
+                    return Ok(symbol_location);
+                }
+            }
+        }
+
+        Err(ResymCoreError::SymbolNotFoundError(format!(
+            "Symbol '{}' not found",
+            symbol_name
+        )))
+    }
+
     pub fn reconstruct_module_by_path(
         &self,
         module_path: &str,
@@ -724,51 +2280,162 @@ where
         Ok(result)
     }
 
-    fn reconstruct_type_by_type_index_internal(
+    /// Reconstruct the given types (which may include typedefs), the returned
+    /// cross-references are the ones of the first type
+    fn reconstruct_types_by_type_index_internal(
         &self,
         type_finder: &pdb::TypeFinder,
-        type_index: TypeIndex,
+        type_indices: &[TypeIndex],
         primitives_flavor: PrimitiveReconstructionFlavor,
         reconstruct_dependencies: bool,
         print_access_specifiers: bool,
         ignore_std_types: bool,
     ) -> Result<ReconstructedType> {
-        let fmt_configuration = DataFormatConfiguration {
-            print_access_specifiers,
-        };
-        let mut type_data = pdb_types::Data::new(ignore_std_types);
+        if type_indices
+            .iter()
+            .all(|type_index| self.typedef(*type_index).is_none())
+        {
+            return self.reconstruct_udts_by_type_index_internal(
+                type_finder,
+                type_indices,
+                primitives_flavor,
+                reconstruct_dependencies,
+                print_access_specifiers,
+                ignore_std_types,
+            );
+        }
+
+        // Typedefs don't have type records: reconstruct the types they refer
+        // to, followed by alias declarations
+        let mut udt_indices: Vec<TypeIndex> = vec![];
+        let mut alias_declarations = String::new();
+        for type_index in type_indices {
+            let Some(typedef) = self.typedef(*type_index) else {
+                udt_indices.push(*type_index);
+                continue;
+            };
 
-        // If dependencies aren't needed, only process the given type index and return
-        if !reconstruct_dependencies {
             let mut needed_types = pdb_types::NeededTypeSet::new();
-            type_data.add(
+            let (type_left, type_right) = type_name(
                 type_finder,
                 &self.forwarder_to_complete_type,
-                type_index.into(),
+                &self.unnamed_types,
+                typedef.type_index.into(),
                 &primitives_flavor,
+                &self.settings.primitive_type_mapping,
                 &mut needed_types,
             )?;
+            let mut needed_type_indices: Vec<TypeIndex> =
+                needed_types.into_iter().map(|e| e.0 .0).collect();
+            // Keep the output deterministic
+            needed_type_indices.sort_unstable();
+            udt_indices.extend(needed_type_indices);
+            writeln!(
+                alias_declarations,
+                "using {} = {type_left}{type_right};",
+                typedef.name
+            )?;
+        }
+        let mut processed_type_set = HashSet::new();
+        udt_indices.retain(|type_index| processed_type_set.insert(*type_index));
 
-            let mut reconstruction_output = String::new();
-            type_data.reconstruct(
-                &fmt_configuration,
-                &Default::default(),
-                &mut reconstruction_output,
+        let (mut reconstruction_output, xrefs_from) = self
+            .reconstruct_udts_by_type_index_internal(
+                type_finder,
+                &udt_indices,
+                primitives_flavor,
+                reconstruct_dependencies,
+                print_access_specifiers,
+                ignore_std_types,
             )?;
-            let needed_types: Vec<TypeIndex> = needed_types.into_iter().map(|e| e.0 .0).collect();
-            let xrefs_from = self.type_list_from_type_indices(&needed_types);
+        writeln!(reconstruction_output)?;
+        reconstruction_output.push_str(&alias_declarations);
+
+        Ok((reconstruction_output, xrefs_from))
+    }
+
+    /// Configuration used to format the reconstructed types, from the current
+    /// settings
+    fn format_configuration(&self, print_access_specifiers: bool) -> DataFormatConfiguration {
+        DataFormatConfiguration {
+            print_access_specifiers,
+            code_format: self.settings.code_format.clone(),
+            renames: self.rename_map.clone(),
+            type_notes: self.type_notes.clone(),
+            member_filter: self.settings.member_filter.clone(),
+            number_format: self.settings.number_format,
+            doxygen_comments: self.settings.doxygen_comments,
+            flag_enum_comments: self.settings.flag_enum_comments,
+            enum_value_order: self.settings.enum_value_order,
+            source_pdb_name: self.file_name(),
+            image_base: self.settings.image_base,
+        }
+    }
+
+    /// Reconstruct the given user-defined types, the returned cross-references
+    /// are the ones of the first type
+    fn reconstruct_udts_by_type_index_internal(
+        &self,
+        type_finder: &pdb::TypeFinder,
+        type_indices: &[TypeIndex],
+        primitives_flavor: PrimitiveReconstructionFlavor,
+        reconstruct_dependencies: bool,
+        print_access_specifiers: bool,
+        ignore_std_types: bool,
+    ) -> Result<ReconstructedType> {
+        let fmt_configuration = self.format_configuration(print_access_specifiers);
+        let mut type_data = pdb_types::Data::new(ignore_std_types);
+
+        // If dependencies aren't needed, only process the given type indices and return
+        if !reconstruct_dependencies {
+            let mut xrefs_from = None;
+            for type_index in type_indices {
+                let mut needed_types = pdb_types::NeededTypeSet::new();
+                type_data.add(
+                    type_finder,
+                    &self.forwarder_to_complete_type,
+                    &self.unnamed_types,
+                    (*type_index).into(),
+                    &primitives_flavor,
+                    &self.settings.primitive_type_mapping,
+                    &mut needed_types,
+                )?;
+                // Declare referenced types, so the output is self-describing
+                // without being as long as with their definitions
+                if self.settings.forward_declare_dependencies {
+                    for (needed_type_index, _) in &needed_types {
+                        if !type_indices.contains(&needed_type_index.0) {
+                            type_data.add_as_forward_declaration(
+                                type_finder,
+                                &self.unnamed_types,
+                                *needed_type_index,
+                            )?;
+                        }
+                    }
+                }
+                if xrefs_from.is_none() {
+                    let needed_types: Vec<TypeIndex> =
+                        needed_types.into_iter().map(|e| e.0 .0).collect();
+                    xrefs_from = Some(self.type_list_from_type_indices(&needed_types)?);
+                }
+            }
+
+            self.resolve_static_field_rvas(&mut type_data)?;
+            let mut reconstruction_output = String::new();
+            type_data.reconstruct(&fmt_configuration, &[], &mut reconstruction_output)?;
 
-            return Ok((reconstruction_output, xrefs_from));
+            return Ok((reconstruction_output, xrefs_from.unwrap_or_default()));
         }
 
         let mut xrefs_from = vec![];
+        let mut include_directives = BTreeSet::new();
         // Add all the needed types iteratively until we're done
         let mut type_dependency_map: HashMap<TypeIndex, Vec<(TypeIndex, bool)>> = HashMap::new();
         {
             let dep_start = Instant::now();
 
-            // Add the requested type first
-            let mut types_to_process: VecDeque<TypeIndex> = VecDeque::from([type_index]);
+            // Add the requested types first
+            let mut types_to_process: VecDeque<TypeIndex> = type_indices.iter().copied().collect();
             let mut processed_type_set = HashSet::new();
             // Keep processing new types until there's nothing to process
             while let Some(needed_type_index) = types_to_process.pop_front() {
@@ -777,26 +2444,61 @@ where
                     continue;
                 }
 
+                // Replace opaque dependencies with includes or forward declarations
+                if !type_indices.contains(&needed_type_index)
+                    && !self.settings.opaque_type_rules.is_empty()
+                {
+                    let (needed_type_name, _) = type_name(
+                        type_finder,
+                        &self.forwarder_to_complete_type,
+                        &self.unnamed_types,
+                        needed_type_index.into(),
+                        &primitives_flavor,
+                        &self.settings.primitive_type_mapping,
+                        &mut pdb_types::NeededTypeSet::new(),
+                    )?;
+                    if let Some(rule) = self.settings.opaque_type_rules.find(&needed_type_name) {
+                        if let Some(include_directive) = rule.include_directive() {
+                            include_directives.insert(include_directive);
+                        } else {
+                            type_data.add_as_forward_declaration(
+                                type_finder,
+                                &self.unnamed_types,
+                                needed_type_index.into(),
+                            )?;
+                        }
+                        processed_type_set.insert(needed_type_index);
+                        continue;
+                    }
+                }
+
                 // Add the type
                 let mut needed_types = pdb_types::NeededTypeSet::new();
                 type_data.add(
                     type_finder,
                     &self.forwarder_to_complete_type,
+                    &self.unnamed_types,
                     needed_type_index.into(),
                     &primitives_flavor,
+                    &self.settings.primitive_type_mapping,
                     &mut needed_types,
                 )?;
-                // Initialize only once, the first time (i.e., for the requested type)
+                // Initialize only once, the first time (i.e., for the first
+                // requested type)
                 if xrefs_from.is_empty() {
                     let needed_types: Vec<TypeIndex> =
                         needed_types.iter().map(|e| e.0 .0).collect();
-                    xrefs_from = self.type_list_from_type_indices(&needed_types);
+                    xrefs_from = self.type_list_from_type_indices(&needed_types)?;
                 }
 
                 for (type_index, is_pointer) in &needed_types {
                     // Add forward declaration for types referenced by pointers
                     if *is_pointer {
-                        type_data.add_as_forward_declaration(type_finder, *type_index)?;
+                        type_data.add_as_forward_declaration(
+                            type_finder,
+                            &self.unnamed_types,
+                            *type_index,
+                        )?;
                     }
 
                     // Update type dependency map
@@ -819,29 +2521,42 @@ where
             );
         }
 
-        // Deduce type "depth" from the dependency map
-        let type_depth_map = compute_type_depth_map(&type_dependency_map, &[type_index]);
+        // Define types after the types they depend on
+        let (type_order, dependency_cycles) =
+            compute_type_order(&type_dependency_map, type_indices);
+        self.break_dependency_cycles(
+            &mut type_data,
+            type_finder,
+            primitives_flavor,
+            &dependency_cycles,
+        )?;
 
+        self.resolve_static_field_rvas(&mut type_data)?;
         let mut reconstruction_output = String::new();
-        type_data.reconstruct(
-            &fmt_configuration,
-            &type_depth_map,
-            &mut reconstruction_output,
-        )?;
+        if !include_directives.is_empty() {
+            reconstruction_output.push('\n');
+        }
+        for include_directive in include_directives {
+            let _r = writeln!(&mut reconstruction_output, "{include_directive}");
+        }
+        type_data.reconstruct(&fmt_configuration, &type_order, &mut reconstruction_output)?;
 
         Ok((reconstruction_output, xrefs_from))
     }
 
+    /// Reconstruct all the types found in the PDB. Reconstruction stops with
+    /// `OperationCancelledError` if `cancellation_token` gets cancelled.
     pub fn reconstruct_all_types(
         &self,
         primitives_flavor: PrimitiveReconstructionFlavor,
         print_access_specifiers: bool,
         ignore_std_types: bool,
+        cancellation_token: &CancellationToken,
     ) -> Result<String> {
         let mut type_data = pdb_types::Data::new(ignore_std_types);
         let mut processed_types = Vec::new();
         let mut type_dependency_map: HashMap<TypeIndex, Vec<(TypeIndex, bool)>> = HashMap::new();
-        {
+        let type_order = {
             let mut type_finder = self.type_information.finder();
             // Populate our `TypeFinder`
             let mut type_iter = self.type_information.iter();
@@ -852,6 +2567,7 @@ where
             // Add the requested types
             let mut type_iter = self.type_information.iter();
             while let Some(item) = type_iter.next()? {
+                cancellation_token.check()?;
                 let mut needed_types = pdb_types::NeededTypeSet::new();
                 // Note(ergelet): try to get the complete type's index here.
                 // This avoids adding empty "forward reference" type index which
@@ -864,8 +2580,10 @@ where
                 let result = type_data.add(
                     &type_finder,
                     &self.forwarder_to_complete_type,
+                    &self.unnamed_types,
                     complete_type_index,
                     &primitives_flavor,
+                    &self.settings.primitive_type_mapping,
                     &mut needed_types,
                 );
 
@@ -886,7 +2604,11 @@ where
                     for (type_index, is_pointer) in &needed_types {
                         // Add forward declaration for types referenced by pointers
                         if *is_pointer {
-                            type_data.add_as_forward_declaration(&type_finder, *type_index)?;
+                            type_data.add_as_forward_declaration(
+                                &type_finder,
+                                &self.unnamed_types,
+                                *type_index,
+                            )?;
                         }
 
                         // Update type dependency map
@@ -901,104 +2623,311 @@ where
                     }
                 }
             }
+
+            // Define types after the types they depend on
+            let (type_order, dependency_cycles) =
+                compute_type_order(&type_dependency_map, &processed_types);
+            self.break_dependency_cycles(
+                &mut type_data,
+                &type_finder,
+                primitives_flavor,
+                &dependency_cycles,
+            )?;
+            type_order
+        };
+
+        self.resolve_static_field_rvas(&mut type_data)?;
+        cancellation_token.check()?;
+        let mut reconstruction_output = String::new();
+        type_data.reconstruct(
+            &self.format_configuration(print_access_specifiers),
+            &type_order,
+            &mut reconstruction_output,
+        )?;
+
+        Ok(reconstruction_output)
+    }
+
+    /// Reconstruct all the enumerations found in the PDB, sorted by name
+    pub fn reconstruct_all_enums(
+        &self,
+        primitives_flavor: PrimitiveReconstructionFlavor,
+        ignore_std_types: bool,
+    ) -> Result<String> {
+        // Populate our `TypeFinder`
+        let mut type_finder = self.type_information.finder();
+        {
+            let mut type_iter = self.type_information.iter();
+            while (type_iter.next()?).is_some() {
+                type_finder.update(&type_iter);
+            }
+        }
+
+        let type_list = self.type_list()?;
+        let mut enum_list: Vec<&(String, TypeIndex)> = type_list
+            .iter()
+            .filter(|(type_name, type_index)| {
+                self.type_infos
+                    .get(type_index)
+                    .map(|type_info| type_info.kind == TypeKind::Enum)
+                    .unwrap_or_default()
+                    && !(ignore_std_types && type_name.starts_with("std::"))
+            })
+            .collect();
+        enum_list.sort();
+
+        let mut type_data = pdb_types::Data::new(ignore_std_types);
+        let mut enum_indices: Vec<pdb::TypeIndex> = Vec::with_capacity(enum_list.len());
+        for (_, type_index) in enum_list {
+            type_data.add(
+                &type_finder,
+                &self.forwarder_to_complete_type,
+                &self.unnamed_types,
+                (*type_index).into(),
+                &primitives_flavor,
+                &self.settings.primitive_type_mapping,
+                &mut pdb_types::NeededTypeSet::new(),
+            )?;
+            enum_indices.push((*type_index).into());
+        }
+
+        let mut reconstruction_output = String::new();
+        type_data.reconstruct(
+            &self.format_configuration(false),
+            // Note: enums don't depend on each other, keep them sorted by name
+            &enum_indices,
+            &mut reconstruction_output,
+        )?;
+
+        Ok(reconstruction_output)
+    }
+
+    pub fn get_xrefs_for_type(&self, type_index: TypeIndex) -> Result<TypeList> {
+        self.ensure_xref_cache()?;
+
+        // Query xref cache
+        if let Some(xref_list) = self
+            .xref_to_map
+            .read()
+            .expect("lock shouldn't be poisoned")
+            .get(&type_index)
+        {
+            // Convert the xref list into a proper Name+TypeIndex tuple list
+            let xref_type_list = self.type_list_from_type_indices(&xref_list)?;
+
+            Ok(xref_type_list)
+        } else {
+            // No xrefs found for the given type
+            Ok(vec![])
+        }
+    }
+
+    /// Retrieve the list of all types whose reconstruction would pull the
+    /// given type in as a dependency (i.e., direct and indirect xrefs)
+    pub fn get_reverse_dependencies_for_type(&self, type_index: TypeIndex) -> Result<TypeList> {
+        self.ensure_xref_cache()?;
+
+        let xref_to_map = self.xref_to_map.read().expect("lock shouldn't be poisoned");
+        let mut reverse_dependencies = HashSet::new();
+        let mut types_to_visit = VecDeque::from([type_index]);
+        while let Some(current_type_index) = types_to_visit.pop_front() {
+            if let Some(xref_list) = xref_to_map.get(&current_type_index) {
+                for xref_type_index in xref_list.iter() {
+                    if *xref_type_index != type_index
+                        && reverse_dependencies.insert(*xref_type_index)
+                    {
+                        types_to_visit.push_back(*xref_type_index);
+                    }
+                }
+            }
         }
+        let reverse_dependencies: Vec<TypeIndex> = reverse_dependencies.into_iter().collect();
 
-        // Deduce type "depth" from the dependency map
-        let type_depth_map = compute_type_depth_map(&type_dependency_map, &processed_types);
-
-        let mut reconstruction_output = String::new();
-        type_data.reconstruct(
-            &DataFormatConfiguration {
-                print_access_specifiers,
-            },
-            &type_depth_map,
-            &mut reconstruction_output,
-        )?;
-
-        Ok(reconstruction_output)
+        self.type_list_from_type_indices(&reverse_dependencies)
     }
 
-    pub fn get_xrefs_for_type(&self, type_index: TypeIndex) -> Result<TypeList> {
-        // Generate xref cache if empty
-        if self
-            .xref_to_map
-            .read()
-            .expect("lock shouldn't be poisoned")
-            .is_empty()
-        {
-            // Populate our `TypeFinder`
-            let mut type_finder = self.type_information.finder();
-            {
-                let mut type_iter = self.type_information.iter();
-                while (type_iter.next()?).is_some() {
-                    type_finder.update(&type_iter);
+    /// Build the graph of type-to-type references reachable from the given
+    /// type, or the graph of all types if `root_type_index` is `None`
+    pub fn type_dependency_graph(
+        &self,
+        root_type_index: Option<TypeIndex>,
+        ignore_std_types: bool,
+    ) -> Result<TypeDependencyGraph> {
+        let type_list = self.type_list()?;
+        let type_names: HashMap<TypeIndex, &String> = type_list
+            .iter()
+            .map(|(type_name, type_index)| (*type_index, type_name))
+            .collect();
+        let is_ignored = |type_index: &TypeIndex| match type_names.get(type_index) {
+            Some(type_name) => ignore_std_types && type_name.starts_with("std::"),
+            // Not a complete type
+            None => true,
+        };
+
+        let mut types_to_process: VecDeque<TypeIndex> = match root_type_index {
+            Some(type_index) => {
+                if !type_names.contains_key(&type_index) {
+                    return Err(ResymCoreError::InvalidParameterError(format!(
+                        "Type #0x{type_index:x} not found"
+                    )));
                 }
+                VecDeque::from([type_index])
             }
+            None => self
+                .type_list()?
+                .iter()
+                .map(|(_, type_index)| *type_index)
+                .collect(),
+        };
 
-            // Iterate through all types
-            let xref_map: DashMap<TypeIndex, Vec<TypeIndex>> = DashMap::default();
+        // Populate our `TypeFinder`
+        let mut type_finder = self.type_information.finder();
+        {
             let mut type_iter = self.type_information.iter();
-            while let Some(type_item) = type_iter.next()? {
-                let current_type_index = type_item.index();
-                // Reconstruct type and retrieve referenced types
-                let mut type_data = pdb_types::Data::new(false);
-                let mut needed_types = pdb_types::NeededTypeSet::new();
-                let result = type_data.add(
-                    &type_finder,
-                    &self.forwarder_to_complete_type,
-                    current_type_index,
-                    &PrimitiveReconstructionFlavor::Raw,
-                    &mut needed_types,
-                );
-                // Process result
-                if let Err(err) = result {
-                    // Handle error
-                    match err {
-                        ResymCoreError::PdbError(err) => {
-                            // Ignore this kind of error since some particular PDB features might not be supported.
-                            // This allows the recontruction to go through with the correctly reconstructed types.
-                            log::warn!(
-                                "Failed to reconstruct type with index {current_type_index}: {err}"
-                            )
-                        }
-                        _ => return Err(err),
-                    }
-                }
+            while (type_iter.next()?).is_some() {
+                type_finder.update(&type_iter);
+            }
+        }
 
-                par_iter_if_available!(needed_types).for_each(|(t, _)| {
-                    if let Some(mut xref_list) = xref_map.get_mut(&t.0) {
-                        xref_list.push(current_type_index.0);
-                    } else {
-                        xref_map.insert(t.0, vec![current_type_index.0]);
+        let mut graph = TypeDependencyGraph::default();
+        let mut processed_type_set = HashSet::new();
+        while let Some(type_index) = types_to_process.pop_front() {
+            if is_ignored(&type_index) || !processed_type_set.insert(type_index) {
+                continue;
+            }
+
+            // Reconstruct type and retrieve referenced types
+            let mut type_data = pdb_types::Data::new(ignore_std_types);
+            let mut needed_types = pdb_types::NeededTypeSet::new();
+            if let Err(err) = type_data.add(
+                &type_finder,
+                &self.forwarder_to_complete_type,
+                &self.unnamed_types,
+                type_index.into(),
+                &PrimitiveReconstructionFlavor::Raw,
+                &self.settings.primitive_type_mapping,
+                &mut needed_types,
+            ) {
+                match err {
+                    ResymCoreError::PdbError(err) => {
+                        // Keep the type in the graph, without its references
+                        log::warn!("Failed to reconstruct type with index {type_index}: {err}")
                     }
-                });
+                    _ => return Err(err),
+                }
             }
 
-            // Update cache
-            if let Ok(mut xref_map_ref) = self.xref_to_map.write() {
-                *xref_map_ref = xref_map;
+            for (needed_type_index, is_pointer) in needed_types {
+                let needed_type_index = needed_type_index.0;
+                if needed_type_index == type_index || is_ignored(&needed_type_index) {
+                    continue;
+                }
+                graph
+                    .edges
+                    .push((type_index, needed_type_index, is_pointer));
+                types_to_process.push_back(needed_type_index);
             }
+            graph
+                .nodes
+                .push((type_names[&type_index].clone(), type_index));
         }
+        // Sort the graph to get a deterministic output
+        graph.nodes.sort_unstable();
+        graph.edges.sort_unstable();
 
-        // Query xref cache
-        if let Some(xref_list) = self
+        Ok(graph)
+    }
+
+    /// Build the graph of type-to-type references reachable from the type
+    /// with the given name
+    pub fn type_dependency_graph_by_name(
+        &self,
+        type_name: &str,
+        ignore_std_types: bool,
+    ) -> Result<TypeDependencyGraph> {
+        let type_index = self
+            .type_list()?
+            .iter()
+            .find(|(name, _)| name == type_name)
+            .map(|(_, type_index)| *type_index)
+            .ok_or_else(|| ResymCoreError::TypeNameNotFoundError(type_name.to_owned()))?;
+
+        self.type_dependency_graph(Some(type_index), ignore_std_types)
+    }
+
+    /// Generate xref cache if empty
+    fn ensure_xref_cache(&self) -> Result<()> {
+        if !self
             .xref_to_map
             .read()
             .expect("lock shouldn't be poisoned")
-            .get(&type_index)
+            .is_empty()
         {
-            // Convert the xref list into a proper Name+TypeIndex tuple list
-            let xref_type_list = self.type_list_from_type_indices(&xref_list);
+            return Ok(());
+        }
 
-            Ok(xref_type_list)
-        } else {
-            // No xrefs found for the given type
-            Ok(vec![])
+        // Populate our `TypeFinder`
+        let mut type_finder = self.type_information.finder();
+        {
+            let mut type_iter = self.type_information.iter();
+            while (type_iter.next()?).is_some() {
+                type_finder.update(&type_iter);
+            }
+        }
+
+        // Iterate through all types
+        let xref_map: DashMap<TypeIndex, Vec<TypeIndex>> = DashMap::default();
+        let mut type_iter = self.type_information.iter();
+        while let Some(type_item) = type_iter.next()? {
+            let current_type_index = type_item.index();
+            // Reconstruct type and retrieve referenced types
+            let mut type_data = pdb_types::Data::new(false);
+            let mut needed_types = pdb_types::NeededTypeSet::new();
+            let result = type_data.add(
+                &type_finder,
+                &self.forwarder_to_complete_type,
+                &self.unnamed_types,
+                current_type_index,
+                &PrimitiveReconstructionFlavor::Raw,
+                &self.settings.primitive_type_mapping,
+                &mut needed_types,
+            );
+            // Process result
+            if let Err(err) = result {
+                // Handle error
+                match err {
+                    ResymCoreError::PdbError(err) => {
+                        // Ignore this kind of error since some particular PDB features might not be supported.
+                        // This allows the recontruction to go through with the correctly reconstructed types.
+                        log::warn!(
+                            "Failed to reconstruct type with index {current_type_index}: {err}"
+                        )
+                    }
+                    _ => return Err(err),
+                }
+            }
+
+            par_iter_if_available!(needed_types).for_each(|(t, _)| {
+                if let Some(mut xref_list) = xref_map.get_mut(&t.0) {
+                    xref_list.push(current_type_index.0);
+                } else {
+                    xref_map.insert(t.0, vec![current_type_index.0]);
+                }
+            });
+        }
+
+        // Update cache
+        if let Ok(mut xref_map_ref) = self.xref_to_map.write() {
+            *xref_map_ref = xref_map;
         }
+
+        Ok(())
     }
 
-    fn type_list_from_type_indices(&self, type_indices: &[TypeIndex]) -> TypeList {
-        par_iter_if_available!(self.complete_type_list)
+    fn type_list_from_type_indices(&self, type_indices: &[TypeIndex]) -> Result<TypeList> {
+        let type_list: &[(String, TypeIndex)] = &self.type_list()?;
+
+        Ok(par_iter_if_available!(type_list)
             .filter_map(|(type_name, type_index)| {
                 if type_indices.contains(type_index) {
                     Some((type_name.clone(), *type_index))
@@ -1006,7 +2935,176 @@ where
                     None
                 }
             })
-            .collect()
+            .collect())
+    }
+
+    /// Forward declare the types closing the given dependency cycles (see
+    /// `compute_type_order`), and log the cycles
+    fn break_dependency_cycles<'t>(
+        &self,
+        type_data: &mut pdb_types::Data<'t>,
+        type_finder: &pdb::TypeFinder<'t>,
+        primitives_flavor: PrimitiveReconstructionFlavor,
+        dependency_cycles: &[Vec<TypeIndex>],
+    ) -> Result<()> {
+        for dependency_cycle in dependency_cycles {
+            type_data.add_as_forward_declaration(
+                type_finder,
+                &self.unnamed_types,
+                dependency_cycle[0].into(),
+            )?;
+
+            let type_names = dependency_cycle
+                .iter()
+                .map(|type_index| {
+                    type_name(
+                        type_finder,
+                        &self.forwarder_to_complete_type,
+                        &self.unnamed_types,
+                        (*type_index).into(),
+                        &primitives_flavor,
+                        &self.settings.primitive_type_mapping,
+                        &mut pdb_types::NeededTypeSet::new(),
+                    )
+                    .map(|(type_name, _)| type_name)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            log::info!(
+                "Broke dependency cycle with a forward declaration of '{}': {}",
+                type_names[0],
+                type_names.join(" -> ")
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Annotate static data members with their RVA, if enabled
+    fn resolve_static_field_rvas(&self, type_data: &mut pdb_types::Data) -> Result<()> {
+        if self.settings.print_static_member_rvas && type_data.has_static_fields() {
+            type_data.resolve_static_field_rvas(self.global_data_rvas()?);
+        }
+
+        Ok(())
+    }
+
+    /// Map the qualified names of global data symbols to their RVA. The map
+    /// is built once, as the global symbol stream doesn't change.
+    fn global_data_rvas(&self) -> Result<&HashMap<String, u32>> {
+        if let Some(global_data_rvas) = self.global_data_rvas.get() {
+            return Ok(global_data_rvas);
+        }
+
+        let mut global_data_rvas = HashMap::new();
+        let mut symbol_table = self.global_symbols.iter();
+        while let Some(symbol) = symbol_table.next()? {
+            if let Ok(pdb::SymbolData::Data(data)) = symbol.parse() {
+                if let Some(data_rva) = symbol_rva(&data.offset, &self.sections) {
+                    global_data_rvas
+                        .entry(data.name.to_string().into_owned())
+                        .or_insert(data_rva);
+                }
+            }
+        }
+
+        Ok(self.global_data_rvas.get_or_init(|| global_data_rvas))
+    }
+
+    /// Reconstruct the given symbol if it's a public or function symbol.
+    /// Returns the symbol's name and its declaration without trailing comments.
+    fn reconstruct_public_symbol(
+        &self,
+        type_finder: &pdb::ItemFinder<'_, pdb::TypeIndex>,
+        symbol: &pdb::Symbol<'_>,
+        primitives_flavor: PrimitiveReconstructionFlavor,
+        print_access_specifiers: bool,
+    ) -> Option<(String, String)> {
+        match symbol.parse().ok()? {
+            pdb::SymbolData::Public(_)
+            | pdb::SymbolData::Export(_)
+            | pdb::SymbolData::Procedure(_) => {}
+            _ => return None,
+        }
+
+        let symbol_name = get_symbol_name(symbol)?;
+        let reconstructed_symbol = self.reconstruct_symbol(
+            type_finder,
+            symbol,
+            primitives_flavor,
+            print_access_specifiers,
+        )?;
+        // Strip trailing comments (i.e., RVAs and code sizes)
+        let reconstructed_symbol = match reconstructed_symbol.split_once(" //") {
+            Some((declaration, _)) => declaration.to_owned(),
+            None => reconstructed_symbol,
+        };
+
+        Some((symbol_name, reconstructed_symbol))
+    }
+
+    fn symbol_location(&self, symbol: &pdb::Symbol<'_>) -> Option<SymbolLocation> {
+        let (symbol_offset, symbol_name, size) = match symbol.parse().ok()? {
+            pdb::SymbolData::Procedure(procedure) => {
+                (procedure.offset, procedure.name, Some(procedure.len))
+            }
+            pdb::SymbolData::Data(data) => (data.offset, data.name, None),
+            pdb::SymbolData::Public(data) => (data.offset, data.name, None),
+            pdb::SymbolData::Thunk(thunk) => (thunk.offset, thunk.name, Some(thunk.len.into())),
+            _ => {
+                return None;
+            }
+        };
+        let rva = symbol_rva(&symbol_offset, &self.sections)?;
+        // Prefer the PE's section headers, when available
+        let section_name = self
+            .pe_file
+            .as_ref()
+            .and_then(|pe_file| pe_file.section_at_rva(rva))
+            .map(|section| section.name.clone())
+            .or_else(|| {
+                self.sections
+                    .get((symbol_offset.section - 1) as usize)
+                    .map(|section_header| section_header.name().to_string())
+            })
+            .unwrap_or_default();
+        let (export_names, outside_image) = match &self.pe_file {
+            Some(pe_file) => (
+                pe_file
+                    .exports_at_rva(rva)
+                    .iter()
+                    .map(|export| export.display_name())
+                    .collect(),
+                !pe_file.contains_rva(rva),
+            ),
+            None => (vec![], false),
+        };
+
+        Some(SymbolLocation {
+            name: symbol_name.to_string().into_owned(),
+            rva,
+            section_name,
+            size,
+            export_names,
+            outside_image,
+        })
+    }
+
+    /// Format the RVA comment printed before reconstructed symbols, along
+    /// with the section and exports of the symbol if a PE image is loaded
+    fn symbol_rva_annotation(&self, rva: u32) -> String {
+        let mut annotation = format!("{} ", format_rva(rva, self.settings.image_base));
+        if let Some(pe_file) = &self.pe_file {
+            if !pe_file.contains_rva(rva) {
+                annotation.push_str("(outside of the image) ");
+            } else if let Some(section) = pe_file.section_at_rva(rva) {
+                let _r = write!(annotation, "Section={} ", section.name);
+            }
+            for export in pe_file.exports_at_rva(rva) {
+                let _r = write!(annotation, "Export={} ", export.display_name());
+            }
+        }
+
+        annotation
     }
 
     fn reconstruct_symbol(
@@ -1016,14 +3114,22 @@ where
         primitives_flavor: PrimitiveReconstructionFlavor,
         print_access_specifiers: bool,
     ) -> Option<String> {
+        if self.settings.member_filter.hide_compiler_generated
+            && get_symbol_name(symbol).is_some_and(|name| is_compiler_generated_name(&name))
+        {
+            return None;
+        }
+
         let mut needed_types = pdb_types::NeededTypeSet::new();
         match symbol.parse().ok()? {
             pdb::SymbolData::UserDefinedType(udt) => {
                 if let Ok(type_name) = type_name(
                     type_finder,
                     &self.forwarder_to_complete_type,
+                    &self.unnamed_types,
                     udt.type_index,
                     &primitives_flavor,
+                    &self.settings.primitive_type_mapping,
                     &mut needed_types,
                 ) {
                     if type_name.0 == "..." {
@@ -1043,31 +3149,36 @@ where
             // Functions and methods
             pdb::SymbolData::Procedure(procedure) => {
                 let symbol_rva = symbol_rva(&procedure.offset, &self.sections)
-                    .map(|offset| format!("RVA=0x{:x} ", offset))
+                    .map(|rva| self.symbol_rva_annotation(rva))
                     .unwrap_or_default();
                 if let Ok(type_name) = type_name(
                     type_finder,
                     &self.forwarder_to_complete_type,
+                    &self.unnamed_types,
                     procedure.type_index,
                     &primitives_flavor,
+                    &self.settings.primitive_type_mapping,
                     &mut needed_types,
                 ) {
                     let static_prefix = if procedure.global { "" } else { "static " };
                     if type_name.0 == "..." {
                         // No type
                         Some(format!(
-                            "{}void {}(); // {}CodeSize=0x{:x} (missing type information)",
-                            static_prefix, procedure.name, symbol_rva, procedure.len,
+                            "{}void {}(); // {}CodeSize={} (missing type information)",
+                            static_prefix,
+                            procedure.name,
+                            symbol_rva,
+                            self.settings.number_format.size(procedure.len.into()),
                         ))
                     } else {
                         Some(format!(
-                            "{}{}{}{}; // {}CodeSize=0x{:x}",
+                            "{}{}{}{}; // {}CodeSize={}",
                             static_prefix,
                             type_name.0,
                             procedure.name,
                             type_name.1,
                             symbol_rva,
-                            procedure.len,
+                            self.settings.number_format.size(procedure.len.into()),
                         ))
                     }
                 } else {
@@ -1078,13 +3189,15 @@ where
             // Global variables
             pdb::SymbolData::Data(data) => {
                 let symbol_rva = symbol_rva(&data.offset, &self.sections)
-                    .map(|offset| format!("RVA=0x{:x} ", offset))
+                    .map(|rva| self.symbol_rva_annotation(rva))
                     .unwrap_or_default();
                 if let Ok(type_name) = type_name(
                     type_finder,
                     &self.forwarder_to_complete_type,
+                    &self.unnamed_types,
                     data.type_index,
                     &primitives_flavor,
+                    &self.settings.primitive_type_mapping,
                     &mut needed_types,
                 ) {
                     let static_prefix = if data.global { "" } else { "static " };
@@ -1125,7 +3238,7 @@ where
             // Public symbols
             pdb::SymbolData::Public(data) => {
                 let symbol_rva = symbol_rva(&data.offset, &self.sections)
-                    .map(|offset| format!("RVA=0x{:x} ", offset))
+                    .map(|rva| self.symbol_rva_annotation(rva))
                     .unwrap_or_default();
                 Some(
                     if let Some(demangled_symbol) =
@@ -1144,6 +3257,19 @@ where
                 )
             }
 
+            // Thunks
+            pdb::SymbolData::Thunk(thunk) => {
+                let symbol_rva = symbol_rva(&thunk.offset, &self.sections)
+                    .map(|rva| self.symbol_rva_annotation(rva))
+                    .unwrap_or_default();
+                Some(format!(
+                    "void {}(); // {}CodeSize={} (thunk)",
+                    thunk.name,
+                    symbol_rva,
+                    self.settings.number_format.size(thunk.len.into()),
+                ))
+            }
+
             // Exported symbols
             pdb::SymbolData::Export(data) => Some(
                 if let Some(demangled_symbol) =
@@ -1166,53 +3292,201 @@ where
     }
 }
 
-fn compute_type_depth_map(
+/// Order the given types and their dependencies so that every type comes after
+/// the types it needs to be defined first (i.e., the ones it references by
+/// value). Types referenced through pointers are forward declared instead, so
+/// they don't constrain the order.
+///
+/// Types referencing each other by value (e.g., in method signatures) cannot
+/// all be defined first. These cycles are returned along with the order, each
+/// one starting and ending with the type which is referenced before being
+/// defined (and thus must be forward declared).
+fn compute_type_order(
     type_dependency_map: &HashMap<TypeIndex, Vec<(TypeIndex, bool)>>,
     root_types: &[TypeIndex],
-) -> BTreeMap<usize, Vec<pdb::TypeIndex>> {
-    let depth_start = Instant::now();
-
-    let mut type_depth_map: HashMap<TypeIndex, usize> =
-        HashMap::from_iter(root_types.iter().map(|elem| (*elem, 0)));
-    // Perform depth-first search to determine the "depth" of each type
-    let mut types_to_visit: VecDeque<(usize, TypeIndex)> =
-        VecDeque::from_iter(root_types.iter().map(|elem| (0, *elem)));
-    while let Some((current_type_depth, current_type_index)) = types_to_visit.pop_back() {
-        if let Some(type_dependencies) = type_dependency_map.get(&current_type_index) {
-            for (child_type_index, child_is_pointer) in type_dependencies {
-                // Visit child only if it's directly referenced, to avoid infinite loops
-                if !child_is_pointer && *child_type_index != current_type_index {
-                    let current_child_depth = current_type_depth + 1;
-                    if let Some(child_type_depth) = type_depth_map.get_mut(child_type_index) {
-                        *child_type_depth = std::cmp::max(*child_type_depth, current_child_depth);
-                    } else {
-                        type_depth_map.insert(*child_type_index, current_child_depth);
-                    }
-                    types_to_visit.push_back((current_child_depth, *child_type_index));
-                }
-            }
+) -> (Vec<pdb::TypeIndex>, Vec<Vec<TypeIndex>>) {
+    let order_start = Instant::now();
+
+    // Dependencies are visited in type index order, so that the output
+    // doesn't depend on the order in which they were found
+    let by_value_dependencies = |type_index: TypeIndex| -> Vec<TypeIndex> {
+        let mut dependencies: Vec<TypeIndex> = type_dependency_map
+            .get(&type_index)
+            .into_iter()
+            .flatten()
+            .filter(|(_, is_pointer)| !is_pointer)
+            .map(|(dependency, _)| *dependency)
+            .collect();
+        // Note: dependencies are popped from the back
+        dependencies.sort_unstable_by(|lhs, rhs| rhs.cmp(lhs));
+        dependencies.dedup();
+        dependencies
+    };
+
+    let mut type_order = Vec::new();
+    let mut dependency_cycles = Vec::new();
+    let mut visited_types = HashSet::new();
+    let mut ordered_types = HashSet::new();
+    for root_type_index in root_types {
+        if !visited_types.insert(*root_type_index) {
+            continue;
         }
-    }
 
-    // Invert type depth map
-    let inverted_type_depth_map: BTreeMap<usize, Vec<pdb::TypeIndex>> = type_depth_map
-        .into_iter()
-        .fold(BTreeMap::new(), |mut acc, (type_index, type_depth)| {
-            if let Some(type_indices) = acc.get_mut(&type_depth) {
-                type_indices.push(type_index.into());
+        // Post-order depth-first search. Note: it's iterative, as dependency
+        // chains can be deep enough to overflow the stack.
+        let mut types_to_visit = vec![(*root_type_index, by_value_dependencies(*root_type_index))];
+        while let Some((type_index, dependencies)) = types_to_visit.last_mut() {
+            let type_index = *type_index;
+            if let Some(dependency) = dependencies.pop() {
+                if visited_types.insert(dependency) {
+                    types_to_visit.push((dependency, by_value_dependencies(dependency)));
+                } else if dependency != type_index && !ordered_types.contains(&dependency) {
+                    // The dependency is still being visited, we've found a
+                    // cycle. Note: types can reference themselves in their
+                    // own definition.
+                    let cycle_start = types_to_visit
+                        .iter()
+                        .position(|(visited_type_index, _)| *visited_type_index == dependency)
+                        .unwrap_or_default();
+                    dependency_cycles.push(
+                        types_to_visit[cycle_start..]
+                            .iter()
+                            .map(|(visited_type_index, _)| *visited_type_index)
+                            .chain(std::iter::once(dependency))
+                            .collect(),
+                    );
+                }
             } else {
-                acc.insert(type_depth, vec![type_index.into()]);
+                // All the dependencies of the type have been ordered
+                type_order.push(type_index.into());
+                ordered_types.insert(type_index);
+                types_to_visit.pop();
             }
-
-            acc
-        });
+        }
+    }
 
     log::debug!(
-        "Depth calculation took {} ms",
-        depth_start.elapsed().as_millis()
+        "Type ordering took {} ms",
+        order_start.elapsed().as_millis()
     );
 
-    inverted_type_depth_map
+    (type_order, dependency_cycles)
+}
+
+/// Indicate if `symbol_name` is the name of one of the given type's methods
+/// (and not, e.g., of a method of a nested type)
+fn is_method_of(symbol_name: &str, type_name: &str) -> bool {
+    symbol_name
+        .strip_prefix(type_name)
+        .and_then(|name| name.strip_prefix("::"))
+        .map_or(false, |method_name| {
+            // Template arguments may contain qualified names
+            let method_name = method_name.split('<').next().unwrap_or_default();
+            !method_name.is_empty() && !method_name.contains("::")
+        })
+}
+
+/// Split a qualified name into its scope (if any) and unqualified name, ignoring
+/// the `::` found in template arguments
+fn split_qualified_name(name: &str) -> (Option<&str>, &str) {
+    let mut depth = 0_usize;
+    let mut scope_end = None;
+    for (index, c) in name.char_indices() {
+        match c {
+            '<' | '(' => depth += 1,
+            '>' | ')' => depth = depth.saturating_sub(1),
+            ':' if depth == 0 && name[index + 1..].starts_with(':') => scope_end = Some(index),
+            _ => {}
+        }
+    }
+
+    match scope_end {
+        Some(scope_end) => (Some(&name[..scope_end]), &name[scope_end + 2..]),
+        None => (None, name),
+    }
+}
+
+/// Indicate if the given type is `bool` (possibly `const`)
+fn is_bool_type(type_finder: &pdb::TypeFinder, type_index: pdb::TypeIndex) -> bool {
+    match type_finder.find(type_index).and_then(|item| item.parse()) {
+        Ok(pdb::TypeData::Primitive(data)) => {
+            data.kind == pdb::PrimitiveKind::Bool8 && data.indirection.is_none()
+        }
+        Ok(pdb::TypeData::Modifier(data)) => is_bool_type(type_finder, data.underlying_type),
+        _ => false,
+    }
+}
+
+/// Indicate if the given type is an enum with an enumerator named `name`
+fn is_enumerator_of(
+    type_finder: &pdb::TypeFinder,
+    type_forwarder: &pdb_types::TypeForwarder,
+    type_index: pdb::TypeIndex,
+    name: &str,
+) -> bool {
+    let type_index = pdb_types::resolve_complete_type_index(type_forwarder, type_index);
+    let fields = match type_finder.find(type_index).and_then(|item| item.parse()) {
+        Ok(pdb::TypeData::Enumeration(data)) => data.fields,
+        Ok(pdb::TypeData::Modifier(data)) => {
+            return is_enumerator_of(type_finder, type_forwarder, data.underlying_type, name);
+        }
+        _ => return false,
+    };
+
+    let mut field_list_index = Some(fields);
+    while let Some(index) = field_list_index {
+        let Ok(pdb::TypeData::FieldList(field_list)) =
+            type_finder.find(index).and_then(|item| item.parse())
+        else {
+            return false;
+        };
+        if field_list.fields.iter().any(|field| {
+            matches!(field, pdb::TypeData::Enumerate(enumerate) if enumerate.name.to_string() == name)
+        }) {
+            return true;
+        }
+        field_list_index = field_list.continuation;
+    }
+
+    false
+}
+
+/// Score how well `candidate` matches `query` (lowercase), which must be a
+/// case-insensitive subsequence of it. Consecutive characters and characters
+/// at the start of words (e.g., after `::`) are favored.
+fn fuzzy_match_score(query: &[char], candidate: &str) -> Option<i64> {
+    let mut score = 0;
+    let mut query_chars = query.iter().peekable();
+    let mut previous_char: Option<char> = None;
+    let mut previous_char_matched = false;
+    for c in candidate.chars() {
+        let Some(query_char) = query_chars.peek() else {
+            break;
+        };
+
+        let char_matched = c.to_ascii_lowercase() == **query_char;
+        if char_matched {
+            query_chars.next();
+            score += 1;
+            if previous_char_matched {
+                score += 4;
+            }
+            let is_word_start = match previous_char {
+                None => true,
+                Some(previous_char) => {
+                    !previous_char.is_alphanumeric()
+                        || (previous_char.is_lowercase() && c.is_uppercase())
+                }
+            };
+            if is_word_start {
+                score += 8;
+            }
+        }
+        previous_char = Some(c);
+        previous_char_matched = char_matched;
+    }
+
+    query_chars.peek().is_none().then_some(score)
 }
 
 fn get_symbol_name(symbol: &pdb::Symbol) -> Option<String> {
@@ -1231,6 +3505,9 @@ fn get_symbol_name(symbol: &pdb::Symbol) -> Option<String> {
         // Public symbols
         pdb::SymbolData::Public(data) => Some(data.name.to_string().to_string()),
 
+        // Thunks (e.g., incremental linking and import thunks)
+        pdb::SymbolData::Thunk(thunk) => Some(thunk.name.to_string().to_string()),
+
         // Exported symbols
         pdb::SymbolData::Export(data) => Some(data.name.to_string().to_string()),
 
@@ -1256,6 +3533,46 @@ fn get_symbol_name(symbol: &pdb::Symbol) -> Option<String> {
     })
 }
 
+/// Format GUIDs the same way as Microsoft's tools
+fn format_guid(guid: &impl std::fmt::Display) -> String {
+    guid.to_string().to_uppercase()
+}
+
+/// Read the number of streams from the MSF stream directory (big MSF format
+/// only), which isn't exposed by the `pdb` crate
+fn msf_stream_count(reader: &mut (impl Read + Seek)) -> Option<u32> {
+    let stream_count = read_msf_stream_count(reader);
+    let _ = reader.seek(io::SeekFrom::Start(0));
+
+    stream_count
+}
+
+fn read_msf_stream_count(reader: &mut (impl Read + Seek)) -> Option<u32> {
+    const MSF_MAGIC: &[u8; 32] = b"Microsoft C/C++ MSF 7.00\r\n\x1aDS\0\0\0";
+
+    if &read_bytes_at::<32>(reader, 0)? != MSF_MAGIC {
+        return None;
+    }
+    let block_size = u64::from(read_u32_at(reader, 32)?);
+    let block_map_address = u64::from(read_u32_at(reader, 52)?);
+    // The directory starts with the number of streams
+    let first_directory_block = u64::from(read_u32_at(reader, block_map_address * block_size)?);
+
+    read_u32_at(reader, first_directory_block * block_size)
+}
+
+fn read_u32_at(reader: &mut (impl Read + Seek), offset: u64) -> Option<u32> {
+    read_bytes_at::<4>(reader, offset).map(u32::from_le_bytes)
+}
+
+fn read_bytes_at<const N: usize>(reader: &mut (impl Read + Seek), offset: u64) -> Option<[u8; N]> {
+    let mut bytes = [0; N];
+    reader.seek(io::SeekFrom::Start(offset)).ok()?;
+    reader.read_exact(&mut bytes).ok()?;
+
+    Some(bytes)
+}
+
 fn symbol_rva(
     symbol_offset: &pdb::PdbInternalSectionOffset,
     sections: &[pdb::ImageSectionHeader],
@@ -1271,7 +3588,9 @@ fn symbol_rva(
     }
 }
 
-fn demangle_symbol_name(
+/// Demangle an MSVC-decorated symbol name (e.g., `?foo@@YAXXZ`). Returns `None`
+/// if the given name cannot be demangled.
+pub fn demangle_symbol_name(
     symbol_name: impl AsRef<str>,
     print_access_specifiers: bool,
 ) -> Option<String> {
@@ -1302,11 +3621,92 @@ fn symbol_priority(symbol: &pdb::Symbol) -> u16 {
             | pdb::SymbolData::Data(_) => 0,
             // Public symbols
             pdb::SymbolData::Public(_) => 1,
+            // Thunks
+            pdb::SymbolData::Thunk(_) => 2,
             // Exported symbols
-            pdb::SymbolData::Export(_) => 2,
+            pdb::SymbolData::Export(_) => 3,
             _ => 10,
         }
     } else {
         0
     }
 }
+
+/// Kind of the given named symbol, `None` for symbols that aren't listed
+fn symbol_kind(symbol: &pdb::Symbol) -> Option<SymbolKind> {
+    match symbol.parse().ok()? {
+        pdb::SymbolData::Procedure(_) => Some(SymbolKind::Function),
+        pdb::SymbolData::Data(_) => Some(SymbolKind::Data),
+        pdb::SymbolData::Thunk(_) => Some(SymbolKind::Thunk),
+        pdb::SymbolData::Public(_) => Some(SymbolKind::Public),
+        pdb::SymbolData::UserDefinedType(_) | pdb::SymbolData::Export(_) => Some(SymbolKind::Other),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TYPE_A: TypeIndex = 0x1000;
+    const TYPE_B: TypeIndex = 0x1001;
+    const TYPE_C: TypeIndex = 0x1002;
+    const TYPE_D: TypeIndex = 0x1003;
+    const TYPE_E: TypeIndex = 0x1004;
+
+    fn type_order(type_indices: &[TypeIndex]) -> Vec<pdb::TypeIndex> {
+        type_indices
+            .iter()
+            .map(|type_index| pdb::TypeIndex(*type_index))
+            .collect()
+    }
+
+    #[test]
+    fn compute_type_order_breaks_two_type_cycles() {
+        // A and B reference each other by value
+        let type_dependency_map = HashMap::from([
+            (TYPE_A, vec![(TYPE_B, false)]),
+            (TYPE_B, vec![(TYPE_A, false)]),
+        ]);
+
+        let (order, dependency_cycles) = compute_type_order(&type_dependency_map, &[TYPE_A]);
+        assert_eq!(order, type_order(&[TYPE_B, TYPE_A]));
+        // A is referenced by B before being defined, so it's the one
+        // `break_dependency_cycles` forward declares
+        assert_eq!(dependency_cycles, vec![vec![TYPE_A, TYPE_B, TYPE_A]]);
+    }
+
+    #[test]
+    fn compute_type_order_breaks_three_type_cycles() {
+        // A -> B -> C -> A by value, C also needs E and A points to D
+        let type_dependency_map = HashMap::from([
+            (TYPE_A, vec![(TYPE_B, false), (TYPE_D, true)]),
+            (TYPE_B, vec![(TYPE_C, false)]),
+            (TYPE_C, vec![(TYPE_A, false), (TYPE_E, false)]),
+            (TYPE_E, vec![]),
+        ]);
+
+        // Types referenced through pointers don't need to be defined first
+        let (order, dependency_cycles) = compute_type_order(&type_dependency_map, &[TYPE_A]);
+        assert_eq!(order, type_order(&[TYPE_E, TYPE_C, TYPE_B, TYPE_A]));
+        assert_eq!(
+            dependency_cycles,
+            vec![vec![TYPE_A, TYPE_B, TYPE_C, TYPE_A]]
+        );
+
+        // The forward declared type is the first one of the cycle to be
+        // visited, which depends on the root types
+        let (order, dependency_cycles) = compute_type_order(&type_dependency_map, &[TYPE_B]);
+        assert_eq!(order, type_order(&[TYPE_A, TYPE_E, TYPE_C, TYPE_B]));
+        assert_eq!(
+            dependency_cycles,
+            vec![vec![TYPE_B, TYPE_C, TYPE_A, TYPE_B]]
+        );
+
+        // Root types which have already been ordered are skipped
+        let (order, dependency_cycles) =
+            compute_type_order(&type_dependency_map, &[TYPE_A, TYPE_B, TYPE_C]);
+        assert_eq!(order, type_order(&[TYPE_E, TYPE_C, TYPE_B, TYPE_A]));
+        assert_eq!(dependency_cycles.len(), 1);
+    }
+}
@@ -1,14 +1,15 @@
 use std::fmt;
 
 use super::{
+    code_format::write_opening_brace,
     enumeration::Enum,
     field::{FieldAccess, StaticField},
-    fmt_struct_fields_recursive, is_unnamed_type,
-    primitive_types::PrimitiveReconstructionFlavor,
+    fmt_struct_fields_recursive, format_rva, is_unnamed_type, owned_unique_name,
+    primitive_types::{PrimitiveReconstructionFlavor, PrimitiveTypeMapping},
     resolve_complete_type_index, type_bitfield_info, type_name, type_size,
     union::Union,
-    DataFormatConfiguration, Field, Method, NeededTypeSet, ReconstructibleTypeData, Result,
-    ResymCoreError, TypeForwarder,
+    unnamed_type_name, DataFormatConfiguration, Field, Method, NeededTypeSet,
+    ReconstructibleTypeData, Result, ResymCoreError, TypeForwarder, UnnamedTypeMap,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -56,6 +57,8 @@ pub struct Class<'p> {
     pub index: pdb::TypeIndex,
     pub kind: pdb::ClassKind,
     pub name: String,
+    /// Decorated name of the type, if any
+    pub unique_name: Option<String>,
     pub size: u64,
     pub base_classes: Vec<BaseClass>,
     pub fields: Vec<Field<'p>>,
@@ -79,12 +82,15 @@ impl<'p> Class<'p> {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn add_fields(
         &mut self,
         type_finder: &pdb::TypeFinder<'p>,
         type_forwarder: &TypeForwarder,
+        unnamed_types: &UnnamedTypeMap,
         type_index: pdb::TypeIndex,
         primitive_flavor: &PrimitiveReconstructionFlavor,
+        primitive_type_mapping: &PrimitiveTypeMapping,
         needed_types: &mut NeededTypeSet,
     ) -> Result<()> {
         // Resolve the complete type's index, if present in the PDB
@@ -95,8 +101,10 @@ impl<'p> Class<'p> {
                     self.add_field(
                         type_finder,
                         type_forwarder,
+                        unnamed_types,
                         field,
                         primitive_flavor,
+                        primitive_type_mapping,
                         needed_types,
                     )?;
                 }
@@ -106,8 +114,10 @@ impl<'p> Class<'p> {
                     self.add_fields(
                         type_finder,
                         type_forwarder,
+                        unnamed_types,
                         continuation,
                         primitive_flavor,
+                        primitive_type_mapping,
                         needed_types,
                     )?;
                 }
@@ -118,7 +128,7 @@ impl<'p> Class<'p> {
                 let name_str = data.name.to_string();
                 // Rename unnamed anonymous tags to something unique
                 let name = if is_unnamed_type(&name_str) {
-                    format!("_unnamed_{type_index}")
+                    unnamed_type_name(type_finder, unnamed_types, complete_type_index)
                 } else {
                     name_str.into_owned()
                 };
@@ -127,6 +137,7 @@ impl<'p> Class<'p> {
                     index: type_index,
                     kind: data.kind,
                     name,
+                    unique_name: owned_unique_name(data.unique_name),
                     size: data.size,
                     fields: Vec::new(),
                     static_fields: Vec::new(),
@@ -146,8 +157,10 @@ impl<'p> Class<'p> {
                     class.add_fields(
                         type_finder,
                         type_forwarder,
+                        unnamed_types,
                         fields,
                         primitive_flavor,
+                        primitive_type_mapping,
                         needed_types,
                     )?;
                 }
@@ -159,7 +172,7 @@ impl<'p> Class<'p> {
                 let name_str = data.name.to_string();
                 // Rename unnamed anonymous tags to something unique
                 let name = if is_unnamed_type(&name_str) {
-                    format!("_unnamed_{type_index}")
+                    unnamed_type_name(type_finder, unnamed_types, complete_type_index)
                 } else {
                     name_str.into_owned()
                 };
@@ -167,6 +180,7 @@ impl<'p> Class<'p> {
                 let mut u = Union {
                     index: type_index,
                     name,
+                    unique_name: owned_unique_name(data.unique_name),
                     size: data.size,
                     fields: Vec::new(),
                     static_fields: Vec::new(),
@@ -180,8 +194,10 @@ impl<'p> Class<'p> {
                 u.add_fields(
                     type_finder,
                     type_forwarder,
+                    unnamed_types,
                     data.fields,
                     primitive_flavor,
+                    primitive_type_mapping,
                     needed_types,
                 )?;
 
@@ -192,7 +208,7 @@ impl<'p> Class<'p> {
                 let name_str = data.name.to_string();
                 // Rename unnamed anonymous tags to something unique
                 let name = if is_unnamed_type(&name_str) {
-                    format!("_unnamed_{type_index}")
+                    unnamed_type_name(type_finder, unnamed_types, complete_type_index)
                 } else {
                     name_str.into_owned()
                 };
@@ -200,11 +216,14 @@ impl<'p> Class<'p> {
                 let mut e = Enum {
                     index: type_index,
                     name,
+                    unique_name: owned_unique_name(data.unique_name),
                     underlying_type_name: type_name(
                         type_finder,
                         type_forwarder,
+                        unnamed_types,
                         data.underlying_type,
                         primitive_flavor,
+                        primitive_type_mapping,
                         needed_types,
                     )?
                     .0,
@@ -236,12 +255,15 @@ impl<'p> Class<'p> {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn add_field(
         &mut self,
         type_finder: &pdb::TypeFinder<'p>,
         type_forwarder: &TypeForwarder,
+        unnamed_types: &UnnamedTypeMap,
         field: &pdb::TypeData<'p>,
         primitive_flavor: &PrimitiveReconstructionFlavor,
+        primitive_type_mapping: &PrimitiveTypeMapping,
         needed_types: &mut NeededTypeSet,
     ) -> Result<()> {
         match *field {
@@ -252,8 +274,10 @@ impl<'p> Class<'p> {
                 let (type_left, type_right) = type_name(
                     type_finder,
                     type_forwarder,
+                    unnamed_types,
                     complete_type_index,
                     primitive_flavor,
+                    primitive_type_mapping,
                     needed_types,
                 )?;
                 let type_bitfield_info = type_bitfield_info(type_finder, complete_type_index)?;
@@ -278,8 +302,10 @@ impl<'p> Class<'p> {
                 let (type_left, type_right) = type_name(
                     type_finder,
                     type_forwarder,
+                    unnamed_types,
                     complete_type_index,
                     primitive_flavor,
+                    primitive_type_mapping,
                     needed_types,
                 )?;
                 let access = FieldAccess::from_field_attribute(data.attributes.access());
@@ -289,6 +315,7 @@ impl<'p> Class<'p> {
                     type_right,
                     name: data.name,
                     access,
+                    rva: None,
                 });
             }
 
@@ -298,8 +325,10 @@ impl<'p> Class<'p> {
                     data.attributes,
                     type_finder,
                     type_forwarder,
+                    unnamed_types,
                     data.method_type,
                     primitive_flavor,
+                    primitive_type_mapping,
                     needed_types,
                 )?;
                 if data.attributes.is_static() {
@@ -326,8 +355,10 @@ impl<'p> Class<'p> {
                                 attributes,
                                 type_finder,
                                 type_forwarder,
+                                unnamed_types,
                                 method_type,
                                 primitive_flavor,
+                                primitive_type_mapping,
                                 needed_types,
                             )?;
 
@@ -359,8 +390,10 @@ impl<'p> Class<'p> {
                     type_name: type_name(
                         type_finder,
                         type_forwarder,
+                        unnamed_types,
                         complete_base_class_type_index,
                         primitive_flavor,
+                        primitive_type_mapping,
                         needed_types,
                     )?
                     .0,
@@ -377,8 +410,10 @@ impl<'p> Class<'p> {
                     type_name: type_name(
                         type_finder,
                         type_forwarder,
+                        unnamed_types,
                         complete_base_class_type_index,
                         primitive_flavor,
+                        primitive_type_mapping,
                         needed_types,
                     )?
                     .0,
@@ -445,13 +480,22 @@ impl ReconstructibleTypeData for Class<'_> {
             }
         }
 
-        writeln!(f, " {{ /* Size={:#x} */", self.size)?;
+        write_opening_brace(
+            f,
+            &fmt_configuration.code_format,
+            "",
+            &format!(
+                " /* Size={} */",
+                fmt_configuration.number_format.size(self.size)
+            ),
+        )?;
 
         for base in &self.base_classes {
             writeln!(
                 f,
-                "  /* {:#06x}: fields for {} */",
-                base.offset, base.type_name
+                "  /* {}: fields for {} */",
+                fmt_configuration.number_format.offset(base.offset.into()),
+                base.type_name
             )?;
         }
 
@@ -476,13 +520,23 @@ impl ReconstructibleTypeData for Class<'_> {
         }
 
         // Dump fields while detecting unnamed structs and unions
-        fmt_struct_fields_recursive(fmt_configuration, &self.fields, 1, f)?;
+        let member_filter = &fmt_configuration.member_filter;
+        fmt_struct_fields_recursive(
+            fmt_configuration,
+            &member_filter.visible_fields(&self.fields),
+            1,
+            f,
+        )?;
 
         // Static fields
-        for field in &self.static_fields {
+        for field in self
+            .static_fields
+            .iter()
+            .filter(|field| !member_filter.is_hidden(&field.name.to_string(), &field.access))
+        {
             writeln!(
                 f,
-                "  {}static {} {}{};",
+                "  {}static {} {}{};{}",
                 if fmt_configuration.print_access_specifiers {
                     &field.access
                 } else {
@@ -491,17 +545,26 @@ impl ReconstructibleTypeData for Class<'_> {
                 field.type_left,
                 &field.name,
                 field.type_right,
+                field
+                    .rva
+                    .map(|rva| format!(" /* {} */", format_rva(rva, fmt_configuration.image_base)))
+                    .unwrap_or_default(),
             )?;
         }
 
-        if !self.instance_methods.is_empty() {
+        let instance_methods = self
+            .instance_methods
+            .iter()
+            .filter(|method| !member_filter.is_hidden(&method.name.to_string(), &method.access))
+            .collect::<Vec<_>>();
+        if !instance_methods.is_empty() {
             let class_name = self.name.as_str().into();
             writeln!(f, "  ")?;
-            for method in &self.instance_methods {
+            for method in instance_methods {
                 let method_has_class_name = method.name == class_name;
                 writeln!(
                     f,
-                    "  {}{}{}{}{}({}){}{}{}{};",
+                    "  {}{}{}{}{}{}({}){}{}{}{}{};",
                     if fmt_configuration.print_access_specifiers {
                         &method.access
                     } else {
@@ -522,6 +585,10 @@ impl ReconstructibleTypeData for Class<'_> {
                     } else {
                         ""
                     },
+                    method
+                        .calling_convention
+                        .map(|calling_convention| format!("{calling_convention} "))
+                        .unwrap_or_default(),
                     &method.name,
                     method
                         .arguments
@@ -532,17 +599,23 @@ impl ReconstructibleTypeData for Class<'_> {
                     method.return_type_name.1,
                     if method.is_const { " const" } else { "" },
                     if method.is_volatile { " volatile" } else { "" },
+                    if method.is_override { " override" } else { "" },
                     if method.is_pure_virtual { " = 0" } else { "" },
                 )?;
             }
         }
 
-        if !self.static_methods.is_empty() {
+        let static_methods = self
+            .static_methods
+            .iter()
+            .filter(|method| !member_filter.is_hidden(&method.name.to_string(), &method.access))
+            .collect::<Vec<_>>();
+        if !static_methods.is_empty() {
             writeln!(f, "  ")?;
-            for method in &self.static_methods {
+            for method in static_methods {
                 writeln!(
                     f,
-                    "  {}static {}{}{}({}){}{}{};",
+                    "  {}static {}{}{}{}({}){}{}{};",
                     if fmt_configuration.print_access_specifiers {
                         &method.access
                     } else {
@@ -554,6 +627,10 @@ impl ReconstructibleTypeData for Class<'_> {
                     } else {
                         ""
                     },
+                    method
+                        .calling_convention
+                        .map(|calling_convention| format!("{calling_convention} "))
+                        .unwrap_or_default(),
                     &method.name,
                     method
                         .arguments
@@ -0,0 +1,214 @@
+use std::fmt;
+
+use regex::Regex;
+
+/// Number of spaces used by the emitter for each indentation level
+const EMITTER_INDENT_WIDTH: usize = 2;
+
+/// Placement of opening braces, applied by the emitter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BraceStyle {
+    /// Opening braces are placed at the end of the declaration's line
+    Attach,
+    /// Opening braces are placed on their own line
+    Allman,
+}
+
+/// Binding of `*` in pointer declarations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointerAlignment {
+    /// `int* p`
+    Left,
+    /// `int *p`
+    Right,
+}
+
+/// Style applied to reconstructed code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeFormatConfiguration {
+    pub indent_width: usize,
+    pub use_tabs: bool,
+    pub brace_style: BraceStyle,
+    pub pointer_alignment: PointerAlignment,
+    /// Lines longer than this are wrapped after commas in parameter lists.
+    /// `None` disables wrapping.
+    pub max_line_width: Option<usize>,
+}
+
+impl Default for CodeFormatConfiguration {
+    /// Style used by the emitter
+    fn default() -> Self {
+        Self {
+            indent_width: EMITTER_INDENT_WIDTH,
+            use_tabs: false,
+            brace_style: BraceStyle::Attach,
+            pointer_alignment: PointerAlignment::Left,
+            max_line_width: None,
+        }
+    }
+}
+
+/// Write the opening brace of a block declared on the current line (at the
+/// given indentation), followed by `comment` (e.g., ` /* Size=0x10 */`)
+pub(super) fn write_opening_brace(
+    f: &mut impl fmt::Write,
+    code_format: &CodeFormatConfiguration,
+    indentation: &str,
+    comment: &str,
+) -> fmt::Result {
+    match code_format.brace_style {
+        BraceStyle::Attach => writeln!(f, " {{{comment}"),
+        BraceStyle::Allman => writeln!(f, "{comment}\n{indentation}{{"),
+    }
+}
+
+/// Reformat code produced by the emitter according to the given configuration.
+/// Note: braces are placed by the emitter, `brace_style` is ignored here.
+pub fn format_code(code: &str, code_format: &CodeFormatConfiguration) -> String {
+    // Matches `*` bound to the type in declarations (e.g., `int** p`)
+    let pointer_regex =
+        Regex::new(r"(?P<type>[\w>\]])(?P<stars>\*+) (?P<name>[A-Za-z_(])").expect("valid regex");
+
+    code.split('\n')
+        .map(|line| {
+            let content = line.trim_start_matches(' ');
+            if content.is_empty() {
+                return String::default();
+            }
+
+            let leading_spaces = line.len() - content.len();
+            let indent_level = leading_spaces / EMITTER_INDENT_WIDTH;
+            let mut indent = if code_format.use_tabs {
+                "\t".repeat(indent_level)
+            } else {
+                " ".repeat(indent_level * code_format.indent_width)
+            };
+            indent.push_str(&" ".repeat(leading_spaces % EMITTER_INDENT_WIDTH));
+
+            let content = match code_format.pointer_alignment {
+                PointerAlignment::Left => content.into(),
+                PointerAlignment::Right => {
+                    pointer_regex.replace_all(content, "${type} ${stars}${name}")
+                }
+            };
+
+            match code_format.max_line_width {
+                None => format!("{indent}{content}"),
+                Some(max_line_width) => wrap_line(&indent, &content, max_line_width, code_format),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Wrap a line that's longer than `max_line_width` after commas in parameter
+/// lists. Continuation lines are indented twice.
+fn wrap_line(
+    indent: &str,
+    content: &str,
+    max_line_width: usize,
+    code_format: &CodeFormatConfiguration,
+) -> String {
+    let indent_width = indent
+        .chars()
+        .map(|c| {
+            if c == '\t' {
+                code_format.indent_width
+            } else {
+                1
+            }
+        })
+        .sum::<usize>();
+    if indent_width + content.chars().count() <= max_line_width {
+        return format!("{indent}{content}");
+    }
+
+    // Positions where the line can be broken, with their nesting depth.
+    // Lines are never broken within comments or template argument lists.
+    let mut break_points: Vec<(usize, usize)> = vec![];
+    let mut open_brackets: Vec<char> = vec![];
+    let mut in_comment = false;
+    let mut previous_char = None;
+    let mut chars = content.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        let next_char = chars.peek().map(|(_, c)| *c);
+        match c {
+            // The rest of the line is a comment
+            '/' if !in_comment && next_char == Some('/') => break,
+            '/' if !in_comment && next_char == Some('*') => {
+                in_comment = true;
+            }
+            '*' if in_comment && next_char == Some('/') => {
+                in_comment = false;
+            }
+            _ if in_comment => {}
+            '(' | '<' | '[' => open_brackets.push(c),
+            // `->` doesn't close template argument lists
+            '>' if previous_char == Some('-') => {}
+            '>' => {
+                if open_brackets.last() == Some(&'<') {
+                    open_brackets.pop();
+                }
+            }
+            // Also close unmatched `<` (e.g., from `operator<`)
+            ')' | ']' => {
+                let opening_bracket = if c == ')' { '(' } else { '[' };
+                if let Some(position) = open_brackets.iter().rposition(|b| *b == opening_bracket) {
+                    open_brackets.truncate(position);
+                }
+            }
+            ',' if !open_brackets.is_empty() && !open_brackets.contains(&'<') => {
+                break_points.push((index + 1, open_brackets.len()));
+            }
+            _ => {}
+        }
+        previous_char = Some(c);
+    }
+
+    let continuation_prefix = if code_format.use_tabs {
+        format!("{indent}\t\t")
+    } else {
+        format!("{indent}{}", " ".repeat(2 * code_format.indent_width))
+    };
+    let continuation_prefix_width = indent_width + 2 * code_format.indent_width;
+    let segment_width =
+        |start: usize, end: usize| content[start..end].trim_start_matches(' ').chars().count();
+
+    let mut line_breaks = vec![];
+    let mut line_start = 0;
+    let mut line_prefix_width = indent_width;
+    while line_prefix_width + segment_width(line_start, content.len()) > max_line_width {
+        // Prefer breaking the outermost list, as late as possible
+        let remaining_break_points = break_points
+            .iter()
+            .filter(|(position, _)| *position > line_start && *position < content.len());
+        let Some(first_break_point) = remaining_break_points.clone().next() else {
+            break;
+        };
+        let (break_position, _) = remaining_break_points
+            .filter(|(position, _)| {
+                line_prefix_width + segment_width(line_start, *position) <= max_line_width
+            })
+            .min_by_key(|(position, depth)| (*depth, std::cmp::Reverse(*position)))
+            .unwrap_or(first_break_point);
+
+        line_breaks.push(*break_position);
+        line_start = *break_position;
+        line_prefix_width = continuation_prefix_width;
+    }
+
+    let mut output = String::with_capacity(content.len() + indent.len());
+    let mut line_prefix = indent;
+    let mut line_start = 0;
+    for position in line_breaks {
+        output.push_str(line_prefix);
+        output.push_str(content[line_start..position].trim_start_matches(' '));
+        output.push('\n');
+        line_prefix = &continuation_prefix;
+        line_start = position;
+    }
+    output.push_str(line_prefix);
+    output.push_str(content[line_start..].trim_start_matches(' '));
+
+    output
+}
@@ -1,12 +1,17 @@
-use std::fmt;
+use std::{fmt, str::FromStr};
 
-use super::{DataFormatConfiguration, NeededTypeSet, ReconstructibleTypeData};
-use crate::error::Result;
+use super::{
+    code_format::write_opening_brace, DataFormatConfiguration, NeededTypeSet, NumberFormat,
+    ReconstructibleTypeData,
+};
+use crate::error::{Result, ResymCoreError};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Enum<'p> {
     pub index: pdb::TypeIndex,
     pub name: String,
+    /// Decorated name of the type, if any
+    pub unique_name: Option<String>,
     pub underlying_type_name: String,
     pub values: Vec<EnumValue<'p>>,
 }
@@ -55,32 +60,148 @@ impl<'p> Enum<'p> {
             });
         }
     }
+
+    /// Indicate if the enum looks like a set of bit flags: every value is zero
+    /// or made of the bits of single-bit values, and the values aren't a plain
+    /// sequence (e.g., `0, 1, 2, 3`).
+    fn is_flag_enum(&self) -> bool {
+        let Some(values) = self
+            .values
+            .iter()
+            .map(|value| variant_as_u64(&value.value))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return false;
+        };
+        let single_bits = values
+            .iter()
+            .filter(|value| value.is_power_of_two())
+            .fold(0, |bits, value| bits | value);
+        if single_bits.count_ones() < 2 || values.iter().any(|value| value & !single_bits != 0) {
+            return false;
+        }
+
+        let mut non_zero_values = values
+            .into_iter()
+            .filter(|value| *value != 0)
+            .collect::<Vec<_>>();
+        non_zero_values.sort_unstable();
+        non_zero_values.dedup();
+
+        !non_zero_values
+            .iter()
+            .copied()
+            .eq(1..=non_zero_values.len() as u64)
+    }
+
+    /// Enumerators, in the order they should be printed in
+    fn ordered_values(&self, order: EnumValueOrder) -> Vec<&EnumValue<'p>> {
+        let mut values = self.values.iter().collect::<Vec<_>>();
+        // Note: sorts are stable, enumerators with the same value keep their
+        // relative order
+        match order {
+            EnumValueOrder::Record => {}
+            EnumValueOrder::Value => values.sort_by_key(|value| variant_as_i128(&value.value)),
+            EnumValueOrder::Name => {
+                values.sort_by(|a, b| a.name.to_string().cmp(&b.name.to_string()))
+            }
+        }
+
+        values
+    }
+}
+
+/// Order in which enumerators are printed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EnumValueOrder {
+    /// Order of the PDB's records, usually the declaration order
+    #[default]
+    Record,
+    /// Sorted numerically
+    Value,
+    /// Sorted alphabetically
+    Name,
+}
+
+impl FromStr for EnumValueOrder {
+    type Err = ResymCoreError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "record" | "pdb" => Ok(EnumValueOrder::Record),
+            "value" => Ok(EnumValueOrder::Value),
+            "name" => Ok(EnumValueOrder::Name),
+            _ => Err(ResymCoreError::InvalidParameterError(format!(
+                "invalid enum value order '{s}', expected 'record', 'value' or 'name'"
+            ))),
+        }
+    }
+}
+
+fn variant_as_i128(value: &pdb::Variant) -> i128 {
+    match *value {
+        pdb::Variant::U8(v) => v.into(),
+        pdb::Variant::U16(v) => v.into(),
+        pdb::Variant::U32(v) => v.into(),
+        pdb::Variant::U64(v) => v.into(),
+        pdb::Variant::I8(v) => v.into(),
+        pdb::Variant::I16(v) => v.into(),
+        pdb::Variant::I32(v) => v.into(),
+        pdb::Variant::I64(v) => v.into(),
+    }
+}
+
+/// Value of an enumerator, `None` if negative
+fn variant_as_u64(value: &pdb::Variant) -> Option<u64> {
+    variant_as_i128(value).try_into().ok()
+}
+
+/// Describe the bits set in a flag value (e.g., `0x6, bits 1, 2`)
+fn fmt_flag_bits(value: u64, number_format: &NumberFormat) -> String {
+    let formatted_value = number_format.unsigned(value, 0);
+    let bits = (0..u64::BITS)
+        .filter(|bit| value & (1 << bit) != 0)
+        .map(|bit| bit.to_string())
+        .collect::<Vec<_>>();
+    if bits.len() == 1 {
+        format!("{formatted_value}, bit {}", bits[0])
+    } else {
+        format!("{formatted_value}, bits {}", bits.join(", "))
+    }
 }
 
 impl ReconstructibleTypeData for Enum<'_> {
     fn reconstruct(
         &self,
-        _fmt_configuration: &DataFormatConfiguration,
+        fmt_configuration: &DataFormatConfiguration,
         f: &mut impl std::fmt::Write,
     ) -> fmt::Result {
-        writeln!(f, "enum {} : {} {{", self.name, self.underlying_type_name)?;
+        let is_flag_enum = fmt_configuration.flag_enum_comments && self.is_flag_enum();
+        // Note: scoped enums (`enum class`) are printed as unscoped enums, as
+        // MSVC emits the same records (properties and decorated names) for both
+        write!(f, "enum {} : {}", self.name, self.underlying_type_name)?;
+        write_opening_brace(
+            f,
+            &fmt_configuration.code_format,
+            "",
+            if is_flag_enum { " // flags" } else { "" },
+        )?;
 
-        for value in &self.values {
-            writeln!(
+        for value in self.ordered_values(fmt_configuration.enum_value_order) {
+            write!(
                 f,
                 "  {} = {},",
                 value.name.to_string(),
-                match value.value {
-                    pdb::Variant::U8(v) => format!("0x{v:02x}"),
-                    pdb::Variant::U16(v) => format!("0x{v:04x}"),
-                    pdb::Variant::U32(v) => format!("0x{v:08x}"),
-                    pdb::Variant::U64(v) => format!("0x{v:16x}"),
-                    pdb::Variant::I8(v) => format!("{v}"),
-                    pdb::Variant::I16(v) => format!("{v}"),
-                    pdb::Variant::I32(v) => format!("{v}"),
-                    pdb::Variant::I64(v) => format!("{v}"),
-                }
+                fmt_configuration.number_format.variant(&value.value)
             )?;
+            match variant_as_u64(&value.value) {
+                Some(flag_value) if is_flag_enum && flag_value != 0 => writeln!(
+                    f,
+                    " // {}",
+                    fmt_flag_bits(flag_value, &fmt_configuration.number_format)
+                )?,
+                _ => writeln!(f)?,
+            }
         }
         writeln!(f, "}};")?;
 
@@ -21,6 +21,8 @@ pub struct StaticField<'p> {
     pub type_right: String,
     pub name: pdb::RawString<'p>,
     pub access: FieldAccess,
+    /// RVA of the member's definition, if it has been resolved
+    pub rva: Option<u32>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1,6 +1,8 @@
 use super::{
-    argument_list, field::FieldAccess, primitive_types::PrimitiveReconstructionFlavor, type_name,
-    NeededTypeSet, TypeForwarder,
+    argument_list,
+    field::FieldAccess,
+    primitive_types::{PrimitiveReconstructionFlavor, PrimitiveTypeMapping},
+    type_name, NeededTypeSet, TypeForwarder, UnnamedTypeMap,
 };
 use crate::error::{Result, ResymCoreError};
 
@@ -11,6 +13,11 @@ pub struct Method<'p> {
     pub arguments: Vec<(String, String)>,
     pub is_virtual: bool,
     pub is_pure_virtual: bool,
+    /// Virtual method overriding a base class' method
+    pub is_override: bool,
+    /// Calling convention keyword (e.g., `__thiscall`), only set for the
+    /// Microsoft flavor and when it isn't the default one
+    pub calling_convention: Option<&'static str>,
     pub is_ctor: bool,
     pub is_dtor: bool,
     pub is_const: bool,
@@ -19,13 +26,16 @@ pub struct Method<'p> {
 }
 
 impl<'p> Method<'p> {
+    #[allow(clippy::too_many_arguments)]
     pub fn find(
         name: pdb::RawString<'p>,
         attributes: pdb::FieldAttributes,
         type_finder: &pdb::TypeFinder<'p>,
         type_forwarder: &TypeForwarder,
+        unnamed_types: &UnnamedTypeMap,
         type_index: pdb::TypeIndex,
         primitive_flavor: &PrimitiveReconstructionFlavor,
+        primitive_type_mapping: &PrimitiveTypeMapping,
         needed_types: &mut NeededTypeSet,
     ) -> Result<Method<'p>> {
         match type_finder.find(type_index)?.parse()? {
@@ -34,15 +44,19 @@ impl<'p> Method<'p> {
                 return_type_name: type_name(
                     type_finder,
                     type_forwarder,
+                    unnamed_types,
                     data.return_type,
                     primitive_flavor,
+                    primitive_type_mapping,
                     needed_types,
                 )?,
                 arguments: argument_list(
                     type_finder,
                     type_forwarder,
+                    unnamed_types,
                     data.argument_list,
                     primitive_flavor,
+                    primitive_type_mapping,
                     needed_types,
                 )?,
                 is_virtual: attributes.is_virtual()
@@ -50,6 +64,16 @@ impl<'p> Method<'p> {
                     | attributes.is_intro_virtual(),
                 // FIXME: Check the `is_intro_virtual` issue.
                 is_pure_virtual: attributes.is_pure_virtual(),
+                // Note: methods introducing a virtual function are flagged as
+                // such, the other virtual methods override a base class' method
+                is_override: (attributes.is_virtual() || attributes.is_pure_virtual())
+                    && !attributes.is_intro_virtual(),
+                calling_convention: match primitive_flavor {
+                    PrimitiveReconstructionFlavor::Microsoft => {
+                        calling_convention_keyword(data.attributes.calling_convention())
+                    }
+                    _ => None,
+                },
                 is_ctor: data.attributes.is_constructor()
                     || data.attributes.is_constructor_with_virtual_bases(),
                 is_dtor: name.to_string().starts_with('~'),
@@ -72,7 +96,7 @@ impl<'p> Method<'p> {
 
             other => {
                 log::error!("other: {:?}", other);
-                Err(ResymCoreError::NotImplementedError(
+                Err(ResymCoreError::UnsupportedTypeRecordError(
                     "Unhandled type data".to_owned(),
                 ))
             }
@@ -105,3 +129,38 @@ impl<'p> Method<'p> {
         }
     }
 }
+
+/// Keyword of the given `CV_call_e` calling convention. Note: `__cdecl` (near
+/// C) is omitted, as it's the default convention and the only one recorded for
+/// x64 (and ARM64) code, where the other keywords are ignored by the compiler.
+fn calling_convention_keyword(calling_convention: u8) -> Option<&'static str> {
+    match calling_convention {
+        0x02 | 0x03 => Some("__pascal"),
+        0x04 | 0x05 => Some("__fastcall"),
+        0x07 | 0x08 => Some("__stdcall"),
+        0x0b => Some("__thiscall"),
+        0x11 => Some("__clrcall"),
+        0x18 => Some("__vectorcall"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calling_convention_keyword_omits_near_c() {
+        // `CV_CALL_NEAR_C`
+        assert_eq!(calling_convention_keyword(0x00), None);
+    }
+
+    #[test]
+    fn calling_convention_keyword_prints_explicit_conventions() {
+        // E.g., COM methods (`STDMETHOD`) are `__stdcall` on x86
+        assert_eq!(calling_convention_keyword(0x07), Some("__stdcall"));
+        assert_eq!(calling_convention_keyword(0x0b), Some("__thiscall"));
+        assert_eq!(calling_convention_keyword(0x04), Some("__fastcall"));
+        assert_eq!(calling_convention_keyword(0x18), Some("__vectorcall"));
+    }
+}
@@ -1,24 +1,40 @@
 mod class;
+mod code_format;
 mod enumeration;
 mod field;
 mod forward_declaration;
 mod method;
+mod number_format;
+mod opaque_types;
 mod primitive_types;
+mod renames;
 mod union;
 
-use std::collections::{BTreeMap, HashSet};
-use std::fmt;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::{self, Write as _};
 use std::ops::Range;
 
 use crate::error::{Result, ResymCoreError};
+use crate::layout_check::{FieldLayout, TypeLayout};
 use class::Class;
+use code_format::write_opening_brace;
 use enumeration::Enum;
-use field::{Field, FieldAccess};
+use field::{Field, FieldAccess, StaticField};
 use method::Method;
 use primitive_types::primitive_kind_as_str;
 use union::Union;
 
-pub use primitive_types::{include_headers_for_flavor, PrimitiveReconstructionFlavor};
+pub use code_format::{format_code, BraceStyle, CodeFormatConfiguration, PointerAlignment};
+pub use enumeration::EnumValueOrder;
+pub use number_format::{NumberBase, NumberFormat};
+pub use opaque_types::{OpaqueTypeRule, OpaqueTypeRules};
+pub use primitive_types::{
+    include_headers_for_flavor, PrimitiveReconstructionFlavor, PrimitiveTypeMapping,
+};
+pub use renames::RenameMap;
+
+/// Free-form notes attached to types by the user, by type name
+pub type TypeNotes = BTreeMap<String, String>;
 
 use self::forward_declaration::{ForwardDeclaration, ForwardDeclarationKind};
 
@@ -30,18 +46,28 @@ pub type NeededTypeSet = HashSet<(pdb::TypeIndex, bool)>;
 
 pub type TypeForwarder = dashmap::DashMap<pdb::TypeIndex, pdb::TypeIndex>;
 
+/// Complete anonymous types of a PDB, by content hash and in the order of the
+/// type stream (see `unnamed_type_name`)
+pub type UnnamedTypeMap = HashMap<u64, Vec<pdb::TypeIndex>>;
+
 /// Return a pair of strings representing the given `type_index`.
 pub fn type_name(
     type_finder: &pdb::TypeFinder,
     type_forwarder: &TypeForwarder,
+    unnamed_types: &UnnamedTypeMap,
     type_index: pdb::TypeIndex,
     primitive_flavor: &PrimitiveReconstructionFlavor,
+    primitive_type_mapping: &PrimitiveTypeMapping,
     needed_types: &mut NeededTypeSet,
 ) -> Result<(String, String)> {
     let (type_left, type_right) = match type_finder.find(type_index)?.parse()? {
         pdb::TypeData::Primitive(data) => {
-            let name =
-                primitive_kind_as_str(primitive_flavor, data.kind, data.indirection.is_some())?;
+            let name = primitive_kind_as_str(
+                primitive_flavor,
+                primitive_type_mapping,
+                data.kind,
+                data.indirection.is_some(),
+            )?;
 
             (name, String::default())
         }
@@ -51,7 +77,7 @@ pub fn type_name(
             // Rename unnamed anonymous tags to something unique
             let name = data.name.to_string();
             if is_unnamed_type(&name) {
-                let name = format!("_unnamed_{type_index}");
+                let name = unnamed_type_name(type_finder, unnamed_types, type_index);
                 (name, String::default())
             } else {
                 (name.into_owned(), String::default())
@@ -63,7 +89,7 @@ pub fn type_name(
             // Rename unnamed anonymous tags to something unique
             let name = data.name.to_string();
             if is_unnamed_type(&name) {
-                let name = format!("_unnamed_{type_index}");
+                let name = unnamed_type_name(type_finder, unnamed_types, type_index);
                 (name, String::default())
             } else {
                 (name.into_owned(), String::default())
@@ -83,8 +109,10 @@ pub fn type_name(
             let (type_left, type_right) = type_name(
                 type_finder,
                 type_forwarder,
+                unnamed_types,
                 complete_underlying_type_index,
                 primitive_flavor,
+                primitive_type_mapping,
                 &mut temporary_needed_types,
             )?;
 
@@ -112,8 +140,10 @@ pub fn type_name(
             let (type_left, type_right) = type_name(
                 type_finder,
                 type_forwarder,
+                unnamed_types,
                 complete_underlying_type_index,
                 primitive_flavor,
+                primitive_type_mapping,
                 needed_types,
             )?;
 
@@ -134,8 +164,10 @@ pub fn type_name(
             let ((type_left, type_right), mut dimensions) = array_base_name(
                 type_finder,
                 type_forwarder,
+                unnamed_types,
                 complete_element_type_index,
                 primitive_flavor,
+                primitive_type_mapping,
                 needed_types,
             )?;
             let type_size = u32::try_from(type_size(type_finder, complete_element_type_index)?)?;
@@ -178,8 +210,10 @@ pub fn type_name(
             let (type_left, type_right) = type_name(
                 type_finder,
                 type_forwarder,
+                unnamed_types,
                 complete_underlying_type_index,
                 primitive_flavor,
+                primitive_type_mapping,
                 needed_types,
             )?;
             (type_left, format!("{} : {}", type_right, data.length))
@@ -194,8 +228,10 @@ pub fn type_name(
                 type_name(
                     type_finder,
                     type_forwarder,
+                    unnamed_types,
                     complete_return_type_index,
                     primitive_flavor,
+                    primitive_type_mapping,
                     needed_types,
                 )?
             } else {
@@ -204,8 +240,10 @@ pub fn type_name(
             let arg_list = argument_list(
                 type_finder,
                 type_forwarder,
+                unnamed_types,
                 data.argument_list,
                 primitive_flavor,
+                primitive_type_mapping,
                 needed_types,
             )?;
 
@@ -232,22 +270,28 @@ pub fn type_name(
             let (ret_type_left, ret_type_right) = type_name(
                 type_finder,
                 type_forwarder,
+                unnamed_types,
                 complete_return_type_index,
                 primitive_flavor,
+                primitive_type_mapping,
                 needed_types,
             )?;
             let (class_type_left, _) = type_name(
                 type_finder,
                 type_forwarder,
+                unnamed_types,
                 complete_class_type_index,
                 primitive_flavor,
+                primitive_type_mapping,
                 needed_types,
             )?;
             let arg_list = argument_list(
                 type_finder,
                 type_forwarder,
+                unnamed_types,
                 data.argument_list,
                 primitive_flavor,
+                primitive_type_mapping,
                 needed_types,
             )?;
 
@@ -282,8 +326,10 @@ pub fn type_name(
 fn array_base_name(
     type_finder: &pdb::TypeFinder,
     type_forwarder: &TypeForwarder,
+    unnamed_types: &UnnamedTypeMap,
     type_index: pdb::TypeIndex,
     primitive_flavor: &PrimitiveReconstructionFlavor,
+    primitive_type_mapping: &PrimitiveTypeMapping,
     needed_types: &mut NeededTypeSet,
 ) -> Result<((String, String), Vec<usize>)> {
     match type_finder.find(type_index)?.parse()? {
@@ -294,8 +340,10 @@ fn array_base_name(
             let ((type_left, type_right), mut base_dimensions) = array_base_name(
                 type_finder,
                 type_forwarder,
+                unnamed_types,
                 complete_element_type_index,
                 primitive_flavor,
+                primitive_type_mapping,
                 needed_types,
             )?;
             let type_size = u32::try_from(type_size(type_finder, complete_element_type_index)?)?;
@@ -327,8 +375,10 @@ fn array_base_name(
             type_name(
                 type_finder,
                 type_forwarder,
+                unnamed_types,
                 type_index,
                 primitive_flavor,
+                primitive_type_mapping,
                 needed_types,
             )?,
             vec![],
@@ -339,8 +389,10 @@ fn array_base_name(
 pub fn argument_list(
     type_finder: &pdb::TypeFinder,
     type_forwarder: &TypeForwarder,
+    unnamed_types: &UnnamedTypeMap,
     type_index: pdb::TypeIndex,
     primitive_flavor: &PrimitiveReconstructionFlavor,
+    primitive_type_mapping: &PrimitiveTypeMapping,
     needed_types: &mut NeededTypeSet,
 ) -> Result<Vec<(String, String)>> {
     match type_finder.find(type_index)?.parse()? {
@@ -350,8 +402,10 @@ pub fn argument_list(
                 args.push(type_name(
                     type_finder,
                     type_forwarder,
+                    unnamed_types,
                     arg_type,
                     primitive_flavor,
+                    primitive_type_mapping,
                     needed_types,
                 )?);
             }
@@ -467,6 +521,194 @@ pub fn is_unnamed_type(type_name: &str) -> bool {
         || type_name.contains("__unnamed")
 }
 
+/// Name given to the anonymous type with the given index. The name is derived
+/// from the type's content (kind, size and fields) rather than from its index,
+/// so that the same anonymous type gets the same name in different PDBs and
+/// diffs don't show spurious renames.
+/// Anonymous types sharing the same content in a PDB are told apart by an
+/// ordinal, following their order in `unnamed_types`.
+pub fn unnamed_type_name(
+    type_finder: &pdb::TypeFinder,
+    unnamed_types: &UnnamedTypeMap,
+    type_index: pdb::TypeIndex,
+) -> String {
+    let Some(hash) = unnamed_type_hash(type_finder, type_index) else {
+        // Fall back to the type's index, which is at least unique in its PDB
+        return format!("_unnamed_{type_index}");
+    };
+
+    let ordinal = unnamed_types
+        .get(&hash)
+        .and_then(|type_indices| type_indices.iter().position(|index| *index == type_index))
+        .unwrap_or_default();
+    format_unnamed_type_name(hash, ordinal)
+}
+
+/// Record the complete anonymous type with the given index in `unnamed_types`
+/// and return its name (see `unnamed_type_name`). Types must be added in the
+/// order of the type stream.
+pub fn add_unnamed_type(
+    type_finder: &pdb::TypeFinder,
+    unnamed_types: &mut UnnamedTypeMap,
+    type_index: pdb::TypeIndex,
+) -> String {
+    let Some(hash) = unnamed_type_hash(type_finder, type_index) else {
+        return format!("_unnamed_{type_index}");
+    };
+
+    let type_indices = unnamed_types.entry(hash).or_default();
+    type_indices.push(type_index);
+    format_unnamed_type_name(hash, type_indices.len() - 1)
+}
+
+fn unnamed_type_hash(type_finder: &pdb::TypeFinder, type_index: pdb::TypeIndex) -> Option<u64> {
+    let mut signature = String::new();
+    write_type_signature(type_finder, type_index, 0, &mut signature).ok()?;
+    Some(stable_hash(signature.as_bytes()))
+}
+
+fn format_unnamed_type_name(hash: u64, ordinal: usize) -> String {
+    if ordinal == 0 {
+        format!("_unnamed_{hash:016x}")
+    } else {
+        format!("_unnamed_{hash:016x}_{ordinal}")
+    }
+}
+
+/// Hash the given data with FNV-1a. Unlike `DefaultHasher`'s, its output is
+/// guaranteed not to change between Rust versions, so it can be persisted.
+pub(crate) fn stable_hash(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Decorated name of a type record, if any
+fn owned_unique_name(unique_name: Option<pdb::RawString>) -> Option<String> {
+    unique_name.map(|unique_name| unique_name.to_string().into_owned())
+}
+
+/// Describe the given type in `signature`, for `unnamed_type_name`.
+/// Named types are described by their name and anonymous types by their
+/// content, which includes the anonymous types they contain.
+fn write_type_signature(
+    type_finder: &pdb::TypeFinder,
+    type_index: pdb::TypeIndex,
+    depth: usize,
+    signature: &mut String,
+) -> Result<()> {
+    // Limit recursion in case of malformed PDBs
+    const MAX_DEPTH: usize = 16;
+    if depth > MAX_DEPTH {
+        return Ok(());
+    }
+
+    match type_finder.find(type_index)?.parse()? {
+        pdb::TypeData::Primitive(data) => {
+            let _ = write!(signature, "{:?}{:?}", data.kind, data.indirection);
+        }
+        pdb::TypeData::Class(data) => {
+            let name = data.name.to_string();
+            if depth > 0 && !is_unnamed_type(&name) {
+                signature.push_str(&name);
+            } else {
+                let _ = write!(signature, "{:?}[{}]{{", data.kind, data.size);
+                if let Some(fields) = data.fields {
+                    write_field_list_signature(type_finder, fields, depth, signature)?;
+                }
+                signature.push('}');
+            }
+        }
+        pdb::TypeData::Union(data) => {
+            let name = data.name.to_string();
+            if depth > 0 && !is_unnamed_type(&name) {
+                signature.push_str(&name);
+            } else {
+                let _ = write!(signature, "Union[{}]{{", data.size);
+                write_field_list_signature(type_finder, data.fields, depth, signature)?;
+                signature.push('}');
+            }
+        }
+        pdb::TypeData::Enumeration(data) => {
+            let name = data.name.to_string();
+            if depth > 0 && !is_unnamed_type(&name) {
+                signature.push_str(&name);
+            } else {
+                signature.push_str("Enum:");
+                write_type_signature(type_finder, data.underlying_type, depth + 1, signature)?;
+                signature.push('{');
+                write_field_list_signature(type_finder, data.fields, depth, signature)?;
+                signature.push('}');
+            }
+        }
+        pdb::TypeData::Pointer(data) => {
+            write_type_signature(type_finder, data.underlying_type, depth + 1, signature)?;
+            signature.push('*');
+        }
+        pdb::TypeData::Modifier(data) => {
+            if data.constant {
+                signature.push_str("const ");
+            }
+            if data.volatile {
+                signature.push_str("volatile ");
+            }
+            write_type_signature(type_finder, data.underlying_type, depth + 1, signature)?;
+        }
+        pdb::TypeData::Array(data) => {
+            write_type_signature(type_finder, data.element_type, depth + 1, signature)?;
+            let _ = write!(signature, "{:?}", data.dimensions);
+        }
+        pdb::TypeData::Bitfield(data) => {
+            write_type_signature(type_finder, data.underlying_type, depth + 1, signature)?;
+            let _ = write!(signature, ":{}@{}", data.length, data.position);
+        }
+        // Other types (e.g., function types) don't need to be told apart
+        _ => signature.push('?'),
+    }
+
+    Ok(())
+}
+
+fn write_field_list_signature(
+    type_finder: &pdb::TypeFinder,
+    type_index: pdb::TypeIndex,
+    depth: usize,
+    signature: &mut String,
+) -> Result<()> {
+    if let pdb::TypeData::FieldList(data) = type_finder.find(type_index)?.parse()? {
+        for field in &data.fields {
+            match field {
+                pdb::TypeData::Member(data) => {
+                    let _ = write!(signature, "{}@{}:", data.name, data.offset);
+                    write_type_signature(type_finder, data.field_type, depth + 1, signature)?;
+                    signature.push(';');
+                }
+                pdb::TypeData::Enumerate(data) => {
+                    let _ = write!(signature, "{}={:?};", data.name, data.value);
+                }
+                // Nested type declarations and methods of anonymous types are
+                // reflected by their members
+                _ => {}
+            }
+        }
+
+        if let Some(continuation) = data.continuation {
+            write_field_list_signature(type_finder, continuation, depth, signature)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Format an RVA, followed by the matching virtual address if an image base is
+/// given (e.g., `RVA=0x1000 VA=0x180001000`)
+pub fn format_rva(rva: u32, image_base: Option<u64>) -> String {
+    match image_base {
+        Some(image_base) => format!("RVA={rva:#x} VA={:#x}", image_base.wrapping_add(rva.into())),
+        None => format!("RVA={rva:#x}"),
+    }
+}
+
 /// Trait for type data that can be reconstructed to C++
 pub trait ReconstructibleTypeData {
     fn reconstruct(
@@ -495,10 +737,65 @@ pub struct Data<'p> {
 }
 
 impl Data<'_> {
+    /// Return the size and field offsets of the class, struct or union added
+    /// with the given index, if any
+    pub fn type_layout(&self, type_index: pdb::TypeIndex) -> Option<TypeLayout> {
+        let (name, size, fields) = if let Some(class) = self.classes.get(&type_index) {
+            (&class.name, class.size, &class.fields)
+        } else {
+            let union = self.unions.get(&type_index)?;
+            (&union.name, union.size, &union.fields)
+        };
+
+        Some(TypeLayout {
+            name: name.clone(),
+            size: Some(size),
+            fields: fields
+                .iter()
+                .map(|field| FieldLayout {
+                    name: field.name.to_string().into_owned(),
+                    offset: field.offset,
+                    is_bitfield: field.bitfield_info.is_some(),
+                })
+                .collect(),
+        })
+    }
+
+    /// Reconstruct the types added so far, in `type_order` (or in type index
+    /// order if it's empty)
     pub fn reconstruct(
         &self,
         fmt_configuration: &DataFormatConfiguration,
-        type_depth_map: &BTreeMap<usize, Vec<pdb::TypeIndex>>,
+        type_order: &[pdb::TypeIndex],
+        output_writer: &mut impl std::fmt::Write,
+    ) -> Result<()> {
+        let use_default_format =
+            fmt_configuration.code_format == CodeFormatConfiguration::default();
+        if use_default_format && fmt_configuration.renames.is_empty() {
+            return self.reconstruct_unformatted(fmt_configuration, type_order, output_writer);
+        }
+
+        // Apply renames and reformat the emitter's output if needed.
+        // Note: renames are applied first, as they rely on the emitter's style.
+        let mut unformatted_output = String::new();
+        self.reconstruct_unformatted(fmt_configuration, type_order, &mut unformatted_output)?;
+        let renamed_output = fmt_configuration.renames.apply(&unformatted_output);
+        if use_default_format {
+            output_writer.write_str(&renamed_output)?;
+        } else {
+            output_writer.write_str(&format_code(
+                &renamed_output,
+                &fmt_configuration.code_format,
+            ))?;
+        }
+
+        Ok(())
+    }
+
+    fn reconstruct_unformatted(
+        &self,
+        fmt_configuration: &DataFormatConfiguration,
+        type_order: &[pdb::TypeIndex],
         output_writer: &mut impl std::fmt::Write,
     ) -> Result<()> {
         // Forward declarations
@@ -513,37 +810,56 @@ impl Data<'_> {
             e.reconstruct(fmt_configuration, output_writer)?;
         }
 
-        if !type_depth_map.is_empty() {
-            // Follow type depth map order
-            for type_indices in type_depth_map.values().rev() {
-                for type_index in type_indices.iter() {
-                    // Enum definitions
-                    if let Some(e) = self.enums.get(type_index) {
-                        if self.ignore_std_types && e.name.starts_with("std::") {
-                            // Type is in the `std` namespace and should be ignored
-                            continue;
-                        }
-                        writeln!(output_writer)?;
-                        e.reconstruct(fmt_configuration, output_writer)?;
+        if !type_order.is_empty() {
+            // Follow the given order
+            for type_index in type_order {
+                // Enum definitions
+                if let Some(e) = self.enums.get(type_index) {
+                    if self.ignore_std_types && e.name.starts_with("std::") {
+                        // Type is in the `std` namespace and should be ignored
+                        continue;
                     }
-                    // Class definitions
-                    else if let Some(c) = self.classes.get(type_index) {
-                        if self.ignore_std_types && c.name.starts_with("std::") {
-                            // Type is in the `std` namespace and should be ignored
-                            continue;
-                        }
-                        writeln!(output_writer)?;
-                        c.reconstruct(fmt_configuration, output_writer)?;
+                    writeln!(output_writer)?;
+                    write_type_comment(
+                        fmt_configuration,
+                        &e.name,
+                        None,
+                        e.unique_name.as_deref(),
+                        output_writer,
+                    )?;
+                    e.reconstruct(fmt_configuration, output_writer)?;
+                }
+                // Class definitions
+                else if let Some(c) = self.classes.get(type_index) {
+                    if self.ignore_std_types && c.name.starts_with("std::") {
+                        // Type is in the `std` namespace and should be ignored
+                        continue;
                     }
-                    // Union definitions
-                    else if let Some(u) = self.unions.get(type_index) {
-                        if self.ignore_std_types && u.name.starts_with("std::") {
-                            // Type is in the `std` namespace and should be ignored
-                            continue;
-                        }
-                        writeln!(output_writer)?;
-                        u.reconstruct(fmt_configuration, output_writer)?;
+                    writeln!(output_writer)?;
+                    write_type_comment(
+                        fmt_configuration,
+                        &c.name,
+                        Some(c.size),
+                        c.unique_name.as_deref(),
+                        output_writer,
+                    )?;
+                    c.reconstruct(fmt_configuration, output_writer)?;
+                }
+                // Union definitions
+                else if let Some(u) = self.unions.get(type_index) {
+                    if self.ignore_std_types && u.name.starts_with("std::") {
+                        // Type is in the `std` namespace and should be ignored
+                        continue;
                     }
+                    writeln!(output_writer)?;
+                    write_type_comment(
+                        fmt_configuration,
+                        &u.name,
+                        Some(u.size),
+                        u.unique_name.as_deref(),
+                        output_writer,
+                    )?;
+                    u.reconstruct(fmt_configuration, output_writer)?;
                 }
             }
         } else {
@@ -556,6 +872,13 @@ impl Data<'_> {
                     continue;
                 }
                 writeln!(output_writer)?;
+                write_type_comment(
+                    fmt_configuration,
+                    &e.name,
+                    None,
+                    e.unique_name.as_deref(),
+                    output_writer,
+                )?;
                 e.reconstruct(fmt_configuration, output_writer)?;
             }
 
@@ -566,6 +889,13 @@ impl Data<'_> {
                     continue;
                 }
                 writeln!(output_writer)?;
+                write_type_comment(
+                    fmt_configuration,
+                    &class.name,
+                    Some(class.size),
+                    class.unique_name.as_deref(),
+                    output_writer,
+                )?;
                 class.reconstruct(fmt_configuration, output_writer)?;
             }
 
@@ -576,6 +906,13 @@ impl Data<'_> {
                     continue;
                 }
                 writeln!(output_writer)?;
+                write_type_comment(
+                    fmt_configuration,
+                    &u.name,
+                    Some(u.size),
+                    u.unique_name.as_deref(),
+                    output_writer,
+                )?;
                 u.reconstruct(fmt_configuration, output_writer)?;
             }
         }
@@ -584,6 +921,97 @@ impl Data<'_> {
     }
 }
 
+/// Write the note attached to the given type, if any, as a comment block.
+/// When Doxygen comments are enabled, the type's metadata is added to the
+/// block.
+fn write_type_comment(
+    fmt_configuration: &DataFormatConfiguration,
+    type_name: &str,
+    size: Option<u64>,
+    unique_name: Option<&str>,
+    f: &mut impl std::fmt::Write,
+) -> fmt::Result {
+    let note = fmt_configuration
+        .type_notes
+        .get(type_name)
+        .filter(|note| !note.trim().is_empty());
+
+    let mut lines: Vec<String> = note
+        .map(|note| {
+            note.trim_end()
+                .lines()
+                // Make sure notes cannot terminate the comment block early
+                .map(|line| line.replace("*/", "* /"))
+                .collect()
+        })
+        .unwrap_or_default();
+    if fmt_configuration.doxygen_comments {
+        if !lines.is_empty() {
+            lines.push(String::default());
+        }
+        if let Some(size) = size {
+            lines.push(format!(
+                "- Size: {}",
+                fmt_configuration.number_format.size(size)
+            ));
+        }
+        if let Some(unique_name) = unique_name {
+            lines.push(format!("- Mangled name: `{unique_name}`"));
+        }
+        if !fmt_configuration.source_pdb_name.is_empty() {
+            lines.push(format!(
+                "- Source PDB: {}",
+                fmt_configuration.source_pdb_name.replace("*/", "* /")
+            ));
+        }
+    }
+    if lines.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(
+        f,
+        "{}",
+        if fmt_configuration.doxygen_comments {
+            "/**"
+        } else {
+            "/*"
+        }
+    )?;
+    for line in lines {
+        if line.is_empty() {
+            writeln!(f, " *")?;
+        } else {
+            writeln!(f, " * {line}")?;
+        }
+    }
+    writeln!(f, " */")
+}
+
+/// Write a Doxygen comment with the given field's offset and size, if enabled
+fn write_field_comment(
+    fmt_configuration: &DataFormatConfiguration,
+    field: &Field,
+    indentation: &str,
+    f: &mut impl std::fmt::Write,
+) -> fmt::Result {
+    if !fmt_configuration.doxygen_comments {
+        return Ok(());
+    }
+
+    let number_format = &fmt_configuration.number_format;
+    write!(
+        f,
+        "{indentation}/** Offset: {}, size: {}",
+        number_format.offset(field.offset),
+        number_format.size(field.size as u64)
+    )?;
+    if let Some((bit_position, bit_size)) = field.bitfield_info {
+        write!(f, ", bit position: {bit_position}, bit width: {bit_size}")?;
+    }
+    writeln!(f, " */")
+}
+
 impl<'p> Data<'p> {
     pub fn new(ignore_std_types: bool) -> Self {
         Self {
@@ -596,12 +1024,15 @@ impl<'p> Data<'p> {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn add(
         &mut self,
         type_finder: &pdb::TypeFinder<'p>,
         type_forwarder: &TypeForwarder,
+        unnamed_types: &UnnamedTypeMap,
         type_index: pdb::TypeIndex,
         primitive_flavor: &PrimitiveReconstructionFlavor,
+        primitive_type_mapping: &PrimitiveTypeMapping,
         needed_types: &mut NeededTypeSet,
     ) -> Result<()> {
         match type_finder.find(type_index)?.parse()? {
@@ -609,7 +1040,7 @@ impl<'p> Data<'p> {
                 let name_str = data.name.to_string();
                 // Rename unnamed anonymous tags to something unique
                 let name = if is_unnamed_type(&name_str) {
-                    format!("_unnamed_{type_index}")
+                    unnamed_type_name(type_finder, unnamed_types, type_index)
                 } else {
                     name_str.into_owned()
                 };
@@ -627,6 +1058,7 @@ impl<'p> Data<'p> {
                     index: type_index,
                     kind: data.kind,
                     name: name.clone(),
+                    unique_name: owned_unique_name(data.unique_name),
                     size: data.size,
                     fields: Vec::new(),
                     static_fields: Vec::new(),
@@ -650,8 +1082,10 @@ impl<'p> Data<'p> {
                     if let Err(err) = class.add_fields(
                         type_finder,
                         type_forwarder,
+                        unnamed_types,
                         fields,
                         primitive_flavor,
+                        primitive_type_mapping,
                         needed_types,
                     ) {
                         log::error!(
@@ -670,7 +1104,7 @@ impl<'p> Data<'p> {
                 let name_str = data.name.to_string();
                 // Rename unnamed anonymous tags to something unique
                 let name = if is_unnamed_type(&name_str) {
-                    format!("_unnamed_{type_index}")
+                    unnamed_type_name(type_finder, unnamed_types, type_index)
                 } else {
                     name_str.into_owned()
                 };
@@ -683,6 +1117,7 @@ impl<'p> Data<'p> {
                 let mut u = Union {
                     index: type_index,
                     name: name.clone(),
+                    unique_name: owned_unique_name(data.unique_name),
                     size: data.size,
                     fields: Vec::new(),
                     static_fields: Vec::new(),
@@ -696,8 +1131,10 @@ impl<'p> Data<'p> {
                 if let Err(err) = u.add_fields(
                     type_finder,
                     type_forwarder,
+                    unnamed_types,
                     data.fields,
                     primitive_flavor,
+                    primitive_type_mapping,
                     needed_types,
                 ) {
                     log::error!(
@@ -715,7 +1152,7 @@ impl<'p> Data<'p> {
                 let name_str = data.name.to_string();
                 // Rename unnamed anonymous tags to something unique
                 let name = if is_unnamed_type(&name_str) {
-                    format!("_unnamed_{type_index}")
+                    unnamed_type_name(type_finder, unnamed_types, type_index)
                 } else {
                     name_str.into_owned()
                 };
@@ -728,11 +1165,14 @@ impl<'p> Data<'p> {
                 let mut e = Enum {
                     index: type_index,
                     name: name.clone(),
+                    unique_name: owned_unique_name(data.unique_name),
                     underlying_type_name: type_name(
                         type_finder,
                         type_forwarder,
+                        unnamed_types,
                         data.underlying_type,
                         primitive_flavor,
+                        primitive_type_mapping,
                         needed_types,
                     )?
                     .0,
@@ -761,6 +1201,7 @@ impl<'p> Data<'p> {
     pub fn add_as_forward_declaration(
         &mut self,
         type_finder: &pdb::TypeFinder<'p>,
+        unnamed_types: &UnnamedTypeMap,
         type_index: pdb::TypeIndex,
     ) -> Result<()> {
         match type_finder.find(type_index)?.parse()? {
@@ -768,7 +1209,7 @@ impl<'p> Data<'p> {
                 let name_str = data.name.to_string();
                 // Rename unnamed anonymous tags to something unique
                 let name = if is_unnamed_type(&name_str) {
-                    format!("_unnamed_{type_index}")
+                    unnamed_type_name(type_finder, unnamed_types, type_index)
                 } else {
                     name_str.into_owned()
                 };
@@ -787,7 +1228,7 @@ impl<'p> Data<'p> {
                 let name_str = data.name.to_string();
                 // Rename unnamed anonymous tags to something unique
                 let name = if is_unnamed_type(&name_str) {
-                    format!("_unnamed_{type_index}")
+                    unnamed_type_name(type_finder, unnamed_types, type_index)
                 } else {
                     name_str.into_owned()
                 };
@@ -807,6 +1248,36 @@ impl<'p> Data<'p> {
 
         Ok(())
     }
+
+    pub fn has_static_fields(&self) -> bool {
+        self.classes
+            .values()
+            .any(|class| !class.static_fields.is_empty())
+            || self
+                .unions
+                .values()
+                .any(|union| !union.static_fields.is_empty())
+    }
+
+    /// Attach RVAs to static data members, given the RVAs of global data
+    /// symbols by qualified name
+    pub fn resolve_static_field_rvas(&mut self, global_data_rvas: &HashMap<String, u32>) {
+        let static_field_rva = |type_name: &str, field: &StaticField| {
+            global_data_rvas
+                .get(&format!("{type_name}::{}", field.name))
+                .copied()
+        };
+        for class in self.classes.values_mut() {
+            for field in &mut class.static_fields {
+                field.rva = static_field_rva(&class.name, field);
+            }
+        }
+        for union in self.unions.values_mut() {
+            for field in &mut union.static_fields {
+                field.rva = static_field_rva(&union.name, field);
+            }
+        }
+    }
 }
 
 pub fn resolve_complete_type_index(
@@ -850,9 +1321,9 @@ fn fmt_struct_fields_recursive(
                             if bit_offset_delta > 0 {
                                 writeln!(
                                     f,
-                                    "{}/* {:#06x} */ {} : {}; /* BitPos={} */",
+                                    "{}/* {} */ {} : {}; /* BitPos={} */",
                                     &indentation,
-                                    last_field.offset,
+                                    fmt_configuration.number_format.offset(last_field.offset),
                                     last_field.type_left,
                                     bit_offset_delta,
                                     potential_padding_bit_offset
@@ -864,9 +1335,9 @@ fn fmt_struct_fields_recursive(
                             // needed. It's not incorrect but might produce less elegant output.
                             writeln!(
                                 f,
-                                "{}/* {:#06x} */ {} : 0; /* BitPos={} */",
+                                "{}/* {} */ {} : 0; /* BitPos={} */",
                                 &indentation,
-                                last_field.offset,
+                                fmt_configuration.number_format.offset(last_field.offset),
                                 last_field.type_left,
                                 potential_padding_bit_offset
                             )?;
@@ -875,11 +1346,12 @@ fn fmt_struct_fields_recursive(
                 }
             }
 
+            write_field_comment(fmt_configuration, field, &indentation, f)?;
             writeln!(
                 f,
-                "{}/* {:#06x} */ {}{} {}{};{}",
+                "{}/* {} */ {}{} {}{};{}",
                 &indentation,
-                field.offset,
+                fmt_configuration.number_format.offset(field.offset),
                 if fmt_configuration.print_access_specifiers {
                     &field.access
                 } else {
@@ -896,7 +1368,8 @@ fn fmt_struct_fields_recursive(
             )?;
             last_field = Some(field);
         } else {
-            writeln!(f, "{}union {{", &indentation)?;
+            write!(f, "{}union", &indentation)?;
+            write_opening_brace(f, &fmt_configuration.code_format, &indentation, "")?;
             fmt_union_fields_recursive(fmt_configuration, &fields[union_range], depth + 1, f)?;
             writeln!(f, "{}}};", &indentation)?;
             last_field = None;
@@ -1028,11 +1501,12 @@ fn fmt_union_fields_recursive(
         // Fields out of unnamed structs are represented by "empty" structs
         if struct_range.is_empty() {
             let field = &fields[struct_range.start];
+            write_field_comment(fmt_configuration, field, &indentation, f)?;
             writeln!(
                 f,
-                "{}/* {:#06x} */ {}{} {}{};{}",
+                "{}/* {} */ {}{} {}{};{}",
                 &indentation,
-                field.offset,
+                fmt_configuration.number_format.offset(field.offset),
                 if fmt_configuration.print_access_specifiers {
                     &field.access
                 } else {
@@ -1048,7 +1522,8 @@ fn fmt_union_fields_recursive(
                 }
             )?;
         } else {
-            writeln!(f, "{}struct {{", &indentation)?;
+            write!(f, "{}struct", &indentation)?;
+            write_opening_brace(f, &fmt_configuration.code_format, &indentation, "")?;
             fmt_struct_fields_recursive(fmt_configuration, &fields[struct_range], depth + 1, f)?;
             writeln!(f, "{}}};", &indentation)?;
         }
@@ -1113,12 +1588,102 @@ fn find_unnamed_structs_in_unions(fields: &[Field]) -> Vec<Range<usize>> {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DataFormatConfiguration {
     pub print_access_specifiers: bool,
+    pub code_format: CodeFormatConfiguration,
+    pub renames: RenameMap,
+    pub type_notes: TypeNotes,
+    pub member_filter: MemberFilter,
+    pub number_format: NumberFormat,
+    /// Emit Doxygen comment blocks with the metadata of types and fields
+    pub doxygen_comments: bool,
+    /// Annotate the enumerators of flag enums with their bits
+    pub flag_enum_comments: bool,
+    pub enum_value_order: EnumValueOrder,
+    /// Name of the PDB the types come from, mentioned in Doxygen comments
+    pub source_pdb_name: String,
+    /// Image base used to print virtual addresses next to RVAs, if any
+    pub image_base: Option<u64>,
 }
 
 impl Default for DataFormatConfiguration {
     fn default() -> Self {
         Self {
             print_access_specifiers: true,
+            code_format: CodeFormatConfiguration::default(),
+            renames: RenameMap::default(),
+            type_notes: TypeNotes::default(),
+            member_filter: MemberFilter::default(),
+            number_format: NumberFormat::default(),
+            doxygen_comments: false,
+            flag_enum_comments: false,
+            enum_value_order: EnumValueOrder::default(),
+            source_pdb_name: String::default(),
+            image_base: None,
+        }
+    }
+}
+
+/// Members left out of reconstructed types
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemberFilter {
+    /// Only print public members, to get the API surface of classes
+    pub public_only: bool,
+    /// Omit members and symbols injected by the compiler (e.g., deleting
+    /// destructors and dynamic initializers)
+    pub hide_compiler_generated: bool,
+}
+
+/// Names of members injected by the compiler
+const COMPILER_GENERATED_MEMBER_NAMES: &[&str] = &[
+    "__vecDelDtor",
+    "__scalarDelDtor",
+    "__vbaseDtor",
+    "__vfptr",
+    "__vbptr",
+    "__local_vftable_ctor_closure",
+    "__autoclassinit",
+    "__autoclassinit2",
+];
+/// Patterns found in the names of symbols generated by the compiler
+const COMPILER_GENERATED_NAME_PATTERNS: &[&str] = &[
+    "$initializer$",
+    "`scalar deleting destructor'",
+    "`vector deleting destructor'",
+    "`dynamic initializer for ",
+    "`dynamic atexit destructor for ",
+    "`vftable'",
+    "`vbtable'",
+];
+
+/// Return `true` if the given member or symbol name was generated by the
+/// compiler
+pub(crate) fn is_compiler_generated_name(name: &str) -> bool {
+    COMPILER_GENERATED_MEMBER_NAMES.contains(&name)
+        || COMPILER_GENERATED_NAME_PATTERNS
+            .iter()
+            .any(|pattern| name.contains(pattern))
+}
+
+impl MemberFilter {
+    /// Return `true` if the given member shouldn't be printed
+    pub(crate) fn is_hidden(&self, name: &str, access: &FieldAccess) -> bool {
+        (self.public_only && matches!(access, FieldAccess::Private | FieldAccess::Protected))
+            || (self.hide_compiler_generated && is_compiler_generated_name(name))
+    }
+
+    /// Return the fields which should be printed
+    pub(crate) fn visible_fields<'a, 'p>(
+        &self,
+        fields: &'a [Field<'p>],
+    ) -> std::borrow::Cow<'a, [Field<'p>]> {
+        if self.public_only || self.hide_compiler_generated {
+            fields
+                .iter()
+                .filter(|field| !self.is_hidden(&field.name.to_string(), &field.access))
+                .cloned()
+                .collect::<Vec<_>>()
+                .into()
+        } else {
+            fields.into()
         }
     }
 }
@@ -0,0 +1,67 @@
+/// Base used to print offsets, sizes and enum values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberBase {
+    /// `0x1c`
+    Hexadecimal,
+    /// `28`
+    Decimal,
+}
+
+/// Style of the numbers printed in reconstructed types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    pub base: NumberBase,
+    /// Pad hexadecimal offsets and enum values with leading zeros (e.g.,
+    /// `0x001c`). Decimal numbers are never padded, as leading zeros denote
+    /// octal literals in C.
+    pub zero_padded: bool,
+}
+
+impl Default for NumberFormat {
+    /// Style used by the emitter
+    fn default() -> Self {
+        Self {
+            base: NumberBase::Hexadecimal,
+            zero_padded: true,
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Format the offset of a member within its type
+    pub fn offset(&self, offset: u64) -> String {
+        self.unsigned(offset, 4)
+    }
+
+    /// Format the size of a type or symbol, never padded
+    pub fn size(&self, size: u64) -> String {
+        self.unsigned(size, 0)
+    }
+
+    /// Format an unsigned value, padded to the given number of hexadecimal
+    /// digits if needed
+    pub fn unsigned(&self, value: u64, digits: usize) -> String {
+        match self.base {
+            NumberBase::Hexadecimal if self.zero_padded => {
+                format!("{value:#0width$x}", width = digits + 2)
+            }
+            NumberBase::Hexadecimal => format!("{value:#x}"),
+            NumberBase::Decimal => value.to_string(),
+        }
+    }
+
+    /// Format a constant value (e.g., an enum value). Unsigned values are
+    /// padded to their width, signed values are always printed in decimal.
+    pub fn variant(&self, value: &pdb::Variant) -> String {
+        match *value {
+            pdb::Variant::U8(v) => self.unsigned(v.into(), 2),
+            pdb::Variant::U16(v) => self.unsigned(v.into(), 4),
+            pdb::Variant::U32(v) => self.unsigned(v.into(), 8),
+            pdb::Variant::U64(v) => self.unsigned(v, 16),
+            pdb::Variant::I8(v) => format!("{v}"),
+            pdb::Variant::I16(v) => format!("{v}"),
+            pdb::Variant::I32(v) => format!("{v}"),
+            pdb::Variant::I64(v) => format!("{v}"),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use regex::Regex;
+
+use crate::error::{Result, ResymCoreError};
+
+/// Rule matching types that shouldn't be reconstructed as dependencies
+#[derive(Debug, Clone)]
+pub struct OpaqueTypeRule {
+    pattern: Regex,
+    /// Header to include in place of the type's definition. Matching types are
+    /// forward declared if `None`.
+    pub header: Option<String>,
+}
+
+impl OpaqueTypeRule {
+    /// Return the `#include` directive associated with the rule, if any
+    pub fn include_directive(&self) -> Option<String> {
+        self.header.as_ref().map(|header| {
+            if header.starts_with('<') || header.starts_with('"') {
+                format!("#include {header}")
+            } else {
+                format!("#include <{header}>")
+            }
+        })
+    }
+}
+
+/// Set of rules used to treat well-known types (e.g., SDK or CRT types) as
+/// opaque when reconstructing dependencies
+#[derive(Debug, Clone, Default)]
+pub struct OpaqueTypeRules {
+    rules: Vec<OpaqueTypeRule>,
+}
+
+impl OpaqueTypeRules {
+    /// Parse rules from a string containing one rule per line, using the
+    /// `<regex> [=> <header>]` format. Empty lines are ignored.
+    pub fn parse(rules: &str) -> Result<Self> {
+        let rules = rules
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (pattern, header) = match line.split_once("=>") {
+                    Some((pattern, header)) => (pattern.trim(), Some(header.trim().to_string())),
+                    None => (line, None),
+                };
+                let pattern = Regex::new(pattern).map_err(|err| {
+                    ResymCoreError::InvalidParameterError(format!(
+                        "invalid opaque type pattern '{pattern}': {err}"
+                    ))
+                })?;
+
+                Ok(OpaqueTypeRule { pattern, header })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { rules })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Find the first rule matching the given type name
+    pub fn find(&self, type_name: &str) -> Option<&OpaqueTypeRule> {
+        self.rules
+            .iter()
+            .find(|rule| rule.pattern.is_match(type_name))
+    }
+}
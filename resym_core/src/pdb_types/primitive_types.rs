@@ -1,12 +1,18 @@
-use std::str::FromStr;
+use std::{collections::BTreeMap, str::FromStr};
 
 use crate::error::{Result, ResymCoreError};
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum PrimitiveReconstructionFlavor {
+    /// Fixed-width integer types from `<cstdint>` (e.g., `uint8_t`, `int64_t`)
     Portable,
+    /// `Portable` types, with `signed char` and `unsigned char` also treated
+    /// as fixed-width integers (i.e., `int8_t` and `uint8_t`)
+    Stdint,
     Microsoft,
     Raw,
+    /// `Portable` types, renamed with a user-defined `PrimitiveTypeMapping`
+    Custom,
 }
 
 impl FromStr for PrimitiveReconstructionFlavor {
@@ -15,8 +21,10 @@ impl FromStr for PrimitiveReconstructionFlavor {
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "portable" => Ok(PrimitiveReconstructionFlavor::Portable),
+            "stdint" => Ok(PrimitiveReconstructionFlavor::Stdint),
             "ms" | "msft" | "microsoft" => Ok(PrimitiveReconstructionFlavor::Microsoft),
             "raw" => Ok(PrimitiveReconstructionFlavor::Raw),
+            "custom" => Ok(PrimitiveReconstructionFlavor::Custom),
             _ => Err(ResymCoreError::ParsePrimitiveFlavorError(s.to_owned())),
         }
     }
@@ -27,7 +35,9 @@ pub fn include_headers_for_flavor(
     ignore_std_types: bool,
 ) -> String {
     let flavor_header = match flavor {
-        PrimitiveReconstructionFlavor::Portable => "#include <cstdint>\n",
+        PrimitiveReconstructionFlavor::Portable
+        | PrimitiveReconstructionFlavor::Stdint
+        | PrimitiveReconstructionFlavor::Custom => "#include <cstdint>\n",
         PrimitiveReconstructionFlavor::Microsoft => "#include <Windows.h>\n",
         PrimitiveReconstructionFlavor::Raw => "",
     };
@@ -50,8 +60,96 @@ pub fn include_headers_for_flavor(
     format!("{flavor_header}{common_std_headers}")
 }
 
+/// Names of primitive types with the `Portable` flavor
+const PORTABLE_PRIMITIVE_NAMES: [&str; 18] = [
+    "void",
+    "char",
+    "unsigned char",
+    "wchar_t",
+    "char16_t",
+    "char32_t",
+    "char8_t",
+    "int8_t",
+    "uint8_t",
+    "int16_t",
+    "uint16_t",
+    "int32_t",
+    "uint32_t",
+    "int64_t",
+    "uint64_t",
+    "float",
+    "double",
+    "bool",
+];
+
+/// User-defined names of primitive types (e.g., `u32` or `BYTE`), applied to
+/// types reconstructed with the `Custom` flavor. Primitive types which aren't
+/// mapped keep their `Portable` name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrimitiveTypeMapping {
+    names: BTreeMap<String, String>,
+}
+
+impl PrimitiveTypeMapping {
+    /// Parse a mapping from a string containing one primitive type per line,
+    /// using the `<portable name> => <name>` format (e.g., `uint32_t => u32`).
+    /// Empty lines are ignored.
+    pub fn parse(mapping: &str) -> Result<Self> {
+        let mut names = BTreeMap::new();
+        for line in mapping.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let Some((portable_name, name)) = line.split_once("=>") else {
+                return Err(ResymCoreError::InvalidParameterError(format!(
+                    "invalid primitive type mapping '{line}', expected '<portable name> => <name>'"
+                )));
+            };
+            let (portable_name, name) = (portable_name.trim(), name.trim());
+            if !PORTABLE_PRIMITIVE_NAMES.contains(&portable_name) {
+                return Err(ResymCoreError::InvalidParameterError(format!(
+                    "unknown primitive type '{portable_name}', expected one of: {}",
+                    PORTABLE_PRIMITIVE_NAMES.join(", ")
+                )));
+            }
+            if name.is_empty() {
+                return Err(ResymCoreError::InvalidParameterError(format!(
+                    "invalid primitive type mapping '{line}'"
+                )));
+            }
+            names.insert(portable_name.to_string(), name.to_string());
+        }
+
+        Ok(Self { names })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Return the name to display for the primitive type with the given
+    /// `Portable` name
+    pub fn name<'a>(&'a self, portable_name: &'a str) -> &'a str {
+        self.names
+            .get(portable_name)
+            .map_or(portable_name, String::as_str)
+    }
+}
+
+impl std::fmt::Display for PrimitiveTypeMapping {
+    /// Serialize the mapping in the format expected by
+    /// `PrimitiveTypeMapping::parse`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (portable_name, name) in self.names.iter() {
+            writeln!(f, "{portable_name} => {name}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Name of the given primitive type. `primitive_type_mapping` is only used
+/// with the `Custom` flavor.
 pub fn primitive_kind_as_str(
     flavor: &PrimitiveReconstructionFlavor,
+    primitive_type_mapping: &PrimitiveTypeMapping,
     primitive_kind: pdb::PrimitiveKind,
     indirection: bool,
 ) -> Result<String> {
@@ -59,6 +157,19 @@ pub fn primitive_kind_as_str(
         PrimitiveReconstructionFlavor::Portable => {
             primitive_kind_as_str_portable(primitive_kind, indirection)
         }
+        PrimitiveReconstructionFlavor::Stdint => {
+            primitive_kind_as_str_stdint(primitive_kind, indirection)
+        }
+        PrimitiveReconstructionFlavor::Custom => {
+            let mut string_representation = primitive_type_mapping
+                .name(primitive_kind_portable_name(primitive_kind)?)
+                .to_string();
+            if indirection {
+                string_representation.push('*');
+            }
+
+            Ok(string_representation)
+        }
         PrimitiveReconstructionFlavor::Microsoft => {
             primitive_kind_as_str_microsoft(primitive_kind, indirection)
         }
@@ -72,7 +183,34 @@ fn primitive_kind_as_str_portable(
     primitive_kind: pdb::PrimitiveKind,
     indirection: bool,
 ) -> Result<String> {
-    let str_representation = match primitive_kind {
+    let mut string_representation = primitive_kind_portable_name(primitive_kind)?.to_string();
+    if indirection {
+        string_representation.push('*');
+    }
+
+    Ok(string_representation)
+}
+
+fn primitive_kind_as_str_stdint(
+    primitive_kind: pdb::PrimitiveKind,
+    indirection: bool,
+) -> Result<String> {
+    let mut string_representation = match primitive_kind {
+        // Only plain `char` (`RChar`) is meant for text
+        pdb::PrimitiveKind::Char => "int8_t",
+        pdb::PrimitiveKind::UChar => "uint8_t",
+        _ => primitive_kind_portable_name(primitive_kind)?,
+    }
+    .to_string();
+    if indirection {
+        string_representation.push('*');
+    }
+
+    Ok(string_representation)
+}
+
+fn primitive_kind_portable_name(primitive_kind: pdb::PrimitiveKind) -> Result<&'static str> {
+    match primitive_kind {
         pdb::PrimitiveKind::Void => Ok("void"),
         pdb::PrimitiveKind::Char | pdb::PrimitiveKind::RChar => Ok("char"),
         pdb::PrimitiveKind::UChar => Ok("unsigned char"),
@@ -103,17 +241,10 @@ fn primitive_kind_as_str_portable(
         // cases of legitimate "NoType" occurences?
         pdb::PrimitiveKind::NoType => Ok("..."),
 
-        _ => Err(ResymCoreError::NotImplementedError(format!(
+        _ => Err(ResymCoreError::UnsupportedTypeRecordError(format!(
             "/* FIXME: Unhandled primitive kind: '{primitive_kind:?}' */ void"
         ))),
-    };
-
-    let mut string_representation = str_representation?.to_string();
-    if indirection {
-        string_representation.push('*');
     }
-
-    Ok(string_representation)
 }
 
 fn primitive_kind_as_str_microsoft(
@@ -167,7 +298,7 @@ fn primitive_kind_as_str_microsoft(
         // cases of legitimate "NoType" occurences?
         pdb::PrimitiveKind::NoType => Ok("..."),
 
-        _ => Err(ResymCoreError::NotImplementedError(format!(
+        _ => Err(ResymCoreError::UnsupportedTypeRecordError(format!(
             "/* FIXME: Unhandled primitive kind: '{primitive_kind:?}' */ void"
         ))),
     };
@@ -208,7 +339,7 @@ fn primitive_kind_as_str_raw(
         // cases of legitimate "NoType" occurences?
         pdb::PrimitiveKind::NoType => Ok("..."),
 
-        _ => Err(ResymCoreError::NotImplementedError(format!(
+        _ => Err(ResymCoreError::UnsupportedTypeRecordError(format!(
             "/* FIXME: Unhandled primitive kind: '{primitive_kind:?}' */ void"
         ))),
     };
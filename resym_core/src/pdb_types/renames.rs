@@ -0,0 +1,294 @@
+use std::{borrow::Cow, collections::BTreeMap, sync::OnceLock};
+
+use regex::Regex;
+
+use crate::error::{Result, ResymCoreError};
+
+/// User-defined names for types and members, applied to reconstructed code
+/// without modifying the PDB (e.g., to give meaningful names to
+/// `_unnamed_1234`-like types)
+#[derive(Debug, Clone, Default)]
+pub struct RenameMap {
+    types: IdentifierRenames,
+    /// Member renames, by type name
+    members: BTreeMap<String, IdentifierRenames>,
+    /// Renames applied within type definitions, built on first use
+    scoped_renames: OnceLock<BTreeMap<String, IdentifierRenames>>,
+}
+
+impl PartialEq for RenameMap {
+    fn eq(&self, other: &Self) -> bool {
+        self.types == other.types && self.members == other.members
+    }
+}
+
+impl Eq for RenameMap {}
+
+impl RenameMap {
+    /// Parse renames from a string containing one rename per line, using the
+    /// `<type> => <new name>` format for types and the
+    /// `<type>.<member> => <new name>` format for members. Empty lines are
+    /// ignored.
+    pub fn parse(renames: &str) -> Result<Self> {
+        let mut rename_map = Self::default();
+        for line in renames.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let Some((old_name, new_name)) = line.split_once("=>") else {
+                return Err(ResymCoreError::InvalidParameterError(format!(
+                    "invalid rename '{line}', expected '<name> => <new name>'"
+                )));
+            };
+            let (old_name, new_name) = (old_name.trim(), new_name.trim());
+            let invalid_rename_error =
+                || ResymCoreError::InvalidParameterError(format!("invalid rename '{line}'"));
+            if old_name.is_empty() {
+                return Err(invalid_rename_error());
+            }
+
+            match old_name.rsplit_once('.') {
+                Some((type_name, member_name)) if is_identifier(member_name) => {
+                    if !is_identifier(new_name) {
+                        return Err(invalid_rename_error());
+                    }
+                    rename_map.rename_member(type_name.trim(), member_name, new_name);
+                }
+                _ => {
+                    if !new_name.split("::").all(is_identifier) {
+                        return Err(invalid_rename_error());
+                    }
+                    rename_map.rename_type(old_name, new_name);
+                }
+            }
+        }
+
+        Ok(rename_map)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty() && self.members.is_empty()
+    }
+
+    pub fn rename_type(&mut self, type_name: &str, new_name: &str) {
+        self.types
+            .insert(type_name.to_string(), new_name.to_string());
+        self.scoped_renames = OnceLock::new();
+    }
+
+    pub fn rename_member(&mut self, type_name: &str, member_name: &str, new_name: &str) {
+        self.members
+            .entry(type_name.to_string())
+            .or_default()
+            .insert(member_name.to_string(), new_name.to_string());
+        self.scoped_renames = OnceLock::new();
+    }
+
+    /// Return the name to display for the given type
+    pub fn type_name<'a>(&'a self, type_name: &'a str) -> &'a str {
+        self.types.get(type_name).unwrap_or(type_name)
+    }
+
+    /// Renames applied within type definitions. Constructors and destructors
+    /// of renamed types are referred to by their unqualified names, so we
+    /// rename them as members.
+    fn scoped_renames(&self) -> &BTreeMap<String, IdentifierRenames> {
+        self.scoped_renames.get_or_init(|| {
+            let mut scoped_renames = self.members.clone();
+            for (type_name, new_name) in self.types.iter() {
+                let unqualified_name = |name: &'_ str| name.rsplit("::").next().map(str::to_string);
+                if let (Some(old_name), Some(new_name)) =
+                    (unqualified_name(type_name), unqualified_name(new_name))
+                {
+                    if is_identifier(&old_name) {
+                        scoped_renames
+                            .entry(type_name.clone())
+                            .or_default()
+                            .insert(old_name, new_name);
+                    }
+                }
+            }
+            scoped_renames
+        })
+    }
+
+    /// Apply renames to reconstructed code. Member renames only apply within
+    /// the definition of their type.
+    pub fn apply(&self, code: &str) -> String {
+        if self.is_empty() {
+            return code.to_string();
+        }
+
+        let scoped_renames = self.scoped_renames();
+        static TYPE_DEFINITION_RE: OnceLock<Regex> = OnceLock::new();
+        let type_definition_re = TYPE_DEFINITION_RE.get_or_init(|| {
+            Regex::new(r"^\s*(?:struct|class|union|enum(?:\s+class)?)\s+(.+?)(?:\s+:\s+.*)?\s*\{")
+                .expect("regex should be valid")
+        });
+        // Enclosing scopes, with the name of the type they define, if any
+        let mut scopes: Vec<Option<&str>> = vec![];
+        let mut output = String::with_capacity(code.len());
+        for line in code.split_inclusive('\n') {
+            let enclosing_type = scopes.iter().rev().find_map(|scope| *scope);
+            let mut renamed_line = Cow::Borrowed(line);
+            if let Some(member_renames) = enclosing_type.and_then(|name| scoped_renames.get(name)) {
+                renamed_line = Cow::Owned(member_renames.replace(line));
+            }
+
+            // Update scopes before renaming types, as type definitions are
+            // looked up by their original name
+            let mut defined_type = type_definition_re
+                .captures(line)
+                .and_then(|captures| captures.get(1))
+                .and_then(|name| scoped_renames.get_key_value(name.as_str()))
+                .map(|(name, _)| name.as_str());
+            for c in line.chars() {
+                match c {
+                    // The first opening brace begins the type's definition
+                    '{' => scopes.push(defined_type.take()),
+                    '}' => {
+                        scopes.pop();
+                    }
+                    _ => {}
+                }
+            }
+
+            output.push_str(&self.types.replace(&renamed_line));
+        }
+
+        output
+    }
+}
+
+impl std::fmt::Display for RenameMap {
+    /// Serialize renames in the format expected by `RenameMap::parse`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (type_name, new_name) in self.types.iter() {
+            writeln!(f, "{type_name} => {new_name}")?;
+        }
+        for (type_name, member_renames) in &self.members {
+            for (member_name, new_name) in member_renames.iter() {
+                writeln!(f, "{type_name}.{member_name} => {new_name}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(is_identifier_char)
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Names to replace in code, with the new name of each. Names are matched
+/// with a single regex, compiled on first use.
+#[derive(Debug, Clone, Default)]
+pub(super) struct IdentifierRenames {
+    renames: BTreeMap<String, String>,
+    matcher: OnceLock<Regex>,
+}
+
+impl PartialEq for IdentifierRenames {
+    fn eq(&self, other: &Self) -> bool {
+        self.renames == other.renames
+    }
+}
+
+impl Eq for IdentifierRenames {}
+
+impl From<BTreeMap<String, String>> for IdentifierRenames {
+    fn from(renames: BTreeMap<String, String>) -> Self {
+        Self {
+            renames,
+            matcher: OnceLock::new(),
+        }
+    }
+}
+
+impl IdentifierRenames {
+    pub(super) fn is_empty(&self) -> bool {
+        self.renames.is_empty()
+    }
+
+    pub(super) fn get(&self, name: &str) -> Option<&str> {
+        self.renames.get(name).map(String::as_str)
+    }
+
+    pub(super) fn insert(&mut self, name: String, new_name: String) {
+        self.renames.insert(name, new_name);
+        self.matcher = OnceLock::new();
+    }
+
+    pub(super) fn iter(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.renames.iter()
+    }
+
+    /// Regex matching any of the names, when not followed by an identifier
+    /// character
+    fn matcher(&self) -> &Regex {
+        self.matcher.get_or_init(|| {
+            // Alternatives are tried in order, so longer names go first to
+            // match `Renamed::Nested` rather than `Renamed`
+            let mut names: Vec<&String> = self
+                .renames
+                .keys()
+                .filter(|name| !name.is_empty())
+                .collect();
+            names.sort_by_key(|name| std::cmp::Reverse(name.len()));
+            let alternatives: Vec<String> =
+                names.into_iter().map(|name| regex::escape(name)).collect();
+            Regex::new(&format!(
+                r"({})(?:[^\p{{Alphabetic}}\p{{N}}_]|\z)",
+                alternatives.join("|")
+            ))
+            .expect("regex should be valid")
+        })
+    }
+
+    /// Replace whole occurrences of the (possibly qualified) names, i.e. that
+    /// aren't part of a longer identifier or nested in another scope. Names
+    /// are replaced in a single pass, so that renames don't apply to each
+    /// other.
+    pub(super) fn replace(&self, text: &str) -> String {
+        if self.is_empty() {
+            return text.to_string();
+        }
+
+        let matcher = self.matcher();
+        let mut output = String::with_capacity(text.len());
+        let mut copied_up_to = 0;
+        let mut search_from = 0;
+        while let Some(name) = matcher
+            .captures_at(text, search_from)
+            .and_then(|captures| captures.get(1))
+        {
+            if name.is_empty() {
+                // Only empty names were given
+                break;
+            }
+            let is_start_boundary = text[..name.start()]
+                .chars()
+                .next_back()
+                .map_or(true, |c| !is_identifier_char(c) && c != ':');
+            if is_start_boundary {
+                output.push_str(&text[copied_up_to..name.start()]);
+                output.push_str(&self.renames[name.as_str()]);
+                copied_up_to = name.end();
+                search_from = name.end();
+            } else {
+                // Look for names starting after this character
+                search_from = name.start()
+                    + text[name.start()..]
+                        .chars()
+                        .next()
+                        .map_or(1, char::len_utf8);
+            }
+        }
+        output.push_str(&text[copied_up_to..]);
+
+        output
+    }
+}
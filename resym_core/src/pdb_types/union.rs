@@ -2,12 +2,14 @@ use std::fmt;
 
 use super::{
     class::Class,
+    code_format::write_opening_brace,
     enumeration::Enum,
     field::{FieldAccess, StaticField},
-    fmt_union_fields_recursive, is_unnamed_type,
-    primitive_types::PrimitiveReconstructionFlavor,
-    resolve_complete_type_index, type_bitfield_info, type_name, type_size, DataFormatConfiguration,
-    Field, Method, NeededTypeSet, ReconstructibleTypeData, TypeForwarder,
+    fmt_union_fields_recursive, format_rva, is_unnamed_type, owned_unique_name,
+    primitive_types::{PrimitiveReconstructionFlavor, PrimitiveTypeMapping},
+    resolve_complete_type_index, type_bitfield_info, type_name, type_size, unnamed_type_name,
+    DataFormatConfiguration, Field, Method, NeededTypeSet, ReconstructibleTypeData, TypeForwarder,
+    UnnamedTypeMap,
 };
 use crate::error::{Result, ResymCoreError};
 
@@ -15,6 +17,8 @@ use crate::error::{Result, ResymCoreError};
 pub struct Union<'p> {
     pub index: pdb::TypeIndex,
     pub name: String,
+    /// Decorated name of the type, if any
+    pub unique_name: Option<String>,
     pub size: u64,
     pub fields: Vec<Field<'p>>,
     pub static_fields: Vec<StaticField<'p>>,
@@ -26,12 +30,15 @@ pub struct Union<'p> {
 }
 
 impl<'p> Union<'p> {
+    #[allow(clippy::too_many_arguments)]
     pub fn add_fields(
         &mut self,
         type_finder: &pdb::TypeFinder<'p>,
         type_forwarder: &TypeForwarder,
+        unnamed_types: &UnnamedTypeMap,
         type_index: pdb::TypeIndex,
         primitive_flavor: &PrimitiveReconstructionFlavor,
+        primitive_type_mapping: &PrimitiveTypeMapping,
         needed_types: &mut NeededTypeSet,
     ) -> Result<()> {
         // Resolve the complete type's index, if present in the PDB
@@ -42,8 +49,10 @@ impl<'p> Union<'p> {
                     self.add_field(
                         type_finder,
                         type_forwarder,
+                        unnamed_types,
                         field,
                         primitive_flavor,
+                        primitive_type_mapping,
                         needed_types,
                     )?;
                 }
@@ -53,8 +62,10 @@ impl<'p> Union<'p> {
                     self.add_fields(
                         type_finder,
                         type_forwarder,
+                        unnamed_types,
                         continuation,
                         primitive_flavor,
+                        primitive_type_mapping,
                         needed_types,
                     )?;
                 }
@@ -65,7 +76,7 @@ impl<'p> Union<'p> {
                 let name_str = data.name.to_string();
                 // Rename unnamed anonymous tags to something unique
                 let name = if is_unnamed_type(&name_str) {
-                    format!("_unnamed_{type_index}")
+                    unnamed_type_name(type_finder, unnamed_types, complete_type_index)
                 } else {
                     name_str.into_owned()
                 };
@@ -74,6 +85,7 @@ impl<'p> Union<'p> {
                     index: type_index,
                     kind: data.kind,
                     name,
+                    unique_name: owned_unique_name(data.unique_name),
                     size: data.size,
                     fields: Vec::new(),
                     static_fields: Vec::new(),
@@ -93,8 +105,10 @@ impl<'p> Union<'p> {
                     class.add_fields(
                         type_finder,
                         type_forwarder,
+                        unnamed_types,
                         fields,
                         primitive_flavor,
+                        primitive_type_mapping,
                         needed_types,
                     )?;
                 }
@@ -106,7 +120,7 @@ impl<'p> Union<'p> {
                 let name_str = data.name.to_string();
                 // Rename unnamed anonymous tags to something unique
                 let name = if is_unnamed_type(&name_str) {
-                    format!("_unnamed_{type_index}")
+                    unnamed_type_name(type_finder, unnamed_types, complete_type_index)
                 } else {
                     name_str.into_owned()
                 };
@@ -114,6 +128,7 @@ impl<'p> Union<'p> {
                 let mut u = Union {
                     index: type_index,
                     name,
+                    unique_name: owned_unique_name(data.unique_name),
                     size: data.size,
                     fields: Vec::new(),
                     static_fields: Vec::new(),
@@ -127,8 +142,10 @@ impl<'p> Union<'p> {
                 u.add_fields(
                     type_finder,
                     type_forwarder,
+                    unnamed_types,
                     data.fields,
                     primitive_flavor,
+                    primitive_type_mapping,
                     needed_types,
                 )?;
 
@@ -139,7 +156,7 @@ impl<'p> Union<'p> {
                 let name_str = data.name.to_string();
                 // Rename unnamed anonymous tags to something unique
                 let name = if is_unnamed_type(&name_str) {
-                    format!("_unnamed_{type_index}")
+                    unnamed_type_name(type_finder, unnamed_types, complete_type_index)
                 } else {
                     name_str.into_owned()
                 };
@@ -147,11 +164,14 @@ impl<'p> Union<'p> {
                 let mut e = Enum {
                     index: type_index,
                     name,
+                    unique_name: owned_unique_name(data.unique_name),
                     underlying_type_name: type_name(
                         type_finder,
                         type_forwarder,
+                        unnamed_types,
                         data.underlying_type,
                         primitive_flavor,
+                        primitive_type_mapping,
                         needed_types,
                     )?
                     .0,
@@ -179,12 +199,15 @@ impl<'p> Union<'p> {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn add_field(
         &mut self,
         type_finder: &pdb::TypeFinder<'p>,
         type_forwarder: &TypeForwarder,
+        unnamed_types: &UnnamedTypeMap,
         field: &pdb::TypeData<'p>,
         primitive_flavor: &PrimitiveReconstructionFlavor,
+        primitive_type_mapping: &PrimitiveTypeMapping,
         needed_types: &mut NeededTypeSet,
     ) -> Result<()> {
         match *field {
@@ -195,8 +218,10 @@ impl<'p> Union<'p> {
                 let (type_left, type_right) = type_name(
                     type_finder,
                     type_forwarder,
+                    unnamed_types,
                     complete_type_index,
                     primitive_flavor,
+                    primitive_type_mapping,
                     needed_types,
                 )?;
                 let type_bitfield_info = type_bitfield_info(type_finder, complete_type_index)?;
@@ -221,8 +246,10 @@ impl<'p> Union<'p> {
                 let (type_left, type_right) = type_name(
                     type_finder,
                     type_forwarder,
+                    unnamed_types,
                     complete_type_index,
                     primitive_flavor,
+                    primitive_type_mapping,
                     needed_types,
                 )?;
                 let access = FieldAccess::from_field_attribute(data.attributes.access());
@@ -232,6 +259,7 @@ impl<'p> Union<'p> {
                     type_right,
                     name: data.name,
                     access,
+                    rva: None,
                 });
             }
 
@@ -241,8 +269,10 @@ impl<'p> Union<'p> {
                     data.attributes,
                     type_finder,
                     type_forwarder,
+                    unnamed_types,
                     data.method_type,
                     primitive_flavor,
+                    primitive_type_mapping,
                     needed_types,
                 )?;
                 if data.attributes.is_static() {
@@ -282,8 +312,10 @@ impl<'p> Union<'p> {
                                 attributes,
                                 type_finder,
                                 type_forwarder,
+                                unnamed_types,
                                 method_type,
                                 primitive_flavor,
+                                primitive_type_mapping,
                                 needed_types,
                             )?;
 
@@ -325,7 +357,16 @@ impl ReconstructibleTypeData for Union<'_> {
         fmt_configuration: &DataFormatConfiguration,
         f: &mut impl std::fmt::Write,
     ) -> fmt::Result {
-        writeln!(f, "union {} {{ /* Size={:#x} */", self.name, self.size)?;
+        write!(f, "union {}", self.name)?;
+        write_opening_brace(
+            f,
+            &fmt_configuration.code_format,
+            "",
+            &format!(
+                " /* Size={} */",
+                fmt_configuration.number_format.size(self.size)
+            ),
+        )?;
 
         // Nested delcarations
         if !self.nested_classes.is_empty() {
@@ -348,13 +389,23 @@ impl ReconstructibleTypeData for Union<'_> {
         }
 
         // Dump fields while detecting unnamed structs and unions
-        fmt_union_fields_recursive(fmt_configuration, &self.fields, 1, f)?;
+        let member_filter = &fmt_configuration.member_filter;
+        fmt_union_fields_recursive(
+            fmt_configuration,
+            &member_filter.visible_fields(&self.fields),
+            1,
+            f,
+        )?;
 
         // Static fields
-        for field in &self.static_fields {
+        for field in self
+            .static_fields
+            .iter()
+            .filter(|field| !member_filter.is_hidden(&field.name.to_string(), &field.access))
+        {
             writeln!(
                 f,
-                "  {}static {} {}{};",
+                "  {}static {} {}{};{}",
                 if fmt_configuration.print_access_specifiers {
                     &field.access
                 } else {
@@ -363,12 +414,21 @@ impl ReconstructibleTypeData for Union<'_> {
                 field.type_left,
                 &field.name,
                 field.type_right,
+                field
+                    .rva
+                    .map(|rva| format!(" /* {} */", format_rva(rva, fmt_configuration.image_base)))
+                    .unwrap_or_default(),
             )?;
         }
 
-        if !self.instance_methods.is_empty() {
+        let instance_methods = self
+            .instance_methods
+            .iter()
+            .filter(|method| !member_filter.is_hidden(&method.name.to_string(), &method.access))
+            .collect::<Vec<_>>();
+        if !instance_methods.is_empty() {
             writeln!(f, "  ")?;
-            for method in &self.instance_methods {
+            for method in instance_methods {
                 writeln!(
                     f,
                     "  {}{}{}{}{}({}){}{}{}{};",
@@ -403,9 +463,14 @@ impl ReconstructibleTypeData for Union<'_> {
             }
         }
 
-        if !self.static_methods.is_empty() {
+        let static_methods = self
+            .static_methods
+            .iter()
+            .filter(|method| !member_filter.is_hidden(&method.name.to_string(), &method.access))
+            .collect::<Vec<_>>();
+        if !static_methods.is_empty() {
             writeln!(f, "  ")?;
-            for method in &self.static_methods {
+            for method in static_methods {
                 writeln!(
                     f,
                     "  {}{}static {}{}{}({}){}{}{};",
@@ -0,0 +1,264 @@
+//! Minimal parser for the headers of PE images, used to relate the RVAs found
+//! in PDB files to the image they describe.
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+use crate::error::{Result, ResymCoreError};
+
+const DOS_SIGNATURE: &[u8] = b"MZ";
+const PE_SIGNATURE: &[u8] = b"PE\0\0";
+const COFF_HEADER_SIZE: usize = 20;
+const SECTION_HEADER_SIZE: usize = 40;
+const PE32_MAGIC: u16 = 0x10b;
+const PE32_PLUS_MAGIC: u16 = 0x20b;
+const EXPORT_DIRECTORY_INDEX: u32 = 0;
+/// Upper bound on the number of exports, to avoid allocating absurd amounts
+/// of memory when parsing corrupted images
+const MAX_EXPORT_COUNT: u32 = 0x10_0000;
+
+/// Information extracted from the headers of a PE image
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeFile {
+    /// Preferred virtual address of the image
+    pub image_base: u64,
+    /// Size of the image once mapped in memory
+    pub size_of_image: u32,
+    pub sections: Vec<PeSection>,
+    /// Exported functions and variables, sorted by RVA
+    pub exports: Vec<PeExport>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeSection {
+    pub name: String,
+    pub virtual_address: u32,
+    pub virtual_size: u32,
+    pointer_to_raw_data: u32,
+    size_of_raw_data: u32,
+}
+
+impl PeSection {
+    pub fn contains_rva(&self, rva: u32) -> bool {
+        rva >= self.virtual_address
+            && u64::from(rva) < u64::from(self.virtual_address) + u64::from(self.mapped_size())
+    }
+
+    /// Sections' virtual size is sometimes left to 0 by linkers
+    fn mapped_size(&self) -> u32 {
+        self.virtual_size.max(self.size_of_raw_data)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeExport {
+    /// Name of the export, `None` for exports by ordinal only
+    pub name: Option<String>,
+    pub ordinal: u32,
+    pub rva: u32,
+}
+
+impl PeExport {
+    /// Name of the export, or its ordinal (e.g., `#12`)
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("#{}", self.ordinal))
+    }
+}
+
+impl PeFile {
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_from_file(file_path: &Path) -> Result<Self> {
+        Self::parse(&std::fs::read(file_path)?)
+    }
+
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        if data.get(..DOS_SIGNATURE.len()) != Some(DOS_SIGNATURE) {
+            return Err(invalid_pe_file("missing DOS signature"));
+        }
+        let nt_headers_offset = read_u32(data, 0x3c)? as usize;
+        if read_bytes::<4>(data, nt_headers_offset)? != PE_SIGNATURE {
+            return Err(invalid_pe_file("missing PE signature"));
+        }
+
+        let coff_header_offset = nt_headers_offset + PE_SIGNATURE.len();
+        let section_count = read_u16(data, coff_header_offset + 2)? as usize;
+        let optional_header_size = read_u16(data, coff_header_offset + 16)? as usize;
+        let optional_header_offset = coff_header_offset + COFF_HEADER_SIZE;
+        let (image_base, data_directories_offset) = match read_u16(data, optional_header_offset)? {
+            PE32_MAGIC => (
+                read_u32(data, optional_header_offset + 28)?.into(),
+                optional_header_offset + 92,
+            ),
+            PE32_PLUS_MAGIC => (
+                read_u64(data, optional_header_offset + 24)?,
+                optional_header_offset + 108,
+            ),
+            magic => {
+                return Err(invalid_pe_file(&format!(
+                    "unsupported optional header magic 0x{magic:x}"
+                )))
+            }
+        };
+        let size_of_image = read_u32(data, optional_header_offset + 56)?;
+
+        let section_headers_offset = optional_header_offset + optional_header_size;
+        let sections = (0..section_count)
+            .map(|section_index| {
+                parse_section_header(
+                    data,
+                    section_headers_offset + section_index * SECTION_HEADER_SIZE,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut pe_file = Self {
+            image_base,
+            size_of_image,
+            sections,
+            exports: vec![],
+        };
+        // Exports are optional, ignore them if they cannot be parsed
+        if let Ok(exports) = pe_file.parse_exports(data, data_directories_offset) {
+            pe_file.exports = exports;
+        }
+
+        Ok(pe_file)
+    }
+
+    /// Indicate if the given RVA is part of the image once mapped in memory
+    pub fn contains_rva(&self, rva: u32) -> bool {
+        rva < self.size_of_image
+    }
+
+    pub fn section_at_rva(&self, rva: u32) -> Option<&PeSection> {
+        self.sections
+            .iter()
+            .find(|section| section.contains_rva(rva))
+    }
+
+    /// Exports located at the given RVA (a symbol can be exported under
+    /// several names)
+    pub fn exports_at_rva(&self, rva: u32) -> &[PeExport] {
+        let start = self.exports.partition_point(|export| export.rva < rva);
+        let end = self.exports.partition_point(|export| export.rva <= rva);
+        &self.exports[start..end]
+    }
+
+    fn parse_exports(&self, data: &[u8], data_directories_offset: usize) -> Result<Vec<PeExport>> {
+        let data_directory_count = read_u32(data, data_directories_offset)?;
+        if data_directory_count <= EXPORT_DIRECTORY_INDEX {
+            return Ok(vec![]);
+        }
+        let export_directory_rva = read_u32(data, data_directories_offset + 4)?;
+        let export_directory_size = read_u32(data, data_directories_offset + 8)?;
+        if export_directory_rva == 0 {
+            return Ok(vec![]);
+        }
+
+        let export_directory_offset = self.rva_to_offset(export_directory_rva)?;
+        let ordinal_base = read_u32(data, export_directory_offset + 16)?;
+        let function_count = read_u32(data, export_directory_offset + 20)?;
+        let name_count = read_u32(data, export_directory_offset + 24)?;
+        if function_count > MAX_EXPORT_COUNT || name_count > MAX_EXPORT_COUNT {
+            return Err(invalid_pe_file("too many exports"));
+        }
+        let functions_offset = self.rva_to_offset(read_u32(data, export_directory_offset + 28)?)?;
+        let names_offset = self.rva_to_offset(read_u32(data, export_directory_offset + 32)?)?;
+        let name_ordinals_offset =
+            self.rva_to_offset(read_u32(data, export_directory_offset + 36)?)?;
+
+        // Names are associated to functions through their index
+        let mut function_names = vec![None; function_count as usize];
+        for name_index in 0..name_count as usize {
+            let function_index = read_u16(data, name_ordinals_offset + name_index * 2)? as usize;
+            let name_offset = self.rva_to_offset(read_u32(data, names_offset + name_index * 4)?)?;
+            if let Some(function_name) = function_names.get_mut(function_index) {
+                *function_name = Some(read_c_string(data, name_offset)?);
+            }
+        }
+
+        let mut exports = vec![];
+        for (function_index, name) in function_names.into_iter().enumerate() {
+            let rva = read_u32(data, functions_offset + function_index * 4)?;
+            // Skip unused ordinals, as well as forwarders (which point to a
+            // string in the export directory)
+            let is_forwarder = rva >= export_directory_rva
+                && u64::from(rva)
+                    < u64::from(export_directory_rva) + u64::from(export_directory_size);
+            if rva == 0 || is_forwarder {
+                continue;
+            }
+
+            exports.push(PeExport {
+                name,
+                ordinal: ordinal_base.wrapping_add(function_index as u32),
+                rva,
+            });
+        }
+        exports.sort_by_key(|export| export.rva);
+
+        Ok(exports)
+    }
+
+    /// Convert an RVA into an offset in the image's file
+    fn rva_to_offset(&self, rva: u32) -> Result<usize> {
+        let section = self
+            .section_at_rva(rva)
+            .ok_or_else(|| invalid_pe_file(&format!("RVA 0x{rva:x} isn't part of any section")))?;
+
+        Ok(section.pointer_to_raw_data as usize + (rva - section.virtual_address) as usize)
+    }
+}
+
+fn parse_section_header(data: &[u8], offset: usize) -> Result<PeSection> {
+    let name_bytes = read_bytes::<8>(data, offset)?;
+    let name_length = name_bytes
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(name_bytes.len());
+
+    Ok(PeSection {
+        name: String::from_utf8_lossy(&name_bytes[..name_length]).into_owned(),
+        virtual_size: read_u32(data, offset + 8)?,
+        virtual_address: read_u32(data, offset + 12)?,
+        size_of_raw_data: read_u32(data, offset + 16)?,
+        pointer_to_raw_data: read_u32(data, offset + 20)?,
+    })
+}
+
+fn read_c_string(data: &[u8], offset: usize) -> Result<String> {
+    let bytes = data
+        .get(offset..)
+        .ok_or_else(|| invalid_pe_file("truncated string"))?;
+    let length = bytes
+        .iter()
+        .position(|byte| *byte == 0)
+        .ok_or_else(|| invalid_pe_file("unterminated string"))?;
+
+    Ok(String::from_utf8_lossy(&bytes[..length]).into_owned())
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16> {
+    Ok(u16::from_le_bytes(read_bytes(data, offset)?))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32> {
+    Ok(u32::from_le_bytes(read_bytes(data, offset)?))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Result<u64> {
+    Ok(u64::from_le_bytes(read_bytes(data, offset)?))
+}
+
+fn read_bytes<const N: usize>(data: &[u8], offset: usize) -> Result<[u8; N]> {
+    offset
+        .checked_add(N)
+        .and_then(|end| data.get(offset..end))
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| invalid_pe_file("truncated headers"))
+}
+
+fn invalid_pe_file(reason: &str) -> ResymCoreError {
+    ResymCoreError::InvalidPeFileError(reason.to_string())
+}
@@ -0,0 +1,241 @@
+//! Plugin system based on the Rhai scripting language.
+//!
+//! Plugins are `.rhai` scripts which can define any of the following hooks:
+//! - `post_process(code)`: return a modified version of reconstructed code
+//! - `export_formats()`: return an array of `#{ name, extension, function }`
+//!   maps describing custom export formats. `function(code)` must return the
+//!   exported content.
+//! - `menu_commands()`: return an array of `#{ label, function }` maps
+//!   describing menu commands. `function(code)` is invoked with the content
+//!   currently displayed and can return a message to report to the user.
+use std::path::Path;
+
+use rhai::{Dynamic, Engine, Scope, AST};
+
+use crate::error::{Result, ResymCoreError};
+
+/// Extension of the files loaded from plugin directories
+pub const PLUGIN_FILE_EXTENSION: &str = "rhai";
+/// Maximum number of operations a single hook invocation can execute, to
+/// avoid hanging on faulty plugins
+const MAX_OPERATIONS: u64 = 50_000_000;
+
+const POST_PROCESS_HOOK: &str = "post_process";
+const EXPORT_FORMATS_HOOK: &str = "export_formats";
+const MENU_COMMANDS_HOOK: &str = "menu_commands";
+
+struct Plugin {
+    name: String,
+    ast: AST,
+}
+
+/// Custom export format, provided by a plugin
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginExportFormat {
+    pub plugin_name: String,
+    pub name: String,
+    /// File extension, without the leading dot
+    pub extension: String,
+    function: String,
+}
+
+/// Custom menu command, provided by a plugin
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginMenuCommand {
+    pub plugin_name: String,
+    pub label: String,
+    function: String,
+}
+
+pub struct PluginManager {
+    engine: Engine,
+    plugins: Vec<Plugin>,
+}
+
+impl Default for PluginManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PluginManager {
+    pub fn new() -> Self {
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+
+        Self {
+            engine,
+            plugins: vec![],
+        }
+    }
+
+    /// Names of the loaded plugins, in loading order
+    pub fn plugin_names(&self) -> impl Iterator<Item = &str> {
+        self.plugins.iter().map(|plugin| plugin.name.as_str())
+    }
+
+    /// Load all the plugins found in the given directory, in alphabetical
+    /// order. Plugins that fail to compile are skipped and reported in the
+    /// returned list of errors.
+    pub fn load_directory(&mut self, directory_path: &Path) -> Result<Vec<ResymCoreError>> {
+        let mut plugin_paths: Vec<_> = std::fs::read_dir(directory_path)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.is_file()
+                    && path.extension().and_then(|ext| ext.to_str()) == Some(PLUGIN_FILE_EXTENSION)
+            })
+            .collect();
+        plugin_paths.sort();
+
+        let mut errors = vec![];
+        for plugin_path in plugin_paths {
+            let plugin_name = plugin_path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let load_result = std::fs::read_to_string(&plugin_path)
+                .map_err(ResymCoreError::from)
+                .and_then(|source| self.load_plugin(&plugin_name, &source));
+            if let Err(err) = load_result {
+                errors.push(err);
+            }
+        }
+
+        Ok(errors)
+    }
+
+    /// Compile and register a plugin from its source code
+    pub fn load_plugin(&mut self, plugin_name: &str, source: &str) -> Result<()> {
+        let ast = self.engine.compile(source).map_err(|err| {
+            ResymCoreError::PluginError(format!("failed to compile '{plugin_name}': {err}"))
+        })?;
+        self.plugins.push(Plugin {
+            name: plugin_name.to_string(),
+            ast,
+        });
+
+        Ok(())
+    }
+
+    /// Run the `post_process` hook of all plugins on the given code, in
+    /// loading order
+    pub fn post_process(&self, code: String) -> Result<String> {
+        self.plugins
+            .iter()
+            .filter(|plugin| plugin.has_function(POST_PROCESS_HOOK, 1))
+            .try_fold(code, |code, plugin| {
+                self.call_fn(plugin, POST_PROCESS_HOOK, (code,))
+            })
+    }
+
+    /// List the export formats registered by plugins
+    pub fn export_formats(&self) -> Result<Vec<PluginExportFormat>> {
+        let mut export_formats = vec![];
+        for plugin in self
+            .plugins
+            .iter()
+            .filter(|plugin| plugin.has_function(EXPORT_FORMATS_HOOK, 0))
+        {
+            let formats: rhai::Array = self.call_fn(plugin, EXPORT_FORMATS_HOOK, ())?;
+            for format in formats {
+                export_formats.push(PluginExportFormat {
+                    plugin_name: plugin.name.clone(),
+                    name: map_string_field(plugin, &format, "name")?,
+                    extension: map_string_field(plugin, &format, "extension")?,
+                    function: map_string_field(plugin, &format, "function")?,
+                });
+            }
+        }
+
+        Ok(export_formats)
+    }
+
+    /// Convert the given code with a plugin's export format
+    pub fn export(&self, export_format: &PluginExportFormat, code: &str) -> Result<String> {
+        let plugin = self.plugin_by_name(&export_format.plugin_name)?;
+        self.call_fn(plugin, &export_format.function, (code.to_string(),))
+    }
+
+    /// List the menu commands registered by plugins
+    pub fn menu_commands(&self) -> Result<Vec<PluginMenuCommand>> {
+        let mut menu_commands = vec![];
+        for plugin in self
+            .plugins
+            .iter()
+            .filter(|plugin| plugin.has_function(MENU_COMMANDS_HOOK, 0))
+        {
+            let commands: rhai::Array = self.call_fn(plugin, MENU_COMMANDS_HOOK, ())?;
+            for command in commands {
+                menu_commands.push(PluginMenuCommand {
+                    plugin_name: plugin.name.clone(),
+                    label: map_string_field(plugin, &command, "label")?,
+                    function: map_string_field(plugin, &command, "function")?,
+                });
+            }
+        }
+
+        Ok(menu_commands)
+    }
+
+    /// Run a plugin's menu command on the given code. Returns the message
+    /// returned by the command, if any.
+    pub fn run_menu_command(
+        &self,
+        menu_command: &PluginMenuCommand,
+        code: &str,
+    ) -> Result<Option<String>> {
+        let plugin = self.plugin_by_name(&menu_command.plugin_name)?;
+        let result: Dynamic = self.call_fn(plugin, &menu_command.function, (code.to_string(),))?;
+
+        Ok(if result.is_unit() {
+            None
+        } else {
+            Some(result.to_string())
+        })
+    }
+
+    fn plugin_by_name(&self, plugin_name: &str) -> Result<&Plugin> {
+        self.plugins
+            .iter()
+            .find(|plugin| plugin.name == plugin_name)
+            .ok_or_else(|| {
+                ResymCoreError::PluginError(format!("plugin '{plugin_name}' isn't loaded"))
+            })
+    }
+
+    fn call_fn<T: rhai::Variant + Clone>(
+        &self,
+        plugin: &Plugin,
+        function: &str,
+        args: impl rhai::FuncArgs,
+    ) -> Result<T> {
+        self.engine
+            .call_fn(&mut Scope::new(), &plugin.ast, function, args)
+            .map_err(|err| {
+                ResymCoreError::PluginError(format!(
+                    "'{}' failed in '{function}': {err}",
+                    plugin.name
+                ))
+            })
+    }
+}
+
+impl Plugin {
+    fn has_function(&self, name: &str, param_count: usize) -> bool {
+        self.ast
+            .iter_functions()
+            .any(|function| function.name == name && function.params.len() == param_count)
+    }
+}
+
+fn map_string_field(plugin: &Plugin, value: &Dynamic, field_name: &str) -> Result<String> {
+    value
+        .read_lock::<rhai::Map>()
+        .and_then(|map| map.get(field_name).map(|field| field.to_string()))
+        .ok_or_else(|| {
+            ResymCoreError::PluginError(format!(
+                "'{}' returned an entry without '{field_name}'",
+                plugin.name
+            ))
+        })
+}
@@ -0,0 +1,68 @@
+use std::path::Path;
+
+use resym_core::pdb_file::{PdbFile, SymbolAtAddress, SymbolLocation};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_SYMBOL_NAME: &str = "__scrt_initialize_default_local_stdio_options";
+const TEST_SYMBOL_RVA: u32 = 0x14680;
+
+#[test]
+fn test_symbol_lookup_by_rva() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    let symbol = pdb_file
+        .symbol_at_rva(TEST_SYMBOL_RVA)
+        .unwrap_or_else(|err| panic!("address lookup failed: {err}"));
+    assert_eq!(
+        symbol,
+        SymbolAtAddress {
+            name: TEST_SYMBOL_NAME.to_string(),
+            rva: TEST_SYMBOL_RVA,
+            offset: 0,
+        }
+    );
+
+    // Addresses inside of the symbol should resolve to it
+    let symbol = pdb_file
+        .symbol_at_rva(TEST_SYMBOL_RVA + 0x10)
+        .unwrap_or_else(|err| panic!("address lookup failed: {err}"));
+    assert_eq!(symbol.name, TEST_SYMBOL_NAME);
+    assert_eq!(symbol.offset, 0x10);
+}
+
+#[test]
+fn test_symbol_lookup_by_rva_not_found() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    // Nothing precedes the PE header
+    assert!(pdb_file.symbol_at_rva(0).is_err());
+}
+
+#[test]
+fn test_symbol_location_by_name() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    let symbol_location = pdb_file
+        .symbol_location_by_name(TEST_SYMBOL_NAME)
+        .unwrap_or_else(|err| panic!("symbol lookup failed: {err}"));
+    assert_eq!(
+        symbol_location,
+        SymbolLocation {
+            name: TEST_SYMBOL_NAME.to_string(),
+            rva: TEST_SYMBOL_RVA,
+            section_name: ".text".to_string(),
+            size: Some(0x45),
+            export_names: vec![],
+            outside_image: false,
+        }
+    );
+}
+
+#[test]
+fn test_symbol_location_by_name_not_found() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    assert!(pdb_file
+        .symbol_location_by_name("resym_test::DoesNotExist")
+        .is_err());
+}
@@ -0,0 +1,92 @@
+use std::{path::Path, sync::Arc, time::Duration};
+
+use resym_core::{
+    backend::{Backend, BackendCommand},
+    cancellation::CancellationToken,
+    frontend::{FrontendCommand, FrontendController},
+    pdb_file::{LoadMode, PdbFile},
+    pdb_types::PrimitiveReconstructionFlavor,
+    ResymCoreError,
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+
+struct TestFrontendController {
+    tx_ui: crossbeam_channel::Sender<FrontendCommand>,
+}
+
+impl FrontendController for TestFrontendController {
+    fn send_command(&self, command: FrontendCommand) -> resym_core::Result<()> {
+        self.tx_ui
+            .send(command)
+            .map_err(|err| ResymCoreError::CrossbeamError(err.to_string()))
+    }
+}
+
+#[test]
+fn test_cancelled_loading() {
+    let cancellation_token = CancellationToken::default();
+    cancellation_token.cancel();
+
+    let result = PdbFile::load_from_file_with_mode(
+        Path::new(TEST_PDB_FILE_PATH),
+        LoadMode::default(),
+        &cancellation_token,
+    );
+    assert!(matches!(
+        result,
+        Err(ResymCoreError::OperationCancelledError)
+    ));
+
+    // Tokens can be reused once reset
+    cancellation_token.reset();
+    PdbFile::load_from_file_with_mode(
+        Path::new(TEST_PDB_FILE_PATH),
+        LoadMode::default(),
+        &cancellation_token,
+    )
+    .expect("load test.pdb");
+}
+
+#[test]
+fn test_cancelled_reconstruction() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let cancellation_token = CancellationToken::default();
+    cancellation_token.cancel();
+
+    let result = pdb_file.reconstruct_all_types(
+        PrimitiveReconstructionFlavor::Portable,
+        true,
+        false,
+        &cancellation_token,
+    );
+    assert!(matches!(
+        result,
+        Err(ResymCoreError::OperationCancelledError)
+    ));
+}
+
+#[test]
+fn test_cancel_without_pending_commands() {
+    let (tx_ui, rx_ui) = crossbeam_channel::unbounded();
+    let backend =
+        Backend::new(Arc::new(TestFrontendController { tx_ui })).expect("backend creation");
+
+    // Cancelling when nothing is pending doesn't affect later commands
+    backend.cancel_operations();
+    backend
+        .send_command(BackendCommand::LoadPDBFromPath(
+            0,
+            TEST_PDB_FILE_PATH.into(),
+        ))
+        .expect("send command");
+    loop {
+        let command = rx_ui
+            .recv_timeout(Duration::from_secs(30))
+            .expect("load result");
+        if let FrontendCommand::LoadPDBResult(result) = command {
+            assert_eq!(result.expect("load test.pdb"), 0);
+            break;
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use std::path::Path;
+
+use resym_core::clang_format::clang_format;
+
+#[test]
+fn test_clang_format_missing_binary() {
+    // Missing binaries should be reported as errors, so that callers can fall
+    // back to the unformatted code
+    assert!(clang_format(
+        Path::new("resym-missing-clang-format"),
+        "LLVM",
+        "struct Test { int a; };\n"
+    )
+    .is_err());
+}
@@ -0,0 +1,167 @@
+use std::path::Path;
+
+use resym_core::{
+    pdb_file::PdbFile,
+    pdb_types::{
+        format_code, BraceStyle, CodeFormatConfiguration, PointerAlignment,
+        PrimitiveReconstructionFlavor,
+    },
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_CODE: &str = concat!(
+    "struct Test { /* Size=0x10 */\n",
+    "  /* 0x0000 */ char** field1;\n",
+    "  union {\n",
+    "    /* 0x0008 */ const char* field2;\n",
+    "  };\n",
+    "  \n",
+    "  public: void Method(int* param);\n",
+    "};\n",
+);
+
+#[test]
+fn test_default_code_format() {
+    assert_eq!(
+        format_code(TEST_CODE, &CodeFormatConfiguration::default()),
+        TEST_CODE.replace("  \n", "\n")
+    );
+}
+
+#[test]
+fn test_custom_code_format() {
+    let code_format = CodeFormatConfiguration {
+        indent_width: 4,
+        use_tabs: false,
+        brace_style: BraceStyle::Attach,
+        pointer_alignment: PointerAlignment::Right,
+        max_line_width: None,
+    };
+    assert_eq!(
+        format_code(TEST_CODE, &code_format),
+        concat!(
+            "struct Test { /* Size=0x10 */\n",
+            "    /* 0x0000 */ char **field1;\n",
+            "    union {\n",
+            "        /* 0x0008 */ const char *field2;\n",
+            "    };\n",
+            "\n",
+            "    public: void Method(int *param);\n",
+            "};\n",
+        )
+    );
+
+    let code_format = CodeFormatConfiguration {
+        use_tabs: true,
+        ..Default::default()
+    };
+    assert!(
+        format_code(TEST_CODE, &code_format).contains("\n\t\t/* 0x0008 */ const char* field2;\n")
+    );
+}
+
+#[test]
+fn test_line_wrapping() {
+    let code_format = CodeFormatConfiguration {
+        max_line_width: Some(60),
+        ..Default::default()
+    };
+    let code = concat!(
+        "struct Test { /* Size=0x10 */\n",
+        "  /* 0x0000 */ void (*callback)(std::pair<int,char>* pair, unsigned long size, void* user_data);\n",
+        "  /* 0x0008 */ char* field1;\n",
+        "};",
+    );
+    assert_eq!(
+        format_code(code, &code_format),
+        concat!(
+            "struct Test { /* Size=0x10 */\n",
+            "  /* 0x0000 */ void (*callback)(std::pair<int,char>* pair,\n",
+            "      unsigned long size, void* user_data);\n",
+            "  /* 0x0008 */ char* field1;\n",
+            "};",
+        )
+    );
+}
+
+#[test]
+fn test_line_wrapping_skips_comments_and_template_arguments() {
+    let code_format = CodeFormatConfiguration {
+        max_line_width: Some(60),
+        ..Default::default()
+    };
+    let code = concat!(
+        "struct Test { /* Size=0x10 */\n",
+        "  /* 0x0000 */ auto compute(decltype(p->x) first, std::pair<int, int> second, int third) -> std::map<int, int>; // one, two\n",
+        "  /* 0x0008 */ std::map<std::string, std::vector<int>> lookup_table; // key, value\n",
+        "};",
+    );
+    // `->` shouldn't close template argument lists, and commas in comments
+    // or template argument lists shouldn't be used to break lines
+    assert_eq!(
+        format_code(code, &code_format),
+        concat!(
+            "struct Test { /* Size=0x10 */\n",
+            "  /* 0x0000 */ auto compute(decltype(p->x) first,\n",
+            "      std::pair<int, int> second,\n",
+            "      int third) -> std::map<int, int>; // one, two\n",
+            "  /* 0x0008 */ std::map<std::string, std::vector<int>> lookup_table; // key, value\n",
+            "};",
+        )
+    );
+}
+
+#[test]
+fn test_type_reconstruction_code_format() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    pdb_file.settings.code_format = CodeFormatConfiguration {
+        brace_style: BraceStyle::Allman,
+        ..Default::default()
+    };
+
+    let (reconstructed_type, _) = pdb_file
+        .reconstruct_type_by_name(
+            "resym_test::StructTest",
+            PrimitiveReconstructionFlavor::Portable,
+            false,
+            true,
+            false,
+        )
+        .unwrap_or_else(|err| panic!("type reconstruction failed: {err}"));
+
+    assert!(reconstructed_type.contains("\nstruct resym_test::StructTest /* Size="));
+    assert!(reconstructed_type.contains(" */\n{\n"));
+}
+
+#[test]
+fn test_type_reconstruction_allman_braces() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    pdb_file.settings.code_format = CodeFormatConfiguration {
+        indent_width: 4,
+        brace_style: BraceStyle::Allman,
+        ..Default::default()
+    };
+
+    // Braces of anonymous aggregates are placed by the emitter too
+    let (reconstructed_type, _) = pdb_file
+        .reconstruct_type_by_name(
+            "resym_test::StructUnnamedUdtTest3",
+            PrimitiveReconstructionFlavor::Portable,
+            false,
+            true,
+            false,
+        )
+        .unwrap_or_else(|err| panic!("type reconstruction failed: {err}"));
+    assert!(reconstructed_type.contains(concat!(
+        "\nstruct resym_test::StructUnnamedUdtTest3 /* Size=0x40 */\n",
+        "{\n",
+        "    /* 0x0000 */ public: uint64_t Before;\n",
+        "    union\n",
+        "    {\n",
+        "        struct\n",
+        "        {\n",
+        "            /* 0x0008 */ public: uint64_t u1;\n",
+    )));
+}
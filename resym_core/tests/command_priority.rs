@@ -0,0 +1,106 @@
+use std::{sync::Arc, time::Duration};
+
+use resym_core::{
+    backend::{Backend, BackendCommand, CommandPriority},
+    diffing::DiffOptions,
+    frontend::{FrontendCommand, FrontendController},
+    pdb_types::PrimitiveReconstructionFlavor,
+    ResymCoreError,
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+
+struct TestFrontendController {
+    tx_ui: crossbeam_channel::Sender<FrontendCommand>,
+}
+
+impl FrontendController for TestFrontendController {
+    fn send_command(&self, command: FrontendCommand) -> resym_core::Result<()> {
+        self.tx_ui
+            .send(command)
+            .map_err(|err| ResymCoreError::CrossbeamError(err.to_string()))
+    }
+}
+
+#[test]
+fn test_command_priority() {
+    let flavor = PrimitiveReconstructionFlavor::Portable;
+
+    // Commands processing whole PDBs don't delay interactive ones
+    for command in [
+        BackendCommand::ReconstructAllTypes(0, flavor, true, true, false),
+        BackendCommand::ReconstructAllEnums(0, flavor, true, false),
+        BackendCommand::ReconstructAllSymbols(0, flavor, true, false),
+        BackendCommand::ListChangedTypesMerged(
+            1,
+            2,
+            String::default(),
+            false,
+            false,
+            DiffOptions::default(),
+        ),
+    ] {
+        assert_eq!(command.priority(), CommandPriority::Batch);
+    }
+    for command in [
+        BackendCommand::ReconstructTypeByName(
+            0,
+            "resym_test::StructTest".to_string(),
+            flavor,
+            false,
+            true,
+            false,
+            false,
+        ),
+        BackendCommand::ListTypes(0, "Struct".to_string(), false, false, false),
+        BackendCommand::UnloadPDB(0),
+    ] {
+        assert_eq!(command.priority(), CommandPriority::Interactive);
+    }
+}
+
+#[test]
+fn test_command_ordering() {
+    let (tx_ui, rx_ui) = crossbeam_channel::unbounded();
+    let backend =
+        Backend::new(Arc::new(TestFrontendController { tx_ui })).expect("backend creation");
+    let flavor = PrimitiveReconstructionFlavor::Portable;
+
+    // Queue commands while the first PDB is being loaded
+    for command in [
+        BackendCommand::LoadPDBFromPath(0, TEST_PDB_FILE_PATH.into()),
+        BackendCommand::ReconstructAllTypes(0, flavor, true, true, false),
+        BackendCommand::ListTypes(0, "Struct".to_string(), false, false, false),
+        BackendCommand::LoadPDBFromPath(0, TEST_PDB_FILE_PATH.into()),
+    ] {
+        backend.send_command(command).expect("send command");
+    }
+
+    // Interactive commands jump ahead of batch commands, but reloading the
+    // PDB waits for the batch commands using it
+    let mut results = vec![];
+    while results.len() < 4 {
+        let result_name = match rx_ui
+            .recv_timeout(Duration::from_secs(30))
+            .expect("command result")
+        {
+            FrontendCommand::LoadPDBResult(result) => {
+                result.expect("load test.pdb");
+                "LoadPDBResult"
+            }
+            FrontendCommand::ReconstructTypeResult(..) => "ReconstructTypeResult",
+            FrontendCommand::ListTypesResult(..) => "ListTypesResult",
+            _ => continue,
+        };
+        results.push(result_name);
+    }
+    assert_eq!(
+        results,
+        [
+            "LoadPDBResult",
+            "ListTypesResult",
+            "ReconstructTypeResult",
+            "LoadPDBResult"
+        ]
+    );
+}
@@ -0,0 +1,67 @@
+use std::path::Path;
+
+use resym_core::{
+    pdb_file::{PdbFile, SymbolicConstant},
+    pdb_types::PrimitiveReconstructionFlavor,
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+
+#[test]
+fn test_constant_listing() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    let constants = pdb_file
+        .constant_list(PrimitiveReconstructionFlavor::Portable)
+        .expect("constant listing should succeed");
+    assert!(constants
+        .windows(2)
+        .all(|pair| pair[0].name <= pair[1].name));
+
+    // Enumerators are declared with their enum
+    assert!(constants.iter().all(|constant| constant.name != "VT_I2"));
+    // Static data members are declared with their class, when it's defined
+    let type_list = pdb_file.type_list().expect("type listing should succeed");
+    let is_class_defined = type_list
+        .iter()
+        .any(|(type_name, _)| type_name == "std::_False_trivial_cat");
+    match constants
+        .iter()
+        .find(|constant| constant.name == "std::_False_trivial_cat::_Bitcopy_constructible")
+    {
+        Some(constant) => {
+            assert!(!is_class_defined);
+            assert_eq!(
+                constant.to_string(),
+                "namespace std::_False_trivial_cat { constexpr bool _Bitcopy_constructible = false; }"
+            );
+        }
+        None => assert!(is_class_defined),
+    }
+    // Booleans are printed as such
+    assert!(constants
+        .iter()
+        .filter(|constant| constant.type_name.trim_start_matches("const ") == "bool")
+        .all(|constant| constant.value == "true" || constant.value == "false"));
+}
+
+#[test]
+fn test_constant_declaration() {
+    let constant = SymbolicConstant {
+        name: "kAnswer".to_string(),
+        type_name: "int32_t".to_string(),
+        value: "42".to_string(),
+    };
+    assert_eq!(constant.to_string(), "constexpr int32_t kAnswer = 42;");
+
+    // Qualified names cannot be declared, and `const` is implied by `constexpr`
+    let constant = SymbolicConstant {
+        name: "resym_test::constants::kEnabled".to_string(),
+        type_name: "const bool".to_string(),
+        value: "true".to_string(),
+    };
+    assert_eq!(
+        constant.to_string(),
+        "namespace resym_test::constants { constexpr bool kEnabled = true; }"
+    );
+}
@@ -0,0 +1,47 @@
+use std::path::Path;
+
+use resym_core::{pdb_file::PdbFile, pdb_types::PrimitiveReconstructionFlavor};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_TYPE_NAME: &str = "resym_test::BitFieldsTest2";
+
+#[test]
+fn test_doxygen_comments_reconstruction() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    // Doxygen comments aren't emitted by default
+    let (reconstructed_type, _) = pdb_file
+        .reconstruct_type_by_name(
+            TEST_TYPE_NAME,
+            PrimitiveReconstructionFlavor::Portable,
+            false,
+            false,
+            false,
+        )
+        .expect("reconstruction should succeed");
+    assert!(!reconstructed_type.contains("/**"));
+
+    pdb_file.settings.doxygen_comments = true;
+    pdb_file
+        .type_notes
+        .insert(TEST_TYPE_NAME.to_string(), "Packed flags".to_string());
+    let (reconstructed_type, _) = pdb_file
+        .reconstruct_type_by_name(
+            TEST_TYPE_NAME,
+            PrimitiveReconstructionFlavor::Portable,
+            false,
+            false,
+            false,
+        )
+        .expect("reconstruction should succeed");
+    // Notes and metadata share the same block
+    assert!(reconstructed_type.contains("/**\n * Packed flags\n *\n * - Size: 0x2\n"));
+    assert!(reconstructed_type.contains(" * - Mangled name: `.?AU"));
+    assert!(reconstructed_type.contains(&format!(
+        " * - Source PDB: test.pdb\n */\nstruct {TEST_TYPE_NAME} {{"
+    )));
+    assert!(reconstructed_type.contains(
+        "  /** Offset: 0x0001, size: 0x1, bit position: 6, bit width: 2 */\n  /* 0x0001 */"
+    ));
+}
@@ -0,0 +1,60 @@
+use std::path::Path;
+
+use resym_core::{
+    pdb_file::PdbFile,
+    pdb_types::{EnumValueOrder, PrimitiveReconstructionFlavor},
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_ENUM_NAME: &str = "JOB_OBJECT_NET_RATE_CONTROL_FLAGS";
+
+#[test]
+fn test_enum_value_order_parsing() {
+    assert_eq!(
+        "value".parse::<EnumValueOrder>().ok(),
+        Some(EnumValueOrder::Value)
+    );
+    assert_eq!(
+        "Name".parse::<EnumValueOrder>().ok(),
+        Some(EnumValueOrder::Name)
+    );
+    assert_eq!(
+        "pdb".parse::<EnumValueOrder>().ok(),
+        Some(EnumValueOrder::Record)
+    );
+    assert!("size".parse::<EnumValueOrder>().is_err());
+}
+
+#[test]
+fn test_enum_value_order_reconstruction() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let mut enumerators_order = |enum_value_order| {
+        pdb_file.settings.enum_value_order = enum_value_order;
+        let (reconstructed_type, _) = pdb_file
+            .reconstruct_type_by_name(
+                TEST_ENUM_NAME,
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                false,
+                false,
+            )
+            .expect("reconstruction should succeed");
+        reconstructed_type
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("JOB_OBJECT_NET_RATE_CONTROL_"))
+            .filter_map(|line| line.split(' ').next())
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        enumerators_order(EnumValueOrder::Value),
+        ["ENABLE", "MAX_BANDWIDTH", "DSCP_TAG", "VALID_FLAGS"]
+    );
+    assert_eq!(
+        enumerators_order(EnumValueOrder::Name),
+        ["DSCP_TAG", "ENABLE", "MAX_BANDWIDTH", "VALID_FLAGS"]
+    );
+    assert_eq!(enumerators_order(EnumValueOrder::Record).len(), 4);
+}
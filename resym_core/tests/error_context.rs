@@ -0,0 +1,37 @@
+use resym_core::{
+    backend::BackendCommand, frontend::FrontendCommand, ErrorContext, ResymCoreError,
+};
+
+#[test]
+fn test_command_error_context() {
+    let command = BackendCommand::ListTypes(1, "Struct".to_string(), false, false, false);
+    assert_eq!(command.name(), "ListTypes");
+    assert_eq!(command.pdb_slot(), Some(1));
+    assert_eq!(BackendCommand::SetDoxygenComments(true).pdb_slot(), None);
+
+    let context = ErrorContext {
+        command: command.name(),
+        pdb_slot: command.pdb_slot(),
+    };
+    let frontend_command = FrontendCommand::ReconstructTypeResult(Err(
+        ResymCoreError::TypeNameNotFoundError("StructTest".to_string()),
+    ))
+    .with_error_context(context);
+    let err = frontend_command.error().expect("error should be kept");
+    assert_eq!(err.context(), Some(&context));
+    assert!(matches!(
+        err.without_context(),
+        ResymCoreError::TypeNameNotFoundError(_)
+    ));
+    // The context doesn't change how errors are displayed
+    assert_eq!(err.to_string(), "type not found: StructTest");
+
+    // Cancellations are left untouched
+    let frontend_command =
+        FrontendCommand::ReconstructTypeResult(Err(ResymCoreError::OperationCancelledError))
+            .with_error_context(context);
+    assert!(matches!(
+        frontend_command.error(),
+        Some(ResymCoreError::OperationCancelledError)
+    ));
+}
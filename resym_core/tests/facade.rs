@@ -0,0 +1,65 @@
+use resym_core::{
+    pdb_file::PdbFile, pdb_types::PrimitiveReconstructionFlavor, ReconstructionOptions, Resym,
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_PDB_FROM_FILE_PATH: &str = "tests/data/test_diff_from.pdb";
+const TEST_PDB_TO_FILE_PATH: &str = "tests/data/test_diff_to.pdb";
+
+#[test]
+fn test_list_types() {
+    let resym = Resym::open(TEST_PDB_FILE_PATH).expect("load test.pdb");
+
+    let type_list = resym
+        .list_types("resym_test::StructTest", false, false, false)
+        .expect("list types");
+    assert!(type_list
+        .iter()
+        .any(|(type_name, _)| type_name == "resym_test::StructTest"));
+
+    // Invalid regular expressions are reported instead of matching nothing
+    assert!(resym.list_types("(", false, true, false).is_err());
+}
+
+#[test]
+fn test_reconstruct_matches_pdb_file() {
+    let resym = Resym::open(TEST_PDB_FILE_PATH).expect("load test.pdb");
+    let pdb_file = PdbFile::load_from_file(TEST_PDB_FILE_PATH.as_ref()).expect("load test.pdb");
+    let options = ReconstructionOptions {
+        primitives_flavor: PrimitiveReconstructionFlavor::Microsoft,
+        ..Default::default()
+    };
+
+    let reconstructed_type = resym
+        .reconstruct("resym_test::StructTest", &options)
+        .expect("reconstruct type");
+    let (expected_type, _) = pdb_file
+        .reconstruct_type_by_name(
+            "resym_test::StructTest",
+            PrimitiveReconstructionFlavor::Microsoft,
+            true,
+            true,
+            false,
+        )
+        .expect("reconstruct type");
+    assert_eq!(reconstructed_type, expected_type);
+
+    assert!(resym
+        .reconstruct("resym_test::MissingType", &options)
+        .is_err());
+}
+
+#[test]
+fn test_diff() {
+    let resym_from = Resym::open(TEST_PDB_FROM_FILE_PATH).expect("load test_diff_from.pdb");
+    let resym_to = Resym::open(TEST_PDB_TO_FILE_PATH).expect("load test_diff_to.pdb");
+
+    let diff = resym_from
+        .diff(
+            &resym_to,
+            "UserStructAdd",
+            &ReconstructionOptions::default(),
+        )
+        .expect("diff type");
+    assert!(diff.statistics.lines_added > 0);
+}
@@ -0,0 +1,51 @@
+use std::path::Path;
+
+use resym_core::{
+    pdb_file::PdbFile,
+    pdb_types::{NumberBase, NumberFormat, PrimitiveReconstructionFlavor},
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_FLAG_ENUM_NAME: &str = "JOB_OBJECT_NET_RATE_CONTROL_FLAGS";
+const TEST_ENUM_NAME: &str = "resym_test::EnumTest1";
+
+#[test]
+fn test_flag_enum_comments_reconstruction() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let reconstruct_type = |pdb_file: &PdbFile<_>, type_name: &str| {
+        pdb_file
+            .reconstruct_type_by_name(
+                type_name,
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                false,
+                false,
+            )
+            .expect("reconstruction should succeed")
+            .0
+    };
+
+    // Flag enums aren't annotated by default
+    assert!(!reconstruct_type(&pdb_file, TEST_FLAG_ENUM_NAME).contains("//"));
+
+    pdb_file.settings.flag_enum_comments = true;
+    let reconstructed_type = reconstruct_type(&pdb_file, TEST_FLAG_ENUM_NAME);
+    assert!(reconstructed_type.contains(" { // flags\n"));
+    assert!(reconstructed_type.contains(" // 0x1, bit 0\n"));
+    assert!(reconstructed_type.contains(" // 0x4, bit 2\n"));
+    // Combinations of flags list all of their bits
+    assert!(reconstructed_type.contains(" // 0x7, bits 0, 1, 2\n"));
+
+    // Enums whose values are a plain sequence aren't flags
+    assert!(!reconstruct_type(&pdb_file, TEST_ENUM_NAME).contains("//"));
+
+    // Flag values follow the number format
+    pdb_file.settings.number_format = NumberFormat {
+        base: NumberBase::Decimal,
+        zero_padded: false,
+    };
+    let reconstructed_type = reconstruct_type(&pdb_file, TEST_FLAG_ENUM_NAME);
+    assert!(reconstructed_type.contains(" // 4, bit 2\n"));
+    assert!(reconstructed_type.contains(" // 7, bits 0, 1, 2\n"));
+}
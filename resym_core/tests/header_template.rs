@@ -0,0 +1,44 @@
+use resym_core::header_template::{current_date, HeaderTemplate, DEFAULT_HEADER_TEMPLATE};
+
+#[test]
+fn test_header_template_rendering() {
+    let header_template = HeaderTemplate::new("// {pdb_name} ({guid}) - {unknown}\n// {type_name}");
+    let header = header_template.render(&[
+        ("pdb_name", "test.pdb"),
+        ("guid", "ABCD"),
+        ("type_name", "resym_test::StructTest"),
+    ]);
+    // Unknown placeholders should be kept as is and the header should end
+    // with a new line
+    assert_eq!(
+        header,
+        "// test.pdb (ABCD) - {unknown}\n// resym_test::StructTest\n"
+    );
+}
+
+#[test]
+fn test_default_header_template() {
+    let header = HeaderTemplate::default().render(&[
+        ("pdb_path", "tests/data/test.pdb"),
+        ("architecture", "X86"),
+        ("resym_version", "1.0.0"),
+    ]);
+    assert_eq!(
+        header,
+        DEFAULT_HEADER_TEMPLATE
+            .replace("{resym_version}", "1.0.0")
+            .replace("{pdb_path}", "tests/data/test.pdb")
+            .replace("{architecture}", "X86")
+    );
+    assert!(!header.contains('{'));
+}
+
+#[test]
+fn test_current_date_format() {
+    let date = current_date();
+    let parts: Vec<&str> = date.split('-').collect();
+    assert_eq!(parts.len(), 3);
+    assert_eq!(parts[0].len(), 4);
+    assert_eq!(parts[1].len(), 2);
+    assert_eq!(parts[2].len(), 2);
+}
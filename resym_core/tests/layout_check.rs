@@ -0,0 +1,208 @@
+use std::path::Path;
+
+use resym_core::{
+    layout_check::{
+        check_header_layouts, generate_layout_assertions, parse_type_layouts, FieldLayout,
+        LayoutMismatch, TypeLayout,
+    },
+    pdb_file::PdbFile,
+    pdb_types::{BraceStyle, CodeFormatConfiguration, NumberBase, NumberFormat},
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_HEADER: &str = "
+namespace resym_test {
+// Hand-written definition
+struct StructUnnamedUdtTest3 { // Size=0x40
+    uint64_t Before; // +0x0
+    union {
+        struct {
+            uint64_t u1; // +0x8
+            uint64_t u2; // +0x10
+        };
+        uint64_t* p1;
+    };
+    uint64_t Middle; // +0x20
+    uint64_t Renamed; // +0x30
+    uint64_t After; /* 0x38 */
+};
+}
+
+typedef struct
+{
+    /* 0x0000 */ char name[16];
+    /* 0x0010 */ uint32_t flags : 3;
+    /* 0x0010 */ uint32_t : 0;
+    /* 0x0018 */ void (*callback)(int);
+    void Method();
+} UndefinedStruct;
+";
+
+#[test]
+fn test_header_layout_parsing() {
+    let layouts = parse_type_layouts(TEST_HEADER);
+
+    assert_eq!(
+        layouts,
+        vec![
+            TypeLayout {
+                name: "resym_test::StructUnnamedUdtTest3".to_string(),
+                size: Some(0x40),
+                fields: [
+                    ("Before", 0x0, false),
+                    ("u1", 0x8, false),
+                    ("u2", 0x10, false),
+                    ("Middle", 0x20, false),
+                    ("Renamed", 0x30, false),
+                    ("After", 0x38, false)
+                ]
+                .into_iter()
+                .map(|(name, offset, is_bitfield)| FieldLayout {
+                    name: name.to_string(),
+                    offset,
+                    is_bitfield
+                })
+                .collect(),
+            },
+            TypeLayout {
+                name: "UndefinedStruct".to_string(),
+                size: None,
+                fields: [
+                    ("name", 0x0, false),
+                    ("flags", 0x10, true),
+                    ("callback", 0x18, false)
+                ]
+                .into_iter()
+                .map(|(name, offset, is_bitfield)| FieldLayout {
+                    name: name.to_string(),
+                    offset,
+                    is_bitfield
+                })
+                .collect(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_decimal_header_layout_parsing() {
+    let layouts = parse_type_layouts(
+        "struct Decimal { /* Size=24 */\n  /* 0 */ char name[16]; // 3D position\n  /* 16 */ int32_t flags;\n  int32_t other; // 20\n};\n",
+    );
+
+    assert_eq!(
+        layouts,
+        vec![TypeLayout {
+            name: "Decimal".to_string(),
+            size: Some(24),
+            fields: [
+                ("name", 0, false),
+                ("flags", 16, false),
+                ("other", 20, false)
+            ]
+            .into_iter()
+            .map(|(name, offset, is_bitfield)| FieldLayout {
+                name: name.to_string(),
+                offset,
+                is_bitfield
+            })
+            .collect(),
+        }]
+    );
+}
+
+#[test]
+fn test_header_layout_checking() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    let layout_checks = check_header_layouts(&pdb_file, TEST_HEADER).expect("layout check");
+    assert_eq!(layout_checks.len(), 2);
+
+    assert_eq!(
+        layout_checks[0].type_name,
+        "resym_test::StructUnnamedUdtTest3"
+    );
+    assert_eq!(layout_checks[0].checked_field_count, 6);
+    assert_eq!(
+        layout_checks[0].mismatches,
+        vec![
+            LayoutMismatch::FieldOffset {
+                field_name: "Middle".to_string(),
+                expected: 0x20,
+                actual: 0x28,
+            },
+            LayoutMismatch::MissingField("Renamed".to_string()),
+        ]
+    );
+
+    assert_eq!(layout_checks[1].type_name, "UndefinedStruct");
+    assert_eq!(
+        layout_checks[1].mismatches,
+        vec![LayoutMismatch::MissingType]
+    );
+}
+
+#[test]
+fn test_header_layout_checking_with_custom_code_format() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let default_layout_checks = check_header_layouts(&pdb_file, TEST_HEADER).expect("layout check");
+
+    // Layouts come from type records, so the style of reconstructed types
+    // doesn't matter
+    pdb_file.settings.code_format = CodeFormatConfiguration {
+        brace_style: BraceStyle::Allman,
+        max_line_width: Some(20),
+        ..Default::default()
+    };
+    pdb_file.settings.number_format = NumberFormat {
+        base: NumberBase::Decimal,
+        zero_padded: false,
+    };
+    assert_eq!(
+        check_header_layouts(&pdb_file, TEST_HEADER).expect("layout check"),
+        default_layout_checks
+    );
+}
+
+#[test]
+fn test_layout_assertion_generation() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    let layout_assertions = generate_layout_assertions(
+        &pdb_file,
+        Some(&["resym_test::StructUnnamedUdtTest3".to_string()]),
+        false,
+    )
+    .expect("assertion generation");
+    // Fields of anonymous unions and structs are checked too
+    for (field_name, offset) in [("Before", 0x0), ("u1", 0x8), ("p4", 0x20), ("After", 0x38)] {
+        assert!(layout_assertions.contains(&format!(
+            "static_assert(offsetof(resym_test::StructUnnamedUdtTest3, {field_name}) == 0x{offset:x}, \"Unexpected offset for resym_test::StructUnnamedUdtTest3::{field_name}\");\n"
+        )));
+    }
+}
+
+#[test]
+fn test_layout_assertion_generation_with_custom_code_format() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let type_names = ["resym_test::StructUnnamedUdtTest3".to_string()];
+    let default_layout_assertions = generate_layout_assertions(&pdb_file, Some(&type_names), false)
+        .expect("assertion generation");
+
+    pdb_file.settings.code_format = CodeFormatConfiguration {
+        brace_style: BraceStyle::Allman,
+        max_line_width: Some(20),
+        ..Default::default()
+    };
+    pdb_file.settings.number_format = NumberFormat {
+        base: NumberBase::Decimal,
+        zero_padded: false,
+    };
+    assert_eq!(
+        generate_layout_assertions(&pdb_file, Some(&type_names), false)
+            .expect("assertion generation"),
+        default_layout_assertions
+    );
+}
@@ -0,0 +1,62 @@
+use std::path::Path;
+
+use resym_core::{pdb_file::PdbFile, pdb_types::PrimitiveReconstructionFlavor};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_CASES: &[&str] = &[
+    "resym_test::SpecializedInterfaceImplClass",
+    "resym_test::ClassWithRefsAndStaticsTest",
+];
+
+#[test]
+fn test_public_members_only_reconstruction() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    pdb_file.settings.member_filter.public_only = true;
+
+    for test_case_type_name in TEST_CASES {
+        let (reconstructed_type, _) = pdb_file
+            .reconstruct_type_by_name(
+                test_case_type_name,
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                true,
+                false,
+            )
+            .expect("reconstruction should succeed");
+        assert!(!reconstructed_type.contains("private: "));
+        assert!(!reconstructed_type.contains("protected: "));
+    }
+
+    // Public members should still be printed
+    let (reconstructed_type, _) = pdb_file
+        .reconstruct_type_by_name(
+            "resym_test::SpecializedInterfaceImplClass",
+            PrimitiveReconstructionFlavor::Portable,
+            false,
+            true,
+            false,
+        )
+        .expect("reconstruction should succeed");
+    assert!(reconstructed_type.contains("public: SpecializedInterfaceImplClass();"));
+}
+
+#[test]
+fn test_compiler_generated_members_reconstruction() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    pdb_file.settings.member_filter.hide_compiler_generated = true;
+
+    for test_case_type_name in ["resym_test::UnionTest", "resym_test::StructTest"] {
+        let (reconstructed_type, _) = pdb_file
+            .reconstruct_type_by_name(
+                test_case_type_name,
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                true,
+                false,
+            )
+            .expect("reconstruction should succeed");
+        assert!(!reconstructed_type.contains("__vecDelDtor"));
+    }
+}
@@ -24,6 +24,7 @@ fn test_module_diffing_by_path() {
         PrimitiveReconstructionFlavor::Portable,
         true,
         true,
+        false,
     )
     .unwrap_or_else(|err| panic!("module diffing failed: {err}"));
 
@@ -0,0 +1,54 @@
+use std::path::Path;
+
+use resym_core::{
+    pdb_file::{PDBDataSource, PdbFile},
+    pdb_types::{NumberBase, NumberFormat, PrimitiveReconstructionFlavor},
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+
+fn reconstruct_type(pdb_file: &PdbFile<PDBDataSource>, type_name: &str) -> String {
+    pdb_file
+        .reconstruct_type_by_name(
+            type_name,
+            PrimitiveReconstructionFlavor::Portable,
+            false,
+            false,
+            false,
+        )
+        .expect("reconstruction should succeed")
+        .0
+}
+
+#[test]
+fn test_decimal_number_format() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    pdb_file.settings.number_format = NumberFormat {
+        base: NumberBase::Decimal,
+        zero_padded: true,
+    };
+
+    let reconstructed_type = reconstruct_type(&pdb_file, "resym_test::StructTest");
+    assert!(reconstructed_type.contains("struct resym_test::StructTest { /* Size=24 */"));
+    assert!(reconstructed_type.contains("  /* 10 */ uint16_t u2;"));
+
+    let reconstructed_type = reconstruct_type(&pdb_file, "resym_test::EnumTest2");
+    assert!(reconstructed_type.contains("  kEnumTest2Val3 = 2,"));
+}
+
+#[test]
+fn test_unpadded_number_format() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    pdb_file.settings.number_format = NumberFormat {
+        base: NumberBase::Hexadecimal,
+        zero_padded: false,
+    };
+
+    let reconstructed_type = reconstruct_type(&pdb_file, "resym_test::StructTest");
+    assert!(reconstructed_type.contains("  /* 0xa */ uint16_t u2;"));
+
+    let reconstructed_type = reconstruct_type(&pdb_file, "resym_test::EnumTest2");
+    assert!(reconstructed_type.contains("  kEnumTest2Val3 = 0x2,"));
+}
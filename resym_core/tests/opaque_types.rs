@@ -0,0 +1,79 @@
+use std::path::Path;
+
+use resym_core::{
+    pdb_file::PdbFile,
+    pdb_types::{OpaqueTypeRules, PrimitiveReconstructionFlavor},
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_TYPE_NAME: &str = "resym_test::SpecializedInterfaceImplClass";
+const TEST_BASE_TYPE_NAME: &str = "resym_test::PureVirtualClassSpecialized";
+
+#[test]
+fn test_opaque_type_rules_parsing() {
+    let rules =
+        OpaqueTypeRules::parse("^std::\n\n  ^_LIST_ENTRY$ => windows.h  \n^HWND__$ => \"hwnd.h\"")
+            .expect("rules should be valid");
+    assert!(!rules.is_empty());
+
+    let rule = rules.find("std::vector<int>").expect("rule should match");
+    assert_eq!(rule.include_directive(), None);
+    let rule = rules.find("_LIST_ENTRY").expect("rule should match");
+    assert_eq!(
+        rule.include_directive().as_deref(),
+        Some("#include <windows.h>")
+    );
+    let rule = rules.find("HWND__").expect("rule should match");
+    assert_eq!(
+        rule.include_directive().as_deref(),
+        Some("#include \"hwnd.h\"")
+    );
+    assert!(rules.find("_LIST_ENTRY_EX").is_none());
+
+    // Invalid regular expressions should be reported
+    assert!(OpaqueTypeRules::parse("^std::(").is_err());
+}
+
+#[test]
+fn test_opaque_type_include() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    pdb_file.settings.opaque_type_rules =
+        OpaqueTypeRules::parse(&format!("^{TEST_BASE_TYPE_NAME}$ => base.h"))
+            .expect("rules should be valid");
+
+    let (reconstructed_type, _) = pdb_file
+        .reconstruct_type_by_name(
+            TEST_TYPE_NAME,
+            PrimitiveReconstructionFlavor::Portable,
+            true,
+            true,
+            false,
+        )
+        .unwrap_or_else(|err| panic!("type reconstruction failed: {err}"));
+
+    assert!(reconstructed_type.starts_with("\n#include <base.h>\n"));
+    assert!(!reconstructed_type.contains(&format!("\nclass {TEST_BASE_TYPE_NAME} ")));
+    assert!(reconstructed_type.contains(&format!("\nclass {TEST_TYPE_NAME} ")));
+}
+
+#[test]
+fn test_opaque_type_forward_declaration() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    pdb_file.settings.opaque_type_rules =
+        OpaqueTypeRules::parse(&format!("^{TEST_BASE_TYPE_NAME}$")).expect("rules should be valid");
+
+    let (reconstructed_type, _) = pdb_file
+        .reconstruct_type_by_name(
+            TEST_TYPE_NAME,
+            PrimitiveReconstructionFlavor::Portable,
+            true,
+            true,
+            false,
+        )
+        .unwrap_or_else(|err| panic!("type reconstruction failed: {err}"));
+
+    assert!(reconstructed_type.contains(&format!("class {TEST_BASE_TYPE_NAME};\n")));
+    assert!(!reconstructed_type.contains(&format!("\nclass {TEST_BASE_TYPE_NAME} ")));
+}
@@ -0,0 +1,210 @@
+use std::path::Path;
+
+use resym_core::{
+    pdb_file::PdbFile,
+    pdb_types::PrimitiveReconstructionFlavor,
+    pe_file::{PeExport, PeFile},
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_SYMBOL_NAME: &str = "__scrt_initialize_default_local_stdio_options";
+const TEST_SYMBOL_RVA: u32 = 0x14680;
+const NT_HEADERS_OFFSET: usize = 0x80;
+const OPTIONAL_HEADER_OFFSET: usize = NT_HEADERS_OFFSET + 4 + 20;
+const PE32_PLUS_OPTIONAL_HEADER_SIZE: usize = 0xf0;
+const EXPORT_DIRECTORY_RVA: u32 = 0x1000;
+const EXPORT_DIRECTORY_OFFSET: usize = 0x400;
+
+/// Build the headers of a PE image with the given optional header magic and
+/// image base
+fn build_pe_headers(magic: u16, image_base: u64) -> Vec<u8> {
+    let mut data = vec![0; 0x200];
+    data[..2].copy_from_slice(b"MZ");
+    data[0x3c..0x40].copy_from_slice(&(NT_HEADERS_OFFSET as u32).to_le_bytes());
+    data[NT_HEADERS_OFFSET..NT_HEADERS_OFFSET + 4].copy_from_slice(b"PE\0\0");
+
+    let optional_header_offset = NT_HEADERS_OFFSET + 4 + 20;
+    data[optional_header_offset..optional_header_offset + 2].copy_from_slice(&magic.to_le_bytes());
+    if magic == 0x10b {
+        data[optional_header_offset + 28..optional_header_offset + 32]
+            .copy_from_slice(&(image_base as u32).to_le_bytes());
+    } else {
+        data[optional_header_offset + 24..optional_header_offset + 32]
+            .copy_from_slice(&image_base.to_le_bytes());
+    }
+
+    data
+}
+
+fn write_u16(data: &mut [u8], offset: usize, value: u16) {
+    data[offset..offset + 2].copy_from_slice(&value.to_le_bytes());
+}
+
+fn write_u32(data: &mut [u8], offset: usize, value: u32) {
+    data[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+}
+
+/// Build a PE32+ image with an `.rdata` section holding an export directory
+/// and a `.text` section matching the one of test.pdb. The test symbol is
+/// exported by name, and another function by ordinal only.
+fn build_pe_image(size_of_image: u32) -> Vec<u8> {
+    let mut data = build_pe_headers(0x20b, 0x140000000);
+    data.resize(0x600, 0);
+
+    let coff_header_offset = NT_HEADERS_OFFSET + 4;
+    write_u16(&mut data, coff_header_offset + 2, 2);
+    write_u16(
+        &mut data,
+        coff_header_offset + 16,
+        PE32_PLUS_OPTIONAL_HEADER_SIZE as u16,
+    );
+    write_u32(&mut data, OPTIONAL_HEADER_OFFSET + 56, size_of_image);
+    write_u32(&mut data, OPTIONAL_HEADER_OFFSET + 108, 16);
+    write_u32(
+        &mut data,
+        OPTIONAL_HEADER_OFFSET + 112,
+        EXPORT_DIRECTORY_RVA,
+    );
+    write_u32(&mut data, OPTIONAL_HEADER_OFFSET + 116, 0x100);
+
+    let section_headers_offset = OPTIONAL_HEADER_OFFSET + PE32_PLUS_OPTIONAL_HEADER_SIZE;
+    for (section_index, (name, virtual_address, virtual_size, raw_size, raw_offset)) in [
+        (b".rdata\0\0", EXPORT_DIRECTORY_RVA, 0x1000, 0x200, 0x400),
+        (b".text\0\0\0", 0x14000, 0x1000, 0, 0),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let offset = section_headers_offset + section_index * 40;
+        data[offset..offset + 8].copy_from_slice(name);
+        write_u32(&mut data, offset + 8, virtual_size);
+        write_u32(&mut data, offset + 12, virtual_address);
+        write_u32(&mut data, offset + 16, raw_size);
+        write_u32(&mut data, offset + 20, raw_offset);
+    }
+
+    // Export directory, followed by the function, name and ordinal tables
+    write_u32(&mut data, EXPORT_DIRECTORY_OFFSET + 16, 1);
+    write_u32(&mut data, EXPORT_DIRECTORY_OFFSET + 20, 2);
+    write_u32(&mut data, EXPORT_DIRECTORY_OFFSET + 24, 1);
+    write_u32(
+        &mut data,
+        EXPORT_DIRECTORY_OFFSET + 28,
+        EXPORT_DIRECTORY_RVA + 0x40,
+    );
+    write_u32(
+        &mut data,
+        EXPORT_DIRECTORY_OFFSET + 32,
+        EXPORT_DIRECTORY_RVA + 0x50,
+    );
+    write_u32(
+        &mut data,
+        EXPORT_DIRECTORY_OFFSET + 36,
+        EXPORT_DIRECTORY_RVA + 0x60,
+    );
+    write_u32(&mut data, EXPORT_DIRECTORY_OFFSET + 0x40, TEST_SYMBOL_RVA);
+    write_u32(&mut data, EXPORT_DIRECTORY_OFFSET + 0x44, 0x14700);
+    write_u32(
+        &mut data,
+        EXPORT_DIRECTORY_OFFSET + 0x50,
+        EXPORT_DIRECTORY_RVA + 0x70,
+    );
+    write_u16(&mut data, EXPORT_DIRECTORY_OFFSET + 0x60, 0);
+    let name_offset = EXPORT_DIRECTORY_OFFSET + 0x70;
+    data[name_offset..name_offset + TEST_SYMBOL_NAME.len()]
+        .copy_from_slice(TEST_SYMBOL_NAME.as_bytes());
+
+    data
+}
+
+#[test]
+fn test_pe_image_base() {
+    let pe_file = PeFile::parse(&build_pe_headers(0x20b, 0x180000000)).expect("parse PE32+");
+    assert_eq!(pe_file.image_base, 0x180000000);
+
+    let pe_file = PeFile::parse(&build_pe_headers(0x10b, 0x400000)).expect("parse PE32");
+    assert_eq!(pe_file.image_base, 0x400000);
+}
+
+#[test]
+fn test_invalid_pe_headers() {
+    // Not a PE image
+    assert!(PeFile::parse(b"Microsoft C/C++ MSF 7.00\r\n").is_err());
+    // Unknown optional header
+    assert!(PeFile::parse(&build_pe_headers(0x107, 0x400000)).is_err());
+    // Truncated headers
+    assert!(PeFile::parse(&build_pe_headers(0x20b, 0x180000000)[..0xa0]).is_err());
+}
+
+#[test]
+fn test_pe_sections_and_exports() {
+    let pe_file = PeFile::parse(&build_pe_image(0x20000)).expect("parse PE32+");
+    assert_eq!(
+        pe_file
+            .sections
+            .iter()
+            .map(|section| section.name.as_str())
+            .collect::<Vec<_>>(),
+        vec![".rdata", ".text"]
+    );
+    assert_eq!(
+        pe_file
+            .section_at_rva(TEST_SYMBOL_RVA)
+            .map(|section| section.name.as_str()),
+        Some(".text")
+    );
+    assert!(pe_file.section_at_rva(0x16000).is_none());
+
+    assert_eq!(
+        pe_file.exports,
+        vec![
+            PeExport {
+                name: Some(TEST_SYMBOL_NAME.to_string()),
+                ordinal: 1,
+                rva: TEST_SYMBOL_RVA,
+            },
+            PeExport {
+                name: None,
+                ordinal: 2,
+                rva: 0x14700,
+            },
+        ]
+    );
+    assert_eq!(pe_file.exports_at_rva(0x14700)[0].display_name(), "#2");
+    assert!(pe_file.exports_at_rva(0x14690).is_empty());
+
+    assert!(pe_file.contains_rva(0x1ffff));
+    assert!(!pe_file.contains_rva(0x20000));
+}
+
+#[test]
+fn test_symbol_location_with_pe() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    pdb_file.pe_file = Some(PeFile::parse(&build_pe_image(0x20000)).expect("parse PE32+"));
+
+    let symbol_location = pdb_file
+        .symbol_location_by_name(TEST_SYMBOL_NAME)
+        .expect("symbol lookup");
+    assert_eq!(symbol_location.section_name, ".text");
+    assert_eq!(symbol_location.export_names, vec![TEST_SYMBOL_NAME]);
+    assert!(!symbol_location.outside_image);
+
+    let reconstructed_symbol = pdb_file
+        .reconstruct_symbol_by_name(
+            TEST_SYMBOL_NAME,
+            PrimitiveReconstructionFlavor::Portable,
+            false,
+        )
+        .expect("symbol reconstruction");
+    assert!(reconstructed_symbol.contains(&format!(
+        "RVA=0x{TEST_SYMBOL_RVA:x} Section=.text Export={TEST_SYMBOL_NAME} "
+    )));
+
+    // Symbols pointing past the end of the image are flagged
+    pdb_file.pe_file = Some(PeFile::parse(&build_pe_image(0x10000)).expect("parse PE32+"));
+    let symbol_location = pdb_file
+        .symbol_location_by_name(TEST_SYMBOL_NAME)
+        .expect("symbol lookup");
+    assert!(symbol_location.outside_image);
+}
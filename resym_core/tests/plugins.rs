@@ -0,0 +1,82 @@
+#![cfg(feature = "plugins")]
+
+use resym_core::plugins::PluginManager;
+
+const TEST_PLUGIN: &str = r#"
+fn post_process(code) {
+    "// Processed\n" + code
+}
+
+fn export_formats() {
+    [#{ name: "Line count", extension: "txt", function: "count_lines" }]
+}
+
+fn menu_commands() {
+    [#{ label: "Say hello", function: "say_hello" }]
+}
+
+fn count_lines(code) {
+    `${code.split("\n").len()}`
+}
+
+fn say_hello(code) {
+    "Hello!"
+}
+"#;
+
+#[test]
+fn test_plugin_hooks() {
+    let mut plugin_manager = PluginManager::new();
+    plugin_manager
+        .load_plugin("test", TEST_PLUGIN)
+        .expect("failed to load plugin");
+
+    let code = "struct Test {\n  int a;\n};".to_string();
+    assert_eq!(
+        plugin_manager.post_process(code.clone()).unwrap(),
+        format!("// Processed\n{code}")
+    );
+
+    let export_formats = plugin_manager.export_formats().unwrap();
+    assert_eq!(export_formats.len(), 1);
+    assert_eq!(export_formats[0].name, "Line count");
+    assert_eq!(export_formats[0].extension, "txt");
+    assert_eq!(
+        plugin_manager.export(&export_formats[0], &code).unwrap(),
+        "3"
+    );
+
+    let menu_commands = plugin_manager.menu_commands().unwrap();
+    assert_eq!(menu_commands.len(), 1);
+    assert_eq!(menu_commands[0].label, "Say hello");
+    assert_eq!(
+        plugin_manager
+            .run_menu_command(&menu_commands[0], &code)
+            .unwrap()
+            .as_deref(),
+        Some("Hello!")
+    );
+}
+
+#[test]
+fn test_plugin_without_hooks() {
+    let mut plugin_manager = PluginManager::new();
+    plugin_manager
+        .load_plugin("empty", "let a = 1;")
+        .expect("failed to load plugin");
+
+    // Plugins that don't define hooks shouldn't have any effect
+    assert_eq!(
+        plugin_manager.post_process("code".to_string()).unwrap(),
+        "code"
+    );
+    assert!(plugin_manager.export_formats().unwrap().is_empty());
+    assert!(plugin_manager.menu_commands().unwrap().is_empty());
+}
+
+#[test]
+fn test_plugin_invalid_source() {
+    let mut plugin_manager = PluginManager::new();
+    assert!(plugin_manager.load_plugin("invalid", "fn (").is_err());
+    assert_eq!(plugin_manager.plugin_names().count(), 0);
+}
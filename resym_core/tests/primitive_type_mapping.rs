@@ -0,0 +1,91 @@
+use std::path::Path;
+
+use resym_core::{
+    pdb_file::PdbFile,
+    pdb_types::{PrimitiveReconstructionFlavor, PrimitiveTypeMapping},
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_TYPE_NAME: &str = "resym_test::PrimitiveTypesTest";
+
+#[test]
+fn test_primitive_type_mapping_parsing() {
+    let mapping = PrimitiveTypeMapping::parse("\n  uint32_t => u32  \nunsigned char => BYTE\n")
+        .expect("valid mapping");
+    assert_eq!(mapping.name("uint32_t"), "u32");
+    assert_eq!(mapping.name("unsigned char"), "BYTE");
+    assert_eq!(mapping.name("int32_t"), "int32_t");
+    assert_eq!(
+        PrimitiveTypeMapping::parse(&mapping.to_string()).expect("valid mapping"),
+        mapping
+    );
+
+    assert!(PrimitiveTypeMapping::parse("uint32_t").is_err());
+    assert!(PrimitiveTypeMapping::parse("uint32_t =>").is_err());
+    assert!(PrimitiveTypeMapping::parse("DWORD => u32").is_err());
+}
+
+#[test]
+fn test_primitive_type_mapping_reconstruction() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    pdb_file.settings.primitive_type_mapping =
+        PrimitiveTypeMapping::parse("uint32_t => u32\nunsigned char => BYTE\nchar => i8")
+            .expect("valid mapping");
+    let reconstruct_type = |primitives_flavor| {
+        pdb_file
+            .reconstruct_type_by_name(TEST_TYPE_NAME, primitives_flavor, false, false, false)
+            .expect("reconstruction should succeed")
+            .0
+    };
+
+    let reconstructed_type = reconstruct_type(PrimitiveReconstructionFlavor::Custom);
+    assert!(reconstructed_type.contains(" u32 i3;"));
+    assert!(reconstructed_type.contains(" BYTE c2;"));
+    assert!(reconstructed_type.contains(" i8 c1;"));
+    // Primitive types which aren't mapped keep their portable name
+    assert!(reconstructed_type.contains(" int32_t i4;"));
+
+    // The mapping only applies to the custom flavor
+    assert!(reconstruct_type(PrimitiveReconstructionFlavor::Portable).contains(" uint32_t i3;"));
+}
+
+#[test]
+fn test_primitive_type_mapping_only_renames_primitive_types() {
+    // Name `resym_test::ArrayTest::array1` like a primitive type
+    let mut pdb_data = std::fs::read(TEST_PDB_FILE_PATH).expect("read test.pdb");
+    let field_name = b"array1\0";
+    let offset = pdb_data
+        .windows(field_name.len())
+        .position(|window| window == field_name)
+        .expect("test.pdb should contain `ArrayTest::array1`");
+    pdb_data[offset..offset + "int8_t".len()].copy_from_slice(b"int8_t");
+    let mut pdb_file = PdbFile::load_from_bytes_as_vec("test.pdb".to_string(), pdb_data)
+        .expect("load patched test.pdb");
+    pdb_file.settings.primitive_type_mapping =
+        PrimitiveTypeMapping::parse("char => CHAR\nint8_t => i8").expect("valid mapping");
+    pdb_file.type_notes.insert(
+        TEST_TYPE_NAME.to_string(),
+        "Lists the char types".to_string(),
+    );
+    let reconstruct_type = |type_name| {
+        pdb_file
+            .reconstruct_type_by_name(
+                type_name,
+                PrimitiveReconstructionFlavor::Custom,
+                false,
+                false,
+                false,
+            )
+            .expect("reconstruction should succeed")
+            .0
+    };
+
+    // Primitive types are renamed as a whole
+    let reconstructed_type = reconstruct_type(TEST_TYPE_NAME);
+    assert!(reconstructed_type.contains(" CHAR c1;"));
+    assert!(reconstructed_type.contains(" unsigned char c2;"));
+    // Notes, comments and fields are left untouched
+    assert!(reconstructed_type.contains(" * Lists the char types\n"));
+    assert!(reconstruct_type("resym_test::ArrayTest").contains(" CHAR int8_t[64];"));
+}
@@ -0,0 +1,81 @@
+use std::path::Path;
+
+use resym_core::{
+    pdb_file::PdbFile,
+    pdb_types::{PrimitiveReconstructionFlavor, RenameMap},
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_TYPE_NAME: &str = "resym_test::StructTest";
+
+#[test]
+fn test_rename_map_parsing() {
+    let rename_map = RenameMap::parse("\n  _unnamed_1234 => Header  \nHeader.u1 => flags\n")
+        .expect("renames should be valid");
+    assert!(!rename_map.is_empty());
+    assert_eq!(rename_map.type_name("_unnamed_1234"), "Header");
+    assert_eq!(rename_map.type_name("Other"), "Other");
+    // Serialized renames should be parsed back identically
+    assert_eq!(
+        RenameMap::parse(&rename_map.to_string()).expect("renames should be valid"),
+        rename_map
+    );
+
+    // Invalid renames should be reported
+    assert!(RenameMap::parse("_unnamed_1234").is_err());
+    assert!(RenameMap::parse("_unnamed_1234 => not valid").is_err());
+    assert!(RenameMap::parse("Header.u1 => ns::flags").is_err());
+}
+
+#[test]
+fn test_rename_map_apply() {
+    let rename_map = RenameMap::parse("A => B\nB => C\nA.a => first").expect("valid renames");
+    let code = "struct A { /* Size=0x8 */\n  /* 0x0000 */ int32_t a;\n  /* 0x0004 */ B* b;\n};\n\nstruct B { /* Size=0x4 */\n  /* 0x0000 */ ns::A a;\n};\n";
+
+    // Renames shouldn't apply to each other, and members should only be
+    // renamed in their type's definition
+    assert_eq!(
+        rename_map.apply(code),
+        "struct B { /* Size=0x8 */\n  /* 0x0000 */ int32_t first;\n  /* 0x0004 */ C* b;\n};\n\nstruct C { /* Size=0x4 */\n  /* 0x0000 */ ns::A a;\n};\n"
+    );
+}
+
+#[test]
+fn test_rename_map_apply_qualified_names() {
+    let rename_map =
+        RenameMap::parse("A => B\nA::Inner => Other\nop$ => op").expect("valid renames");
+    let code = "A::Inner x;\nA::InnerX y;\nA a;\nxA z;\nns::A w;\nop$ v;\n";
+
+    // The longest matching name should be renamed, only when not part of a
+    // longer identifier. Names containing regex metacharacters are matched
+    // literally.
+    assert_eq!(
+        rename_map.apply(code),
+        "Other x;\nB::InnerX y;\nB a;\nxA z;\nns::A w;\nop v;\n"
+    );
+}
+
+#[test]
+fn test_rename_map_reconstruction() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    pdb_file.rename_map = RenameMap::parse(&format!(
+        "{TEST_TYPE_NAME} => Renamed\n{TEST_TYPE_NAME}.u1 => flags"
+    ))
+    .expect("renames should be valid");
+
+    let (reconstructed_type, _) = pdb_file
+        .reconstruct_type_by_name(
+            TEST_TYPE_NAME,
+            PrimitiveReconstructionFlavor::Portable,
+            false,
+            true,
+            false,
+        )
+        .expect("reconstruction should succeed");
+    assert!(reconstructed_type.contains("struct Renamed {"));
+    assert!(reconstructed_type.contains("unsigned char flags;"));
+    assert!(reconstructed_type.contains("Renamed(const Renamed&);"));
+    assert!(reconstructed_type.contains("~Renamed();"));
+    assert!(!reconstructed_type.contains("StructTest"));
+}
@@ -0,0 +1,37 @@
+use std::path::Path;
+
+use resym_core::{pdb_file::PdbFile, pdb_types::PrimitiveReconstructionFlavor};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_SOURCE_FILE_NAME: &str = "symbol_zoo.cpp";
+
+#[test]
+fn test_source_file_listing() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    let source_file_list = pdb_file
+        .source_file_list()
+        .unwrap_or_else(|err| panic!("source file listing failed: {err}"));
+
+    assert!(source_file_list
+        .iter()
+        .any(|(file_path, _)| file_path.to_lowercase().ends_with(TEST_SOURCE_FILE_NAME)));
+}
+
+#[test]
+fn test_source_file_reconstruction() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    let source_file_list = pdb_file
+        .source_file_list()
+        .unwrap_or_else(|err| panic!("source file listing failed: {err}"));
+    let (file_path, _) = source_file_list
+        .iter()
+        .find(|(file_path, _)| file_path.to_lowercase().ends_with(TEST_SOURCE_FILE_NAME))
+        .expect("test source file should be listed");
+
+    let reconstructed_source_file = pdb_file
+        .reconstruct_source_file_by_path(file_path, PrimitiveReconstructionFlavor::Portable, true)
+        .unwrap_or_else(|err| panic!("source file reconstruction failed: {err}"));
+    assert!(reconstructed_source_file.starts_with(&format!("// {file_path}:")));
+}
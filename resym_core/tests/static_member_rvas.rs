@@ -0,0 +1,55 @@
+use std::path::Path;
+
+use resym_core::{pdb_file::PdbFile, pdb_types::PrimitiveReconstructionFlavor};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_TYPE_NAME: &str = "resym_test::ClassWithRefsAndStaticsTest";
+
+#[test]
+fn test_static_member_rvas_reconstruction() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    // Static members aren't annotated by default
+    let (reconstructed_type, _) = pdb_file
+        .reconstruct_type_by_name(
+            TEST_TYPE_NAME,
+            PrimitiveReconstructionFlavor::Portable,
+            false,
+            true,
+            false,
+        )
+        .expect("reconstruction should succeed");
+    assert!(reconstructed_type.contains("private: static int32_t sint;\n"));
+
+    pdb_file.settings.print_static_member_rvas = true;
+    let (reconstructed_type, _) = pdb_file
+        .reconstruct_type_by_name(
+            TEST_TYPE_NAME,
+            PrimitiveReconstructionFlavor::Portable,
+            false,
+            true,
+            false,
+        )
+        .expect("reconstruction should succeed");
+    assert!(reconstructed_type.contains("private: static int32_t sint; /* RVA=0x"));
+    assert!(reconstructed_type.contains("private: static bool sbool; /* RVA=0x"));
+
+    // Virtual addresses are printed next to RVAs when an image base is set
+    pdb_file.settings.image_base = Some(0x140000000);
+    let (reconstructed_type, _) = pdb_file
+        .reconstruct_type_by_name(
+            TEST_TYPE_NAME,
+            PrimitiveReconstructionFlavor::Portable,
+            false,
+            true,
+            false,
+        )
+        .expect("reconstruction should succeed");
+    let annotation_start = reconstructed_type
+        .find("private: static int32_t sint; /* RVA=0x")
+        .expect("static member should be annotated");
+    let annotation = &reconstructed_type[annotation_start..];
+    let annotation = &annotation[..annotation.find('\n').unwrap_or(annotation.len())];
+    assert!(annotation.contains(" VA=0x1400"));
+}
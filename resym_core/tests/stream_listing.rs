@@ -0,0 +1,43 @@
+use std::path::Path;
+
+use resym_core::pdb_file::PdbFile;
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+/// Index of the PDB information stream
+const PDB_STREAM_INDEX: u16 = 1;
+
+#[test]
+fn test_stream_listing() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    let stream_list = pdb_file
+        .stream_list()
+        .unwrap_or_else(|err| panic!("stream listing failed: {err}"));
+
+    // Every stream of the MSF directory is listed, in order
+    assert_eq!(stream_list.len(), 116);
+    assert!(stream_list
+        .iter()
+        .enumerate()
+        .all(|(i, (_, stream_index))| i == *stream_index as usize));
+    assert!(stream_list[PDB_STREAM_INDEX as usize]
+        .0
+        .starts_with("#1 PDB "));
+    // Named streams are resolved
+    assert!(stream_list
+        .iter()
+        .any(|(stream_name, _)| stream_name.contains(" /names ")));
+}
+
+#[test]
+fn test_stream_data() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    let stream_data = pdb_file
+        .stream_data(PDB_STREAM_INDEX)
+        .unwrap_or_else(|err| panic!("stream reading failed: {err}"));
+    assert!(!stream_data.is_empty());
+
+    // Out of bounds streams should trigger an error
+    assert!(pdb_file.stream_data(u16::MAX).is_err());
+}
@@ -0,0 +1,53 @@
+use std::path::Path;
+
+use resym_core::{
+    pdb_file::{PdbFile, SymbolKind, SymbolKindFilter},
+    pdb_types::PrimitiveReconstructionFlavor,
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_THUNK_NAME: &str = "WideCharToMultiByte";
+
+#[test]
+fn test_symbol_listing_by_kind() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    let function_list = pdb_file
+        .symbol_list_by_kind(&SymbolKindFilter::from_kinds(&[SymbolKind::Function]))
+        .expect("symbol listing");
+    assert!(function_list.iter().any(|(name, _)| name == "main"));
+    assert!(!function_list
+        .iter()
+        .any(|(name, _)| name == TEST_THUNK_NAME));
+
+    // Import thunks share their name with public symbols, they're only listed
+    // when publics are filtered out
+    let thunk_list = pdb_file
+        .symbol_list_by_kind(&SymbolKindFilter::from_kinds(&[SymbolKind::Thunk]))
+        .expect("symbol listing");
+    assert_eq!(thunk_list.len(), 72);
+    let (_, thunk_index) = thunk_list
+        .iter()
+        .find(|(name, _)| name == TEST_THUNK_NAME)
+        .expect("thunk not found");
+    let reconstructed_thunk = pdb_file
+        .reconstruct_symbol_by_index(*thunk_index, PrimitiveReconstructionFlavor::Portable, false)
+        .expect("symbol reconstruction");
+    assert!(reconstructed_thunk.starts_with(&format!("void {TEST_THUNK_NAME}(); // RVA=0x")));
+    assert!(reconstructed_thunk.ends_with(" (thunk)"));
+
+    // `SymbolKindFilter::default()` keeps all symbols but thunks
+    let symbol_list = pdb_file.symbol_list().expect("symbol listing");
+    assert_eq!(
+        symbol_list,
+        pdb_file
+            .symbol_list_by_kind(&SymbolKindFilter::from_kinds(&[]))
+            .expect("symbol listing")
+    );
+    assert!(function_list.iter().all(|(name, _)| symbol_list
+        .iter()
+        .any(|(listed_name, _)| listed_name == name)));
+    assert!(!symbol_list
+        .iter()
+        .any(|(_, symbol_index)| symbol_index == thunk_index));
+}
@@ -1,7 +1,16 @@
 use std::path::Path;
 
+use regex::Regex;
 use resym_core::{
-    diffing::diff_type_by_name, pdb_file::PdbFile, pdb_types::PrimitiveReconstructionFlavor,
+    cancellation::CancellationToken,
+    diffing::{
+        changed_type_names, compare_type_across_pdbs, diff_all_types, diff_text, diff_type_by_name,
+        diff_type_by_name_three_way, split_range_by_inline_changes, DiffChange, DiffOptions,
+        DiffStatistics,
+    },
+    pdb_file::PdbFile,
+    pdb_types::{NumberBase, NumberFormat, PrimitiveReconstructionFlavor},
+    ResymCoreError,
 };
 
 const TEST_PDB_FROM_FILE_PATH: &str = "tests/data/test_diff_from.pdb";
@@ -27,10 +36,7 @@ fn test_struct_diffing() {
             &pdb_file_to,
             test_case_type_name,
             PrimitiveReconstructionFlavor::Portable,
-            false,
-            false,
-            false,
-            false,
+            &DiffOptions::default(),
         )
         .expect("diff generation");
         insta::assert_snapshot!(diffed_type.data);
@@ -49,10 +55,613 @@ fn test_struct_diffing_inexistent_type() {
         &pdb_file_to,
         INEXISTENT_TYPE_NAME,
         PrimitiveReconstructionFlavor::Portable,
+        &DiffOptions::default(),
+    )
+    .is_err());
+}
+
+#[test]
+fn test_struct_diffing_ignore_comments() {
+    let pdb_file_from = PdbFile::load_from_file(Path::new(TEST_PDB_FROM_FILE_PATH))
+        .expect("load test_diff_from.pdb");
+    let pdb_file_to =
+        PdbFile::load_from_file(Path::new(TEST_PDB_TO_FILE_PATH)).expect("load test_diff_to.pdb");
+
+    for test_case_type_name in TEST_CASES {
+        let diffed_type = diff_type_by_name(
+            &pdb_file_from,
+            &pdb_file_to,
+            test_case_type_name,
+            PrimitiveReconstructionFlavor::Portable,
+            &DiffOptions {
+                print_header: true,
+                ignore_comments: true,
+                ..Default::default()
+            },
+        )
+        .expect("diff generation");
+        // The header banner must not be part of the diff
+        assert!(!diffed_type
+            .data
+            .contains("Showing differences between two PDB files"));
+        assert!(diffed_type.data.lines().all(|line| !line
+            .get(1..)
+            .unwrap_or_default()
+            .trim_start()
+            .starts_with("//")));
+    }
+}
+
+#[test]
+fn test_struct_diffing_match_unique_names() {
+    let pdb_file_from = PdbFile::load_from_file(Path::new(TEST_PDB_FROM_FILE_PATH))
+        .expect("load test_diff_from.pdb");
+    let pdb_file_to =
+        PdbFile::load_from_file(Path::new(TEST_PDB_TO_FILE_PATH)).expect("load test_diff_to.pdb");
+
+    // Types that exist in both PDBs should be paired the same way
+    for test_case_type_name in TEST_CASES {
+        let diffed_type = diff_type_by_name(
+            &pdb_file_from,
+            &pdb_file_to,
+            test_case_type_name,
+            PrimitiveReconstructionFlavor::Portable,
+            &DiffOptions::default(),
+        )
+        .expect("diff generation");
+        let diffed_type_unique = diff_type_by_name(
+            &pdb_file_from,
+            &pdb_file_to,
+            test_case_type_name,
+            PrimitiveReconstructionFlavor::Portable,
+            &DiffOptions {
+                match_unique_names: true,
+                ..Default::default()
+            },
+        )
+        .expect("diff generation");
+        assert_eq!(diffed_type.data, diffed_type_unique.data);
+    }
+
+    // Types are paired by their decorated name
+    assert_eq!(
+        pdb_file_from
+            .type_unique_name("UserStructRemove")
+            .expect("unique name lookup")
+            .as_deref(),
+        Some(".?AUUserStructRemove@@")
+    );
+    let diffed_type = diff_type_by_name(
+        &pdb_file_from,
+        &pdb_file_to,
+        "UserStructRemove",
+        PrimitiveReconstructionFlavor::Portable,
+        &DiffOptions {
+            match_unique_names: true,
+            ..Default::default()
+        },
+    )
+    .expect("diff generation");
+    assert!(diffed_type.data.contains(
+        "-struct UserStructRemove { /* Size=0x18 */\n+struct UserStructRemove { /* Size=0x10 */\n"
+    ));
+    assert!(diffed_type
+        .data
+        .contains("\n-  /* 0x0004 */ char field2;\n"));
+    assert!(diffed_type
+        .data
+        .contains("\n-  /* 0x0010 */ int32_t field4;\n"));
+}
+
+#[test]
+fn test_struct_diffing_match_unique_names_of_renamed_types() {
+    // Rename `UserStructRemove` in test_diff_to.pdb's type records, while
+    // keeping its decorated name
+    let mut pdb_data_to = std::fs::read(TEST_PDB_TO_FILE_PATH).expect("read test_diff_to.pdb");
+    let type_record_names = b"UserStructRemove\0.?AUUserStructRemove@@";
+    let mut renamed_records = 0;
+    while let Some(offset) = pdb_data_to
+        .windows(type_record_names.len())
+        .position(|window| window == type_record_names)
+    {
+        pdb_data_to[offset..offset + "UserStructRename".len()].copy_from_slice(b"UserStructRename");
+        renamed_records += 1;
+    }
+    assert!(renamed_records > 0);
+    let pdb_file_from = PdbFile::load_from_file(Path::new(TEST_PDB_FROM_FILE_PATH))
+        .expect("load test_diff_from.pdb");
+    let pdb_file_to = PdbFile::load_from_bytes_as_vec("test_diff_to.pdb".to_string(), pdb_data_to)
+        .expect("load renamed test_diff_to.pdb");
+    assert_eq!(
+        pdb_file_to
+            .type_unique_name("UserStructRename")
+            .expect("unique name lookup")
+            .as_deref(),
+        Some(".?AUUserStructRemove@@")
+    );
+    assert_eq!(
+        pdb_file_to
+            .type_size(".?AUUserStructRemove@@")
+            .expect("size lookup"),
+        Some(0x10)
+    );
+
+    let diffed_type = diff_type_by_name(
+        &pdb_file_from,
+        &pdb_file_to,
+        "UserStructRemove",
+        PrimitiveReconstructionFlavor::Portable,
+        &DiffOptions {
+            match_unique_names: true,
+            ..Default::default()
+        },
+    )
+    .expect("diff generation");
+    assert!(diffed_type
+        .data
+        .contains("+struct UserStructRename { /* Size=0x10 */\n"));
+    // Sizes are those of the paired types
+    assert_eq!(diffed_type.statistics.size_before, Some(0x18));
+    assert_eq!(diffed_type.statistics.size_after, Some(0x10));
+}
+
+#[test]
+fn test_struct_diffing_ignore_offset_only_changes() {
+    let pdb_file_from = PdbFile::load_from_file(Path::new(TEST_PDB_FROM_FILE_PATH))
+        .expect("load test_diff_from.pdb");
+    let pdb_file_to =
+        PdbFile::load_from_file(Path::new(TEST_PDB_TO_FILE_PATH)).expect("load test_diff_to.pdb");
+
+    let diffed_type = diff_type_by_name(
+        &pdb_file_from,
+        &pdb_file_to,
+        "UserStructAddAndReplace",
+        PrimitiveReconstructionFlavor::Portable,
+        &DiffOptions {
+            ignore_offset_only_changes: true,
+            ..Default::default()
+        },
+    )
+    .expect("diff generation");
+    // Fields that only moved are displayed with their new offset
+    let removed_lines: Vec<&str> = diffed_type
+        .data
+        .lines()
+        .filter(|line| line.starts_with('-'))
+        .collect();
+    assert_eq!(
+        removed_lines,
+        vec!["-struct UserStructAddAndReplace { /* Size=0x10 */"]
+    );
+    for unchanged_line in [
+        "   /* 0x0004 */ int32_t field1;",
+        "   /* 0x000c */ char field2;",
+        "   /* 0x0018 */ void* field3;",
+    ] {
+        assert!(diffed_type.data.lines().any(|line| line == unchanged_line));
+    }
+    assert!(diffed_type
+        .data
+        .lines()
+        .any(|line| line == "+  /* 0x0008 */ int32_t between12;"));
+
+    // Replaced lines come with word-level changes
+    assert_eq!(diffed_type.inline_changes.len(), diffed_type.metadata.len());
+    let (removed_line, inline_changes) = diffed_type
+        .data
+        .lines()
+        .zip(diffed_type.inline_changes.iter())
+        .find(|(line, _)| line.starts_with('-'))
+        .expect("removed line");
+    assert!(!inline_changes.is_empty());
+    for inline_change in inline_changes {
+        assert!(removed_line[inline_change.clone()].contains("0x10"));
+    }
+}
+
+#[test]
+fn test_struct_diffing_inline_changes() {
+    let pdb_file_from = PdbFile::load_from_file(Path::new(TEST_PDB_FROM_FILE_PATH))
+        .expect("load test_diff_from.pdb");
+    let pdb_file_to =
+        PdbFile::load_from_file(Path::new(TEST_PDB_TO_FILE_PATH)).expect("load test_diff_to.pdb");
+
+    for test_case_type_name in TEST_CASES {
+        let diffed_type = diff_type_by_name(
+            &pdb_file_from,
+            &pdb_file_to,
+            test_case_type_name,
+            PrimitiveReconstructionFlavor::Portable,
+            &DiffOptions::default(),
+        )
+        .expect("diff generation");
+
+        // Inline changes must be provided for each line and stay within it
+        assert_eq!(diffed_type.inline_changes.len(), diffed_type.metadata.len());
+        for (line, inline_changes) in diffed_type
+            .data
+            .split_inclusive('\n')
+            .zip(diffed_type.inline_changes.iter())
+        {
+            for inline_change in inline_changes {
+                assert!(inline_change.end <= line.len());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_split_range_by_inline_changes() {
+    assert_eq!(
+        split_range_by_inline_changes(0..10, &[]),
+        vec![(0..10, false)]
+    );
+    assert_eq!(
+        split_range_by_inline_changes(2..10, &[0..3, 5..6, 9..12]),
+        vec![
+            (2..3, true),
+            (3..5, false),
+            (5..6, true),
+            (6..9, false),
+            (9..10, true)
+        ]
+    );
+}
+
+#[test]
+fn test_struct_diffing_statistics() {
+    let pdb_file_from = PdbFile::load_from_file(Path::new(TEST_PDB_FROM_FILE_PATH))
+        .expect("load test_diff_from.pdb");
+    let pdb_file_to =
+        PdbFile::load_from_file(Path::new(TEST_PDB_TO_FILE_PATH)).expect("load test_diff_to.pdb");
+
+    let diffed_type = diff_type_by_name(
+        &pdb_file_from,
+        &pdb_file_to,
+        "UserStructAddAndReplace",
+        PrimitiveReconstructionFlavor::Portable,
+        &DiffOptions::default(),
+    )
+    .expect("diff generation");
+    assert_eq!(
+        diffed_type.statistics,
+        DiffStatistics {
+            lines_added: 8,
+            lines_removed: 4,
+            fields_added: 7,
+            fields_removed: 3,
+            virtual_methods_added: 0,
+            virtual_methods_removed: 0,
+            size_before: Some(0x10),
+            size_after: Some(0x28),
+        }
+    );
+}
+
+#[test]
+fn test_struct_diffing_decimal_number_format() {
+    let mut pdb_file_from = PdbFile::load_from_file(Path::new(TEST_PDB_FROM_FILE_PATH))
+        .expect("load test_diff_from.pdb");
+    let mut pdb_file_to =
+        PdbFile::load_from_file(Path::new(TEST_PDB_TO_FILE_PATH)).expect("load test_diff_to.pdb");
+    let number_format = NumberFormat {
+        base: NumberBase::Decimal,
+        zero_padded: false,
+    };
+    pdb_file_from.settings.number_format = number_format;
+    pdb_file_to.settings.number_format = number_format;
+
+    let diff_type = |ignore_offset_only_changes: bool| {
+        diff_type_by_name(
+            &pdb_file_from,
+            &pdb_file_to,
+            "UserStructAddAndReplace",
+            PrimitiveReconstructionFlavor::Portable,
+            &DiffOptions {
+                ignore_offset_only_changes,
+                ..Default::default()
+            },
+        )
+        .expect("diff generation")
+    };
+
+    // Statistics shouldn't depend on the number format
+    let diffed_type = diff_type(false);
+    assert!(diffed_type
+        .data
+        .lines()
+        .any(|line| line == "+  /* 8 */ int32_t between12;"));
+    assert_eq!(
+        diffed_type.statistics,
+        DiffStatistics {
+            lines_added: 8,
+            lines_removed: 4,
+            fields_added: 7,
+            fields_removed: 3,
+            virtual_methods_added: 0,
+            virtual_methods_removed: 0,
+            size_before: Some(0x10),
+            size_after: Some(0x28),
+        }
+    );
+
+    // Decimal offsets should be masked too
+    let removed_lines: Vec<String> = diff_type(true)
+        .data
+        .lines()
+        .filter(|line| line.starts_with('-'))
+        .map(str::to_string)
+        .collect();
+    assert_eq!(
+        removed_lines,
+        vec!["-struct UserStructAddAndReplace { /* Size=16 */"]
+    );
+}
+
+#[test]
+fn test_struct_diffing_three_way() {
+    let pdb_file_from = PdbFile::load_from_file(Path::new(TEST_PDB_FROM_FILE_PATH))
+        .expect("load test_diff_from.pdb");
+    let pdb_file_to =
+        PdbFile::load_from_file(Path::new(TEST_PDB_TO_FILE_PATH)).expect("load test_diff_to.pdb");
+
+    // Unchanged in both variants
+    let diffed_type = diff_type_by_name_three_way(
+        &pdb_file_from,
+        &pdb_file_from,
+        &pdb_file_from,
+        "UserStructAddAndReplace",
+        PrimitiveReconstructionFlavor::Portable,
+        &DiffOptions::default(),
+    )
+    .expect("diff generation");
+    assert!(diffed_type
+        .metadata
+        .iter()
+        .all(|(_, change)| *change == DiffChange::Equal));
+
+    // Changed in one variant only
+    let diffed_type = diff_type_by_name_three_way(
+        &pdb_file_from,
+        &pdb_file_to,
+        &pdb_file_from,
+        "UserStructAddAndReplace",
+        PrimitiveReconstructionFlavor::Portable,
+        &DiffOptions::default(),
+    )
+    .expect("diff generation");
+    assert!(!diffed_type.data.contains("<<<<<<<"));
+    assert_eq!(diffed_type.statistics.lines_added, 8);
+    assert_eq!(diffed_type.statistics.lines_removed, 4);
+}
+
+#[test]
+fn test_struct_comparison_across_pdbs() {
+    let pdb_file_from = PdbFile::load_from_file(Path::new(TEST_PDB_FROM_FILE_PATH))
+        .expect("load test_diff_from.pdb");
+    let pdb_file_to =
+        PdbFile::load_from_file(Path::new(TEST_PDB_TO_FILE_PATH)).expect("load test_diff_to.pdb");
+
+    let type_revisions = compare_type_across_pdbs(
+        &[&pdb_file_from, &pdb_file_from, &pdb_file_to],
+        "UserStructAddAndReplace",
+        PrimitiveReconstructionFlavor::Portable,
+        false,
         false,
+    )
+    .expect("type comparison");
+    assert_eq!(type_revisions.len(), 3);
+    assert!(type_revisions.iter().all(|revision| revision.present));
+    assert_eq!(
+        type_revisions
+            .iter()
+            .map(|revision| (
+                revision.size,
+                revision.size_changed,
+                revision.layout_changed
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            (Some(0x10), false, false),
+            (Some(0x10), false, false),
+            (Some(0x28), true, true),
+        ]
+    );
+
+    // Types absent from some PDBs are reported as such
+    let type_revisions = compare_type_across_pdbs(
+        &[&pdb_file_from, &pdb_file_to],
+        "NewStruct",
+        PrimitiveReconstructionFlavor::Portable,
         false,
         false,
+    )
+    .expect("type comparison");
+    assert!(!type_revisions[0].present);
+    assert!(type_revisions[1].present);
+    assert!(type_revisions[1].layout_changed);
+}
+
+#[test]
+fn test_text_diffing() {
+    let diff = diff_text(
+        "struct A {\n  int32_t a;\n};\n",
+        "struct A {\n  int32_t a;\n  int32_t b;\n};\n",
         false,
     )
-    .is_err());
+    .expect("diff_text");
+
+    assert_eq!(
+        diff.data,
+        " struct A {\n   int32_t a;\n+  int32_t b;\n };\n"
+    );
+    assert_eq!(diff.statistics.lines_added, 1);
+    assert_eq!(diff.statistics.lines_removed, 0);
+}
+
+#[test]
+fn test_text_diffing_ignore_comments_keeps_layout_comments() {
+    let diff = diff_text(
+        "// Reference PDB file: a.pdb\nstruct B : public A { /* Size=0x10 */\n  /* 0x0000: fields for A */\n  /* 0x0008 */ int32_t b;\n};\n",
+        "// New PDB file: b.pdb\nstruct B : public A { /* Size=0x10 */\n  /* 0x0004: fields for A */\n  /* 0x0008 */ int32_t b;\n};\n",
+        true,
+    )
+    .expect("diff_text");
+
+    // Base class layout changes are reported, header changes aren't
+    assert_eq!(
+        diff.data,
+        " struct B : public A { /* Size=0x10 */\n-  /* 0x0000: fields for A */\n+  /* 0x0004: fields for A */\n   /* 0x0008 */ int32_t b;\n };\n"
+    );
+    assert_eq!(diff.statistics.lines_added, 1);
+    assert_eq!(diff.statistics.lines_removed, 1);
+}
+
+#[test]
+fn test_unified_patch_formatting() {
+    let text_from: String = (0..12).map(|line| format!("{line}\n")).collect();
+    let text_to = text_from.replace("1\n2", "one\n2").replace("10\n", "");
+    let diff = diff_text(&text_from, &text_to, false).expect("diff_text");
+
+    // Distant changes end up in separate hunks
+    assert_eq!(
+        diff.to_unified_patch("a/test.h", "b/test.h"),
+        "--- a/test.h\n+++ b/test.h\n@@ -1,5 +1,5 @@\n 0\n-1\n+one\n 2\n 3\n 4\n@@ -8,5 +8,4 @@\n 7\n 8\n 9\n-10\n 11\n"
+    );
+
+    let diff = diff_text(&text_from, &text_from, false).expect("diff_text");
+    assert!(diff.to_unified_patch("a/test.h", "b/test.h").is_empty());
+}
+
+#[test]
+fn test_side_by_side_diff_formatting() {
+    let diff = diff_text("a\nb\nc\n", "a\nB\nc\nd\n", false).expect("diff_text");
+
+    assert_eq!(diff.to_side_by_side(), "a   a\nb | B\nc   c\n  > d\n");
+}
+
+#[test]
+fn test_all_types_diffing() {
+    let pdb_file_from = PdbFile::load_from_file(Path::new(TEST_PDB_FROM_FILE_PATH))
+        .expect("load test_diff_from.pdb");
+    let pdb_file_to =
+        PdbFile::load_from_file(Path::new(TEST_PDB_TO_FILE_PATH)).expect("load test_diff_to.pdb");
+
+    let changed_types = diff_all_types(
+        &pdb_file_from,
+        &pdb_file_to,
+        None,
+        PrimitiveReconstructionFlavor::Portable,
+        &DiffOptions {
+            ignore_std_types: true,
+            ..Default::default()
+        },
+        &CancellationToken::default(),
+    )
+    .expect("diff all types");
+
+    let changed_type_names: Vec<&str> = changed_types
+        .iter()
+        .map(|changed_type| changed_type.type_name.as_str())
+        .collect();
+    for test_case_type_name in TEST_CASES {
+        assert!(
+            changed_type_names.contains(test_case_type_name),
+            "{test_case_type_name} should have changed"
+        );
+    }
+    // Types are ordered by name
+    assert!(changed_type_names
+        .windows(2)
+        .all(|names| names[0] < names[1]));
+    // Unchanged types aren't reported
+    assert!(changed_types.iter().all(|changed_type| {
+        changed_type.diff.statistics.lines_added > 0
+            || changed_type.diff.statistics.lines_removed > 0
+    }));
+
+    // Only types matching the filter are diffed
+    let type_name_filter = Regex::new("^UserStruct").expect("valid regex");
+    let filtered_changed_types = diff_all_types(
+        &pdb_file_from,
+        &pdb_file_to,
+        Some(&type_name_filter),
+        PrimitiveReconstructionFlavor::Portable,
+        &DiffOptions {
+            ignore_std_types: true,
+            ..Default::default()
+        },
+        &CancellationToken::default(),
+    )
+    .expect("diff all types");
+    let filtered_changed_type_names: Vec<&str> = filtered_changed_types
+        .iter()
+        .map(|changed_type| changed_type.type_name.as_str())
+        .collect();
+    assert_eq!(
+        filtered_changed_type_names,
+        changed_type_names
+            .iter()
+            .copied()
+            .filter(|type_name| type_name.starts_with("UserStruct"))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_changed_type_names() {
+    let pdb_file_from = PdbFile::load_from_file(Path::new(TEST_PDB_FROM_FILE_PATH))
+        .expect("load test_diff_from.pdb");
+    let pdb_file_to =
+        PdbFile::load_from_file(Path::new(TEST_PDB_TO_FILE_PATH)).expect("load test_diff_to.pdb");
+
+    let cancellation_token = CancellationToken::default();
+    let diff_options = DiffOptions::default();
+
+    let changed_types = changed_type_names(
+        &pdb_file_from,
+        &pdb_file_to,
+        &diff_options,
+        &cancellation_token,
+    )
+    .expect("changed type names");
+    for test_case_type_name in TEST_CASES {
+        assert!(changed_types.contains(*test_case_type_name));
+    }
+    // `std` types are skipped if requested
+    let changed_types_without_std = changed_type_names(
+        &pdb_file_from,
+        &pdb_file_to,
+        &DiffOptions {
+            ignore_std_types: true,
+            ..diff_options
+        },
+        &cancellation_token,
+    )
+    .expect("changed type names");
+    assert!(changed_types_without_std
+        .iter()
+        .all(|type_name| !type_name.starts_with("std::")));
+    assert!(changed_types_without_std.is_subset(&changed_types));
+    // A PDB compared with itself has no changed types
+    let changed_types = changed_type_names(
+        &pdb_file_from,
+        &pdb_file_from,
+        &diff_options,
+        &cancellation_token,
+    )
+    .expect("changed type names");
+    assert!(changed_types.is_empty());
+
+    // Computing changed types can be cancelled
+    cancellation_token.cancel();
+    assert!(matches!(
+        changed_type_names(
+            &pdb_file_from,
+            &pdb_file_to,
+            &diff_options,
+            &cancellation_token
+        ),
+        Err(ResymCoreError::OperationCancelledError)
+    ));
 }
@@ -0,0 +1,317 @@
+use std::{borrow::Cow, path::Path};
+
+use resym_core::{
+    cancellation::CancellationToken,
+    pdb_file::{LoadMode, PdbFile, TypeInfo, TypeKind},
+    pdb_types::PrimitiveReconstructionFlavor,
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+
+#[test]
+fn test_type_infos() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let type_info = |type_name: &str| {
+        let (_, type_index) = pdb_file
+            .complete_type_list
+            .iter()
+            .find(|(name, _)| name == type_name)
+            .expect("type should exist");
+        pdb_file.type_infos.get(type_index).copied()
+    };
+
+    assert_eq!(
+        type_info("resym_test::StructTest"),
+        Some(TypeInfo {
+            kind: TypeKind::Struct,
+            size: 0x18
+        })
+    );
+    assert_eq!(
+        type_info("resym_test::UnionTest"),
+        Some(TypeInfo {
+            kind: TypeKind::Union,
+            size: 0x8
+        })
+    );
+    assert_eq!(
+        type_info("resym_test::EnumTest2"),
+        Some(TypeInfo {
+            kind: TypeKind::Enum,
+            size: 0x1
+        })
+    );
+}
+
+#[test]
+fn test_type_properties() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let type_properties = |type_name: &str| {
+        let (_, type_index) = pdb_file
+            .complete_type_list
+            .iter()
+            .find(|(name, _)| name == type_name)
+            .expect("type should exist");
+        pdb_file
+            .type_properties(*type_index)
+            .expect("type properties should be found")
+    };
+
+    let struct_properties = type_properties("resym_test::StructTest");
+    assert_eq!(struct_properties.name, "resym_test::StructTest");
+    assert_eq!(struct_properties.size, Some(0x18));
+    assert!(struct_properties.unique_name.is_some());
+    assert!(!struct_properties.is_forward_reference);
+
+    let enum_properties = type_properties("resym_test::EnumTest2");
+    assert_eq!(enum_properties.size, Some(0x1));
+    assert_eq!(enum_properties.field_count, 3);
+
+    // Forward references have the size of their complete type
+    let (_, struct_index) = pdb_file
+        .complete_type_list
+        .iter()
+        .find(|(name, _)| name == "resym_test::StructTest")
+        .expect("type should exist");
+    let forward_reference_index = pdb_file
+        .forwarder_to_complete_type
+        .iter()
+        .find(|entry| entry.value().0 == *struct_index)
+        .map(|entry| entry.key().0)
+        .expect("forward reference should exist");
+    let forward_reference_properties = pdb_file
+        .type_properties(forward_reference_index)
+        .expect("type properties should be found");
+    assert!(forward_reference_properties.is_forward_reference);
+    assert_eq!(forward_reference_properties.size, Some(0x18));
+
+    assert!(pdb_file.type_properties(0).is_err());
+}
+
+#[test]
+fn test_typedef_listing() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    // Typedefs are declared by `S_UDT` symbols
+    let (_, typedef_index) = pdb_file
+        .typedef_list()
+        .into_iter()
+        .find(|(name, _)| name == "UCHAR")
+        .expect("typedef should exist");
+    assert_eq!(
+        pdb_file.type_infos.get(&typedef_index).copied(),
+        Some(TypeInfo {
+            kind: TypeKind::Typedef,
+            size: 0x1
+        })
+    );
+
+    // Typedefs are reconstructed as aliases
+    let (reconstructed_typedef, _) = pdb_file
+        .reconstruct_type_by_name(
+            "UCHAR",
+            PrimitiveReconstructionFlavor::Portable,
+            false,
+            false,
+            false,
+        )
+        .expect("reconstruction should succeed");
+    assert!(reconstructed_typedef.ends_with("\nusing UCHAR = unsigned char;\n"));
+}
+
+#[test]
+fn test_reduced_memory_mode() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let reduced_memory_pdb_file = PdbFile::load_from_file_with_mode(
+        Path::new(TEST_PDB_FILE_PATH),
+        LoadMode::ReducedMemory,
+        &CancellationToken::default(),
+    )
+    .expect("load test.pdb");
+    assert_eq!(reduced_memory_pdb_file.load_mode(), LoadMode::ReducedMemory);
+
+    // Type names aren't kept in memory, but resolved from the type stream
+    assert!(reduced_memory_pdb_file.complete_type_list.is_empty());
+    assert_eq!(
+        reduced_memory_pdb_file.type_list().expect("type listing"),
+        pdb_file.type_list().expect("type listing")
+    );
+    assert_eq!(reduced_memory_pdb_file.typedefs, pdb_file.typedefs);
+    assert_eq!(
+        reduced_memory_pdb_file.forwarder_to_complete_type.len(),
+        pdb_file.forwarder_to_complete_type.len()
+    );
+    for forwarder in pdb_file.forwarder_to_complete_type.iter() {
+        assert_eq!(
+            reduced_memory_pdb_file
+                .forwarder_to_complete_type
+                .get(forwarder.key())
+                .map(|complete_type| *complete_type),
+            Some(*forwarder.value())
+        );
+    }
+    // The type list is only resolved once
+    assert!(matches!(
+        reduced_memory_pdb_file.type_list().expect("type listing"),
+        Cow::Borrowed(_)
+    ));
+
+    // Reconstructed types (and their xrefs) are identical
+    let reconstruct_type = |pdb_file: &PdbFile<_>| {
+        pdb_file
+            .reconstruct_type_by_name(
+                "resym_test::ClassWithNestedDeclarationsTest",
+                PrimitiveReconstructionFlavor::Portable,
+                true,
+                false,
+                false,
+            )
+            .expect("type reconstruction")
+    };
+    assert_eq!(
+        reconstruct_type(&reduced_memory_pdb_file),
+        reconstruct_type(&pdb_file)
+    );
+}
+
+#[test]
+fn test_load_from_reader() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let pdb_data = std::fs::read(TEST_PDB_FILE_PATH).expect("read test.pdb");
+    let pdb_file_from_reader =
+        PdbFile::load_from_reader("test.pdb", std::io::Cursor::new(pdb_data))
+            .expect("load test.pdb from memory");
+
+    assert_eq!(pdb_file_from_reader.file_path, Path::new("test.pdb"));
+    assert_eq!(
+        pdb_file_from_reader.complete_type_list,
+        pdb_file.complete_type_list
+    );
+    assert_eq!(pdb_file_from_reader.type_infos, pdb_file.type_infos);
+
+    let pdb_data: Box<[u8]> = std::fs::read(TEST_PDB_FILE_PATH)
+        .expect("read test.pdb")
+        .into_boxed_slice();
+    let pdb_file_from_bytes = PdbFile::load_from_bytes("test.pdb".to_string(), pdb_data)
+        .expect("load test.pdb from bytes");
+    assert_eq!(
+        pdb_file_from_bytes.complete_type_list,
+        pdb_file.complete_type_list
+    );
+}
+
+#[test]
+fn test_unnamed_type_names_are_stable() {
+    let unnamed_type_names = |pdb_file_path: &str| {
+        let pdb_file = PdbFile::load_from_file(Path::new(pdb_file_path)).expect("load PDB");
+        pdb_file
+            .complete_type_list
+            .iter()
+            .filter(|(type_name, _)| type_name.starts_with("_unnamed_"))
+            .map(|(type_name, _)| type_name.clone())
+            .collect::<std::collections::BTreeSet<_>>()
+    };
+
+    // Anonymous types are named after their content, not their type index
+    let unnamed_types_from = unnamed_type_names("tests/data/test_diff_from.pdb");
+    assert!(!unnamed_types_from.is_empty());
+    assert!(unnamed_types_from.iter().all(|type_name| {
+        let (hash, ordinal) = type_name["_unnamed_".len()..]
+            .split_once('_')
+            .unwrap_or((&type_name["_unnamed_".len()..], "0"));
+        hash.len() == 16
+            && hash.chars().all(|c| c.is_ascii_hexdigit())
+            && ordinal.parse::<usize>().is_ok()
+    }));
+
+    // Anonymous types which didn't change between two builds keep their name
+    let unnamed_types_to = unnamed_type_names("tests/data/test_diff_to.pdb");
+    assert!(unnamed_types_from
+        .intersection(&unnamed_types_to)
+        .next()
+        .is_some());
+
+    // And can still be reconstructed by name
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let unnamed_type_name = unnamed_type_names(TEST_PDB_FILE_PATH)
+        .into_iter()
+        .next()
+        .expect("test.pdb should contain anonymous types");
+    let (reconstructed_type, _) = pdb_file
+        .reconstruct_type_by_name(
+            &unnamed_type_name,
+            PrimitiveReconstructionFlavor::Portable,
+            false,
+            false,
+            false,
+        )
+        .expect("reconstruction should succeed");
+    assert!(reconstructed_type.contains(&unnamed_type_name));
+}
+
+#[test]
+fn test_identical_unnamed_types_get_distinct_names() {
+    // test.pdb contains distinct anonymous types with identical members
+    // (e.g., `_LARGE_INTEGER`'s anonymous struct and another `<unnamed-tag>`)
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let unnamed_type_names = pdb_file
+        .complete_type_list
+        .iter()
+        .filter(|(type_name, _)| type_name.starts_with("_unnamed_"))
+        .map(|(type_name, _)| type_name.clone())
+        .collect::<Vec<_>>();
+    let unique_type_names = unnamed_type_names
+        .iter()
+        .collect::<std::collections::BTreeSet<_>>();
+    assert_eq!(unique_type_names.len(), unnamed_type_names.len());
+
+    // The first one keeps the plain name, the next ones get an ordinal
+    let duplicate_type_name = unnamed_type_names
+        .iter()
+        .find(|type_name| type_name.ends_with("_1"))
+        .expect("test.pdb should contain identical anonymous types");
+    let first_type_name = duplicate_type_name.strip_suffix("_1").unwrap().to_string();
+    assert!(unique_type_names.contains(&first_type_name));
+
+    for type_name in [&first_type_name, duplicate_type_name] {
+        let (reconstructed_type, _) = pdb_file
+            .reconstruct_type_by_name(
+                type_name,
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                false,
+                false,
+            )
+            .expect("reconstruction should succeed");
+        assert!(reconstructed_type.contains(type_name.as_str()));
+    }
+}
+
+#[test]
+fn test_fuzzy_type_search() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    // Exact names come first, even when typed in lowercase
+    let matches = pdb_file
+        .fuzzy_find_types("structtest", 10)
+        .expect("search should succeed");
+    assert!(matches.len() <= 10);
+    assert_eq!(
+        matches.first().map(|(type_name, _)| type_name.as_str()),
+        Some("resym_test::StructTest")
+    );
+
+    // Characters are matched in order, not necessarily consecutively
+    let matches = pdb_file
+        .fuzzy_find_types("rtstt", 1000)
+        .expect("search should succeed");
+    assert!(matches
+        .iter()
+        .any(|(type_name, _)| type_name == "resym_test::StructTest"));
+
+    assert!(pdb_file
+        .fuzzy_find_types("tsetcurts", 1000)
+        .expect("search should succeed")
+        .iter()
+        .all(|(type_name, _)| type_name != "resym_test::StructTest"));
+}
@@ -0,0 +1,31 @@
+use std::path::Path;
+
+use resym_core::{pdb_file::PdbFile, pdb_types::PrimitiveReconstructionFlavor};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_TYPE_NAME: &str = "resym_test::StructTest";
+
+#[test]
+fn test_type_notes_reconstruction() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    pdb_file.type_notes.insert(
+        TEST_TYPE_NAME.to_string(),
+        "Allocated in Init()\n\nSee */ for details".to_string(),
+    );
+
+    let (reconstructed_type, _) = pdb_file
+        .reconstruct_type_by_name(
+            TEST_TYPE_NAME,
+            PrimitiveReconstructionFlavor::Portable,
+            false,
+            true,
+            false,
+        )
+        .expect("reconstruction should succeed");
+    // Notes should be emitted right above their type's definition, without
+    // terminating the comment block early
+    assert!(reconstructed_type.contains(&format!(
+        "/*\n * Allocated in Init()\n *\n * See * / for details\n */\nstruct {TEST_TYPE_NAME} {{"
+    )));
+}
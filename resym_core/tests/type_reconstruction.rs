@@ -1,6 +1,9 @@
 use std::path::Path;
 
-use resym_core::{pdb_file::PdbFile, pdb_types::PrimitiveReconstructionFlavor};
+use resym_core::{
+    pdb_file::{PDBDataSource, PdbFile},
+    pdb_types::PrimitiveReconstructionFlavor,
+};
 
 const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
 const TEST_CASES: &[&str] = &[
@@ -86,3 +89,291 @@ fn test_type_reconstruction_internal(
         insta::assert_snapshot!(snapshot_name, reconstructed_type);
     }
 }
+
+#[test]
+fn test_multiple_types_reconstruction() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let type_indices: Vec<_> = [
+        "resym_test::InterfaceImplClass",
+        "resym_test::SpecializedInterfaceImplClass",
+    ]
+    .iter()
+    .map(|type_name| {
+        pdb_file
+            .complete_type_list
+            .iter()
+            .find(|(name, _)| name == type_name)
+            .unwrap_or_else(|| panic!("type '{type_name}' not found"))
+            .1
+    })
+    .collect();
+
+    let reconstructed_types = pdb_file
+        .reconstruct_types_by_index(
+            &type_indices,
+            PrimitiveReconstructionFlavor::Portable,
+            true,
+            true,
+            false,
+        )
+        .expect("reconstruction should succeed");
+    assert!(reconstructed_types.contains("class resym_test::InterfaceImplClass "));
+    assert!(reconstructed_types.contains("class resym_test::SpecializedInterfaceImplClass "));
+    // Shared base classes should only be defined once
+    assert_eq!(
+        reconstructed_types
+            .matches("class resym_test::PureVirtualClass {")
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_all_enums_reconstruction() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    let reconstructed_enums = pdb_file
+        .reconstruct_all_enums(PrimitiveReconstructionFlavor::Portable, true)
+        .expect("reconstruction should succeed");
+    assert!(reconstructed_enums
+        .contains("\nenum resym_test::EnumTest1 : int32_t {\n  kEnumTest1Val1 = 0x0000,\n"));
+    // Only enumerations are reconstructed
+    assert!(!reconstructed_enums.contains("\nstruct "));
+    assert!(!reconstructed_enums.contains("\nclass "));
+    // Enumerations are sorted by name
+    let enum_test1_position = reconstructed_enums
+        .find("enum resym_test::EnumTest1 ")
+        .expect("EnumTest1 should be reconstructed");
+    let enum_test2_position = reconstructed_enums
+        .find("enum resym_test::EnumTest2 ")
+        .expect("EnumTest2 should be reconstructed");
+    assert!(enum_test1_position < enum_test2_position);
+}
+
+#[test]
+fn test_dependencies_are_defined_first() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    let (reconstructed_type, _) = pdb_file
+        .reconstruct_type_by_name(
+            "resym_test::SpecializedInterfaceImplClass",
+            PrimitiveReconstructionFlavor::Portable,
+            true,
+            true,
+            false,
+        )
+        .expect("reconstruction should succeed");
+    let definition_position = |type_name: &str| {
+        reconstructed_type
+            .find(&format!("\nclass {type_name} "))
+            .unwrap_or_else(|| panic!("{type_name} should be defined"))
+    };
+    // Base classes must be defined before their derived classes
+    assert!(
+        definition_position("resym_test::PureVirtualClass")
+            < definition_position("resym_test::PureVirtualClassSpecialized")
+    );
+    assert!(
+        definition_position("resym_test::PureVirtualClassSpecialized")
+            < definition_position("resym_test::SpecializedInterfaceImplClass")
+    );
+
+    // The order doesn't change from one reconstruction to the next
+    let (reconstructed_type_again, _) = pdb_file
+        .reconstruct_type_by_name(
+            "resym_test::SpecializedInterfaceImplClass",
+            PrimitiveReconstructionFlavor::Portable,
+            true,
+            true,
+            false,
+        )
+        .expect("reconstruction should succeed");
+    assert_eq!(reconstructed_type, reconstructed_type_again);
+}
+
+#[test]
+fn test_forward_declare_dependencies() {
+    let mut pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    pdb_file.settings.forward_declare_dependencies = true;
+
+    let (reconstructed_type, _) = pdb_file
+        .reconstruct_type_by_name(
+            "resym_test::SpecializedInterfaceImplClass",
+            PrimitiveReconstructionFlavor::Portable,
+            false,
+            true,
+            false,
+        )
+        .expect("reconstruction should succeed");
+    // Referenced types are declared but not defined
+    assert!(reconstructed_type.contains("\nclass resym_test::PureVirtualClassSpecialized;\n"));
+    assert!(!reconstructed_type.contains("\nclass resym_test::PureVirtualClassSpecialized "));
+    assert!(reconstructed_type.contains("\nclass resym_test::SpecializedInterfaceImplClass "));
+}
+
+#[test]
+fn test_virtual_method_specifiers() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let reconstruct_type = |type_name| {
+        pdb_file
+            .reconstruct_type_by_name(
+                type_name,
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                false,
+                false,
+            )
+            .expect("reconstruction should succeed")
+            .0
+    };
+
+    // Methods introducing a virtual function aren't overrides
+    let reconstructed_type = reconstruct_type("resym_test::PureVirtualClass");
+    assert!(reconstructed_type.contains(" InterfaceVirtual()"));
+    assert!(!reconstructed_type.contains("override"));
+    assert!(reconstruct_type("resym_test::InterfaceImplClass")
+        .contains(" virtual int32_t InterfaceVirtual() override;\n"));
+}
+
+#[test]
+fn test_method_calling_conventions() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    for primitives_flavor in [
+        PrimitiveReconstructionFlavor::Portable,
+        PrimitiveReconstructionFlavor::Microsoft,
+    ] {
+        let (reconstructed_type, _) = pdb_file
+            .reconstruct_type_by_name(
+                "resym_test::InterfaceImplClass",
+                primitives_flavor,
+                false,
+                false,
+                false,
+            )
+            .expect("reconstruction should succeed");
+
+        // Note: x64 member functions use the default convention, which is omitted
+        assert!(reconstructed_type.contains(" InterfaceVirtual() override;\n"));
+        assert!(!reconstructed_type.contains("__cdecl"));
+        assert!(!reconstructed_type.contains("__thiscall"));
+    }
+}
+
+/// Set the calling convention of one of `resym_test::InterfaceImplClass`'s
+/// `LF_MFUNCTION` records in test.pdb's raw data, as recorded in x86 PDBs
+fn patch_interface_impl_class_method(
+    pdb_data: &mut [u8],
+    return_type: u32,
+    function_attributes: u8,
+    calling_convention: u8,
+) {
+    const LF_MFUNCTION: u16 = 0x1009;
+    const CLASS_TYPE: u32 = 0x190e;
+    const THIS_TYPE: u32 = 0x1910;
+    const EMPTY_ARGUMENT_LIST: u32 = 0x1002;
+
+    let mut record = Vec::new();
+    record.extend_from_slice(&LF_MFUNCTION.to_le_bytes());
+    record.extend_from_slice(&return_type.to_le_bytes());
+    record.extend_from_slice(&CLASS_TYPE.to_le_bytes());
+    record.extend_from_slice(&THIS_TYPE.to_le_bytes());
+    // Near C, the only convention recorded for x64
+    record.push(0x00);
+    record.push(function_attributes);
+    record.extend_from_slice(&0_u16.to_le_bytes());
+    record.extend_from_slice(&EMPTY_ARGUMENT_LIST.to_le_bytes());
+
+    let offsets = pdb_data
+        .windows(record.len())
+        .enumerate()
+        .filter(|(_, window)| *window == record.as_slice())
+        .map(|(offset, _)| offset)
+        .collect::<Vec<_>>();
+    assert_eq!(offsets.len(), 1, "record should be unique in test.pdb");
+    pdb_data[offsets[0] + 14] = calling_convention;
+}
+
+#[test]
+fn test_method_calling_conventions_are_printed() {
+    let mut pdb_data = std::fs::read(TEST_PDB_FILE_PATH).expect("read test.pdb");
+    // `int InterfaceVirtual()` becomes `__stdcall` (`CV_CALL_NEAR_STD`) and
+    // the default constructor `__thiscall` (`CV_CALL_THISCALL`)
+    patch_interface_impl_class_method(&mut pdb_data, 0x74, 0x00, 0x07);
+    patch_interface_impl_class_method(&mut pdb_data, 0x03, 0x0a, 0x0b);
+    let pdb_file = PdbFile::load_from_bytes_as_vec("test.pdb".to_string(), pdb_data)
+        .expect("load patched test.pdb");
+
+    let reconstruct_type = |primitives_flavor| {
+        pdb_file
+            .reconstruct_type_by_name(
+                "resym_test::InterfaceImplClass",
+                primitives_flavor,
+                false,
+                false,
+                false,
+            )
+            .expect("reconstruction should succeed")
+            .0
+    };
+
+    let reconstructed_type = reconstruct_type(PrimitiveReconstructionFlavor::Microsoft);
+    assert!(reconstructed_type.contains(" virtual LONG __stdcall InterfaceVirtual() override;\n"));
+    assert!(reconstructed_type.contains(" __thiscall InterfaceImplClass();\n"));
+
+    // Other flavors don't print calling conventions
+    let reconstructed_type = reconstruct_type(PrimitiveReconstructionFlavor::Portable);
+    assert!(reconstructed_type.contains(" virtual int32_t InterfaceVirtual() override;\n"));
+    assert!(reconstructed_type.contains(" InterfaceImplClass();\n"));
+    assert!(!reconstructed_type.contains("__stdcall"));
+    assert!(!reconstructed_type.contains("__thiscall"));
+}
+
+#[test]
+fn test_type_reconstruction_stdint_primitives() {
+    assert_eq!(
+        "stdint".parse::<PrimitiveReconstructionFlavor>().ok(),
+        Some(PrimitiveReconstructionFlavor::Stdint)
+    );
+
+    fn reconstruct_type(
+        pdb_file: &PdbFile<PDBDataSource>,
+        primitives_flavor: PrimitiveReconstructionFlavor,
+    ) -> String {
+        pdb_file
+            .reconstruct_type_by_name(
+                "resym_test::PrimitiveTypesTest",
+                primitives_flavor,
+                false,
+                false,
+                false,
+            )
+            .expect("reconstruction should succeed")
+            .0
+    }
+
+    // Plain `char` is kept for text
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let reconstructed_type = reconstruct_type(&pdb_file, PrimitiveReconstructionFlavor::Stdint);
+    assert!(reconstructed_type.contains(" char c1;"));
+    assert!(reconstructed_type.contains(" uint8_t c2;"));
+    assert!(reconstructed_type.contains(" uint32_t i3;"));
+    assert!(reconstructed_type.contains(" int64_t i8;"));
+
+    // `c1` becomes a `signed char` (`T_CHAR` instead of `T_RCHAR`)
+    let mut pdb_data = std::fs::read(TEST_PDB_FILE_PATH).expect("read test.pdb");
+    let c1_member = b"\x0d\x15\x03\x00\x70\x00\x00\x00\x01\x00c1\x00";
+    let offset = pdb_data
+        .windows(c1_member.len())
+        .position(|window| window == c1_member)
+        .expect("c1 should be a member of PrimitiveTypesTest");
+    pdb_data[offset + 4] = 0x10;
+    let pdb_file = PdbFile::load_from_bytes_as_vec("test.pdb".to_string(), pdb_data)
+        .expect("load patched test.pdb");
+    assert!(
+        reconstruct_type(&pdb_file, PrimitiveReconstructionFlavor::Stdint).contains(" int8_t c1;")
+    );
+    assert!(
+        reconstruct_type(&pdb_file, PrimitiveReconstructionFlavor::Portable).contains(" char c1;")
+    );
+}
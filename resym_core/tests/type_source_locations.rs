@@ -0,0 +1,35 @@
+use std::path::Path;
+
+use resym_core::pdb_file::PdbFile;
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_TYPE_NAME: &str = "resym_test::ClassWithRefsAndStaticsTest";
+const TEST_SOURCE_FILE_NAME: &str = "symbol_zoo.cpp";
+
+#[test]
+fn test_type_source_locations() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    let source_locations = pdb_file
+        .type_source_locations(TEST_TYPE_NAME)
+        .unwrap_or_else(|err| panic!("type source location lookup failed: {err}"));
+    assert!(source_locations.iter().any(|source_location| {
+        source_location
+            .file_path
+            .to_lowercase()
+            .ends_with(TEST_SOURCE_FILE_NAME)
+            && source_location.method_count > 0
+    }));
+
+    // Types without methods cannot be located
+    let source_locations = pdb_file
+        .type_source_locations("resym_test::UnknownType")
+        .unwrap_or_else(|err| panic!("type source location lookup failed: {err}"));
+    assert!(source_locations.is_empty());
+
+    // Methods of types nested in the given scope shouldn't be counted
+    let source_locations = pdb_file
+        .type_source_locations("resym_test")
+        .unwrap_or_else(|err| panic!("type source location lookup failed: {err}"));
+    assert!(source_locations.is_empty());
+}
@@ -0,0 +1,69 @@
+use std::path::Path;
+
+use resym_core::pdb_file::{PdbFile, TypeIndex};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+
+fn type_index_by_name<T>(pdb_file: &PdbFile<T>, type_name: &str) -> TypeIndex
+where
+    T: std::io::Seek + std::io::Read + std::fmt::Debug,
+{
+    pdb_file
+        .complete_type_list
+        .iter()
+        .find(|(name, _)| name == type_name)
+        .unwrap_or_else(|| panic!("type '{type_name}' not found"))
+        .1
+}
+
+#[test]
+fn test_type_reverse_dependencies() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let type_index = type_index_by_name(&pdb_file, "resym_test::PureVirtualClassSpecialized");
+
+    let xref_list = pdb_file
+        .get_xrefs_for_type(type_index)
+        .unwrap_or_else(|err| panic!("xref resolution failed: {err}"));
+    let reverse_dependency_list = pdb_file
+        .get_reverse_dependencies_for_type(type_index)
+        .unwrap_or_else(|err| panic!("reverse dependency resolution failed: {err}"));
+
+    // Derived classes depend on their base class
+    assert!(reverse_dependency_list
+        .iter()
+        .any(|(type_name, _)| type_name == "resym_test::SpecializedInterfaceImplClass"));
+    // Direct xrefs are a subset of the reverse dependencies
+    assert!(xref_list
+        .iter()
+        .all(|xref| reverse_dependency_list.contains(xref)));
+    // The type itself isn't listed
+    assert!(reverse_dependency_list
+        .iter()
+        .all(|(_, dependency_index)| *dependency_index != type_index));
+}
+
+#[test]
+fn test_type_dependency_graph() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let type_index = type_index_by_name(&pdb_file, "resym_test::SpecializedInterfaceImplClass");
+    let base_type_index = type_index_by_name(&pdb_file, "resym_test::PureVirtualClassSpecialized");
+
+    let graph = pdb_file
+        .type_dependency_graph(Some(type_index), false)
+        .unwrap_or_else(|err| panic!("dependency graph generation failed: {err}"));
+
+    // Derived classes reference their base class
+    assert!(graph
+        .edges
+        .iter()
+        .any(|(from, to, _)| *from == type_index && *to == base_type_index));
+    // All edges connect nodes of the graph
+    assert!(graph.edges.iter().all(|(from, to, _)| {
+        graph.nodes.iter().any(|(_, index)| index == from)
+            && graph.nodes.iter().any(|(_, index)| index == to)
+    }));
+
+    let dot = graph.to_dot();
+    assert!(dot.starts_with("digraph types {\n"));
+    assert!(dot.contains(&format!("    t{type_index} -> t{base_type_index}")));
+}
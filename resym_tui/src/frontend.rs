@@ -0,0 +1,37 @@
+use crossbeam_channel::Sender;
+use ratatui::crossterm::event::Event;
+use resym_core::{
+    frontend::{FrontendCommand, FrontendController},
+    Result, ResymCoreError,
+};
+
+/// Events processed by the main loop of the terminal UI
+pub enum TuiEvent {
+    /// Terminal input (e.g., key presses or resizes)
+    Input(Event),
+    /// Result sent by the backend
+    Backend(FrontendCommand),
+}
+
+/// Frontend implementation for the terminal UI
+/// This struct enables the backend to communicate with us (the frontend). Its
+/// commands are queued along with terminal input, so that the main loop wakes
+/// up for either.
+pub struct TuiFrontendController {
+    tx_event: Sender<TuiEvent>,
+}
+
+impl FrontendController for TuiFrontendController {
+    /// Used by the backend to send us commands and trigger a UI update
+    fn send_command(&self, command: FrontendCommand) -> Result<()> {
+        self.tx_event
+            .send(TuiEvent::Backend(command))
+            .map_err(|err| ResymCoreError::CrossbeamError(err.to_string()))
+    }
+}
+
+impl TuiFrontendController {
+    pub fn new(tx_event: Sender<TuiEvent>) -> Self {
+        Self { tx_event }
+    }
+}
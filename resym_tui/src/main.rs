@@ -0,0 +1,72 @@
+mod frontend;
+mod tui_app;
+
+use std::{io, panic, path::PathBuf};
+
+use anyhow::Result;
+use ratatui::{
+    backend::CrosstermBackend,
+    crossterm::{
+        execute,
+        terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    },
+    Terminal,
+};
+use structopt::StructOpt;
+
+use crate::tui_app::{TuiApp, PDB_DIFF_TO_SLOT, PDB_MAIN_SLOT};
+
+/// resym-tui is a terminal UI that allows browsing, extracting and diffing
+/// types from PDB files.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "resym-tui")]
+struct ResymTuiOptions {
+    /// Path to the PDB file to open
+    pdb_path: Option<PathBuf>,
+    /// Path to a PDB file to compare the first one with
+    diff_pdb_path: Option<PathBuf>,
+    /// Path to another PDB file to browse alongside the first one (e.g., other
+    /// modules of the same process)
+    #[structopt(short = "a", long = "add", number_of_values = 1)]
+    additional_pdb_paths: Vec<PathBuf>,
+}
+
+fn main() -> Result<()> {
+    let opt = ResymTuiOptions::from_args();
+    let mut app = TuiApp::new()?;
+    if let Some(pdb_path) = opt.pdb_path {
+        app.open_pdb(PDB_MAIN_SLOT, pdb_path)?;
+        if let Some(diff_pdb_path) = opt.diff_pdb_path {
+            app.open_pdb(PDB_DIFF_TO_SLOT, diff_pdb_path)?;
+        }
+    }
+    for pdb_path in opt.additional_pdb_paths {
+        app.add_pdb(pdb_path)?;
+    }
+
+    install_panic_hook();
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+    let result = app.run(&mut terminal);
+
+    // Restore the terminal, even if the application failed
+    restore_terminal()?;
+
+    result
+}
+
+/// Restore the terminal before panic messages are printed, so that they're
+/// readable and the shell remains usable
+fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |panic_info| {
+        let _ = restore_terminal();
+        default_hook(panic_info);
+    }));
+}
+
+fn restore_terminal() -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(io::stdout(), LeaveAlternateScreen)
+}
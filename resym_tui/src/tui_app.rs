@@ -0,0 +1,709 @@
+use std::{collections::BTreeMap, path::PathBuf, sync::Arc, thread};
+
+use anyhow::Result;
+use crossbeam_channel::{Receiver, Sender};
+use ratatui::{
+    backend::Backend as TerminalBackend,
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame, Terminal,
+};
+use resym_core::{
+    backend::{Backend, BackendCommand, PDBSlot, TaggedTypeList},
+    diffing::{Diff, DiffChange, DiffOptions},
+    frontend::FrontendCommand,
+    pdb_types::PrimitiveReconstructionFlavor,
+};
+
+use crate::frontend::{TuiEvent, TuiFrontendController};
+
+/// Slot for the PDB being browsed, or the PDB we're diffing from
+pub const PDB_MAIN_SLOT: PDBSlot = 0;
+/// Slot used for the PDB we're diffing to
+pub const PDB_DIFF_TO_SLOT: PDBSlot = 1;
+/// First slot used for the PDBs browsed alongside the main PDB (e.g., other
+/// modules of the same process)
+const PDB_ADDITIONAL_SLOTS_START: PDBSlot = 2;
+const PRIMITIVE_TYPES_FLAVOR: PrimitiveReconstructionFlavor =
+    PrimitiveReconstructionFlavor::Portable;
+/// Number of lines scrolled at once with Page Up/Page Down
+const PAGE_LENGTH: isize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FocusedPanel {
+    TypeList,
+    ReconstructedType,
+}
+
+/// What keyboard input is used for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InputMode {
+    /// Keys are shortcuts
+    Normal,
+    /// Keys edit the type filter
+    Filter,
+    /// Keys edit the path of the PDB to load in the given slot
+    OpenPdb(PDBSlot),
+}
+
+/// Struct that represents our terminal application.
+/// It contains the whole application's context at all time.
+pub struct TuiApp {
+    backend: Backend,
+    rx_event: Receiver<TuiEvent>,
+    /// Listed types, tagged with the slot of their PDB
+    type_list: TaggedTypeList,
+    type_list_state: ListState,
+    type_filter: String,
+    /// Reconstructed type or diff, ready to be rendered
+    reconstructed_type: Vec<Line<'static>>,
+    reconstructed_type_scroll: u16,
+    focused_panel: FocusedPanel,
+    input_mode: InputMode,
+    /// Path being typed when opening a PDB
+    pdb_path_input: String,
+    /// Indicate if a PDB is loaded in `PDB_DIFF_TO_SLOT`
+    comparing: bool,
+    /// File names of the PDBs opened for browsing (i.e., all but the one in
+    /// `PDB_DIFF_TO_SLOT`)
+    pdb_names: BTreeMap<PDBSlot, String>,
+    /// Slot of the PDB whose types are listed and reconstructed
+    active_slot: PDBSlot,
+    /// List the types of every PDB opened for browsing, instead of the
+    /// active one's
+    search_all_pdbs: bool,
+    status: String,
+    should_quit: bool,
+}
+
+impl TuiApp {
+    pub fn new() -> Result<Self> {
+        // Backend results and terminal input are processed by the same loop
+        let (tx_event, rx_event) = crossbeam_channel::unbounded::<TuiEvent>();
+        let app = Self::with_event_channel(tx_event.clone(), rx_event)?;
+        spawn_input_thread(tx_event);
+
+        Ok(app)
+    }
+
+    /// Create the application without reading terminal input
+    fn with_event_channel(
+        tx_event: Sender<TuiEvent>,
+        rx_event: Receiver<TuiEvent>,
+    ) -> Result<Self> {
+        let frontend_controller = Arc::new(TuiFrontendController::new(tx_event));
+        let backend = Backend::new(frontend_controller)?;
+
+        Ok(Self {
+            backend,
+            rx_event,
+            type_list: vec![],
+            type_list_state: ListState::default(),
+            type_filter: String::default(),
+            reconstructed_type: vec![],
+            reconstructed_type_scroll: 0,
+            focused_panel: FocusedPanel::TypeList,
+            input_mode: InputMode::Normal,
+            pdb_path_input: String::default(),
+            comparing: false,
+            pdb_names: BTreeMap::default(),
+            active_slot: PDB_MAIN_SLOT,
+            search_all_pdbs: false,
+            status: "No PDB loaded (press 'o' to open one)".to_string(),
+            should_quit: false,
+        })
+    }
+
+    /// Load a PDB in the given slot. Loading a PDB in `PDB_DIFF_TO_SLOT`
+    /// switches to comparing types with the main PDB.
+    pub fn open_pdb(&mut self, pdb_slot: PDBSlot, pdb_path: PathBuf) -> Result<()> {
+        self.status = format!("Loading '{}' ...", pdb_path.display());
+        if pdb_slot != PDB_DIFF_TO_SLOT {
+            let pdb_name = pdb_path.file_name().unwrap_or_default().to_string_lossy();
+            self.pdb_names.insert(pdb_slot, pdb_name.into_owned());
+        }
+        self.backend
+            .send_command(BackendCommand::LoadPDBFromPath(pdb_slot, pdb_path))?;
+
+        Ok(())
+    }
+
+    /// Load a PDB in a new slot, to browse it alongside the PDBs already
+    /// opened
+    pub fn add_pdb(&mut self, pdb_path: PathBuf) -> Result<()> {
+        self.open_pdb(self.next_additional_slot(), pdb_path)
+    }
+
+    fn next_additional_slot(&self) -> PDBSlot {
+        self.pdb_names
+            .keys()
+            .last()
+            .map_or(PDB_ADDITIONAL_SLOTS_START, |pdb_slot| {
+                (pdb_slot + 1).max(PDB_ADDITIONAL_SLOTS_START)
+            })
+    }
+
+    /// Main loop, runs until the user quits
+    pub fn run(&mut self, terminal: &mut Terminal<impl TerminalBackend>) -> Result<()> {
+        while !self.should_quit {
+            terminal.draw(|frame| self.draw(frame))?;
+            match self.rx_event.recv()? {
+                TuiEvent::Input(Event::Key(key_event)) if key_event.kind == KeyEventKind::Press => {
+                    self.process_key_event(key_event)?
+                }
+                // Other events (e.g., resizes) only need a redraw
+                TuiEvent::Input(_) => {}
+                TuiEvent::Backend(command) => self.process_backend_command(command)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let main_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .split(frame.size());
+        let panels = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+            .split(main_layout[0]);
+
+        let tag_types = self.search_all_pdbs && !self.comparing;
+        let type_list = List::new(self.type_list.iter().map(|(type_name, pdb_slot, _)| {
+            if tag_types {
+                ListItem::new(format!("{type_name} [{}]", self.pdb_name(*pdb_slot)))
+            } else {
+                ListItem::new(type_name.as_str())
+            }
+        }))
+        .block(self.panel_block(
+            if tag_types {
+                "Types (all PDBs)"
+            } else {
+                "Types"
+            },
+            FocusedPanel::TypeList,
+        ))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(type_list, panels[0], &mut self.type_list_state);
+
+        let view_title = if self.comparing {
+            "Type diff"
+        } else {
+            "Reconstructed type"
+        };
+        let reconstructed_type = Paragraph::new(self.reconstructed_type.clone())
+            .block(self.panel_block(view_title, FocusedPanel::ReconstructedType))
+            .scroll((self.reconstructed_type_scroll, 0));
+        frame.render_widget(reconstructed_type, panels[1]);
+
+        let input_line = match self.input_mode {
+            InputMode::Normal => Line::styled(
+                format!("Filter: {}", self.type_filter),
+                Style::default().add_modifier(Modifier::DIM),
+            ),
+            InputMode::Filter => Line::raw(format!("Filter: {}_", self.type_filter)),
+            InputMode::OpenPdb(PDB_DIFF_TO_SLOT) => {
+                Line::raw(format!("Compare with PDB: {}_", self.pdb_path_input))
+            }
+            InputMode::OpenPdb(PDB_MAIN_SLOT) => {
+                Line::raw(format!("Open PDB: {}_", self.pdb_path_input))
+            }
+            InputMode::OpenPdb(_) => Line::raw(format!("Add PDB: {}_", self.pdb_path_input)),
+        };
+        frame.render_widget(Paragraph::new(input_line), main_layout[1]);
+
+        let status = if self.backend.pending_command_count() > 0 {
+            format!("Working ... | {}", self.status)
+        } else {
+            self.status.clone()
+        };
+        let shortcuts = match self.input_mode {
+            InputMode::Normal => "Enter: reconstruct, /: filter, o: open, a: add PDB, g: search all PDBs, c: compare with, x: stop comparing, Tab: switch panel, q: quit",
+            InputMode::Filter => "Enter/Esc: done",
+            InputMode::OpenPdb(_) => "Enter: open, Esc: cancel",
+        };
+        frame.render_widget(
+            Paragraph::new(format!("{status} | {shortcuts}")),
+            main_layout[2],
+        );
+    }
+
+    fn pdb_name(&self, pdb_slot: PDBSlot) -> &str {
+        self.pdb_names.get(&pdb_slot).map_or("?", String::as_str)
+    }
+
+    fn panel_block(&self, title: &'static str, panel: FocusedPanel) -> Block<'static> {
+        let block = Block::default().borders(Borders::ALL).title(title);
+        if self.focused_panel == panel {
+            block.border_style(Style::default().add_modifier(Modifier::BOLD))
+        } else {
+            block
+        }
+    }
+
+    fn process_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
+        // Raw mode swallows Ctrl+C, handle it ourselves
+        if key_event.modifiers.contains(KeyModifiers::CONTROL)
+            && key_event.code == KeyCode::Char('c')
+        {
+            self.should_quit = true;
+            return Ok(());
+        }
+
+        match self.input_mode {
+            InputMode::Normal => self.process_shortcut(key_event.code),
+            InputMode::Filter => {
+                match key_event.code {
+                    KeyCode::Char(c) => self.type_filter.push(c),
+                    KeyCode::Backspace => {
+                        self.type_filter.pop();
+                    }
+                    KeyCode::Enter | KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        return Ok(());
+                    }
+                    _ => return Ok(()),
+                }
+                // Filter as the user types
+                self.update_type_list()
+            }
+            InputMode::OpenPdb(pdb_slot) => {
+                match key_event.code {
+                    KeyCode::Char(c) => self.pdb_path_input.push(c),
+                    KeyCode::Backspace => {
+                        self.pdb_path_input.pop();
+                    }
+                    KeyCode::Enter => {
+                        self.input_mode = InputMode::Normal;
+                        let pdb_path = PathBuf::from(std::mem::take(&mut self.pdb_path_input));
+                        return self.open_pdb(pdb_slot, pdb_path);
+                    }
+                    KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.pdb_path_input.clear();
+                    }
+                    _ => {}
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn process_shortcut(&mut self, key_code: KeyCode) -> Result<()> {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Char('/') => self.input_mode = InputMode::Filter,
+            KeyCode::Char('o') => self.input_mode = InputMode::OpenPdb(PDB_MAIN_SLOT),
+            KeyCode::Char('a') => self.input_mode = InputMode::OpenPdb(self.next_additional_slot()),
+            KeyCode::Char('c') => self.input_mode = InputMode::OpenPdb(PDB_DIFF_TO_SLOT),
+            KeyCode::Char('g') => {
+                self.search_all_pdbs = !self.search_all_pdbs;
+                self.update_type_list()?;
+            }
+            KeyCode::Char('x') if self.comparing => {
+                self.comparing = false;
+                self.reconstructed_type.clear();
+                self.backend
+                    .send_command(BackendCommand::UnloadPDB(PDB_DIFF_TO_SLOT))?;
+                self.update_type_list()?;
+            }
+            KeyCode::Tab => {
+                self.focused_panel = match self.focused_panel {
+                    FocusedPanel::TypeList => FocusedPanel::ReconstructedType,
+                    FocusedPanel::ReconstructedType => FocusedPanel::TypeList,
+                }
+            }
+            KeyCode::Up => self.scroll_focused_panel(-1),
+            KeyCode::Down => self.scroll_focused_panel(1),
+            KeyCode::PageUp => self.scroll_focused_panel(-PAGE_LENGTH),
+            KeyCode::PageDown => self.scroll_focused_panel(PAGE_LENGTH),
+            KeyCode::Enter => self.reconstruct_selected_type()?,
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn scroll_focused_panel(&mut self, offset: isize) {
+        match self.focused_panel {
+            FocusedPanel::TypeList => {
+                if self.type_list.is_empty() {
+                    return;
+                }
+                let selected_index = self.type_list_state.selected().unwrap_or_default() as isize;
+                let last_index = self.type_list.len() as isize - 1;
+                self.type_list_state
+                    .select(Some((selected_index + offset).clamp(0, last_index) as usize));
+            }
+            FocusedPanel::ReconstructedType => {
+                let last_line = self.reconstructed_type.len().saturating_sub(1);
+                self.reconstructed_type_scroll = (self.reconstructed_type_scroll as isize + offset)
+                    .clamp(0, last_line.min(u16::MAX as usize) as isize)
+                    as u16;
+            }
+        }
+    }
+
+    /// Request the list of types matching the current filter. Types of both
+    /// PDBs are listed when comparing.
+    fn update_type_list(&self) -> Result<()> {
+        let command = if self.comparing {
+            BackendCommand::ListTypesMerged(
+                vec![PDB_MAIN_SLOT, PDB_DIFF_TO_SLOT],
+                self.type_filter.clone(),
+                true,
+                false,
+                false,
+            )
+        } else if self.search_all_pdbs {
+            BackendCommand::ListTypesTagged(
+                self.pdb_names.keys().copied().collect(),
+                self.type_filter.clone(),
+                true,
+                false,
+                false,
+            )
+        } else {
+            BackendCommand::ListTypes(
+                self.active_slot,
+                self.type_filter.clone(),
+                true,
+                false,
+                false,
+            )
+        };
+        self.backend.send_command(command)?;
+
+        Ok(())
+    }
+
+    /// Reconstruct (or diff) the selected type. Selecting a type from another
+    /// PDB than the active one makes it active.
+    fn reconstruct_selected_type(&mut self) -> Result<()> {
+        let Some((type_name, pdb_slot, type_index)) = self
+            .type_list_state
+            .selected()
+            .and_then(|selected_index| self.type_list.get(selected_index))
+        else {
+            return Ok(());
+        };
+
+        if !self.comparing && *pdb_slot != self.active_slot {
+            self.active_slot = *pdb_slot;
+            self.status = format!("Browsing '{}'", self.pdb_name(*pdb_slot));
+        }
+
+        let command = if self.comparing {
+            // Note: merged type lists only contain type names
+            BackendCommand::DiffTypeByName(
+                PDB_MAIN_SLOT,
+                PDB_DIFF_TO_SLOT,
+                type_name.clone(),
+                PRIMITIVE_TYPES_FLAVOR,
+                DiffOptions {
+                    reconstruct_dependencies: true,
+                    print_access_specifiers: true,
+                    ..Default::default()
+                },
+            )
+        } else {
+            BackendCommand::ReconstructTypeByIndex(
+                *pdb_slot,
+                *type_index,
+                PRIMITIVE_TYPES_FLAVOR,
+                false,
+                true,
+                true,
+                false,
+            )
+        };
+        self.backend.send_command(command)?;
+
+        Ok(())
+    }
+
+    fn set_type_list(&mut self, type_list: TaggedTypeList) {
+        self.status = format!("{} types", type_list.len());
+        self.type_list_state
+            .select(if type_list.is_empty() { None } else { Some(0) });
+        self.type_list = type_list;
+    }
+
+    fn process_backend_command(&mut self, command: FrontendCommand) -> Result<()> {
+        match command {
+            FrontendCommand::LoadPDBResult(result) => match result {
+                Err(err) => self.status = format!("Failed to load PDB: {err}"),
+                Ok(pdb_slot) => {
+                    self.status = "PDB loaded".to_string();
+                    if pdb_slot == PDB_DIFF_TO_SLOT {
+                        self.comparing = true;
+                    } else if pdb_slot == PDB_MAIN_SLOT
+                        || !self.pdb_names.contains_key(&self.active_slot)
+                    {
+                        // Additional PDBs are only browsed once one of their
+                        // types is selected, unless no other PDB is opened
+                        self.active_slot = pdb_slot;
+                    }
+                    self.reconstructed_type.clear();
+                    self.update_type_list()?;
+                }
+            },
+
+            FrontendCommand::ListTypesResult(type_list) => {
+                let type_list = type_list
+                    .into_iter()
+                    .map(|(type_name, type_index)| (type_name, self.active_slot, type_index))
+                    .collect();
+                self.set_type_list(type_list);
+            }
+
+            FrontendCommand::ListTypesTaggedResult(type_list) => self.set_type_list(type_list),
+
+            FrontendCommand::SearchFilterError(_, err) => {
+                self.status = format!("Invalid filter: {err}");
+            }
+
+            FrontendCommand::ReconstructTypeResult(result) => match result {
+                Err(err) => self.status = format!("Failed to reconstruct type: {err}"),
+                Ok((reconstructed_type, _)) => {
+                    self.reconstructed_type = reconstructed_type
+                        .lines()
+                        .map(|line| Line::raw(line.to_string()))
+                        .collect();
+                    self.reconstructed_type_scroll = 0;
+                }
+            },
+
+            FrontendCommand::DiffResult(result) => match result {
+                Err(err) => self.status = format!("Failed to diff type: {err}"),
+                Ok(type_diff) => {
+                    self.status = format!(
+                        "{} line(s) added, {} line(s) removed",
+                        type_diff.statistics.lines_added, type_diff.statistics.lines_removed
+                    );
+                    self.reconstructed_type = diff_lines(&type_diff);
+                    self.reconstructed_type_scroll = 0;
+                }
+            },
+
+            // Ignore results of commands we don't send
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+/// Color the lines of a diff (already prefixed with `+`/`-`), depending on
+/// how they changed
+fn diff_lines(type_diff: &Diff) -> Vec<Line<'static>> {
+    type_diff
+        .data
+        .lines()
+        .zip(&type_diff.metadata)
+        .map(|(line, (_, change))| {
+            let style = match change {
+                DiffChange::Insert => Style::default().fg(Color::Green),
+                DiffChange::Delete => Style::default().fg(Color::Red),
+                DiffChange::Equal => Style::default(),
+            };
+            Line::styled(line.to_string(), style)
+        })
+        .collect()
+}
+
+/// Forward terminal input to the main loop, from a dedicated thread
+fn spawn_input_thread(tx_event: Sender<TuiEvent>) {
+    thread::spawn(move || {
+        while let Ok(event) = event::read() {
+            if tx_event.send(TuiEvent::Input(event)).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    const TEST_PDB_FILE_PATH: &str = "../resym_core/tests/data/test.pdb";
+
+    fn test_app() -> TuiApp {
+        let (tx_event, rx_event) = crossbeam_channel::unbounded::<TuiEvent>();
+        TuiApp::with_event_channel(tx_event, rx_event).expect("TuiApp creation failed")
+    }
+
+    fn press_key(app: &mut TuiApp, key_code: KeyCode) {
+        app.process_key_event(KeyEvent::new(key_code, KeyModifiers::NONE))
+            .expect("key processing failed");
+    }
+
+    fn type_text(app: &mut TuiApp, text: &str) {
+        for c in text.chars() {
+            press_key(app, KeyCode::Char(c));
+        }
+    }
+
+    /// Process backend results until one matching `predicate` has been
+    /// processed
+    fn process_backend_commands_until(app: &mut TuiApp, predicate: fn(&FrontendCommand) -> bool) {
+        loop {
+            let event = app
+                .rx_event
+                .recv_timeout(Duration::from_secs(30))
+                .expect("backend result");
+            if let TuiEvent::Backend(command) = event {
+                let found = predicate(&command);
+                app.process_backend_command(command)
+                    .expect("backend result processing failed");
+                if found {
+                    return;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_filter_input() {
+        let mut app = test_app();
+
+        press_key(&mut app, KeyCode::Char('/'));
+        assert_eq!(app.input_mode, InputMode::Filter);
+        // Shortcuts are typed in the filter
+        type_text(&mut app, "Structq");
+        press_key(&mut app, KeyCode::Backspace);
+        assert_eq!(app.type_filter, "Struct");
+        assert!(!app.should_quit);
+
+        press_key(&mut app, KeyCode::Esc);
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.type_filter, "Struct");
+        press_key(&mut app, KeyCode::Char('q'));
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_ctrl_c_quits_in_every_mode() {
+        let mut app = test_app();
+
+        press_key(&mut app, KeyCode::Char('o'));
+        app.process_key_event(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL))
+            .expect("key processing failed");
+        assert!(app.should_quit);
+        assert!(app.pdb_path_input.is_empty());
+    }
+
+    #[test]
+    fn test_open_pdb_input() {
+        let mut app = test_app();
+
+        press_key(&mut app, KeyCode::Char('o'));
+        assert_eq!(app.input_mode, InputMode::OpenPdb(PDB_MAIN_SLOT));
+        type_text(&mut app, "test.pdb");
+        assert_eq!(app.pdb_path_input, "test.pdb");
+        press_key(&mut app, KeyCode::Esc);
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(app.pdb_path_input.is_empty());
+
+        press_key(&mut app, KeyCode::Char('c'));
+        assert_eq!(app.input_mode, InputMode::OpenPdb(PDB_DIFF_TO_SLOT));
+        press_key(&mut app, KeyCode::Esc);
+        press_key(&mut app, KeyCode::Char('a'));
+        assert_eq!(
+            app.input_mode,
+            InputMode::OpenPdb(PDB_ADDITIONAL_SLOTS_START)
+        );
+    }
+
+    #[test]
+    fn test_additional_slots() {
+        let mut app = test_app();
+
+        app.add_pdb(TEST_PDB_FILE_PATH.into())
+            .expect("add_pdb failed");
+        app.add_pdb(TEST_PDB_FILE_PATH.into())
+            .expect("add_pdb failed");
+        assert_eq!(
+            app.pdb_names.keys().copied().collect::<Vec<_>>(),
+            [PDB_ADDITIONAL_SLOTS_START, PDB_ADDITIONAL_SLOTS_START + 1]
+        );
+        assert_eq!(app.next_additional_slot(), PDB_ADDITIONAL_SLOTS_START + 2);
+    }
+
+    #[test]
+    fn test_browse_types() {
+        let mut app = test_app();
+
+        press_key(&mut app, KeyCode::Char('o'));
+        type_text(&mut app, TEST_PDB_FILE_PATH);
+        press_key(&mut app, KeyCode::Enter);
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.pdb_name(PDB_MAIN_SLOT), "test.pdb");
+
+        // Types are listed once the PDB is loaded
+        process_backend_commands_until(&mut app, |command| {
+            matches!(command, FrontendCommand::LoadPDBResult(_))
+        });
+        assert_eq!(app.status, "PDB loaded");
+        process_backend_commands_until(&mut app, |command| {
+            matches!(command, FrontendCommand::ListTypesResult(_))
+        });
+        assert!(app.type_list.len() > 1);
+        assert_eq!(app.type_list_state.selected(), Some(0));
+
+        // The selection stays within the list
+        press_key(&mut app, KeyCode::Up);
+        assert_eq!(app.type_list_state.selected(), Some(0));
+        press_key(&mut app, KeyCode::Down);
+        assert_eq!(app.type_list_state.selected(), Some(1));
+        press_key(&mut app, KeyCode::PageDown);
+        press_key(&mut app, KeyCode::PageDown);
+        assert!(app.type_list_state.selected() < Some(app.type_list.len()));
+
+        press_key(&mut app, KeyCode::Enter);
+        process_backend_commands_until(&mut app, |command| {
+            matches!(command, FrontendCommand::ReconstructTypeResult(_))
+        });
+        assert!(!app.reconstructed_type.is_empty());
+
+        // Scrolling applies to the focused panel
+        let selected_index = app.type_list_state.selected();
+        press_key(&mut app, KeyCode::Tab);
+        assert_eq!(app.focused_panel, FocusedPanel::ReconstructedType);
+        press_key(&mut app, KeyCode::Down);
+        assert_eq!(app.type_list_state.selected(), selected_index);
+        assert_eq!(
+            app.reconstructed_type_scroll,
+            1.min(app.reconstructed_type.len() as u16 - 1)
+        );
+    }
+
+    #[test]
+    fn test_comparing_state() {
+        let mut app = test_app();
+
+        app.process_backend_command(FrontendCommand::LoadPDBResult(Ok(PDB_DIFF_TO_SLOT)))
+            .expect("backend result processing failed");
+        assert!(app.comparing);
+        // The PDB compared with isn't browsed
+        assert!(app.pdb_names.is_empty());
+
+        app.reconstructed_type = vec![Line::raw("struct Test {};")];
+        press_key(&mut app, KeyCode::Char('x'));
+        assert!(!app.comparing);
+        assert!(app.reconstructed_type.is_empty());
+    }
+}
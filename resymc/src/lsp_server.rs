@@ -0,0 +1,386 @@
+//! Lightweight language server, which lets editors display reconstructed types
+//! from a PDB file when hovering type names. Only the small subset of the
+//! Language Server Protocol needed for that is implemented, on top of the
+//! JSON-RPC server.
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    io::{BufRead, Read, Write},
+};
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use crate::rpc_server::{
+    error_response, RpcError, RpcServer, INVALID_REQUEST, JSONRPC_VERSION, METHOD_NOT_FOUND,
+    PARSE_ERROR,
+};
+
+/// Prefix of the custom methods forwarded to the JSON-RPC server as is
+/// (e.g., `resym/reconstruct_type`)
+const RESYM_METHOD_PREFIX: &str = "resym/";
+/// `TextDocumentSyncKind.Full`: clients send whole documents on change
+const TEXT_DOCUMENT_SYNC_FULL: u64 = 1;
+/// Largest message accepted, to avoid allocating whatever size a malformed
+/// `Content-Length` header announces
+const MAX_CONTENT_LENGTH: usize = 64 * 1024 * 1024;
+
+pub struct LspServer<'a> {
+    rpc_server: RpcServer<'a>,
+    /// Content of the documents opened in the editor, by URI
+    documents: RefCell<HashMap<String, String>>,
+}
+
+impl<'a> LspServer<'a> {
+    pub fn new(rpc_server: RpcServer<'a>) -> Self {
+        Self {
+            rpc_server,
+            documents: RefCell::default(),
+        }
+    }
+
+    /// Answer messages read from `reader` until EOF is reached or an `exit`
+    /// notification is received
+    pub fn serve<R: BufRead, W: Write>(&self, mut reader: R, mut writer: W) -> Result<()> {
+        while let Some(message) = read_message(&mut reader)? {
+            let (response, exit) = self.handle_message(&message);
+            if let Some(response) = response {
+                write!(
+                    writer,
+                    "Content-Length: {}\r\n\r\n{response}",
+                    response.len()
+                )?;
+                writer.flush()?;
+            }
+            if exit {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handle a single message. Returns the serialized response (`None` for
+    /// notifications) and whether the server should exit.
+    pub fn handle_message(&self, message: &str) -> (Option<String>, bool) {
+        let message: Value = match serde_json::from_str(message) {
+            Ok(message) => message,
+            Err(err) => {
+                let error = RpcError::new(PARSE_ERROR, err.to_string());
+                return (Some(error_response(Value::Null, error)), false);
+            }
+        };
+
+        let id = message.get("id").cloned();
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            // Responses to server-initiated requests aren't expected
+            let error = RpcError::new(INVALID_REQUEST, "Missing 'method' member");
+            return (id.map(|id| error_response(id, error)), false);
+        };
+        let params = message.get("params").cloned().unwrap_or_else(|| json!({}));
+
+        let result = match method {
+            "initialize" => Ok(json!({
+                "capabilities": {
+                    "textDocumentSync": TEXT_DOCUMENT_SYNC_FULL,
+                    "hoverProvider": true,
+                },
+                "serverInfo": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                },
+            })),
+            "textDocument/didOpen" => {
+                self.update_document(
+                    &params["textDocument"]["uri"],
+                    &params["textDocument"]["text"],
+                );
+                Ok(Value::Null)
+            }
+            "textDocument/didChange" => {
+                // Full synchronization: the last change contains the whole document
+                if let Some(change) = params["contentChanges"].as_array().and_then(|c| c.last()) {
+                    self.update_document(&params["textDocument"]["uri"], &change["text"]);
+                }
+                Ok(Value::Null)
+            }
+            "textDocument/didClose" => {
+                if let Some(uri) = params["textDocument"]["uri"].as_str() {
+                    self.documents.borrow_mut().remove(uri);
+                }
+                Ok(Value::Null)
+            }
+            "textDocument/hover" => Ok(self.hover(&params)),
+            "shutdown" => Ok(Value::Null),
+            "exit" => return (None, true),
+            _ => {
+                if let Some(rpc_method) = method.strip_prefix(RESYM_METHOD_PREFIX) {
+                    self.rpc_server.dispatch(rpc_method, params)
+                } else {
+                    Err(RpcError::new(
+                        METHOD_NOT_FOUND,
+                        format!("Unknown method '{method}'"),
+                    ))
+                }
+            }
+        };
+
+        // Notifications must not be answered
+        let response = id.map(|id| match result {
+            Ok(result) => {
+                json!({"jsonrpc": JSONRPC_VERSION, "id": id, "result": result}).to_string()
+            }
+            Err(error) => error_response(id, error),
+        });
+
+        (response, false)
+    }
+
+    fn update_document(&self, uri: &Value, text: &Value) {
+        if let (Some(uri), Some(text)) = (uri.as_str(), text.as_str()) {
+            self.documents
+                .borrow_mut()
+                .insert(uri.to_string(), text.to_string());
+        }
+    }
+
+    /// Reconstruct the type under the cursor, returns `null` if there's none
+    fn hover(&self, params: &Value) -> Value {
+        let type_name = {
+            let documents = self.documents.borrow();
+            let (Some(text), Some(line), Some(character)) = (
+                params["textDocument"]["uri"]
+                    .as_str()
+                    .and_then(|uri| documents.get(uri)),
+                params["position"]["line"].as_u64(),
+                params["position"]["character"].as_u64(),
+            ) else {
+                return Value::Null;
+            };
+            let Some(type_name) = text
+                .lines()
+                .nth(line as usize)
+                .and_then(|line| identifier_at(line, character as usize))
+            else {
+                return Value::Null;
+            };
+            type_name
+        };
+
+        match self.reconstruct_type(&type_name) {
+            Some(reconstructed_type) => json!({
+                "contents": {
+                    "kind": "markdown",
+                    "value": format!("```cpp\n{reconstructed_type}\n```"),
+                },
+            }),
+            None => Value::Null,
+        }
+    }
+
+    /// Reconstruct a type given its (possibly unqualified) name
+    fn reconstruct_type(&self, type_name: &str) -> Option<String> {
+        let reconstruct = |type_name: &str| {
+            self.rpc_server
+                .dispatch("reconstruct_type", json!({ "name": type_name }))
+                .ok()
+                .and_then(|result| result.as_str().map(str::to_string))
+        };
+
+        reconstruct(type_name).or_else(|| {
+            // Unqualified names (e.g., without namespace) are looked up among
+            // the types whose name ends with them
+            let qualified_suffix = format!("::{type_name}");
+            let type_list = self
+                .rpc_server
+                .dispatch("list_types", json!({ "filter": type_name }))
+                .ok()?;
+            let qualified_type_name = type_list.as_array()?.iter().find_map(|t| {
+                t["name"]
+                    .as_str()
+                    .filter(|name| name.ends_with(&qualified_suffix))
+            })?;
+            reconstruct(qualified_type_name)
+        })
+    }
+}
+
+/// Read a message framed with a `Content-Length` header. Returns `None` on EOF.
+fn read_message<R: BufRead>(reader: &mut R) -> Result<Option<String>> {
+    let mut content_length = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("Content-Length") {
+                content_length = Some(value.trim().parse::<usize>()?);
+            }
+        }
+    }
+
+    let content_length =
+        content_length.ok_or_else(|| anyhow!("Missing 'Content-Length' header"))?;
+    if content_length > MAX_CONTENT_LENGTH {
+        return Err(anyhow!(
+            "Message of {content_length} bytes exceeds the maximum of {MAX_CONTENT_LENGTH} bytes"
+        ));
+    }
+    let mut content = vec![0; content_length];
+    reader.read_exact(&mut content)?;
+
+    Ok(Some(String::from_utf8(content)?))
+}
+
+/// Extract the (possibly qualified) C++ identifier found at the given
+/// character offset in `line`. Offsets are counted in UTF-16 code units, which
+/// is the position encoding clients use by default.
+fn identifier_at(line: &str, utf16_character: usize) -> Option<String> {
+    let is_identifier_char = |c: &char| c.is_alphanumeric() || *c == '_' || *c == ':';
+    let chars: Vec<char> = line.chars().collect();
+    // Index of the character spanning the given UTF-16 offset
+    let character = chars
+        .iter()
+        .scan(0, |utf16_end, c| {
+            *utf16_end += c.len_utf16();
+            Some(*utf16_end)
+        })
+        .position(|utf16_end| utf16_end > utf16_character)?;
+    if !chars.get(character).is_some_and(is_identifier_char) {
+        return None;
+    }
+
+    let start = chars[..character]
+        .iter()
+        .rposition(|c| !is_identifier_char(c))
+        .map_or(0, |i| i + 1);
+    let end = chars[character..]
+        .iter()
+        .position(|c| !is_identifier_char(c))
+        .map_or(chars.len(), |i| character + i);
+    let identifier: String = chars[start..end].iter().collect();
+    let identifier = identifier.trim_matches(':');
+
+    if identifier.is_empty() || identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        None
+    } else {
+        Some(identifier.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use resym_core::{backend::Backend, frontend::FrontendCommand};
+
+    use super::*;
+    use crate::frontend::CLIFrontendController;
+
+    const TEST_PDB_FILE_PATH: &str = "../resym_core/tests/data/test.pdb";
+
+    fn frame(message: Value) -> String {
+        let message = message.to_string();
+        format!("Content-Length: {}\r\n\r\n{message}", message.len())
+    }
+
+    #[test]
+    fn identifier_at_successful() {
+        let line = "    resym_test::StructTest* value;";
+        assert_eq!(
+            identifier_at(line, 4).as_deref(),
+            Some("resym_test::StructTest")
+        );
+        assert_eq!(
+            identifier_at(line, 20).as_deref(),
+            Some("resym_test::StructTest")
+        );
+        assert_eq!(identifier_at(line, 28).as_deref(), Some("value"));
+        assert_eq!(identifier_at(line, 0), None);
+        assert_eq!(identifier_at(line, 100), None);
+    }
+
+    #[test]
+    fn identifier_at_counts_utf16_code_units() {
+        // 'é' is one UTF-16 code unit, '😀' is a surrogate pair
+        let line = "/* é😀 */ StructTest* valué;";
+        assert_eq!(identifier_at(line, 3).as_deref(), Some("é"));
+        assert_eq!(identifier_at(line, 4), None);
+        assert_eq!(identifier_at(line, 5), None);
+        assert_eq!(identifier_at(line, 10).as_deref(), Some("StructTest"));
+        assert_eq!(identifier_at(line, 19).as_deref(), Some("StructTest"));
+        assert_eq!(identifier_at(line, 22).as_deref(), Some("valué"));
+        assert_eq!(identifier_at(line, 26).as_deref(), Some("valué"));
+        assert_eq!(identifier_at(line, 27), None);
+    }
+
+    #[test]
+    fn read_message_rejects_oversized_messages() {
+        let message = format!("Content-Length: {}\r\n\r\n{{}}", MAX_CONTENT_LENGTH + 1);
+        assert!(read_message(&mut message.as_bytes()).is_err());
+        assert_eq!(
+            read_message(&mut frame(json!({})).as_bytes())
+                .unwrap()
+                .as_deref(),
+            Some("{}")
+        );
+    }
+
+    #[test]
+    fn serve_hover_successful() {
+        let (tx_ui, rx_ui) = crossbeam_channel::unbounded::<FrontendCommand>();
+        let frontend_controller = Arc::new(CLIFrontendController::new(tx_ui, rx_ui));
+        let backend = Backend::new(frontend_controller.clone()).expect("Backend creation failed");
+        let rpc_server = RpcServer::new(&backend, &frontend_controller);
+        rpc_server
+            .load_pdb_from_path(0, TEST_PDB_FILE_PATH.into())
+            .expect("Failed to load PDB");
+        let lsp_server = LspServer::new(rpc_server);
+
+        let uri = "file:///test.cpp";
+        let messages = [
+            json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}}),
+            json!({"jsonrpc": "2.0", "method": "textDocument/didOpen", "params": {
+                "textDocument": {"uri": uri, "languageId": "cpp", "version": 1, "text": "StructTest value;"},
+            }}),
+            json!({"jsonrpc": "2.0", "id": 2, "method": "textDocument/hover", "params": {
+                "textDocument": {"uri": uri}, "position": {"line": 0, "character": 3},
+            }}),
+            json!({"jsonrpc": "2.0", "id": 3, "method": "textDocument/hover", "params": {
+                "textDocument": {"uri": uri}, "position": {"line": 0, "character": 10},
+            }}),
+            json!({"jsonrpc": "2.0", "id": 4, "method": "shutdown"}),
+            json!({"jsonrpc": "2.0", "method": "exit"}),
+        ]
+        .map(frame)
+        .join("");
+        let mut output = vec![];
+
+        lsp_server
+            .serve(messages.as_bytes(), &mut output)
+            .expect("serve failed");
+
+        let mut output = output.as_slice();
+        let mut responses = vec![];
+        while let Some(response) = read_message(&mut output).unwrap() {
+            responses.push(serde_json::from_str::<Value>(&response).unwrap());
+        }
+        assert_eq!(responses.len(), 4);
+        assert_eq!(
+            responses[0]["result"]["capabilities"]["hoverProvider"],
+            true
+        );
+        assert!(responses[1]["result"]["contents"]["value"]
+            .as_str()
+            .unwrap()
+            .contains("struct resym_test::StructTest"));
+        // Nothing to show when hovering whitespace
+        assert!(responses[2]["result"].is_null());
+        assert!(responses[3]["result"].is_null());
+    }
+}
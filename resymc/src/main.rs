@@ -1,6 +1,9 @@
 mod frontend;
+mod lsp_server;
+mod progress;
 mod resymc_app;
 mod resymc_options;
+mod rpc_server;
 mod syntax_highlighting;
 
 use anyhow::Result;
@@ -8,17 +11,22 @@ use resym_core::pdb_types::PrimitiveReconstructionFlavor;
 use structopt::StructOpt;
 
 use crate::resymc_app::ResymcApp;
-use crate::resymc_options::ResymcOptions;
+use crate::resymc_options::{ResymcArguments, ResymcOptions};
 
 const DEFAULT_PRIMITIVE_FLAVOR: PrimitiveReconstructionFlavor = PrimitiveReconstructionFlavor::Raw;
 
 fn main() -> Result<()> {
-    env_logger::init();
-    let app = ResymcApp::new()?;
+    let args = ResymcArguments::from_args();
+    env_logger::Builder::new()
+        .filter_level(args.log_level())
+        .parse_default_env()
+        .init();
+    let mut app = ResymcApp::new()?;
+    app.set_show_progress(!args.quiet);
+    app.set_refresh_downloads(args.refresh_downloads)?;
 
     // Process command and options
-    let opt = ResymcOptions::from_args();
-    match opt {
+    match args.command {
         ResymcOptions::List {
             pdb_path,
             type_name_filter,
@@ -26,8 +34,27 @@ fn main() -> Result<()> {
             case_insensitive,
             use_regex,
             ignore_std_types,
-        } => app.list_types_command(
-            pdb_path,
+            reduced_memory,
+        } => {
+            app.set_reduced_memory_mode(reduced_memory)?;
+            app.list_types_command(
+                pdb_path,
+                type_name_filter,
+                case_insensitive,
+                use_regex,
+                ignore_std_types,
+                output_file_path,
+            )
+        }
+        ResymcOptions::Search {
+            type_name_filter,
+            pdb_paths,
+            output_file_path,
+            case_insensitive,
+            use_regex,
+            ignore_std_types,
+        } => app.search_types_command(
+            pdb_paths,
             type_name_filter,
             case_insensitive,
             use_regex,
@@ -39,44 +66,171 @@ fn main() -> Result<()> {
             type_name,
             output_file_path,
             primitive_types_flavor,
+            primitive_type_mapping,
             print_header,
+            header_template,
             print_dependencies,
+            forward_declarations,
             print_access_specifiers,
+            print_static_rvas,
+            doxygen,
+            flag_comments,
+            enum_order,
+            image_base,
+            pe_path,
             ignore_std_types,
+            reduced_memory,
+            opaque_types,
             highlight_syntax,
-        } => app.dump_types_command(
+            watch,
+            watch_diff,
+        } => {
+            app.set_reduced_memory_mode(reduced_memory)?;
+            if let Some(header_template_path) = header_template {
+                app.set_header_template(&header_template_path)?;
+            }
+            let primitive_types_flavor = primitive_types_flavor_for(
+                primitive_types_flavor,
+                primitive_type_mapping.is_some(),
+            );
+            if let Some(primitive_type_mapping_path) = primitive_type_mapping {
+                app.set_primitive_type_mapping(&primitive_type_mapping_path)?;
+            }
+            app.set_print_static_member_rvas(print_static_rvas)?;
+            app.set_doxygen_comments(doxygen)?;
+            app.set_flag_enum_comments(flag_comments)?;
+            app.set_enum_value_order(enum_order)?;
+            app.set_forward_declare_dependencies(forward_declarations)?;
+            app.set_image_base(image_base, pe_path.as_deref())?;
+            if watch {
+                return app.watch_type_command(
+                    pdb_path,
+                    type_name,
+                    primitive_types_flavor,
+                    print_header,
+                    print_dependencies,
+                    print_access_specifiers,
+                    ignore_std_types,
+                    opaque_types,
+                    highlight_syntax,
+                    watch_diff,
+                    output_file_path,
+                );
+            }
+            app.dump_types_command(
+                pdb_path,
+                Some(type_name),
+                primitive_types_flavor,
+                print_header,
+                print_dependencies,
+                print_access_specifiers,
+                ignore_std_types,
+                opaque_types,
+                highlight_syntax,
+                output_file_path,
+            )
+        }
+        ResymcOptions::DumpAll {
             pdb_path,
-            Some(type_name),
-            primitive_types_flavor.unwrap_or(DEFAULT_PRIMITIVE_FLAVOR),
+            output_file_path,
+            primitive_types_flavor,
+            primitive_type_mapping,
             print_header,
-            print_dependencies,
+            header_template,
             print_access_specifiers,
+            print_static_rvas,
+            doxygen,
+            flag_comments,
+            enum_order,
+            image_base,
+            pe_path,
             ignore_std_types,
+            reduced_memory,
             highlight_syntax,
-            output_file_path,
-        ),
-        ResymcOptions::DumpAll {
+        } => {
+            app.set_reduced_memory_mode(reduced_memory)?;
+            if let Some(header_template_path) = header_template {
+                app.set_header_template(&header_template_path)?;
+            }
+            let primitive_types_flavor = primitive_types_flavor_for(
+                primitive_types_flavor,
+                primitive_type_mapping.is_some(),
+            );
+            if let Some(primitive_type_mapping_path) = primitive_type_mapping {
+                app.set_primitive_type_mapping(&primitive_type_mapping_path)?;
+            }
+            app.set_print_static_member_rvas(print_static_rvas)?;
+            app.set_doxygen_comments(doxygen)?;
+            app.set_flag_enum_comments(flag_comments)?;
+            app.set_enum_value_order(enum_order)?;
+            app.set_image_base(image_base, pe_path.as_deref())?;
+            app.dump_types_command(
+                pdb_path,
+                None,
+                primitive_types_flavor,
+                print_header,
+                false,
+                print_access_specifiers,
+                ignore_std_types,
+                vec![],
+                highlight_syntax,
+                output_file_path,
+            )
+        }
+        ResymcOptions::Enums {
             pdb_path,
             output_file_path,
             primitive_types_flavor,
             print_header,
+            flag_comments,
+            enum_order,
+            ignore_std_types,
+            highlight_syntax,
+        } => {
+            app.set_flag_enum_comments(flag_comments)?;
+            app.set_enum_value_order(enum_order)?;
+            app.enums_command(
+                pdb_path,
+                primitive_types_flavor.unwrap_or(DEFAULT_PRIMITIVE_FLAVOR),
+                print_header,
+                ignore_std_types,
+                highlight_syntax,
+                output_file_path,
+            )
+        }
+        ResymcOptions::Diff {
+            from_pdb_path,
+            to_pdb_path,
+            type_name,
+            output_file_path,
+            primitive_types_flavor,
+            print_header,
+            print_dependencies,
             print_access_specifiers,
             ignore_std_types,
+            ignore_comments,
+            match_unique_names,
+            ignore_offset_only_changes,
             highlight_syntax,
-        } => app.dump_types_command(
-            pdb_path,
-            None,
+        } => app.diff_type_command(
+            from_pdb_path,
+            to_pdb_path,
+            type_name,
             primitive_types_flavor.unwrap_or(DEFAULT_PRIMITIVE_FLAVOR),
             print_header,
-            false,
+            print_dependencies,
             print_access_specifiers,
             ignore_std_types,
+            ignore_comments,
+            match_unique_names,
+            ignore_offset_only_changes,
             highlight_syntax,
             output_file_path,
         ),
-        ResymcOptions::Diff {
-            from_pdb_path,
-            to_pdb_path,
+        ResymcOptions::Diff3 {
+            base_pdb_path,
+            a_pdb_path,
+            b_pdb_path,
             type_name,
             output_file_path,
             primitive_types_flavor,
@@ -84,19 +238,37 @@ fn main() -> Result<()> {
             print_dependencies,
             print_access_specifiers,
             ignore_std_types,
+            ignore_comments,
             highlight_syntax,
-        } => app.diff_type_command(
-            from_pdb_path,
-            to_pdb_path,
+        } => app.diff_type_three_way_command(
+            base_pdb_path,
+            a_pdb_path,
+            b_pdb_path,
             type_name,
             primitive_types_flavor.unwrap_or(DEFAULT_PRIMITIVE_FLAVOR),
             print_header,
             print_dependencies,
             print_access_specifiers,
             ignore_std_types,
+            ignore_comments,
             highlight_syntax,
             output_file_path,
         ),
+        ResymcOptions::CompareType {
+            type_name,
+            pdb_paths,
+            output_file_path,
+            primitive_types_flavor,
+            print_access_specifiers,
+            ignore_std_types,
+        } => app.compare_type_command(
+            pdb_paths,
+            type_name,
+            primitive_types_flavor.unwrap_or(DEFAULT_PRIMITIVE_FLAVOR),
+            print_access_specifiers,
+            ignore_std_types,
+            output_file_path,
+        ),
         ResymcOptions::ListModules {
             pdb_path,
             module_path_filter,
@@ -135,6 +307,7 @@ fn main() -> Result<()> {
             primitive_types_flavor,
             print_header,
             print_access_specifiers,
+            ignore_comments,
             highlight_syntax,
         } => app.diff_module_command(
             from_pdb_path,
@@ -143,6 +316,7 @@ fn main() -> Result<()> {
             primitive_types_flavor.unwrap_or(DEFAULT_PRIMITIVE_FLAVOR),
             print_header,
             print_access_specifiers,
+            ignore_comments,
             highlight_syntax,
             output_file_path,
         ),
@@ -153,12 +327,16 @@ fn main() -> Result<()> {
             case_insensitive,
             use_regex,
             ignore_std_types,
+            undecorate_names,
+            symbol_kinds,
         } => app.list_symbols_command(
             pdb_path,
             symbol_name_filter,
             case_insensitive,
             use_regex,
             ignore_std_types,
+            undecorate_names,
+            &symbol_kinds,
             output_file_path,
         ),
         ResymcOptions::DumpSymbol {
@@ -168,29 +346,50 @@ fn main() -> Result<()> {
             primitive_types_flavor,
             print_header,
             print_access_specifiers,
+            image_base,
+            pe_path,
             highlight_syntax,
-        } => app.dump_symbol_command(
+        } => {
+            app.set_image_base(image_base, pe_path.as_deref())?;
+            app.dump_symbol_command(
+                pdb_path,
+                Some(symbol_name),
+                primitive_types_flavor.unwrap_or(DEFAULT_PRIMITIVE_FLAVOR),
+                print_header,
+                print_access_specifiers,
+                highlight_syntax,
+                output_file_path,
+            )
+        }
+        ResymcOptions::DumpAllSymbols {
             pdb_path,
-            Some(symbol_name),
-            primitive_types_flavor.unwrap_or(DEFAULT_PRIMITIVE_FLAVOR),
+            output_file_path,
+            primitive_types_flavor,
             print_header,
             print_access_specifiers,
+            image_base,
+            pe_path,
             highlight_syntax,
-            output_file_path,
-        ),
-        ResymcOptions::DumpAllSymbols {
+        } => {
+            app.set_image_base(image_base, pe_path.as_deref())?;
+            app.dump_symbol_command(
+                pdb_path,
+                None,
+                primitive_types_flavor.unwrap_or(DEFAULT_PRIMITIVE_FLAVOR),
+                print_header,
+                print_access_specifiers,
+                highlight_syntax,
+                output_file_path,
+            )
+        }
+        ResymcOptions::Constants {
             pdb_path,
             output_file_path,
             primitive_types_flavor,
-            print_header,
-            print_access_specifiers,
             highlight_syntax,
-        } => app.dump_symbol_command(
+        } => app.constants_command(
             pdb_path,
-            None,
             primitive_types_flavor.unwrap_or(DEFAULT_PRIMITIVE_FLAVOR),
-            print_header,
-            print_access_specifiers,
             highlight_syntax,
             output_file_path,
         ),
@@ -202,6 +401,7 @@ fn main() -> Result<()> {
             primitive_types_flavor,
             print_header,
             print_access_specifiers,
+            ignore_comments,
             highlight_syntax,
         } => app.diff_symbol_command(
             from_pdb_path,
@@ -210,8 +410,122 @@ fn main() -> Result<()> {
             primitive_types_flavor.unwrap_or(DEFAULT_PRIMITIVE_FLAVOR),
             print_header,
             print_access_specifiers,
+            ignore_comments,
             highlight_syntax,
             output_file_path,
         ),
+        ResymcOptions::DiffPublicSymbols {
+            from_pdb_path,
+            to_pdb_path,
+            output_file_path,
+            primitive_types_flavor,
+            print_header,
+            print_access_specifiers,
+            ignore_comments,
+            highlight_syntax,
+        } => app.diff_public_symbols_command(
+            from_pdb_path,
+            to_pdb_path,
+            primitive_types_flavor.unwrap_or(DEFAULT_PRIMITIVE_FLAVOR),
+            print_header,
+            print_access_specifiers,
+            ignore_comments,
+            highlight_syntax,
+            output_file_path,
+        ),
+        ResymcOptions::DiffDir {
+            from_directory_path,
+            to_directory_path,
+            output_directory_path,
+            primitive_types_flavor,
+            print_access_specifiers,
+            ignore_std_types,
+            ignore_comments,
+        } => app.diff_dir_command(
+            from_directory_path,
+            to_directory_path,
+            output_directory_path,
+            primitive_types_flavor.unwrap_or(DEFAULT_PRIMITIVE_FLAVOR),
+            print_access_specifiers,
+            ignore_std_types,
+            ignore_comments,
+        ),
+        ResymcOptions::DiffAll {
+            from_pdb_path,
+            to_pdb_path,
+            type_name_filter,
+            output_directory_path,
+            primitive_types_flavor,
+            print_access_specifiers,
+            ignore_std_types,
+            ignore_comments,
+            ignore_offset_only_changes,
+        } => app.diff_all_command(
+            from_pdb_path,
+            to_pdb_path,
+            type_name_filter,
+            output_directory_path,
+            primitive_types_flavor.unwrap_or(DEFAULT_PRIMITIVE_FLAVOR),
+            print_access_specifiers,
+            ignore_std_types,
+            ignore_comments,
+            ignore_offset_only_changes,
+        ),
+        ResymcOptions::CheckHeader {
+            pdb_path,
+            header_path,
+            output_file_path,
+        } => app.check_header_command(pdb_path, header_path, output_file_path),
+        ResymcOptions::Undname {
+            decorated_name,
+            output_file_path,
+            print_access_specifiers,
+        } => app.undname_command(decorated_name, print_access_specifiers, output_file_path),
+        ResymcOptions::LookupAddress {
+            pdb_path,
+            address,
+            output_file_path,
+            image_base,
+        } => app.lookup_address_command(pdb_path, address, image_base, output_file_path),
+        ResymcOptions::Addr {
+            pdb_path,
+            symbol_name,
+            pe_path,
+            output_file_path,
+        } => app.addr_command(pdb_path, symbol_name, pe_path, output_file_path),
+        ResymcOptions::DependencyGraph {
+            pdb_path,
+            output_file_path,
+            type_name,
+            ignore_std_types,
+        } => app.dependency_graph_command(pdb_path, type_name, ignore_std_types, output_file_path),
+        ResymcOptions::LayoutAssertions {
+            pdb_path,
+            output_file_path,
+            type_names,
+            ignore_std_types,
+        } => {
+            app.layout_assertions_command(pdb_path, type_names, ignore_std_types, output_file_path)
+        }
+        ResymcOptions::Info {
+            pdb_path,
+            output_file_path,
+        } => app.info_command(pdb_path, output_file_path),
+        ResymcOptions::Serve { pdb_paths, port } => app.serve_command(pdb_paths, port),
+        ResymcOptions::Lsp { pdb_path } => app.lsp_command(pdb_path),
+    }
+}
+
+/// Flavor used when none is given on the command line. Custom primitive type
+/// names only apply to the `Custom` flavor, so it's used when a mapping is
+/// given.
+fn primitive_types_flavor_for(
+    primitive_types_flavor: Option<PrimitiveReconstructionFlavor>,
+    has_primitive_type_mapping: bool,
+) -> PrimitiveReconstructionFlavor {
+    match primitive_types_flavor {
+        Some(primitive_types_flavor) => primitive_types_flavor,
+        None if has_primitive_type_mapping => PrimitiveReconstructionFlavor::Custom,
+        None => DEFAULT_PRIMITIVE_FLAVOR,
     }
 }
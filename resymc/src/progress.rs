@@ -0,0 +1,191 @@
+//! Progress indicators drawn on stderr while long operations are running, so
+//! that loading huge PDB files or diffing all of their types doesn't look like
+//! a hang.
+use std::{
+    io::{IsTerminal, Write},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+
+use crossbeam_channel::{RecvTimeoutError, Sender};
+
+const REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+/// Operations shorter than this don't display anything, to avoid flickering
+const DISPLAY_DELAY: Duration = Duration::from_millis(300);
+const SPINNER_FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+const BAR_WIDTH: usize = 30;
+/// ANSI sequence moving the cursor back to the beginning of the line and
+/// erasing it
+const CLEAR_LINE: &str = "\r\x1b[2K";
+
+/// Set while a progress bar is being drawn. Progress bars started during
+/// another one's operation (e.g., loading a PDB while diffing a directory)
+/// aren't drawn, the outer one already covers them.
+static DRAWING: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ProgressState {
+    message: String,
+    position: usize,
+    /// Number of steps of the operation, `None` if unknown (spinner)
+    length: Option<usize>,
+}
+
+/// Progress bar (or spinner, for operations whose length isn't known) which is
+/// redrawn periodically from a background thread until dropped.
+/// Nothing is drawn when disabled or when stderr isn't a terminal.
+pub struct ProgressBar {
+    state: Arc<Mutex<ProgressState>>,
+    draw_thread: Option<(Sender<()>, JoinHandle<()>)>,
+}
+
+impl ProgressBar {
+    pub fn spinner(enabled: bool, message: impl Into<String>) -> Self {
+        Self::start(enabled, None, message.into())
+    }
+
+    pub fn new(enabled: bool, length: usize, message: impl Into<String>) -> Self {
+        Self::start(enabled, Some(length), message.into())
+    }
+
+    /// Print a line on stdout without mixing it with the progress bar
+    pub fn println(&self, line: &str) {
+        let _state = self.state.lock();
+        if self.draw_thread.is_some() {
+            eprint!("{CLEAR_LINE}");
+        }
+        println!("{line}");
+    }
+
+    /// Move to the next step of the operation
+    pub fn inc(&self, message: impl Into<String>) {
+        if let Ok(mut state) = self.state.lock() {
+            state.position += 1;
+            state.message = message.into();
+        }
+    }
+
+    fn start(enabled: bool, length: Option<usize>, message: String) -> Self {
+        let state = Arc::new(Mutex::new(ProgressState {
+            message,
+            position: 0,
+            length,
+        }));
+        if !enabled
+            || !std::io::stderr().is_terminal()
+            || DRAWING
+                .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+                .is_err()
+        {
+            return Self {
+                state,
+                draw_thread: None,
+            };
+        }
+
+        // Note: the thread stops once the sender is dropped
+        let (tx_stop, rx_stop) = crossbeam_channel::bounded::<()>(0);
+        let draw_thread = std::thread::spawn({
+            let state = state.clone();
+            move || {
+                let start_time = Instant::now();
+                let mut frame = 0;
+                while let Err(RecvTimeoutError::Timeout) = rx_stop.recv_timeout(REFRESH_INTERVAL) {
+                    let elapsed = start_time.elapsed();
+                    if elapsed < DISPLAY_DELAY {
+                        continue;
+                    }
+                    if let Ok(state) = state.lock() {
+                        eprint!("{CLEAR_LINE}{}", render(&state, elapsed, frame));
+                        let _ = std::io::stderr().flush();
+                    }
+                    frame += 1;
+                }
+                eprint!("{CLEAR_LINE}");
+            }
+        });
+
+        Self {
+            state,
+            draw_thread: Some((tx_stop, draw_thread)),
+        }
+    }
+}
+
+impl Drop for ProgressBar {
+    fn drop(&mut self) {
+        if let Some((tx_stop, draw_thread)) = self.draw_thread.take() {
+            drop(tx_stop);
+            let _ = draw_thread.join();
+            DRAWING.store(false, Ordering::Release);
+        }
+    }
+}
+
+fn render(state: &ProgressState, elapsed: Duration, frame: usize) -> String {
+    let elapsed = elapsed.as_secs_f32();
+    match state.length {
+        None => format!(
+            "{} {} ({elapsed:.1}s)",
+            SPINNER_FRAMES[frame % SPINNER_FRAMES.len()],
+            state.message
+        ),
+        Some(length) => {
+            let filled = if length == 0 {
+                BAR_WIDTH
+            } else {
+                BAR_WIDTH * state.position.min(length) / length
+            };
+            format!(
+                "[{}{}] {}/{length} {} ({elapsed:.1}s)",
+                "=".repeat(filled),
+                " ".repeat(BAR_WIDTH - filled),
+                state.position,
+                state.message
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_spinner() {
+        let state = ProgressState {
+            message: "Loading PDB".to_string(),
+            position: 0,
+            length: None,
+        };
+        assert_eq!(
+            render(&state, Duration::from_millis(1500), 11),
+            "⠙ Loading PDB (1.5s)"
+        );
+    }
+
+    #[test]
+    fn render_progress_bar() {
+        let state = ProgressState {
+            message: "a.pdb".to_string(),
+            position: 1,
+            length: Some(3),
+        };
+        assert_eq!(
+            render(&state, Duration::from_secs(2), 0),
+            format!("[{}{}] 1/3 a.pdb (2.0s)", "=".repeat(10), " ".repeat(20))
+        );
+    }
+
+    #[test]
+    fn disabled_progress_bar_draws_nothing() {
+        let progress_bar = ProgressBar::new(false, 2, "a.pdb");
+        progress_bar.inc("b.pdb");
+        assert!(progress_bar.draw_thread.is_none());
+        assert_eq!(progress_bar.state.lock().unwrap().position, 1);
+    }
+}
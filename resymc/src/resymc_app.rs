@@ -1,25 +1,56 @@
-use std::{fs::File, io::Write, path::PathBuf, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs::File,
+    io::{BufReader, Write},
+    net::TcpListener,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
 
 use anyhow::{anyhow, Result};
+use regex::Regex;
 use resym_core::{
-    backend::{Backend, BackendCommand, PDBSlot},
+    backend::{Backend, BackendCommand, PDBSlot, SymbolListOptions},
+    diffing::{diff_text, ChangedType, Diff, DiffChange, DiffOptions},
     frontend::FrontendCommand,
-    pdb_types::PrimitiveReconstructionFlavor,
+    header_template::HeaderTemplate,
+    layout_check::TypeLayoutCheck,
+    pdb_file::{demangle_symbol_name, LoadMode, SymbolKind, SymbolKindFilter},
+    pdb_types::{
+        EnumValueOrder, OpaqueTypeRules, PrimitiveReconstructionFlavor, PrimitiveTypeMapping,
+    },
+    pe_file::PeFile,
     syntax_highlighting::CodeTheme,
 };
 
-use crate::{frontend::CLIFrontendController, syntax_highlighting::highlight_code};
+use crate::{
+    frontend::CLIFrontendController, lsp_server::LspServer, progress::ProgressBar,
+    rpc_server::RpcServer, syntax_highlighting::highlight_code,
+};
 
 /// Slot for the single PDB or for the PDB we're diffing from
 const PDB_MAIN_SLOT: PDBSlot = 0;
 /// Slot used for the PDB we're diffing to
 const PDB_DIFF_TO_SLOT: PDBSlot = 1;
+/// Slot used for the second variant in three-way diffs
+const PDB_DIFF_TO_2_SLOT: PDBSlot = 2;
 
 /// Struct that represents our CLI application.
 /// It contains the whole application's context at all time.
 pub struct ResymcApp {
     frontend_controller: Arc<CLIFrontendController>,
     backend: Backend,
+    /// Draw progress bars on stderr during long operations
+    show_progress: bool,
+}
+
+/// Directory in which PDB files downloaded from URLs are cached.
+/// Note: the cache is kept in a per-user directory, as other users could plant
+/// PDB files in a shared one (e.g., the temporary directory).
+#[cfg(feature = "http")]
+fn url_cache_directory() -> Option<PathBuf> {
+    dirs::cache_dir().map(|cache_directory| cache_directory.join("resym").join("pdb_cache"))
 }
 
 impl ResymcApp {
@@ -28,13 +59,33 @@ impl ResymcApp {
         let (tx_ui, rx_ui) = crossbeam_channel::unbounded::<FrontendCommand>();
         let frontend_controller = Arc::new(CLIFrontendController::new(tx_ui, rx_ui));
         let backend = Backend::new(frontend_controller.clone())?;
+        // Only download PDB files given as URLs once
+        #[cfg(feature = "http")]
+        backend.send_command(BackendCommand::SetURLCacheDirectory(url_cache_directory()))?;
 
         Ok(Self {
             frontend_controller,
             backend,
+            show_progress: false,
         })
     }
 
+    pub fn set_show_progress(&mut self, enabled: bool) {
+        self.show_progress = enabled;
+    }
+
+    /// Download PDB files given as URLs even if they're cached, replacing the
+    /// cached copies
+    pub fn set_refresh_downloads(&self, enabled: bool) -> Result<()> {
+        #[cfg(feature = "http")]
+        self.backend
+            .send_command(BackendCommand::RefreshURLCache(enabled))?;
+        #[cfg(not(feature = "http"))]
+        let _ = enabled;
+
+        Ok(())
+    }
+
     pub fn list_types_command(
         &self,
         pdb_path: PathBuf,
@@ -45,16 +96,7 @@ impl ResymcApp {
         output_file_path: Option<PathBuf>,
     ) -> Result<()> {
         // Request the backend to load the PDB
-        self.backend
-            .send_command(BackendCommand::LoadPDBFromPath(PDB_MAIN_SLOT, pdb_path))?;
-        // Wait for the backend to finish loading the PDB
-        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
-            if let Err(err) = result {
-                return Err(anyhow!("Failed to load PDB: {}", err));
-            }
-        } else {
-            return Err(anyhow!("Invalid response received from the backend?"));
-        }
+        self.load_pdb_from_path(PDB_MAIN_SLOT, pdb_path)?;
 
         // Queue a request for the backend to return the list of types that
         // match the given filter
@@ -66,9 +108,11 @@ impl ResymcApp {
             ignore_std_types,
         ))?;
         // Wait for the backend to finish filtering types
-        if let FrontendCommand::ListTypesResult(type_list) =
-            self.frontend_controller.rx_ui.recv()?
-        {
+        let response = self.frontend_controller.rx_ui.recv()?;
+        if let FrontendCommand::SearchFilterError(_, err) = response {
+            return Err(anyhow!("Invalid search filter: {err}"));
+        }
+        if let FrontendCommand::ListTypesResult(type_list) = response {
             // Dump output
             if let Some(output_file_path) = output_file_path {
                 let mut output_file = File::create(output_file_path)?;
@@ -86,6 +130,166 @@ impl ResymcApp {
         }
     }
 
+    pub fn search_types_command(
+        &self,
+        pdb_paths: Vec<PathBuf>,
+        type_name_filter: String,
+        case_insensitive: bool,
+        use_regex: bool,
+        ignore_std_types: bool,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        // Request the backend to load the PDBs, one slot each
+        let pdb_slots: Vec<PDBSlot> = (0..pdb_paths.len()).collect();
+        let pdb_file_names: Vec<String> = pdb_paths
+            .iter()
+            .map(|pdb_path| {
+                pdb_path
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect();
+        for (pdb_slot, pdb_path) in pdb_slots.iter().zip(pdb_paths) {
+            self.load_pdb_from_path(*pdb_slot, pdb_path)?;
+        }
+
+        // Queue a request for the backend to return the list of types that
+        // match the given filter, in all PDBs
+        self.backend.send_command(BackendCommand::ListTypesTagged(
+            pdb_slots,
+            type_name_filter,
+            case_insensitive,
+            use_regex,
+            ignore_std_types,
+        ))?;
+        // Wait for the backend to finish filtering types
+        let response = self.frontend_controller.rx_ui.recv()?;
+        if let FrontendCommand::SearchFilterError(_, err) = response {
+            return Err(anyhow!("Invalid search filter: {err}"));
+        }
+        if let FrontendCommand::ListTypesTaggedResult(tagged_type_list) = response {
+            let lines = tagged_type_list
+                .into_iter()
+                .map(|(type_name, pdb_slot, _)| {
+                    format!("{}\t{type_name}", pdb_file_names[pdb_slot])
+                });
+
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                for line in lines {
+                    writeln!(output_file, "{line}")?;
+                }
+            } else {
+                for line in lines {
+                    println!("{line}");
+                }
+            }
+            Ok(())
+        } else {
+            Err(anyhow!("Invalid response received from the backend?"))
+        }
+    }
+
+    /// Use the template stored in the given file for headers printed by
+    /// subsequent commands
+    pub fn set_header_template(&self, header_template_path: &Path) -> Result<()> {
+        let header_template = std::fs::read_to_string(header_template_path)?;
+        self.backend
+            .send_command(BackendCommand::SetHeaderTemplate(HeaderTemplate::new(
+                &header_template,
+            )))?;
+
+        Ok(())
+    }
+
+    /// Use the primitive type names stored in the given file for types
+    /// reconstructed with the `Custom` flavor by subsequent commands
+    pub fn set_primitive_type_mapping(&self, primitive_type_mapping_path: &Path) -> Result<()> {
+        let primitive_type_mapping =
+            PrimitiveTypeMapping::parse(&std::fs::read_to_string(primitive_type_mapping_path)?)?;
+        self.backend
+            .send_command(BackendCommand::SetPrimitiveTypeMapping(
+                primitive_type_mapping,
+            ))?;
+
+        Ok(())
+    }
+
+    /// Annotate static data members with their RVA in subsequent commands
+    pub fn set_print_static_member_rvas(&self, enabled: bool) -> Result<()> {
+        self.backend
+            .send_command(BackendCommand::SetPrintStaticMemberRvas(enabled))?;
+
+        Ok(())
+    }
+
+    /// Emit Doxygen comments with the metadata of types and fields in
+    /// subsequent commands
+    pub fn set_doxygen_comments(&self, enabled: bool) -> Result<()> {
+        self.backend
+            .send_command(BackendCommand::SetDoxygenComments(enabled))?;
+
+        Ok(())
+    }
+
+    /// Annotate the enumerators of flag enums with their bits in subsequent
+    /// commands
+    pub fn set_flag_enum_comments(&self, enabled: bool) -> Result<()> {
+        self.backend
+            .send_command(BackendCommand::SetFlagEnumComments(enabled))?;
+
+        Ok(())
+    }
+
+    /// Print enumerators in the given order in subsequent commands
+    pub fn set_enum_value_order(&self, order: EnumValueOrder) -> Result<()> {
+        self.backend
+            .send_command(BackendCommand::SetEnumValueOrder(order))?;
+
+        Ok(())
+    }
+
+    /// Forward declare the types referenced by the types reconstructed
+    /// without their dependencies, in subsequent commands
+    pub fn set_forward_declare_dependencies(&self, enabled: bool) -> Result<()> {
+        self.backend
+            .send_command(BackendCommand::SetForwardDeclareDependencies(enabled))?;
+
+        Ok(())
+    }
+
+    /// Load PDBs without keeping type names in memory, for subsequent commands
+    pub fn set_reduced_memory_mode(&self, enabled: bool) -> Result<()> {
+        let load_mode = if enabled {
+            LoadMode::ReducedMemory
+        } else {
+            LoadMode::Default
+        };
+        self.backend
+            .send_command(BackendCommand::SetLoadMode(load_mode))?;
+
+        Ok(())
+    }
+
+    /// Print virtual addresses next to RVAs, using the given image base or the
+    /// one of the given PE image. The sections and exports of the PE image are
+    /// printed next to symbols as well.
+    pub fn set_image_base(&self, image_base: Option<u64>, pe_path: Option<&Path>) -> Result<()> {
+        let image_base = if let Some(pe_path) = pe_path {
+            self.load_pe_from_path(PDB_MAIN_SLOT, pe_path.to_path_buf())?;
+            Some(PeFile::load_from_file(pe_path)?.image_base)
+        } else {
+            image_base
+        };
+        self.backend
+            .send_command(BackendCommand::SetImageBase(image_base))?;
+
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn dump_types_command(
         &self,
@@ -96,22 +300,25 @@ impl ResymcApp {
         print_dependencies: bool,
         print_access_specifiers: bool,
         ignore_std_types: bool,
+        opaque_types: Vec<String>,
         highlight_syntax: bool,
         output_file_path: Option<PathBuf>,
     ) -> Result<()> {
-        // Request the backend to load the PDB
-        self.backend
-            .send_command(BackendCommand::LoadPDBFromPath(PDB_MAIN_SLOT, pdb_path))?;
-        // Wait for the backend to finish loading the PDB
-        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
-            if let Err(err) = result {
-                return Err(anyhow!("Failed to load PDB: {}", err));
-            }
-        } else {
-            return Err(anyhow!("Invalid response received from the backend?"));
+        // Request the backend to treat the given types as opaque
+        if !opaque_types.is_empty() {
+            let opaque_type_rules = OpaqueTypeRules::parse(&opaque_types.join("\n"))?;
+            self.backend
+                .send_command(BackendCommand::SetOpaqueTypeRules(opaque_type_rules))?;
         }
 
+        // Request the backend to load the PDB
+        self.load_pdb_from_path(PDB_MAIN_SLOT, pdb_path)?;
+
         // Queue a request for the backend to reconstruct the given type
+        let progress_bar = ProgressBar::spinner(
+            self.show_progress && type_name.is_none(),
+            "Reconstructing all types",
+        );
         if let Some(type_name) = type_name {
             self.backend
                 .send_command(BackendCommand::ReconstructTypeByName(
@@ -134,23 +341,16 @@ impl ResymcApp {
                 ))?;
         }
         // Wait for the backend to finish filtering types
-        if let FrontendCommand::ReconstructTypeResult(reconstructed_type_result) =
-            self.frontend_controller.rx_ui.recv()?
-        {
+        let response = self.frontend_controller.rx_ui.recv()?;
+        drop(progress_bar);
+        if let FrontendCommand::ReconstructTypeResult(reconstructed_type_result) = response {
             let (reconstructed_type, _) = reconstructed_type_result?;
             // Dump output
             if let Some(output_file_path) = output_file_path {
                 let mut output_file = File::create(output_file_path)?;
                 output_file.write_all(reconstructed_type.as_bytes())?;
-            } else if highlight_syntax {
-                let theme = CodeTheme::default();
-                if let Some(colorized_reconstructed_type) =
-                    highlight_code(&theme, &reconstructed_type, None)
-                {
-                    println!("{colorized_reconstructed_type}");
-                }
             } else {
-                println!("{reconstructed_type}");
+                print_reconstructed_type(&reconstructed_type, highlight_syntax);
             }
 
             Ok(())
@@ -159,95 +359,38 @@ impl ResymcApp {
         }
     }
 
-    #[allow(clippy::too_many_arguments)]
-    pub fn diff_type_command(
+    pub fn enums_command(
         &self,
-        from_pdb_path: PathBuf,
-        to_pdb_path: PathBuf,
-        type_name: String,
+        pdb_path: PathBuf,
         primitive_types_flavor: PrimitiveReconstructionFlavor,
         print_header: bool,
-        print_dependencies: bool,
-        print_access_specifiers: bool,
         ignore_std_types: bool,
         highlight_syntax: bool,
         output_file_path: Option<PathBuf>,
     ) -> Result<()> {
-        // Request the backend to load the first PDB
-        self.backend.send_command(BackendCommand::LoadPDBFromPath(
-            PDB_MAIN_SLOT,
-            from_pdb_path.clone(),
-        ))?;
-        // Wait for the backend to finish loading the PDB
-        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
-            if let Err(err) = result {
-                return Err(anyhow!(
-                    "Failed to load PDB '{}': {}",
-                    from_pdb_path.display(),
-                    err
-                ));
-            }
-        } else {
-            return Err(anyhow!("Invalid response received from the backend?"));
-        }
-
-        // Request the backend to load the second PDB
-        self.backend.send_command(BackendCommand::LoadPDBFromPath(
-            PDB_DIFF_TO_SLOT,
-            to_pdb_path.clone(),
-        ))?;
-        // Wait for the backend to finish loading the PDB
-        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
-            if let Err(err) = result {
-                return Err(anyhow!(
-                    "Failed to load PDB '{}': {}",
-                    to_pdb_path.display(),
-                    err
-                ));
-            }
-        } else {
-            return Err(anyhow!("Invalid response received from the backend?"));
-        }
+        // Request the backend to load the PDB
+        self.load_pdb_from_path(PDB_MAIN_SLOT, pdb_path)?;
 
-        // Queue a request for the backend to diff the given type
-        self.backend.send_command(BackendCommand::DiffTypeByName(
-            PDB_MAIN_SLOT,
-            PDB_DIFF_TO_SLOT,
-            type_name,
-            primitive_types_flavor,
-            print_header,
-            print_dependencies,
-            print_access_specifiers,
-            ignore_std_types,
-        ))?;
+        // Queue a request for the backend to reconstruct all enumerations
+        self.backend
+            .send_command(BackendCommand::ReconstructAllEnums(
+                PDB_MAIN_SLOT,
+                primitive_types_flavor,
+                print_header,
+                ignore_std_types,
+            ))?;
         // Wait for the backend to finish
-        if let FrontendCommand::DiffResult(reconstructed_type_diff_result) =
+        if let FrontendCommand::ReconstructAllEnumsResult(reconstructed_enums_result) =
             self.frontend_controller.rx_ui.recv()?
         {
-            let reconstructed_type_diff = reconstructed_type_diff_result?;
+            let reconstructed_enums = reconstructed_enums_result?;
+
             // Dump output
             if let Some(output_file_path) = output_file_path {
                 let mut output_file = File::create(output_file_path)?;
-                output_file.write_all(reconstructed_type_diff.data.as_bytes())?;
-            } else if highlight_syntax {
-                let theme = CodeTheme::default();
-                let line_descriptions =
-                    reconstructed_type_diff
-                        .metadata
-                        .iter()
-                        .fold(vec![], |mut acc, e| {
-                            acc.push(e.1);
-                            acc
-                        });
-                if let Some(colorized_reconstructed_type) = highlight_code(
-                    &theme,
-                    &reconstructed_type_diff.data,
-                    Some(line_descriptions),
-                ) {
-                    println!("{colorized_reconstructed_type}");
-                }
+                output_file.write_all(reconstructed_enums.as_bytes())?;
             } else {
-                println!("{}", reconstructed_type_diff.data);
+                print_reconstructed_type(&reconstructed_enums, highlight_syntax);
             }
 
             Ok(())
@@ -256,160 +399,449 @@ impl ResymcApp {
         }
     }
 
-    pub fn list_modules_command(
+    /// Reconstruct the given type each time its PDB file changes, until the
+    /// process is interrupted
+    #[allow(clippy::too_many_arguments)]
+    pub fn watch_type_command(
         &self,
         pdb_path: PathBuf,
-        module_path_filter: String,
-        case_insensitive: bool,
-        use_regex: bool,
+        type_name: String,
+        primitive_types_flavor: PrimitiveReconstructionFlavor,
+        print_header: bool,
+        print_dependencies: bool,
+        print_access_specifiers: bool,
+        ignore_std_types: bool,
+        opaque_types: Vec<String>,
+        highlight_syntax: bool,
+        print_diff: bool,
         output_file_path: Option<PathBuf>,
     ) -> Result<()> {
-        // Request the backend to load the PDB
-        self.backend
-            .send_command(BackendCommand::LoadPDBFromPath(PDB_MAIN_SLOT, pdb_path))?;
-        // Wait for the backend to finish loading the PDB
-        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
-            if let Err(err) = result {
-                return Err(anyhow!("Failed to load PDB: {}", err));
-            }
-        } else {
-            return Err(anyhow!("Invalid response received from the backend?"));
+        // Request the backend to treat the given types as opaque
+        if !opaque_types.is_empty() {
+            let opaque_type_rules = OpaqueTypeRules::parse(&opaque_types.join("\n"))?;
+            self.backend
+                .send_command(BackendCommand::SetOpaqueTypeRules(opaque_type_rules))?;
         }
+        // Request the backend to notify us when the PDB changes
+        self.backend
+            .send_command(BackendCommand::SetWatchPDBFiles(true))?;
 
-        // Queue a request for the backend to return the list of all modules
-        self.backend.send_command(BackendCommand::ListModules(
-            PDB_MAIN_SLOT,
-            module_path_filter,
-            case_insensitive,
-            use_regex,
-        ))?;
-        // Wait for the backend to finish listing modules
-        if let FrontendCommand::ListModulesResult(module_list_result) =
-            self.frontend_controller.rx_ui.recv()?
-        {
-            // Dump output
-            let module_list = module_list_result?;
-            if let Some(output_file_path) = output_file_path {
-                let mut output_file = File::create(output_file_path)?;
-                for (module_path, module_id) in module_list {
-                    writeln!(output_file, "Mod {module_id:04} | '{module_path}'")?;
+        let mut previous_output: Option<String> = None;
+        loop {
+            match self.reload_and_reconstruct_type(
+                &pdb_path,
+                &type_name,
+                primitive_types_flavor,
+                print_header,
+                print_dependencies,
+                print_access_specifiers,
+                ignore_std_types,
+            ) {
+                // Note: the PDB might be rewritten while we're loading it, so
+                // errors are reported without leaving, unless the PDB has never
+                // been loaded (in which case it isn't watched)
+                Err(err) if previous_output.is_none() => return Err(err),
+                Err(err) => eprintln!("{err}"),
+                Ok(reconstructed_type) if previous_output.as_ref() == Some(&reconstructed_type) => {
+                    eprintln!("'{}' changed, but '{type_name}' didn't", pdb_path.display());
                 }
-            } else {
-                for (module_path, module_id) in module_list {
-                    println!("Mod {module_id:04} | '{module_path}'");
+                Ok(reconstructed_type) => {
+                    if let Some(output_file_path) = &output_file_path {
+                        let mut output_file = File::create(output_file_path)?;
+                        output_file.write_all(reconstructed_type.as_bytes())?;
+                        eprintln!("'{}' has been updated", output_file_path.display());
+                    } else {
+                        match &previous_output {
+                            Some(previous_output) if print_diff => {
+                                let diff = diff_text(previous_output, &reconstructed_type, false)?;
+                                print_reconstructed_type_diff(&diff, highlight_syntax);
+                            }
+                            _ => print_reconstructed_type(&reconstructed_type, highlight_syntax),
+                        }
+                    }
+                    previous_output = Some(reconstructed_type);
                 }
             }
 
-            Ok(())
-        } else {
-            Err(anyhow!("Invalid response received from the backend?"))
+            self.wait_for_pdb_change()?;
+            eprintln!("'{}' changed, reloading ...", pdb_path.display());
         }
     }
 
     #[allow(clippy::too_many_arguments)]
-    pub fn dump_module_command(
+    fn reload_and_reconstruct_type(
         &self,
-        pdb_path: PathBuf,
-        module_id: usize,
+        pdb_path: &Path,
+        type_name: &str,
         primitive_types_flavor: PrimitiveReconstructionFlavor,
         print_header: bool,
+        print_dependencies: bool,
         print_access_specifiers: bool,
-        highlight_syntax: bool,
-        output_file_path: Option<PathBuf>,
-    ) -> Result<()> {
-        // Request the backend to load the PDB
-        self.backend
-            .send_command(BackendCommand::LoadPDBFromPath(PDB_MAIN_SLOT, pdb_path))?;
-        // Wait for the backend to finish loading the PDB
-        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
-            if let Err(err) = result {
-                return Err(anyhow!("Failed to load PDB: {}", err));
-            }
+        ignore_std_types: bool,
+    ) -> Result<String> {
+        self.backend.send_command(BackendCommand::LoadPDBFromPath(
+            PDB_MAIN_SLOT,
+            pdb_path.to_path_buf(),
+        ))?;
+        if let FrontendCommand::LoadPDBResult(result) = self.recv_ignoring_pdb_changes()? {
+            result
+                .map_err(|err| anyhow!("Failed to load PDB '{}': {}", pdb_path.display(), err))?;
         } else {
             return Err(anyhow!("Invalid response received from the backend?"));
         }
 
-        // Queue a request for the backend to reconstruct the given module
         self.backend
-            .send_command(BackendCommand::ReconstructModuleByIndex(
+            .send_command(BackendCommand::ReconstructTypeByName(
                 PDB_MAIN_SLOT,
-                module_id,
+                type_name.to_string(),
                 primitive_types_flavor,
                 print_header,
+                print_dependencies,
                 print_access_specifiers,
+                ignore_std_types,
             ))?;
-        // Wait for the backend to finish filtering types
-        if let FrontendCommand::ReconstructModuleResult(reconstructed_module) =
-            self.frontend_controller.rx_ui.recv()?
+        if let FrontendCommand::ReconstructTypeResult(reconstructed_type_result) =
+            self.recv_ignoring_pdb_changes()?
         {
-            let reconstructed_module = reconstructed_module?;
-            // Dump output
-            if let Some(output_file_path) = output_file_path {
-                let mut output_file = File::create(output_file_path)?;
-                output_file.write_all(reconstructed_module.as_bytes())?;
-            } else if highlight_syntax {
-                let theme = CodeTheme::default();
-                if let Some(colorized_reconstructed_type) =
-                    highlight_code(&theme, &reconstructed_module, None)
-                {
-                    println!("{colorized_reconstructed_type}");
-                }
-            } else {
-                println!("{reconstructed_module}");
-            }
-            Ok(())
+            let (reconstructed_type, _) = reconstructed_type_result?;
+            Ok(reconstructed_type)
         } else {
             Err(anyhow!("Invalid response received from the backend?"))
         }
     }
 
-    #[allow(clippy::too_many_arguments)]
-    pub fn diff_module_command(
-        &self,
-        from_pdb_path: PathBuf,
-        to_pdb_path: PathBuf,
-        module_path: String,
-        primitive_types_flavor: PrimitiveReconstructionFlavor,
-        print_header: bool,
+    /// Receive the next command from the backend, skipping notifications of
+    /// changes to watched PDB files
+    fn recv_ignoring_pdb_changes(&self) -> Result<FrontendCommand> {
+        loop {
+            match self.frontend_controller.rx_ui.recv()? {
+                FrontendCommand::PDBFileChanged(..) => {}
+                command => return Ok(command),
+            }
+        }
+    }
+
+    /// Block until a watched PDB file changes, and until it's been left alone
+    /// for a little while (files tend to be written several times in a row)
+    fn wait_for_pdb_change(&self) -> Result<()> {
+        const SETTLE_DURATION: Duration = Duration::from_millis(500);
+
+        while !matches!(
+            self.frontend_controller.rx_ui.recv()?,
+            FrontendCommand::PDBFileChanged(..)
+        ) {}
+        while let Ok(command) = self.frontend_controller.rx_ui.recv_timeout(SETTLE_DURATION) {
+            if !matches!(command, FrontendCommand::PDBFileChanged(..)) {
+                return Err(anyhow!("Invalid response received from the backend?"));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn diff_type_command(
+        &self,
+        from_pdb_path: PathBuf,
+        to_pdb_path: PathBuf,
+        type_name: String,
+        primitive_types_flavor: PrimitiveReconstructionFlavor,
+        print_header: bool,
+        print_dependencies: bool,
         print_access_specifiers: bool,
+        ignore_std_types: bool,
+        ignore_comments: bool,
+        match_unique_names: bool,
+        ignore_offset_only_changes: bool,
         highlight_syntax: bool,
         output_file_path: Option<PathBuf>,
     ) -> Result<()> {
         // Request the backend to load the first PDB
-        self.backend.send_command(BackendCommand::LoadPDBFromPath(
+        self.load_pdb_from_path(PDB_MAIN_SLOT, from_pdb_path)?;
+
+        // Request the backend to load the second PDB
+        self.load_pdb_from_path(PDB_DIFF_TO_SLOT, to_pdb_path)?;
+
+        // Queue a request for the backend to diff the given type
+        self.backend.send_command(BackendCommand::DiffTypeByName(
             PDB_MAIN_SLOT,
-            from_pdb_path.clone(),
+            PDB_DIFF_TO_SLOT,
+            type_name,
+            primitive_types_flavor,
+            DiffOptions {
+                print_header,
+                reconstruct_dependencies: print_dependencies,
+                print_access_specifiers,
+                ignore_std_types,
+                ignore_comments,
+                match_unique_names,
+                ignore_offset_only_changes,
+            },
         ))?;
-        // Wait for the backend to finish loading the PDB
-        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
-            if let Err(err) = result {
-                return Err(anyhow!(
-                    "Failed to load PDB '{}': {}",
-                    from_pdb_path.display(),
-                    err
-                ));
+        // Wait for the backend to finish
+        if let FrontendCommand::DiffResult(reconstructed_type_diff_result) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let reconstructed_type_diff = reconstructed_type_diff_result?;
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                output_file.write_all(reconstructed_type_diff.data.as_bytes())?;
+            } else {
+                print_reconstructed_type_diff(&reconstructed_type_diff, highlight_syntax);
             }
+
+            Ok(())
         } else {
-            return Err(anyhow!("Invalid response received from the backend?"));
+            Err(anyhow!("Invalid response received from the backend?"))
         }
+    }
 
-        // Request the backend to load the second PDB
-        self.backend.send_command(BackendCommand::LoadPDBFromPath(
-            PDB_DIFF_TO_SLOT,
-            to_pdb_path.clone(),
+    #[allow(clippy::too_many_arguments)]
+    pub fn diff_type_three_way_command(
+        &self,
+        base_pdb_path: PathBuf,
+        a_pdb_path: PathBuf,
+        b_pdb_path: PathBuf,
+        type_name: String,
+        primitive_types_flavor: PrimitiveReconstructionFlavor,
+        print_header: bool,
+        print_dependencies: bool,
+        print_access_specifiers: bool,
+        ignore_std_types: bool,
+        ignore_comments: bool,
+        highlight_syntax: bool,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        // Request the backend to load the three PDBs
+        self.load_pdb_from_path(PDB_MAIN_SLOT, base_pdb_path)?;
+        self.load_pdb_from_path(PDB_DIFF_TO_SLOT, a_pdb_path)?;
+        self.load_pdb_from_path(PDB_DIFF_TO_2_SLOT, b_pdb_path)?;
+
+        // Queue a request for the backend to diff the given type
+        self.backend
+            .send_command(BackendCommand::DiffTypeByNameThreeWay(
+                PDB_MAIN_SLOT,
+                PDB_DIFF_TO_SLOT,
+                PDB_DIFF_TO_2_SLOT,
+                type_name,
+                primitive_types_flavor,
+                DiffOptions {
+                    print_header,
+                    reconstruct_dependencies: print_dependencies,
+                    print_access_specifiers,
+                    ignore_std_types,
+                    ignore_comments,
+                    ..Default::default()
+                },
+            ))?;
+        // Wait for the backend to finish
+        if let FrontendCommand::DiffResult(reconstructed_type_diff_result) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let reconstructed_type_diff = reconstructed_type_diff_result?;
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                output_file.write_all(reconstructed_type_diff.data.as_bytes())?;
+            } else {
+                print_reconstructed_type_diff(&reconstructed_type_diff, highlight_syntax);
+            }
+
+            Ok(())
+        } else {
+            Err(anyhow!("Invalid response received from the backend?"))
+        }
+    }
+
+    pub fn compare_type_command(
+        &self,
+        pdb_paths: Vec<PathBuf>,
+        type_name: String,
+        primitive_types_flavor: PrimitiveReconstructionFlavor,
+        print_access_specifiers: bool,
+        ignore_std_types: bool,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        // Request the backend to load the PDBs, one slot each
+        let pdb_slots: Vec<PDBSlot> = (0..pdb_paths.len()).collect();
+        for (pdb_slot, pdb_path) in pdb_slots.iter().zip(pdb_paths) {
+            self.load_pdb_from_path(*pdb_slot, pdb_path)?;
+        }
+
+        // Queue a request for the backend to compare the given type
+        self.backend
+            .send_command(BackendCommand::CompareTypeAcrossPDBs(
+                pdb_slots,
+                type_name,
+                primitive_types_flavor,
+                print_access_specifiers,
+                ignore_std_types,
+            ))?;
+        // Wait for the backend to finish
+        if let FrontendCommand::CompareTypeResult(type_revisions_result) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let type_revisions = type_revisions_result?;
+            let lines = type_revisions.into_iter().map(|type_revision| {
+                let pdb_file_name = type_revision
+                    .pdb_file_path
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .into_owned();
+                let size = match type_revision.size {
+                    Some(size) => format!("0x{size:x}"),
+                    None if type_revision.present => "?".to_string(),
+                    None => "-".to_string(),
+                };
+                let changes = if !type_revision.layout_changed {
+                    String::default()
+                } else {
+                    let statistics = &type_revision.statistics;
+                    format!(
+                        "{}+{}/-{} lines, +{}/-{} fields",
+                        if type_revision.size_changed {
+                            "size changed, "
+                        } else {
+                            ""
+                        },
+                        statistics.lines_added,
+                        statistics.lines_removed,
+                        statistics.fields_added,
+                        statistics.fields_removed,
+                    )
+                };
+                format!("{pdb_file_name}\t{size}\t{changes}")
+            });
+
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                for line in lines {
+                    writeln!(output_file, "{line}")?;
+                }
+            } else {
+                for line in lines {
+                    println!("{line}");
+                }
+            }
+
+            Ok(())
+        } else {
+            Err(anyhow!("Invalid response received from the backend?"))
+        }
+    }
+
+    pub fn list_modules_command(
+        &self,
+        pdb_path: PathBuf,
+        module_path_filter: String,
+        case_insensitive: bool,
+        use_regex: bool,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        // Request the backend to load the PDB
+        self.load_pdb_from_path(PDB_MAIN_SLOT, pdb_path)?;
+
+        // Queue a request for the backend to return the list of all modules
+        self.backend.send_command(BackendCommand::ListModules(
+            PDB_MAIN_SLOT,
+            module_path_filter,
+            case_insensitive,
+            use_regex,
         ))?;
-        // Wait for the backend to finish loading the PDB
-        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
-            if let Err(err) = result {
-                return Err(anyhow!(
-                    "Failed to load PDB '{}': {}",
-                    to_pdb_path.display(),
-                    err
-                ));
+        // Wait for the backend to finish listing modules
+        let response = self.frontend_controller.rx_ui.recv()?;
+        if let FrontendCommand::SearchFilterError(_, err) = response {
+            return Err(anyhow!("Invalid search filter: {err}"));
+        }
+        if let FrontendCommand::ListModulesResult(module_list_result) = response {
+            // Dump output
+            let module_list = module_list_result?;
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                for (module_path, module_id) in module_list {
+                    writeln!(output_file, "Mod {module_id:04} | '{module_path}'")?;
+                }
+            } else {
+                for (module_path, module_id) in module_list {
+                    println!("Mod {module_id:04} | '{module_path}'");
+                }
             }
+
+            Ok(())
         } else {
-            return Err(anyhow!("Invalid response received from the backend?"));
+            Err(anyhow!("Invalid response received from the backend?"))
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn dump_module_command(
+        &self,
+        pdb_path: PathBuf,
+        module_id: usize,
+        primitive_types_flavor: PrimitiveReconstructionFlavor,
+        print_header: bool,
+        print_access_specifiers: bool,
+        highlight_syntax: bool,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        // Request the backend to load the PDB
+        self.load_pdb_from_path(PDB_MAIN_SLOT, pdb_path)?;
+
+        // Queue a request for the backend to reconstruct the given module
+        self.backend
+            .send_command(BackendCommand::ReconstructModuleByIndex(
+                PDB_MAIN_SLOT,
+                module_id,
+                primitive_types_flavor,
+                print_header,
+                print_access_specifiers,
+            ))?;
+        // Wait for the backend to finish filtering types
+        if let FrontendCommand::ReconstructModuleResult(reconstructed_module) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let reconstructed_module = reconstructed_module?;
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                output_file.write_all(reconstructed_module.as_bytes())?;
+            } else if highlight_syntax {
+                let theme = CodeTheme::default();
+                if let Some(colorized_reconstructed_type) =
+                    highlight_code(&theme, &reconstructed_module, None)
+                {
+                    println!("{colorized_reconstructed_type}");
+                }
+            } else {
+                println!("{reconstructed_module}");
+            }
+            Ok(())
+        } else {
+            Err(anyhow!("Invalid response received from the backend?"))
         }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn diff_module_command(
+        &self,
+        from_pdb_path: PathBuf,
+        to_pdb_path: PathBuf,
+        module_path: String,
+        primitive_types_flavor: PrimitiveReconstructionFlavor,
+        print_header: bool,
+        print_access_specifiers: bool,
+        ignore_comments: bool,
+        highlight_syntax: bool,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        // Request the backend to load the first PDB
+        self.load_pdb_from_path(PDB_MAIN_SLOT, from_pdb_path)?;
+
+        // Request the backend to load the second PDB
+        self.load_pdb_from_path(PDB_DIFF_TO_SLOT, to_pdb_path)?;
 
         // Queue a request for the backend to diff the given module
         self.backend.send_command(BackendCommand::DiffModuleByPath(
@@ -419,6 +851,7 @@ impl ResymcApp {
             primitive_types_flavor,
             print_header,
             print_access_specifiers,
+            ignore_comments,
         ))?;
         // Wait for the backend to finish
         if let FrontendCommand::DiffResult(reconstructed_module_diff_result) =
@@ -431,14 +864,12 @@ impl ResymcApp {
                 output_file.write_all(reconstructed_module_diff.data.as_bytes())?;
             } else if highlight_syntax {
                 let theme = CodeTheme::default();
-                let line_descriptions =
-                    reconstructed_module_diff
-                        .metadata
-                        .iter()
-                        .fold(vec![], |mut acc, e| {
-                            acc.push(e.1);
-                            acc
-                        });
+                let line_descriptions = reconstructed_module_diff
+                    .metadata
+                    .iter()
+                    .zip(reconstructed_module_diff.inline_changes.iter())
+                    .map(|(metadata, inline_changes)| (metadata.1, inline_changes.clone()))
+                    .collect();
                 if let Some(colorized_reconstructed_module) = highlight_code(
                     &theme,
                     &reconstructed_module_diff.data,
@@ -456,6 +887,7 @@ impl ResymcApp {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn list_symbols_command(
         &self,
         pdb_path: PathBuf,
@@ -463,34 +895,31 @@ impl ResymcApp {
         case_insensitive: bool,
         use_regex: bool,
         ignore_std_types: bool,
+        undecorate_names: bool,
+        symbol_kinds: &[SymbolKind],
         output_file_path: Option<PathBuf>,
     ) -> Result<()> {
         // Request the backend to load the PDB
-        self.backend
-            .send_command(BackendCommand::LoadPDBFromPath(PDB_MAIN_SLOT, pdb_path))?;
-        // Wait for the backend to finish loading the PDB
-        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
-            if let Err(err) = result {
-                return Err(anyhow!("Failed to load PDB: {}", err));
-            }
-        } else {
-            return Err(anyhow!(
-                "LoadPDBResult expected. Invalid response received from the backend?"
-            ));
-        }
+        self.load_pdb_from_path(PDB_MAIN_SLOT, pdb_path)?;
 
         // Queue a request for the backend to return the list of all modules
         self.backend.send_command(BackendCommand::ListSymbols(
             PDB_MAIN_SLOT,
             symbol_name_filter,
-            case_insensitive,
-            use_regex,
-            ignore_std_types,
+            SymbolListOptions {
+                case_insensitive_search: case_insensitive,
+                use_regex,
+                ignore_std_types,
+                undecorate_names,
+                kind_filter: SymbolKindFilter::from_kinds(symbol_kinds),
+            },
         ))?;
         // Wait for the backend to finish listing modules
-        if let FrontendCommand::ListSymbolsResult(symbol_list) =
-            self.frontend_controller.rx_ui.recv()?
-        {
+        let response = self.frontend_controller.rx_ui.recv()?;
+        if let FrontendCommand::SearchFilterError(_, err) = response {
+            return Err(anyhow!("Invalid search filter: {err}"));
+        }
+        if let FrontendCommand::ListSymbolsResult(symbol_list) = response {
             // Dump output
             if let Some(output_file_path) = output_file_path {
                 let mut output_file = File::create(output_file_path)?;
@@ -523,18 +952,7 @@ impl ResymcApp {
         output_file_path: Option<PathBuf>,
     ) -> Result<()> {
         // Request the backend to load the PDB
-        self.backend
-            .send_command(BackendCommand::LoadPDBFromPath(PDB_MAIN_SLOT, pdb_path))?;
-        // Wait for the backend to finish loading the PDB
-        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
-            if let Err(err) = result {
-                return Err(anyhow!("Failed to load PDB: {}", err));
-            }
-        } else {
-            return Err(anyhow!(
-                "LoadPDBFromPath expected. Invalid response received from the backend?"
-            ));
-        }
+        self.load_pdb_from_path(PDB_MAIN_SLOT, pdb_path)?;
 
         // Queue a request for the backend to reconstruct the given module
         if let Some(symbol_name) = symbol_name {
@@ -582,57 +1000,62 @@ impl ResymcApp {
         }
     }
 
-    #[allow(clippy::too_many_arguments)]
-    pub fn diff_symbol_command(
+    pub fn constants_command(
         &self,
-        from_pdb_path: PathBuf,
-        to_pdb_path: PathBuf,
-        symbol_name: String,
+        pdb_path: PathBuf,
         primitive_types_flavor: PrimitiveReconstructionFlavor,
-        print_header: bool,
-        print_access_specifiers: bool,
         highlight_syntax: bool,
         output_file_path: Option<PathBuf>,
     ) -> Result<()> {
-        // Request the backend to load the first PDB
-        self.backend.send_command(BackendCommand::LoadPDBFromPath(
+        // Request the backend to load the PDB
+        self.load_pdb_from_path(PDB_MAIN_SLOT, pdb_path)?;
+
+        // Queue a request for the backend to list the constants
+        self.backend.send_command(BackendCommand::ListConstants(
             PDB_MAIN_SLOT,
-            from_pdb_path.clone(),
+            primitive_types_flavor,
         ))?;
-        // Wait for the backend to finish loading the PDB
-        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
-            if let Err(err) = result {
-                return Err(anyhow!(
-                    "Failed to load PDB '{}': {}",
-                    from_pdb_path.display(),
-                    err
-                ));
-            }
-        } else {
-            return Err(anyhow!(
-                "LoadPDBResult expected. Invalid response received from the backend?"
-            ));
-        }
+        // Wait for the backend to finish
+        if let FrontendCommand::ListConstantsResult(constant_list) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let constant_declarations: String = constant_list?
+                .into_iter()
+                .map(|constant| format!("{constant}\n"))
+                .collect();
 
-        // Request the backend to load the second PDB
-        self.backend.send_command(BackendCommand::LoadPDBFromPath(
-            PDB_DIFF_TO_SLOT,
-            to_pdb_path.clone(),
-        ))?;
-        // Wait for the backend to finish loading the PDB
-        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
-            if let Err(err) = result {
-                return Err(anyhow!(
-                    "Failed to load PDB '{}': {}",
-                    to_pdb_path.display(),
-                    err
-                ));
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                output_file.write_all(constant_declarations.as_bytes())?;
+            } else {
+                print_reconstructed_type(&constant_declarations, highlight_syntax);
             }
+
+            Ok(())
         } else {
-            return Err(anyhow!(
-                "LoadPDBResult expected. Invalid response received from the backend?"
-            ));
+            Err(anyhow!("Invalid response received from the backend?"))
         }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn diff_symbol_command(
+        &self,
+        from_pdb_path: PathBuf,
+        to_pdb_path: PathBuf,
+        symbol_name: String,
+        primitive_types_flavor: PrimitiveReconstructionFlavor,
+        print_header: bool,
+        print_access_specifiers: bool,
+        ignore_comments: bool,
+        highlight_syntax: bool,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        // Request the backend to load the first PDB
+        self.load_pdb_from_path(PDB_MAIN_SLOT, from_pdb_path)?;
+
+        // Request the backend to load the second PDB
+        self.load_pdb_from_path(PDB_DIFF_TO_SLOT, to_pdb_path)?;
 
         // Queue a request for the backend to diff the given module
         self.backend.send_command(BackendCommand::DiffSymbolByName(
@@ -642,6 +1065,7 @@ impl ResymcApp {
             primitive_types_flavor,
             print_header,
             print_access_specifiers,
+            ignore_comments,
         ))?;
         // Wait for the backend to finish
         if let FrontendCommand::DiffResult(reconstructed_symbol_diff_result) =
@@ -654,14 +1078,12 @@ impl ResymcApp {
                 output_file.write_all(reconstructed_symbol_diff.data.as_bytes())?;
             } else if highlight_syntax {
                 let theme = CodeTheme::default();
-                let line_descriptions =
-                    reconstructed_symbol_diff
-                        .metadata
-                        .iter()
-                        .fold(vec![], |mut acc, e| {
-                            acc.push(e.1);
-                            acc
-                        });
+                let line_descriptions = reconstructed_symbol_diff
+                    .metadata
+                    .iter()
+                    .zip(reconstructed_symbol_diff.inline_changes.iter())
+                    .map(|(metadata, inline_changes)| (metadata.1, inline_changes.clone()))
+                    .collect();
                 if let Some(colorized_reconstructed_module) = highlight_code(
                     &theme,
                     &reconstructed_symbol_diff.data,
@@ -680,67 +1102,1679 @@ impl ResymcApp {
             ))
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::fs;
+    #[allow(clippy::too_many_arguments)]
+    pub fn diff_public_symbols_command(
+        &self,
+        from_pdb_path: PathBuf,
+        to_pdb_path: PathBuf,
+        primitive_types_flavor: PrimitiveReconstructionFlavor,
+        print_header: bool,
+        print_access_specifiers: bool,
+        ignore_comments: bool,
+        highlight_syntax: bool,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        // Request the backend to load the first PDB
+        self.load_pdb_from_path(PDB_MAIN_SLOT, from_pdb_path)?;
 
-    use super::*;
+        // Request the backend to load the second PDB
+        self.load_pdb_from_path(PDB_DIFF_TO_SLOT, to_pdb_path)?;
+
+        // Queue a request for the backend to diff public symbols
+        self.backend
+            .send_command(BackendCommand::DiffPublicSymbols(
+                PDB_MAIN_SLOT,
+                PDB_DIFF_TO_SLOT,
+                primitive_types_flavor,
+                print_header,
+                print_access_specifiers,
+                ignore_comments,
+            ))?;
+        // Wait for the backend to finish
+        if let FrontendCommand::DiffResult(reconstructed_symbols_diff_result) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let reconstructed_symbols_diff = reconstructed_symbols_diff_result?;
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                output_file.write_all(reconstructed_symbols_diff.data.as_bytes())?;
+            } else if highlight_syntax {
+                let theme = CodeTheme::default();
+                let line_descriptions = reconstructed_symbols_diff
+                    .metadata
+                    .iter()
+                    .zip(reconstructed_symbols_diff.inline_changes.iter())
+                    .map(|(metadata, inline_changes)| (metadata.1, inline_changes.clone()))
+                    .collect();
+                if let Some(colorized_reconstructed_symbols) = highlight_code(
+                    &theme,
+                    &reconstructed_symbols_diff.data,
+                    Some(line_descriptions),
+                ) {
+                    println!("{colorized_reconstructed_symbols}");
+                }
+            } else {
+                println!("{}", reconstructed_symbols_diff.data);
+            }
+
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "DiffResult expected. Invalid response received from the backend?"
+            ))
+        }
+    }
+
+    /// Diff all the types of the PDB files found in two directories, paired by
+    /// file name, and write a change report for each pair to
+    /// `output_directory_path`
+    #[allow(clippy::too_many_arguments)]
+    pub fn diff_dir_command(
+        &self,
+        from_directory_path: PathBuf,
+        to_directory_path: PathBuf,
+        output_directory_path: PathBuf,
+        primitive_types_flavor: PrimitiveReconstructionFlavor,
+        print_access_specifiers: bool,
+        ignore_std_types: bool,
+        ignore_comments: bool,
+    ) -> Result<()> {
+        let from_pdb_paths = list_pdb_files(&from_directory_path)?;
+        let to_pdb_paths = list_pdb_files(&to_directory_path)?;
+        std::fs::create_dir_all(&output_directory_path)?;
+
+        let progress_bar = ProgressBar::new(self.show_progress, from_pdb_paths.len(), "");
+        for (file_name, from_pdb_path) in &from_pdb_paths {
+            progress_bar.inc(file_name);
+            let Some(to_pdb_path) = to_pdb_paths.get(file_name) else {
+                progress_bar.println(&format!("{file_name}: removed"));
+                continue;
+            };
+
+            // Note: keep going if a pair cannot be diffed, so other pairs still
+            // get their report
+            match self.diff_all_types(
+                from_pdb_path,
+                to_pdb_path,
+                None,
+                primitive_types_flavor,
+                print_access_specifiers,
+                ignore_std_types,
+                ignore_comments,
+                false,
+            ) {
+                Err(err) => progress_bar.println(&format!("{file_name}: {err}")),
+                Ok(changed_types) => {
+                    let report_path = output_directory_path.join(format!("{file_name}.diff"));
+                    std::fs::write(&report_path, changed_types_report(&changed_types))?;
+                    progress_bar.println(&format!(
+                        "{file_name}: {} changed type(s)",
+                        changed_types.len()
+                    ));
+                }
+            }
+        }
+        drop(progress_bar);
+        for file_name in to_pdb_paths.keys() {
+            if !from_pdb_paths.contains_key(file_name) {
+                println!("{file_name}: added");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Diff all the types defined in both PDB files whose name matches
+    /// `type_name_filter`, print a summary of the changes and optionally write
+    /// the diff of each changed type to `output_directory_path`
+    #[allow(clippy::too_many_arguments)]
+    pub fn diff_all_command(
+        &self,
+        from_pdb_path: PathBuf,
+        to_pdb_path: PathBuf,
+        type_name_filter: Option<String>,
+        output_directory_path: Option<PathBuf>,
+        primitive_types_flavor: PrimitiveReconstructionFlavor,
+        print_access_specifiers: bool,
+        ignore_std_types: bool,
+        ignore_comments: bool,
+        ignore_offset_only_changes: bool,
+    ) -> Result<()> {
+        let type_name_filter = type_name_filter
+            .map(|type_name_filter| Regex::new(&type_name_filter))
+            .transpose()?;
+        if let Some(output_directory_path) = &output_directory_path {
+            std::fs::create_dir_all(output_directory_path)?;
+        }
+
+        let changed_types = self.diff_all_types(
+            &from_pdb_path,
+            &to_pdb_path,
+            type_name_filter,
+            primitive_types_flavor,
+            print_access_specifiers,
+            ignore_std_types,
+            ignore_comments,
+            ignore_offset_only_changes,
+        )?;
+        let mut changed_type_count = 0;
+        let mut diff_file_names = HashSet::new();
+        for changed_type in &changed_types {
+            // Types added or removed have no counterpart to be diffed against
+            if !is_present_in_both_pdbs(&changed_type.diff) {
+                continue;
+            }
+
+            println!(
+                "{}: +{} -{}",
+                changed_type.type_name,
+                changed_type.diff.statistics.lines_added,
+                changed_type.diff.statistics.lines_removed
+            );
+            if let Some(output_directory_path) = &output_directory_path {
+                let diff_path = output_directory_path.join(format!(
+                    "{}.diff",
+                    unique_file_name(&changed_type.type_name, &mut diff_file_names)
+                ));
+                std::fs::write(diff_path, &changed_type.diff.data)?;
+            }
+            changed_type_count += 1;
+        }
+        println!("{changed_type_count} changed type(s)");
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn diff_all_types(
+        &self,
+        from_pdb_path: &Path,
+        to_pdb_path: &Path,
+        type_name_filter: Option<Regex>,
+        primitive_types_flavor: PrimitiveReconstructionFlavor,
+        print_access_specifiers: bool,
+        ignore_std_types: bool,
+        ignore_comments: bool,
+        ignore_offset_only_changes: bool,
+    ) -> Result<Vec<ChangedType>> {
+        // Request the backend to load both PDBs
+        self.load_pdb_from_path(PDB_MAIN_SLOT, from_pdb_path.to_path_buf())?;
+        self.load_pdb_from_path(PDB_DIFF_TO_SLOT, to_pdb_path.to_path_buf())?;
+
+        // Queue a request for the backend to diff all types
+        let _progress_bar = ProgressBar::spinner(self.show_progress, "Diffing all types");
+        self.backend.send_command(BackendCommand::DiffAllTypes(
+            PDB_MAIN_SLOT,
+            PDB_DIFF_TO_SLOT,
+            type_name_filter,
+            primitive_types_flavor,
+            DiffOptions {
+                print_access_specifiers,
+                ignore_std_types,
+                ignore_comments,
+                ignore_offset_only_changes,
+                ..Default::default()
+            },
+        ))?;
+        // Wait for the backend to finish
+        if let FrontendCommand::DiffAllTypesResult(changed_types_result) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            Ok(changed_types_result?)
+        } else {
+            Err(anyhow!(
+                "DiffAllTypesResult expected. Invalid response received from the backend?"
+            ))
+        }
+    }
+
+    pub fn check_header_command(
+        &self,
+        pdb_path: PathBuf,
+        header_path: PathBuf,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        let header = std::fs::read_to_string(header_path)?;
+        // Request the backend to load the PDB
+        self.load_pdb_from_path(PDB_MAIN_SLOT, pdb_path)?;
+
+        // Queue a request for the backend to check the header's layouts
+        self.backend
+            .send_command(BackendCommand::CheckHeaderLayouts(PDB_MAIN_SLOT, header))?;
+        // Wait for the backend to finish
+        if let FrontendCommand::CheckHeaderLayoutsResult(layout_checks_result) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let layout_checks = layout_checks_result?;
+            let report = layout_checks_report(&layout_checks);
+
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                write!(output_file, "{report}")?;
+            } else {
+                print!("{report}");
+            }
+
+            // Fail if the header doesn't match the PDB, to be usable in scripts
+            let mismatching_type_count = layout_checks
+                .iter()
+                .filter(|layout_check| !layout_check.mismatches.is_empty())
+                .count();
+            if mismatching_type_count > 0 {
+                Err(anyhow!(
+                    "{mismatching_type_count} type(s) don't match the PDB's layouts"
+                ))
+            } else {
+                Ok(())
+            }
+        } else {
+            Err(anyhow!("Invalid response received from the backend?"))
+        }
+    }
+
+    pub fn undname_command(
+        &self,
+        decorated_name: String,
+        print_access_specifiers: bool,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        let demangled_name = demangle_symbol_name(&decorated_name, print_access_specifiers)
+            .ok_or_else(|| anyhow!("Failed to demangle '{}'", decorated_name))?;
+
+        // Dump output
+        if let Some(output_file_path) = output_file_path {
+            let mut output_file = File::create(output_file_path)?;
+            writeln!(output_file, "{demangled_name}")?;
+        } else {
+            println!("{demangled_name}");
+        }
+
+        Ok(())
+    }
+
+    pub fn lookup_address_command(
+        &self,
+        pdb_path: PathBuf,
+        address: u64,
+        image_base: Option<u64>,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        let rva = address
+            .checked_sub(image_base.unwrap_or_default())
+            .and_then(|rva| u32::try_from(rva).ok())
+            .ok_or_else(|| anyhow!("Address 0x{:x} is out of the image's range", address))?;
+
+        // Request the backend to load the PDB
+        self.load_pdb_from_path(PDB_MAIN_SLOT, pdb_path)?;
+
+        // Queue a request for the backend to look up the address
+        self.backend
+            .send_command(BackendCommand::LookupSymbolByRVA(PDB_MAIN_SLOT, rva))?;
+        // Wait for the backend to finish
+        if let FrontendCommand::LookupSymbolByRVAResult(symbol_result) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let symbol = symbol_result?;
+            let symbol_text = if symbol.offset == 0 {
+                format!("{} (RVA=0x{:x})", symbol.name, symbol.rva)
+            } else {
+                format!(
+                    "{}+0x{:x} (RVA=0x{:x})",
+                    symbol.name, symbol.offset, symbol.rva
+                )
+            };
+
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                writeln!(output_file, "{symbol_text}")?;
+            } else {
+                println!("{symbol_text}");
+            }
+
+            Ok(())
+        } else {
+            Err(anyhow!("Invalid response received from the backend?"))
+        }
+    }
+
+    pub fn addr_command(
+        &self,
+        pdb_path: PathBuf,
+        symbol_name: String,
+        pe_path: Option<PathBuf>,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        // Request the backend to load the PE image (if any) and the PDB
+        if let Some(pe_path) = pe_path {
+            self.load_pe_from_path(PDB_MAIN_SLOT, pe_path)?;
+        }
+        self.load_pdb_from_path(PDB_MAIN_SLOT, pdb_path)?;
+
+        // Queue a request for the backend to look up the symbol
+        self.backend
+            .send_command(BackendCommand::LookupSymbolLocationByName(
+                PDB_MAIN_SLOT,
+                symbol_name,
+            ))?;
+        // Wait for the backend to finish
+        if let FrontendCommand::LookupSymbolLocationResult(symbol_location_result) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let symbol_location = symbol_location_result?;
+            let mut symbol_text = format!(
+                "{}: RVA=0x{:x} Section={} Size={}",
+                symbol_location.name,
+                symbol_location.rva,
+                symbol_location.section_name,
+                symbol_location
+                    .size
+                    .map(|size| format!("0x{size:x}"))
+                    .unwrap_or_else(|| "?".to_string())
+            );
+            if !symbol_location.export_names.is_empty() {
+                symbol_text.push_str(&format!(
+                    " Exports={}",
+                    symbol_location.export_names.join(",")
+                ));
+            }
+            if symbol_location.outside_image {
+                symbol_text.push_str(" (outside of the image)");
+            }
+
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                writeln!(output_file, "{symbol_text}")?;
+            } else {
+                println!("{symbol_text}");
+            }
+
+            Ok(())
+        } else {
+            Err(anyhow!("Invalid response received from the backend?"))
+        }
+    }
+
+    pub fn dependency_graph_command(
+        &self,
+        pdb_path: PathBuf,
+        type_name: Option<String>,
+        ignore_std_types: bool,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        // Request the backend to load the PDB
+        self.load_pdb_from_path(PDB_MAIN_SLOT, pdb_path)?;
+
+        // Queue a request for the backend to export the dependency graph
+        let command = if let Some(type_name) = type_name {
+            BackendCommand::ExportTypeDependencyGraphByName(
+                PDB_MAIN_SLOT,
+                type_name,
+                ignore_std_types,
+            )
+        } else {
+            BackendCommand::ExportTypeDependencyGraph(PDB_MAIN_SLOT, None, ignore_std_types)
+        };
+        self.backend.send_command(command)?;
+        // Wait for the backend to finish
+        if let FrontendCommand::ExportTypeDependencyGraphResult(export_result) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let dependency_graph = export_result?;
+
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                output_file.write_all(dependency_graph.as_bytes())?;
+            } else {
+                print!("{dependency_graph}");
+            }
+
+            Ok(())
+        } else {
+            Err(anyhow!("Invalid response received from the backend?"))
+        }
+    }
+
+    pub fn layout_assertions_command(
+        &self,
+        pdb_path: PathBuf,
+        type_names: Vec<String>,
+        ignore_std_types: bool,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        // Request the backend to load the PDB
+        self.load_pdb_from_path(PDB_MAIN_SLOT, pdb_path)?;
+
+        // Queue a request for the backend to generate the assertions
+        self.backend
+            .send_command(BackendCommand::ExportLayoutAssertions(
+                PDB_MAIN_SLOT,
+                (!type_names.is_empty()).then_some(type_names),
+                ignore_std_types,
+            ))?;
+        // Wait for the backend to finish
+        if let FrontendCommand::ExportLayoutAssertionsResult(export_result) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let layout_assertions = export_result?;
+
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                output_file.write_all(layout_assertions.as_bytes())?;
+            } else {
+                print!("{layout_assertions}");
+            }
+
+            Ok(())
+        } else {
+            Err(anyhow!("Invalid response received from the backend?"))
+        }
+    }
+
+    pub fn info_command(&self, pdb_path: PathBuf, output_file_path: Option<PathBuf>) -> Result<()> {
+        // Request the backend to load the PDB
+        self.load_pdb_from_path(PDB_MAIN_SLOT, pdb_path)?;
+
+        // Queue a request for the backend to return the PDB's metadata
+        self.backend
+            .send_command(BackendCommand::GetPDBMetadata(PDB_MAIN_SLOT))?;
+        // Wait for the backend to finish
+        if let FrontendCommand::PDBMetadataResult(_, metadata_result) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let metadata = metadata_result?;
+            let lines = [
+                format!("File: {}", metadata.file_path.display()),
+                format!("GUID: {}", metadata.guid),
+                format!("Age: {}", metadata.age),
+                format!("Signature: 0x{:08x}", metadata.signature),
+                format!("Symbol server key: {}", metadata.symbol_server_key()),
+                format!("Machine type: {}", metadata.machine_type),
+                format!("Streams: {}", metadata.stream_count),
+                format!("Types: {}", metadata.type_count),
+                format!("Modules: {}", metadata.module_count),
+                format!(
+                    "Stripped: {}",
+                    if metadata.is_stripped { "yes" } else { "no" }
+                ),
+            ];
+
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                for line in lines {
+                    writeln!(output_file, "{line}")?;
+                }
+            } else {
+                for line in lines {
+                    println!("{line}");
+                }
+            }
+
+            Ok(())
+        } else {
+            Err(anyhow!("Invalid response received from the backend?"))
+        }
+    }
+
+    pub fn serve_command(&self, pdb_paths: Vec<PathBuf>, port: Option<u16>) -> Result<()> {
+        let rpc_server = RpcServer::new(&self.backend, &self.frontend_controller);
+        // Request the backend to load the given PDBs, in consecutive slots
+        for (pdb_slot, pdb_path) in pdb_paths.into_iter().enumerate() {
+            rpc_server.load_pdb_from_path(pdb_slot, pdb_path)?;
+        }
+
+        if let Some(port) = port {
+            let listener = TcpListener::bind(("127.0.0.1", port))?;
+            log::info!("Listening on {}", listener.local_addr()?);
+            // Clients are served one at a time, as they share the same backend
+            for stream in listener.incoming() {
+                // Errors affecting a single client are logged, to keep serving
+                // the next ones
+                let stream = match stream {
+                    Ok(stream) => stream,
+                    Err(err) => {
+                        log::warn!("Failed to accept connection: {err}");
+                        continue;
+                    }
+                };
+                let reader = match stream.try_clone() {
+                    Ok(reader) => BufReader::new(reader),
+                    Err(err) => {
+                        log::warn!("Failed to set up connection: {err}");
+                        continue;
+                    }
+                };
+                match rpc_server.serve(reader, stream) {
+                    Ok(true) => break,
+                    Ok(false) => {}
+                    Err(err) => log::warn!("Connection closed with an error: {err}"),
+                }
+            }
+        } else {
+            rpc_server.serve(std::io::stdin().lock(), std::io::stdout().lock())?;
+        }
+
+        Ok(())
+    }
+
+    pub fn lsp_command(&self, pdb_path: PathBuf) -> Result<()> {
+        let rpc_server = RpcServer::new(&self.backend, &self.frontend_controller);
+        // Request the backend to load the PDB
+        rpc_server.load_pdb_from_path(PDB_MAIN_SLOT, pdb_path)?;
+
+        LspServer::new(rpc_server).serve(std::io::stdin().lock(), std::io::stdout().lock())
+    }
+
+    /// Request the backend to load a PDB in the given slot and wait for it
+    /// to finish. `pdb_path` can also be an HTTP(S) URL, in which case the
+    /// PDB is downloaded (or read from the cache) first.
+    fn load_pdb_from_path(&self, pdb_slot: PDBSlot, pdb_path: PathBuf) -> Result<()> {
+        let _progress_bar = ProgressBar::spinner(
+            self.show_progress,
+            format!("Loading '{}'", pdb_path.display()),
+        );
+        #[cfg(feature = "http")]
+        if let Some(pdb_url) = pdb_url(&pdb_path) {
+            self.download_pdb(pdb_slot, pdb_url)?;
+        } else {
+            self.backend
+                .send_command(BackendCommand::LoadPDBFromPath(pdb_slot, pdb_path.clone()))?;
+        }
+        #[cfg(not(feature = "http"))]
+        self.backend
+            .send_command(BackendCommand::LoadPDBFromPath(pdb_slot, pdb_path.clone()))?;
+
+        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
+            result
+                .map(|_| ())
+                .map_err(|err| anyhow!("Failed to load PDB '{}': {}", pdb_path.display(), err))
+        } else {
+            Err(anyhow!(
+                "LoadPDBResult expected. Invalid response received from the backend?"
+            ))
+        }
+    }
+
+    /// Request the backend to download a PDB and, once downloaded, to load it
+    /// in the given slot
+    #[cfg(feature = "http")]
+    fn download_pdb(&self, pdb_slot: PDBSlot, pdb_url: &str) -> Result<()> {
+        self.backend.send_command(BackendCommand::LoadPDBFromURL(
+            pdb_slot,
+            pdb_url.to_string(),
+        ))?;
+        match self.frontend_controller.rx_ui.recv()? {
+            FrontendCommand::LoadURLResult(Ok((pdb_slot, pdb_name, pdb_data))) => {
+                self.backend
+                    .send_command(BackendCommand::LoadPDBFromVec(pdb_slot, pdb_name, pdb_data))?;
+                Ok(())
+            }
+            FrontendCommand::LoadURLResult(Err(err)) => {
+                Err(anyhow!("Failed to download PDB '{pdb_url}': {err}"))
+            }
+            _ => Err(anyhow!(
+                "LoadURLResult expected. Invalid response received from the backend?"
+            )),
+        }
+    }
+
+    fn load_pe_from_path(&self, pdb_slot: PDBSlot, pe_path: PathBuf) -> Result<()> {
+        self.backend
+            .send_command(BackendCommand::LoadPEFromPath(pdb_slot, pe_path.clone()))?;
+        if let FrontendCommand::LoadPEResult(result) = self.frontend_controller.rx_ui.recv()? {
+            result
+                .map(|_| ())
+                .map_err(|err| anyhow!("Failed to load PE '{}': {}", pe_path.display(), err))
+        } else {
+            Err(anyhow!(
+                "LoadPEResult expected. Invalid response received from the backend?"
+            ))
+        }
+    }
+}
+
+/// URL of the PDB file, if the given path is an HTTP(S) URL
+#[cfg(feature = "http")]
+fn pdb_url(pdb_path: &Path) -> Option<&str> {
+    pdb_path
+        .to_str()
+        .filter(|pdb_path| pdb_path.starts_with("http://") || pdb_path.starts_with("https://"))
+}
+
+/// List the PDB files contained in the given directory, indexed by file name
+fn list_pdb_files(directory_path: &Path) -> Result<BTreeMap<String, PathBuf>> {
+    let mut pdb_files = BTreeMap::new();
+    for entry in std::fs::read_dir(directory_path)
+        .map_err(|err| anyhow!("Failed to list '{}': {}", directory_path.display(), err))?
+    {
+        let path = entry?.path();
+        let is_pdb_file = path
+            .extension()
+            .map(|extension| extension.eq_ignore_ascii_case("pdb"))
+            .unwrap_or(false);
+        if is_pdb_file && path.is_file() {
+            if let Some(file_name) = path.file_name() {
+                pdb_files.insert(file_name.to_string_lossy().to_string(), path);
+            }
+        }
+    }
+
+    Ok(pdb_files)
+}
+
+/// Concatenate the diffs of the given types, each preceded by a summary line
+fn changed_types_report(changed_types: &[ChangedType]) -> String {
+    let mut report = String::default();
+    for changed_type in changed_types {
+        report.push_str(&format!(
+            "// {}: +{} -{}\n",
+            changed_type.type_name,
+            changed_type.diff.statistics.lines_added,
+            changed_type.diff.statistics.lines_removed
+        ));
+        report.push_str(&changed_type.diff.data);
+        if !report.ends_with('\n') {
+            report.push('\n');
+        }
+        report.push('\n');
+    }
+
+    report
+}
+
+/// Indicate if the diffed type was defined in both PDB files (i.e., it wasn't
+/// entirely added or removed)
+fn is_present_in_both_pdbs(diff: &Diff) -> bool {
+    let mut changes = diff.metadata.iter().map(|(_, change)| *change);
+    !changes.clone().all(|change| change == DiffChange::Insert)
+        && !changes.all(|change| change == DiffChange::Delete)
+}
+
+/// Turn a type name (e.g., `ns::Type<int>`) into something that can be used as
+/// a file name
+fn type_name_to_file_name(type_name: &str) -> String {
+    type_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Turn a type name into a file name (see `type_name_to_file_name`) which
+/// isn't part of `used_file_names` yet, by appending a numeric suffix if
+/// needed. Names are compared case-insensitively, for file systems that are.
+fn unique_file_name(type_name: &str, used_file_names: &mut HashSet<String>) -> String {
+    let file_name = type_name_to_file_name(type_name);
+    let mut unique_file_name = file_name.clone();
+    let mut suffix = 1;
+    while !used_file_names.insert(unique_file_name.to_lowercase()) {
+        suffix += 1;
+        unique_file_name = format!("{file_name}_{suffix}");
+    }
+
+    unique_file_name
+}
+
+/// List the mismatches found for each of the given types
+fn layout_checks_report(layout_checks: &[TypeLayoutCheck]) -> String {
+    let mut report = String::default();
+    for layout_check in layout_checks {
+        if layout_check.mismatches.is_empty() {
+            report.push_str(&format!(
+                "{}: OK ({} field(s) checked)\n",
+                layout_check.type_name, layout_check.checked_field_count
+            ));
+        } else {
+            report.push_str(&format!(
+                "{}: {} mismatch(es)\n",
+                layout_check.type_name,
+                layout_check.mismatches.len()
+            ));
+            for mismatch in &layout_check.mismatches {
+                report.push_str(&format!("  {mismatch}\n"));
+            }
+        }
+    }
+
+    report
+}
+
+fn print_reconstructed_type(reconstructed_type: &str, highlight_syntax: bool) {
+    if highlight_syntax {
+        let theme = CodeTheme::default();
+        if let Some(colorized_reconstructed_type) = highlight_code(&theme, reconstructed_type, None)
+        {
+            println!("{colorized_reconstructed_type}");
+        }
+    } else {
+        println!("{reconstructed_type}");
+    }
+}
+
+fn print_reconstructed_type_diff(reconstructed_type_diff: &Diff, highlight_syntax: bool) {
+    if highlight_syntax {
+        let theme = CodeTheme::default();
+        let line_descriptions = reconstructed_type_diff
+            .metadata
+            .iter()
+            .zip(reconstructed_type_diff.inline_changes.iter())
+            .map(|(metadata, inline_changes)| (metadata.1, inline_changes.clone()))
+            .collect();
+        if let Some(colorized_reconstructed_type) = highlight_code(
+            &theme,
+            &reconstructed_type_diff.data,
+            Some(line_descriptions),
+        ) {
+            println!("{colorized_reconstructed_type}");
+        }
+    } else {
+        println!("{}", reconstructed_type_diff.data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    use tempdir::TempDir;
+
+    const TEST_PDB_FILE_PATH: &str = "../resym_core/tests/data/test.pdb";
+    const TEST_PDB_FROM_FILE_PATH: &str = "../resym_core/tests/data/test_diff_from.pdb";
+    const TEST_PDB_TO_FILE_PATH: &str = "../resym_core/tests/data/test_diff_to.pdb";
+
+    // List types
+    #[test]
+    fn list_types_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::new();
+        // The command should fail
+        assert!(app
+            .list_types_command(
+                pdb_path,
+                "resym_test::StructTest".to_string(),
+                false,
+                false,
+                false,
+                None,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn list_types_command_stdio_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        // The command should succeed
+        assert!(app
+            .list_types_command(
+                pdb_path,
+                "resym_test::StructTest".to_string(),
+                true,
+                true,
+                true,
+                None,
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn list_types_command_invalid_regex() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        // The command should fail, instead of listing no types
+        assert!(app
+            .list_types_command(
+                pdb_path,
+                "resym_test::(StructTest".to_string(),
+                false,
+                true,
+                false,
+                None,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn list_types_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir =
+            TempDir::new("list_types_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+        // The command should succeed
+        assert!(app
+            .list_types_command(
+                pdb_path,
+                "resym_test::ClassWithNestedDeclarationsTest".to_string(),
+                false,
+                false,
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            concat!(
+                "resym_test::ClassWithNestedDeclarationsTest::NestEnum\n",
+                "resym_test::ClassWithNestedDeclarationsTest\n",
+                "resym_test::ClassWithNestedDeclarationsTest::NestedUnion\n",
+                "resym_test::ClassWithNestedDeclarationsTest::NestedClass\n",
+                "resym_test::ClassWithNestedDeclarationsTest::NestedStruct\n"
+            )
+        );
+    }
+
+    #[test]
+    fn list_types_command_reduced_memory_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir = TempDir::new("list_types_command_reduced_memory_file_successful")
+            .expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+        // The command should succeed
+        assert!(app.set_reduced_memory_mode(true).is_ok());
+        assert!(app
+            .list_types_command(
+                pdb_path,
+                "resym_test::ClassWithNestedDeclarationsTest".to_string(),
+                false,
+                false,
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            concat!(
+                "resym_test::ClassWithNestedDeclarationsTest::NestEnum\n",
+                "resym_test::ClassWithNestedDeclarationsTest\n",
+                "resym_test::ClassWithNestedDeclarationsTest::NestedUnion\n",
+                "resym_test::ClassWithNestedDeclarationsTest::NestedClass\n",
+                "resym_test::ClassWithNestedDeclarationsTest::NestedStruct\n"
+            )
+        );
+    }
+
+    // Search types
+    #[test]
+    fn search_types_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_paths = vec![
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH),
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH),
+        ];
+        let tmp_dir =
+            TempDir::new("search_types_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+        // The command should succeed
+        assert!(app
+            .search_types_command(
+                pdb_paths,
+                "^UserStructAdd$".to_string(),
+                false,
+                true,
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Types found in both PDBs are listed once per PDB
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            concat!(
+                "test_diff_from.pdb\tUserStructAdd\n",
+                "test_diff_to.pdb\tUserStructAdd\n"
+            )
+        );
+    }
+
+    // Dump types
+    #[test]
+    fn dump_types_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::new();
+        // The command should fail
+        assert!(app
+            .dump_types_command(
+                pdb_path,
+                None,
+                PrimitiveReconstructionFlavor::Microsoft,
+                false,
+                false,
+                false,
+                false,
+                vec![],
+                false,
+                None
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn dump_types_command_stdio_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+
+        // The command should succeed
+        assert!(app
+            .dump_types_command(
+                pdb_path,
+                None,
+                PrimitiveReconstructionFlavor::Microsoft,
+                true,
+                true,
+                true,
+                true,
+                vec![],
+                true,
+                None
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn dump_types_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir =
+            TempDir::new("dump_types_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+
+        // The command should succeed
+        assert!(app
+            .dump_types_command(
+                pdb_path,
+                Some("resym_test::ClassWithNestedDeclarationsTest".to_string()),
+                PrimitiveReconstructionFlavor::Microsoft,
+                false,
+                false,
+                false,
+                false,
+                vec![],
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            concat!("\nclass resym_test::ClassWithNestedDeclarationsTest { /* Size=0x1 */\n};\n")
+        );
+    }
+
+    // Dump enums
+    #[test]
+    fn enums_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::new();
+        // The command should fail
+        assert!(app
+            .enums_command(
+                pdb_path,
+                PrimitiveReconstructionFlavor::Microsoft,
+                false,
+                false,
+                false,
+                None
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn enums_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir =
+            TempDir::new("enums_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.h");
+
+        // The command should succeed
+        assert!(app
+            .enums_command(
+                pdb_path,
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                true,
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert!(output.contains("\nenum resym_test::EnumTest1 : int32_t {\n"));
+        assert!(output.contains("\nenum resym_test::EnumTest2 : unsigned char {\n"));
+        assert!(!output.contains("\nstruct "));
+    }
+
+    // Diff type
+    #[test]
+    fn diff_type_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_from = PathBuf::new();
+        let pdb_path_to = PathBuf::new();
+
+        // The command should fail
+        assert!(app
+            .diff_type_command(
+                pdb_path_from,
+                pdb_path_to,
+                "".to_string(),
+                PrimitiveReconstructionFlavor::Microsoft,
+                false,
+                false,
+                false,
+                false,
+                false,
+                false,
+                false,
+                false,
+                None
+            )
+            .is_err());
+    }
+    #[test]
+    fn diff_type_command_stdio_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
+        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
+
+        // The command should succeed
+        assert!(app
+            .diff_type_command(
+                pdb_path_from,
+                pdb_path_to,
+                "UserStructAddAndReplace".to_string(),
+                PrimitiveReconstructionFlavor::Microsoft,
+                true,
+                true,
+                true,
+                true,
+                true,
+                true,
+                true,
+                false,
+                None
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn diff_type_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
+        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
+
+        let tmp_dir =
+            TempDir::new("diff_type_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+
+        // The command should succeed
+        assert!(app
+            .diff_type_command(
+                pdb_path_from,
+                pdb_path_to,
+                "UserStructAddAndReplace".to_string(),
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                false,
+                false,
+                false,
+                false,
+                false,
+                false,
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            concat!(
+                " \n-struct UserStructAddAndReplace { /* Size=0x10 */\n",
+                "-  /* 0x0000 */ int32_t field1;\n-  /* 0x0004 */ char field2;\n",
+                "-  /* 0x0008 */ void* field3;\n+struct UserStructAddAndReplace { /* Size=0x28 */\n",
+                "+  /* 0x0000 */ int32_t before1;\n+  /* 0x0004 */ int32_t field1;\n",
+                "+  /* 0x0008 */ int32_t between12;\n+  /* 0x000c */ char field2;\n",
+                "+  /* 0x0010 */ int32_t between23;\n+  /* 0x0018 */ void* field3;\n",
+                "+  /* 0x0020 */ int32_t after3;\n };\n",
+            )
+        );
+    }
+
+    // Diff type (three-way)
+    #[test]
+    fn diff_type_three_way_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+
+        // The command should fail
+        assert!(app
+            .diff_type_three_way_command(
+                PathBuf::new(),
+                PathBuf::new(),
+                PathBuf::new(),
+                "".to_string(),
+                PrimitiveReconstructionFlavor::Microsoft,
+                false,
+                false,
+                false,
+                false,
+                false,
+                false,
+                None
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn diff_type_three_way_command_stdio_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_base = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
+        let pdb_path_a = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
+        let pdb_path_b = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
+
+        // The command should succeed
+        assert!(app
+            .diff_type_three_way_command(
+                pdb_path_base,
+                pdb_path_a,
+                pdb_path_b,
+                "UserStructAddAndReplace".to_string(),
+                PrimitiveReconstructionFlavor::Microsoft,
+                true,
+                true,
+                true,
+                true,
+                true,
+                true,
+                None
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn diff_type_three_way_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_base = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
+        let pdb_path_a = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
+        let pdb_path_b = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
+
+        let tmp_dir = TempDir::new("diff_type_three_way_command_file_successful")
+            .expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+
+        // The command should succeed
+        assert!(app
+            .diff_type_three_way_command(
+                pdb_path_base,
+                pdb_path_a,
+                pdb_path_b,
+                "UserStructAddAndReplace".to_string(),
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                false,
+                false,
+                false,
+                false,
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Identical changes in both variants shouldn't produce conflicts
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert!(!output.contains("<<<<<<<"));
+        assert!(output.contains("+struct UserStructAddAndReplace { /* Size=0x28 */\n"));
+    }
+
+    // Compare type
+    #[test]
+    fn compare_type_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+
+        // The command should fail
+        assert!(app
+            .compare_type_command(
+                vec![PathBuf::new(), PathBuf::new()],
+                "".to_string(),
+                PrimitiveReconstructionFlavor::Microsoft,
+                false,
+                false,
+                None
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn compare_type_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
+        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
+
+        let tmp_dir =
+            TempDir::new("compare_type_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+
+        // The command should succeed
+        assert!(app
+            .compare_type_command(
+                vec![pdb_path_from.clone(), pdb_path_to, pdb_path_from],
+                "UserStructAddAndReplace".to_string(),
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            concat!(
+                "test_diff_from.pdb\t0x10\t\n",
+                "test_diff_to.pdb\t0x28\tsize changed, +8/-4 lines, +7/-3 fields\n",
+                "test_diff_from.pdb\t0x10\tsize changed, +4/-8 lines, +3/-7 fields\n",
+            )
+        );
+    }
+
+    // List modules
+    #[test]
+    fn list_modules_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::new();
+        // The command should fail
+        assert!(app
+            .list_modules_command(pdb_path, "*".to_string(), false, false, None)
+            .is_err());
+    }
+
+    #[test]
+    fn list_modules_command_stdio_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        // The command should succeed
+        assert!(app
+            .list_modules_command(pdb_path, "*".to_string(), true, true, None)
+            .is_ok());
+    }
+
+    #[test]
+    fn list_modules_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir =
+            TempDir::new("list_modules_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+        // The command should succeed
+        assert!(app
+            .list_modules_command(
+                pdb_path,
+                "*".to_string(),
+                false,
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            concat!(
+                "Mod 0048 | '* Linker Generated Manifest RES *'\n",
+                "Mod 0053 | '* Linker *'\n"
+            )
+        );
+    }
+
+    // Dump module
+    #[test]
+    fn dump_module_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::new();
+        // The command should fail
+        assert!(app
+            .dump_module_command(
+                pdb_path,
+                9, // exe_main.obj
+                PrimitiveReconstructionFlavor::Microsoft,
+                false,
+                false,
+                false,
+                None
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn dump_module_command_stdio_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        // The command should succeed
+        assert!(app
+            .dump_module_command(
+                pdb_path,
+                9, // exe_main.obj
+                PrimitiveReconstructionFlavor::Microsoft,
+                true,
+                true,
+                true,
+                None
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn dump_module_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir =
+            TempDir::new("dump_module_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+        // The command should succeed
+        assert!(app
+            .dump_module_command(
+                pdb_path,
+                27, // default_local_stdio_options.obj
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                false,
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            concat!(
+                "using namespace std;\n",
+                "using PUWSTR_C = const wchar_t*;\n",
+                "using TP_CALLBACK_ENVIRON_V3 = _TP_CALLBACK_ENVIRON_V3;\n",
+                "uint64_t* (__local_stdio_scanf_options)(); // RVA=0x14670 CodeSize=0x8\n",
+                "static uint64_t _OptionsStorage; // RVA=0x1eb28 \n",
+                "void (__scrt_initialize_default_local_stdio_options)(); // RVA=0x14680 CodeSize=0x45\n",
+            )
+        );
+    }
+
+    // Diff module
+    #[test]
+    fn diff_module_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_from = PathBuf::new();
+        let pdb_path_to = PathBuf::new();
+
+        // The command should fail
+        assert!(app
+            .diff_module_command(
+                pdb_path_from,
+                pdb_path_to,
+                "d:\\a01\\_work\\43\\s\\Intermediate\\vctools\\msvcrt.nativeproj_607447030\\objd\\amd64\\exe_main.obj".to_string(),
+                PrimitiveReconstructionFlavor::Microsoft,
+                false,
+                false,
+                false,
+                false,
+                None
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn diff_module_command_stdio_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
+        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
+
+        // The command should succeed
+        assert!(app
+            .diff_module_command(
+                pdb_path_from,
+                pdb_path_to,
+                "d:\\a01\\_work\\43\\s\\Intermediate\\vctools\\msvcrt.nativeproj_607447030\\objd\\amd64\\exe_main.obj".to_string(),
+                PrimitiveReconstructionFlavor::Microsoft,
+                true,
+                true,
+                true,true,
+                None
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn diff_module_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
+        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
+
+        let tmp_dir =
+            TempDir::new("diff_module_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+
+        // The command should succeed
+        assert!(app
+            .diff_module_command(
+                pdb_path_from,
+                pdb_path_to,
+                "d:\\a01\\_work\\43\\s\\Intermediate\\vctools\\msvcrt.nativeproj_607447030\\objd\\amd64\\default_local_stdio_options.obj".to_string(),
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                false,
+                false,
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            concat!(
+                " using namespace std;\n",
+                " using PUWSTR_C = const wchar_t*;\n",
+                " using TP_CALLBACK_ENVIRON_V3 = _TP_CALLBACK_ENVIRON_V3;\n",
+                " uint64_t* (__local_stdio_scanf_options)(); // RVA=0x13c30 CodeSize=0x8\n",
+                " static uint64_t _OptionsStorage; // RVA=0x1c898 \n",
+                " void (__scrt_initialize_default_local_stdio_options)(); // RVA=0x13c40 CodeSize=0x45\n",
+            )
+        );
+    }
+
+    // List symbols
+    #[test]
+    fn list_symbols_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::new();
+        // The command should fail
+        assert!(app
+            .list_symbols_command(
+                pdb_path,
+                "*".to_string(),
+                false,
+                false,
+                false,
+                false,
+                &[],
+                None
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn list_symbols_command_stdio_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        // The command should succeed
+        assert!(app
+            .list_symbols_command(pdb_path, "*".to_string(), true, true, true, true, &[], None)
+            .is_ok());
+    }
+
+    #[test]
+    fn list_symbols_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir =
+            TempDir::new("list_modules_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+        // The command should succeed
+        assert!(app
+            .list_symbols_command(
+                pdb_path,
+                "resym_test::UnionTest".to_string(),
+                false,
+                false,
+                false,
+                false,
+                &[],
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            concat!(
+                "resym_test::UnionTest::UnionTest\n",
+                "resym_test::UnionTest::~UnionTest\n"
+            )
+        );
+    }
+
+    #[test]
+    fn list_symbols_command_kind_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir = TempDir::new("list_symbols_command_kind_file_successful")
+            .expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+        // The command should succeed
+        assert!(app
+            .list_symbols_command(
+                pdb_path,
+                "^WideCharToMultiByte$".to_string(),
+                false,
+                true,
+                false,
+                false,
+                &[SymbolKind::Thunk],
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(output, "WideCharToMultiByte\n");
+    }
 
-    use tempdir::TempDir;
+    #[test]
+    fn list_symbols_command_undecorated_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir = TempDir::new("list_symbols_command_undecorated_file_successful")
+            .expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+        // The command should succeed
+        assert!(app
+            .list_symbols_command(
+                pdb_path,
+                "resym_test::".to_string(),
+                false,
+                false,
+                false,
+                true,
+                &[],
+                Some(output_path.clone()),
+            )
+            .is_ok());
 
-    const TEST_PDB_FILE_PATH: &str = "../resym_core/tests/data/test.pdb";
-    const TEST_PDB_FROM_FILE_PATH: &str = "../resym_core/tests/data/test_diff_from.pdb";
-    const TEST_PDB_TO_FILE_PATH: &str = "../resym_core/tests/data/test_diff_to.pdb";
+        // Check that no decorated names are left
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert!(!output.is_empty());
+        assert!(output.lines().all(|line| !line.starts_with('?')));
+    }
 
-    // List types
+    // Dump symbol
     #[test]
-    fn list_types_command_invalid_pdb_path() {
+    fn dump_symbol_command_invalid_pdb_path() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::new();
         // The command should fail
         assert!(app
-            .list_types_command(
+            .dump_symbol_command(
                 pdb_path,
-                "resym_test::StructTest".to_string(),
+                Some("??1UnionTest@resym_test@@QEAA@XZ".to_string()),
+                PrimitiveReconstructionFlavor::Microsoft,
                 false,
                 false,
                 false,
-                None,
+                None
             )
             .is_err());
     }
 
     #[test]
-    fn list_types_command_stdio_successful() {
+    fn dump_symbol_command_stdio_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
         // The command should succeed
         assert!(app
-            .list_types_command(
+            .dump_symbol_command(
                 pdb_path,
-                "resym_test::StructTest".to_string(),
+                Some("??1UnionTest@resym_test@@QEAA@XZ".to_string()),
+                PrimitiveReconstructionFlavor::Microsoft,
                 true,
                 true,
                 true,
-                None,
+                None
             )
             .is_ok());
     }
 
     #[test]
-    fn list_types_command_file_successful() {
+    fn dump_symbol_command_file_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
         let tmp_dir =
-            TempDir::new("list_types_command_file_successful").expect("TempDir creation failed");
+            TempDir::new("dump_module_command_file_successful").expect("TempDir creation failed");
         let output_path = tmp_dir.path().join("output.txt");
         // The command should succeed
         assert!(app
-            .list_types_command(
+            .dump_symbol_command(
                 pdb_path,
-                "resym_test::ClassWithNestedDeclarationsTest".to_string(),
+                Some("??1UnionTest@resym_test@@QEAA@XZ".to_string()),
+                PrimitiveReconstructionFlavor::Portable,
                 false,
                 false,
                 false,
@@ -752,28 +2786,63 @@ mod tests {
         let output = fs::read_to_string(output_path).expect("Failed to read output file");
         assert_eq!(
             output,
-            concat!(
-                "resym_test::ClassWithNestedDeclarationsTest::NestEnum\n",
-                "resym_test::ClassWithNestedDeclarationsTest\n",
-                "resym_test::ClassWithNestedDeclarationsTest::NestedUnion\n",
-                "resym_test::ClassWithNestedDeclarationsTest::NestedClass\n",
-                "resym_test::ClassWithNestedDeclarationsTest::NestedStruct\n"
-            )
+            "__cdecl resym_test::UnionTest::~UnionTest(void); // RVA=0x11bc0 ",
         );
     }
 
-    // Dump types
+    // Dump constants
     #[test]
-    fn dump_types_command_invalid_pdb_path() {
+    fn constants_command_invalid_pdb_path() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::new();
         // The command should fail
         assert!(app
-            .dump_types_command(
+            .constants_command(
                 pdb_path,
-                None,
                 PrimitiveReconstructionFlavor::Microsoft,
                 false,
+                None
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn constants_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir =
+            TempDir::new("constants_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.h");
+
+        // The command should succeed
+        assert!(app
+            .constants_command(
+                pdb_path,
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert!(output.contains("\nconstexpr VARENUM VT_I2 = 0x0002;\n"));
+    }
+
+    // Diff symbol
+    #[test]
+    fn diff_symbol_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_from = PathBuf::new();
+        let pdb_path_to = PathBuf::new();
+
+        // The command should fail
+        assert!(app
+            .diff_symbol_command(
+                pdb_path_from,
+                pdb_path_to,
+                "?_RTC_GetSrcLine@@YAHPEAEPEA_WKPEAH1K@Z".to_string(),
+                PrimitiveReconstructionFlavor::Microsoft,
                 false,
                 false,
                 false,
@@ -784,41 +2853,44 @@ mod tests {
     }
 
     #[test]
-    fn dump_types_command_stdio_successful() {
+    fn diff_symbol_command_stdio_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
+        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
 
         // The command should succeed
         assert!(app
-            .dump_types_command(
-                pdb_path,
-                None,
+            .diff_symbol_command(
+                pdb_path_from,
+                pdb_path_to,
+                "?_RTC_GetSrcLine@@YAHPEAEPEA_WKPEAH1K@Z".to_string(),
                 PrimitiveReconstructionFlavor::Microsoft,
                 true,
                 true,
                 true,
                 true,
-                true,
                 None
             )
             .is_ok());
     }
 
     #[test]
-    fn dump_types_command_file_successful() {
+    fn diff_symbol_command_file_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
+        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
+
         let tmp_dir =
-            TempDir::new("dump_types_command_file_successful").expect("TempDir creation failed");
+            TempDir::new("diff_module_command_file_successful").expect("TempDir creation failed");
         let output_path = tmp_dir.path().join("output.txt");
 
         // The command should succeed
         assert!(app
-            .dump_types_command(
-                pdb_path,
-                Some("resym_test::ClassWithNestedDeclarationsTest".to_string()),
-                PrimitiveReconstructionFlavor::Microsoft,
-                false,
+            .diff_symbol_command(
+                pdb_path_from,
+                pdb_path_to,
+                "?_RTC_GetSrcLine@@YAHPEAEPEA_WKPEAH1K@Z".to_string(),
+                PrimitiveReconstructionFlavor::Portable,
                 false,
                 false,
                 false,
@@ -831,202 +2903,344 @@ mod tests {
         let output = fs::read_to_string(output_path).expect("Failed to read output file");
         assert_eq!(
             output,
-            concat!("\nclass resym_test::ClassWithNestedDeclarationsTest { /* Size=0x1 */\n};\n")
+            " int __cdecl _RTC_GetSrcLine(unsigned char *, wchar_t *, unsigned long, int *, wchar_t *, unsigned long); // RVA=0x14c90 \n",
         );
     }
 
-    // Diff type
+    // Diff public symbols
     #[test]
-    fn diff_type_command_invalid_pdb_path() {
+    fn diff_public_symbols_command_invalid_pdb_path() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path_from = PathBuf::new();
         let pdb_path_to = PathBuf::new();
 
         // The command should fail
         assert!(app
-            .diff_type_command(
+            .diff_public_symbols_command(
                 pdb_path_from,
                 pdb_path_to,
-                "".to_string(),
                 PrimitiveReconstructionFlavor::Microsoft,
                 false,
                 false,
                 false,
                 false,
-                false,
                 None
             )
             .is_err());
     }
+
     #[test]
-    fn diff_type_command_stdio_successful() {
+    fn diff_public_symbols_command_stdio_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
         let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
 
         // The command should succeed
         assert!(app
-            .diff_type_command(
+            .diff_public_symbols_command(
                 pdb_path_from,
                 pdb_path_to,
-                "UserStructAddAndReplace".to_string(),
                 PrimitiveReconstructionFlavor::Microsoft,
                 true,
                 true,
                 true,
                 true,
-                true,
                 None
             )
             .is_ok());
     }
 
     #[test]
-    fn diff_type_command_file_successful() {
+    fn diff_public_symbols_command_file_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
         let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
 
-        let tmp_dir =
-            TempDir::new("diff_type_command_file_successful").expect("TempDir creation failed");
+        let tmp_dir = TempDir::new("diff_public_symbols_command_file_successful")
+            .expect("TempDir creation failed");
         let output_path = tmp_dir.path().join("output.txt");
 
         // The command should succeed
         assert!(app
-            .diff_type_command(
+            .diff_public_symbols_command(
                 pdb_path_from,
                 pdb_path_to,
-                "UserStructAddAndReplace".to_string(),
                 PrimitiveReconstructionFlavor::Portable,
                 false,
                 false,
                 false,
                 false,
-                false,
                 Some(output_path.clone()),
             )
             .is_ok());
 
         // Check output file's content
         let output = fs::read_to_string(output_path).expect("Failed to read output file");
-        assert_eq!(
-            output,
-            concat!(
-                " \n-struct UserStructAddAndReplace { /* Size=0x10 */\n",
-                "-  /* 0x0000 */ int32_t field1;\n-  /* 0x0004 */ char field2;\n",
-                "-  /* 0x0008 */ void* field3;\n+struct UserStructAddAndReplace { /* Size=0x28 */\n",
-                "+  /* 0x0000 */ int32_t before1;\n+  /* 0x0004 */ int32_t field1;\n",
-                "+  /* 0x0008 */ int32_t between12;\n+  /* 0x000c */ char field2;\n",
-                "+  /* 0x0010 */ int32_t between23;\n+  /* 0x0018 */ void* field3;\n",
-                "+  /* 0x0020 */ int32_t after3;\n };\n",
+        assert!(output.contains(
+            " int __cdecl _RTC_GetSrcLine(unsigned char *, wchar_t *, unsigned long, int *, wchar_t *, unsigned long);\n"
+        ));
+        // Addresses must not be part of the output
+        assert!(!output.contains("RVA="));
+    }
+
+    // Diff directories
+    #[test]
+    fn diff_dir_command_invalid_directory_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let tmp_dir = TempDir::new("diff_dir_command_invalid_directory_path")
+            .expect("TempDir creation failed");
+
+        // The command should fail
+        assert!(app
+            .diff_dir_command(
+                tmp_dir.path().join("inexistent_from"),
+                tmp_dir.path().join("inexistent_to"),
+                tmp_dir.path().join("output"),
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                false,
+                false,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn diff_dir_command_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let tmp_dir = TempDir::new("diff_dir_command_successful").expect("TempDir creation failed");
+        let from_directory_path = tmp_dir.path().join("from");
+        let to_directory_path = tmp_dir.path().join("to");
+        let output_directory_path = tmp_dir.path().join("output");
+        fs::create_dir(&from_directory_path).expect("Failed to create directory");
+        fs::create_dir(&to_directory_path).expect("Failed to create directory");
+        fs::copy(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH),
+            from_directory_path.join("test.pdb"),
+        )
+        .expect("Failed to copy PDB file");
+        fs::copy(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH),
+            to_directory_path.join("test.pdb"),
+        )
+        .expect("Failed to copy PDB file");
+
+        // The command should succeed
+        assert!(app
+            .diff_dir_command(
+                from_directory_path,
+                to_directory_path,
+                output_directory_path.clone(),
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                true,
+                false,
+            )
+            .is_ok());
+
+        // Check the report's content
+        let report = fs::read_to_string(output_directory_path.join("test.pdb.diff"))
+            .expect("Failed to read report");
+        assert!(report.contains("// UserStructAddAndReplace: +8 -4\n"));
+        assert!(report.contains("+  /* 0x0000 */ int32_t before1;\n"));
+    }
+
+    // Diff all types
+    #[test]
+    fn diff_all_command_invalid_filter() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
+        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
+
+        // The command should fail
+        assert!(app
+            .diff_all_command(
+                pdb_path_from,
+                pdb_path_to,
+                Some("(".to_string()),
+                None,
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                true,
+                false,
+                false,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn diff_all_command_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
+        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
+        let tmp_dir = TempDir::new("diff_all_command_successful").expect("TempDir creation failed");
+        let output_directory_path = tmp_dir.path().join("output");
+
+        // The command should succeed
+        assert!(app
+            .diff_all_command(
+                pdb_path_from,
+                pdb_path_to,
+                Some("Struct".to_string()),
+                Some(output_directory_path.clone()),
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                true,
+                false,
+                false,
             )
+            .is_ok());
+
+        // Check the diffs' content
+        let diff = fs::read_to_string(output_directory_path.join("UserStructAddAndReplace.diff"))
+            .expect("Failed to read diff");
+        assert!(diff.contains("+  /* 0x0000 */ int32_t before1;\n"));
+        // Types that only exist in one of the PDBs aren't diffed
+        assert!(!output_directory_path.join("NewStruct.diff").exists());
+        assert!(!output_directory_path.join("RemovedStruct.diff").exists());
+        // Types that don't match the filter aren't either
+        for diff_entry in fs::read_dir(&output_directory_path).expect("Failed to list diffs") {
+            let diff_file_name = diff_entry.expect("Failed to list diffs").file_name();
+            assert!(diff_file_name.to_string_lossy().contains("Struct"));
+        }
+    }
+
+    #[test]
+    fn unique_file_name_collisions() {
+        let mut used_file_names = HashSet::new();
+        assert_eq!(unique_file_name("a<b>", &mut used_file_names), "a_b_");
+        assert_eq!(unique_file_name("a_b_", &mut used_file_names), "a_b__2");
+        assert_eq!(unique_file_name("ns::T", &mut used_file_names), "ns__T");
+        assert_eq!(unique_file_name("ns__T", &mut used_file_names), "ns__T_2");
+        assert_eq!(unique_file_name("ns::t", &mut used_file_names), "ns__t_3");
+        // Suffixed names don't collide with other types either
+        assert_eq!(unique_file_name("a_b__2", &mut used_file_names), "a_b__2_2");
+    }
+
+    // Check header layouts
+    #[test]
+    fn check_header_command_mismatching_layout() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let tmp_dir = TempDir::new("check_header_command_mismatching_layout")
+            .expect("TempDir creation failed");
+        let header_path = tmp_dir.path().join("test.h");
+        let output_path = tmp_dir.path().join("output.txt");
+        fs::write(
+            &header_path,
+            "struct resym_test::StructUnnamedUdtTest3 {\n  uint64_t Middle; // +0x20\n};\n",
+        )
+        .expect("Failed to write header");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+
+        // The command should fail
+        assert!(app
+            .check_header_command(pdb_path, header_path, Some(output_path.clone()))
+            .is_err());
+
+        // Check the report's content
+        let report = fs::read_to_string(output_path).expect("Failed to read report");
+        assert_eq!(
+            report,
+            "resym_test::StructUnnamedUdtTest3: 1 mismatch(es)\n  field `Middle` is at offset 0x28, expected 0x20\n"
         );
     }
 
-    // List modules
     #[test]
-    fn list_modules_command_invalid_pdb_path() {
+    fn check_header_command_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let tmp_dir =
+            TempDir::new("check_header_command_successful").expect("TempDir creation failed");
+        let header_path = tmp_dir.path().join("test.h");
+        let output_path = tmp_dir.path().join("output.txt");
+        fs::write(
+            &header_path,
+            "struct resym_test::StructUnnamedUdtTest3 { /* Size=0x40 */\n  /* 0x0000 */ uint64_t Before;\n  /* 0x0038 */ uint64_t After;\n};\n",
+        )
+        .expect("Failed to write header");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+
+        // The command should succeed
+        assert!(app
+            .check_header_command(pdb_path, header_path, Some(output_path.clone()))
+            .is_ok());
+
+        // Check the report's content
+        let report = fs::read_to_string(output_path).expect("Failed to read report");
+        assert_eq!(
+            report,
+            "resym_test::StructUnnamedUdtTest3: OK (2 field(s) checked)\n"
+        );
+    }
+
+    // Demangle symbol name
+    #[test]
+    fn undname_command_invalid_name() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path = PathBuf::new();
         // The command should fail
         assert!(app
-            .list_modules_command(pdb_path, "*".to_string(), false, false, None)
+            .undname_command("not_a_decorated_name@".to_string(), false, None)
             .is_err());
     }
 
     #[test]
-    fn list_modules_command_stdio_successful() {
+    fn undname_command_stdio_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
         // The command should succeed
         assert!(app
-            .list_modules_command(pdb_path, "*".to_string(), true, true, None)
+            .undname_command("?foo@@YAXXZ".to_string(), true, None)
             .is_ok());
     }
 
     #[test]
-    fn list_modules_command_file_successful() {
+    fn undname_command_file_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
         let tmp_dir =
-            TempDir::new("list_modules_command_file_successful").expect("TempDir creation failed");
+            TempDir::new("undname_command_file_successful").expect("TempDir creation failed");
         let output_path = tmp_dir.path().join("output.txt");
         // The command should succeed
         assert!(app
-            .list_modules_command(
-                pdb_path,
-                "*".to_string(),
-                false,
-                false,
-                Some(output_path.clone()),
-            )
+            .undname_command("?foo@@YAXXZ".to_string(), false, Some(output_path.clone()))
             .is_ok());
 
         // Check output file's content
         let output = fs::read_to_string(output_path).expect("Failed to read output file");
-        assert_eq!(
-            output,
-            concat!(
-                "Mod 0048 | '* Linker Generated Manifest RES *'\n",
-                "Mod 0053 | '* Linker *'\n"
-            )
-        );
+        assert_eq!(output, "void __cdecl foo(void)\n");
     }
 
-    // Dump module
+    // Look up address
     #[test]
-    fn dump_module_command_invalid_pdb_path() {
+    fn lookup_address_command_invalid_pdb_path() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::new();
         // The command should fail
         assert!(app
-            .dump_module_command(
-                pdb_path,
-                9, // exe_main.obj
-                PrimitiveReconstructionFlavor::Microsoft,
-                false,
-                false,
-                false,
-                None
-            )
+            .lookup_address_command(pdb_path, 0x1000, None, None)
             .is_err());
     }
 
     #[test]
-    fn dump_module_command_stdio_successful() {
+    fn lookup_address_command_stdio_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
         // The command should succeed
         assert!(app
-            .dump_module_command(
-                pdb_path,
-                9, // exe_main.obj
-                PrimitiveReconstructionFlavor::Microsoft,
-                true,
-                true,
-                true,
-                None
-            )
+            .lookup_address_command(pdb_path, 0x14680, None, None)
             .is_ok());
     }
 
     #[test]
-    fn dump_module_command_file_successful() {
+    fn lookup_address_command_file_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
-        let tmp_dir =
-            TempDir::new("dump_module_command_file_successful").expect("TempDir creation failed");
+        let tmp_dir = TempDir::new("lookup_address_command_file_successful")
+            .expect("TempDir creation failed");
         let output_path = tmp_dir.path().join("output.txt");
         // The command should succeed
         assert!(app
-            .dump_module_command(
+            .lookup_address_command(
                 pdb_path,
-                27, // default_local_stdio_options.obj
-                PrimitiveReconstructionFlavor::Portable,
-                false,
-                false,
-                false,
-                Some(output_path.clone()),
+                0x140014690,
+                Some(0x140000000),
+                Some(output_path.clone())
             )
             .is_ok());
 
@@ -1034,80 +3248,50 @@ mod tests {
         let output = fs::read_to_string(output_path).expect("Failed to read output file");
         assert_eq!(
             output,
-            concat!(
-                "using namespace std;\n",
-                "using PUWSTR_C = const wchar_t*;\n",
-                "using TP_CALLBACK_ENVIRON_V3 = _TP_CALLBACK_ENVIRON_V3;\n",
-                "uint64_t* (__local_stdio_scanf_options)(); // RVA=0x14670 CodeSize=0x8\n",
-                "static uint64_t _OptionsStorage; // RVA=0x1eb28 \n",
-                "void (__scrt_initialize_default_local_stdio_options)(); // RVA=0x14680 CodeSize=0x45\n",
-            )
+            "__scrt_initialize_default_local_stdio_options+0x10 (RVA=0x14680)\n"
         );
     }
 
-    // Diff module
+    // Symbol location
     #[test]
-    fn diff_module_command_invalid_pdb_path() {
+    fn addr_command_invalid_pdb_path() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path_from = PathBuf::new();
-        let pdb_path_to = PathBuf::new();
-
+        let pdb_path = PathBuf::new();
         // The command should fail
         assert!(app
-            .diff_module_command(
-                pdb_path_from,
-                pdb_path_to,
-                "d:\\a01\\_work\\43\\s\\Intermediate\\vctools\\msvcrt.nativeproj_607447030\\objd\\amd64\\exe_main.obj".to_string(),
-                PrimitiveReconstructionFlavor::Microsoft,
-                false,
-                false,
-                false,
-                None
-            )
+            .addr_command(pdb_path, "main".to_string(), None, None)
             .is_err());
     }
 
     #[test]
-    fn diff_module_command_stdio_successful() {
+    fn addr_command_stdio_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
-        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
-
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
         // The command should succeed
         assert!(app
-            .diff_module_command(
-                pdb_path_from,
-                pdb_path_to,
-                "d:\\a01\\_work\\43\\s\\Intermediate\\vctools\\msvcrt.nativeproj_607447030\\objd\\amd64\\exe_main.obj".to_string(),
-                PrimitiveReconstructionFlavor::Microsoft,
-                true,
-                true,true,
+            .addr_command(
+                pdb_path,
+                "__scrt_initialize_default_local_stdio_options".to_string(),
+                None,
                 None
             )
             .is_ok());
     }
 
     #[test]
-    fn diff_module_command_file_successful() {
+    fn addr_command_file_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
-        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
-
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
         let tmp_dir =
-            TempDir::new("diff_module_command_file_successful").expect("TempDir creation failed");
+            TempDir::new("addr_command_file_successful").expect("TempDir creation failed");
         let output_path = tmp_dir.path().join("output.txt");
-
         // The command should succeed
         assert!(app
-            .diff_module_command(
-                pdb_path_from,
-                pdb_path_to,
-                "d:\\a01\\_work\\43\\s\\Intermediate\\vctools\\msvcrt.nativeproj_607447030\\objd\\amd64\\default_local_stdio_options.obj".to_string(),
-                PrimitiveReconstructionFlavor::Portable,
-                false,
-                false,
-                false,
-                Some(output_path.clone()),
+            .addr_command(
+                pdb_path,
+                "__scrt_initialize_default_local_stdio_options".to_string(),
+                None,
+                Some(output_path.clone())
             )
             .is_ok());
 
@@ -1115,81 +3299,80 @@ mod tests {
         let output = fs::read_to_string(output_path).expect("Failed to read output file");
         assert_eq!(
             output,
-            concat!(
-                " using namespace std;\n",
-                " using PUWSTR_C = const wchar_t*;\n",
-                " using TP_CALLBACK_ENVIRON_V3 = _TP_CALLBACK_ENVIRON_V3;\n",
-                " uint64_t* (__local_stdio_scanf_options)(); // RVA=0x13c30 CodeSize=0x8\n",
-                " static uint64_t _OptionsStorage; // RVA=0x1c898 \n",
-                " void (__scrt_initialize_default_local_stdio_options)(); // RVA=0x13c40 CodeSize=0x45\n",
-            )
+            "__scrt_initialize_default_local_stdio_options: RVA=0x14680 Section=.text Size=0x45\n"
         );
     }
 
-    // List symbols
     #[test]
-    fn list_symbols_command_invalid_pdb_path() {
+    fn addr_command_invalid_pe_path() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path = PathBuf::new();
-        // The command should fail
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        // The command should fail, PDB files aren't PE images
         assert!(app
-            .list_symbols_command(pdb_path, "*".to_string(), false, false, false, None)
+            .addr_command(
+                pdb_path.clone(),
+                "__scrt_initialize_default_local_stdio_options".to_string(),
+                Some(pdb_path),
+                None
+            )
             .is_err());
     }
 
+    // PDB information
     #[test]
-    fn list_symbols_command_stdio_successful() {
+    fn info_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::new();
+        // The command should fail
+        assert!(app.info_command(pdb_path, None).is_err());
+    }
+
+    #[test]
+    fn info_command_stdio_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
         // The command should succeed
-        assert!(app
-            .list_symbols_command(pdb_path, "*".to_string(), true, true, true, None)
-            .is_ok());
+        assert!(app.info_command(pdb_path, None).is_ok());
     }
 
     #[test]
-    fn list_symbols_command_file_successful() {
+    fn info_command_file_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
         let tmp_dir =
-            TempDir::new("list_modules_command_file_successful").expect("TempDir creation failed");
+            TempDir::new("info_command_file_successful").expect("TempDir creation failed");
         let output_path = tmp_dir.path().join("output.txt");
         // The command should succeed
         assert!(app
-            .list_symbols_command(
-                pdb_path,
-                "resym_test::UnionTest".to_string(),
-                false,
-                false,
-                false,
-                Some(output_path.clone()),
-            )
+            .info_command(pdb_path, Some(output_path.clone()))
             .is_ok());
 
         // Check output file's content
         let output = fs::read_to_string(output_path).expect("Failed to read output file");
-        assert_eq!(
-            output,
-            concat!(
-                "resym_test::UnionTest::UnionTest\n",
-                "resym_test::UnionTest::~UnionTest\n"
-            )
-        );
+        assert!(output.contains("\nMachine type: Amd64\n"));
+        assert!(output.ends_with("\nStripped: no\n"));
     }
 
-    // Dump symbol
+    // Tests for `dependency_graph_command`
     #[test]
-    fn dump_symbol_command_invalid_pdb_path() {
+    fn dependency_graph_command_invalid_pdb_path() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::new();
         // The command should fail
         assert!(app
-            .dump_symbol_command(
+            .dependency_graph_command(pdb_path, None, false, None)
+            .is_err());
+    }
+
+    #[test]
+    fn dependency_graph_command_invalid_type_name() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        // The command should fail
+        assert!(app
+            .dependency_graph_command(
                 pdb_path,
-                Some("??1UnionTest@resym_test@@QEAA@XZ".to_string()),
-                PrimitiveReconstructionFlavor::Microsoft,
-                false,
-                false,
+                Some("resym_test::MissingType".to_string()),
                 false,
                 None
             )
@@ -1197,67 +3380,50 @@ mod tests {
     }
 
     #[test]
-    fn dump_symbol_command_stdio_successful() {
+    fn dependency_graph_command_stdio_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
         // The command should succeed
         assert!(app
-            .dump_symbol_command(
-                pdb_path,
-                Some("??1UnionTest@resym_test@@QEAA@XZ".to_string()),
-                PrimitiveReconstructionFlavor::Microsoft,
-                true,
-                true,
-                true,
-                None
-            )
+            .dependency_graph_command(pdb_path, None, true, None)
             .is_ok());
     }
 
     #[test]
-    fn dump_symbol_command_file_successful() {
+    fn dependency_graph_command_file_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
-        let tmp_dir =
-            TempDir::new("dump_module_command_file_successful").expect("TempDir creation failed");
-        let output_path = tmp_dir.path().join("output.txt");
+        let tmp_dir = TempDir::new("dependency_graph_command_file_successful")
+            .expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.dot");
         // The command should succeed
         assert!(app
-            .dump_symbol_command(
+            .dependency_graph_command(
                 pdb_path,
-                Some("??1UnionTest@resym_test@@QEAA@XZ".to_string()),
-                PrimitiveReconstructionFlavor::Portable,
-                false,
+                Some("resym_test::SpecializedInterfaceImplClass".to_string()),
                 false,
-                false,
-                Some(output_path.clone()),
+                Some(output_path.clone())
             )
             .is_ok());
 
         // Check output file's content
         let output = fs::read_to_string(output_path).expect("Failed to read output file");
-        assert_eq!(
-            output,
-            "__cdecl resym_test::UnionTest::~UnionTest(void); // RVA=0x11bc0 ",
-        );
+        assert!(output.starts_with("digraph types {\n"));
+        assert!(output.contains("[label=\"resym_test::SpecializedInterfaceImplClass\"];\n"));
+        assert!(output.contains("[label=\"resym_test::PureVirtualClassSpecialized\"];\n"));
+        assert!(output.ends_with("}\n"));
     }
 
-    // Diff symbol
+    // Tests for `layout_assertions_command`
     #[test]
-    fn diff_symbol_command_invalid_pdb_path() {
+    fn layout_assertions_command_invalid_type_name() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path_from = PathBuf::new();
-        let pdb_path_to = PathBuf::new();
-
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
         // The command should fail
         assert!(app
-            .diff_symbol_command(
-                pdb_path_from,
-                pdb_path_to,
-                "?_RTC_GetSrcLine@@YAHPEAEPEA_WKPEAH1K@Z".to_string(),
-                PrimitiveReconstructionFlavor::Microsoft,
-                false,
-                false,
+            .layout_assertions_command(
+                pdb_path,
+                vec!["resym_test::MissingType".to_string()],
                 false,
                 None
             )
@@ -1265,55 +3431,64 @@ mod tests {
     }
 
     #[test]
-    fn diff_symbol_command_stdio_successful() {
+    fn layout_assertions_command_file_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
-        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
-
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir = TempDir::new("layout_assertions_command_file_successful")
+            .expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.cpp");
         // The command should succeed
         assert!(app
-            .diff_symbol_command(
-                pdb_path_from,
-                pdb_path_to,
-                "?_RTC_GetSrcLine@@YAHPEAEPEA_WKPEAH1K@Z".to_string(),
-                PrimitiveReconstructionFlavor::Microsoft,
-                true,
-                true,
-                true,
-                None
+            .layout_assertions_command(
+                pdb_path,
+                vec![
+                    "resym_test::StructUnnamedUdtTest3".to_string(),
+                    "resym_test::BitFieldsTest2".to_string()
+                ],
+                false,
+                Some(output_path.clone())
             )
             .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert!(output.contains("#include <cstddef>\n"));
+        assert!(output.contains("static_assert(sizeof(resym_test::StructUnnamedUdtTest3) == 0x40, \"Unexpected size for resym_test::StructUnnamedUdtTest3\");\n"));
+        assert!(output.contains("static_assert(offsetof(resym_test::StructUnnamedUdtTest3, Middle) == 0x28, \"Unexpected offset for resym_test::StructUnnamedUdtTest3::Middle\");\n"));
+        assert!(output.contains("static_assert(sizeof(resym_test::BitFieldsTest2) == 0x2, \"Unexpected size for resym_test::BitFieldsTest2\");\n"));
+        // Bitfields cannot be checked with `offsetof`
+        assert!(!output.contains("offsetof(resym_test::BitFieldsTest2"));
     }
 
+    // Serve
     #[test]
-    fn diff_symbol_command_file_successful() {
+    fn serve_command_invalid_pdb_path() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
-        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
-
-        let tmp_dir =
-            TempDir::new("diff_module_command_file_successful").expect("TempDir creation failed");
-        let output_path = tmp_dir.path().join("output.txt");
-
-        // The command should succeed
-        assert!(app
-            .diff_symbol_command(
-                pdb_path_from,
-                pdb_path_to,
-                "?_RTC_GetSrcLine@@YAHPEAEPEA_WKPEAH1K@Z".to_string(),
-                PrimitiveReconstructionFlavor::Portable,
-                false,
-                false,
-                false,
-                Some(output_path.clone()),
-            )
-            .is_ok());
+        let pdb_path = PathBuf::new();
+        // The command should fail before serving anything
+        assert!(app.serve_command(vec![pdb_path], None).is_err());
+    }
 
-        // Check output file's content
-        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+    // PDB URLs
+    #[cfg(feature = "http")]
+    #[test]
+    fn pdb_url_only_matches_http_urls() {
         assert_eq!(
-            output,
-            " int __cdecl _RTC_GetSrcLine(unsigned char *, wchar_t *, unsigned long, int *, wchar_t *, unsigned long); // RVA=0x14c90 \n",
+            pdb_url(Path::new(
+                "https://msdl.microsoft.com/download/symbols/test.pdb"
+            )),
+            Some("https://msdl.microsoft.com/download/symbols/test.pdb")
         );
+        assert_eq!(pdb_url(Path::new(TEST_PDB_FILE_PATH)), None);
+        assert_eq!(pdb_url(Path::new("ftp://example.com/test.pdb")), None);
+    }
+
+    // LSP
+    #[test]
+    fn lsp_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::new();
+        // The command should fail before serving anything
+        assert!(app.lsp_command(pdb_path).is_err());
     }
 }
@@ -1,6 +1,9 @@
-use std::path::PathBuf;
+use std::{num::ParseIntError, path::PathBuf};
 
-use resym_core::pdb_types::PrimitiveReconstructionFlavor;
+use resym_core::{
+    pdb_file::SymbolKind,
+    pdb_types::{EnumValueOrder, PrimitiveReconstructionFlavor},
+};
 use structopt::StructOpt;
 
 const PKG_NAME: &str = env!("CARGO_PKG_NAME");
@@ -8,8 +11,41 @@ const PKG_NAME: &str = env!("CARGO_PKG_NAME");
 #[derive(Debug, StructOpt)]
 #[structopt(
     name = PKG_NAME,
-    about = "resymc is a utility that allows browsing and extracting types from PDB files."
+    about = "resymc is a utility that allows browsing and extracting types from PDB files.",
+    after_help = "PDB files can also be given as HTTP(S) URLs (except for `dump --watch`, `serve` \
+                  and `lsp`), in which case they're downloaded once and cached. Cached copies \
+                  never expire, use `--refresh-downloads` to download them again."
 )]
+pub struct ResymcArguments {
+    /// Only print errors, without progress bars
+    #[structopt(short = "q", long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+    /// Print informational messages (-v) or debug messages (-vv)
+    #[structopt(short = "v", long, global = true, parse(from_occurrences))]
+    pub verbose: u8,
+    /// Download PDB files given as URLs even if they're cached
+    #[structopt(long, global = true)]
+    pub refresh_downloads: bool,
+    #[structopt(subcommand)]
+    pub command: ResymcOptions,
+}
+
+impl ResymcArguments {
+    /// Level of the messages to log, unless overridden with `RUST_LOG`
+    pub fn log_level(&self) -> log::LevelFilter {
+        if self.quiet {
+            return log::LevelFilter::Error;
+        }
+        match self.verbose {
+            0 => log::LevelFilter::Warn,
+            1 => log::LevelFilter::Info,
+            2 => log::LevelFilter::Debug,
+            _ => log::LevelFilter::Trace,
+        }
+    }
+}
+
+#[derive(Debug, StructOpt)]
 pub enum ResymcOptions {
     /// List types from a given PDB file
     List {
@@ -28,6 +64,30 @@ pub enum ResymcOptions {
         /// Filter out types in the `std` namespace
         #[structopt(short = "s", long)]
         ignore_std_types: bool,
+        /// Don't keep type names in memory (slower, for huge PDB files)
+        #[structopt(long)]
+        reduced_memory: bool,
+    },
+    /// Search types in several PDB files at once (e.g., all the modules of a
+    /// process), printing the PDB each type was found in
+    Search {
+        /// Search filter
+        type_name_filter: String,
+        /// Paths of the PDB files to search
+        #[structopt(required = true, min_values = 1)]
+        pdb_paths: Vec<PathBuf>,
+        /// Path of the output file
+        #[structopt(short = "o", long)]
+        output_file_path: Option<PathBuf>,
+        /// Do not match case
+        #[structopt(short = "i", long)]
+        case_insensitive: bool,
+        /// Use regular expressions
+        #[structopt(short = "r", long)]
+        use_regex: bool,
+        /// Filter out types in the `std` namespace
+        #[structopt(short = "s", long)]
+        ignore_std_types: bool,
     },
     /// Dump type from a given PDB file
     Dump {
@@ -40,21 +100,66 @@ pub enum ResymcOptions {
         /// Representation of primitive types
         #[structopt(short = "f", long)]
         primitive_types_flavor: Option<PrimitiveReconstructionFlavor>,
+        /// Path of a file giving custom names to primitive types, one per
+        /// line (`<portable name> => <name>`). Implies `-f custom`.
+        #[structopt(long)]
+        primitive_type_mapping: Option<PathBuf>,
         /// Print header
         #[structopt(short = "h", long)]
         print_header: bool,
+        /// Path of a file containing the template of the header (e.g.,
+        /// `// {pdb_name} ({guid})`)
+        #[structopt(long)]
+        header_template: Option<PathBuf>,
         /// Print declarations of referenced types
         #[structopt(short = "d", long)]
         print_dependencies: bool,
+        /// Only print forward declarations of referenced types, instead of
+        /// their definitions
+        #[structopt(long, conflicts_with = "print-dependencies")]
+        forward_declarations: bool,
         /// Print C++ access specifiers
         #[structopt(short = "a", long)]
         print_access_specifiers: bool,
+        /// Annotate static data members with the RVA of their definition
+        #[structopt(long)]
+        print_static_rvas: bool,
+        /// Emit Doxygen comments with the metadata of types and fields
+        #[structopt(long)]
+        doxygen: bool,
+        /// Annotate the enumerators of flag enums with their bits
+        #[structopt(long)]
+        flag_comments: bool,
+        /// Order of enumerators (`record`, `value` or `name`)
+        #[structopt(long, default_value = "record")]
+        enum_order: EnumValueOrder,
+        /// Print virtual addresses next to RVAs, using the given image base
+        #[structopt(long, parse(try_from_str = parse_address))]
+        image_base: Option<u64>,
+        /// Print virtual addresses next to RVAs, using the image base of the
+        /// given PE image
+        #[structopt(long, conflicts_with = "image-base")]
+        pe_path: Option<PathBuf>,
         /// Filter out types in the `std` namespace
         #[structopt(short = "s", long)]
         ignore_std_types: bool,
+        /// Don't keep type names in memory (slower, for huge PDB files)
+        #[structopt(long)]
+        reduced_memory: bool,
+        /// Treat referenced types matching the given rule (`<regex> [=> <header>]`)
+        /// as opaque
+        #[structopt(short = "O", long = "opaque-type", number_of_values = 1)]
+        opaque_types: Vec<String>,
         /// Highlight C++ output
         #[structopt(short = "H", long)]
         highlight_syntax: bool,
+        /// Reconstruct the type again each time the PDB file changes
+        #[structopt(short = "w", long)]
+        watch: bool,
+        /// In watch mode, print a diff against the previous output instead of
+        /// the whole type
+        #[structopt(long)]
+        watch_diff: bool,
     },
     /// Dump all types from a given PDB file
     DumpAll {
@@ -65,12 +170,68 @@ pub enum ResymcOptions {
         /// Representation of primitive types
         #[structopt(short = "f", long)]
         primitive_types_flavor: Option<PrimitiveReconstructionFlavor>,
+        /// Path of a file giving custom names to primitive types, one per
+        /// line (`<portable name> => <name>`). Implies `-f custom`.
+        #[structopt(long)]
+        primitive_type_mapping: Option<PathBuf>,
         /// Print header
         #[structopt(short = "h", long)]
         print_header: bool,
+        /// Path of a file containing the template of the header (e.g.,
+        /// `// {pdb_name} ({guid})`)
+        #[structopt(long)]
+        header_template: Option<PathBuf>,
         /// Print C++ access specifiers
         #[structopt(short = "a", long)]
         print_access_specifiers: bool,
+        /// Annotate static data members with the RVA of their definition
+        #[structopt(long)]
+        print_static_rvas: bool,
+        /// Emit Doxygen comments with the metadata of types and fields
+        #[structopt(long)]
+        doxygen: bool,
+        /// Annotate the enumerators of flag enums with their bits
+        #[structopt(long)]
+        flag_comments: bool,
+        /// Order of enumerators (`record`, `value` or `name`)
+        #[structopt(long, default_value = "record")]
+        enum_order: EnumValueOrder,
+        /// Print virtual addresses next to RVAs, using the given image base
+        #[structopt(long, parse(try_from_str = parse_address))]
+        image_base: Option<u64>,
+        /// Print virtual addresses next to RVAs, using the image base of the
+        /// given PE image
+        #[structopt(long, conflicts_with = "image-base")]
+        pe_path: Option<PathBuf>,
+        /// Filter out types in the `std` namespace
+        #[structopt(short = "s", long)]
+        ignore_std_types: bool,
+        /// Don't keep type names in memory (slower, for huge PDB files)
+        #[structopt(long)]
+        reduced_memory: bool,
+        /// Highlight C++ output
+        #[structopt(short = "H", long)]
+        highlight_syntax: bool,
+    },
+    /// Dump all enumerations from a given PDB file into a single header,
+    /// sorted by name
+    Enums {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+        /// Representation of primitive types
+        #[structopt(short = "f", long)]
+        primitive_types_flavor: Option<PrimitiveReconstructionFlavor>,
+        /// Print header
+        #[structopt(short = "h", long)]
+        print_header: bool,
+        /// Annotate the enumerators of flag enums with their bits
+        #[structopt(long)]
+        flag_comments: bool,
+        /// Order of enumerators (`record`, `value` or `name`)
+        #[structopt(long, default_value = "record")]
+        enum_order: EnumValueOrder,
         /// Filter out types in the `std` namespace
         #[structopt(short = "s", long)]
         ignore_std_types: bool,
@@ -103,10 +264,74 @@ pub enum ResymcOptions {
         /// Filter out types in the `std` namespace
         #[structopt(short = "s", long)]
         ignore_std_types: bool,
+        /// Ignore `//` comment lines (e.g., header) when computing the diff
+        #[structopt(short = "c", long)]
+        ignore_comments: bool,
+        /// Pair types by their unique (decorated) name instead of their name
+        #[structopt(short = "u", long)]
+        match_unique_names: bool,
+        /// Don't report fields whose offset changed only because of a previous
+        /// field
+        #[structopt(long)]
+        ignore_offset_only_changes: bool,
         /// Highlight C++ output and add/deleted lines
         #[structopt(short = "H", long)]
         highlight_syntax: bool,
     },
+    /// Compute a three-way diff for a type between a base PDB file and two variants
+    Diff3 {
+        /// Path of the base PDB file
+        base_pdb_path: PathBuf,
+        /// Path of the first variant PDB file
+        a_pdb_path: PathBuf,
+        /// Path of the second variant PDB file
+        b_pdb_path: PathBuf,
+        /// Name of the type to diff
+        type_name: String,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+        /// Representation of primitive types
+        #[structopt(short = "f", long)]
+        primitive_types_flavor: Option<PrimitiveReconstructionFlavor>,
+        /// Print header
+        #[structopt(short = "h", long)]
+        print_header: bool,
+        /// Print declarations of referenced types
+        #[structopt(short = "d", long)]
+        print_dependencies: bool,
+        /// Print C++ access specifiers
+        #[structopt(short = "a", long)]
+        print_access_specifiers: bool,
+        /// Filter out types in the `std` namespace
+        #[structopt(short = "s", long)]
+        ignore_std_types: bool,
+        /// Ignore `//` comment lines (e.g., header) when computing the diff
+        #[structopt(short = "c", long)]
+        ignore_comments: bool,
+        /// Highlight C++ output and add/deleted lines
+        #[structopt(short = "H", long)]
+        highlight_syntax: bool,
+    },
+    /// Compare a type across several PDB files (e.g., successive builds)
+    CompareType {
+        /// Name of the type to compare
+        type_name: String,
+        /// Paths of the PDB files to compare, in order
+        #[structopt(required = true, min_values = 2)]
+        pdb_paths: Vec<PathBuf>,
+        /// Path of the output file
+        #[structopt(short = "o", long)]
+        output_file_path: Option<PathBuf>,
+        /// Representation of primitive types
+        #[structopt(short = "f", long)]
+        primitive_types_flavor: Option<PrimitiveReconstructionFlavor>,
+        /// Print C++ access specifiers
+        #[structopt(short = "a", long)]
+        print_access_specifiers: bool,
+        /// Filter out types in the `std` namespace
+        #[structopt(short = "s", long)]
+        ignore_std_types: bool,
+    },
     /// List modules from a given PDB file
     ListModules {
         /// Path to the PDB file
@@ -162,6 +387,9 @@ pub enum ResymcOptions {
         /// Print C++ access specifiers
         #[structopt(short = "a", long)]
         print_access_specifiers: bool,
+        /// Ignore `//` comment lines (e.g., header) when computing the diff
+        #[structopt(short = "c", long)]
+        ignore_comments: bool,
         /// Highlight C++ output and add/deleted lines
         #[structopt(short = "H", long)]
         highlight_syntax: bool,
@@ -183,6 +411,14 @@ pub enum ResymcOptions {
         /// Filter out types in the `std` namespace
         #[structopt(short = "s", long)]
         ignore_std_types: bool,
+        /// Print undecorated symbol names
+        #[structopt(short = "u", long)]
+        undecorate_names: bool,
+        /// Only list symbols of the given kinds (function, data, thunk, public
+        /// or other). Can be repeated. All kinds but thunks are listed by
+        /// default.
+        #[structopt(short = "k", long = "kind")]
+        symbol_kinds: Vec<SymbolKind>,
     },
     /// Dump symbol from a given PDB file
     DumpSymbol {
@@ -201,6 +437,13 @@ pub enum ResymcOptions {
         /// Print C++ access specifiers
         #[structopt(short = "a", long)]
         print_access_specifiers: bool,
+        /// Print virtual addresses next to RVAs, using the given image base
+        #[structopt(long, parse(try_from_str = parse_address))]
+        image_base: Option<u64>,
+        /// Print virtual addresses next to RVAs, using the image base of the
+        /// given PE image
+        #[structopt(long, conflicts_with = "image-base")]
+        pe_path: Option<PathBuf>,
         /// Highlight C++ output
         #[structopt(short = "H", long)]
         highlight_syntax: bool,
@@ -220,6 +463,26 @@ pub enum ResymcOptions {
         /// Print C++ access specifiers
         #[structopt(short = "a", long)]
         print_access_specifiers: bool,
+        /// Print virtual addresses next to RVAs, using the given image base
+        #[structopt(long, parse(try_from_str = parse_address))]
+        image_base: Option<u64>,
+        /// Print virtual addresses next to RVAs, using the image base of the
+        /// given PE image
+        #[structopt(long, conflicts_with = "image-base")]
+        pe_path: Option<PathBuf>,
+        /// Highlight C++ output
+        #[structopt(short = "H", long)]
+        highlight_syntax: bool,
+    },
+    /// Dump all constants (`S_CONSTANT` symbols) from a given PDB file
+    Constants {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+        /// Representation of primitive types
+        #[structopt(short = "f", long)]
+        primitive_types_flavor: Option<PrimitiveReconstructionFlavor>,
         /// Highlight C++ output
         #[structopt(short = "H", long)]
         highlight_syntax: bool,
@@ -243,8 +506,188 @@ pub enum ResymcOptions {
         /// Print C++ access specifiers
         #[structopt(short = "a", long)]
         print_access_specifiers: bool,
+        /// Ignore `//` comment lines (e.g., header) when computing the diff
+        #[structopt(short = "c", long)]
+        ignore_comments: bool,
         /// Highlight C++ output and add/deleted lines
         #[structopt(short = "H", long)]
         highlight_syntax: bool,
     },
+    /// Compute diff for all public symbols between two given PDB files
+    DiffPublicSymbols {
+        /// Path of the PDB file to compute the diff from
+        from_pdb_path: PathBuf,
+        /// Path of the PDB file to compute the diff to
+        to_pdb_path: PathBuf,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+        /// Representation of primitive types
+        #[structopt(short = "f", long)]
+        primitive_types_flavor: Option<PrimitiveReconstructionFlavor>,
+        /// Print header
+        #[structopt(short = "h", long)]
+        print_header: bool,
+        /// Print C++ access specifiers
+        #[structopt(short = "a", long)]
+        print_access_specifiers: bool,
+        /// Ignore `//` comment lines (e.g., header) when computing the diff
+        #[structopt(short = "c", long)]
+        ignore_comments: bool,
+        /// Highlight C++ output and add/deleted lines
+        #[structopt(short = "H", long)]
+        highlight_syntax: bool,
+    },
+    /// Diff all types of the PDB files found in two directories, paired by
+    /// file name
+    DiffDir {
+        /// Directory containing the PDB files to compute diffs from
+        from_directory_path: PathBuf,
+        /// Directory containing the PDB files to compute diffs to
+        to_directory_path: PathBuf,
+        /// Directory where change reports (one per PDB) are written
+        output_directory_path: PathBuf,
+        /// Representation of primitive types
+        #[structopt(short = "f", long)]
+        primitive_types_flavor: Option<PrimitiveReconstructionFlavor>,
+        /// Print C++ access specifiers
+        #[structopt(short = "a", long)]
+        print_access_specifiers: bool,
+        /// Filter out types in the `std` namespace
+        #[structopt(short = "s", long)]
+        ignore_std_types: bool,
+        /// Ignore `//` comment lines when computing diffs
+        #[structopt(short = "c", long)]
+        ignore_comments: bool,
+    },
+    /// Diff all the types defined in both of the given PDB files and print a
+    /// summary of the ones that changed
+    DiffAll {
+        /// Path of the PDB file to compute the diffs from
+        from_pdb_path: PathBuf,
+        /// Path of the PDB file to compute the diffs to
+        to_pdb_path: PathBuf,
+        /// Only diff types whose name matches the given regular expression
+        #[structopt(long = "filter")]
+        type_name_filter: Option<String>,
+        /// Directory where the diff of each changed type is written
+        #[structopt(short = "o", long)]
+        output_directory_path: Option<PathBuf>,
+        /// Representation of primitive types
+        #[structopt(short = "f", long)]
+        primitive_types_flavor: Option<PrimitiveReconstructionFlavor>,
+        /// Print C++ access specifiers
+        #[structopt(short = "a", long)]
+        print_access_specifiers: bool,
+        /// Filter out types in the `std` namespace
+        #[structopt(short = "s", long)]
+        ignore_std_types: bool,
+        /// Ignore `//` comment lines when computing diffs
+        #[structopt(short = "c", long)]
+        ignore_comments: bool,
+        /// Don't report fields whose offset changed only because of a previous
+        /// field
+        #[structopt(long)]
+        ignore_offset_only_changes: bool,
+    },
+    /// Check the layouts of the types defined in a C/C++ header against a PDB
+    /// file (fields must be annotated with their offset, e.g. `// +0x10`)
+    CheckHeader {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+        /// Path to the header file
+        header_path: PathBuf,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+    },
+    /// Demangle an MSVC-decorated symbol name
+    Undname {
+        /// Decorated name (e.g., `?foo@@YAXXZ`)
+        decorated_name: String,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+        /// Print C++ access specifiers
+        #[structopt(short = "a", long)]
+        print_access_specifiers: bool,
+    },
+    /// Find the symbol located at a given address
+    LookupAddress {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+        /// Address to look up (RVA, or VA if an image base is given)
+        #[structopt(parse(try_from_str = parse_address))]
+        address: u64,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+        /// Image base used to convert the address into an RVA
+        #[structopt(short = "b", long, parse(try_from_str = parse_address))]
+        image_base: Option<u64>,
+    },
+    /// Print the location (RVA, section and size) of a symbol
+    Addr {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+        /// Name of the symbol
+        symbol_name: String,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+        /// Print the exports of the symbol, using the given PE image
+        #[structopt(long)]
+        pe_path: Option<PathBuf>,
+    },
+    /// Export the type dependency graph of a given PDB file in the Graphviz DOT format
+    DependencyGraph {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+        /// Only export the dependencies of the given type
+        #[structopt(short = "t", long)]
+        type_name: Option<String>,
+        /// Filter out types in the `std` namespace
+        #[structopt(short = "s", long)]
+        ignore_std_types: bool,
+    },
+    /// Generate a C++ translation unit containing `static_assert`s for the
+    /// sizes and field offsets of types
+    LayoutAssertions {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+        /// Only generate assertions for the given types (all types otherwise)
+        #[structopt(short = "t", long = "type", number_of_values = 1)]
+        type_names: Vec<String>,
+        /// Filter out types in the `std` namespace
+        #[structopt(short = "s", long)]
+        ignore_std_types: bool,
+    },
+    /// Print information about a given PDB file
+    Info {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+    },
+    /// Keep PDB files loaded and answer JSON-RPC requests over stdio or TCP
+    Serve {
+        /// Paths to the PDB files to load on startup (in slots 0, 1, ...)
+        pdb_paths: Vec<PathBuf>,
+        /// Listen on the given TCP port (on localhost) instead of stdio
+        #[structopt(short = "p", long)]
+        port: Option<u16>,
+    },
+    /// Run a language server over stdio, which lets editors display types
+    /// from a given PDB file on hover
+    Lsp {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+    },
+}
+
+/// Parse an hexadecimal (if prefixed with `0x`) or decimal address
+fn parse_address(text: &str) -> Result<u64, ParseIntError> {
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex_text) => u64::from_str_radix(hex_text, 16),
+        None => text.parse(),
+    }
 }
@@ -0,0 +1,545 @@
+//! Minimal JSON-RPC 2.0 server, which keeps PDB files loaded in memory between
+//! requests. Requests and responses are exchanged as newline-delimited JSON
+//! objects.
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    io::{BufRead, Write},
+    path::PathBuf,
+};
+
+use anyhow::{anyhow, Result};
+use resym_core::{
+    backend::{Backend, BackendCommand, PDBSlot},
+    diffing::DiffOptions,
+    frontend::FrontendCommand,
+    pdb_types::PrimitiveReconstructionFlavor,
+};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+
+use crate::frontend::CLIFrontendController;
+
+pub const JSONRPC_VERSION: &str = "2.0";
+
+// Error codes defined by the JSON-RPC 2.0 specification
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+/// Error code used for errors returned by the backend
+pub const SERVER_ERROR: i64 = -32000;
+
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl RpcError {
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<anyhow::Error> for RpcError {
+    fn from(err: anyhow::Error) -> Self {
+        Self::new(SERVER_ERROR, err.to_string())
+    }
+}
+
+impl From<resym_core::ResymCoreError> for RpcError {
+    fn from(err: resym_core::ResymCoreError) -> Self {
+        Self::new(SERVER_ERROR, err.to_string())
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Deserialize)]
+struct LoadParams {
+    path: PathBuf,
+    #[serde(default)]
+    slot: PDBSlot,
+}
+
+#[derive(Deserialize)]
+struct ListTypesParams {
+    #[serde(default)]
+    slot: PDBSlot,
+    /// List the types of every loaded PDB instead of `slot`'s. Results are
+    /// then annotated with the slot of their PDB.
+    #[serde(default)]
+    all_slots: bool,
+    #[serde(default)]
+    filter: String,
+    #[serde(default)]
+    case_insensitive: bool,
+    #[serde(default)]
+    use_regex: bool,
+    #[serde(default)]
+    ignore_std_types: bool,
+}
+
+#[derive(Deserialize)]
+struct ReconstructTypeParams {
+    #[serde(default)]
+    slot: PDBSlot,
+    name: String,
+    primitive_types_flavor: Option<String>,
+    #[serde(default)]
+    print_header: bool,
+    #[serde(default)]
+    print_dependencies: bool,
+    #[serde(default = "default_true")]
+    print_access_specifiers: bool,
+    #[serde(default)]
+    ignore_std_types: bool,
+}
+
+#[derive(Deserialize)]
+struct DiffTypeParams {
+    #[serde(default)]
+    from_slot: PDBSlot,
+    #[serde(default = "default_diff_to_slot")]
+    to_slot: PDBSlot,
+    name: String,
+    primitive_types_flavor: Option<String>,
+    #[serde(default)]
+    print_header: bool,
+    #[serde(default)]
+    print_dependencies: bool,
+    #[serde(default = "default_true")]
+    print_access_specifiers: bool,
+    #[serde(default)]
+    ignore_std_types: bool,
+    #[serde(default)]
+    ignore_comments: bool,
+    #[serde(default)]
+    match_unique_names: bool,
+    #[serde(default)]
+    ignore_offset_only_changes: bool,
+}
+
+fn default_diff_to_slot() -> PDBSlot {
+    1
+}
+
+#[derive(Deserialize)]
+struct LookupAddressParams {
+    #[serde(default)]
+    slot: PDBSlot,
+    rva: u32,
+}
+
+/// JSON-RPC server which forwards requests to the backend
+pub struct RpcServer<'a> {
+    backend: &'a Backend,
+    frontend_controller: &'a CLIFrontendController,
+    /// Slots in which a PDB has been loaded. The backend doesn't answer
+    /// commands targeting empty slots, so we have to check them ourselves.
+    loaded_slots: RefCell<HashSet<PDBSlot>>,
+}
+
+impl<'a> RpcServer<'a> {
+    pub fn new(backend: &'a Backend, frontend_controller: &'a CLIFrontendController) -> Self {
+        Self {
+            backend,
+            frontend_controller,
+            loaded_slots: RefCell::default(),
+        }
+    }
+
+    /// Answer requests read from `reader` until EOF is reached or a `shutdown`
+    /// request is received. Returns `true` if the server has been shut down.
+    pub fn serve<R: BufRead, W: Write>(&self, reader: R, mut writer: W) -> Result<bool> {
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (response, shutdown) = self.handle_request(&line);
+            if let Some(response) = response {
+                writeln!(writer, "{response}")?;
+                writer.flush()?;
+            }
+            if shutdown {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Handle a single request. Returns the serialized response (`None` for
+    /// notifications) and whether the server should shut down.
+    pub fn handle_request(&self, request: &str) -> (Option<String>, bool) {
+        let request: Value = match serde_json::from_str(request) {
+            Ok(request) => request,
+            Err(err) => {
+                let error = RpcError::new(PARSE_ERROR, err.to_string());
+                return (Some(error_response(Value::Null, error)), false);
+            }
+        };
+
+        let id = request.get("id").cloned();
+        let Some(method) = request.get("method").and_then(Value::as_str) else {
+            let error = RpcError::new(INVALID_REQUEST, "Missing 'method' member");
+            return (
+                Some(error_response(id.unwrap_or(Value::Null), error)),
+                false,
+            );
+        };
+        let params = request.get("params").cloned().unwrap_or_else(|| json!({}));
+
+        let shutdown = method == "shutdown";
+        let result = self.dispatch(method, params);
+        // Requests without identifier are notifications and must not be answered
+        let response = id.map(|id| match result {
+            Ok(result) => {
+                json!({"jsonrpc": JSONRPC_VERSION, "id": id, "result": result}).to_string()
+            }
+            Err(error) => error_response(id, error),
+        });
+
+        (response, shutdown)
+    }
+
+    /// Execute the given method and return its result
+    pub fn dispatch(&self, method: &str, params: Value) -> std::result::Result<Value, RpcError> {
+        match method {
+            "load" => {
+                let params: LoadParams = parse_params(params)?;
+                self.load_pdb_from_path(params.slot, params.path)?;
+                Ok(json!({ "slot": params.slot }))
+            }
+            "list_types" => {
+                let params: ListTypesParams = parse_params(params)?;
+                if params.all_slots {
+                    let mut pdb_slots: Vec<PDBSlot> =
+                        self.loaded_slots.borrow().iter().copied().collect();
+                    pdb_slots.sort_unstable();
+                    self.backend.send_command(BackendCommand::ListTypesTagged(
+                        pdb_slots,
+                        params.filter,
+                        params.case_insensitive,
+                        params.use_regex,
+                        params.ignore_std_types,
+                    ))?;
+                } else {
+                    self.check_slot(params.slot)?;
+                    self.backend.send_command(BackendCommand::ListTypes(
+                        params.slot,
+                        params.filter,
+                        params.case_insensitive,
+                        params.use_regex,
+                        params.ignore_std_types,
+                    ))?;
+                }
+                match self
+                    .frontend_controller
+                    .rx_ui
+                    .recv()
+                    .map_err(anyhow::Error::from)?
+                {
+                    FrontendCommand::ListTypesResult(type_list) => Ok(type_list
+                        .into_iter()
+                        .map(|(name, index)| json!({ "name": name, "index": index }))
+                        .collect()),
+                    FrontendCommand::ListTypesTaggedResult(type_list) => Ok(type_list
+                        .into_iter()
+                        .map(|(name, slot, index)| {
+                            json!({ "name": name, "slot": slot, "index": index })
+                        })
+                        .collect()),
+                    FrontendCommand::SearchFilterError(_, err) => {
+                        Err(RpcError::new(INVALID_PARAMS, err))
+                    }
+                    _ => Err(invalid_backend_response()),
+                }
+            }
+            "reconstruct_type" => {
+                let params: ReconstructTypeParams = parse_params(params)?;
+                self.check_slot(params.slot)?;
+                self.backend
+                    .send_command(BackendCommand::ReconstructTypeByName(
+                        params.slot,
+                        params.name,
+                        parse_primitive_types_flavor(params.primitive_types_flavor)?,
+                        params.print_header,
+                        params.print_dependencies,
+                        params.print_access_specifiers,
+                        params.ignore_std_types,
+                    ))?;
+                if let FrontendCommand::ReconstructTypeResult(reconstructed_type_result) = self
+                    .frontend_controller
+                    .rx_ui
+                    .recv()
+                    .map_err(anyhow::Error::from)?
+                {
+                    let (reconstructed_type, _) = reconstructed_type_result?;
+                    Ok(Value::String(reconstructed_type))
+                } else {
+                    Err(invalid_backend_response())
+                }
+            }
+            "diff_type" => {
+                let params: DiffTypeParams = parse_params(params)?;
+                self.check_slot(params.from_slot)?;
+                self.check_slot(params.to_slot)?;
+                self.backend.send_command(BackendCommand::DiffTypeByName(
+                    params.from_slot,
+                    params.to_slot,
+                    params.name,
+                    parse_primitive_types_flavor(params.primitive_types_flavor)?,
+                    DiffOptions {
+                        print_header: params.print_header,
+                        reconstruct_dependencies: params.print_dependencies,
+                        print_access_specifiers: params.print_access_specifiers,
+                        ignore_std_types: params.ignore_std_types,
+                        ignore_comments: params.ignore_comments,
+                        match_unique_names: params.match_unique_names,
+                        ignore_offset_only_changes: params.ignore_offset_only_changes,
+                    },
+                ))?;
+                if let FrontendCommand::DiffResult(diff_result) = self
+                    .frontend_controller
+                    .rx_ui
+                    .recv()
+                    .map_err(anyhow::Error::from)?
+                {
+                    Ok(Value::String(diff_result?.data))
+                } else {
+                    Err(invalid_backend_response())
+                }
+            }
+            "lookup_address" => {
+                let params: LookupAddressParams = parse_params(params)?;
+                self.check_slot(params.slot)?;
+                self.backend
+                    .send_command(BackendCommand::LookupSymbolByRVA(params.slot, params.rva))?;
+                if let FrontendCommand::LookupSymbolByRVAResult(symbol_result) = self
+                    .frontend_controller
+                    .rx_ui
+                    .recv()
+                    .map_err(anyhow::Error::from)?
+                {
+                    let symbol = symbol_result?;
+                    Ok(json!({
+                        "name": symbol.name,
+                        "rva": symbol.rva,
+                        "offset": symbol.offset,
+                    }))
+                } else {
+                    Err(invalid_backend_response())
+                }
+            }
+            "shutdown" => Ok(Value::Null),
+            _ => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("Unknown method '{method}'"),
+            )),
+        }
+    }
+
+    /// Request the backend to load a PDB in the given slot and wait for it
+    /// to finish
+    pub fn load_pdb_from_path(&self, pdb_slot: PDBSlot, pdb_path: PathBuf) -> Result<()> {
+        self.backend
+            .send_command(BackendCommand::LoadPDBFromPath(pdb_slot, pdb_path.clone()))?;
+        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
+            result
+                .map(|_| {
+                    self.loaded_slots.borrow_mut().insert(pdb_slot);
+                })
+                .map_err(|err| anyhow!("Failed to load PDB '{}': {}", pdb_path.display(), err))
+        } else {
+            Err(anyhow!(
+                "LoadPDBResult expected. Invalid response received from the backend?"
+            ))
+        }
+    }
+
+    fn check_slot(&self, pdb_slot: PDBSlot) -> std::result::Result<(), RpcError> {
+        if self.loaded_slots.borrow().contains(&pdb_slot) {
+            Ok(())
+        } else {
+            Err(RpcError::new(
+                SERVER_ERROR,
+                format!("No PDB loaded in slot {pdb_slot}"),
+            ))
+        }
+    }
+}
+
+fn parse_params<T: DeserializeOwned>(params: Value) -> std::result::Result<T, RpcError> {
+    serde_json::from_value(params).map_err(|err| RpcError::new(INVALID_PARAMS, err.to_string()))
+}
+
+fn parse_primitive_types_flavor(
+    flavor: Option<String>,
+) -> std::result::Result<PrimitiveReconstructionFlavor, RpcError> {
+    flavor.map_or(Ok(crate::DEFAULT_PRIMITIVE_FLAVOR), |flavor| {
+        flavor.parse().map_err(|err: resym_core::ResymCoreError| {
+            RpcError::new(INVALID_PARAMS, err.to_string())
+        })
+    })
+}
+
+fn invalid_backend_response() -> RpcError {
+    RpcError::new(SERVER_ERROR, "Invalid response received from the backend?")
+}
+
+pub fn error_response(id: Value, error: RpcError) -> String {
+    json!({
+        "jsonrpc": JSONRPC_VERSION,
+        "id": id,
+        "error": { "code": error.code, "message": error.message },
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    const TEST_PDB_FILE_PATH: &str = "../resym_core/tests/data/test.pdb";
+
+    fn rpc_call(server: &RpcServer, request: Value) -> Value {
+        let (response, _) = server.handle_request(&request.to_string());
+        serde_json::from_str(&response.expect("response expected")).expect("invalid response")
+    }
+
+    fn with_server<F: FnOnce(&RpcServer)>(f: F) {
+        let (tx_ui, rx_ui) = crossbeam_channel::unbounded::<FrontendCommand>();
+        let frontend_controller = Arc::new(CLIFrontendController::new(tx_ui, rx_ui));
+        let backend = Backend::new(frontend_controller.clone()).expect("Backend creation failed");
+        f(&RpcServer::new(&backend, &frontend_controller));
+    }
+
+    #[test]
+    fn handle_request_parse_error() {
+        with_server(|server| {
+            let (response, shutdown) = server.handle_request("{not json");
+            let response: Value = serde_json::from_str(&response.unwrap()).unwrap();
+            assert_eq!(response["error"]["code"], PARSE_ERROR);
+            assert!(!shutdown);
+        });
+    }
+
+    #[test]
+    fn handle_request_method_not_found() {
+        with_server(|server| {
+            let response = rpc_call(
+                server,
+                json!({"jsonrpc": "2.0", "id": 1, "method": "unknown"}),
+            );
+            assert_eq!(response["id"], 1);
+            assert_eq!(response["error"]["code"], METHOD_NOT_FOUND);
+        });
+    }
+
+    #[test]
+    fn handle_request_invalid_pdb_path() {
+        with_server(|server| {
+            let response = rpc_call(
+                server,
+                json!({"jsonrpc": "2.0", "id": 1, "method": "load", "params": {"path": "invalid.pdb"}}),
+            );
+            assert_eq!(response["error"]["code"], SERVER_ERROR);
+        });
+    }
+
+    #[test]
+    fn handle_request_empty_slot() {
+        with_server(|server| {
+            let response = rpc_call(
+                server,
+                json!({"jsonrpc": "2.0", "id": 1, "method": "list_types", "params": {"slot": 3}}),
+            );
+            assert_eq!(response["error"]["code"], SERVER_ERROR);
+        });
+    }
+
+    #[test]
+    fn handle_request_list_types_all_slots() {
+        with_server(|server| {
+            for pdb_slot in 0..2 {
+                let response = rpc_call(
+                    server,
+                    json!({"jsonrpc": "2.0", "id": 1, "method": "load", "params": {"path": TEST_PDB_FILE_PATH, "slot": pdb_slot}}),
+                );
+                assert_eq!(response["result"]["slot"], pdb_slot);
+            }
+
+            let response = rpc_call(
+                server,
+                json!({"jsonrpc": "2.0", "id": 2, "method": "list_types", "params": {"filter": "^resym_test::StructTest$", "use_regex": true, "all_slots": true}}),
+            );
+            let slots: Vec<&Value> = response["result"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|t| &t["slot"])
+                .collect();
+            assert_eq!(slots, [0, 1]);
+        });
+    }
+
+    #[test]
+    fn handle_request_notification() {
+        with_server(|server| {
+            let (response, shutdown) =
+                server.handle_request(&json!({"jsonrpc": "2.0", "method": "shutdown"}).to_string());
+            assert!(response.is_none());
+            assert!(shutdown);
+        });
+    }
+
+    #[test]
+    fn serve_successful() {
+        with_server(|server| {
+            let requests = [
+                json!({"jsonrpc": "2.0", "id": 1, "method": "load", "params": {"path": TEST_PDB_FILE_PATH}}),
+                json!({"jsonrpc": "2.0", "id": 2, "method": "list_types", "params": {"filter": "resym_test::StructTest"}}),
+                json!({"jsonrpc": "2.0", "id": 3, "method": "reconstruct_type", "params": {"name": "resym_test::StructTest"}}),
+                json!({"jsonrpc": "2.0", "id": 4, "method": "shutdown"}),
+                json!({"jsonrpc": "2.0", "id": 5, "method": "list_types"}),
+            ]
+            .map(|request| request.to_string())
+            .join("\n");
+            let mut output = vec![];
+
+            let shutdown = server
+                .serve(requests.as_bytes(), &mut output)
+                .expect("serve failed");
+            assert!(shutdown);
+
+            let responses: Vec<Value> = String::from_utf8(output)
+                .unwrap()
+                .lines()
+                .map(|line| serde_json::from_str(line).unwrap())
+                .collect();
+            // The last request must not have been answered
+            assert_eq!(responses.len(), 4);
+            assert_eq!(responses[0]["result"]["slot"], 0);
+            assert!(responses[1]["result"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|t| t["name"] == "resym_test::StructTest"));
+            assert!(responses[2]["result"]
+                .as_str()
+                .unwrap()
+                .contains("struct resym_test::StructTest"));
+            assert!(responses[3]["result"].is_null());
+        });
+    }
+}
@@ -1,11 +1,14 @@
-use resym_core::{diffing::DiffChange, syntax_highlighting::CodeTheme};
+use resym_core::{
+    diffing::{split_range_by_inline_changes, DiffChange, DiffInlineChanges},
+    syntax_highlighting::CodeTheme,
+};
 use syntect::{
     easy::HighlightLines,
     highlighting::{Color, Style},
     util::{as_24_bit_terminal_escaped, LinesWithEndings},
 };
 
-pub type LineDescriptions = Vec<DiffChange>;
+pub type LineDescriptions = Vec<(DiffChange, DiffInlineChanges)>;
 
 const COLOR_TRANSPARENT: Color = Color {
     r: 0x00,
@@ -25,6 +28,18 @@ const COLOR_GREEN: Color = Color {
     b: 0x10,
     a: 0xFF,
 };
+const COLOR_RED_EMPHASIZED: Color = Color {
+    r: 0x90,
+    g: 0x20,
+    b: 0x20,
+    a: 0xFF,
+};
+const COLOR_GREEN_EMPHASIZED: Color = Color {
+    r: 0x20,
+    g: 0x90,
+    b: 0x20,
+    a: 0xFF,
+};
 
 /// Function relying on `syntect` to highlight the given `code` str.
 /// In case of success, the result is a `String` that is ready to be printed in a
@@ -71,13 +86,13 @@ impl CodeHighlighter {
         let mut output = String::default();
         let mut h = HighlightLines::new(syntax, &self.ts.themes[theme]);
         for (line_id, line) in LinesWithEndings::from(code).enumerate() {
-            let mut regions = h.highlight_line(line, &self.ps).ok()?;
+            let regions = h.highlight_line(line, &self.ps).ok()?;
             // Apply highlight related to diff changes if needed
-            if let Some(line_descriptions) = &line_descriptions {
-                highlight_regions_diff(&mut regions, line_descriptions.get(line_id));
+            let regions = if let Some(line_descriptions) = &line_descriptions {
+                highlight_regions_diff(regions, line_descriptions.get(line_id))
             } else {
-                highlight_regions_diff(&mut regions, None);
-            }
+                highlight_regions_diff(regions, None)
+            };
             let _r = write!(
                 &mut output,
                 "{}",
@@ -90,23 +105,47 @@ impl CodeHighlighter {
 }
 
 /// Changes the background of regions that have been affected in the diff.
-fn highlight_regions_diff(regions: &mut [(Style, &str)], line_description: Option<&DiffChange>) {
-    if let Some(line_description) = line_description {
-        let bg_color = match line_description {
-            DiffChange::Insert => COLOR_GREEN,
-            DiffChange::Delete => COLOR_RED,
-            DiffChange::Equal => COLOR_TRANSPARENT,
+/// Regions are split so that words that changed within the line can be
+/// emphasized.
+fn highlight_regions_diff<'a>(
+    mut regions: Vec<(Style, &'a str)>,
+    line_description: Option<&(DiffChange, DiffInlineChanges)>,
+) -> Vec<(Style, &'a str)> {
+    if let Some((line_change, inline_changes)) = line_description {
+        let (bg_color, bg_color_emphasized) = match line_change {
+            DiffChange::Insert => (COLOR_GREEN, COLOR_GREEN_EMPHASIZED),
+            DiffChange::Delete => (COLOR_RED, COLOR_RED_EMPHASIZED),
+            DiffChange::Equal => (COLOR_TRANSPARENT, COLOR_TRANSPARENT),
         };
-        regions.iter_mut().for_each(|(style, str)| {
-            if *str != "\n" {
-                style.background = bg_color;
-            } else {
-                style.background = COLOR_TRANSPARENT;
+
+        let mut split_regions = Vec::with_capacity(regions.len());
+        let mut region_offset = 0;
+        for (style, str) in regions {
+            let region_range = region_offset..(region_offset + str.len());
+            for (sub_range, emphasized) in
+                split_range_by_inline_changes(region_range, inline_changes)
+            {
+                let sub_str =
+                    &str[(sub_range.start - region_offset)..(sub_range.end - region_offset)];
+                let mut style = style;
+                style.background = if sub_str == "\n" {
+                    COLOR_TRANSPARENT
+                } else if emphasized {
+                    bg_color_emphasized
+                } else {
+                    bg_color
+                };
+                split_regions.push((style, sub_str));
             }
-        });
+            region_offset += str.len();
+        }
+
+        split_regions
     } else {
         regions.iter_mut().for_each(|(style, _)| {
             style.background = COLOR_TRANSPARENT;
         });
+
+        regions
     }
 }